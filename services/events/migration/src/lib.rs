@@ -1,12 +1,70 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20251004_052634_create_events_table;
+mod m20261019_090000_create_venues_and_seating_tables;
+mod m20261019_100000_create_ticket_types_table;
+mod m20261019_110000_create_event_series_tables;
+mod m20261019_120000_add_event_status_and_sequence;
+mod m20261019_130000_create_import_jobs_table;
+mod m20261019_140000_add_event_organization;
+mod m20261019_150000_add_timezones_and_event_times;
+mod m20261019_160000_create_categories_and_tags_tables;
+mod m20261019_170000_add_venue_locations;
+mod m20261019_180000_create_event_media_table;
+mod m20261019_190000_add_event_description_html;
+mod m20261019_200000_create_event_revisions_table;
+mod m20261019_210000_add_event_version;
+mod m20261019_220000_add_scheduled_publishing;
+mod m20261019_230000_create_event_templates_table;
+mod m20261020_000000_create_event_sessions_table;
+mod m20261020_010000_create_event_translations_table;
+mod m20261020_020000_create_event_cancellations_table;
+mod m20261020_060000_create_idempotency_keys_table;
+mod m20261020_070000_add_ticket_type_section;
+mod m20261020_080000_scope_event_external_uid_by_organizer;
+mod m20261020_100000_add_import_job_organizer;
+mod m20261020_110000_add_event_cancellation_claim_tokens;
+mod m20261020_120000_add_venue_owner;
+mod m20261020_130000_add_import_job_queue;
+mod m20261020_140000_add_event_series_owner;
+mod m20261020_150000_restrict_event_category_deletes;
+mod m20261020_160000_add_series_description_html;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20251004_052634_create_events_table::Migration)]
+        vec![
+            Box::new(m20251004_052634_create_events_table::Migration),
+            Box::new(m20261019_090000_create_venues_and_seating_tables::Migration),
+            Box::new(m20261019_100000_create_ticket_types_table::Migration),
+            Box::new(m20261019_110000_create_event_series_tables::Migration),
+            Box::new(m20261019_120000_add_event_status_and_sequence::Migration),
+            Box::new(m20261019_130000_create_import_jobs_table::Migration),
+            Box::new(m20261019_140000_add_event_organization::Migration),
+            Box::new(m20261019_150000_add_timezones_and_event_times::Migration),
+            Box::new(m20261019_160000_create_categories_and_tags_tables::Migration),
+            Box::new(m20261019_170000_add_venue_locations::Migration),
+            Box::new(m20261019_180000_create_event_media_table::Migration),
+            Box::new(m20261019_190000_add_event_description_html::Migration),
+            Box::new(m20261019_200000_create_event_revisions_table::Migration),
+            Box::new(m20261019_210000_add_event_version::Migration),
+            Box::new(m20261019_220000_add_scheduled_publishing::Migration),
+            Box::new(m20261019_230000_create_event_templates_table::Migration),
+            Box::new(m20261020_000000_create_event_sessions_table::Migration),
+            Box::new(m20261020_010000_create_event_translations_table::Migration),
+            Box::new(m20261020_020000_create_event_cancellations_table::Migration),
+            Box::new(m20261020_060000_create_idempotency_keys_table::Migration),
+            Box::new(m20261020_070000_add_ticket_type_section::Migration),
+            Box::new(m20261020_080000_scope_event_external_uid_by_organizer::Migration),
+            Box::new(m20261020_100000_add_import_job_organizer::Migration),
+            Box::new(m20261020_110000_add_event_cancellation_claim_tokens::Migration),
+            Box::new(m20261020_120000_add_venue_owner::Migration),
+            Box::new(m20261020_130000_add_import_job_queue::Migration),
+            Box::new(m20261020_140000_add_event_series_owner::Migration),
+            Box::new(m20261020_150000_restrict_event_category_deletes::Migration),
+            Box::new(m20261020_160000_add_series_description_html::Migration),
+        ]
     }
 }
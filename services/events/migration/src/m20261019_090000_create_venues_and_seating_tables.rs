@@ -0,0 +1,239 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create venues table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Venues::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Venues::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuid_generate_v4()"),
+                    )
+                    .col(ColumnDef::new(Venues::Name).string_len(100).not_null())
+                    .col(
+                        ColumnDef::new(Venues::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(Venues::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create venue_sections table
+        manager
+            .create_table(
+                Table::create()
+                    .table(VenueSections::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VenueSections::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuid_generate_v4()"),
+                    )
+                    .col(ColumnDef::new(VenueSections::VenueId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(VenueSections::Name)
+                            .string_len(100)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(VenueSections::Position).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_venue_sections_venue_id")
+                            .from(VenueSections::Table, VenueSections::VenueId)
+                            .to(Venues::Table, Venues::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create venue_seats table
+        manager
+            .create_table(
+                Table::create()
+                    .table(VenueSeats::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VenueSeats::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuid_generate_v4()"),
+                    )
+                    .col(ColumnDef::new(VenueSeats::VenueId).uuid().not_null())
+                    .col(ColumnDef::new(VenueSeats::SectionId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(VenueSeats::RowLabel)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(VenueSeats::RowPosition).integer().not_null())
+                    .col(ColumnDef::new(VenueSeats::Label).string_len(20).not_null())
+                    .col(ColumnDef::new(VenueSeats::Position).integer().not_null())
+                    .col(ColumnDef::new(VenueSeats::X).double().not_null())
+                    .col(ColumnDef::new(VenueSeats::Y).double().not_null())
+                    .col(
+                        ColumnDef::new(VenueSeats::Accessible)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(VenueSeats::Obstructed)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_venue_seats_section_id")
+                            .from(VenueSeats::Table, VenueSeats::SectionId)
+                            .to(VenueSections::Table, VenueSections::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_venue_seats_venue_id")
+                            .from(VenueSeats::Table, VenueSeats::VenueId)
+                            .to(Venues::Table, Venues::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // A seat label is unique within its row
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_venue_seats_section_row_label")
+                    .table(VenueSeats::Table)
+                    .col(VenueSeats::SectionId)
+                    .col(VenueSeats::RowLabel)
+                    .col(VenueSeats::Label)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create index on venue_id for loading a whole chart
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_venue_seats_venue_id")
+                    .table(VenueSeats::Table)
+                    .col(VenueSeats::VenueId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Link events to venues
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Events::Table)
+                    .add_column(ColumnDef::new(Events::VenueId).uuid().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_events_venue_id")
+                            .from_tbl(Events::Table)
+                            .from_col(Events::VenueId)
+                            .to_tbl(Venues::Table)
+                            .to_col(Venues::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Unlink events from venues
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Events::Table)
+                    .drop_foreign_key(Alias::new("fk_events_venue_id"))
+                    .drop_column(Events::VenueId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Drop the seating tables
+        manager
+            .drop_table(Table::drop().table(VenueSeats::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(VenueSections::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Venues::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Events {
+    Table,
+    VenueId,
+}
+
+#[derive(DeriveIden)]
+enum Venues {
+    Table,
+    Id,
+    Name,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum VenueSections {
+    Table,
+    Id,
+    VenueId,
+    Name,
+    Position,
+}
+
+#[derive(DeriveIden)]
+enum VenueSeats {
+    Table,
+    Id,
+    VenueId,
+    SectionId,
+    RowLabel,
+    RowPosition,
+    Label,
+    Position,
+    X,
+    Y,
+    Accessible,
+    Obstructed,
+}
@@ -0,0 +1,137 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create ticket_types table
+        manager
+            .create_table(
+                Table::create()
+                    .table(TicketTypes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TicketTypes::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuid_generate_v4()"),
+                    )
+                    .col(ColumnDef::new(TicketTypes::EventId).uuid().not_null())
+                    .col(ColumnDef::new(TicketTypes::Name).string_len(100).not_null())
+                    .col(
+                        ColumnDef::new(TicketTypes::PriceMinor)
+                            .big_integer()
+                            .not_null()
+                            .check(Expr::col(TicketTypes::PriceMinor).gte(0)),
+                    )
+                    .col(ColumnDef::new(TicketTypes::Currency).char_len(3).not_null())
+                    .col(
+                        ColumnDef::new(TicketTypes::Quantity)
+                            .integer()
+                            .not_null()
+                            .check(Expr::col(TicketTypes::Quantity).gte(0)),
+                    )
+                    .col(
+                        ColumnDef::new(TicketTypes::MinPerOrder)
+                            .integer()
+                            .not_null()
+                            .default(1)
+                            .check(Expr::col(TicketTypes::MinPerOrder).gte(1)),
+                    )
+                    .col(
+                        ColumnDef::new(TicketTypes::MaxPerOrder)
+                            .integer()
+                            .not_null()
+                            .default(10),
+                    )
+                    .col(ColumnDef::new(TicketTypes::SalesStart).timestamp_with_time_zone())
+                    .col(ColumnDef::new(TicketTypes::SalesEnd).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(TicketTypes::Visibility)
+                            .string_len(20)
+                            .not_null()
+                            .default("public"),
+                    )
+                    .col(
+                        ColumnDef::new(TicketTypes::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(TicketTypes::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .check(
+                        Expr::col(TicketTypes::MaxPerOrder)
+                            .gte(Expr::col(TicketTypes::MinPerOrder)),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_ticket_types_event_id")
+                            .from(TicketTypes::Table, TicketTypes::EventId)
+                            .to(Events::Table, Events::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create index on event_id for loading an event's ticket types
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_ticket_types_event_id")
+                    .table(TicketTypes::Table)
+                    .col(TicketTypes::EventId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop the index
+        manager
+            .drop_index(Index::drop().name("idx_ticket_types_event_id").to_owned())
+            .await?;
+
+        // Drop the ticket_types table
+        manager
+            .drop_table(Table::drop().table(TicketTypes::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Events {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum TicketTypes {
+    Table,
+    Id,
+    EventId,
+    Name,
+    PriceMinor,
+    Currency,
+    Quantity,
+    MinPerOrder,
+    MaxPerOrder,
+    SalesStart,
+    SalesEnd,
+    Visibility,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,208 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create event_series table
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventSeries::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EventSeries::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuid_generate_v4()"),
+                    )
+                    .col(
+                        ColumnDef::new(EventSeries::Title)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(EventSeries::Description).text().not_null())
+                    .col(
+                        ColumnDef::new(EventSeries::VenueName)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(EventSeries::VenueId).uuid())
+                    .col(
+                        ColumnDef::new(EventSeries::StartsAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(EventSeries::Rrule).text().not_null())
+                    .col(
+                        ColumnDef::new(EventSeries::Exdates)
+                            .array(ColumnType::TimestampWithTimeZone)
+                            .not_null()
+                            .extra("DEFAULT '{}'"),
+                    )
+                    .col(ColumnDef::new(EventSeries::LastOccurrenceAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(EventSeries::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(EventSeries::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_event_series_venue_id")
+                            .from(EventSeries::Table, EventSeries::VenueId)
+                            .to(Venues::Table, Venues::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create index on the series window used by the listing endpoint
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_event_series_window")
+                    .table(EventSeries::Table)
+                    .col(EventSeries::StartsAt)
+                    .col(EventSeries::LastOccurrenceAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create event_series_overrides table
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventSeriesOverrides::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EventSeriesOverrides::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuid_generate_v4()"),
+                    )
+                    .col(
+                        ColumnDef::new(EventSeriesOverrides::SeriesId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventSeriesOverrides::OccurrenceStart)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(EventSeriesOverrides::Title).string_len(255))
+                    .col(ColumnDef::new(EventSeriesOverrides::Description).text())
+                    .col(ColumnDef::new(EventSeriesOverrides::StartsAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(EventSeriesOverrides::VenueName).string_len(255))
+                    .col(ColumnDef::new(EventSeriesOverrides::VenueId).uuid())
+                    .col(
+                        ColumnDef::new(EventSeriesOverrides::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(EventSeriesOverrides::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_event_series_overrides_series_id")
+                            .from(EventSeriesOverrides::Table, EventSeriesOverrides::SeriesId)
+                            .to(EventSeries::Table, EventSeries::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_event_series_overrides_venue_id")
+                            .from(EventSeriesOverrides::Table, EventSeriesOverrides::VenueId)
+                            .to(Venues::Table, Venues::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // One override per occurrence of a series
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_event_series_overrides_occurrence")
+                    .table(EventSeriesOverrides::Table)
+                    .col(EventSeriesOverrides::SeriesId)
+                    .col(EventSeriesOverrides::OccurrenceStart)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop the event_series_overrides table
+        manager
+            .drop_table(Table::drop().table(EventSeriesOverrides::Table).to_owned())
+            .await?;
+
+        // Drop the event_series table
+        manager
+            .drop_table(Table::drop().table(EventSeries::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Venues {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum EventSeries {
+    Table,
+    Id,
+    Title,
+    Description,
+    VenueName,
+    VenueId,
+    StartsAt,
+    Rrule,
+    Exdates,
+    LastOccurrenceAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum EventSeriesOverrides {
+    Table,
+    Id,
+    SeriesId,
+    OccurrenceStart,
+    Title,
+    Description,
+    StartsAt,
+    VenueName,
+    VenueId,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,88 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Track lifecycle and revision count for calendar sync
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Events::Table)
+                    .add_column(
+                        ColumnDef::new(Events::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("scheduled"),
+                    )
+                    .add_column(
+                        ColumnDef::new(Events::Sequence)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(ColumnDef::new(Events::OrganizerId).uuid().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create indexes backing the per-venue and per-organizer feeds
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_events_venue_id")
+                    .table(Events::Table)
+                    .col(Events::VenueId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_events_organizer_id")
+                    .table(Events::Table)
+                    .col(Events::OrganizerId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop the indexes
+        manager
+            .drop_index(Index::drop().name("idx_events_organizer_id").to_owned())
+            .await?;
+        manager
+            .drop_index(Index::drop().name("idx_events_venue_id").to_owned())
+            .await?;
+
+        // Drop the columns
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Events::Table)
+                    .drop_column(Events::OrganizerId)
+                    .drop_column(Events::Sequence)
+                    .drop_column(Events::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Events {
+    Table,
+    VenueId,
+    Status,
+    Sequence,
+    OrganizerId,
+}
@@ -0,0 +1,124 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Remember the UID of imported events so re-imports skip them
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Events::Table)
+                    .add_column(ColumnDef::new(Events::ExternalUid).string_len(255).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_events_external_uid")
+                    .table(Events::Table)
+                    .col(Events::ExternalUid)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create import_jobs table
+        manager
+            .create_table(
+                Table::create()
+                    .table(ImportJobs::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ImportJobs::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuid_generate_v4()"),
+                    )
+                    .col(
+                        ColumnDef::new(ImportJobs::Source)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ImportJobs::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(ColumnDef::new(ImportJobs::TotalRows).integer().not_null())
+                    .col(
+                        ColumnDef::new(ImportJobs::Results)
+                            .json_binary()
+                            .not_null()
+                            .extra("DEFAULT '[]'::jsonb"),
+                    )
+                    .col(ColumnDef::new(ImportJobs::Error).text())
+                    .col(
+                        ColumnDef::new(ImportJobs::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(ImportJobs::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(ColumnDef::new(ImportJobs::CompletedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop the import_jobs table
+        manager
+            .drop_table(Table::drop().table(ImportJobs::Table).to_owned())
+            .await?;
+
+        // Drop the external UID column and its index
+        manager
+            .drop_index(Index::drop().name("idx_events_external_uid").to_owned())
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Events::Table)
+                    .drop_column(Events::ExternalUid)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Events {
+    Table,
+    ExternalUid,
+}
+
+#[derive(DeriveIden)]
+enum ImportJobs {
+    Table,
+    Id,
+    Source,
+    Status,
+    TotalRows,
+    Results,
+    Error,
+    CreatedAt,
+    UpdatedAt,
+    CompletedAt,
+}
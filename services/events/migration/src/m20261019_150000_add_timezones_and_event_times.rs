@@ -0,0 +1,117 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Existing rows were entered in UTC, so that is their timezone
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Venues::Table)
+                    .add_column(
+                        ColumnDef::new(Venues::Timezone)
+                            .string_len(64)
+                            .not_null()
+                            .default("UTC"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Events::Table)
+                    .add_column(
+                        ColumnDef::new(Events::Timezone)
+                            .string_len(64)
+                            .not_null()
+                            .default("UTC"),
+                    )
+                    .add_column(
+                        ColumnDef::new(Events::EndsAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Events::DoorsOpenAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(EventSeries::Table)
+                    .add_column(
+                        ColumnDef::new(EventSeries::Timezone)
+                            .string_len(64)
+                            .not_null()
+                            .default("UTC"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(EventSeries::Table)
+                    .drop_column(EventSeries::Timezone)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Events::Table)
+                    .drop_column(Events::Timezone)
+                    .drop_column(Events::EndsAt)
+                    .drop_column(Events::DoorsOpenAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Venues::Table)
+                    .drop_column(Venues::Timezone)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Venues {
+    Table,
+    Timezone,
+}
+
+#[derive(DeriveIden)]
+enum Events {
+    Table,
+    Timezone,
+    EndsAt,
+    DoorsOpenAt,
+}
+
+#[derive(DeriveIden)]
+enum EventSeries {
+    Table,
+    Timezone,
+}
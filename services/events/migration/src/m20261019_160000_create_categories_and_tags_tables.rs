@@ -0,0 +1,179 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create categories table; parents cannot be deleted while they have children
+        manager
+            .create_table(
+                Table::create()
+                    .table(Categories::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Categories::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuid_generate_v4()"),
+                    )
+                    .col(ColumnDef::new(Categories::ParentId).uuid().null())
+                    .col(ColumnDef::new(Categories::Name).string_len(100).not_null())
+                    .col(
+                        ColumnDef::new(Categories::Slug)
+                            .string_len(60)
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Categories::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(Categories::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_categories_parent_id")
+                            .from(Categories::Table, Categories::ParentId)
+                            .to(Categories::Table, Categories::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create event_categories table linking events to categories
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventCategories::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(EventCategories::EventId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(EventCategories::CategoryId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(EventCategories::EventId)
+                            .col(EventCategories::CategoryId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_event_categories_event_id")
+                            .from(EventCategories::Table, EventCategories::EventId)
+                            .to(Events::Table, Events::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_event_categories_category_id")
+                            .from(EventCategories::Table, EventCategories::CategoryId)
+                            .to(Categories::Table, Categories::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create event_tags table
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventTags::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(EventTags::EventId).uuid().not_null())
+                    .col(ColumnDef::new(EventTags::Tag).string_len(40).not_null())
+                    .primary_key(Index::create().col(EventTags::EventId).col(EventTags::Tag))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_event_tags_event_id")
+                            .from(EventTags::Table, EventTags::EventId)
+                            .to(Events::Table, Events::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create indexes for filtering events by category and by tag
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_event_categories_category_id")
+                    .table(EventCategories::Table)
+                    .col(EventCategories::CategoryId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_event_tags_tag")
+                    .table(EventTags::Table)
+                    .col(EventTags::Tag)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EventTags::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(EventCategories::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Categories::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Events {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Categories {
+    Table,
+    Id,
+    ParentId,
+    Name,
+    Slug,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum EventCategories {
+    Table,
+    EventId,
+    CategoryId,
+}
+
+#[derive(DeriveIden)]
+enum EventTags {
+    Table,
+    EventId,
+    Tag,
+}
@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // earthdistance models the earth as a sphere on top of cube
+        manager
+            .get_connection()
+            .execute_unprepared("CREATE EXTENSION IF NOT EXISTS cube")
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared("CREATE EXTENSION IF NOT EXISTS earthdistance")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Venues::Table)
+                    .add_column(ColumnDef::new(Venues::Latitude).double().null())
+                    .add_column(ColumnDef::new(Venues::Longitude).double().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Spatial index for radius searches; the expression must match the
+        // one the queries use
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE INDEX IF NOT EXISTS idx_venues_location ON venues \
+                 USING gist (ll_to_earth(latitude, longitude)) \
+                 WHERE latitude IS NOT NULL AND longitude IS NOT NULL",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("idx_venues_location").to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Venues::Table)
+                    .drop_column(Venues::Latitude)
+                    .drop_column(Venues::Longitude)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Venues {
+    Table,
+    Latitude,
+    Longitude,
+}
@@ -0,0 +1,115 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventMedia::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EventMedia::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuid_generate_v4()"),
+                    )
+                    .col(ColumnDef::new(EventMedia::EventId).uuid().not_null())
+                    .col(ColumnDef::new(EventMedia::Kind).string_len(20).not_null())
+                    .col(
+                        ColumnDef::new(EventMedia::ContentType)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventMedia::SizeBytes)
+                            .big_integer()
+                            .not_null()
+                            .check(Expr::col(EventMedia::SizeBytes).gte(0)),
+                    )
+                    .col(ColumnDef::new(EventMedia::Width).integer())
+                    .col(ColumnDef::new(EventMedia::Height).integer())
+                    .col(
+                        ColumnDef::new(EventMedia::StorageKey)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(EventMedia::ThumbnailKey).string_len(255))
+                    .col(ColumnDef::new(EventMedia::Url).text().not_null())
+                    .col(ColumnDef::new(EventMedia::ThumbnailUrl).text())
+                    .col(
+                        ColumnDef::new(EventMedia::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_event_media_event_id")
+                            .from(EventMedia::Table, EventMedia::EventId)
+                            .to(Events::Table, Events::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_event_media_event_id")
+                    .table(EventMedia::Table)
+                    .col(EventMedia::EventId)
+                    .col(EventMedia::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // An event has at most one poster
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX IF NOT EXISTS idx_event_media_poster \
+                 ON event_media (event_id) WHERE kind = 'poster'",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EventMedia::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Events {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum EventMedia {
+    Table,
+    Id,
+    EventId,
+    Kind,
+    ContentType,
+    SizeBytes,
+    Width,
+    Height,
+    StorageKey,
+    ThumbnailKey,
+    Url,
+    ThumbnailUrl,
+    CreatedAt,
+}
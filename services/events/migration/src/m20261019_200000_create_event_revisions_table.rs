@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One row per change to an event, keyed by the event's sequence
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventRevisions::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(EventRevisions::EventId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(EventRevisions::Revision)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventRevisions::Action)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(EventRevisions::ActorId).uuid())
+                    .col(
+                        ColumnDef::new(EventRevisions::Changes)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventRevisions::Snapshot)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventRevisions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(EventRevisions::EventId)
+                            .col(EventRevisions::Revision),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_event_revisions_event_id")
+                            .from(EventRevisions::Table, EventRevisions::EventId)
+                            .to(Events::Table, Events::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Revisions are append-only
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE OR REPLACE FUNCTION reject_event_revision_change() RETURNS trigger AS $$ \
+                 BEGIN RAISE EXCEPTION 'event revisions are immutable'; END; \
+                 $$ LANGUAGE plpgsql; \
+                 CREATE TRIGGER event_revisions_immutable BEFORE UPDATE ON event_revisions \
+                 FOR EACH ROW EXECUTE FUNCTION reject_event_revision_change();",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EventRevisions::Table).to_owned())
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared("DROP FUNCTION IF EXISTS reject_event_revision_change()")
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Events {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum EventRevisions {
+    Table,
+    EventId,
+    Revision,
+    Action,
+    ActorId,
+    Changes,
+    Snapshot,
+    CreatedAt,
+}
@@ -0,0 +1,154 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Scheduled and actual publication and on-sale times
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Events::Table)
+                    .add_column(
+                        ColumnDef::new(Events::PublishAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Events::OnSaleAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Events::PublishedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Events::SalesOpenedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Existing events were public and on sale from the start
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE events SET published_at = created_at, sales_opened_at = created_at",
+            )
+            .await?;
+
+        // Let the scheduler find pending transitions without scanning
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE INDEX IF NOT EXISTS idx_events_pending_publication \
+                 ON events (publish_at) WHERE published_at IS NULL",
+            )
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE INDEX IF NOT EXISTS idx_events_pending_sales \
+                 ON events (on_sale_at) WHERE sales_opened_at IS NULL",
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(OutboxMessages::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OutboxMessages::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(OutboxMessages::EventType)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(OutboxMessages::EventId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(OutboxMessages::Payload)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OutboxMessages::OccurredAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OutboxMessages::ClaimedUntil)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(OutboxMessages::DispatchedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE INDEX IF NOT EXISTS idx_outbox_messages_pending \
+                 ON outbox_messages (occurred_at) WHERE dispatched_at IS NULL",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OutboxMessages::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Events::Table)
+                    .drop_column(Events::PublishAt)
+                    .drop_column(Events::OnSaleAt)
+                    .drop_column(Events::PublishedAt)
+                    .drop_column(Events::SalesOpenedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Events {
+    Table,
+    PublishAt,
+    OnSaleAt,
+    PublishedAt,
+    SalesOpenedAt,
+}
+
+#[derive(DeriveIden)]
+enum OutboxMessages {
+    Table,
+    Id,
+    EventType,
+    EventId,
+    Payload,
+    OccurredAt,
+    ClaimedUntil,
+    DispatchedAt,
+}
@@ -0,0 +1,86 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventTemplates::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EventTemplates::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(EventTemplates::Name)
+                            .string_len(100)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventTemplates::OrganizerId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(EventTemplates::OrganizationId).uuid().null())
+                    .col(
+                        ColumnDef::new(EventTemplates::Blueprint)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventTemplates::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_event_templates_organizer_id")
+                    .table(EventTemplates::Table)
+                    .col(EventTemplates::OrganizerId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_event_templates_organization_id")
+                    .table(EventTemplates::Table)
+                    .col(EventTemplates::OrganizationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EventTemplates::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum EventTemplates {
+    Table,
+    Id,
+    Name,
+    OrganizerId,
+    OrganizationId,
+    Blueprint,
+    CreatedAt,
+}
@@ -0,0 +1,178 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventSessions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EventSessions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(EventSessions::EventId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(EventSessions::Title)
+                            .string_len(200)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(EventSessions::Stage).string_len(100).null())
+                    .col(
+                        ColumnDef::new(EventSessions::StartsAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventSessions::EndsAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventSessions::Capacity)
+                            .integer()
+                            .null()
+                            .check(Expr::col(EventSessions::Capacity).gte(0)),
+                    )
+                    .col(
+                        ColumnDef::new(EventSessions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(EventSessions::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .check(Expr::col(EventSessions::EndsAt).gt(Expr::col(EventSessions::StartsAt)))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_event_sessions_event_id")
+                            .from(EventSessions::Table, EventSessions::EventId)
+                            .to(Events::Table, Events::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_event_sessions_event_id_starts_at")
+                    .table(EventSessions::Table)
+                    .col(EventSessions::EventId)
+                    .col(EventSessions::StartsAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // What each ticket type admits to: the whole event, one day of it or
+        // one session. Sessions with tickets can't be deleted.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TicketTypes::Table)
+                    .add_column(
+                        ColumnDef::new(TicketTypes::Admission)
+                            .string_len(20)
+                            .not_null()
+                            .default("event"),
+                    )
+                    .add_column(ColumnDef::new(TicketTypes::AdmissionDay).date().null())
+                    .add_column(ColumnDef::new(TicketTypes::SessionId).uuid().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_ticket_types_session_id")
+                            .from_tbl(TicketTypes::Table)
+                            .from_col(TicketTypes::SessionId)
+                            .to_tbl(EventSessions::Table)
+                            .to_col(EventSessions::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE ticket_types ADD CONSTRAINT chk_ticket_types_admission CHECK (\
+                 (admission = 'event' AND admission_day IS NULL AND session_id IS NULL) \
+                 OR (admission = 'day' AND admission_day IS NOT NULL AND session_id IS NULL) \
+                 OR (admission = 'session' AND admission_day IS NULL AND session_id IS NOT NULL))",
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_ticket_types_session_id")
+                    .table(TicketTypes::Table)
+                    .col(TicketTypes::SessionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE ticket_types DROP CONSTRAINT IF EXISTS chk_ticket_types_admission",
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TicketTypes::Table)
+                    .drop_foreign_key(Alias::new("fk_ticket_types_session_id"))
+                    .drop_column(TicketTypes::Admission)
+                    .drop_column(TicketTypes::AdmissionDay)
+                    .drop_column(TicketTypes::SessionId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(EventSessions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Events {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum EventSessions {
+    Table,
+    Id,
+    EventId,
+    Title,
+    Stage,
+    StartsAt,
+    EndsAt,
+    Capacity,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum TicketTypes {
+    Table,
+    Admission,
+    AdmissionDay,
+    SessionId,
+}
@@ -0,0 +1,112 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Locale the event's own title and description are written in.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Events::Table)
+                    .add_column(
+                        ColumnDef::new(Events::DefaultLocale)
+                            .string_len(35)
+                            .not_null()
+                            .default("en"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventTranslations::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(EventTranslations::EventId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(EventTranslations::Locale)
+                            .string_len(35)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventTranslations::Title)
+                            .string_len(200)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventTranslations::Description)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventTranslations::DescriptionHtml)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventTranslations::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(EventTranslations::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(EventTranslations::EventId)
+                            .col(EventTranslations::Locale),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_event_translations_event_id")
+                            .from(EventTranslations::Table, EventTranslations::EventId)
+                            .to(Events::Table, Events::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EventTranslations::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Events::Table)
+                    .drop_column(Events::DefaultLocale)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Events {
+    Table,
+    Id,
+    DefaultLocale,
+}
+
+#[derive(DeriveIden)]
+enum EventTranslations {
+    Table,
+    EventId,
+    Locale,
+    Title,
+    Description,
+    DescriptionHtml,
+    CreatedAt,
+    UpdatedAt,
+}
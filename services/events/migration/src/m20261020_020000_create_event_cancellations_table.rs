@@ -0,0 +1,128 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Progress of the work that follows a cancellation: stopping sales,
+        // refunding orders and notifying ticket holders.
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventCancellations::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EventCancellations::EventId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(EventCancellations::Reason).text().not_null())
+                    .col(
+                        ColumnDef::new(EventCancellations::RequestedBy)
+                            .uuid()
+                            .not_null(),
+                    )
+                    // Step being worked on; null once every step is done
+                    .col(
+                        ColumnDef::new(EventCancellations::Step)
+                            .string_len(20)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventCancellations::OrdersRefunded)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(EventCancellations::HoldersNotified)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(EventCancellations::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(EventCancellations::LastError).text().null())
+                    .col(
+                        ColumnDef::new(EventCancellations::NextAttemptAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventCancellations::StartedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventCancellations::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventCancellations::CompletedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_event_cancellations_event_id")
+                            .from(EventCancellations::Table, EventCancellations::EventId)
+                            .to(Events::Table, Events::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_event_cancellations_next_attempt_at")
+                    .table(EventCancellations::Table)
+                    .col(EventCancellations::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EventCancellations::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Events {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum EventCancellations {
+    Table,
+    EventId,
+    Reason,
+    RequestedBy,
+    Step,
+    OrdersRefunded,
+    HoldersNotified,
+    Attempts,
+    LastError,
+    NextAttemptAt,
+    StartedAt,
+    UpdatedAt,
+    CompletedAt,
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Section of the venue's seating chart a ticket type sells seats in;
+        // null for general admission
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TicketTypes::Table)
+                    .add_column(ColumnDef::new(TicketTypes::SectionId).uuid().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TicketTypes::Table)
+                    .drop_column(TicketTypes::SectionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum TicketTypes {
+    Table,
+    SectionId,
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Owner of the imported events, the only one who may read the job;
+        // null for command-line imports without an organizer
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ImportJobs::Table)
+                    .add_column(ColumnDef::new(ImportJobs::OrganizerId).uuid().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ImportJobs::Table)
+                    .drop_column(ImportJobs::OrganizerId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum ImportJobs {
+    Table,
+    OrganizerId,
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Identifies the runner holding a cancellation, so one whose lease
+        // lapsed can't overwrite the progress of the runner that took over
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(EventCancellations::Table)
+                    .add_column(ColumnDef::new(EventCancellations::ClaimToken).uuid().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(EventCancellations::Table)
+                    .drop_column(EventCancellations::ClaimToken)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum EventCancellations {
+    Table,
+    ClaimToken,
+}
@@ -0,0 +1,45 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Who may change the venue's seating chart: the organization's owners
+        // and managers, or the organizer for venues outside one. Venues
+        // created before this have neither
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Venues::Table)
+                    .add_column(ColumnDef::new(Venues::OrganizerId).uuid().null())
+                    .add_column(ColumnDef::new(Venues::OrganizationId).uuid().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Venues::Table)
+                    .drop_column(Venues::OrganizerId)
+                    .drop_column(Venues::OrganizationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Venues {
+    Table,
+    OrganizerId,
+    OrganizationId,
+}
@@ -0,0 +1,85 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Jobs still open from before this ran in tasks that died with the
+        // process, and their entries were never stored
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE import_jobs \
+                 SET status = 'failed', error = 'Interrupted by a restart', completed_at = NOW() \
+                 WHERE status IN ('pending', 'running')",
+            )
+            .await?;
+
+        // The entries still to import, so any replica can pick a job up,
+        // and the runner holding it until `claimed_until`
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ImportJobs::Table)
+                    .add_column(
+                        ColumnDef::new(ImportJobs::Entries)
+                            .json_binary()
+                            .not_null()
+                            .extra("DEFAULT '[]'::jsonb"),
+                    )
+                    .add_column(ColumnDef::new(ImportJobs::ClaimToken).uuid().null())
+                    .add_column(
+                        ColumnDef::new(ImportJobs::ClaimedUntil)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Let runners find open jobs without scanning finished ones
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE INDEX IF NOT EXISTS idx_import_jobs_open \
+                 ON import_jobs (created_at) WHERE completed_at IS NULL",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .if_exists()
+                    .name("idx_import_jobs_open")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ImportJobs::Table)
+                    .drop_column(ImportJobs::Entries)
+                    .drop_column(ImportJobs::ClaimToken)
+                    .drop_column(ImportJobs::ClaimedUntil)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum ImportJobs {
+    Table,
+    Entries,
+    ClaimToken,
+    ClaimedUntil,
+}
@@ -0,0 +1,45 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Who may change the series and its occurrences: the organization's
+        // owners and managers, or the organizer for series outside one.
+        // Series created before this have neither
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(EventSeries::Table)
+                    .add_column(ColumnDef::new(EventSeries::OrganizerId).uuid().null())
+                    .add_column(ColumnDef::new(EventSeries::OrganizationId).uuid().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(EventSeries::Table)
+                    .drop_column(EventSeries::OrganizerId)
+                    .drop_column(EventSeries::OrganizationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum EventSeries {
+    Table,
+    OrganizerId,
+    OrganizationId,
+}
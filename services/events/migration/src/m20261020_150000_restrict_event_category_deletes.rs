@@ -0,0 +1,54 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Deleting a category used to silently unfile every event under it;
+        // it now has to be taken off its events first
+        replace_category_foreign_key(manager, ForeignKeyAction::Restrict).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        replace_category_foreign_key(manager, ForeignKeyAction::Cascade).await
+    }
+}
+
+async fn replace_category_foreign_key(
+    manager: &SchemaManager<'_>,
+    on_delete: ForeignKeyAction,
+) -> Result<(), DbErr> {
+    manager
+        .drop_foreign_key(
+            ForeignKey::drop()
+                .name("fk_event_categories_category_id")
+                .table(EventCategories::Table)
+                .to_owned(),
+        )
+        .await?;
+
+    manager
+        .create_foreign_key(
+            ForeignKey::create()
+                .name("fk_event_categories_category_id")
+                .from(EventCategories::Table, EventCategories::CategoryId)
+                .to(Categories::Table, Categories::Id)
+                .on_delete(on_delete)
+                .to_owned(),
+        )
+        .await
+}
+
+#[derive(DeriveIden)]
+enum EventCategories {
+    Table,
+    CategoryId,
+}
+
+#[derive(DeriveIden)]
+enum Categories {
+    Table,
+    Id,
+}
@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Existing rows stay NULL and are rendered by the service on load
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(EventSeries::Table)
+                    .add_column(ColumnDef::new(EventSeries::DescriptionHtml).text().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(EventSeriesOverrides::Table)
+                    .add_column(
+                        ColumnDef::new(EventSeriesOverrides::DescriptionHtml)
+                            .text()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(EventSeriesOverrides::Table)
+                    .drop_column(EventSeriesOverrides::DescriptionHtml)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(EventSeries::Table)
+                    .drop_column(EventSeries::DescriptionHtml)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum EventSeries {
+    Table,
+    DescriptionHtml,
+}
+
+#[derive(DeriveIden)]
+enum EventSeriesOverrides {
+    Table,
+    DescriptionHtml,
+}
@@ -0,0 +1,124 @@
+use shared::UseCase;
+use std::path::Path;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{ImportJob, ImportRowOutcome},
+    use_cases::ImportEventsParams,
+    ImportEventsUseCase,
+};
+
+use super::super::import::{parse_entries, ImportFormat};
+
+const USAGE: &str = "usage: events import <file> [--format ics|csv] [--organizer <user-id>]";
+
+fn print_report(job: &ImportJob) {
+    for result in job.results() {
+        let uid = result.uid.as_deref().unwrap_or("-");
+        match &result.outcome {
+            ImportRowOutcome::Created { event_id } => {
+                println!("line {:>5}  created    {}  {}", result.line, uid, event_id)
+            }
+            ImportRowOutcome::Duplicate { event_id } => {
+                println!("line {:>5}  duplicate  {}  {}", result.line, uid, event_id)
+            }
+            ImportRowOutcome::Invalid { error } => {
+                println!("line {:>5}  invalid    {}  {}", result.line, uid, error)
+            }
+            ImportRowOutcome::Failed { error } => {
+                println!("line {:>5}  failed     {}  {}", result.line, uid, error)
+            }
+        }
+    }
+    println!(
+        "Import {}: {} created, {} duplicate, {} invalid, {} failed",
+        job.id(),
+        job.created_count(),
+        job.duplicate_count(),
+        job.invalid_count(),
+        job.failed_count()
+    );
+}
+
+/// Runs `events import`, returning the process exit code: 0 when every entry
+/// was created or skipped as a duplicate, 1 when some entries were rejected,
+/// 2 when the file could not be imported at all.
+pub async fn run(use_case: &ImportEventsUseCase, args: &[String]) -> i32 {
+    let mut path = None;
+    let mut format = None;
+    let mut organizer_id = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next().and_then(|name| ImportFormat::from_name(name)) {
+                Some(parsed) => format = Some(parsed),
+                None => {
+                    eprintln!("❌ --format must be ics or csv");
+                    return 2;
+                }
+            },
+            "--organizer" => match args.next().and_then(|id| Uuid::parse_str(id).ok()) {
+                Some(id) => organizer_id = Some(id),
+                None => {
+                    eprintln!("❌ --organizer must be a user ID");
+                    return 2;
+                }
+            },
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(Path::new(arg)),
+            _ => {
+                eprintln!("{}", USAGE);
+                return 2;
+            }
+        }
+    }
+    let Some(path) = path else {
+        eprintln!("{}", USAGE);
+        return 2;
+    };
+
+    let Some(format) = format.or_else(|| ImportFormat::from_path(path)) else {
+        eprintln!("❌ Cannot tell the file format; pass --format ics or --format csv");
+        return 2;
+    };
+
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            eprintln!("❌ Failed to read {}: {}", path.display(), e);
+            return 2;
+        }
+    };
+
+    let entries = match parse_entries(format, &bytes) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("❌ {}: {}", path.display(), e);
+            return 2;
+        }
+    };
+
+    let params = ImportEventsParams {
+        source: path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string()),
+        entries,
+        organizer_id,
+        run_in_background: false,
+    };
+
+    match use_case.execute(params).await {
+        Ok(job) => {
+            print_report(&job);
+            if job.invalid_count() + job.failed_count() > 0 {
+                1
+            } else {
+                0
+            }
+        }
+        Err(e) => {
+            eprintln!("❌ Import failed: {}", e);
+            2
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use shared::AuthenticatedUser;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::use_cases::CancelEventParams;
+
+const MAX_REASON_LENGTH: usize = 500;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CancelEventRequest {
+    /// Why the event is off; sent to every ticket holder.
+    #[schema(example = "The headliner is ill and the show can't go ahead")]
+    pub reason: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CancelEventRequestError {
+    #[error("A reason is required")]
+    ReasonEmpty,
+    #[error("Reason is too long (maximum 500 characters)")]
+    ReasonTooLong,
+}
+
+impl TryFrom<(Uuid, AuthenticatedUser, CancelEventRequest)> for CancelEventParams {
+    type Error = CancelEventRequestError;
+
+    fn try_from(
+        (id, user, req): (Uuid, AuthenticatedUser, CancelEventRequest),
+    ) -> Result<Self, Self::Error> {
+        let reason = req.reason.trim();
+        if reason.is_empty() {
+            return Err(CancelEventRequestError::ReasonEmpty);
+        }
+        if reason.chars().count() > MAX_REASON_LENGTH {
+            return Err(CancelEventRequestError::ReasonTooLong);
+        }
+
+        Ok(Self {
+            id,
+            reason: reason.to_string(),
+            requested_by: user.id(),
+        })
+    }
+}
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use shared::AuthenticatedUser;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::use_cases::{CreateCategoryParams, UpdateCategoryParams};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateCategoryRequest {
+    #[schema(example = "Jazz")]
+    pub name: String,
+    /// URL-friendly identifier (lowercase letters, digits and dashes);
+    /// derived from `name` when omitted.
+    #[schema(example = "jazz")]
+    pub slug: Option<String>,
+    /// Category to nest under; omit for a top-level category.
+    pub parent_id: Option<Uuid>,
+}
+
+/// Fields to change; omitted fields keep their current value.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateCategoryRequest {
+    #[schema(example = "Jazz & Blues")]
+    pub name: Option<String>,
+    #[schema(example = "jazz-blues")]
+    pub slug: Option<String>,
+    /// New parent; `null` moves the category to the top level.
+    #[serde(default, deserialize_with = "present")]
+    #[schema(value_type = Option<Uuid>)]
+    pub parent_id: Option<Option<Uuid>>,
+}
+
+/// Tells an explicit `null` (`Some(None)`) apart from a missing field (`None`).
+fn present<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CategoryRequestError {
+    #[error("Category name is required")]
+    NameEmpty,
+    #[error("Category name is too long (maximum 100 characters)")]
+    NameTooLong,
+    #[error("Slug must be 1-60 lowercase letters, digits or dashes")]
+    InvalidSlug,
+    #[error("At least one field must be provided")]
+    NoChanges,
+}
+
+const MAX_NAME_LENGTH: usize = 100;
+const MAX_SLUG_LENGTH: usize = 60;
+
+fn validate_name(name: &str) -> Result<String, CategoryRequestError> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        return Err(CategoryRequestError::NameEmpty);
+    }
+    if trimmed.chars().count() > MAX_NAME_LENGTH {
+        return Err(CategoryRequestError::NameTooLong);
+    }
+    Ok(trimmed.to_string())
+}
+
+fn validate_slug(slug: &str) -> Result<String, CategoryRequestError> {
+    let slug = slug.trim();
+    if slug.is_empty()
+        || slug.len() > MAX_SLUG_LENGTH
+        || slug.starts_with('-')
+        || slug.ends_with('-')
+        || !slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err(CategoryRequestError::InvalidSlug);
+    }
+    Ok(slug.to_string())
+}
+
+/// Lowercases `name` and joins its alphanumeric runs with dashes, e.g.
+/// "Jazz & Blues" becomes `jazz-blues`.
+fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for word in name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        if slug.len() + word.len() + 1 > MAX_SLUG_LENGTH {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word.to_ascii_lowercase());
+    }
+    slug
+}
+
+impl TryFrom<(AuthenticatedUser, CreateCategoryRequest)> for CreateCategoryParams {
+    type Error = CategoryRequestError;
+
+    fn try_from(
+        (user, req): (AuthenticatedUser, CreateCategoryRequest),
+    ) -> Result<Self, Self::Error> {
+        let name = validate_name(&req.name)?;
+        let slug = validate_slug(&req.slug.unwrap_or_else(|| slugify(&name)))?;
+
+        Ok(Self {
+            name,
+            slug,
+            parent_id: req.parent_id,
+            requested_by: user.id(),
+        })
+    }
+}
+
+impl TryFrom<(Uuid, AuthenticatedUser, UpdateCategoryRequest)> for UpdateCategoryParams {
+    type Error = CategoryRequestError;
+
+    fn try_from(
+        (id, user, req): (Uuid, AuthenticatedUser, UpdateCategoryRequest),
+    ) -> Result<Self, Self::Error> {
+        if req.name.is_none() && req.slug.is_none() && req.parent_id.is_none() {
+            return Err(CategoryRequestError::NoChanges);
+        }
+
+        Ok(Self {
+            id,
+            name: req.name.as_deref().map(validate_name).transpose()?,
+            slug: req.slug.as_deref().map(validate_slug).transpose()?,
+            parent_id: req.parent_id,
+            requested_by: user.id(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Jazz & Blues"), "jazz-blues");
+        assert_eq!(slugify("  Stand-up Comedy! "), "stand-up-comedy");
+        assert!(validate_slug(&slugify("Jazz & Blues")).is_ok());
+        assert!(validate_slug("Jazz").is_err());
+    }
+}
@@ -1,19 +1,63 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use uuid::Uuid;
 
-use crate::domain::use_cases::CreateEventParams;
+use crate::domain::{
+    entities::{Locale, LocaleError, RichText, ScheduleEnd, ScheduleTime},
+    use_cases::CreateEventParams,
+};
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateEventRequest {
     #[schema(example = "Summer Music Festival 2024")]
     pub title: String,
-    #[schema(example = "Join us for an amazing outdoor music festival featuring top artists")]
+    /// Markdown; HTML is accepted but sanitized.
+    #[schema(example = "Join us for an **amazing** outdoor music festival featuring top artists")]
     pub description: String,
+    /// Start as an absolute time. Give either this or `starts_at`.
     #[schema(example = "2024-07-15T18:00:00Z")]
-    pub event_date: DateTime<Utc>,
+    pub event_date: Option<DateTime<Utc>>,
+    /// Start as wall-clock time in `timezone`.
+    #[schema(example = "2024-07-15T19:00:00")]
+    pub starts_at: Option<NaiveDateTime>,
+    /// IANA timezone the event is held in; defaults to the venue's, or UTC.
+    #[schema(example = "America/Chicago")]
+    pub timezone: Option<String>,
+    /// Wall-clock end in `timezone`. Give either this or `duration_minutes`.
+    #[schema(example = "2024-07-15T23:00:00")]
+    pub ends_at: Option<NaiveDateTime>,
+    #[schema(example = 240)]
+    pub duration_minutes: Option<u32>,
+    /// Wall-clock time doors open, in `timezone`.
+    #[schema(example = "2024-07-15T18:00:00")]
+    pub doors_open_at: Option<NaiveDateTime>,
     #[schema(example = "Central Park Amphitheater")]
     pub venue_name: String,
+    /// Venue with a seating chart; omit for general-admission events.
+    #[schema(example = "6f1c5430-2b1e-4b7a-9d7e-2f0c1a9b8e11")]
+    pub venue_id: Option<Uuid>,
+    /// Create the event for an organization the caller owns or manages, so
+    /// its other owners and managers can change it too.
+    pub organization_id: Option<Uuid>,
+    /// Categories to file the event under.
+    #[serde(default)]
+    pub category_ids: Vec<Uuid>,
+    /// Free-form labels; matched case-insensitively.
+    #[serde(default)]
+    #[schema(example = json!(["outdoor", "family-friendly"]))]
+    pub tags: Vec<String>,
+    /// Keep the event hidden until this time; omit to publish right away.
+    #[schema(example = "2024-05-01T09:00:00Z")]
+    pub publish_at: Option<DateTime<Utc>>,
+    /// Open ticket sales at this time; omit to open them on publication.
+    #[schema(example = "2024-05-03T10:00:00Z")]
+    pub on_sale_at: Option<DateTime<Utc>>,
+    /// Language `title` and `description` are written in; defaults to `en`.
+    /// Other languages are added as translations.
+    #[schema(example = "en")]
+    pub default_locale: Option<String>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -24,15 +68,73 @@ pub enum CreateEventRequestError {
     TitleTooLong,
     #[error("Description is too long (maximum 2000 characters)")]
     DescriptionTooLong,
-    #[error("Event date must be in the future")]
-    EventDateInPast,
     #[error("Venue name is required")]
     VenueNameEmpty,
     #[error("Venue name is too long (maximum 100 characters)")]
     VenueNameTooLong,
+    #[error("Either event_date or starts_at is required")]
+    StartMissing,
+    #[error("Give either event_date or starts_at, not both")]
+    StartGivenTwice,
+    #[error("Give either ends_at or duration_minutes, not both")]
+    EndGivenTwice,
+    #[error("Duration must be between 1 minute and 30 days")]
+    DurationOutOfRange,
+    #[error("Tags must be between 1 and 40 characters")]
+    TagLength,
+    #[error("At most 20 tags are allowed")]
+    TooManyTags,
+    #[error(transparent)]
+    Timezone(#[from] UnknownTimezone),
+    #[error(transparent)]
+    Locale(#[from] LocaleError),
 }
 
-fn validate_title(title: &str) -> Result<String, CreateEventRequestError> {
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown timezone `{0}`; expected an IANA name such as America/Chicago")]
+pub struct UnknownTimezone(String);
+
+/// Longest event accepted through `duration_minutes`.
+const MAX_DURATION_MINUTES: u32 = 30 * 24 * 60;
+
+const MAX_TAGS: usize = 20;
+const MAX_TAG_LENGTH: usize = 40;
+
+pub(super) fn parse_timezone(name: &str) -> Result<Tz, UnknownTimezone> {
+    let trimmed = name.trim();
+    trimmed
+        .parse()
+        .map_err(|_| UnknownTimezone(trimmed.to_string()))
+}
+
+pub(super) fn validate_start(
+    event_date: Option<DateTime<Utc>>,
+    starts_at: Option<NaiveDateTime>,
+) -> Result<Option<ScheduleTime>, CreateEventRequestError> {
+    match (event_date, starts_at) {
+        (Some(_), Some(_)) => Err(CreateEventRequestError::StartGivenTwice),
+        (Some(instant), None) => Ok(Some(ScheduleTime::Instant(instant))),
+        (None, Some(local)) => Ok(Some(ScheduleTime::Local(local))),
+        (None, None) => Ok(None),
+    }
+}
+
+pub(super) fn validate_end(
+    ends_at: Option<NaiveDateTime>,
+    duration_minutes: Option<u32>,
+) -> Result<Option<ScheduleEnd>, CreateEventRequestError> {
+    match (ends_at, duration_minutes) {
+        (Some(_), Some(_)) => Err(CreateEventRequestError::EndGivenTwice),
+        (Some(local), None) => Ok(Some(ScheduleEnd::At(ScheduleTime::Local(local)))),
+        (None, Some(minutes)) if minutes == 0 || minutes > MAX_DURATION_MINUTES => {
+            Err(CreateEventRequestError::DurationOutOfRange)
+        }
+        (None, Some(minutes)) => Ok(Some(ScheduleEnd::After(Duration::minutes(minutes.into())))),
+        (None, None) => Ok(None),
+    }
+}
+
+pub(super) fn validate_title(title: &str) -> Result<String, CreateEventRequestError> {
     let trimmed = title.trim();
     if trimmed.is_empty() {
         return Err(CreateEventRequestError::TitleEmpty);
@@ -43,22 +145,16 @@ fn validate_title(title: &str) -> Result<String, CreateEventRequestError> {
     Ok(trimmed.to_string())
 }
 
-fn validate_description(description: &str) -> Result<String, CreateEventRequestError> {
+/// Renders the Markdown description; the length limit applies to the source.
+pub(super) fn validate_description(description: &str) -> Result<RichText, CreateEventRequestError> {
     let trimmed = description.trim();
     if trimmed.len() > 2000 {
         return Err(CreateEventRequestError::DescriptionTooLong);
     }
-    Ok(trimmed.to_string())
-}
-
-fn validate_event_date(event_date: &DateTime<Utc>) -> Result<(), CreateEventRequestError> {
-    if event_date < &Utc::now() {
-        return Err(CreateEventRequestError::EventDateInPast);
-    }
-    Ok(())
+    Ok(RichText::from_markdown(trimmed))
 }
 
-fn validate_venue_name(venue_name: &str) -> Result<String, CreateEventRequestError> {
+pub(super) fn validate_venue_name(venue_name: &str) -> Result<String, CreateEventRequestError> {
     let trimmed = venue_name.trim();
     if trimmed.is_empty() {
         return Err(CreateEventRequestError::VenueNameEmpty);
@@ -69,20 +165,97 @@ fn validate_venue_name(venue_name: &str) -> Result<String, CreateEventRequestErr
     Ok(trimmed.to_string())
 }
 
+/// Trims and lowercases tags and drops duplicates, keeping the first
+/// occurrence's position.
+pub(super) fn validate_tags(tags: Vec<String>) -> Result<Vec<String>, CreateEventRequestError> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() || tag.chars().count() > MAX_TAG_LENGTH {
+            return Err(CreateEventRequestError::TagLength);
+        }
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    if normalized.len() > MAX_TAGS {
+        return Err(CreateEventRequestError::TooManyTags);
+    }
+    Ok(normalized)
+}
+
+/// Drops repeated category ids.
+pub(super) fn dedup_category_ids(category_ids: Vec<Uuid>) -> Vec<Uuid> {
+    let mut distinct = Vec::with_capacity(category_ids.len());
+    for id in category_ids {
+        if !distinct.contains(&id) {
+            distinct.push(id);
+        }
+    }
+    distinct
+}
+
 impl TryFrom<CreateEventRequest> for CreateEventParams {
     type Error = CreateEventRequestError;
 
     fn try_from(req: CreateEventRequest) -> Result<Self, Self::Error> {
         let title = validate_title(&req.title)?;
         let description = validate_description(&req.description)?;
-        validate_event_date(&req.event_date)?;
+        let starts_at = validate_start(req.event_date, req.starts_at)?
+            .ok_or(CreateEventRequestError::StartMissing)?;
+        let timezone = req.timezone.as_deref().map(parse_timezone).transpose()?;
+        let end = validate_end(req.ends_at, req.duration_minutes)?;
         let venue_name = validate_venue_name(&req.venue_name)?;
+        let tags = validate_tags(req.tags)?;
+        let default_locale = req
+            .default_locale
+            .as_deref()
+            .map(Locale::parse)
+            .transpose()?
+            .unwrap_or_default();
 
         Ok(Self {
             title,
             description,
-            event_date: req.event_date,
+            starts_at,
+            timezone,
+            end,
+            doors_open_at: req.doors_open_at.map(ScheduleTime::Local),
             venue_name,
+            venue_id: req.venue_id,
+            category_ids: dedup_category_ids(req.category_ids),
+            tags,
+            publish_at: req.publish_at,
+            on_sale_at: req.on_sale_at,
+            default_locale,
+            organizer_id: None,
+            organization_id: req.organization_id,
+            external_uid: None,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_tags_normalizes_and_deduplicates() {
+        let tags = validate_tags(vec![
+            " Outdoor ".to_string(),
+            "jazz".to_string(),
+            "OUTDOOR".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(tags, vec!["outdoor", "jazz"]);
+
+        assert!(matches!(
+            validate_tags(vec!["  ".to_string()]),
+            Err(CreateEventRequestError::TagLength)
+        ));
+        assert!(matches!(
+            validate_tags((0..21).map(|i| format!("tag-{}", i)).collect()),
+            Err(CreateEventRequestError::TooManyTags)
+        ));
+    }
+}
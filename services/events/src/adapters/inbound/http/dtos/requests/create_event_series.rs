@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::AuthenticatedUser;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{entities::RichText, use_cases::CreateEventSeriesParams};
+
+use super::create_event::{parse_timezone, UnknownTimezone};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateEventSeriesRequest {
+    #[schema(example = "Thursday Comedy Night")]
+    pub title: String,
+    #[schema(example = "Stand-up showcase with rotating headliners")]
+    pub description: String,
+    #[schema(example = "The Laugh Cellar")]
+    pub venue_name: String,
+    #[schema(example = "6f1c5430-2b1e-4b7a-9d7e-2f0c1a9b8e11")]
+    pub venue_id: Option<Uuid>,
+    /// Start of the first occurrence; also the series' DTSTART.
+    #[schema(example = "2026-11-05T20:00:00Z")]
+    pub starts_at: DateTime<Utc>,
+    /// IANA timezone the rule repeats in, so occurrences keep their local
+    /// time across daylight saving changes; defaults to the venue's, or UTC.
+    #[schema(example = "America/Chicago")]
+    pub timezone: Option<String>,
+    /// RFC 5545 recurrence rule, with or without the `RRULE:` prefix.
+    #[schema(example = "FREQ=WEEKLY;BYDAY=TH;COUNT=20")]
+    pub rrule: String,
+    /// Occurrence starts to skip (EXDATE).
+    #[serde(default)]
+    #[schema(example = json!(["2026-12-24T20:00:00Z"]))]
+    pub exdates: Vec<DateTime<Utc>>,
+    /// Create the series for an organization the caller owns or manages, so
+    /// its other owners and managers can change it too.
+    pub organization_id: Option<Uuid>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateEventSeriesRequestError {
+    #[error("Title is required")]
+    TitleEmpty,
+    #[error("Title is too long (maximum 200 characters)")]
+    TitleTooLong,
+    #[error("Description is too long (maximum 2000 characters)")]
+    DescriptionTooLong,
+    #[error("Venue name is required")]
+    VenueNameEmpty,
+    #[error("Venue name is too long (maximum 100 characters)")]
+    VenueNameTooLong,
+    #[error("Recurrence rule is required")]
+    RruleEmpty,
+    #[error("Too many exception dates (maximum 500)")]
+    TooManyExdates,
+    #[error(transparent)]
+    Timezone(#[from] UnknownTimezone),
+}
+
+const MAX_EXDATES: usize = 500;
+
+impl TryFrom<(AuthenticatedUser, CreateEventSeriesRequest)> for CreateEventSeriesParams {
+    type Error = CreateEventSeriesRequestError;
+
+    fn try_from(
+        (user, req): (AuthenticatedUser, CreateEventSeriesRequest),
+    ) -> Result<Self, Self::Error> {
+        let title = req.title.trim();
+        if title.is_empty() {
+            return Err(CreateEventSeriesRequestError::TitleEmpty);
+        }
+        if title.len() > 200 {
+            return Err(CreateEventSeriesRequestError::TitleTooLong);
+        }
+        let description = req.description.trim();
+        if description.len() > 2000 {
+            return Err(CreateEventSeriesRequestError::DescriptionTooLong);
+        }
+        let venue_name = req.venue_name.trim();
+        if venue_name.is_empty() {
+            return Err(CreateEventSeriesRequestError::VenueNameEmpty);
+        }
+        if venue_name.len() > 100 {
+            return Err(CreateEventSeriesRequestError::VenueNameTooLong);
+        }
+        let rrule = req.rrule.trim();
+        if rrule.is_empty() {
+            return Err(CreateEventSeriesRequestError::RruleEmpty);
+        }
+        if req.exdates.len() > MAX_EXDATES {
+            return Err(CreateEventSeriesRequestError::TooManyExdates);
+        }
+        let timezone = req.timezone.as_deref().map(parse_timezone).transpose()?;
+
+        let mut exdates = req.exdates;
+        exdates.sort();
+        exdates.dedup();
+
+        Ok(Self {
+            title: title.to_string(),
+            description: RichText::from_markdown(description),
+            venue_name: venue_name.to_string(),
+            venue_id: req.venue_id,
+            starts_at: req.starts_at,
+            timezone,
+            rrule: rrule.to_string(),
+            exdates,
+            organization_id: req.organization_id,
+            requested_by: user.id(),
+        })
+    }
+}
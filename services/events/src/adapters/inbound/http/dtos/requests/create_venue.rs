@@ -0,0 +1,83 @@
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use shared::AuthenticatedUser;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{GeoPoint, GeoPointError},
+    use_cases::CreateVenueParams,
+};
+
+use super::create_event::{parse_timezone, UnknownTimezone};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateVenueRequest {
+    #[schema(example = "Central Park Amphitheater")]
+    pub name: String,
+    /// IANA timezone that events at the venue default to; UTC when omitted.
+    #[schema(example = "America/New_York")]
+    pub timezone: Option<String>,
+    /// Decimal degrees; give together with `longitude` so the venue shows up
+    /// in searches by distance.
+    #[schema(example = 40.7697)]
+    pub latitude: Option<f64>,
+    #[schema(example = -73.9735)]
+    pub longitude: Option<f64>,
+    /// Organization the venue belongs to, whose owners and managers can then
+    /// change it; the caller's own venue when omitted.
+    pub organization_id: Option<Uuid>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateVenueRequestError {
+    #[error("Venue name is required")]
+    NameEmpty,
+    #[error("Venue name is too long (maximum 100 characters)")]
+    NameTooLong,
+    #[error(transparent)]
+    Timezone(#[from] UnknownTimezone),
+    #[error("Give both latitude and longitude, or neither")]
+    PartialLocation,
+    #[error(transparent)]
+    Location(#[from] GeoPointError),
+}
+
+fn validate_name(name: &str) -> Result<String, CreateVenueRequestError> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        return Err(CreateVenueRequestError::NameEmpty);
+    }
+    if trimmed.len() > 100 {
+        return Err(CreateVenueRequestError::NameTooLong);
+    }
+    Ok(trimmed.to_string())
+}
+
+impl TryFrom<(AuthenticatedUser, CreateVenueRequest)> for CreateVenueParams {
+    type Error = CreateVenueRequestError;
+
+    fn try_from((user, req): (AuthenticatedUser, CreateVenueRequest)) -> Result<Self, Self::Error> {
+        let name = validate_name(&req.name)?;
+        let timezone = req
+            .timezone
+            .as_deref()
+            .map(parse_timezone)
+            .transpose()?
+            .unwrap_or(Tz::UTC);
+
+        let location = match (req.latitude, req.longitude) {
+            (Some(latitude), Some(longitude)) => Some(GeoPoint::new(latitude, longitude)?),
+            (None, None) => None,
+            _ => return Err(CreateVenueRequestError::PartialLocation),
+        };
+
+        Ok(Self {
+            name,
+            timezone,
+            location,
+            organization_id: req.organization_id,
+            requested_by: user.id(),
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::AuthenticatedUser;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::use_cases::{DuplicateEventParams, EventCopyOverrides};
+
+use super::create_event::{
+    parse_timezone, validate_start, validate_title, validate_venue_name, CreateEventRequestError,
+};
+
+/// Changes to make to the copy; anything omitted is copied from the event.
+/// A new `venue_id` also brings the venue's name and timezone unless those
+/// are given too.
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct DuplicateEventRequest {
+    #[schema(example = "Summer Music Festival 2025")]
+    pub title: Option<String>,
+    /// Start of the copy as an absolute time. Give at most one of this and
+    /// `starts_at`; omit both to keep the original start.
+    #[schema(example = "2025-07-15T18:00:00Z")]
+    pub event_date: Option<DateTime<Utc>>,
+    /// Start of the copy as wall-clock time in its timezone.
+    #[schema(example = "2025-07-15T19:00:00")]
+    pub starts_at: Option<NaiveDateTime>,
+    #[schema(example = "America/Chicago")]
+    pub timezone: Option<String>,
+    pub venue_name: Option<String>,
+    pub venue_id: Option<Uuid>,
+}
+
+pub(super) fn validate_overrides(
+    title: Option<&str>,
+    timezone: Option<&str>,
+    venue_name: Option<&str>,
+    venue_id: Option<Uuid>,
+) -> Result<EventCopyOverrides, CreateEventRequestError> {
+    Ok(EventCopyOverrides {
+        title: title.map(validate_title).transpose()?,
+        timezone: timezone.map(parse_timezone).transpose()?,
+        venue_name: venue_name.map(validate_venue_name).transpose()?,
+        venue_id,
+    })
+}
+
+impl TryFrom<(Uuid, AuthenticatedUser, DuplicateEventRequest)> for DuplicateEventParams {
+    type Error = CreateEventRequestError;
+
+    fn try_from(
+        (event_id, user, req): (Uuid, AuthenticatedUser, DuplicateEventRequest),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            event_id,
+            starts_at: validate_start(req.event_date, req.starts_at)?,
+            overrides: validate_overrides(
+                req.title.as_deref(),
+                req.timezone.as_deref(),
+                req.venue_name.as_deref(),
+                req.venue_id,
+            )?,
+            requested_by: user.id(),
+        })
+    }
+}
@@ -0,0 +1,76 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::domain::entities::{GeoPoint, GeoPointError, GeoRadius};
+
+/// Narrows a listing to a category, tags or an area.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventFilterQuery {
+    /// Only events in this category or one of its subcategories.
+    pub category_id: Option<Uuid>,
+    /// Comma-separated tags; only events carrying all of them.
+    #[param(example = "outdoor,free")]
+    pub tags: Option<String>,
+    /// `latitude,longitude` in decimal degrees; only events at venues within
+    /// `radius`, nearest first.
+    #[param(example = "41.8781,-87.6298")]
+    pub near: Option<String>,
+    /// Search radius in kilometres around `near` (default 25, maximum 500).
+    #[param(example = 20)]
+    pub radius: Option<f64>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum EventFilterError {
+    #[error("`near` must be `latitude,longitude` in decimal degrees")]
+    InvalidNear,
+    #[error(transparent)]
+    Location(#[from] GeoPointError),
+    #[error("`radius` requires `near`")]
+    RadiusWithoutNear,
+    #[error("`radius` must be greater than 0 and at most 500 km")]
+    InvalidRadius,
+}
+
+const DEFAULT_RADIUS_KM: f64 = 25.0;
+const MAX_RADIUS_KM: f64 = 500.0;
+
+impl EventFilterQuery {
+    /// The requested tags, normalized like tags on events.
+    pub fn tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = Vec::new();
+        for tag in self.tags.iter().flat_map(|tags| tags.split(',')) {
+            let tag = tag.trim().to_lowercase();
+            if !tag.is_empty() && !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        tags
+    }
+
+    /// The area given by `near` and `radius`, if any.
+    pub fn near(&self) -> Result<Option<GeoRadius>, EventFilterError> {
+        let Some(near) = &self.near else {
+            return match self.radius {
+                Some(_) => Err(EventFilterError::RadiusWithoutNear),
+                None => Ok(None),
+            };
+        };
+
+        let (latitude, longitude) = near
+            .split_once(',')
+            .and_then(|(lat, lng)| Some((lat.trim().parse().ok()?, lng.trim().parse().ok()?)))
+            .ok_or(EventFilterError::InvalidNear)?;
+        let radius_km = self.radius.unwrap_or(DEFAULT_RADIUS_KM);
+        if !(radius_km > 0.0 && radius_km <= MAX_RADIUS_KM) {
+            return Err(EventFilterError::InvalidRadius);
+        }
+
+        Ok(Some(GeoRadius {
+            center: GeoPoint::new(latitude, longitude)?,
+            radius_km,
+        }))
+    }
+}
@@ -0,0 +1,67 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::{entities::ScheduleTime, use_cases::EventSessionParams};
+
+/// Body for creating or replacing a session. Times are wall-clock times in
+/// the event's timezone.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventSessionRequest {
+    #[schema(example = "Headliner: The Midnight")]
+    pub title: String,
+    #[schema(example = "Main Stage")]
+    pub stage: Option<String>,
+    #[schema(example = "2024-07-20T21:00:00")]
+    pub starts_at: NaiveDateTime,
+    #[schema(example = "2024-07-20T22:30:00")]
+    pub ends_at: NaiveDateTime,
+    /// Caps the tickets sold for this session alone.
+    #[schema(example = 5000)]
+    pub capacity: Option<i32>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum EventSessionRequestError {
+    #[error("Session title is required")]
+    TitleEmpty,
+    #[error("Session title is too long (maximum 200 characters)")]
+    TitleTooLong,
+    #[error("Stage name is too long (maximum 100 characters)")]
+    StageTooLong,
+    #[error("Capacity cannot be negative")]
+    NegativeCapacity,
+}
+
+impl TryFrom<EventSessionRequest> for EventSessionParams {
+    type Error = EventSessionRequestError;
+
+    fn try_from(req: EventSessionRequest) -> Result<Self, Self::Error> {
+        let title = req.title.trim();
+        if title.is_empty() {
+            return Err(EventSessionRequestError::TitleEmpty);
+        }
+        if title.len() > 200 {
+            return Err(EventSessionRequestError::TitleTooLong);
+        }
+        let stage = req
+            .stage
+            .as_deref()
+            .map(str::trim)
+            .filter(|stage| !stage.is_empty());
+        if stage.is_some_and(|stage| stage.len() > 100) {
+            return Err(EventSessionRequestError::StageTooLong);
+        }
+        if req.capacity.is_some_and(|capacity| capacity < 0) {
+            return Err(EventSessionRequestError::NegativeCapacity);
+        }
+
+        Ok(Self {
+            title: title.to_string(),
+            stage: stage.map(str::to_string),
+            starts_at: ScheduleTime::Local(req.starts_at),
+            ends_at: ScheduleTime::Local(req.ends_at),
+            capacity: req.capacity,
+        })
+    }
+}
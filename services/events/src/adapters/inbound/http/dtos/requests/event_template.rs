@@ -0,0 +1,97 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::AuthenticatedUser;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::domain::use_cases::{CreateEventTemplateParams, InstantiateEventTemplateParams};
+
+use super::create_event::{validate_start, CreateEventRequestError};
+use super::duplicate_event::validate_overrides;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateEventTemplateRequest {
+    /// Event whose current setup, ticket types and media are saved.
+    pub event_id: Uuid,
+    #[schema(example = "Summer tour stop")]
+    pub name: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateEventTemplateRequestError {
+    #[error("Template name is required")]
+    NameEmpty,
+    #[error("Template name is too long (maximum 100 characters)")]
+    NameTooLong,
+}
+
+impl TryFrom<(AuthenticatedUser, CreateEventTemplateRequest)> for CreateEventTemplateParams {
+    type Error = CreateEventTemplateRequestError;
+
+    fn try_from(
+        (user, req): (AuthenticatedUser, CreateEventTemplateRequest),
+    ) -> Result<Self, Self::Error> {
+        let name = req.name.trim();
+        if name.is_empty() {
+            return Err(CreateEventTemplateRequestError::NameEmpty);
+        }
+        if name.len() > 100 {
+            return Err(CreateEventTemplateRequestError::NameTooLong);
+        }
+
+        Ok(Self {
+            event_id: req.event_id,
+            name: name.to_string(),
+            requested_by: user.id(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventTemplatesQuery {
+    /// List the organization's templates instead of the caller's own.
+    pub organization_id: Option<Uuid>,
+}
+
+/// When the new event starts, and changes to make to the template's setup.
+/// A new `venue_id` also brings the venue's name and timezone unless those
+/// are given too.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct InstantiateEventTemplateRequest {
+    #[schema(example = "Summer Tour: Chicago")]
+    pub title: Option<String>,
+    /// Start as an absolute time. Give either this or `starts_at`.
+    #[schema(example = "2025-07-15T18:00:00Z")]
+    pub event_date: Option<DateTime<Utc>>,
+    /// Start as wall-clock time in the event's timezone.
+    #[schema(example = "2025-07-15T19:00:00")]
+    pub starts_at: Option<NaiveDateTime>,
+    #[schema(example = "America/Chicago")]
+    pub timezone: Option<String>,
+    pub venue_name: Option<String>,
+    pub venue_id: Option<Uuid>,
+}
+
+impl TryFrom<(Uuid, AuthenticatedUser, InstantiateEventTemplateRequest)>
+    for InstantiateEventTemplateParams
+{
+    type Error = CreateEventRequestError;
+
+    fn try_from(
+        (template_id, user, req): (Uuid, AuthenticatedUser, InstantiateEventTemplateRequest),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            template_id,
+            starts_at: validate_start(req.event_date, req.starts_at)?
+                .ok_or(CreateEventRequestError::StartMissing)?,
+            overrides: validate_overrides(
+                req.title.as_deref(),
+                req.timezone.as_deref(),
+                req.venue_name.as_deref(),
+                req.venue_id,
+            )?,
+            requested_by: user.id(),
+        })
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use shared::AuthenticatedUser;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{Locale, LocaleError},
+    use_cases::SaveEventTranslationParams,
+};
+
+use super::create_event::{validate_description, validate_title, CreateEventRequestError};
+
+/// The event's title and description in the locale named in the path.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventTranslationRequest {
+    #[schema(example = "Sommer-Musikfestival 2024")]
+    pub title: String,
+    /// Markdown; HTML is accepted but sanitized.
+    #[schema(example = "Erlebe ein **großartiges** Open-Air-Musikfestival")]
+    pub description: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum EventTranslationRequestError {
+    #[error(transparent)]
+    Locale(#[from] LocaleError),
+    #[error(transparent)]
+    Invalid(#[from] CreateEventRequestError),
+}
+
+impl TryFrom<(Uuid, String, AuthenticatedUser, EventTranslationRequest)>
+    for SaveEventTranslationParams
+{
+    type Error = EventTranslationRequestError;
+
+    fn try_from(
+        (event_id, locale, user, req): (Uuid, String, AuthenticatedUser, EventTranslationRequest),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            event_id,
+            locale: Locale::parse(&locale)?,
+            title: validate_title(&req.title)?,
+            description: validate_description(&req.description)?,
+            requested_by: user.id(),
+        })
+    }
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ImportEventsQuery {
+    /// `ics` or `csv`; defaults to the request's Content-Type.
+    pub format: Option<String>,
+    /// Label stored on the import job, e.g. the original file name.
+    pub source: Option<String>,
+}
@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use shared::AuthenticatedUser;
+use std::collections::HashSet;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::use_cases::{ImportSeatingChartParams, RowLayout, SeatLayout, SectionLayout};
+
+/// JSON seating layout format: sections contain rows, rows contain seats.
+/// Seat coordinates are in an arbitrary unit space; the SVG seat map scales
+/// them to fit.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportSeatingChartRequest {
+    pub sections: Vec<SectionLayoutRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SectionLayoutRequest {
+    #[schema(example = "Orchestra")]
+    pub name: String,
+    pub rows: Vec<RowLayoutRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RowLayoutRequest {
+    #[schema(example = "A")]
+    pub label: String,
+    pub seats: Vec<SeatLayoutRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeatLayoutRequest {
+    #[schema(example = "12")]
+    pub label: String,
+    #[schema(example = 120.0)]
+    pub x: f64,
+    #[schema(example = 40.0)]
+    pub y: f64,
+    #[serde(default)]
+    pub accessible: bool,
+    #[serde(default)]
+    pub obstructed: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportSeatingChartRequestError {
+    #[error("Seating chart must contain at least one seat")]
+    NoSeats,
+    #[error("Section name is required")]
+    SectionNameEmpty,
+    #[error("Section name is too long (maximum 100 characters)")]
+    SectionNameTooLong,
+    #[error("Duplicate section name: {0}")]
+    DuplicateSection(String),
+    #[error("Row label is required in section {0}")]
+    RowLabelEmpty(String),
+    #[error("Row label is too long (maximum 20 characters): {0}")]
+    RowLabelTooLong(String),
+    #[error("Duplicate row {1} in section {0}")]
+    DuplicateRow(String, String),
+    #[error("Seat label is required in section {0}, row {1}")]
+    SeatLabelEmpty(String, String),
+    #[error("Seat label is too long (maximum 20 characters): {0}")]
+    SeatLabelTooLong(String),
+    #[error("Duplicate seat {2} in section {0}, row {1}")]
+    DuplicateSeat(String, String, String),
+    #[error("Seat coordinates must be finite, non-negative numbers")]
+    InvalidCoordinates,
+}
+
+fn validate_label(
+    label: &str,
+    max_len: usize,
+    empty: impl FnOnce() -> ImportSeatingChartRequestError,
+    too_long: impl FnOnce(String) -> ImportSeatingChartRequestError,
+) -> Result<String, ImportSeatingChartRequestError> {
+    let trimmed = label.trim();
+    if trimmed.is_empty() {
+        return Err(empty());
+    }
+    if trimmed.len() > max_len {
+        return Err(too_long(trimmed.to_string()));
+    }
+    Ok(trimmed.to_string())
+}
+
+fn validate_coordinate(value: f64) -> Result<f64, ImportSeatingChartRequestError> {
+    if !value.is_finite() || value < 0.0 {
+        return Err(ImportSeatingChartRequestError::InvalidCoordinates);
+    }
+    Ok(value)
+}
+
+fn validate_row(
+    section: &str,
+    row: RowLayoutRequest,
+) -> Result<RowLayout, ImportSeatingChartRequestError> {
+    let label = validate_label(
+        &row.label,
+        20,
+        || ImportSeatingChartRequestError::RowLabelEmpty(section.to_string()),
+        ImportSeatingChartRequestError::RowLabelTooLong,
+    )?;
+
+    let mut seen = HashSet::new();
+    let mut seats = Vec::with_capacity(row.seats.len());
+    for seat in row.seats {
+        let seat_label = validate_label(
+            &seat.label,
+            20,
+            || ImportSeatingChartRequestError::SeatLabelEmpty(section.to_string(), label.clone()),
+            ImportSeatingChartRequestError::SeatLabelTooLong,
+        )?;
+        if !seen.insert(seat_label.clone()) {
+            return Err(ImportSeatingChartRequestError::DuplicateSeat(
+                section.to_string(),
+                label,
+                seat_label,
+            ));
+        }
+        seats.push(SeatLayout {
+            label: seat_label,
+            x: validate_coordinate(seat.x)?,
+            y: validate_coordinate(seat.y)?,
+            accessible: seat.accessible,
+            obstructed: seat.obstructed,
+        });
+    }
+
+    Ok(RowLayout { label, seats })
+}
+
+fn validate_section(
+    section: SectionLayoutRequest,
+) -> Result<SectionLayout, ImportSeatingChartRequestError> {
+    let name = validate_label(
+        &section.name,
+        100,
+        || ImportSeatingChartRequestError::SectionNameEmpty,
+        |_| ImportSeatingChartRequestError::SectionNameTooLong,
+    )?;
+
+    let mut seen = HashSet::new();
+    let mut rows = Vec::with_capacity(section.rows.len());
+    for row in section.rows {
+        let row = validate_row(&name, row)?;
+        if !seen.insert(row.label.clone()) {
+            return Err(ImportSeatingChartRequestError::DuplicateRow(
+                name, row.label,
+            ));
+        }
+        rows.push(row);
+    }
+
+    Ok(SectionLayout { name, rows })
+}
+
+impl TryFrom<(Uuid, AuthenticatedUser, ImportSeatingChartRequest)> for ImportSeatingChartParams {
+    type Error = ImportSeatingChartRequestError;
+
+    fn try_from(
+        (venue_id, user, req): (Uuid, AuthenticatedUser, ImportSeatingChartRequest),
+    ) -> Result<Self, Self::Error> {
+        let mut seen = HashSet::new();
+        let mut sections = Vec::with_capacity(req.sections.len());
+        for section in req.sections {
+            let section = validate_section(section)?;
+            if !seen.insert(section.name.clone()) {
+                return Err(ImportSeatingChartRequestError::DuplicateSection(
+                    section.name,
+                ));
+            }
+            sections.push(section);
+        }
+
+        let has_seats = sections
+            .iter()
+            .flat_map(|section| section.rows.iter())
+            .any(|row| !row.seats.is_empty());
+        if !has_seats {
+            return Err(ImportSeatingChartRequestError::NoSeats);
+        }
+
+        Ok(Self {
+            venue_id,
+            sections,
+            requested_by: user.id(),
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// Time window and page size shared by the listing endpoints.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListingWindowQuery {
+    /// Inclusive lower bound; defaults to now.
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive upper bound; defaults to 90 days after `from`.
+    pub to: Option<DateTime<Utc>>,
+    /// Maximum number of results (1-200, default 50).
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ListingWindowError {
+    #[error("`to` must be after `from`")]
+    EmptyWindow,
+    #[error("The window cannot span more than 366 days")]
+    WindowTooLarge,
+    #[error("`limit` must be between 1 and 200")]
+    InvalidLimit,
+}
+
+const DEFAULT_WINDOW_DAYS: i64 = 90;
+const MAX_WINDOW_DAYS: i64 = 366;
+const DEFAULT_LIMIT: usize = 50;
+const MAX_LIMIT: usize = 200;
+
+/// A validated `[from, to)` window and limit.
+pub struct ListingWindow {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub limit: usize,
+}
+
+impl TryFrom<ListingWindowQuery> for ListingWindow {
+    type Error = ListingWindowError;
+
+    fn try_from(query: ListingWindowQuery) -> Result<Self, Self::Error> {
+        let from = query.from.unwrap_or_else(Utc::now);
+        let to = query
+            .to
+            .unwrap_or_else(|| from + Duration::days(DEFAULT_WINDOW_DAYS));
+        if to <= from {
+            return Err(ListingWindowError::EmptyWindow);
+        }
+        if to - from > Duration::days(MAX_WINDOW_DAYS) {
+            return Err(ListingWindowError::WindowTooLarge);
+        }
+        let limit = query.limit.unwrap_or(DEFAULT_LIMIT);
+        if limit == 0 || limit > MAX_LIMIT {
+            return Err(ListingWindowError::InvalidLimit);
+        }
+
+        Ok(Self { from, to, limit })
+    }
+}
@@ -1,3 +1,39 @@
+pub mod cancel_event;
+pub mod category;
 pub mod create_event;
+pub mod create_event_series;
+pub mod create_venue;
+pub mod duplicate_event;
+pub mod event_filter;
+pub mod event_session;
+pub mod event_template;
+pub mod event_translation;
+pub mod import_events;
+pub mod import_seating_chart;
+pub mod listing_window;
+pub mod override_occurrence;
+pub mod ticket_type;
+pub mod update_event;
+pub mod upload_media;
 
+pub use cancel_event::CancelEventRequest;
+pub use category::{CreateCategoryRequest, UpdateCategoryRequest};
 pub use create_event::CreateEventRequest;
+pub use create_event_series::CreateEventSeriesRequest;
+pub use create_venue::CreateVenueRequest;
+pub use duplicate_event::DuplicateEventRequest;
+pub use event_filter::EventFilterQuery;
+pub use event_session::EventSessionRequest;
+pub use event_template::{
+    CreateEventTemplateRequest, EventTemplatesQuery, InstantiateEventTemplateRequest,
+};
+pub use event_translation::EventTranslationRequest;
+pub use import_events::ImportEventsQuery;
+pub use import_seating_chart::{
+    ImportSeatingChartRequest, RowLayoutRequest, SeatLayoutRequest, SectionLayoutRequest,
+};
+pub use listing_window::{ListingWindow, ListingWindowQuery};
+pub use override_occurrence::OverrideOccurrenceRequest;
+pub use ticket_type::{AdmissionKind, TicketTypeRequest, TicketVisibility};
+pub use update_event::UpdateEventRequest;
+pub use upload_media::{MediaPlacement, UploadMediaQuery};
@@ -0,0 +1,97 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::AuthenticatedUser;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{entities::RichText, use_cases::OverrideOccurrenceParams};
+
+/// Fields to change for a single occurrence; omitted fields fall back to the
+/// series.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OverrideOccurrenceRequest {
+    #[schema(example = "Thursday Comedy Night: Holiday Special")]
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// Moves this occurrence; it is still addressed by its original start.
+    #[schema(example = "2026-11-19T21:00:00Z")]
+    pub starts_at: Option<DateTime<Utc>>,
+    #[schema(example = "Riverside Hall")]
+    pub venue_name: Option<String>,
+    pub venue_id: Option<Uuid>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum OverrideOccurrenceRequestError {
+    #[error("An override must change at least one field")]
+    Empty,
+    #[error("Title cannot be blank")]
+    TitleEmpty,
+    #[error("Title is too long (maximum 200 characters)")]
+    TitleTooLong,
+    #[error("Description is too long (maximum 2000 characters)")]
+    DescriptionTooLong,
+    #[error("Venue name cannot be blank")]
+    VenueNameEmpty,
+    #[error("Venue name is too long (maximum 100 characters)")]
+    VenueNameTooLong,
+}
+
+impl
+    TryFrom<(
+        Uuid,
+        DateTime<Utc>,
+        AuthenticatedUser,
+        OverrideOccurrenceRequest,
+    )> for OverrideOccurrenceParams
+{
+    type Error = OverrideOccurrenceRequestError;
+
+    fn try_from(
+        (series_id, occurrence_start, user, req): (
+            Uuid,
+            DateTime<Utc>,
+            AuthenticatedUser,
+            OverrideOccurrenceRequest,
+        ),
+    ) -> Result<Self, Self::Error> {
+        if req.title.is_none()
+            && req.description.is_none()
+            && req.starts_at.is_none()
+            && req.venue_name.is_none()
+            && req.venue_id.is_none()
+        {
+            return Err(OverrideOccurrenceRequestError::Empty);
+        }
+
+        let title = req.title.map(|t| t.trim().to_string());
+        match title.as_deref() {
+            Some("") => return Err(OverrideOccurrenceRequestError::TitleEmpty),
+            Some(t) if t.len() > 200 => return Err(OverrideOccurrenceRequestError::TitleTooLong),
+            _ => {}
+        }
+        let description = req.description.map(|d| d.trim().to_string());
+        if description.as_ref().is_some_and(|d| d.len() > 2000) {
+            return Err(OverrideOccurrenceRequestError::DescriptionTooLong);
+        }
+        let venue_name = req.venue_name.map(|v| v.trim().to_string());
+        match venue_name.as_deref() {
+            Some("") => return Err(OverrideOccurrenceRequestError::VenueNameEmpty),
+            Some(v) if v.len() > 100 => {
+                return Err(OverrideOccurrenceRequestError::VenueNameTooLong)
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            series_id,
+            occurrence_start,
+            title,
+            description: description.as_deref().map(RichText::from_markdown),
+            starts_at: req.starts_at,
+            venue_name,
+            venue_id: req.venue_id,
+            requested_by: user.id(),
+        })
+    }
+}
@@ -0,0 +1,292 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{Admission, TicketTypeVisibility},
+    use_cases::TicketTypeParams,
+};
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TicketVisibility {
+    #[default]
+    Public,
+    Hidden,
+}
+
+impl From<TicketVisibility> for TicketTypeVisibility {
+    fn from(visibility: TicketVisibility) -> Self {
+        match visibility {
+            TicketVisibility::Public => TicketTypeVisibility::Public,
+            TicketVisibility::Hidden => TicketTypeVisibility::Hidden,
+        }
+    }
+}
+
+impl From<TicketTypeVisibility> for TicketVisibility {
+    fn from(visibility: TicketTypeVisibility) -> Self {
+        match visibility {
+            TicketTypeVisibility::Public => TicketVisibility::Public,
+            TicketTypeVisibility::Hidden => TicketVisibility::Hidden,
+        }
+    }
+}
+
+/// What a ticket type admits to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AdmissionKind {
+    /// The whole event.
+    Event,
+    /// Every session on one day.
+    Day,
+    /// A single session.
+    Session,
+}
+
+/// Body for creating or replacing a ticket type. Gives the whole event
+/// unless a `day` (a day pass) or a `session_id` is set.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TicketTypeRequest {
+    #[schema(example = "VIP")]
+    pub name: String,
+    /// Price in minor currency units, e.g. cents.
+    #[schema(example = 12500)]
+    pub price_minor: i64,
+    #[schema(example = "USD")]
+    pub currency: String,
+    #[schema(example = 200)]
+    pub quantity: i32,
+    #[schema(example = 1)]
+    pub min_per_order: Option<i32>,
+    #[schema(example = 8)]
+    pub max_per_order: Option<i32>,
+    #[schema(example = "2024-05-01T10:00:00Z")]
+    pub sales_start: Option<DateTime<Utc>>,
+    #[schema(example = "2024-07-15T12:00:00Z")]
+    pub sales_end: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub visibility: TicketVisibility,
+    /// Local date of the event's day the ticket admits to.
+    #[schema(example = "2024-07-20")]
+    pub day: Option<NaiveDate>,
+    /// Session the ticket admits to.
+    pub session_id: Option<Uuid>,
+    /// Section of the venue's seating chart the ticket type sells seats in,
+    /// for pricing sections differently.
+    pub section_id: Option<Uuid>,
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum TicketTypeRequestError {
+    #[error("Ticket type name is required")]
+    NameEmpty,
+    #[error("Ticket type name is too long (maximum 100 characters)")]
+    NameTooLong,
+    #[error("Price cannot be negative")]
+    NegativePrice,
+    #[error("Currency must be an ISO 4217 code of a currency in circulation")]
+    InvalidCurrency,
+    #[error("Quantity cannot be negative")]
+    NegativeQuantity,
+    #[error("Minimum per order must be at least 1")]
+    MinPerOrderTooSmall,
+    #[error("Maximum per order cannot be less than the minimum per order")]
+    MaxPerOrderBelowMin,
+    #[error("Maximum per order cannot exceed the quantity")]
+    MaxPerOrderAboveQuantity,
+    #[error("Sales end must be after sales start")]
+    SalesWindowInvalid,
+    #[error("A ticket type admits to either a day or a session, not both")]
+    AmbiguousAdmission,
+}
+
+const DEFAULT_MIN_PER_ORDER: i32 = 1;
+const DEFAULT_MAX_PER_ORDER: i32 = 10;
+
+/// ISO 4217 codes of currencies in circulation, sorted for binary search.
+const CURRENCIES: &[&str] = &[
+    "AED", "AFN", "ALL", "AMD", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT", "BGN",
+    "BHD", "BIF", "BMD", "BND", "BOB", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD", "CDF",
+    "CHF", "CLP", "CNY", "COP", "CRC", "CUP", "CVE", "CZK", "DJF", "DKK", "DOP", "DZD", "EGP",
+    "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD",
+    "HKD", "HNL", "HTG", "HUF", "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY",
+    "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD", "KYD", "KZT", "LAK", "LBP", "LKR", "LRD",
+    "LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK",
+    "MXN", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK",
+    "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB", "RWF", "SAR", "SBD", "SCR", "SDG",
+    "SEK", "SGD", "SHP", "SLE", "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS",
+    "TMT", "TND", "TOP", "TRY", "TTD", "TWD", "TZS", "UAH", "UGX", "USD", "UYU", "UZS", "VES",
+    "VND", "VUV", "WST", "XAF", "XCD", "XCG", "XOF", "XPF", "YER", "ZAR", "ZMW", "ZWG",
+];
+
+fn validate_name(name: &str) -> Result<String, TicketTypeRequestError> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        return Err(TicketTypeRequestError::NameEmpty);
+    }
+    if trimmed.len() > 100 {
+        return Err(TicketTypeRequestError::NameTooLong);
+    }
+    Ok(trimmed.to_string())
+}
+
+fn validate_currency(currency: &str) -> Result<String, TicketTypeRequestError> {
+    let code = currency.trim().to_ascii_uppercase();
+    if CURRENCIES.binary_search(&code.as_str()).is_err() {
+        return Err(TicketTypeRequestError::InvalidCurrency);
+    }
+    Ok(code)
+}
+
+/// Order limits, defaulting the maximum to at most `quantity` when not
+/// given.
+fn validate_order_limits(
+    min_per_order: Option<i32>,
+    max_per_order: Option<i32>,
+    quantity: i32,
+) -> Result<(i32, i32), TicketTypeRequestError> {
+    let min = min_per_order.unwrap_or(DEFAULT_MIN_PER_ORDER);
+    if min < 1 {
+        return Err(TicketTypeRequestError::MinPerOrderTooSmall);
+    }
+    let max = max_per_order.unwrap_or(DEFAULT_MAX_PER_ORDER.min(quantity).max(min));
+    if max < min {
+        return Err(TicketTypeRequestError::MaxPerOrderBelowMin);
+    }
+    if max_per_order.is_some_and(|max| max > quantity) {
+        return Err(TicketTypeRequestError::MaxPerOrderAboveQuantity);
+    }
+    Ok((min, max))
+}
+
+fn validate_sales_window(
+    sales_start: Option<&DateTime<Utc>>,
+    sales_end: Option<&DateTime<Utc>>,
+) -> Result<(), TicketTypeRequestError> {
+    if let (Some(start), Some(end)) = (sales_start, sales_end) {
+        if end <= start {
+            return Err(TicketTypeRequestError::SalesWindowInvalid);
+        }
+    }
+    Ok(())
+}
+
+impl TryFrom<TicketTypeRequest> for TicketTypeParams {
+    type Error = TicketTypeRequestError;
+
+    fn try_from(req: TicketTypeRequest) -> Result<Self, Self::Error> {
+        let name = validate_name(&req.name)?;
+        if req.price_minor < 0 {
+            return Err(TicketTypeRequestError::NegativePrice);
+        }
+        let currency = validate_currency(&req.currency)?;
+        if req.quantity < 0 {
+            return Err(TicketTypeRequestError::NegativeQuantity);
+        }
+        let (min_per_order, max_per_order) =
+            validate_order_limits(req.min_per_order, req.max_per_order, req.quantity)?;
+        validate_sales_window(req.sales_start.as_ref(), req.sales_end.as_ref())?;
+        let admission = match (req.day, req.session_id) {
+            (None, None) => Admission::Event,
+            (Some(day), None) => Admission::Day(day),
+            (None, Some(session_id)) => Admission::Session(session_id),
+            (Some(_), Some(_)) => return Err(TicketTypeRequestError::AmbiguousAdmission),
+        };
+
+        Ok(Self {
+            name,
+            price_minor: req.price_minor,
+            currency,
+            quantity: req.quantity,
+            min_per_order,
+            max_per_order,
+            sales_start: req.sales_start,
+            sales_end: req.sales_end,
+            visibility: req.visibility.into(),
+            admission,
+            section_id: req.section_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_validate_currency() {
+        use TicketTypeRequestError::InvalidCurrency;
+        let cases = [
+            ("EUR", Ok("EUR")),
+            (" usd ", Ok("USD")),
+            ("jpY", Ok("JPY")),
+            ("XYZ", Err(InvalidCurrency)),
+            ("DEM", Err(InvalidCurrency)),
+            ("US", Err(InvalidCurrency)),
+            ("USDT", Err(InvalidCurrency)),
+            ("", Err(InvalidCurrency)),
+        ];
+        for (currency, expected) in cases {
+            assert_eq!(
+                validate_currency(currency),
+                expected.map(str::to_string),
+                "{:?}",
+                currency
+            );
+        }
+        assert!(CURRENCIES.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_validate_order_limits() {
+        use TicketTypeRequestError::*;
+        let cases = [
+            ((None, None, 200), Ok((1, 10))),
+            ((Some(2), Some(4), 200), Ok((2, 4))),
+            ((Some(4), Some(4), 4), Ok((4, 4))),
+            // The default maximum stays within the quantity
+            ((None, None, 3), Ok((1, 3))),
+            ((Some(12), None, 200), Ok((12, 12))),
+            ((Some(0), None, 200), Err(MinPerOrderTooSmall)),
+            ((Some(5), Some(4), 200), Err(MaxPerOrderBelowMin)),
+            ((None, Some(8), 6), Err(MaxPerOrderAboveQuantity)),
+        ];
+        for ((min, max, quantity), expected) in cases {
+            assert_eq!(
+                validate_order_limits(min, max, quantity),
+                expected,
+                "min {:?}, max {:?}, quantity {}",
+                min,
+                max,
+                quantity
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_sales_window() {
+        use TicketTypeRequestError::SalesWindowInvalid;
+        let may = |day| Utc.with_ymd_and_hms(2026, 5, day, 10, 0, 0).unwrap();
+        let cases = [
+            ((None, None), Ok(())),
+            ((Some(may(1)), None), Ok(())),
+            ((None, Some(may(1))), Ok(())),
+            ((Some(may(1)), Some(may(2))), Ok(())),
+            ((Some(may(2)), Some(may(2))), Err(SalesWindowInvalid)),
+            ((Some(may(2)), Some(may(1))), Err(SalesWindowInvalid)),
+        ];
+        for ((start, end), expected) in cases {
+            assert_eq!(
+                validate_sales_window(start.as_ref(), end.as_ref()),
+                expected,
+                "{:?} to {:?}",
+                start,
+                end
+            );
+        }
+    }
+}
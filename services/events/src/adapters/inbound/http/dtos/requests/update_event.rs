@@ -0,0 +1,131 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::{AuthenticatedUser, IfMatch};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{Locale, ScheduleTime},
+    use_cases::UpdateEventParams,
+};
+
+use super::create_event::{
+    dedup_category_ids, parse_timezone, validate_description, validate_end, validate_start,
+    validate_tags, validate_title, validate_venue_name, CreateEventRequestError,
+};
+
+/// Fields to change; omitted fields keep their current value. Moving the
+/// start keeps the event's duration and how early doors open.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateEventRequest {
+    #[schema(example = "Summer Music Festival 2024 (Extended)")]
+    pub title: Option<String>,
+    /// Markdown; HTML is accepted but sanitized.
+    pub description: Option<String>,
+    /// New start as an absolute time. Give at most one of this and `starts_at`.
+    #[schema(example = "2024-07-16T18:00:00Z")]
+    pub event_date: Option<DateTime<Utc>>,
+    /// New start as wall-clock time in the event's (possibly new) timezone.
+    #[schema(example = "2024-07-16T19:00:00")]
+    pub starts_at: Option<NaiveDateTime>,
+    #[schema(example = "America/Chicago")]
+    pub timezone: Option<String>,
+    pub ends_at: Option<NaiveDateTime>,
+    pub duration_minutes: Option<u32>,
+    pub doors_open_at: Option<NaiveDateTime>,
+    pub venue_name: Option<String>,
+    pub venue_id: Option<Uuid>,
+    /// Replaces the event's categories; `[]` removes them all.
+    pub category_ids: Option<Vec<Uuid>>,
+    /// Replaces the event's tags; `[]` removes them all.
+    pub tags: Option<Vec<String>>,
+    /// Moves a pending publication; rejected once the event is published.
+    pub publish_at: Option<DateTime<Utc>>,
+    /// Moves a pending sales opening; rejected once sales are open.
+    pub on_sale_at: Option<DateTime<Utc>>,
+    /// Language the event's own title and description are in; a locale the
+    /// event is already translated into is rejected.
+    #[schema(example = "de")]
+    pub default_locale: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateEventRequestError {
+    #[error("At least one field must be provided")]
+    NoChanges,
+    #[error(transparent)]
+    Invalid(#[from] CreateEventRequestError),
+}
+
+impl TryFrom<(Uuid, AuthenticatedUser, IfMatch, UpdateEventRequest)> for UpdateEventParams {
+    type Error = UpdateEventRequestError;
+
+    fn try_from(
+        (id, user, if_match, req): (Uuid, AuthenticatedUser, IfMatch, UpdateEventRequest),
+    ) -> Result<Self, Self::Error> {
+        if req.title.is_none()
+            && req.description.is_none()
+            && req.event_date.is_none()
+            && req.starts_at.is_none()
+            && req.timezone.is_none()
+            && req.ends_at.is_none()
+            && req.duration_minutes.is_none()
+            && req.doors_open_at.is_none()
+            && req.venue_name.is_none()
+            && req.venue_id.is_none()
+            && req.category_ids.is_none()
+            && req.tags.is_none()
+            && req.publish_at.is_none()
+            && req.on_sale_at.is_none()
+            && req.default_locale.is_none()
+        {
+            return Err(UpdateEventRequestError::NoChanges);
+        }
+
+        let title = req.title.as_deref().map(validate_title).transpose()?;
+        let description = req
+            .description
+            .as_deref()
+            .map(validate_description)
+            .transpose()?;
+        let starts_at = validate_start(req.event_date, req.starts_at)?;
+        let timezone = req
+            .timezone
+            .as_deref()
+            .map(parse_timezone)
+            .transpose()
+            .map_err(CreateEventRequestError::from)?;
+        let end = validate_end(req.ends_at, req.duration_minutes)?;
+        let venue_name = req
+            .venue_name
+            .as_deref()
+            .map(validate_venue_name)
+            .transpose()?;
+        let tags = req.tags.map(validate_tags).transpose()?;
+        let default_locale = req
+            .default_locale
+            .as_deref()
+            .map(Locale::parse)
+            .transpose()
+            .map_err(CreateEventRequestError::from)?;
+
+        Ok(Self {
+            id,
+            title,
+            description,
+            starts_at,
+            timezone,
+            end,
+            doors_open_at: req.doors_open_at.map(ScheduleTime::Local),
+            venue_name,
+            venue_id: req.venue_id,
+            category_ids: req.category_ids.map(dedup_category_ids),
+            tags,
+            publish_at: req.publish_at,
+            on_sale_at: req.on_sale_at,
+            default_locale,
+            expected_version: if_match.version(),
+            requested_by: user.id(),
+        })
+    }
+}
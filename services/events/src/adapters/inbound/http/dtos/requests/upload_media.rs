@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::entities::MediaKind;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaPlacement {
+    /// The event's main image; uploading a new one replaces it.
+    Poster,
+    Gallery,
+}
+
+impl From<MediaPlacement> for MediaKind {
+    fn from(placement: MediaPlacement) -> Self {
+        match placement {
+            MediaPlacement::Poster => MediaKind::Poster,
+            MediaPlacement::Gallery => MediaKind::Gallery,
+        }
+    }
+}
+
+impl From<MediaKind> for MediaPlacement {
+    fn from(kind: MediaKind) -> Self {
+        match kind {
+            MediaKind::Poster => MediaPlacement::Poster,
+            MediaKind::Gallery => MediaPlacement::Gallery,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UploadMediaQuery {
+    /// `poster` or `gallery`.
+    pub kind: MediaPlacement,
+}
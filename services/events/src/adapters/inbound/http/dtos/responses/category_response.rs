@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::{Category, CategoryFacet, Taxonomy};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CategoryResponse {
+    #[schema(example = "0b7e6a52-5a3f-4f51-9a4e-0d4c2f6f1b27")]
+    pub id: Uuid,
+    pub parent_id: Option<Uuid>,
+    #[schema(example = "Jazz")]
+    pub name: String,
+    #[schema(example = "jazz")]
+    pub slug: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Category> for CategoryResponse {
+    fn from(category: &Category) -> Self {
+        Self {
+            id: category.id(),
+            parent_id: category.parent_id(),
+            name: category.name().to_string(),
+            slug: category.slug().to_string(),
+            created_at: *category.created_at(),
+            updated_at: *category.updated_at(),
+        }
+    }
+}
+
+impl From<Category> for CategoryResponse {
+    fn from(category: Category) -> Self {
+        (&category).into()
+    }
+}
+
+/// A category with its subcategories, ordered by name.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CategoryTreeResponse {
+    #[serde(flatten)]
+    pub category: CategoryResponse,
+    #[schema(no_recursion)]
+    pub children: Vec<CategoryTreeResponse>,
+}
+
+impl CategoryTreeResponse {
+    /// The top-level categories with everything below them.
+    pub fn forest(taxonomy: &Taxonomy) -> Vec<Self> {
+        taxonomy
+            .roots()
+            .map(|root| Self::build(taxonomy, root))
+            .collect()
+    }
+
+    fn build(taxonomy: &Taxonomy, category: &Category) -> Self {
+        Self {
+            category: category.into(),
+            children: taxonomy
+                .children(category.id())
+                .map(|child| Self::build(taxonomy, child))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CategoryFacetResponse {
+    #[schema(example = "0b7e6a52-5a3f-4f51-9a4e-0d4c2f6f1b27")]
+    pub id: Uuid,
+    pub parent_id: Option<Uuid>,
+    #[schema(example = "Jazz")]
+    pub name: String,
+    #[schema(example = "jazz")]
+    pub slug: String,
+    /// Events filed directly under the category.
+    #[schema(example = 3)]
+    pub count: usize,
+    /// Distinct events in the category or any of its subcategories.
+    #[schema(example = 5)]
+    pub total: usize,
+}
+
+impl From<CategoryFacet> for CategoryFacetResponse {
+    fn from(facet: CategoryFacet) -> Self {
+        let category = facet.category();
+        Self {
+            id: category.id(),
+            parent_id: category.parent_id(),
+            name: category.name().to_string(),
+            slug: category.slug().to_string(),
+            count: facet.count(),
+            total: facet.total(),
+        }
+    }
+}
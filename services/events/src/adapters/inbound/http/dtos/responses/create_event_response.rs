@@ -1,34 +1,98 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::domain::Event;
 
+use super::EventState;
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateEventResponse {
     #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
     pub id: Uuid,
     #[schema(example = "Summer Music Festival 2024")]
     pub title: String,
-    #[schema(example = "Join us for an amazing outdoor music festival")]
+    /// Markdown source of the description.
+    #[schema(example = "Join us for an **amazing** outdoor music festival")]
     pub description: String,
+    /// `description` rendered to sanitized HTML; safe to embed in a page.
+    #[schema(example = "<p>Join us for an <strong>amazing</strong> outdoor music festival</p>\n")]
+    pub description_html: String,
+    /// Start in UTC.
     #[schema(example = "2024-07-15T18:00:00Z")]
     pub event_date: DateTime<Utc>,
+    /// IANA timezone the event is held in.
+    #[schema(example = "America/Chicago")]
+    pub timezone: String,
+    /// Start as wall-clock time in `timezone`, with its UTC offset.
+    #[schema(example = "2024-07-15T13:00:00-05:00")]
+    pub event_date_local: DateTime<FixedOffset>,
+    #[schema(example = "2024-07-15T22:00:00Z")]
+    pub ends_at: Option<DateTime<Utc>>,
+    #[schema(example = "2024-07-15T17:00:00-05:00")]
+    pub ends_at_local: Option<DateTime<FixedOffset>>,
+    #[schema(example = 240)]
+    pub duration_minutes: Option<i64>,
+    #[schema(example = "2024-07-15T17:00:00Z")]
+    pub doors_open_at: Option<DateTime<Utc>>,
+    #[schema(example = "2024-07-15T12:00:00-05:00")]
+    pub doors_open_at_local: Option<DateTime<FixedOffset>>,
     #[schema(example = "Central Park Amphitheater")]
     pub venue_name: String,
+    pub venue_id: Option<Uuid>,
+    pub organizer_id: Option<Uuid>,
+    pub organization_id: Option<Uuid>,
+    pub category_ids: Vec<Uuid>,
+    #[schema(example = json!(["outdoor", "family-friendly"]))]
+    pub tags: Vec<String>,
+    pub status: EventState,
+    /// When the event is to be published; set only for scheduled releases.
+    #[schema(example = "2024-05-01T09:00:00Z")]
+    pub publish_at: Option<DateTime<Utc>>,
+    /// When ticket sales are to open; `null` opens them on publication.
+    #[schema(example = "2024-05-03T10:00:00Z")]
+    pub on_sale_at: Option<DateTime<Utc>>,
+    /// When the event became public; `null` while it is still hidden.
+    pub published_at: Option<DateTime<Utc>>,
+    /// When ticket sales opened; `null` until they do.
+    pub sales_opened_at: Option<DateTime<Utc>>,
+    /// Revision counter, bumped on every update or cancellation.
+    #[schema(example = 0)]
+    pub sequence: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 impl From<Event> for CreateEventResponse {
     fn from(event: Event) -> Self {
+        let schedule = event.schedule();
+        let local = |instant| schedule.local(instant).fixed_offset();
         Self {
             id: event.id(),
             title: event.title().to_string(),
             description: event.description().to_string(),
+            description_html: event.description_html().to_string(),
             event_date: *event.event_date(),
+            timezone: schedule.timezone().name().to_string(),
+            event_date_local: local(event.event_date()),
+            ends_at: schedule.ends_at().copied(),
+            ends_at_local: schedule.ends_at().map(local),
+            duration_minutes: schedule.duration().map(|duration| duration.num_minutes()),
+            doors_open_at: schedule.doors_open_at().copied(),
+            doors_open_at_local: schedule.doors_open_at().map(local),
             venue_name: event.venue_name().to_string(),
+            venue_id: event.venue_id(),
+            organizer_id: event.organizer_id(),
+            organization_id: event.organization_id(),
+            category_ids: event.category_ids().to_vec(),
+            tags: event.tags().to_vec(),
+            status: event.status().into(),
+            publish_at: event.publication().publish_at().copied(),
+            on_sale_at: event.publication().on_sale_at().copied(),
+            published_at: event.publication().published_at().copied(),
+            sales_opened_at: event.publication().sales_opened_at().copied(),
+            sequence: event.sequence(),
             created_at: *event.created_at(),
             updated_at: *event.updated_at(),
         }
@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::{CancellationStep, EventCancellation, StepState};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CancellationStepKind {
+    StopSales,
+    RefundOrders,
+    NotifyHolders,
+}
+
+impl From<CancellationStep> for CancellationStepKind {
+    fn from(step: CancellationStep) -> Self {
+        match step {
+            CancellationStep::StopSales => CancellationStepKind::StopSales,
+            CancellationStep::RefundOrders => CancellationStepKind::RefundOrders,
+            CancellationStep::NotifyHolders => CancellationStepKind::NotifyHolders,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CancellationStepState {
+    Done,
+    InProgress,
+    Pending,
+}
+
+impl From<StepState> for CancellationStepState {
+    fn from(state: StepState) -> Self {
+        match state {
+            StepState::Done => CancellationStepState::Done,
+            StepState::InProgress => CancellationStepState::InProgress,
+            StepState::Pending => CancellationStepState::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CancellationStepResponse {
+    pub step: CancellationStepKind,
+    pub state: CancellationStepState,
+}
+
+/// Progress of a cancelled event through stopping sales, refunding orders
+/// and notifying ticket holders.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventCancellationResponse {
+    pub event_id: Uuid,
+    #[schema(example = "The headliner is ill and the show can't go ahead")]
+    pub reason: String,
+    pub requested_by: Uuid,
+    /// Whether every step is done.
+    pub completed: bool,
+    /// Every step in the order they run.
+    pub steps: Vec<CancellationStepResponse>,
+    #[schema(example = 1250)]
+    pub orders_refunded: i32,
+    #[schema(example = 1180)]
+    pub holders_notified: i32,
+    /// Failed attempts at the current step.
+    pub attempts: i32,
+    /// Why the last attempt at the current step failed.
+    pub last_error: Option<String>,
+    /// When the current step is tried next; unset once completed.
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<EventCancellation> for EventCancellationResponse {
+    fn from(cancellation: EventCancellation) -> Self {
+        Self {
+            event_id: cancellation.event_id(),
+            reason: cancellation.reason().to_string(),
+            requested_by: cancellation.requested_by(),
+            completed: cancellation.is_completed(),
+            steps: CancellationStep::ALL
+                .into_iter()
+                .map(|step| CancellationStepResponse {
+                    step: step.into(),
+                    state: cancellation.step_state(step).into(),
+                })
+                .collect(),
+            orders_refunded: cancellation.orders_refunded(),
+            holders_notified: cancellation.holders_notified(),
+            attempts: cancellation.attempts(),
+            last_error: cancellation.last_error().map(str::to_string),
+            next_attempt_at: (!cancellation.is_completed())
+                .then(|| *cancellation.next_attempt_at()),
+            started_at: *cancellation.started_at(),
+            updated_at: *cancellation.updated_at(),
+            completed_at: cancellation.completed_at().copied(),
+        }
+    }
+}
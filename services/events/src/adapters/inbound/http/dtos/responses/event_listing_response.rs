@@ -0,0 +1,100 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::{EventListing, ListingEntry};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ListingKind {
+    Event,
+    Occurrence,
+}
+
+/// One entry of the events listing: either a one-off event or an occurrence
+/// of a series.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventListingResponse {
+    pub kind: ListingKind,
+    /// Set for one-off events.
+    pub event_id: Option<Uuid>,
+    /// Set for series occurrences.
+    pub series_id: Option<Uuid>,
+    /// Set for series occurrences; addresses the occurrence for overrides.
+    pub original_start: Option<DateTime<Utc>>,
+    #[schema(example = "2026-11-05T20:00:00Z")]
+    pub starts_at: DateTime<Utc>,
+    #[schema(example = "America/Chicago")]
+    pub timezone: String,
+    /// `starts_at` as wall-clock time in `timezone`.
+    #[schema(example = "2026-11-05T14:00:00-06:00")]
+    pub starts_at_local: DateTime<FixedOffset>,
+    pub title: String,
+    /// Markdown source of the description.
+    pub description: String,
+    /// `description` rendered to sanitized HTML.
+    pub description_html: String,
+    /// Language `title` and `description` are in, negotiated from
+    /// `Accept-Language`; unset for series occurrences, which are not
+    /// translated.
+    #[schema(example = "en")]
+    pub locale: Option<String>,
+    pub venue_name: String,
+    pub venue_id: Option<Uuid>,
+    /// Categories of one-off events; empty for series occurrences.
+    pub category_ids: Vec<Uuid>,
+    /// Tags of one-off events; empty for series occurrences.
+    pub tags: Vec<String>,
+    /// Kilometres from the searched point; set only when searching `near`
+    /// one.
+    #[schema(example = 3.2)]
+    pub distance_km: Option<f64>,
+}
+
+impl From<ListingEntry> for EventListingResponse {
+    fn from(entry: ListingEntry) -> Self {
+        let distance_km = entry.distance_km;
+        match entry.listing {
+            EventListing::Single(event) => Self {
+                kind: ListingKind::Event,
+                event_id: Some(event.id()),
+                series_id: None,
+                original_start: None,
+                starts_at: *event.event_date(),
+                timezone: event.schedule().timezone().name().to_string(),
+                starts_at_local: event.schedule().local(event.event_date()).fixed_offset(),
+                title: event.title().to_string(),
+                description: event.description().to_string(),
+                description_html: event.description_html().to_string(),
+                locale: Some(event.locale().to_string()),
+                venue_name: event.venue_name().to_string(),
+                venue_id: event.venue_id(),
+                category_ids: event.category_ids().to_vec(),
+                tags: event.tags().to_vec(),
+                distance_km,
+            },
+            EventListing::Occurrence(occurrence) => Self {
+                kind: ListingKind::Occurrence,
+                event_id: None,
+                series_id: Some(occurrence.series_id()),
+                original_start: Some(*occurrence.original_start()),
+                starts_at: *occurrence.starts_at(),
+                timezone: occurrence.timezone().name().to_string(),
+                starts_at_local: occurrence
+                    .starts_at()
+                    .with_timezone(&occurrence.timezone())
+                    .fixed_offset(),
+                title: occurrence.title().to_string(),
+                description: occurrence.description().to_string(),
+                description_html: occurrence.description_html().to_string(),
+                locale: None,
+                venue_name: occurrence.venue_name().to_string(),
+                venue_id: occurrence.venue_id(),
+                category_ids: Vec::new(),
+                tags: Vec::new(),
+                distance_km,
+            },
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    EventRevision, EventSnapshot, FieldChange, RevisionAction, RichText,
+};
+
+use super::EventState;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionKind {
+    Created,
+    Updated,
+    Cancelled,
+}
+
+impl From<RevisionAction> for RevisionKind {
+    fn from(action: RevisionAction) -> Self {
+        match action {
+            RevisionAction::Created => RevisionKind::Created,
+            RevisionAction::Updated => RevisionKind::Updated,
+            RevisionAction::Cancelled => RevisionKind::Cancelled,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FieldChangeResponse {
+    #[schema(example = "venue_name")]
+    pub field: String,
+    /// Previous value; `null` when unset or for the revision that created
+    /// the event.
+    #[schema(value_type = Object, example = json!("Cellar, Main St"))]
+    pub from: Value,
+    #[schema(value_type = Object, example = json!("Rooftop, Harbor Rd"))]
+    pub to: Value,
+}
+
+impl From<&FieldChange> for FieldChangeResponse {
+    fn from(change: &FieldChange) -> Self {
+        Self {
+            field: change.field.clone(),
+            from: change.from.clone(),
+            to: change.to.clone(),
+        }
+    }
+}
+
+/// One recorded change to an event.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventRevisionResponse {
+    /// Matches the event's `sequence` after the change.
+    #[schema(example = 3)]
+    pub revision: i32,
+    pub action: RevisionKind,
+    /// Who made the change; absent for changes made outside the API.
+    pub actor_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub changes: Vec<FieldChangeResponse>,
+}
+
+impl From<&EventRevision> for EventRevisionResponse {
+    fn from(revision: &EventRevision) -> Self {
+        Self {
+            revision: revision.revision(),
+            action: revision.action().into(),
+            actor_id: revision.actor_id(),
+            created_at: *revision.created_at(),
+            changes: revision.changes().iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The event's tracked fields as they were right after a revision.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventSnapshotResponse {
+    #[schema(example = "Summer Music Festival 2024")]
+    pub title: String,
+    pub description: String,
+    pub description_html: String,
+    pub event_date: DateTime<Utc>,
+    #[schema(example = "America/Chicago")]
+    pub timezone: String,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub doors_open_at: Option<DateTime<Utc>>,
+    pub venue_name: String,
+    pub venue_id: Option<Uuid>,
+    pub organizer_id: Option<Uuid>,
+    pub organization_id: Option<Uuid>,
+    pub status: EventState,
+    pub category_ids: Vec<Uuid>,
+    pub tags: Vec<String>,
+}
+
+/// A revision together with the event as of that revision.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventRevisionDetailResponse {
+    #[serde(flatten)]
+    pub revision: EventRevisionResponse,
+    pub event: EventSnapshotResponse,
+}
+
+impl From<&EventSnapshot> for EventSnapshotResponse {
+    fn from(snapshot: &EventSnapshot) -> Self {
+        Self {
+            title: snapshot.title.clone(),
+            description: snapshot.description.clone(),
+            description_html: RichText::from_markdown(&snapshot.description)
+                .html()
+                .to_string(),
+            event_date: snapshot.starts_at,
+            timezone: snapshot.timezone.name().to_string(),
+            ends_at: snapshot.ends_at,
+            doors_open_at: snapshot.doors_open_at,
+            venue_name: snapshot.venue_name.clone(),
+            venue_id: snapshot.venue_id,
+            organizer_id: snapshot.organizer_id,
+            organization_id: snapshot.organization_id,
+            status: snapshot.status.into(),
+            category_ids: snapshot.category_ids.clone(),
+            tags: snapshot.tags.clone(),
+        }
+    }
+}
+
+impl From<EventRevision> for EventRevisionDetailResponse {
+    fn from(revision: EventRevision) -> Self {
+        Self {
+            revision: (&revision).into(),
+            event: revision.snapshot().into(),
+        }
+    }
+}
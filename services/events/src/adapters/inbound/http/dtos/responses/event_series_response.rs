@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{entities::OccurrenceOverride, EventSeries};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OccurrenceOverrideResponse {
+    pub series_id: Uuid,
+    /// Original start of the overridden occurrence.
+    #[schema(example = "2026-11-19T20:00:00Z")]
+    pub occurrence_start: DateTime<Utc>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// `description` rendered to sanitized HTML.
+    pub description_html: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub venue_name: Option<String>,
+    pub venue_id: Option<Uuid>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&OccurrenceOverride> for OccurrenceOverrideResponse {
+    fn from(override_: &OccurrenceOverride) -> Self {
+        Self {
+            series_id: override_.series_id(),
+            occurrence_start: *override_.occurrence_start(),
+            title: override_.title().map(str::to_string),
+            description: override_.description().map(str::to_string),
+            description_html: override_.description_html().map(str::to_string),
+            starts_at: override_.starts_at().copied(),
+            venue_name: override_.venue_name().map(str::to_string),
+            venue_id: override_.venue_id(),
+            updated_at: *override_.updated_at(),
+        }
+    }
+}
+
+impl From<OccurrenceOverride> for OccurrenceOverrideResponse {
+    fn from(override_: OccurrenceOverride) -> Self {
+        (&override_).into()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventSeriesResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "Thursday Comedy Night")]
+    pub title: String,
+    pub description: String,
+    /// `description` rendered to sanitized HTML; safe to embed in a page.
+    pub description_html: String,
+    #[schema(example = "The Laugh Cellar")]
+    pub venue_name: String,
+    pub venue_id: Option<Uuid>,
+    #[schema(example = "2026-11-05T20:00:00Z")]
+    pub starts_at: DateTime<Utc>,
+    /// IANA timezone the rule repeats in.
+    #[schema(example = "America/Chicago")]
+    pub timezone: String,
+    #[schema(example = "FREQ=WEEKLY;BYDAY=TH;COUNT=20")]
+    pub rrule: String,
+    pub exdates: Vec<DateTime<Utc>>,
+    /// Start of the final occurrence; absent for open-ended series.
+    pub last_occurrence_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub overrides: Vec<OccurrenceOverrideResponse>,
+}
+
+impl From<EventSeries> for EventSeriesResponse {
+    fn from(series: EventSeries) -> Self {
+        Self {
+            id: series.id(),
+            title: series.title().to_string(),
+            description: series.description().to_string(),
+            description_html: series.description_html().to_string(),
+            venue_name: series.venue_name().to_string(),
+            venue_id: series.venue_id(),
+            starts_at: *series.starts_at(),
+            timezone: series.timezone().name().to_string(),
+            rrule: series.rrule().to_string(),
+            exdates: series.exdates().to_vec(),
+            last_occurrence_at: series.last_occurrence_at().copied(),
+            created_at: *series.created_at(),
+            updated_at: *series.updated_at(),
+            overrides: series.overrides().iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{AgendaDay, AgendaStage, EventSession},
+    Event,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventSessionResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    #[schema(example = "Headliner: The Midnight")]
+    pub title: String,
+    #[schema(example = "Main Stage")]
+    pub stage: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    #[schema(example = 5000)]
+    pub capacity: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&EventSession> for EventSessionResponse {
+    fn from(session: &EventSession) -> Self {
+        Self {
+            id: session.id(),
+            event_id: session.event_id(),
+            title: session.title().to_string(),
+            stage: session.stage().map(str::to_string),
+            starts_at: *session.starts_at(),
+            ends_at: *session.ends_at(),
+            capacity: session.capacity(),
+            created_at: *session.created_at(),
+            updated_at: *session.updated_at(),
+        }
+    }
+}
+
+impl From<EventSession> for EventSessionResponse {
+    fn from(session: EventSession) -> Self {
+        (&session).into()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ScheduleStageResponse {
+    /// Absent for sessions not held on a particular stage.
+    #[schema(example = "Main Stage")]
+    pub stage: Option<String>,
+    /// Earliest first.
+    pub sessions: Vec<EventSessionResponse>,
+}
+
+impl From<AgendaStage> for ScheduleStageResponse {
+    fn from(stage: AgendaStage) -> Self {
+        Self {
+            stage: stage.stage,
+            sessions: stage.sessions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ScheduleDayResponse {
+    /// Local date in the event's timezone.
+    #[schema(example = "2024-07-20")]
+    pub date: NaiveDate,
+    /// In the order their first session of the day starts.
+    pub stages: Vec<ScheduleStageResponse>,
+}
+
+impl From<AgendaDay> for ScheduleDayResponse {
+    fn from(day: AgendaDay) -> Self {
+        Self {
+            date: day.date,
+            stages: day.stages.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// An event's sessions grouped by local day, then by stage.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventScheduleResponse {
+    pub event_id: Uuid,
+    #[schema(example = "America/Chicago")]
+    pub timezone: String,
+    pub days: Vec<ScheduleDayResponse>,
+}
+
+impl From<&Event> for EventScheduleResponse {
+    fn from(event: &Event) -> Self {
+        Self {
+            event_id: event.id(),
+            timezone: event.schedule().timezone().name().to_string(),
+            days: event.agenda().into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::EventStatus;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EventState {
+    Scheduled,
+    Cancelled,
+}
+
+impl From<EventStatus> for EventState {
+    fn from(status: EventStatus) -> Self {
+        match status {
+            EventStatus::Scheduled => EventState::Scheduled,
+            EventStatus::Cancelled => EventState::Cancelled,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    AdmissionBlueprint, EventTemplate, MediaBlueprint, SessionBlueprint, TicketTypeBlueprint,
+};
+
+use super::super::requests::{AdmissionKind, MediaPlacement, TicketVisibility};
+
+/// A ticket type the template sets up. Sales windows are relative to each
+/// new event's start.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TemplateTicketTypeResponse {
+    #[schema(example = "VIP")]
+    pub name: String,
+    #[schema(example = 12500)]
+    pub price_minor: i64,
+    #[schema(example = "USD")]
+    pub currency: String,
+    #[schema(example = 200)]
+    pub quantity: i32,
+    #[schema(example = 1)]
+    pub min_per_order: i32,
+    #[schema(example = 8)]
+    pub max_per_order: i32,
+    /// Sales open this many minutes before the event starts.
+    #[schema(example = 43200)]
+    pub sales_start_minutes_before: Option<i64>,
+    /// Sales close this many minutes before the event starts.
+    #[schema(example = 60)]
+    pub sales_end_minutes_before: Option<i64>,
+    pub visibility: TicketVisibility,
+    pub admission: AdmissionKind,
+    /// Days after the event's first day, for day passes.
+    #[schema(example = 1)]
+    pub day_offset: Option<i64>,
+    /// Position in the template's `sessions`, for single-session tickets.
+    pub session_index: Option<usize>,
+}
+
+impl From<&TicketTypeBlueprint> for TemplateTicketTypeResponse {
+    fn from(ticket_type: &TicketTypeBlueprint) -> Self {
+        let (admission, day_offset, session_index) = match ticket_type.admission {
+            AdmissionBlueprint::Event => (AdmissionKind::Event, None, None),
+            AdmissionBlueprint::Day(days) => (AdmissionKind::Day, Some(days), None),
+            AdmissionBlueprint::Session(index) => (AdmissionKind::Session, None, Some(index)),
+        };
+        Self {
+            name: ticket_type.name.clone(),
+            price_minor: ticket_type.price_minor,
+            currency: ticket_type.currency.clone(),
+            quantity: ticket_type.quantity,
+            min_per_order: ticket_type.min_per_order,
+            max_per_order: ticket_type.max_per_order,
+            sales_start_minutes_before: ticket_type.sales_start_minutes_before,
+            sales_end_minutes_before: ticket_type.sales_end_minutes_before,
+            visibility: ticket_type.visibility.into(),
+            admission,
+            day_offset,
+            session_index,
+        }
+    }
+}
+
+/// A session the template sets up, relative to each new event's start.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TemplateSessionResponse {
+    #[schema(example = "Headliner: The Midnight")]
+    pub title: String,
+    #[schema(example = "Main Stage")]
+    pub stage: Option<String>,
+    #[schema(example = 1560)]
+    pub starts_minutes_after: i64,
+    #[schema(example = 90)]
+    pub duration_minutes: i64,
+    pub capacity: Option<i32>,
+}
+
+impl From<&SessionBlueprint> for TemplateSessionResponse {
+    fn from(session: &SessionBlueprint) -> Self {
+        Self {
+            title: session.title.clone(),
+            stage: session.stage.clone(),
+            starts_minutes_after: session.starts_minutes_after,
+            duration_minutes: session.duration_minutes,
+            capacity: session.capacity,
+        }
+    }
+}
+
+/// A media file copied onto each new event.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TemplateMediaResponse {
+    pub kind: MediaPlacement,
+    #[schema(example = "image/jpeg")]
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub url: String,
+    pub thumbnail_url: Option<String>,
+}
+
+impl From<&MediaBlueprint> for TemplateMediaResponse {
+    fn from(media: &MediaBlueprint) -> Self {
+        Self {
+            kind: media.kind.into(),
+            content_type: media.format.content_type().to_string(),
+            size_bytes: media.size_bytes,
+            width: media.width,
+            height: media.height,
+            url: media.url.clone(),
+            thumbnail_url: media.thumbnail_url.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventTemplateResponse {
+    pub id: Uuid,
+    #[schema(example = "Summer tour stop")]
+    pub name: String,
+    /// User who saved the template.
+    pub organizer_id: Uuid,
+    /// Set for templates shared by an organization's owners and managers.
+    pub organization_id: Option<Uuid>,
+    #[schema(example = "Summer Music Festival 2024")]
+    pub title: String,
+    /// Markdown source of the description.
+    pub description: String,
+    #[schema(example = "America/Chicago")]
+    pub timezone: String,
+    #[schema(example = 240)]
+    pub duration_minutes: Option<i64>,
+    /// Doors open this many minutes before the event starts.
+    #[schema(example = 60)]
+    pub doors_open_minutes_before: Option<i64>,
+    #[schema(example = "Central Park Amphitheater")]
+    pub venue_name: String,
+    pub venue_id: Option<Uuid>,
+    pub category_ids: Vec<Uuid>,
+    pub tags: Vec<String>,
+    /// Earliest first.
+    pub sessions: Vec<TemplateSessionResponse>,
+    pub ticket_types: Vec<TemplateTicketTypeResponse>,
+    /// Poster first, then the gallery in upload order.
+    pub media: Vec<TemplateMediaResponse>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<EventTemplate> for EventTemplateResponse {
+    fn from(template: EventTemplate) -> Self {
+        let blueprint = template.blueprint();
+        Self {
+            id: template.id(),
+            name: template.name().to_string(),
+            organizer_id: template.organizer_id(),
+            organization_id: template.organization_id(),
+            title: blueprint.title.clone(),
+            description: blueprint.description.clone(),
+            timezone: blueprint.timezone.name().to_string(),
+            duration_minutes: blueprint.duration_minutes,
+            doors_open_minutes_before: blueprint.doors_open_minutes_before,
+            venue_name: blueprint.venue_name.clone(),
+            venue_id: blueprint.venue_id,
+            category_ids: blueprint.category_ids.clone(),
+            tags: blueprint.tags.clone(),
+            sessions: blueprint.sessions.iter().map(Into::into).collect(),
+            ticket_types: blueprint.ticket_types.iter().map(Into::into).collect(),
+            media: blueprint.media.iter().map(Into::into).collect(),
+            created_at: *template.created_at(),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::EventTranslation;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventTranslationResponse {
+    pub event_id: Uuid,
+    #[schema(example = "de")]
+    pub locale: String,
+    #[schema(example = "Sommer-Musikfestival 2024")]
+    pub title: String,
+    /// Markdown source of the description.
+    pub description: String,
+    /// `description` rendered to sanitized HTML.
+    pub description_html: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<EventTranslation> for EventTranslationResponse {
+    fn from(translation: EventTranslation) -> Self {
+        Self {
+            event_id: translation.event_id(),
+            locale: translation.locale().to_string(),
+            title: translation.title().to_string(),
+            description: translation.description().to_string(),
+            description_html: translation.description_html().to_string(),
+            created_at: *translation.created_at(),
+            updated_at: *translation.updated_at(),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::Venue;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GetVenueResponse {
+    #[schema(example = "6f1c5430-2b1e-4b7a-9d7e-2f0c1a9b8e11")]
+    pub id: Uuid,
+    #[schema(example = "Central Park Amphitheater")]
+    pub name: String,
+    #[schema(example = "America/New_York")]
+    pub timezone: String,
+    #[schema(example = 40.7697)]
+    pub latitude: Option<f64>,
+    #[schema(example = -73.9735)]
+    pub longitude: Option<f64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Venue> for GetVenueResponse {
+    fn from(venue: Venue) -> Self {
+        Self {
+            id: venue.id(),
+            name: venue.name().to_string(),
+            timezone: venue.timezone().name().to_string(),
+            latitude: venue.location().map(|location| location.latitude()),
+            longitude: venue.location().map(|location| location.longitude()),
+            created_at: *venue.created_at(),
+            updated_at: *venue.updated_at(),
+        }
+    }
+}
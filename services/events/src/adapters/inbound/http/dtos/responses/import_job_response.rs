@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::{ImportJob, ImportJobStatus, ImportRowOutcome, ImportRowResult};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportJobState {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl From<ImportJobStatus> for ImportJobState {
+    fn from(status: ImportJobStatus) -> Self {
+        match status {
+            ImportJobStatus::Pending => ImportJobState::Pending,
+            ImportJobStatus::Running => ImportJobState::Running,
+            ImportJobStatus::Completed => ImportJobState::Completed,
+            ImportJobStatus::Failed => ImportJobState::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportOutcome {
+    Created,
+    Duplicate,
+    Invalid,
+    Failed,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportRowResponse {
+    /// Line in the uploaded file where the entry starts.
+    #[schema(example = 2)]
+    pub line: usize,
+    pub uid: Option<String>,
+    pub outcome: ImportOutcome,
+    /// Created event, or the existing event for duplicates.
+    pub event_id: Option<Uuid>,
+    pub error: Option<String>,
+}
+
+impl From<&ImportRowResult> for ImportRowResponse {
+    fn from(result: &ImportRowResult) -> Self {
+        let (outcome, event_id, error) = match &result.outcome {
+            ImportRowOutcome::Created { event_id } => {
+                (ImportOutcome::Created, Some(*event_id), None)
+            }
+            ImportRowOutcome::Duplicate { event_id } => {
+                (ImportOutcome::Duplicate, Some(*event_id), None)
+            }
+            ImportRowOutcome::Invalid { error } => {
+                (ImportOutcome::Invalid, None, Some(error.clone()))
+            }
+            ImportRowOutcome::Failed { error } => {
+                (ImportOutcome::Failed, None, Some(error.clone()))
+            }
+        };
+
+        Self {
+            line: result.line,
+            uid: result.uid.clone(),
+            outcome,
+            event_id,
+            error,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportJobResponse {
+    pub id: Uuid,
+    #[schema(example = "partner-schedule.ics")]
+    pub source: String,
+    pub status: ImportJobState,
+    pub total_rows: usize,
+    pub processed_rows: usize,
+    pub created: usize,
+    pub duplicates: usize,
+    pub invalid: usize,
+    pub failed: usize,
+    pub error: Option<String>,
+    pub results: Vec<ImportRowResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<ImportJob> for ImportJobResponse {
+    fn from(job: ImportJob) -> Self {
+        Self {
+            id: job.id(),
+            source: job.source().to_string(),
+            status: job.status().into(),
+            total_rows: job.total_rows(),
+            processed_rows: job.results().len(),
+            created: job.created_count(),
+            duplicates: job.duplicate_count(),
+            invalid: job.invalid_count(),
+            failed: job.failed_count(),
+            error: job.error().map(str::to_string),
+            results: job.results().iter().map(Into::into).collect(),
+            created_at: *job.created_at(),
+            updated_at: *job.updated_at(),
+            completed_at: job.completed_at().copied(),
+        }
+    }
+}
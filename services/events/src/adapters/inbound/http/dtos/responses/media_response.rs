@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::MediaAsset;
+
+use super::super::requests::MediaPlacement;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MediaResponse {
+    pub id: Uuid,
+    pub kind: MediaPlacement,
+    #[schema(example = "image/jpeg")]
+    pub content_type: String,
+    #[schema(example = 348211)]
+    pub size_bytes: i64,
+    /// Pixel dimensions; absent for video.
+    #[schema(example = 1920)]
+    pub width: Option<i32>,
+    #[schema(example = 1080)]
+    pub height: Option<i32>,
+    #[schema(example = "/events/media/events/550e8400-e29b-41d4-a716-446655440000/poster.jpg")]
+    pub url: String,
+    /// JPEG at most 480 pixels on its longest edge; absent for video.
+    pub thumbnail_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&MediaAsset> for MediaResponse {
+    fn from(asset: &MediaAsset) -> Self {
+        Self {
+            id: asset.id(),
+            kind: asset.kind().into(),
+            content_type: asset.format().content_type().to_string(),
+            size_bytes: asset.size_bytes(),
+            width: asset.width(),
+            height: asset.height(),
+            url: asset.url().to_string(),
+            thumbnail_url: asset.thumbnail_url().map(str::to_string),
+            created_at: *asset.created_at(),
+        }
+    }
+}
@@ -1,9 +1,153 @@
+pub mod cancel_event_error;
+pub mod category_response;
+pub mod create_category_error;
 pub mod create_event_error;
 pub mod create_event_response;
+pub mod create_event_series_error;
+pub mod create_event_session_error;
+pub mod create_event_template_error;
+pub mod create_ticket_type_error;
+pub mod create_venue_error;
+pub mod create_venue_response;
+pub mod delete_category_error;
+pub mod delete_event_media_error;
+pub mod delete_event_session_error;
+pub mod delete_event_template_error;
+pub mod delete_event_translation_error;
+pub mod delete_occurrence_override_error;
+pub mod delete_ticket_type_error;
+pub mod duplicate_event_error;
+pub mod event_cancellation_response;
+pub mod event_listing_response;
+pub mod event_revision_response;
+pub mod event_series_response;
+pub mod event_session_response;
+pub mod event_state;
+pub mod event_template_response;
+pub mod event_translation_response;
+pub mod get_event_cancellation_error;
 pub mod get_event_error;
+pub mod get_event_facets_error;
+pub mod get_event_history_error;
 pub mod get_event_response;
+pub mod get_event_revision_error;
+pub mod get_event_schedule_error;
+pub mod get_event_series_error;
+pub mod get_event_template_error;
+pub mod get_import_job_error;
+pub mod get_organizer_calendar_error;
+pub mod get_seat_map_error;
+pub mod get_seating_chart_error;
+pub mod get_ticket_type_error;
+pub mod get_venue_calendar_error;
+pub mod get_venue_error;
+pub mod get_venue_response;
+pub mod import_events_error;
+pub mod import_job_response;
+pub mod import_seating_chart_error;
+pub mod instantiate_event_template_error;
+pub mod list_categories_error;
+pub mod list_event_templates_error;
+pub mod list_events_error;
+pub mod list_occurrences_error;
+pub mod list_organizer_events_error;
+pub mod list_ticket_types_error;
+pub mod media_response;
+pub mod occurrence_response;
+pub mod override_occurrence_error;
+pub mod publish_event_error;
+pub mod save_event_translation_error;
+pub mod seat_map_response;
+pub mod seating_chart_response;
+pub mod ticket_type_response;
+pub mod update_category_error;
+pub mod update_event_error;
+pub mod update_event_session_error;
+pub mod update_ticket_type_error;
+pub mod upload_event_media_error;
 
+pub use cancel_event_error::CancelEventError;
+pub use category_response::{CategoryFacetResponse, CategoryResponse, CategoryTreeResponse};
+pub use create_category_error::CreateCategoryError;
 pub use create_event_error::CreateEventError;
 pub use create_event_response::CreateEventResponse;
+pub use create_event_series_error::CreateEventSeriesError;
+pub use create_event_session_error::CreateEventSessionError;
+pub use create_event_template_error::CreateEventTemplateError;
+pub use create_ticket_type_error::CreateTicketTypeError;
+pub use create_venue_error::CreateVenueError;
+pub use create_venue_response::CreateVenueResponse;
+pub use delete_category_error::DeleteCategoryError;
+pub use delete_event_media_error::DeleteEventMediaError;
+pub use delete_event_session_error::DeleteEventSessionError;
+pub use delete_event_template_error::DeleteEventTemplateError;
+pub use delete_event_translation_error::DeleteEventTranslationError;
+pub use delete_occurrence_override_error::DeleteOccurrenceOverrideError;
+pub use delete_ticket_type_error::DeleteTicketTypeError;
+pub use duplicate_event_error::DuplicateEventError;
+pub use event_cancellation_response::{
+    CancellationStepKind, CancellationStepResponse, CancellationStepState,
+    EventCancellationResponse,
+};
+pub use event_listing_response::{EventListingResponse, ListingKind};
+pub use event_revision_response::{
+    EventRevisionDetailResponse, EventRevisionResponse, EventSnapshotResponse, FieldChangeResponse,
+    RevisionKind,
+};
+pub use event_series_response::{EventSeriesResponse, OccurrenceOverrideResponse};
+pub use event_session_response::{
+    EventScheduleResponse, EventSessionResponse, ScheduleDayResponse, ScheduleStageResponse,
+};
+pub use event_state::EventState;
+pub use event_template_response::{
+    EventTemplateResponse, TemplateMediaResponse, TemplateSessionResponse,
+    TemplateTicketTypeResponse,
+};
+pub use event_translation_response::EventTranslationResponse;
+pub use get_event_cancellation_error::GetEventCancellationError;
 pub use get_event_error::GetEventError;
+pub use get_event_facets_error::GetEventFacetsError;
+pub use get_event_history_error::GetEventHistoryError;
 pub use get_event_response::GetEventResponse;
+pub use get_event_revision_error::GetEventRevisionError;
+pub use get_event_schedule_error::GetEventScheduleError;
+pub use get_event_series_error::GetEventSeriesError;
+pub use get_event_template_error::GetEventTemplateError;
+pub use get_import_job_error::GetImportJobError;
+pub use get_organizer_calendar_error::GetOrganizerCalendarError;
+pub use get_seat_map_error::GetSeatMapError;
+pub use get_seating_chart_error::GetSeatingChartError;
+pub use get_ticket_type_error::GetTicketTypeError;
+pub use get_venue_calendar_error::GetVenueCalendarError;
+pub use get_venue_error::GetVenueError;
+pub use get_venue_response::GetVenueResponse;
+pub use import_events_error::ImportEventsError;
+pub use import_job_response::{
+    ImportJobResponse, ImportJobState, ImportOutcome, ImportRowResponse,
+};
+pub use import_seating_chart_error::ImportSeatingChartError;
+pub use instantiate_event_template_error::InstantiateEventTemplateError;
+pub use list_categories_error::ListCategoriesError;
+pub use list_event_templates_error::ListEventTemplatesError;
+pub use list_events_error::ListEventsError;
+pub use list_occurrences_error::ListOccurrencesError;
+pub use list_organizer_events_error::ListOrganizerEventsError;
+pub use list_ticket_types_error::ListTicketTypesError;
+pub use media_response::MediaResponse;
+pub use occurrence_response::OccurrenceResponse;
+pub use override_occurrence_error::OverrideOccurrenceError;
+pub use publish_event_error::PublishEventError;
+pub use save_event_translation_error::SaveEventTranslationError;
+pub use seat_map_response::{
+    SeatAvailability, SeatMapResponse, SeatMapRowResponse, SeatMapSeatResponse,
+    SeatMapSectionResponse,
+};
+pub use seating_chart_response::{
+    SeatResponse, SeatingChartResponse, SeatingRowResponse, SeatingSectionResponse,
+};
+pub use ticket_type_response::TicketTypeResponse;
+pub use update_category_error::UpdateCategoryError;
+pub use update_event_error::UpdateEventError;
+pub use update_event_session_error::UpdateEventSessionError;
+pub use update_ticket_type_error::UpdateTicketTypeError;
+pub use upload_event_media_error::UploadEventMediaError;
@@ -0,0 +1,50 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::Occurrence;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OccurrenceResponse {
+    pub series_id: Uuid,
+    /// Start generated by the recurrence rule; identifies the occurrence.
+    #[schema(example = "2026-11-19T20:00:00Z")]
+    pub original_start: DateTime<Utc>,
+    /// Effective start, which differs from `original_start` when rescheduled.
+    #[schema(example = "2026-11-19T21:00:00Z")]
+    pub starts_at: DateTime<Utc>,
+    #[schema(example = "America/Chicago")]
+    pub timezone: String,
+    /// `starts_at` as wall-clock time in `timezone`.
+    #[schema(example = "2026-11-19T15:00:00-06:00")]
+    pub starts_at_local: DateTime<FixedOffset>,
+    pub title: String,
+    pub description: String,
+    /// `description` rendered to sanitized HTML; safe to embed in a page.
+    pub description_html: String,
+    pub venue_name: String,
+    pub venue_id: Option<Uuid>,
+    pub overridden: bool,
+}
+
+impl From<Occurrence> for OccurrenceResponse {
+    fn from(occurrence: Occurrence) -> Self {
+        Self {
+            series_id: occurrence.series_id(),
+            original_start: *occurrence.original_start(),
+            starts_at: *occurrence.starts_at(),
+            timezone: occurrence.timezone().name().to_string(),
+            starts_at_local: occurrence
+                .starts_at()
+                .with_timezone(&occurrence.timezone())
+                .fixed_offset(),
+            title: occurrence.title().to_string(),
+            description: occurrence.description().to_string(),
+            description_html: occurrence.description_html().to_string(),
+            venue_name: occurrence.venue_name().to_string(),
+            venue_id: occurrence.venue_id(),
+            overridden: occurrence.overridden(),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{SeatMap, SeatStatus};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SeatAvailability {
+    Available,
+    Held,
+    Sold,
+}
+
+impl From<SeatStatus> for SeatAvailability {
+    fn from(status: SeatStatus) -> Self {
+        match status {
+            SeatStatus::Available => SeatAvailability::Available,
+            SeatStatus::Held => SeatAvailability::Held,
+            SeatStatus::Sold => SeatAvailability::Sold,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeatMapResponse {
+    pub event_id: Uuid,
+    pub venue_id: Uuid,
+    #[schema(example = 480)]
+    pub total_seats: usize,
+    #[schema(example = 312)]
+    pub available_seats: usize,
+    pub sections: Vec<SeatMapSectionResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeatMapSectionResponse {
+    pub id: Uuid,
+    #[schema(example = "Orchestra")]
+    pub name: String,
+    pub rows: Vec<SeatMapRowResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeatMapRowResponse {
+    #[schema(example = "A")]
+    pub label: String,
+    pub seats: Vec<SeatMapSeatResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeatMapSeatResponse {
+    pub id: Uuid,
+    #[schema(example = "12")]
+    pub label: String,
+    pub x: f64,
+    pub y: f64,
+    pub accessible: bool,
+    pub obstructed: bool,
+    pub status: SeatAvailability,
+}
+
+impl From<SeatMap> for SeatMapResponse {
+    fn from(seat_map: SeatMap) -> Self {
+        let chart = seat_map.chart();
+        let sections: Vec<SeatMapSectionResponse> = chart
+            .sections()
+            .iter()
+            .map(|section| SeatMapSectionResponse {
+                id: section.id(),
+                name: section.name().to_string(),
+                rows: section
+                    .rows()
+                    .iter()
+                    .map(|row| SeatMapRowResponse {
+                        label: row.label().to_string(),
+                        seats: row
+                            .seats()
+                            .iter()
+                            .map(|seat| SeatMapSeatResponse {
+                                id: seat.id(),
+                                label: seat.label().to_string(),
+                                x: seat.x(),
+                                y: seat.y(),
+                                accessible: seat.accessible(),
+                                obstructed: seat.obstructed(),
+                                status: seat_map.status_of(seat.id()).into(),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let total_seats = chart.seats().count();
+        let available_seats = chart
+            .seats()
+            .filter(|seat| seat_map.status_of(seat.id()) == SeatStatus::Available)
+            .count();
+
+        Self {
+            event_id: seat_map.event_id(),
+            venue_id: chart.venue_id(),
+            total_seats,
+            available_seats,
+            sections,
+        }
+    }
+}
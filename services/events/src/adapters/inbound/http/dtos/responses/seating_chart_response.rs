@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{Seat, SeatingRow, SeatingSection},
+    SeatingChart,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeatingChartResponse {
+    pub venue_id: Uuid,
+    #[schema(example = 480)]
+    pub total_seats: usize,
+    pub sections: Vec<SeatingSectionResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeatingSectionResponse {
+    pub id: Uuid,
+    #[schema(example = "Orchestra")]
+    pub name: String,
+    pub rows: Vec<SeatingRowResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeatingRowResponse {
+    #[schema(example = "A")]
+    pub label: String,
+    pub seats: Vec<SeatResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeatResponse {
+    pub id: Uuid,
+    #[schema(example = "12")]
+    pub label: String,
+    pub x: f64,
+    pub y: f64,
+    pub accessible: bool,
+    pub obstructed: bool,
+}
+
+impl From<&Seat> for SeatResponse {
+    fn from(seat: &Seat) -> Self {
+        Self {
+            id: seat.id(),
+            label: seat.label().to_string(),
+            x: seat.x(),
+            y: seat.y(),
+            accessible: seat.accessible(),
+            obstructed: seat.obstructed(),
+        }
+    }
+}
+
+impl From<&SeatingRow> for SeatingRowResponse {
+    fn from(row: &SeatingRow) -> Self {
+        Self {
+            label: row.label().to_string(),
+            seats: row.seats().iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<&SeatingSection> for SeatingSectionResponse {
+    fn from(section: &SeatingSection) -> Self {
+        Self {
+            id: section.id(),
+            name: section.name().to_string(),
+            rows: section.rows().iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<SeatingChart> for SeatingChartResponse {
+    fn from(chart: SeatingChart) -> Self {
+        Self {
+            venue_id: chart.venue_id(),
+            total_seats: chart.seats().count(),
+            sections: chart.sections().iter().map(Into::into).collect(),
+        }
+    }
+}
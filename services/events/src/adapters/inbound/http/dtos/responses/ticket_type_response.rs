@@ -0,0 +1,74 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{entities::Admission, TicketType};
+
+use super::super::requests::{AdmissionKind, TicketVisibility};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TicketTypeResponse {
+    #[schema(example = "9b2f6c1e-8d4a-4e37-b5a1-0c2d3e4f5a6b")]
+    pub id: Uuid,
+    pub event_id: Uuid,
+    #[schema(example = "VIP")]
+    pub name: String,
+    #[schema(example = 12500)]
+    pub price_minor: i64,
+    #[schema(example = "USD")]
+    pub currency: String,
+    #[schema(example = 200)]
+    pub quantity: i32,
+    #[schema(example = 1)]
+    pub min_per_order: i32,
+    #[schema(example = 8)]
+    pub max_per_order: i32,
+    pub sales_start: Option<DateTime<Utc>>,
+    pub sales_end: Option<DateTime<Utc>>,
+    pub visibility: TicketVisibility,
+    pub admission: AdmissionKind,
+    /// Set for day passes.
+    pub day: Option<NaiveDate>,
+    /// Set for single-session tickets.
+    pub session_id: Option<Uuid>,
+    /// Seating-chart section the ticket type sells seats in.
+    pub section_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&TicketType> for TicketTypeResponse {
+    fn from(ticket_type: &TicketType) -> Self {
+        let (admission, day, session_id) = match ticket_type.admission() {
+            Admission::Event => (AdmissionKind::Event, None, None),
+            Admission::Day(day) => (AdmissionKind::Day, Some(day), None),
+            Admission::Session(id) => (AdmissionKind::Session, None, Some(id)),
+        };
+        Self {
+            id: ticket_type.id(),
+            event_id: ticket_type.event_id(),
+            name: ticket_type.name().to_string(),
+            price_minor: ticket_type.price_minor(),
+            currency: ticket_type.currency().to_string(),
+            quantity: ticket_type.quantity(),
+            min_per_order: ticket_type.min_per_order(),
+            max_per_order: ticket_type.max_per_order(),
+            sales_start: ticket_type.sales_start().copied(),
+            sales_end: ticket_type.sales_end().copied(),
+            visibility: ticket_type.visibility().into(),
+            admission,
+            day,
+            session_id,
+            section_id: ticket_type.section_id(),
+            created_at: *ticket_type.created_at(),
+            updated_at: *ticket_type.updated_at(),
+        }
+    }
+}
+
+impl From<TicketType> for TicketTypeResponse {
+    fn from(ticket_type: TicketType) -> Self {
+        (&ticket_type).into()
+    }
+}
@@ -0,0 +1,65 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{use_cases::CancelEventParams, CancelEventUseCase, CancelEventUseCaseError};
+
+use super::super::dtos::{CancelEventError, CancelEventRequest, GetEventResponse};
+
+#[utoipa::path(
+    post,
+    path = "/events/api/events/{id}/cancel",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = CancelEventRequest,
+    responses(
+        (status = 200, description = "Event cancelled; feeds publish it with STATUS:CANCELLED. Sales are stopped, orders refunded and ticket holders notified in the background; follow along at /cancellation", body = GetEventResponse),
+        (status = 400, description = "Missing or overlong reason", body = CancelEventError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = CancelEventError),
+        (status = 404, description = "Event not found", body = CancelEventError),
+        (status = 409, description = "Event is already cancelled", body = CancelEventError),
+        (status = 500, description = "Internal server error", body = CancelEventError)
+    ),
+    tag = "events"
+)]
+pub async fn cancel_event(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    body: web::Json<CancelEventRequest>,
+    use_case: web::Data<CancelEventUseCase>,
+) -> impl Responder {
+    let data: CancelEventParams = match (path.into_inner(), user, body.into_inner()).try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(CancelEventError {
+                error: e.to_string(),
+            });
+        }
+    };
+
+    match use_case.execute(data).await {
+        Ok(event) => {
+            let response: GetEventResponse = event.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(CancelEventUseCaseError::NotFound) => HttpResponse::NotFound().json(CancelEventError {
+            error: "Event not found".to_string(),
+        }),
+        Err(e @ CancelEventUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(CancelEventError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ CancelEventUseCaseError::AlreadyCancelled) => {
+            HttpResponse::Conflict().json(CancelEventError {
+                error: e.to_string(),
+            })
+        }
+        Err(CancelEventUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(CancelEventError { error: msg })
+        }
+    }
+}
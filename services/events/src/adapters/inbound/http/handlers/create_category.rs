@@ -0,0 +1,65 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+
+use crate::domain::{
+    use_cases::CreateCategoryParams, CreateCategoryUseCase, CreateCategoryUseCaseError,
+};
+
+use super::super::dtos::{CategoryResponse, CreateCategoryError, CreateCategoryRequest};
+
+#[utoipa::path(
+    post,
+    path = "/events/api/categories",
+    params(
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = CreateCategoryRequest,
+    responses(
+        (status = 201, description = "Category created successfully", body = CategoryResponse),
+        (status = 400, description = "Invalid request or unknown parent", body = CreateCategoryError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is not an administrator", body = CreateCategoryError),
+        (status = 409, description = "Slug already in use", body = CreateCategoryError),
+        (status = 500, description = "Internal server error", body = CreateCategoryError)
+    ),
+    tag = "categories"
+)]
+pub async fn create_category(
+    user: AuthenticatedUser,
+    body: web::Json<CreateCategoryRequest>,
+    use_case: web::Data<CreateCategoryUseCase>,
+) -> impl Responder {
+    let data: CreateCategoryParams = match (user, body.into_inner()).try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(CreateCategoryError {
+                error: e.to_string(),
+            });
+        }
+    };
+
+    match use_case.execute(data).await {
+        Ok(category) => {
+            let response: CategoryResponse = category.into();
+            HttpResponse::Created().json(response)
+        }
+        Err(e @ CreateCategoryUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(CreateCategoryError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ CreateCategoryUseCaseError::ParentNotFound) => {
+            HttpResponse::BadRequest().json(CreateCategoryError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ CreateCategoryUseCaseError::SlugTaken(_)) => {
+            HttpResponse::Conflict().json(CreateCategoryError {
+                error: e.to_string(),
+            })
+        }
+        Err(CreateCategoryUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(CreateCategoryError { error: msg })
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use actix_web::{web, HttpResponse, Responder};
-use shared::UseCase;
+use shared::{AuthenticatedUser, UseCase};
 
 use crate::domain::{CreateEventUseCase, CreateEventUseCaseError};
 
@@ -8,21 +8,27 @@ use super::super::dtos::{CreateEventError, CreateEventRequest, CreateEventRespon
 #[utoipa::path(
     post,
     path = "/events/api/events",
+    params(
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
     request_body = CreateEventRequest,
     responses(
         (status = 201, description = "Event created successfully", body = CreateEventResponse),
         (status = 400, description = "Invalid request", body = CreateEventError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is not an owner or manager of the organization", body = CreateEventError),
         (status = 500, description = "Internal server error", body = CreateEventError)
     ),
     tag = "events"
 )]
 pub async fn create_event(
+    user: AuthenticatedUser,
     body: web::Json<CreateEventRequest>,
     use_case: web::Data<CreateEventUseCase>,
 ) -> impl Responder {
     use crate::domain::use_cases::CreateEventParams;
 
-    let data: CreateEventParams = match body.into_inner().try_into() {
+    let mut data: CreateEventParams = match body.into_inner().try_into() {
         Ok(data) => data,
         Err(e) => {
             return HttpResponse::BadRequest().json(CreateEventError {
@@ -31,11 +37,32 @@ pub async fn create_event(
         }
     };
 
+    data.organizer_id = Some(user.id());
+
     match use_case.execute(data).await {
         Ok(event) => {
             let response: CreateEventResponse = event.into();
             HttpResponse::Created().json(response)
         }
+        Err(CreateEventUseCaseError::VenueNotFound) => {
+            HttpResponse::BadRequest().json(CreateEventError {
+                error: "Venue not found".to_string(),
+            })
+        }
+        Err(
+            e @ (CreateEventUseCaseError::OrganizerNotFound
+            | CreateEventUseCaseError::CategoryNotFound(_)
+            | CreateEventUseCaseError::InvalidSchedule(_)
+            | CreateEventUseCaseError::EventDateInPast
+            | CreateEventUseCaseError::InvalidPublication(_)),
+        ) => HttpResponse::BadRequest().json(CreateEventError {
+            error: e.to_string(),
+        }),
+        Err(e @ CreateEventUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(CreateEventError {
+                error: e.to_string(),
+            })
+        }
         Err(CreateEventUseCaseError::DatabaseError(msg)) => {
             HttpResponse::InternalServerError().json(CreateEventError { error: msg })
         }
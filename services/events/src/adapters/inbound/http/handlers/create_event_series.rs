@@ -0,0 +1,63 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+
+use crate::domain::{
+    use_cases::CreateEventSeriesParams, CreateEventSeriesUseCase, CreateEventSeriesUseCaseError,
+};
+
+use super::super::dtos::{CreateEventSeriesError, CreateEventSeriesRequest, EventSeriesResponse};
+
+#[utoipa::path(
+    post,
+    path = "/events/api/series",
+    params(
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = CreateEventSeriesRequest,
+    responses(
+        (status = 201, description = "Event series created; it belongs to the given organization, or to the caller when none is given", body = EventSeriesResponse),
+        (status = 400, description = "Invalid request or recurrence rule", body = CreateEventSeriesError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is not an owner or manager of the organization", body = CreateEventSeriesError),
+        (status = 404, description = "Venue not found", body = CreateEventSeriesError),
+        (status = 500, description = "Internal server error", body = CreateEventSeriesError)
+    ),
+    tag = "series"
+)]
+pub async fn create_event_series(
+    user: AuthenticatedUser,
+    body: web::Json<CreateEventSeriesRequest>,
+    use_case: web::Data<CreateEventSeriesUseCase>,
+) -> impl Responder {
+    let data: CreateEventSeriesParams = match (user, body.into_inner()).try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(CreateEventSeriesError {
+                error: e.to_string(),
+            });
+        }
+    };
+
+    match use_case.execute(data).await {
+        Ok(series) => {
+            let response: EventSeriesResponse = series.into();
+            HttpResponse::Created().json(response)
+        }
+        Err(CreateEventSeriesUseCaseError::InvalidRecurrence(msg)) => {
+            HttpResponse::BadRequest().json(CreateEventSeriesError { error: msg })
+        }
+        Err(CreateEventSeriesUseCaseError::VenueNotFound) => {
+            HttpResponse::NotFound().json(CreateEventSeriesError {
+                error: "Venue not found".to_string(),
+            })
+        }
+        Err(e @ CreateEventSeriesUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(CreateEventSeriesError {
+                error: e.to_string(),
+            })
+        }
+        Err(CreateEventSeriesUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(CreateEventSeriesError { error: msg })
+        }
+    }
+}
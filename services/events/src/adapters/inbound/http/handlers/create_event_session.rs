@@ -0,0 +1,81 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{CreateEventSessionUseCase, CreateEventSessionUseCaseError};
+
+use super::super::dtos::{CreateEventSessionError, EventSessionRequest, EventSessionResponse};
+
+#[utoipa::path(
+    post,
+    path = "/events/api/events/{id}/sessions",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = EventSessionRequest,
+    responses(
+        (status = 201, description = "Session created successfully", body = EventSessionResponse),
+        (status = 400, description = "Invalid request, or the session is not within the event", body = CreateEventSessionError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = CreateEventSessionError),
+        (status = 404, description = "Event not found", body = CreateEventSessionError),
+        (status = 409, description = "More tickets already admit to the session than its capacity", body = CreateEventSessionError),
+        (status = 500, description = "Internal server error", body = CreateEventSessionError)
+    ),
+    tag = "sessions"
+)]
+pub async fn create_event_session(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    body: web::Json<EventSessionRequest>,
+    use_case: web::Data<CreateEventSessionUseCase>,
+) -> impl Responder {
+    use crate::domain::use_cases::{CreateEventSessionParams, EventSessionParams};
+
+    let session: EventSessionParams = match body.into_inner().try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(CreateEventSessionError {
+                error: e.to_string(),
+            });
+        }
+    };
+    let data = CreateEventSessionParams {
+        event_id: path.into_inner(),
+        session,
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(session) => {
+            let response: EventSessionResponse = session.into();
+            HttpResponse::Created().json(response)
+        }
+        Err(e @ CreateEventSessionUseCaseError::EventNotFound) => {
+            HttpResponse::NotFound().json(CreateEventSessionError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ CreateEventSessionUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(CreateEventSessionError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (CreateEventSessionUseCaseError::InvalidSchedule(_)
+            | CreateEventSessionUseCaseError::EndsBeforeStart
+            | CreateEventSessionUseCaseError::OutsideEvent),
+        ) => HttpResponse::BadRequest().json(CreateEventSessionError {
+            error: e.to_string(),
+        }),
+        Err(e @ CreateEventSessionUseCaseError::CapacityBelowTickets(_)) => {
+            HttpResponse::Conflict().json(CreateEventSessionError {
+                error: e.to_string(),
+            })
+        }
+        Err(CreateEventSessionUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(CreateEventSessionError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+
+use crate::domain::{
+    use_cases::CreateEventTemplateParams, CreateEventTemplateUseCase,
+    CreateEventTemplateUseCaseError,
+};
+
+use super::super::dtos::{
+    CreateEventTemplateError, CreateEventTemplateRequest, EventTemplateResponse,
+};
+
+#[utoipa::path(
+    post,
+    path = "/events/api/templates",
+    params(
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = CreateEventTemplateRequest,
+    responses(
+        (status = 201, description = "Template saved with copies of the event's media; it belongs to the event's organization, or to the caller for events outside one", body = EventTemplateResponse),
+        (status = 400, description = "Invalid request or event not found", body = CreateEventTemplateError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = CreateEventTemplateError),
+        (status = 500, description = "Internal server error", body = CreateEventTemplateError)
+    ),
+    tag = "templates"
+)]
+pub async fn create_event_template(
+    user: AuthenticatedUser,
+    body: web::Json<CreateEventTemplateRequest>,
+    use_case: web::Data<CreateEventTemplateUseCase>,
+) -> impl Responder {
+    let data: CreateEventTemplateParams = match (user, body.into_inner()).try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(CreateEventTemplateError {
+                error: e.to_string(),
+            });
+        }
+    };
+
+    match use_case.execute(data).await {
+        Ok(template) => {
+            let response: EventTemplateResponse = template.into();
+            HttpResponse::Created().json(response)
+        }
+        Err(e @ CreateEventTemplateUseCaseError::EventNotFound) => {
+            HttpResponse::BadRequest().json(CreateEventTemplateError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ CreateEventTemplateUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(CreateEventTemplateError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ CreateEventTemplateUseCaseError::StorageError(_)) => {
+            HttpResponse::InternalServerError().json(CreateEventTemplateError {
+                error: e.to_string(),
+            })
+        }
+        Err(CreateEventTemplateUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(CreateEventTemplateError { error: msg })
+        }
+    }
+}
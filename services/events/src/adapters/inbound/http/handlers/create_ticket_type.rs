@@ -0,0 +1,76 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{CreateTicketTypeUseCase, CreateTicketTypeUseCaseError};
+
+use super::super::dtos::{CreateTicketTypeError, TicketTypeRequest, TicketTypeResponse};
+
+#[utoipa::path(
+    post,
+    path = "/events/api/events/{id}/ticket-types",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = TicketTypeRequest,
+    responses(
+        (status = 201, description = "Ticket type created successfully", body = TicketTypeResponse),
+        (status = 400, description = "Invalid request, or the admission is not part of the event or exceeds the session's capacity, or the section is not in the venue's seating chart or is missing at a venue with one", body = CreateTicketTypeError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = CreateTicketTypeError),
+        (status = 404, description = "Event not found", body = CreateTicketTypeError),
+        (status = 500, description = "Internal server error", body = CreateTicketTypeError)
+    ),
+    tag = "ticket-types"
+)]
+pub async fn create_ticket_type(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    body: web::Json<TicketTypeRequest>,
+    use_case: web::Data<CreateTicketTypeUseCase>,
+) -> impl Responder {
+    use crate::domain::use_cases::{CreateTicketTypeParams, TicketTypeParams};
+
+    let ticket_type: TicketTypeParams = match body.into_inner().try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(CreateTicketTypeError {
+                error: e.to_string(),
+            });
+        }
+    };
+    let data = CreateTicketTypeParams {
+        event_id: path.into_inner(),
+        ticket_type,
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(ticket_type) => {
+            let response: TicketTypeResponse = ticket_type.into();
+            HttpResponse::Created().json(response)
+        }
+        Err(CreateTicketTypeUseCaseError::EventNotFound) => {
+            HttpResponse::NotFound().json(CreateTicketTypeError {
+                error: "Event not found".to_string(),
+            })
+        }
+        Err(e @ CreateTicketTypeUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(CreateTicketTypeError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (CreateTicketTypeUseCaseError::SalesEndAfterEvent
+            | CreateTicketTypeUseCaseError::InvalidAdmission(_)
+            | CreateTicketTypeUseCaseError::UnknownSection
+            | CreateTicketTypeUseCaseError::SectionRequired),
+        ) => HttpResponse::BadRequest().json(CreateTicketTypeError {
+            error: e.to_string(),
+        }),
+        Err(CreateTicketTypeUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(CreateTicketTypeError { error: msg })
+        }
+    }
+}
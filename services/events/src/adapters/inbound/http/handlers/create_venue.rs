@@ -0,0 +1,54 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+
+use crate::domain::{CreateVenueUseCase, CreateVenueUseCaseError};
+
+use super::super::dtos::{CreateVenueError, CreateVenueRequest, CreateVenueResponse};
+
+#[utoipa::path(
+    post,
+    path = "/events/api/venues",
+    params(
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = CreateVenueRequest,
+    responses(
+        (status = 201, description = "Venue created; it belongs to the given organization, or to the caller when none is given", body = CreateVenueResponse),
+        (status = 400, description = "Invalid request", body = CreateVenueError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is not an owner or manager of the organization", body = CreateVenueError),
+        (status = 500, description = "Internal server error", body = CreateVenueError)
+    ),
+    tag = "venues"
+)]
+pub async fn create_venue(
+    user: AuthenticatedUser,
+    body: web::Json<CreateVenueRequest>,
+    use_case: web::Data<CreateVenueUseCase>,
+) -> impl Responder {
+    use crate::domain::use_cases::CreateVenueParams;
+
+    let data: CreateVenueParams = match (user, body.into_inner()).try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(CreateVenueError {
+                error: e.to_string(),
+            });
+        }
+    };
+
+    match use_case.execute(data).await {
+        Ok(venue) => {
+            let response: CreateVenueResponse = venue.into();
+            HttpResponse::Created().json(response)
+        }
+        Err(e @ CreateVenueUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(CreateVenueError {
+                error: e.to_string(),
+            })
+        }
+        Err(CreateVenueUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(CreateVenueError { error: msg })
+        }
+    }
+}
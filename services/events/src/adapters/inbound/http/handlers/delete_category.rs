@@ -0,0 +1,59 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::DeleteCategoryParams, DeleteCategoryUseCase, DeleteCategoryUseCaseError,
+};
+
+use super::super::dtos::DeleteCategoryError;
+
+#[utoipa::path(
+    delete,
+    path = "/events/api/categories/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Category ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 204, description = "Category deleted"),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is not an administrator", body = DeleteCategoryError),
+        (status = 404, description = "Category not found", body = DeleteCategoryError),
+        (status = 409, description = "Category still has subcategories or events filed under it", body = DeleteCategoryError),
+        (status = 500, description = "Internal server error", body = DeleteCategoryError)
+    ),
+    tag = "categories"
+)]
+pub async fn delete_category(
+    user: AuthenticatedUser,
+    path: web::Path<Uuid>,
+    use_case: web::Data<DeleteCategoryUseCase>,
+) -> impl Responder {
+    let data = DeleteCategoryParams {
+        id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(e @ DeleteCategoryUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(DeleteCategoryError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ DeleteCategoryUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(DeleteCategoryError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (DeleteCategoryUseCaseError::HasSubcategories | DeleteCategoryUseCaseError::InUse),
+        ) => HttpResponse::Conflict().json(DeleteCategoryError {
+            error: e.to_string(),
+        }),
+        Err(DeleteCategoryUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(DeleteCategoryError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::DeleteEventMediaParams, DeleteEventMediaUseCase, DeleteEventMediaUseCaseError,
+};
+
+use super::super::dtos::DeleteEventMediaError;
+
+#[utoipa::path(
+    delete,
+    path = "/events/api/events/{id}/media/{media_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("media_id" = Uuid, Path, description = "Media ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 204, description = "Media deleted"),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = DeleteEventMediaError),
+        (status = 404, description = "Event or media not found", body = DeleteEventMediaError),
+        (status = 500, description = "Internal server error", body = DeleteEventMediaError)
+    ),
+    tag = "events"
+)]
+pub async fn delete_event_media(
+    path: web::Path<(Uuid, Uuid)>,
+    user: AuthenticatedUser,
+    use_case: web::Data<DeleteEventMediaUseCase>,
+) -> impl Responder {
+    let (event_id, media_id) = path.into_inner();
+    let data = DeleteEventMediaParams {
+        event_id,
+        media_id,
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(DeleteEventMediaUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(DeleteEventMediaError {
+                error: "Media not found".to_string(),
+            })
+        }
+        Err(e @ DeleteEventMediaUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(DeleteEventMediaError {
+                error: e.to_string(),
+            })
+        }
+        Err(DeleteEventMediaUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(DeleteEventMediaError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::DeleteEventSessionParams, DeleteEventSessionUseCase, DeleteEventSessionUseCaseError,
+};
+
+use super::super::dtos::DeleteEventSessionError;
+
+#[utoipa::path(
+    delete,
+    path = "/events/api/events/{id}/sessions/{session_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("session_id" = Uuid, Path, description = "Session ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 204, description = "Session deleted"),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = DeleteEventSessionError),
+        (status = 404, description = "Event or session not found", body = DeleteEventSessionError),
+        (status = 409, description = "Ticket types still admit to the session", body = DeleteEventSessionError),
+        (status = 500, description = "Internal server error", body = DeleteEventSessionError)
+    ),
+    tag = "sessions"
+)]
+pub async fn delete_event_session(
+    path: web::Path<(Uuid, Uuid)>,
+    user: AuthenticatedUser,
+    use_case: web::Data<DeleteEventSessionUseCase>,
+) -> impl Responder {
+    let (event_id, session_id) = path.into_inner();
+    let data = DeleteEventSessionParams {
+        event_id,
+        session_id,
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(e @ DeleteEventSessionUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(DeleteEventSessionError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ DeleteEventSessionUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(DeleteEventSessionError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ DeleteEventSessionUseCaseError::HasTicketTypes) => {
+            HttpResponse::Conflict().json(DeleteEventSessionError {
+                error: e.to_string(),
+            })
+        }
+        Err(DeleteEventSessionUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(DeleteEventSessionError { error: msg })
+        }
+    }
+}
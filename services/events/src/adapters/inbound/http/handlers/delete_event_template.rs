@@ -0,0 +1,54 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::DeleteEventTemplateParams, DeleteEventTemplateUseCase,
+    DeleteEventTemplateUseCaseError,
+};
+
+use super::super::dtos::DeleteEventTemplateError;
+
+#[utoipa::path(
+    delete,
+    path = "/events/api/templates/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Template ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 204, description = "Template and its media deleted; events created from it are kept"),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller neither saved the template nor owns or manages its organization", body = DeleteEventTemplateError),
+        (status = 404, description = "Template not found", body = DeleteEventTemplateError),
+        (status = 500, description = "Internal server error", body = DeleteEventTemplateError)
+    ),
+    tag = "templates"
+)]
+pub async fn delete_event_template(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<DeleteEventTemplateUseCase>,
+) -> impl Responder {
+    let data = DeleteEventTemplateParams {
+        id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(e @ DeleteEventTemplateUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(DeleteEventTemplateError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ DeleteEventTemplateUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(DeleteEventTemplateError {
+                error: e.to_string(),
+            })
+        }
+        Err(DeleteEventTemplateUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(DeleteEventTemplateError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::Locale, use_cases::DeleteEventTranslationParams, DeleteEventTranslationUseCase,
+    DeleteEventTranslationUseCaseError,
+};
+
+use super::super::dtos::DeleteEventTranslationError;
+
+#[utoipa::path(
+    delete,
+    path = "/events/api/events/{id}/translations/{locale}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("locale" = String, Path, description = "Language tag such as `de` or `pt-BR`"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 204, description = "Translation deleted"),
+        (status = 400, description = "Invalid locale", body = DeleteEventTranslationError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = DeleteEventTranslationError),
+        (status = 404, description = "Event or translation not found", body = DeleteEventTranslationError),
+        (status = 500, description = "Internal server error", body = DeleteEventTranslationError)
+    ),
+    tag = "translations"
+)]
+pub async fn delete_event_translation(
+    path: web::Path<(Uuid, String)>,
+    user: AuthenticatedUser,
+    use_case: web::Data<DeleteEventTranslationUseCase>,
+) -> impl Responder {
+    let (event_id, locale) = path.into_inner();
+    let locale = match Locale::parse(&locale) {
+        Ok(locale) => locale,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(DeleteEventTranslationError {
+                error: e.to_string(),
+            });
+        }
+    };
+    let data = DeleteEventTranslationParams {
+        event_id,
+        locale,
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(
+            e @ (DeleteEventTranslationUseCaseError::EventNotFound
+            | DeleteEventTranslationUseCaseError::NotFound),
+        ) => HttpResponse::NotFound().json(DeleteEventTranslationError {
+            error: e.to_string(),
+        }),
+        Err(e @ DeleteEventTranslationUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(DeleteEventTranslationError {
+                error: e.to_string(),
+            })
+        }
+        Err(DeleteEventTranslationUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(DeleteEventTranslationError { error: msg })
+        }
+    }
+}
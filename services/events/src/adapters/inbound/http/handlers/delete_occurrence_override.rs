@@ -0,0 +1,59 @@
+use actix_web::{web, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::DeleteOccurrenceOverrideParams, DeleteOccurrenceOverrideUseCase,
+    DeleteOccurrenceOverrideUseCaseError,
+};
+
+use super::super::dtos::DeleteOccurrenceOverrideError;
+
+#[utoipa::path(
+    delete,
+    path = "/events/api/series/{id}/occurrences/{occurrence_start}",
+    params(
+        ("id" = Uuid, Path, description = "Event series ID"),
+        ("occurrence_start" = DateTime<Utc>, Path, description = "Original start of the occurrence (RFC 3339)"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 204, description = "Override removed; the occurrence follows the series again"),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the series' organizer nor an owner or manager of its organization", body = DeleteOccurrenceOverrideError),
+        (status = 404, description = "Series or override not found", body = DeleteOccurrenceOverrideError),
+        (status = 500, description = "Internal server error", body = DeleteOccurrenceOverrideError)
+    ),
+    tag = "series"
+)]
+pub async fn delete_occurrence_override(
+    user: AuthenticatedUser,
+    path: web::Path<(Uuid, DateTime<Utc>)>,
+    use_case: web::Data<DeleteOccurrenceOverrideUseCase>,
+) -> impl Responder {
+    let (series_id, occurrence_start) = path.into_inner();
+    let data = DeleteOccurrenceOverrideParams {
+        series_id,
+        occurrence_start,
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(
+            e @ (DeleteOccurrenceOverrideUseCaseError::SeriesNotFound
+            | DeleteOccurrenceOverrideUseCaseError::NotFound),
+        ) => HttpResponse::NotFound().json(DeleteOccurrenceOverrideError {
+            error: e.to_string(),
+        }),
+        Err(e @ DeleteOccurrenceOverrideUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(DeleteOccurrenceOverrideError {
+                error: e.to_string(),
+            })
+        }
+        Err(DeleteOccurrenceOverrideUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(DeleteOccurrenceOverrideError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::DeleteTicketTypeParams, DeleteTicketTypeUseCase, DeleteTicketTypeUseCaseError,
+};
+
+use super::super::dtos::DeleteTicketTypeError;
+
+#[utoipa::path(
+    delete,
+    path = "/events/api/events/{id}/ticket-types/{ticket_type_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("ticket_type_id" = Uuid, Path, description = "Ticket type ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 204, description = "Ticket type deleted"),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = DeleteTicketTypeError),
+        (status = 404, description = "Event or ticket type not found", body = DeleteTicketTypeError),
+        (status = 500, description = "Internal server error", body = DeleteTicketTypeError)
+    ),
+    tag = "ticket-types"
+)]
+pub async fn delete_ticket_type(
+    path: web::Path<(Uuid, Uuid)>,
+    user: AuthenticatedUser,
+    use_case: web::Data<DeleteTicketTypeUseCase>,
+) -> impl Responder {
+    let (event_id, ticket_type_id) = path.into_inner();
+    let data = DeleteTicketTypeParams {
+        event_id,
+        ticket_type_id,
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(DeleteTicketTypeUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(DeleteTicketTypeError {
+                error: "Ticket type not found".to_string(),
+            })
+        }
+        Err(e @ DeleteTicketTypeUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(DeleteTicketTypeError {
+                error: e.to_string(),
+            })
+        }
+        Err(DeleteTicketTypeUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(DeleteTicketTypeError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::DuplicateEventParams, DuplicateEventUseCase, DuplicateEventUseCaseError,
+};
+
+use super::super::dtos::{DuplicateEventError, DuplicateEventRequest, GetEventResponse};
+
+#[utoipa::path(
+    post,
+    path = "/events/api/events/{id}/duplicate",
+    params(
+        ("id" = Uuid, Path, description = "Event to copy"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = DuplicateEventRequest,
+    responses(
+        (status = 201, description = "Unpublished copy created with the event's ticket types, categories, tags and media; publish it with POST /events/api/events/{id}/publish or schedule it with publish_at", body = GetEventResponse),
+        (status = 400, description = "Invalid request, venue not found, or the copy would start in the past", body = DuplicateEventError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = DuplicateEventError),
+        (status = 404, description = "Event not found", body = DuplicateEventError),
+        (status = 500, description = "Internal server error", body = DuplicateEventError)
+    ),
+    tag = "events"
+)]
+pub async fn duplicate_event(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    body: Option<web::Json<DuplicateEventRequest>>,
+    use_case: web::Data<DuplicateEventUseCase>,
+) -> impl Responder {
+    let req = body.map(web::Json::into_inner).unwrap_or_default();
+    let data: DuplicateEventParams = match (path.into_inner(), user, req).try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(DuplicateEventError {
+                error: e.to_string(),
+            });
+        }
+    };
+
+    match use_case.execute(data).await {
+        Ok(event) => {
+            let response: GetEventResponse = event.into();
+            HttpResponse::Created().json(response)
+        }
+        Err(e @ DuplicateEventUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(DuplicateEventError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ DuplicateEventUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(DuplicateEventError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (DuplicateEventUseCaseError::InvalidSchedule(_)
+            | DuplicateEventUseCaseError::EventDateInPast
+            | DuplicateEventUseCaseError::VenueNotFound),
+        ) => HttpResponse::BadRequest().json(DuplicateEventError {
+            error: e.to_string(),
+        }),
+        Err(e @ DuplicateEventUseCaseError::StorageError(_)) => HttpResponse::InternalServerError()
+            .json(DuplicateEventError {
+                error: e.to_string(),
+            }),
+        Err(DuplicateEventUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(DuplicateEventError { error: msg })
+        }
+    }
+}
@@ -1,34 +1,60 @@
-use actix_web::{web, HttpResponse, Responder};
-use shared::UseCase;
+use actix_web::{
+    http::header::{self, ETag},
+    web, HttpRequest, HttpResponse, Responder,
+};
+use shared::{entity_tag, is_not_modified, AcceptLanguage, AuthenticatedUser, UseCase};
 use uuid::Uuid;
 
-use crate::domain::{GetEventUseCase, GetEventUseCaseError};
+use crate::domain::{use_cases::GetEventParams, GetEventUseCase, GetEventUseCaseError};
 
 use super::super::dtos::{GetEventError, GetEventResponse};
+use super::super::renderers::render_event_ics;
 
 #[utoipa::path(
     get,
     path = "/events/api/events/{id}",
     params(
-        ("id" = Uuid, Path, description = "Event ID")
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("X-User-Id" = Option<Uuid>, Header, description = "Caller, set by the API gateway; lets the people running an event see it before it is published"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a copy the client already has"),
+        ("Accept-Language" = Option<String>, Header, description = "Preferred languages for the title and description; the event's default locale is used when none of them is available")
     ),
     responses(
-        (status = 200, description = "Event found", body = GetEventResponse),
-        (status = 404, description = "Event not found", body = GetEventError),
+        (status = 200, description = "Event found; its version is in the ETag header and the language of its title and description in Content-Language", body = GetEventResponse),
+        (status = 304, description = "The client's copy is current"),
+        (status = 404, description = "Event not found or not published yet", body = GetEventError),
         (status = 500, description = "Internal server error", body = GetEventError)
     ),
     tag = "events"
 )]
 pub async fn get_event(
+    req: HttpRequest,
     path: web::Path<Uuid>,
+    user: Option<AuthenticatedUser>,
+    languages: AcceptLanguage,
     use_case: web::Data<GetEventUseCase>,
 ) -> impl Responder {
     let id = path.into_inner();
+    let params = GetEventParams {
+        id,
+        requested_by: user.map(|user| user.id()),
+        languages: languages.ranges().to_vec(),
+    };
 
-    match use_case.execute(id).await {
+    match use_case.execute(params).await {
+        Ok(event) if is_not_modified(&req, event.version()) => HttpResponse::NotModified()
+            .insert_header(ETag(entity_tag(event.version())))
+            .insert_header((header::VARY, "Accept-Language"))
+            .finish(),
         Ok(event) => {
+            let etag = ETag(entity_tag(event.version()));
+            let locale = event.locale().to_string();
             let response: GetEventResponse = event.into();
-            HttpResponse::Ok().json(response)
+            HttpResponse::Ok()
+                .insert_header(etag)
+                .insert_header((header::CONTENT_LANGUAGE, locale))
+                .insert_header((header::VARY, "Accept-Language"))
+                .json(response)
         }
         Err(GetEventUseCaseError::NotFound) => HttpResponse::NotFound().json(GetEventError {
             error: "Event not found".to_string(),
@@ -38,3 +64,50 @@ pub async fn get_event(
         }
     }
 }
+
+#[utoipa::path(
+    get,
+    path = "/events/api/events/{id}.ics",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("X-User-Id" = Option<Uuid>, Header, description = "Caller, set by the API gateway; lets the people running an event see it before it is published"),
+        ("Accept-Language" = Option<String>, Header, description = "Preferred languages for the summary and description")
+    ),
+    responses(
+        (status = 200, description = "Event as an iCalendar (RFC 5545) object", body = String, content_type = "text/calendar"),
+        (status = 404, description = "Event not found or not published yet", body = GetEventError),
+        (status = 500, description = "Internal server error", body = GetEventError)
+    ),
+    tag = "calendars"
+)]
+pub async fn get_event_ics(
+    path: web::Path<Uuid>,
+    user: Option<AuthenticatedUser>,
+    languages: AcceptLanguage,
+    use_case: web::Data<GetEventUseCase>,
+) -> impl Responder {
+    let id = path.into_inner();
+    let params = GetEventParams {
+        id,
+        requested_by: user.map(|user| user.id()),
+        languages: languages.ranges().to_vec(),
+    };
+
+    match use_case.execute(params).await {
+        Ok(event) => HttpResponse::Ok()
+            .content_type("text/calendar; charset=utf-8")
+            .insert_header((header::CONTENT_LANGUAGE, event.locale().to_string()))
+            .insert_header((header::VARY, "Accept-Language"))
+            .insert_header((
+                "Content-Disposition",
+                format!("attachment; filename=\"{}.ics\"", id),
+            ))
+            .body(render_event_ics(&event)),
+        Err(GetEventUseCaseError::NotFound) => HttpResponse::NotFound().json(GetEventError {
+            error: "Event not found".to_string(),
+        }),
+        Err(GetEventUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetEventError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::GetEventCancellationParams, GetEventCancellationUseCase,
+    GetEventCancellationUseCaseError,
+};
+
+use super::super::dtos::{EventCancellationResponse, GetEventCancellationError};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/events/{id}/cancellation",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "Progress of stopping sales, refunding orders and notifying ticket holders", body = EventCancellationResponse),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor a member of the organization", body = GetEventCancellationError),
+        (status = 404, description = "Event not found, or not cancelled", body = GetEventCancellationError),
+        (status = 500, description = "Internal server error", body = GetEventCancellationError)
+    ),
+    tag = "events"
+)]
+pub async fn get_event_cancellation(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<GetEventCancellationUseCase>,
+) -> impl Responder {
+    let data = GetEventCancellationParams {
+        event_id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(cancellation) => {
+            let response: EventCancellationResponse = cancellation.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(
+            e @ (GetEventCancellationUseCaseError::EventNotFound
+            | GetEventCancellationUseCaseError::NotCancelled),
+        ) => HttpResponse::NotFound().json(GetEventCancellationError {
+            error: e.to_string(),
+        }),
+        Err(e @ GetEventCancellationUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(GetEventCancellationError {
+                error: e.to_string(),
+            })
+        }
+        Err(GetEventCancellationUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetEventCancellationError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+
+use crate::domain::{
+    use_cases::GetEventFacetsParams, GetEventFacetsUseCase, GetEventFacetsUseCaseError,
+};
+
+use super::super::dtos::{
+    CategoryFacetResponse, EventFilterQuery, GetEventFacetsError, ListingWindow, ListingWindowQuery,
+};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/events/facets",
+    params(ListingWindowQuery, EventFilterQuery),
+    responses(
+        (status = 200, description = "Event counts per category for the window, matching the tags and area; `category_id` is ignored", body = [CategoryFacetResponse]),
+        (status = 400, description = "Invalid window or location", body = GetEventFacetsError),
+        (status = 500, description = "Internal server error", body = GetEventFacetsError)
+    ),
+    tag = "events"
+)]
+pub async fn get_event_facets(
+    window: web::Query<ListingWindowQuery>,
+    filter: web::Query<EventFilterQuery>,
+    use_case: web::Data<GetEventFacetsUseCase>,
+) -> impl Responder {
+    let window: ListingWindow = match window.into_inner().try_into() {
+        Ok(window) => window,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(GetEventFacetsError {
+                error: e.to_string(),
+            });
+        }
+    };
+    let near = match filter.near() {
+        Ok(near) => near,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(GetEventFacetsError {
+                error: e.to_string(),
+            });
+        }
+    };
+    let data = GetEventFacetsParams {
+        from: window.from,
+        to: window.to,
+        tags: filter.tags(),
+        near,
+    };
+
+    match use_case.execute(data).await {
+        Ok(facets) => {
+            let response: Vec<CategoryFacetResponse> = facets.into_iter().map(Into::into).collect();
+            HttpResponse::Ok().json(response)
+        }
+        Err(GetEventFacetsUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetEventFacetsError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::{GetEventHistoryParams, GetEventRevisionParams},
+    GetEventHistoryUseCase, GetEventHistoryUseCaseError, GetEventRevisionUseCase,
+    GetEventRevisionUseCaseError,
+};
+
+use super::super::dtos::{
+    EventRevisionDetailResponse, EventRevisionResponse, GetEventHistoryError, GetEventRevisionError,
+};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/events/{id}/history",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "Recorded revisions, oldest first. Only changes to the event's own fields are recorded, not to its ticket types, sessions, media or translations", body = Vec<EventRevisionResponse>),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor a member of the organization", body = GetEventHistoryError),
+        (status = 404, description = "Event not found", body = GetEventHistoryError),
+        (status = 500, description = "Internal server error", body = GetEventHistoryError)
+    ),
+    tag = "events"
+)]
+pub async fn get_event_history(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<GetEventHistoryUseCase>,
+) -> impl Responder {
+    let data = GetEventHistoryParams {
+        event_id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(revisions) => HttpResponse::Ok().json(
+            revisions
+                .iter()
+                .map(EventRevisionResponse::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(GetEventHistoryUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(GetEventHistoryError {
+                error: "Event not found".to_string(),
+            })
+        }
+        Err(e @ GetEventHistoryUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(GetEventHistoryError {
+                error: e.to_string(),
+            })
+        }
+        Err(GetEventHistoryUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetEventHistoryError { error: msg })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/api/events/{id}/history/{revision}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("revision" = i32, Path, description = "Revision number, as listed in the history"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "The revision and the event as of it", body = EventRevisionDetailResponse),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor a member of the organization", body = GetEventRevisionError),
+        (status = 404, description = "Event or revision not found", body = GetEventRevisionError),
+        (status = 500, description = "Internal server error", body = GetEventRevisionError)
+    ),
+    tag = "events"
+)]
+pub async fn get_event_revision(
+    path: web::Path<(Uuid, i32)>,
+    user: AuthenticatedUser,
+    use_case: web::Data<GetEventRevisionUseCase>,
+) -> impl Responder {
+    let (event_id, revision) = path.into_inner();
+    let data = GetEventRevisionParams {
+        event_id,
+        revision,
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(revision) => HttpResponse::Ok().json(EventRevisionDetailResponse::from(revision)),
+        Err(GetEventRevisionUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(GetEventRevisionError {
+                error: "Event not found".to_string(),
+            })
+        }
+        Err(e @ GetEventRevisionUseCaseError::RevisionNotFound(_)) => HttpResponse::NotFound()
+            .json(GetEventRevisionError {
+                error: e.to_string(),
+            }),
+        Err(e @ GetEventRevisionUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(GetEventRevisionError {
+                error: e.to_string(),
+            })
+        }
+        Err(GetEventRevisionUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetEventRevisionError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{use_cases::GetEventParams, GetEventUseCase, GetEventUseCaseError};
+
+use super::super::dtos::{EventScheduleResponse, GetEventScheduleError};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/events/{id}/schedule",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("X-User-Id" = Option<Uuid>, Header, description = "Caller, set by the API gateway; lets the people running an event see it before it is published")
+    ),
+    responses(
+        (status = 200, description = "The event's sessions grouped by local day, then by stage", body = EventScheduleResponse),
+        (status = 404, description = "Event not found or not published yet", body = GetEventScheduleError),
+        (status = 500, description = "Internal server error", body = GetEventScheduleError)
+    ),
+    tag = "sessions"
+)]
+pub async fn get_event_schedule(
+    path: web::Path<Uuid>,
+    user: Option<AuthenticatedUser>,
+    use_case: web::Data<GetEventUseCase>,
+) -> impl Responder {
+    let params = GetEventParams {
+        id: path.into_inner(),
+        requested_by: user.map(|user| user.id()),
+        languages: Vec::new(),
+    };
+
+    match use_case.execute(params).await {
+        Ok(event) => HttpResponse::Ok().json(EventScheduleResponse::from(&event)),
+        Err(GetEventUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(GetEventScheduleError {
+                error: "Event not found".to_string(),
+            })
+        }
+        Err(GetEventUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetEventScheduleError { error: msg })
+        }
+    }
+}
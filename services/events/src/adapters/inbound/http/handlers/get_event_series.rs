@@ -0,0 +1,40 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{GetEventSeriesUseCase, GetEventSeriesUseCaseError};
+
+use super::super::dtos::{EventSeriesResponse, GetEventSeriesError};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/series/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Event series ID")
+    ),
+    responses(
+        (status = 200, description = "Event series found", body = EventSeriesResponse),
+        (status = 404, description = "Event series not found", body = GetEventSeriesError),
+        (status = 500, description = "Internal server error", body = GetEventSeriesError)
+    ),
+    tag = "series"
+)]
+pub async fn get_event_series(
+    path: web::Path<Uuid>,
+    use_case: web::Data<GetEventSeriesUseCase>,
+) -> impl Responder {
+    match use_case.execute(path.into_inner()).await {
+        Ok(series) => {
+            let response: EventSeriesResponse = series.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(GetEventSeriesUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(GetEventSeriesError {
+                error: "Event series not found".to_string(),
+            })
+        }
+        Err(GetEventSeriesUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetEventSeriesError { error: msg })
+        }
+    }
+}
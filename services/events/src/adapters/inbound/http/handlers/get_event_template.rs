@@ -0,0 +1,56 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::GetEventTemplateParams, GetEventTemplateUseCase, GetEventTemplateUseCaseError,
+};
+
+use super::super::dtos::{EventTemplateResponse, GetEventTemplateError};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/templates/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Template ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "Template found", body = EventTemplateResponse),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller neither saved the template nor owns or manages its organization", body = GetEventTemplateError),
+        (status = 404, description = "Template not found", body = GetEventTemplateError),
+        (status = 500, description = "Internal server error", body = GetEventTemplateError)
+    ),
+    tag = "templates"
+)]
+pub async fn get_event_template(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<GetEventTemplateUseCase>,
+) -> impl Responder {
+    let data = GetEventTemplateParams {
+        id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(template) => {
+            let response: EventTemplateResponse = template.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(e @ GetEventTemplateUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(GetEventTemplateError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ GetEventTemplateUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(GetEventTemplateError {
+                error: e.to_string(),
+            })
+        }
+        Err(GetEventTemplateUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetEventTemplateError { error: msg })
+        }
+    }
+}
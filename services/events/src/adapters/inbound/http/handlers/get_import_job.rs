@@ -0,0 +1,54 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{use_cases::GetImportJobParams, GetImportJobUseCase, GetImportJobUseCaseError};
+
+use super::super::dtos::{GetImportJobError, ImportJobResponse};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/imports/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Import job ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "Import job status and per-row results", body = ImportJobResponse),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller isn't the organizer the events were imported for", body = GetImportJobError),
+        (status = 404, description = "Import job not found", body = GetImportJobError),
+        (status = 500, description = "Internal server error", body = GetImportJobError)
+    ),
+    tag = "imports"
+)]
+pub async fn get_import_job(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<GetImportJobUseCase>,
+) -> impl Responder {
+    let data = GetImportJobParams {
+        id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(job) => {
+            let response: ImportJobResponse = job.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(GetImportJobUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(GetImportJobError {
+                error: "Import job not found".to_string(),
+            })
+        }
+        Err(e @ GetImportJobUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(GetImportJobError {
+                error: e.to_string(),
+            })
+        }
+        Err(GetImportJobUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetImportJobError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{GetOrganizerCalendarUseCase, GetOrganizerCalendarUseCaseError};
+
+use super::super::dtos::GetOrganizerCalendarError;
+use super::super::renderers::render_calendar_feed_ics;
+
+#[utoipa::path(
+    get,
+    path = "/events/api/organizers/{id}/events.ics",
+    params(
+        ("id" = Uuid, Path, description = "Organizer ID")
+    ),
+    responses(
+        (status = 200, description = "Subscribable iCalendar feed of the organizer's events, including cancellations", body = String, content_type = "text/calendar"),
+        (status = 500, description = "Internal server error", body = GetOrganizerCalendarError)
+    ),
+    tag = "calendars"
+)]
+pub async fn get_organizer_calendar(
+    path: web::Path<Uuid>,
+    use_case: web::Data<GetOrganizerCalendarUseCase>,
+) -> impl Responder {
+    match use_case.execute(path.into_inner()).await {
+        Ok(feed) => HttpResponse::Ok()
+            .content_type("text/calendar; charset=utf-8")
+            .body(render_calendar_feed_ics(&feed)),
+        Err(GetOrganizerCalendarUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetOrganizerCalendarError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{GetSeatMapUseCase, GetSeatMapUseCaseError};
+
+use super::super::dtos::{GetSeatMapError, SeatMapResponse};
+use super::super::renderers::render_seat_map_svg;
+
+fn error_response(error: GetSeatMapUseCaseError) -> HttpResponse {
+    match error {
+        GetSeatMapUseCaseError::EventNotFound => HttpResponse::NotFound().json(GetSeatMapError {
+            error: "Event not found".to_string(),
+        }),
+        GetSeatMapUseCaseError::NoSeatingChart => HttpResponse::NotFound().json(GetSeatMapError {
+            error: "Event has no seating chart".to_string(),
+        }),
+        GetSeatMapUseCaseError::DatabaseError(msg) => {
+            HttpResponse::InternalServerError().json(GetSeatMapError { error: msg })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/api/events/{id}/seat-map",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Seat availability map", body = SeatMapResponse),
+        (status = 404, description = "Event not found or has no seating chart", body = GetSeatMapError),
+        (status = 500, description = "Internal server error", body = GetSeatMapError)
+    ),
+    tag = "events"
+)]
+pub async fn get_seat_map(
+    path: web::Path<Uuid>,
+    use_case: web::Data<GetSeatMapUseCase>,
+) -> impl Responder {
+    let event_id = path.into_inner();
+
+    match use_case.execute(event_id).await {
+        Ok(seat_map) => {
+            let response: SeatMapResponse = seat_map.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/events/api/events/{id}/seat-map.svg",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Seat availability map rendered as SVG", body = String, content_type = "image/svg+xml"),
+        (status = 404, description = "Event not found or has no seating chart", body = GetSeatMapError),
+        (status = 500, description = "Internal server error", body = GetSeatMapError)
+    ),
+    tag = "events"
+)]
+pub async fn get_seat_map_svg(
+    path: web::Path<Uuid>,
+    use_case: web::Data<GetSeatMapUseCase>,
+) -> impl Responder {
+    let event_id = path.into_inner();
+
+    match use_case.execute(event_id).await {
+        Ok(seat_map) => HttpResponse::Ok()
+            .content_type("image/svg+xml")
+            .body(render_seat_map_svg(&seat_map)),
+        Err(e) => error_response(e),
+    }
+}
@@ -0,0 +1,42 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{GetSeatingChartUseCase, GetSeatingChartUseCaseError};
+
+use super::super::dtos::{GetSeatingChartError, SeatingChartResponse};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/venues/{id}/seating-chart",
+    params(
+        ("id" = Uuid, Path, description = "Venue ID")
+    ),
+    responses(
+        (status = 200, description = "Seating chart found", body = SeatingChartResponse),
+        (status = 404, description = "Venue not found", body = GetSeatingChartError),
+        (status = 500, description = "Internal server error", body = GetSeatingChartError)
+    ),
+    tag = "venues"
+)]
+pub async fn get_seating_chart(
+    path: web::Path<Uuid>,
+    use_case: web::Data<GetSeatingChartUseCase>,
+) -> impl Responder {
+    let venue_id = path.into_inner();
+
+    match use_case.execute(venue_id).await {
+        Ok(chart) => {
+            let response: SeatingChartResponse = chart.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(GetSeatingChartUseCaseError::VenueNotFound) => {
+            HttpResponse::NotFound().json(GetSeatingChartError {
+                error: "Venue not found".to_string(),
+            })
+        }
+        Err(GetSeatingChartUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetSeatingChartError { error: msg })
+        }
+    }
+}
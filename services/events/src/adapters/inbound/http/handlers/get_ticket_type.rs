@@ -0,0 +1,41 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{GetTicketTypeUseCase, GetTicketTypeUseCaseError};
+
+use super::super::dtos::{GetTicketTypeError, TicketTypeResponse};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/events/{id}/ticket-types/{ticket_type_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("ticket_type_id" = Uuid, Path, description = "Ticket type ID")
+    ),
+    responses(
+        (status = 200, description = "Ticket type found", body = TicketTypeResponse),
+        (status = 404, description = "Ticket type not found", body = GetTicketTypeError),
+        (status = 500, description = "Internal server error", body = GetTicketTypeError)
+    ),
+    tag = "ticket-types"
+)]
+pub async fn get_ticket_type(
+    path: web::Path<(Uuid, Uuid)>,
+    use_case: web::Data<GetTicketTypeUseCase>,
+) -> impl Responder {
+    match use_case.execute(path.into_inner()).await {
+        Ok(ticket_type) => {
+            let response: TicketTypeResponse = ticket_type.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(GetTicketTypeUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(GetTicketTypeError {
+                error: "Ticket type not found".to_string(),
+            })
+        }
+        Err(GetTicketTypeUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetTicketTypeError { error: msg })
+        }
+    }
+}
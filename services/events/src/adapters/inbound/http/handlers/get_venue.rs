@@ -0,0 +1,40 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{GetVenueUseCase, GetVenueUseCaseError};
+
+use super::super::dtos::{GetVenueError, GetVenueResponse};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/venues/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Venue ID")
+    ),
+    responses(
+        (status = 200, description = "Venue found", body = GetVenueResponse),
+        (status = 404, description = "Venue not found", body = GetVenueError),
+        (status = 500, description = "Internal server error", body = GetVenueError)
+    ),
+    tag = "venues"
+)]
+pub async fn get_venue(
+    path: web::Path<Uuid>,
+    use_case: web::Data<GetVenueUseCase>,
+) -> impl Responder {
+    let id = path.into_inner();
+
+    match use_case.execute(id).await {
+        Ok(venue) => {
+            let response: GetVenueResponse = venue.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(GetVenueUseCaseError::NotFound) => HttpResponse::NotFound().json(GetVenueError {
+            error: "Venue not found".to_string(),
+        }),
+        Err(GetVenueUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetVenueError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{GetVenueCalendarUseCase, GetVenueCalendarUseCaseError};
+
+use super::super::dtos::GetVenueCalendarError;
+use super::super::renderers::render_calendar_feed_ics;
+
+#[utoipa::path(
+    get,
+    path = "/events/api/venues/{id}/events.ics",
+    params(
+        ("id" = Uuid, Path, description = "Venue ID")
+    ),
+    responses(
+        (status = 200, description = "Subscribable iCalendar feed of the venue's events, including cancellations", body = String, content_type = "text/calendar"),
+        (status = 404, description = "Venue not found", body = GetVenueCalendarError),
+        (status = 500, description = "Internal server error", body = GetVenueCalendarError)
+    ),
+    tag = "calendars"
+)]
+pub async fn get_venue_calendar(
+    path: web::Path<Uuid>,
+    use_case: web::Data<GetVenueCalendarUseCase>,
+) -> impl Responder {
+    match use_case.execute(path.into_inner()).await {
+        Ok(feed) => HttpResponse::Ok()
+            .content_type("text/calendar; charset=utf-8")
+            .body(render_calendar_feed_ics(&feed)),
+        Err(GetVenueCalendarUseCaseError::VenueNotFound) => {
+            HttpResponse::NotFound().json(GetVenueCalendarError {
+                error: "Venue not found".to_string(),
+            })
+        }
+        Err(GetVenueCalendarUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetVenueCalendarError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use actix_web::{http::header, web, HttpRequest, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+
+use crate::adapters::inbound::import::{parse_entries, ImportFormat};
+use crate::domain::{use_cases::ImportEventsParams, ImportEventsUseCase, ImportEventsUseCaseError};
+
+use super::super::dtos::{ImportEventsError, ImportEventsQuery, ImportJobResponse};
+
+/// Files with more entries than this are imported by a background job.
+const BACKGROUND_IMPORT_THRESHOLD: usize = 100;
+
+#[utoipa::path(
+    post,
+    path = "/events/api/imports",
+    params(
+        ImportEventsQuery,
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway; owns the imported events")
+    ),
+    request_body(content = String, description = "iCalendar or CSV file", content_type = "text/calendar"),
+    responses(
+        (status = 201, description = "Import finished; per-row results included", body = ImportJobResponse),
+        (status = 202, description = "Large import queued as a background job; poll the Location", body = ImportJobResponse),
+        (status = 400, description = "Unknown format or unreadable file", body = ImportEventsError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 500, description = "Internal server error", body = ImportEventsError)
+    ),
+    tag = "imports"
+)]
+pub async fn import_events(
+    req: HttpRequest,
+    user: AuthenticatedUser,
+    query: web::Query<ImportEventsQuery>,
+    body: web::Bytes,
+    use_case: web::Data<ImportEventsUseCase>,
+) -> impl Responder {
+    let query = query.into_inner();
+    let format = match query.format.as_deref() {
+        Some(name) => ImportFormat::from_name(name),
+        None => req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(ImportFormat::from_content_type),
+    };
+    let Some(format) = format else {
+        return HttpResponse::BadRequest().json(ImportEventsError {
+            error: "Unknown import format; send text/calendar or text/csv, or pass ?format=ics|csv"
+                .to_string(),
+        });
+    };
+
+    let entries = match parse_entries(format, &body) {
+        Ok(entries) => entries,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(ImportEventsError {
+                error: e.to_string(),
+            });
+        }
+    };
+
+    let run_in_background = entries.len() > BACKGROUND_IMPORT_THRESHOLD;
+    let data = ImportEventsParams {
+        source: query.source.unwrap_or_else(|| "upload".to_string()),
+        entries,
+        organizer_id: Some(user.id()),
+        run_in_background,
+    };
+
+    match use_case.execute(data).await {
+        Ok(job) => {
+            let location = format!("/events/api/imports/{}", job.id());
+            let response: ImportJobResponse = job.into();
+            let mut builder = if run_in_background {
+                HttpResponse::Accepted()
+            } else {
+                HttpResponse::Created()
+            };
+            builder
+                .insert_header((header::LOCATION, location))
+                .json(response)
+        }
+        Err(e @ ImportEventsUseCaseError::NoEntries) => {
+            HttpResponse::BadRequest().json(ImportEventsError {
+                error: e.to_string(),
+            })
+        }
+        Err(ImportEventsUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ImportEventsError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{ImportSeatingChartUseCase, ImportSeatingChartUseCaseError};
+
+use super::super::dtos::{
+    ImportSeatingChartError, ImportSeatingChartRequest, SeatingChartResponse,
+};
+
+#[utoipa::path(
+    put,
+    path = "/events/api/venues/{id}/seating-chart",
+    params(
+        ("id" = Uuid, Path, description = "Venue ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = ImportSeatingChartRequest,
+    responses(
+        (status = 200, description = "Seating chart imported; sections and seats that were already there keep their ids", body = SeatingChartResponse),
+        (status = 400, description = "Invalid layout", body = ImportSeatingChartError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the venue's organizer nor an owner or manager of its organization", body = ImportSeatingChartError),
+        (status = 404, description = "Venue not found", body = ImportSeatingChartError),
+        (status = 409, description = "The layout removes a section that ticket types sell or a seat reserved for an upcoming event", body = ImportSeatingChartError),
+        (status = 500, description = "Internal server error", body = ImportSeatingChartError)
+    ),
+    tag = "venues"
+)]
+pub async fn import_seating_chart(
+    user: AuthenticatedUser,
+    path: web::Path<Uuid>,
+    body: web::Json<ImportSeatingChartRequest>,
+    use_case: web::Data<ImportSeatingChartUseCase>,
+) -> impl Responder {
+    use crate::domain::use_cases::ImportSeatingChartParams;
+
+    let data: ImportSeatingChartParams =
+        match (path.into_inner(), user, body.into_inner()).try_into() {
+            Ok(data) => data,
+            Err(e) => {
+                return HttpResponse::BadRequest().json(ImportSeatingChartError {
+                    error: e.to_string(),
+                });
+            }
+        };
+
+    match use_case.execute(data).await {
+        Ok(chart) => {
+            let response: SeatingChartResponse = chart.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(ImportSeatingChartUseCaseError::VenueNotFound) => {
+            HttpResponse::NotFound().json(ImportSeatingChartError {
+                error: "Venue not found".to_string(),
+            })
+        }
+        Err(e @ ImportSeatingChartUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(ImportSeatingChartError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (ImportSeatingChartUseCaseError::SectionInUse(_)
+            | ImportSeatingChartUseCaseError::SeatReserved(..)),
+        ) => HttpResponse::Conflict().json(ImportSeatingChartError {
+            error: e.to_string(),
+        }),
+        Err(ImportSeatingChartUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ImportSeatingChartError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::InstantiateEventTemplateParams, InstantiateEventTemplateUseCase,
+    InstantiateEventTemplateUseCaseError,
+};
+
+use super::super::dtos::{
+    GetEventResponse, InstantiateEventTemplateError, InstantiateEventTemplateRequest,
+};
+
+#[utoipa::path(
+    post,
+    path = "/events/api/templates/{id}/events",
+    params(
+        ("id" = Uuid, Path, description = "Template ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = InstantiateEventTemplateRequest,
+    responses(
+        (status = 201, description = "Unpublished event created from the template, owned by the caller and the template's organization", body = GetEventResponse),
+        (status = 400, description = "Invalid request, venue not found, or the event would start in the past", body = InstantiateEventTemplateError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller neither saved the template nor owns or manages its organization", body = InstantiateEventTemplateError),
+        (status = 404, description = "Template not found", body = InstantiateEventTemplateError),
+        (status = 500, description = "Internal server error", body = InstantiateEventTemplateError)
+    ),
+    tag = "templates"
+)]
+pub async fn instantiate_event_template(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    body: web::Json<InstantiateEventTemplateRequest>,
+    use_case: web::Data<InstantiateEventTemplateUseCase>,
+) -> impl Responder {
+    let data: InstantiateEventTemplateParams =
+        match (path.into_inner(), user, body.into_inner()).try_into() {
+            Ok(data) => data,
+            Err(e) => {
+                return HttpResponse::BadRequest().json(InstantiateEventTemplateError {
+                    error: e.to_string(),
+                });
+            }
+        };
+
+    match use_case.execute(data).await {
+        Ok(event) => {
+            let response: GetEventResponse = event.into();
+            HttpResponse::Created().json(response)
+        }
+        Err(e @ InstantiateEventTemplateUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(InstantiateEventTemplateError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ InstantiateEventTemplateUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(InstantiateEventTemplateError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (InstantiateEventTemplateUseCaseError::InvalidSchedule(_)
+            | InstantiateEventTemplateUseCaseError::EventDateInPast
+            | InstantiateEventTemplateUseCaseError::VenueNotFound),
+        ) => HttpResponse::BadRequest().json(InstantiateEventTemplateError {
+            error: e.to_string(),
+        }),
+        Err(e @ InstantiateEventTemplateUseCaseError::StorageError(_)) => {
+            HttpResponse::InternalServerError().json(InstantiateEventTemplateError {
+                error: e.to_string(),
+            })
+        }
+        Err(InstantiateEventTemplateUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(InstantiateEventTemplateError { error: msg })
+        }
+    }
+}
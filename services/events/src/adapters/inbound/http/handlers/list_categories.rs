@@ -0,0 +1,24 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+
+use crate::domain::{ListCategoriesUseCase, ListCategoriesUseCaseError};
+
+use super::super::dtos::{CategoryTreeResponse, ListCategoriesError};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/categories",
+    responses(
+        (status = 200, description = "Top-level categories with their subcategories, by name", body = [CategoryTreeResponse]),
+        (status = 500, description = "Internal server error", body = ListCategoriesError)
+    ),
+    tag = "categories"
+)]
+pub async fn list_categories(use_case: web::Data<ListCategoriesUseCase>) -> impl Responder {
+    match use_case.execute(()).await {
+        Ok(taxonomy) => HttpResponse::Ok().json(CategoryTreeResponse::forest(&taxonomy)),
+        Err(ListCategoriesUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ListCategoriesError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+
+use crate::domain::{
+    use_cases::ListEventTemplatesParams, ListEventTemplatesUseCase, ListEventTemplatesUseCaseError,
+};
+
+use super::super::dtos::{EventTemplateResponse, EventTemplatesQuery, ListEventTemplatesError};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/templates",
+    params(
+        EventTemplatesQuery,
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "Templates ordered by name", body = Vec<EventTemplateResponse>),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is not an owner or manager of the organization", body = ListEventTemplatesError),
+        (status = 500, description = "Internal server error", body = ListEventTemplatesError)
+    ),
+    tag = "templates"
+)]
+pub async fn list_event_templates(
+    user: AuthenticatedUser,
+    query: web::Query<EventTemplatesQuery>,
+    use_case: web::Data<ListEventTemplatesUseCase>,
+) -> impl Responder {
+    let data = ListEventTemplatesParams {
+        organization_id: query.into_inner().organization_id,
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(templates) => {
+            let response: Vec<EventTemplateResponse> =
+                templates.into_iter().map(Into::into).collect();
+            HttpResponse::Ok().json(response)
+        }
+        Err(e @ ListEventTemplatesUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(ListEventTemplatesError {
+                error: e.to_string(),
+            })
+        }
+        Err(ListEventTemplatesUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ListEventTemplatesError { error: msg })
+        }
+    }
+}
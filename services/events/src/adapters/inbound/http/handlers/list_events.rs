@@ -0,0 +1,74 @@
+use actix_web::{http::header, web, HttpResponse, Responder};
+use shared::{AcceptLanguage, UseCase};
+
+use crate::domain::{use_cases::ListEventsParams, ListEventsUseCase, ListEventsUseCaseError};
+
+use super::super::dtos::{
+    EventFilterQuery, EventListingResponse, ListEventsError, ListingWindow, ListingWindowQuery,
+};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/events",
+    params(
+        ListingWindowQuery,
+        EventFilterQuery,
+        ("Accept-Language" = Option<String>, Header, description = "Preferred languages for the titles and descriptions of one-off events")
+    ),
+    responses(
+        (status = 200, description = "One-off events and series occurrences in the window, earliest first, or nearest first when searching near a point. Series occurrences are left out when filtering by category or tags", body = [EventListingResponse]),
+        (status = 400, description = "Invalid window, limit or location, or unknown category", body = ListEventsError),
+        (status = 500, description = "Internal server error", body = ListEventsError)
+    ),
+    tag = "events"
+)]
+pub async fn list_events(
+    query: web::Query<ListingWindowQuery>,
+    filter: web::Query<EventFilterQuery>,
+    languages: AcceptLanguage,
+    use_case: web::Data<ListEventsUseCase>,
+) -> impl Responder {
+    let window: ListingWindow = match query.into_inner().try_into() {
+        Ok(window) => window,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(ListEventsError {
+                error: e.to_string(),
+            });
+        }
+    };
+    let near = match filter.near() {
+        Ok(near) => near,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(ListEventsError {
+                error: e.to_string(),
+            });
+        }
+    };
+    let data = ListEventsParams {
+        from: window.from,
+        to: window.to,
+        limit: window.limit,
+        category_id: filter.category_id,
+        tags: filter.tags(),
+        near,
+        languages: languages.ranges().to_vec(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(listings) => {
+            let response: Vec<EventListingResponse> =
+                listings.into_iter().map(Into::into).collect();
+            HttpResponse::Ok()
+                .insert_header((header::VARY, "Accept-Language"))
+                .json(response)
+        }
+        Err(e @ ListEventsUseCaseError::CategoryNotFound) => {
+            HttpResponse::BadRequest().json(ListEventsError {
+                error: e.to_string(),
+            })
+        }
+        Err(ListEventsUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ListEventsError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::ListOccurrencesParams, ListOccurrencesUseCase, ListOccurrencesUseCaseError,
+};
+
+use super::super::dtos::{
+    ListOccurrencesError, ListingWindow, ListingWindowQuery, OccurrenceResponse,
+};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/series/{id}/occurrences",
+    params(
+        ("id" = Uuid, Path, description = "Event series ID"),
+        ListingWindowQuery
+    ),
+    responses(
+        (status = 200, description = "Occurrences in the window with overrides applied", body = [OccurrenceResponse]),
+        (status = 400, description = "Invalid window or limit", body = ListOccurrencesError),
+        (status = 404, description = "Event series not found", body = ListOccurrencesError),
+        (status = 500, description = "Internal server error", body = ListOccurrencesError)
+    ),
+    tag = "series"
+)]
+pub async fn list_occurrences(
+    path: web::Path<Uuid>,
+    query: web::Query<ListingWindowQuery>,
+    use_case: web::Data<ListOccurrencesUseCase>,
+) -> impl Responder {
+    let window: ListingWindow = match query.into_inner().try_into() {
+        Ok(window) => window,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(ListOccurrencesError {
+                error: e.to_string(),
+            });
+        }
+    };
+    let data = ListOccurrencesParams {
+        series_id: path.into_inner(),
+        from: window.from,
+        to: window.to,
+        limit: window.limit,
+    };
+
+    match use_case.execute(data).await {
+        Ok(occurrences) => {
+            let response: Vec<OccurrenceResponse> =
+                occurrences.into_iter().map(Into::into).collect();
+            HttpResponse::Ok().json(response)
+        }
+        Err(ListOccurrencesUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(ListOccurrencesError {
+                error: "Event series not found".to_string(),
+            })
+        }
+        Err(ListOccurrencesUseCaseError::InvalidRecurrence(msg))
+        | Err(ListOccurrencesUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ListOccurrencesError { error: msg })
+        }
+    }
+}
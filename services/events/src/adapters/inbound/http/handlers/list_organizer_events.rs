@@ -0,0 +1,62 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::ListOrganizerEventsParams, ListOrganizerEventsUseCase,
+    ListOrganizerEventsUseCaseError,
+};
+
+use super::super::dtos::{
+    GetEventResponse, ListOrganizerEventsError, ListingWindow, ListingWindowQuery,
+};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/organizers/{id}/events",
+    params(
+        ("id" = Uuid, Path, description = "Organizer ID"),
+        ListingWindowQuery
+    ),
+    responses(
+        (status = 200, description = "The organizer's events in the window, including cancelled ones, earliest first", body = [GetEventResponse]),
+        (status = 400, description = "Invalid window or limit", body = ListOrganizerEventsError),
+        (status = 404, description = "Organizer not found", body = ListOrganizerEventsError),
+        (status = 500, description = "Internal server error", body = ListOrganizerEventsError)
+    ),
+    tag = "events"
+)]
+pub async fn list_organizer_events(
+    path: web::Path<Uuid>,
+    query: web::Query<ListingWindowQuery>,
+    use_case: web::Data<ListOrganizerEventsUseCase>,
+) -> impl Responder {
+    let window: ListingWindow = match query.into_inner().try_into() {
+        Ok(window) => window,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(ListOrganizerEventsError {
+                error: e.to_string(),
+            });
+        }
+    };
+    let data = ListOrganizerEventsParams {
+        organizer_id: path.into_inner(),
+        from: window.from,
+        to: window.to,
+        limit: window.limit,
+    };
+
+    match use_case.execute(data).await {
+        Ok(events) => {
+            let response: Vec<GetEventResponse> = events.into_iter().map(Into::into).collect();
+            HttpResponse::Ok().json(response)
+        }
+        Err(e @ ListOrganizerEventsUseCaseError::OrganizerNotFound) => HttpResponse::NotFound()
+            .json(ListOrganizerEventsError {
+                error: e.to_string(),
+            }),
+        Err(ListOrganizerEventsUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ListOrganizerEventsError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{ListTicketTypesUseCase, ListTicketTypesUseCaseError};
+
+use super::super::dtos::{ListTicketTypesError, TicketTypeResponse};
+
+#[utoipa::path(
+    get,
+    path = "/events/api/events/{id}/ticket-types",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "All ticket types of the event, including hidden ones", body = [TicketTypeResponse]),
+        (status = 404, description = "Event not found", body = ListTicketTypesError),
+        (status = 500, description = "Internal server error", body = ListTicketTypesError)
+    ),
+    tag = "ticket-types"
+)]
+pub async fn list_ticket_types(
+    path: web::Path<Uuid>,
+    use_case: web::Data<ListTicketTypesUseCase>,
+) -> impl Responder {
+    let event_id = path.into_inner();
+
+    match use_case.execute(event_id).await {
+        Ok(ticket_types) => {
+            let response: Vec<TicketTypeResponse> =
+                ticket_types.into_iter().map(Into::into).collect();
+            HttpResponse::Ok().json(response)
+        }
+        Err(ListTicketTypesUseCaseError::EventNotFound) => {
+            HttpResponse::NotFound().json(ListTicketTypesError {
+                error: "Event not found".to_string(),
+            })
+        }
+        Err(ListTicketTypesUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ListTicketTypesError { error: msg })
+        }
+    }
+}
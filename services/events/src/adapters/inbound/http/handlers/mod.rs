@@ -1,2 +1,47 @@
+pub mod cancel_event;
+pub mod create_category;
 pub mod create_event;
+pub mod create_event_series;
+pub mod create_event_session;
+pub mod create_event_template;
+pub mod create_ticket_type;
+pub mod create_venue;
+pub mod delete_category;
+pub mod delete_event_media;
+pub mod delete_event_session;
+pub mod delete_event_template;
+pub mod delete_event_translation;
+pub mod delete_occurrence_override;
+pub mod delete_ticket_type;
+pub mod duplicate_event;
 pub mod get_event;
+pub mod get_event_cancellation;
+pub mod get_event_facets;
+pub mod get_event_history;
+pub mod get_event_schedule;
+pub mod get_event_series;
+pub mod get_event_template;
+pub mod get_import_job;
+pub mod get_organizer_calendar;
+pub mod get_seat_map;
+pub mod get_seating_chart;
+pub mod get_ticket_type;
+pub mod get_venue;
+pub mod get_venue_calendar;
+pub mod import_events;
+pub mod import_seating_chart;
+pub mod instantiate_event_template;
+pub mod list_categories;
+pub mod list_event_templates;
+pub mod list_events;
+pub mod list_occurrences;
+pub mod list_organizer_events;
+pub mod list_ticket_types;
+pub mod override_occurrence;
+pub mod publish_event;
+pub mod save_event_translation;
+pub mod update_category;
+pub mod update_event;
+pub mod update_event_session;
+pub mod update_ticket_type;
+pub mod upload_event_media;
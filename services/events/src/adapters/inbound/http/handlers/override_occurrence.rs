@@ -0,0 +1,71 @@
+use actix_web::{web, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::OverrideOccurrenceParams, OverrideOccurrenceUseCase, OverrideOccurrenceUseCaseError,
+};
+
+use super::super::dtos::{
+    OccurrenceOverrideResponse, OverrideOccurrenceError, OverrideOccurrenceRequest,
+};
+
+#[utoipa::path(
+    put,
+    path = "/events/api/series/{id}/occurrences/{occurrence_start}",
+    params(
+        ("id" = Uuid, Path, description = "Event series ID"),
+        ("occurrence_start" = DateTime<Utc>, Path, description = "Original start of the occurrence (RFC 3339)"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = OverrideOccurrenceRequest,
+    responses(
+        (status = 200, description = "Override stored; replaces any previous override", body = OccurrenceOverrideResponse),
+        (status = 400, description = "Invalid request", body = OverrideOccurrenceError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the series' organizer nor an owner or manager of its organization", body = OverrideOccurrenceError),
+        (status = 404, description = "Series, occurrence or venue not found", body = OverrideOccurrenceError),
+        (status = 500, description = "Internal server error", body = OverrideOccurrenceError)
+    ),
+    tag = "series"
+)]
+pub async fn override_occurrence(
+    user: AuthenticatedUser,
+    path: web::Path<(Uuid, DateTime<Utc>)>,
+    body: web::Json<OverrideOccurrenceRequest>,
+    use_case: web::Data<OverrideOccurrenceUseCase>,
+) -> impl Responder {
+    let (series_id, occurrence_start) = path.into_inner();
+    let data: OverrideOccurrenceParams =
+        match (series_id, occurrence_start, user, body.into_inner()).try_into() {
+            Ok(data) => data,
+            Err(e) => {
+                return HttpResponse::BadRequest().json(OverrideOccurrenceError {
+                    error: e.to_string(),
+                });
+            }
+        };
+
+    match use_case.execute(data).await {
+        Ok(override_) => {
+            let response: OccurrenceOverrideResponse = override_.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(
+            e @ (OverrideOccurrenceUseCaseError::SeriesNotFound
+            | OverrideOccurrenceUseCaseError::OccurrenceNotFound
+            | OverrideOccurrenceUseCaseError::VenueNotFound),
+        ) => HttpResponse::NotFound().json(OverrideOccurrenceError {
+            error: e.to_string(),
+        }),
+        Err(e @ OverrideOccurrenceUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(OverrideOccurrenceError {
+                error: e.to_string(),
+            })
+        }
+        Err(OverrideOccurrenceUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(OverrideOccurrenceError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use actix_web::{http::header::ETag, web, HttpResponse, Responder};
+use shared::{entity_tag, AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{use_cases::PublishEventParams, PublishEventUseCase, PublishEventUseCaseError};
+
+use super::super::dtos::{GetEventResponse, PublishEventError};
+
+#[utoipa::path(
+    post,
+    path = "/events/api/events/{id}/publish",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "Event published now, replacing any scheduled publication; sales open unless on_sale_at is still ahead", body = GetEventResponse),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = PublishEventError),
+        (status = 404, description = "Event not found", body = PublishEventError),
+        (status = 409, description = "Event is cancelled or already published", body = PublishEventError),
+        (status = 500, description = "Internal server error", body = PublishEventError)
+    ),
+    tag = "events"
+)]
+pub async fn publish_event(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<PublishEventUseCase>,
+) -> impl Responder {
+    let data = PublishEventParams {
+        event_id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(event) => {
+            let etag = ETag(entity_tag(event.version()));
+            let response: GetEventResponse = event.into();
+            HttpResponse::Ok().insert_header(etag).json(response)
+        }
+        Err(e @ PublishEventUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(PublishEventError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ PublishEventUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(PublishEventError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (PublishEventUseCaseError::EventCancelled
+            | PublishEventUseCaseError::AlreadyPublished),
+        ) => HttpResponse::Conflict().json(PublishEventError {
+            error: e.to_string(),
+        }),
+        Err(PublishEventUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(PublishEventError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::SaveEventTranslationParams, SaveEventTranslationUseCase,
+    SaveEventTranslationUseCaseError,
+};
+
+use super::super::dtos::{
+    EventTranslationRequest, EventTranslationResponse, SaveEventTranslationError,
+};
+
+#[utoipa::path(
+    put,
+    path = "/events/api/events/{id}/translations/{locale}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("locale" = String, Path, description = "Language tag such as `de` or `pt-BR`"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = EventTranslationRequest,
+    responses(
+        (status = 200, description = "Translation created or replaced", body = EventTranslationResponse),
+        (status = 400, description = "Invalid locale, title or description, or the locale is the event's default one", body = SaveEventTranslationError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = SaveEventTranslationError),
+        (status = 404, description = "Event not found", body = SaveEventTranslationError),
+        (status = 500, description = "Internal server error", body = SaveEventTranslationError)
+    ),
+    tag = "translations"
+)]
+pub async fn save_event_translation(
+    path: web::Path<(Uuid, String)>,
+    user: AuthenticatedUser,
+    body: web::Json<EventTranslationRequest>,
+    use_case: web::Data<SaveEventTranslationUseCase>,
+) -> impl Responder {
+    let (event_id, locale) = path.into_inner();
+    let data: SaveEventTranslationParams =
+        match (event_id, locale, user, body.into_inner()).try_into() {
+            Ok(data) => data,
+            Err(e) => {
+                return HttpResponse::BadRequest().json(SaveEventTranslationError {
+                    error: e.to_string(),
+                });
+            }
+        };
+
+    match use_case.execute(data).await {
+        Ok(translation) => {
+            let response: EventTranslationResponse = translation.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(e @ SaveEventTranslationUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(SaveEventTranslationError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ SaveEventTranslationUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(SaveEventTranslationError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ SaveEventTranslationUseCaseError::DefaultLocale(_)) => HttpResponse::BadRequest()
+            .json(SaveEventTranslationError {
+                error: e.to_string(),
+            }),
+        Err(SaveEventTranslationUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(SaveEventTranslationError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::UpdateCategoryParams, UpdateCategoryUseCase, UpdateCategoryUseCaseError,
+};
+
+use super::super::dtos::{CategoryResponse, UpdateCategoryError, UpdateCategoryRequest};
+
+#[utoipa::path(
+    patch,
+    path = "/events/api/categories/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Category ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = UpdateCategoryRequest,
+    responses(
+        (status = 200, description = "Category updated successfully", body = CategoryResponse),
+        (status = 400, description = "Invalid request, unknown parent or a move below itself", body = UpdateCategoryError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is not an administrator", body = UpdateCategoryError),
+        (status = 404, description = "Category not found", body = UpdateCategoryError),
+        (status = 409, description = "Slug already in use", body = UpdateCategoryError),
+        (status = 500, description = "Internal server error", body = UpdateCategoryError)
+    ),
+    tag = "categories"
+)]
+pub async fn update_category(
+    user: AuthenticatedUser,
+    path: web::Path<Uuid>,
+    body: web::Json<UpdateCategoryRequest>,
+    use_case: web::Data<UpdateCategoryUseCase>,
+) -> impl Responder {
+    let data: UpdateCategoryParams = match (path.into_inner(), user, body.into_inner()).try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(UpdateCategoryError {
+                error: e.to_string(),
+            });
+        }
+    };
+
+    match use_case.execute(data).await {
+        Ok(category) => {
+            let response: CategoryResponse = category.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(e @ UpdateCategoryUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(UpdateCategoryError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ UpdateCategoryUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(UpdateCategoryError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (UpdateCategoryUseCaseError::ParentNotFound | UpdateCategoryUseCaseError::Cycle),
+        ) => HttpResponse::BadRequest().json(UpdateCategoryError {
+            error: e.to_string(),
+        }),
+        Err(e @ UpdateCategoryUseCaseError::SlugTaken(_)) => {
+            HttpResponse::Conflict().json(UpdateCategoryError {
+                error: e.to_string(),
+            })
+        }
+        Err(UpdateCategoryUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(UpdateCategoryError { error: msg })
+        }
+    }
+}
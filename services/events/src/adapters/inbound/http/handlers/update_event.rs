@@ -0,0 +1,97 @@
+use actix_web::{http::header::ETag, web, HttpResponse, Responder};
+use shared::{entity_tag, AuthenticatedUser, IfMatch, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::PublicationError, use_cases::UpdateEventParams, UpdateEventUseCase,
+    UpdateEventUseCaseError,
+};
+
+use super::super::dtos::{GetEventResponse, UpdateEventError, UpdateEventRequest};
+
+#[utoipa::path(
+    patch,
+    path = "/events/api/events/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway"),
+        ("If-Match" = String, Header, description = "ETag of the version being changed, or * to change any version")
+    ),
+    request_body = UpdateEventRequest,
+    responses(
+        (status = 200, description = "Event updated; its sequence is incremented and its new version is in the ETag header", body = GetEventResponse),
+        (status = 400, description = "Invalid request", body = UpdateEventError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = UpdateEventError),
+        (status = 404, description = "Event or venue not found", body = UpdateEventError),
+        (status = 409, description = "Event is cancelled, or the publication or sales opening being moved has already happened, or sessions would fall outside the event, or the new default locale already has a translation", body = UpdateEventError),
+        (status = 412, description = "Event has been modified since the If-Match version", body = UpdateEventError),
+        (status = 428, description = "If-Match header is missing", body = UpdateEventError),
+        (status = 500, description = "Internal server error", body = UpdateEventError)
+    ),
+    tag = "events"
+)]
+pub async fn update_event(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    if_match: IfMatch,
+    body: web::Json<UpdateEventRequest>,
+    use_case: web::Data<UpdateEventUseCase>,
+) -> impl Responder {
+    let data: UpdateEventParams =
+        match (path.into_inner(), user, if_match, body.into_inner()).try_into() {
+            Ok(data) => data,
+            Err(e) => {
+                return HttpResponse::BadRequest().json(UpdateEventError {
+                    error: e.to_string(),
+                });
+            }
+        };
+
+    match use_case.execute(data).await {
+        Ok(event) => {
+            let etag = ETag(entity_tag(event.version()));
+            let response: GetEventResponse = event.into();
+            HttpResponse::Ok().insert_header(etag).json(response)
+        }
+        Err(e @ (UpdateEventUseCaseError::NotFound | UpdateEventUseCaseError::VenueNotFound)) => {
+            HttpResponse::NotFound().json(UpdateEventError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (UpdateEventUseCaseError::CategoryNotFound(_)
+            | UpdateEventUseCaseError::InvalidSchedule(_)
+            | UpdateEventUseCaseError::EventDateInPast),
+        ) => HttpResponse::BadRequest().json(UpdateEventError {
+            error: e.to_string(),
+        }),
+        Err(e @ UpdateEventUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(UpdateEventError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (UpdateEventUseCaseError::EventCancelled
+            | UpdateEventUseCaseError::SessionsOutsideEvent
+            | UpdateEventUseCaseError::DefaultLocaleTranslated(_)
+            | UpdateEventUseCaseError::InvalidPublication(
+                PublicationError::AlreadyPublished | PublicationError::SalesAlreadyOpen,
+            )),
+        ) => HttpResponse::Conflict().json(UpdateEventError {
+            error: e.to_string(),
+        }),
+        Err(e @ UpdateEventUseCaseError::InvalidPublication(_)) => {
+            HttpResponse::BadRequest().json(UpdateEventError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ UpdateEventUseCaseError::VersionMismatch) => HttpResponse::PreconditionFailed()
+            .json(UpdateEventError {
+                error: e.to_string(),
+            }),
+        Err(UpdateEventUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(UpdateEventError { error: msg })
+        }
+    }
+}
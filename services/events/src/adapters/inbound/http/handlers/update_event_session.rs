@@ -0,0 +1,85 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{UpdateEventSessionUseCase, UpdateEventSessionUseCaseError};
+
+use super::super::dtos::{EventSessionRequest, EventSessionResponse, UpdateEventSessionError};
+
+#[utoipa::path(
+    put,
+    path = "/events/api/events/{id}/sessions/{session_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("session_id" = Uuid, Path, description = "Session ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = EventSessionRequest,
+    responses(
+        (status = 200, description = "Session updated successfully", body = EventSessionResponse),
+        (status = 400, description = "Invalid request, or the session is not within the event", body = UpdateEventSessionError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = UpdateEventSessionError),
+        (status = 404, description = "Event or session not found", body = UpdateEventSessionError),
+        (status = 409, description = "More tickets already admit to the session than the new capacity", body = UpdateEventSessionError),
+        (status = 500, description = "Internal server error", body = UpdateEventSessionError)
+    ),
+    tag = "sessions"
+)]
+pub async fn update_event_session(
+    path: web::Path<(Uuid, Uuid)>,
+    user: AuthenticatedUser,
+    body: web::Json<EventSessionRequest>,
+    use_case: web::Data<UpdateEventSessionUseCase>,
+) -> impl Responder {
+    use crate::domain::use_cases::{EventSessionParams, UpdateEventSessionParams};
+
+    let session: EventSessionParams = match body.into_inner().try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(UpdateEventSessionError {
+                error: e.to_string(),
+            });
+        }
+    };
+    let (event_id, session_id) = path.into_inner();
+    let data = UpdateEventSessionParams {
+        event_id,
+        session_id,
+        session,
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(session) => {
+            let response: EventSessionResponse = session.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(
+            e @ (UpdateEventSessionUseCaseError::EventNotFound
+            | UpdateEventSessionUseCaseError::SessionNotFound),
+        ) => HttpResponse::NotFound().json(UpdateEventSessionError {
+            error: e.to_string(),
+        }),
+        Err(e @ UpdateEventSessionUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(UpdateEventSessionError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (UpdateEventSessionUseCaseError::InvalidSchedule(_)
+            | UpdateEventSessionUseCaseError::EndsBeforeStart
+            | UpdateEventSessionUseCaseError::OutsideEvent),
+        ) => HttpResponse::BadRequest().json(UpdateEventSessionError {
+            error: e.to_string(),
+        }),
+        Err(e @ UpdateEventSessionUseCaseError::CapacityBelowTickets(_)) => {
+            HttpResponse::Conflict().json(UpdateEventSessionError {
+                error: e.to_string(),
+            })
+        }
+        Err(UpdateEventSessionUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(UpdateEventSessionError { error: msg })
+        }
+    }
+}
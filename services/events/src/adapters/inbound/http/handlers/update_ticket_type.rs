@@ -0,0 +1,79 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{UpdateTicketTypeUseCase, UpdateTicketTypeUseCaseError};
+
+use super::super::dtos::{TicketTypeRequest, TicketTypeResponse, UpdateTicketTypeError};
+
+#[utoipa::path(
+    put,
+    path = "/events/api/events/{id}/ticket-types/{ticket_type_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("ticket_type_id" = Uuid, Path, description = "Ticket type ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = TicketTypeRequest,
+    responses(
+        (status = 200, description = "Ticket type updated successfully", body = TicketTypeResponse),
+        (status = 400, description = "Invalid request, or the admission is not part of the event or exceeds the session's capacity, or the section is not in the venue's seating chart or is missing at a venue with one", body = UpdateTicketTypeError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = UpdateTicketTypeError),
+        (status = 404, description = "Event or ticket type not found", body = UpdateTicketTypeError),
+        (status = 500, description = "Internal server error", body = UpdateTicketTypeError)
+    ),
+    tag = "ticket-types"
+)]
+pub async fn update_ticket_type(
+    path: web::Path<(Uuid, Uuid)>,
+    user: AuthenticatedUser,
+    body: web::Json<TicketTypeRequest>,
+    use_case: web::Data<UpdateTicketTypeUseCase>,
+) -> impl Responder {
+    use crate::domain::use_cases::{TicketTypeParams, UpdateTicketTypeParams};
+
+    let ticket_type: TicketTypeParams = match body.into_inner().try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(UpdateTicketTypeError {
+                error: e.to_string(),
+            });
+        }
+    };
+    let (event_id, ticket_type_id) = path.into_inner();
+    let data = UpdateTicketTypeParams {
+        event_id,
+        ticket_type_id,
+        ticket_type,
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(ticket_type) => {
+            let response: TicketTypeResponse = ticket_type.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(e @ UpdateTicketTypeUseCaseError::EventNotFound)
+        | Err(e @ UpdateTicketTypeUseCaseError::TicketTypeNotFound) => HttpResponse::NotFound()
+            .json(UpdateTicketTypeError {
+                error: e.to_string(),
+            }),
+        Err(e @ UpdateTicketTypeUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(UpdateTicketTypeError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (UpdateTicketTypeUseCaseError::SalesEndAfterEvent
+            | UpdateTicketTypeUseCaseError::InvalidAdmission(_)
+            | UpdateTicketTypeUseCaseError::UnknownSection
+            | UpdateTicketTypeUseCaseError::SectionRequired),
+        ) => HttpResponse::BadRequest().json(UpdateTicketTypeError {
+            error: e.to_string(),
+        }),
+        Err(UpdateTicketTypeUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(UpdateTicketTypeError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use actix_web::{http::header, web, HttpRequest, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::UploadEventMediaParams, UploadEventMediaUseCase, UploadEventMediaUseCaseError,
+};
+
+use super::super::dtos::{MediaResponse, UploadEventMediaError, UploadMediaQuery};
+
+#[utoipa::path(
+    post,
+    path = "/events/api/events/{id}/media",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        UploadMediaQuery,
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body(
+        content = Vec<u8>,
+        description = "Raw file; JPEG, PNG or WebP images up to 10 MB, or MP4 video up to 50 MB for the gallery",
+        content_type = "image/jpeg"
+    ),
+    responses(
+        (status = 201, description = "Media stored", body = MediaResponse),
+        (status = 400, description = "Empty or unreadable file, or content not matching its Content-Type", body = UploadEventMediaError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither the organizer nor an owner or manager of the organization", body = UploadEventMediaError),
+        (status = 404, description = "Event not found", body = UploadEventMediaError),
+        (status = 409, description = "Event is cancelled or its gallery is full", body = UploadEventMediaError),
+        (status = 413, description = "File too large for its type", body = UploadEventMediaError),
+        (status = 415, description = "Unsupported Content-Type", body = UploadEventMediaError),
+        (status = 500, description = "Internal server error", body = UploadEventMediaError)
+    ),
+    tag = "events"
+)]
+pub async fn upload_event_media(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    query: web::Query<UploadMediaQuery>,
+    body: web::Bytes,
+    use_case: web::Data<UploadEventMediaUseCase>,
+) -> impl Responder {
+    let event_id = path.into_inner();
+    let content_type = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let data = UploadEventMediaParams {
+        event_id,
+        requested_by: user.id(),
+        kind: query.into_inner().kind.into(),
+        content_type,
+        bytes: body.to_vec(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(asset) => HttpResponse::Created().json(MediaResponse::from(&asset)),
+        Err(UploadEventMediaUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(UploadEventMediaError {
+                error: format!("Event with id {} not found", event_id),
+            })
+        }
+        Err(e @ UploadEventMediaUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(UploadEventMediaError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (UploadEventMediaUseCaseError::EventCancelled
+            | UploadEventMediaUseCaseError::GalleryFull),
+        ) => HttpResponse::Conflict().json(UploadEventMediaError {
+            error: e.to_string(),
+        }),
+        Err(e @ UploadEventMediaUseCaseError::UnsupportedType(_)) => {
+            HttpResponse::UnsupportedMediaType().json(UploadEventMediaError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ UploadEventMediaUseCaseError::TooLarge(_)) => {
+            HttpResponse::PayloadTooLarge().json(UploadEventMediaError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (UploadEventMediaUseCaseError::Empty
+            | UploadEventMediaUseCaseError::ContentMismatch(_)
+            | UploadEventMediaUseCaseError::Unreadable),
+        ) => HttpResponse::BadRequest().json(UploadEventMediaError {
+            error: e.to_string(),
+        }),
+        Err(
+            UploadEventMediaUseCaseError::StorageError(msg)
+            | UploadEventMediaUseCaseError::DatabaseError(msg),
+        ) => HttpResponse::InternalServerError().json(UploadEventMediaError { error: msg }),
+    }
+}
@@ -0,0 +1,190 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{entities::CalendarFeed, Event};
+
+const PRODID: &str = "-//Odysseus//Events Service//EN";
+const UID_DOMAIN: &str = "odysseus.events";
+/// RFC 5545 recommends folding content lines longer than 75 octets.
+const MAX_LINE_OCTETS: usize = 75;
+/// How often subscribed clients should re-fetch a feed.
+const REFRESH_INTERVAL: &str = "PT1H";
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\n', '\r'], "\\n")
+}
+
+fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
+    timestamp.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Appends a content line, folding it onto continuation lines (which start
+/// with a space) without splitting UTF-8 sequences.
+fn push_line(out: &mut String, line: &str) {
+    let mut octets = 0;
+    for ch in line.chars() {
+        if octets + ch.len_utf8() > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            octets = 1;
+        }
+        out.push(ch);
+        octets += ch.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+fn push_calendar_header(out: &mut String) {
+    push_line(out, "BEGIN:VCALENDAR");
+    push_line(out, "VERSION:2.0");
+    push_line(out, &format!("PRODID:{PRODID}"));
+    push_line(out, "CALSCALE:GREGORIAN");
+    push_line(out, "METHOD:PUBLISH");
+}
+
+fn push_event(out: &mut String, event: &Event) {
+    push_line(out, "BEGIN:VEVENT");
+    push_line(out, &format!("UID:{}@{UID_DOMAIN}", event.id()));
+    push_line(
+        out,
+        &format!("DTSTAMP:{}", format_timestamp(event.updated_at())),
+    );
+    push_line(
+        out,
+        &format!("DTSTART:{}", format_timestamp(event.event_date())),
+    );
+    if let Some(ends_at) = event.schedule().ends_at() {
+        push_line(out, &format!("DTEND:{}", format_timestamp(ends_at)));
+    }
+    push_line(out, &format!("SUMMARY:{}", escape_text(event.title())));
+    if !event.description().is_empty() {
+        push_line(
+            out,
+            &format!("DESCRIPTION:{}", escape_text(event.description())),
+        );
+    }
+    push_line(
+        out,
+        &format!("LOCATION:{}", escape_text(event.venue_name())),
+    );
+    let status = if event.is_cancelled() {
+        "CANCELLED"
+    } else {
+        "CONFIRMED"
+    };
+    push_line(out, &format!("STATUS:{status}"));
+    push_line(out, &format!("SEQUENCE:{}", event.sequence()));
+    push_line(
+        out,
+        &format!("CREATED:{}", format_timestamp(event.created_at())),
+    );
+    push_line(
+        out,
+        &format!("LAST-MODIFIED:{}", format_timestamp(event.updated_at())),
+    );
+    push_line(out, "END:VEVENT");
+}
+
+/// Renders a single event as an iCalendar object with one VEVENT.
+pub fn render_event_ics(event: &Event) -> String {
+    let mut out = String::new();
+    push_calendar_header(&mut out);
+    push_event(&mut out, event);
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+/// Renders a subscribable feed. Cancelled events stay in the feed with
+/// `STATUS:CANCELLED` so subscribed calendars remove them.
+pub fn render_calendar_feed_ics(feed: &CalendarFeed) -> String {
+    let mut out = String::new();
+    push_calendar_header(&mut out);
+    push_line(&mut out, &format!("NAME:{}", escape_text(feed.name())));
+    push_line(
+        &mut out,
+        &format!("X-WR-CALNAME:{}", escape_text(feed.name())),
+    );
+    push_line(
+        &mut out,
+        &format!("REFRESH-INTERVAL;VALUE=DURATION:{REFRESH_INTERVAL}"),
+    );
+    push_line(&mut out, &format!("X-PUBLISHED-TTL:{REFRESH_INTERVAL}"));
+    for event in feed.events() {
+        push_event(&mut out, event);
+    }
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{EventSchedule, EventStatus};
+    use crate::domain::fixtures::EventFixture;
+    use chrono::{Duration, TimeZone};
+    use chrono_tz::Tz;
+
+    fn event(title: &str, description: &str, status: EventStatus, sequence: i32) -> Event {
+        let at = Utc.with_ymd_and_hms(2026, 11, 5, 20, 0, 0).unwrap();
+        EventFixture {
+            title,
+            description,
+            schedule: EventSchedule::new(Tz::UTC, at, Some(at + Duration::hours(2)), None),
+            venue_name: "Cellar, Main St",
+            status,
+            sequence,
+            ..EventFixture::at(at)
+        }
+        .build()
+    }
+
+    #[test]
+    fn test_render_event_escapes_and_folds() {
+        let long_title = "Comedy; night, with ".repeat(6);
+        let ics = render_event_ics(&event(
+            &long_title,
+            "Line one\nLine two",
+            EventStatus::Scheduled,
+            0,
+        ));
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("DTSTART:20261105T200000Z\r\nDTEND:20261105T220000Z\r\n"));
+        assert!(ics.contains("SUMMARY:Comedy\\; night\\, with "));
+        assert!(ics.contains("DESCRIPTION:Line one\\nLine two\r\n"));
+        assert!(ics.contains("LOCATION:Cellar\\, Main St\r\n"));
+        assert!(ics.contains("STATUS:CONFIRMED\r\n"));
+        assert!(ics.split("\r\n").all(|line| line.len() <= MAX_LINE_OCTETS));
+        assert!(ics.contains("\r\n "));
+    }
+
+    #[test]
+    fn test_render_feed_keeps_cancelled_events() {
+        let cancelled = event("Open mic", "", EventStatus::Cancelled, 2);
+        let feed = CalendarFeed::new("The Cellar".to_string(), vec![cancelled]);
+
+        let ics = render_calendar_feed_ics(&feed);
+
+        assert!(ics.contains("X-WR-CALNAME:The Cellar\r\n"));
+        assert!(ics.contains("STATUS:CANCELLED\r\nSEQUENCE:2\r\n"));
+        assert!(!ics.contains("DESCRIPTION:"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 1);
+    }
+
+    #[test]
+    fn test_folding_respects_multibyte_characters() {
+        let mut out = String::new();
+        push_line(&mut out, &format!("SUMMARY:{}", "é".repeat(60)));
+
+        for line in out.split("\r\n") {
+            assert!(line.len() <= MAX_LINE_OCTETS);
+        }
+        assert_eq!(
+            out.replace("\r\n ", ""),
+            format!("SUMMARY:{}\r\n", "é".repeat(60))
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod icalendar;
+pub mod seat_map_svg;
+
+pub use icalendar::{render_calendar_feed_ics, render_event_ics};
+pub use seat_map_svg::render_seat_map_svg;
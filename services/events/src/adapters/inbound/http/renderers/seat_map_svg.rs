@@ -0,0 +1,156 @@
+use std::fmt::Write;
+
+use crate::domain::{SeatMap, SeatStatus};
+
+const SEAT_RADIUS: f64 = 8.0;
+const PADDING: f64 = 24.0;
+
+const STYLE: &str = ".seat{stroke:#1f2937;stroke-width:1}\
+.available{fill:#22c55e}.held{fill:#f59e0b}.sold{fill:#9ca3af}\
+.accessible{stroke:#2563eb;stroke-width:3}.obstructed{stroke-dasharray:2 2}";
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn status_class(status: SeatStatus) -> &'static str {
+    match status {
+        SeatStatus::Available => "available",
+        SeatStatus::Held => "held",
+        SeatStatus::Sold => "sold",
+    }
+}
+
+/// Renders an event's seat map as a standalone SVG document, one circle per
+/// seat positioned at its chart coordinates and coloured by availability.
+pub fn render_seat_map_svg(seat_map: &SeatMap) -> String {
+    let chart = seat_map.chart();
+
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64);
+    for (i, seat) in chart.seats().enumerate() {
+        if i == 0 {
+            (min_x, min_y, max_x, max_y) = (seat.x(), seat.y(), seat.x(), seat.y());
+        }
+        min_x = min_x.min(seat.x());
+        min_y = min_y.min(seat.y());
+        max_x = max_x.max(seat.x());
+        max_y = max_y.max(seat.y());
+    }
+
+    let view_x = min_x - PADDING;
+    let view_y = min_y - PADDING;
+    let width = (max_x - min_x) + PADDING * 2.0;
+    let height = (max_y - min_y) + PADDING * 2.0;
+
+    let mut svg = String::new();
+    let _ = write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{view_x} {view_y} {width} {height}" data-event-id="{}"><style>{STYLE}</style>"#,
+        seat_map.event_id()
+    );
+
+    for section in chart.sections() {
+        let _ = write!(
+            svg,
+            r#"<g class="section" data-section="{}">"#,
+            escape(section.name())
+        );
+        for row in section.rows() {
+            for seat in row.seats() {
+                let status = status_class(seat_map.status_of(seat.id()));
+                let mut class = format!("seat {status}");
+                if seat.accessible() {
+                    class.push_str(" accessible");
+                }
+                if seat.obstructed() {
+                    class.push_str(" obstructed");
+                }
+                let _ = write!(
+                    svg,
+                    r#"<circle id="seat-{}" class="{class}" cx="{}" cy="{}" r="{SEAT_RADIUS}"><title>{} · Row {} · Seat {} ({status})</title></circle>"#,
+                    seat.id(),
+                    seat.x(),
+                    seat.y(),
+                    escape(section.name()),
+                    escape(row.label()),
+                    escape(seat.label()),
+                );
+            }
+        }
+        svg.push_str("</g>");
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Seat, SeatingChart, SeatingRow, SeatingSection};
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    fn seat(label: &str, x: f64, accessible: bool) -> Seat {
+        Seat::new(
+            Uuid::new_v4(),
+            label.to_string(),
+            x,
+            10.0,
+            accessible,
+            false,
+        )
+    }
+
+    #[test]
+    fn test_render_marks_status_and_attributes() {
+        let sold = seat("1", 10.0, false);
+        let sold_id = sold.id();
+        let accessible = seat("2", 30.0, true);
+        let chart = SeatingChart::new(
+            Uuid::new_v4(),
+            vec![SeatingSection::new(
+                Uuid::new_v4(),
+                "Stalls".to_string(),
+                vec![SeatingRow::new("A".to_string(), vec![sold, accessible])],
+            )],
+        );
+        let seat_map = SeatMap::new(
+            Uuid::new_v4(),
+            chart,
+            HashMap::from([(sold_id, SeatStatus::Sold)]),
+        );
+
+        let svg = render_seat_map_svg(&seat_map);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>"));
+        assert!(svg.contains(&format!(r#"id="seat-{}" class="seat sold""#, sold_id)));
+        assert!(svg.contains(r#"class="seat available accessible""#));
+        assert!(svg.contains(r#"viewBox="-14 -14 68 48""#));
+    }
+
+    #[test]
+    fn test_render_escapes_labels() {
+        let chart = SeatingChart::new(
+            Uuid::new_v4(),
+            vec![SeatingSection::new(
+                Uuid::new_v4(),
+                "<Box & Circle>".to_string(),
+                vec![SeatingRow::new(
+                    "A".to_string(),
+                    vec![seat("1", 0.0, false)],
+                )],
+            )],
+        );
+        let seat_map = SeatMap::new(Uuid::new_v4(), chart, HashMap::new());
+
+        let svg = render_seat_map_svg(&seat_map);
+
+        assert!(svg.contains("&lt;Box &amp; Circle&gt;"));
+        assert!(!svg.contains("<Box"));
+    }
+}
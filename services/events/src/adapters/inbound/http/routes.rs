@@ -1,11 +1,195 @@
 use actix_web::web;
 
-use super::handlers::{create_event, get_event};
+use super::handlers::{
+    cancel_event, create_category, create_event, create_event_series, create_event_session,
+    create_event_template, create_ticket_type, create_venue, delete_category, delete_event_media,
+    delete_event_session, delete_event_template, delete_event_translation,
+    delete_occurrence_override, delete_ticket_type, duplicate_event, get_event,
+    get_event_cancellation, get_event_facets, get_event_history, get_event_schedule,
+    get_event_series, get_event_template, get_import_job, get_organizer_calendar, get_seat_map,
+    get_seating_chart, get_ticket_type, get_venue, get_venue_calendar, import_events,
+    import_seating_chart, instantiate_event_template, list_categories, list_event_templates,
+    list_events, list_occurrences, list_organizer_events, list_ticket_types, override_occurrence,
+    publish_event, save_event_translation, update_category, update_event, update_event_session,
+    update_ticket_type, upload_event_media,
+};
+
+/// Upper bound for uploaded import files.
+const MAX_IMPORT_BYTES: usize = 10 * 1024 * 1024;
+/// Upper bound for uploaded media; the use case applies the per-type limits.
+const MAX_MEDIA_BYTES: usize = 50 * 1024 * 1024;
 
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/api/events")
             .route("", web::post().to(create_event::create_event))
-            .route("/{id}", web::get().to(get_event::get_event)),
+            .route("", web::get().to(list_events::list_events))
+            .route("/facets", web::get().to(get_event_facets::get_event_facets))
+            .route("/{id}.ics", web::get().to(get_event::get_event_ics))
+            .route("/{id}", web::get().to(get_event::get_event))
+            .route("/{id}", web::patch().to(update_event::update_event))
+            .route("/{id}/cancel", web::post().to(cancel_event::cancel_event))
+            .route(
+                "/{id}/cancellation",
+                web::get().to(get_event_cancellation::get_event_cancellation),
+            )
+            .route(
+                "/{id}/duplicate",
+                web::post().to(duplicate_event::duplicate_event),
+            )
+            .route(
+                "/{id}/history",
+                web::get().to(get_event_history::get_event_history),
+            )
+            .route(
+                "/{id}/history/{revision}",
+                web::get().to(get_event_history::get_event_revision),
+            )
+            .service(
+                web::resource("/{id}/media")
+                    .app_data(web::PayloadConfig::new(MAX_MEDIA_BYTES))
+                    .route(web::post().to(upload_event_media::upload_event_media)),
+            )
+            .route(
+                "/{id}/media/{media_id}",
+                web::delete().to(delete_event_media::delete_event_media),
+            )
+            .route(
+                "/{id}/publish",
+                web::post().to(publish_event::publish_event),
+            )
+            .route(
+                "/{id}/schedule",
+                web::get().to(get_event_schedule::get_event_schedule),
+            )
+            .route("/{id}/seat-map", web::get().to(get_seat_map::get_seat_map))
+            .route(
+                "/{id}/seat-map.svg",
+                web::get().to(get_seat_map::get_seat_map_svg),
+            )
+            .route(
+                "/{id}/sessions",
+                web::post().to(create_event_session::create_event_session),
+            )
+            .route(
+                "/{id}/sessions/{session_id}",
+                web::put().to(update_event_session::update_event_session),
+            )
+            .route(
+                "/{id}/sessions/{session_id}",
+                web::delete().to(delete_event_session::delete_event_session),
+            )
+            .route(
+                "/{id}/translations/{locale}",
+                web::put().to(save_event_translation::save_event_translation),
+            )
+            .route(
+                "/{id}/translations/{locale}",
+                web::delete().to(delete_event_translation::delete_event_translation),
+            )
+            .route(
+                "/{id}/ticket-types",
+                web::post().to(create_ticket_type::create_ticket_type),
+            )
+            .route(
+                "/{id}/ticket-types",
+                web::get().to(list_ticket_types::list_ticket_types),
+            )
+            .route(
+                "/{id}/ticket-types/{ticket_type_id}",
+                web::get().to(get_ticket_type::get_ticket_type),
+            )
+            .route(
+                "/{id}/ticket-types/{ticket_type_id}",
+                web::put().to(update_ticket_type::update_ticket_type),
+            )
+            .route(
+                "/{id}/ticket-types/{ticket_type_id}",
+                web::delete().to(delete_ticket_type::delete_ticket_type),
+            ),
+    )
+    .service(
+        web::scope("/api/templates")
+            .route(
+                "",
+                web::post().to(create_event_template::create_event_template),
+            )
+            .route(
+                "",
+                web::get().to(list_event_templates::list_event_templates),
+            )
+            .route(
+                "/{id}",
+                web::get().to(get_event_template::get_event_template),
+            )
+            .route(
+                "/{id}",
+                web::delete().to(delete_event_template::delete_event_template),
+            )
+            .route(
+                "/{id}/events",
+                web::post().to(instantiate_event_template::instantiate_event_template),
+            ),
+    )
+    .service(
+        web::scope("/api/series")
+            .route("", web::post().to(create_event_series::create_event_series))
+            .route("/{id}", web::get().to(get_event_series::get_event_series))
+            .route(
+                "/{id}/occurrences",
+                web::get().to(list_occurrences::list_occurrences),
+            )
+            .route(
+                "/{id}/occurrences/{occurrence_start}",
+                web::put().to(override_occurrence::override_occurrence),
+            )
+            .route(
+                "/{id}/occurrences/{occurrence_start}",
+                web::delete().to(delete_occurrence_override::delete_occurrence_override),
+            ),
+    )
+    .service(
+        web::scope("/api/venues")
+            .route("", web::post().to(create_venue::create_venue))
+            .route("/{id}", web::get().to(get_venue::get_venue))
+            .route(
+                "/{id}/events.ics",
+                web::get().to(get_venue_calendar::get_venue_calendar),
+            )
+            .route(
+                "/{id}/seating-chart",
+                web::get().to(get_seating_chart::get_seating_chart),
+            )
+            .route(
+                "/{id}/seating-chart",
+                web::put().to(import_seating_chart::import_seating_chart),
+            ),
+    )
+    .service(
+        web::scope("/api/categories")
+            .route("", web::post().to(create_category::create_category))
+            .route("", web::get().to(list_categories::list_categories))
+            .route("/{id}", web::patch().to(update_category::update_category))
+            .route("/{id}", web::delete().to(delete_category::delete_category)),
+    )
+    .service(
+        web::scope("/api/imports")
+            .service(
+                web::resource("")
+                    .app_data(web::PayloadConfig::new(MAX_IMPORT_BYTES))
+                    .route(web::post().to(import_events::import_events)),
+            )
+            .route("/{id}", web::get().to(get_import_job::get_import_job)),
+    )
+    .service(
+        web::scope("/api/organizers")
+            .route(
+                "/{id}/events.ics",
+                web::get().to(get_organizer_calendar::get_organizer_calendar),
+            )
+            .route(
+                "/{id}/events",
+                web::get().to(list_organizer_events::list_organizer_events),
+            ),
     );
 }
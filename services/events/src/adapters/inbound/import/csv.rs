@@ -0,0 +1,178 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::{CreateEventRequest, ImportFileError, ParsedEntry};
+
+const REQUIRED_COLUMNS: [&str; 3] = ["title", "event_date", "venue_name"];
+
+struct Columns {
+    title: usize,
+    event_date: usize,
+    venue_name: usize,
+    description: Option<usize>,
+    venue_id: Option<usize>,
+    timezone: Option<usize>,
+    duration_minutes: Option<usize>,
+    uid: Option<usize>,
+}
+
+impl Columns {
+    fn from_headers(headers: &::csv::StringRecord) -> Result<Self, ImportFileError> {
+        let find = |name: &str| {
+            headers
+                .iter()
+                .position(|header| header.trim().eq_ignore_ascii_case(name))
+        };
+        let require = |name: &'static str| find(name).ok_or(ImportFileError::MissingColumn(name));
+
+        Ok(Self {
+            title: require(REQUIRED_COLUMNS[0])?,
+            event_date: require(REQUIRED_COLUMNS[1])?,
+            venue_name: require(REQUIRED_COLUMNS[2])?,
+            description: find("description"),
+            venue_id: find("venue_id"),
+            timezone: find("timezone"),
+            duration_minutes: find("duration_minutes"),
+            uid: find("uid"),
+        })
+    }
+}
+
+fn field(record: &::csv::StringRecord, index: Option<usize>) -> Option<&str> {
+    index
+        .and_then(|i| record.get(i))
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn parse_uuid(value: Option<&str>, column: &str) -> Result<Option<Uuid>, String> {
+    value
+        .map(|v| Uuid::parse_str(v).map_err(|_| format!("Invalid {} `{}`", column, v)))
+        .transpose()
+}
+
+fn parse_record(
+    columns: &Columns,
+    record: &::csv::StringRecord,
+) -> Result<CreateEventRequest, String> {
+    let event_date = field(record, Some(columns.event_date))
+        .ok_or_else(|| "event_date is required".to_string())
+        .and_then(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|_| format!("Invalid event_date `{}`; expected RFC 3339", value))
+        })?;
+
+    let duration_minutes = field(record, columns.duration_minutes)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| format!("Invalid duration_minutes `{}`", value))
+        })
+        .transpose()?;
+
+    Ok(CreateEventRequest {
+        title: field(record, Some(columns.title))
+            .unwrap_or_default()
+            .to_string(),
+        description: field(record, columns.description)
+            .unwrap_or_default()
+            .to_string(),
+        event_date: Some(event_date),
+        starts_at: None,
+        timezone: field(record, columns.timezone).map(str::to_string),
+        ends_at: None,
+        duration_minutes,
+        doors_open_at: None,
+        venue_name: field(record, Some(columns.venue_name))
+            .unwrap_or_default()
+            .to_string(),
+        venue_id: parse_uuid(field(record, columns.venue_id), "venue_id")?,
+        organization_id: None,
+        category_ids: Vec::new(),
+        tags: Vec::new(),
+        publish_at: None,
+        on_sale_at: None,
+        default_locale: None,
+    })
+}
+
+/// Line number of the record starting at `offset`. The reader reports
+/// positions before any blank lines it skipped, so those are stepped over.
+fn line_of(input: &str, offset: usize) -> usize {
+    let bytes = input.as_bytes();
+    let start = offset
+        + bytes[offset..]
+            .iter()
+            .take_while(|&&b| b == b'\r' || b == b'\n')
+            .count();
+    bytes[..start].iter().filter(|&&b| b == b'\n').count() + 1
+}
+
+/// Reads one entry per data row. The header row names the columns; `title`,
+/// `event_date` and `venue_name` are required, `description`, `venue_id`,
+/// `timezone`, `duration_minutes` and `uid` optional.
+pub(super) fn parse(input: &str) -> Result<Vec<ParsedEntry>, ImportFileError> {
+    let mut reader = ::csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(input.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| ImportFileError::Csv(e.to_string()))?
+        .clone();
+    let columns = Columns::from_headers(&headers)?;
+
+    let mut entries = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| ImportFileError::Csv(e.to_string()))?;
+        if record.iter().all(|value| value.trim().is_empty()) {
+            continue;
+        }
+        let line = record
+            .position()
+            .map(|position| line_of(input, position.byte() as usize))
+            .unwrap_or_default();
+
+        entries.push(ParsedEntry {
+            line,
+            uid: field(&record, columns.uid).map(str::to_string),
+            request: parse_record(&columns, &record),
+        });
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reports_rows_by_line() {
+        let input = "Title,Event_Date,Venue_Name,UID\n\
+Comedy,2026-11-05T20:00:00Z,Cellar,c-1\n\
+\n\
+Broken,next thursday,Cellar,c-2\n";
+
+        let entries = parse(input).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].line, 2);
+        assert_eq!(entries[0].uid.as_deref(), Some("c-1"));
+        assert_eq!(entries[0].request.as_ref().unwrap().venue_name, "Cellar");
+        assert_eq!(entries[1].line, 4);
+        assert!(entries[1]
+            .request
+            .as_ref()
+            .unwrap_err()
+            .contains("event_date"));
+    }
+
+    #[test]
+    fn test_parse_requires_columns() {
+        assert!(matches!(
+            parse("title,venue_name\nA,B\n"),
+            Err(ImportFileError::MissingColumn("event_date"))
+        ));
+    }
+}
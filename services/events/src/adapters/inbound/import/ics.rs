@@ -0,0 +1,287 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use super::{CreateEventRequest, ImportFileError, ParsedEntry};
+
+/// A logical content line after unfolding.
+struct ContentLine<'a> {
+    name: String,
+    params: Vec<(String, &'a str)>,
+    value: &'a str,
+}
+
+impl ContentLine<'_> {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.trim_matches('"'))
+    }
+}
+
+/// Joins folded lines (continuations start with a space or tab), keeping the
+/// number of the line each logical line starts on.
+fn unfold(input: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    for (i, raw) in input.split('\n').enumerate() {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some((_, last))) => last.push_str(continuation),
+            _ if raw.is_empty() => {}
+            _ => lines.push((i + 1, raw.to_string())),
+        }
+    }
+    lines
+}
+
+fn parse_content_line(text: &str) -> Option<ContentLine<'_>> {
+    // The value starts at the first colon outside a quoted parameter value.
+    let mut in_quotes = false;
+    let colon = text.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            in_quotes = !in_quotes;
+            None
+        }
+        ':' if !in_quotes => Some(i),
+        _ => None,
+    })?;
+
+    let mut head = text[..colon].split(';');
+    let name = head.next()?.trim().to_ascii_uppercase();
+    let params = head
+        .filter_map(|param| param.split_once('='))
+        .map(|(key, value)| (key.trim().to_ascii_uppercase(), value))
+        .collect();
+
+    Some(ContentLine {
+        name,
+        params,
+        value: &text[colon + 1..],
+    })
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+fn parse_date_time(property: &ContentLine) -> Result<DateTime<Utc>, String> {
+    let value = property.value.trim();
+    let invalid = || format!("Invalid {} value `{}`", property.name, value);
+
+    let local = if property.param("VALUE") == Some("DATE") || value.len() == 8 {
+        NaiveDate::parse_from_str(value, "%Y%m%d")
+            .map_err(|_| invalid())?
+            .and_hms_opt(0, 0, 0)
+            .ok_or_else(invalid)?
+    } else if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").map_err(|_| invalid())?;
+        return Ok(Utc.from_utc_datetime(&naive));
+    } else {
+        NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| invalid())?
+    };
+
+    match property.param("TZID") {
+        Some(tzid) => {
+            let tz: Tz = tzid
+                .parse()
+                .map_err(|_| format!("Unknown time zone `{}`", tzid))?;
+            tz.from_local_datetime(&local)
+                .earliest()
+                .map(|dt| dt.with_timezone(&Utc))
+                .ok_or_else(|| format!("`{}` does not exist in {}", value, tzid))
+        }
+        // Floating times carry no zone; they are read as UTC.
+        None => Ok(Utc.from_utc_datetime(&local)),
+    }
+}
+
+fn duration_minutes(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> Result<u32, String> {
+    u32::try_from((ends_at - starts_at).num_minutes())
+        .ok()
+        .filter(|minutes| *minutes > 0)
+        .ok_or_else(|| "DTEND must be after DTSTART".to_string())
+}
+
+#[derive(Default)]
+struct VEvent<'a> {
+    line: usize,
+    uid: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    location: Option<String>,
+    dtstart: Option<ContentLine<'a>>,
+    dtend: Option<ContentLine<'a>>,
+    recurring: bool,
+    cancelled: bool,
+}
+
+impl From<VEvent<'_>> for ParsedEntry {
+    fn from(event: VEvent<'_>) -> Self {
+        let request = if event.recurring {
+            Err("Recurring events (RRULE/RDATE) are not imported; create a series instead".into())
+        } else if event.cancelled {
+            Err("Cancelled events are not imported".into())
+        } else {
+            event
+                .dtstart
+                .as_ref()
+                .ok_or_else(|| "DTSTART is required".to_string())
+                .and_then(|dtstart| {
+                    let event_date = parse_date_time(dtstart)?;
+                    let duration_minutes = event
+                        .dtend
+                        .as_ref()
+                        .map(|dtend| duration_minutes(event_date, parse_date_time(dtend)?))
+                        .transpose()?;
+                    Ok(CreateEventRequest {
+                        title: event.summary.unwrap_or_default(),
+                        description: event.description.unwrap_or_default(),
+                        event_date: Some(event_date),
+                        starts_at: None,
+                        // Keep the source zone so times are shown as the partner meant them
+                        timezone: dtstart.param("TZID").map(str::to_string),
+                        ends_at: None,
+                        duration_minutes,
+                        doors_open_at: None,
+                        venue_name: event.location.unwrap_or_default(),
+                        venue_id: None,
+                        organization_id: None,
+                        category_ids: Vec::new(),
+                        tags: Vec::new(),
+                        publish_at: None,
+                        on_sale_at: None,
+                        default_locale: None,
+                    })
+                })
+        };
+
+        ParsedEntry {
+            line: event.line,
+            uid: event.uid,
+            request,
+        }
+    }
+}
+
+/// Extracts one entry per top-level VEVENT; nested components such as
+/// VALARM are skipped.
+pub(super) fn parse(input: &str) -> Result<Vec<ParsedEntry>, ImportFileError> {
+    let lines = unfold(input);
+    if !lines
+        .iter()
+        .any(|(_, text)| text.eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err(ImportFileError::NotICalendar);
+    }
+
+    let mut entries = Vec::new();
+    let mut current: Option<VEvent> = None;
+    let mut nested_depth = 0usize;
+
+    for (line, text) in &lines {
+        let Some(property) = parse_content_line(text) else {
+            continue;
+        };
+        let value = property.value.trim().to_ascii_uppercase();
+
+        match (property.name.as_str(), current.as_mut()) {
+            ("BEGIN", None) if value == "VEVENT" => {
+                current = Some(VEvent {
+                    line: *line,
+                    ..Default::default()
+                });
+            }
+            ("BEGIN", Some(_)) => nested_depth += 1,
+            ("END", Some(_)) if nested_depth > 0 => nested_depth -= 1,
+            ("END", Some(_)) if value == "VEVENT" => {
+                if let Some(event) = current.take() {
+                    entries.push(event.into());
+                }
+            }
+            (_, Some(_)) if nested_depth > 0 => {}
+            ("UID", Some(event)) => event.uid = Some(property.value.trim().to_string()),
+            ("SUMMARY", Some(event)) => event.summary = Some(unescape(property.value)),
+            ("DESCRIPTION", Some(event)) => event.description = Some(unescape(property.value)),
+            ("LOCATION", Some(event)) => event.location = Some(unescape(property.value)),
+            ("RRULE" | "RDATE", Some(event)) => event.recurring = true,
+            ("STATUS", Some(event)) => event.cancelled = value == "CANCELLED",
+            ("DTSTART", Some(event)) => event.dtstart = Some(property),
+            ("DTEND", Some(event)) => event.dtend = Some(property),
+            _ => {}
+        }
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+UID:gig-1@partner\r\n\
+DTSTART;TZID=Europe/Berlin:20261105T200000\r\n\
+DTEND;TZID=Europe/Berlin:20261105T223000\r\n\
+SUMMARY:Jazz\\, late\r\n\
+DESCRIPTION:First line\\nsecond \r\n line\r\n\
+LOCATION:Blue Room\r\n\
+BEGIN:VALARM\r\n\
+DESCRIPTION:Reminder\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:weekly@partner\r\n\
+DTSTART:20261106T190000Z\r\n\
+RRULE:FREQ=WEEKLY\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn test_parse_extracts_events_and_converts_time_zones() {
+        let entries = parse(CALENDAR).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        let first = &entries[0];
+        assert_eq!(first.line, 3);
+        assert_eq!(first.uid.as_deref(), Some("gig-1@partner"));
+        let request = first.request.as_ref().unwrap();
+        assert_eq!(request.title, "Jazz, late");
+        assert_eq!(request.description, "First line\nsecond line");
+        assert_eq!(request.venue_name, "Blue Room");
+        assert_eq!(
+            request.event_date.unwrap().to_rfc3339(),
+            "2026-11-05T19:00:00+00:00"
+        );
+        assert_eq!(request.timezone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(request.duration_minutes, Some(150));
+
+        assert!(entries[1]
+            .request
+            .as_ref()
+            .unwrap_err()
+            .contains("Recurring"));
+    }
+
+    #[test]
+    fn test_parse_rejects_non_calendar_input() {
+        assert!(matches!(
+            parse("title,event_date\n"),
+            Err(ImportFileError::NotICalendar)
+        ));
+    }
+}
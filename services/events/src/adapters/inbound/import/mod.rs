@@ -0,0 +1,100 @@
+//! Parsing of partner schedule files into importable entries. Every entry is
+//! validated through the same `CreateEventRequest` rules as the HTTP API.
+
+mod csv;
+mod ics;
+
+use std::path::Path;
+
+use crate::domain::use_cases::{CreateEventParams, ImportEntry};
+
+use super::http::dtos::CreateEventRequest;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    ICalendar,
+    Csv,
+}
+
+impl ImportFormat {
+    /// Parses a format name as given on the command line or in a query
+    /// string.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "ics" | "ical" | "icalendar" => Some(Self::ICalendar),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next()?.trim().to_ascii_lowercase();
+        match mime.as_str() {
+            "text/calendar" => Some(Self::ICalendar),
+            "text/csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Self::from_name)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportFileError {
+    #[error("The file is not valid UTF-8")]
+    NotUtf8,
+    #[error("Not an iCalendar file: BEGIN:VCALENDAR is missing")]
+    NotICalendar,
+    #[error("Missing required CSV column `{0}`")]
+    MissingColumn(&'static str),
+    #[error("Malformed CSV: {0}")]
+    Csv(String),
+}
+
+/// A file entry before validation. `request` is an error when the entry
+/// cannot even be mapped onto a request, e.g. an unparseable date.
+struct ParsedEntry {
+    line: usize,
+    uid: Option<String>,
+    request: Result<CreateEventRequest, String>,
+}
+
+impl From<ParsedEntry> for ImportEntry {
+    fn from(entry: ParsedEntry) -> Self {
+        let params = entry
+            .request
+            .and_then(|request| CreateEventParams::try_from(request).map_err(|e| e.to_string()));
+
+        match params {
+            Ok(params) => ImportEntry::Valid {
+                line: entry.line,
+                uid: entry.uid,
+                params: Box::new(params),
+            },
+            Err(error) => ImportEntry::Invalid {
+                line: entry.line,
+                uid: entry.uid,
+                error,
+            },
+        }
+    }
+}
+
+pub fn parse_entries(
+    format: ImportFormat,
+    bytes: &[u8],
+) -> Result<Vec<ImportEntry>, ImportFileError> {
+    let input = std::str::from_utf8(bytes).map_err(|_| ImportFileError::NotUtf8)?;
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+    let entries = match format {
+        ImportFormat::ICalendar => ics::parse(input)?,
+        ImportFormat::Csv => csv::parse(input)?,
+    };
+
+    Ok(entries.into_iter().map(Into::into).collect())
+}
@@ -1 +1,4 @@
+pub mod cli;
 pub mod http;
+pub mod import;
+pub mod scheduler;
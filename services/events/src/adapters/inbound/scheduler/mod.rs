@@ -0,0 +1,106 @@
+use chrono::Utc;
+use shared::UseCase;
+use std::{sync::Arc, time::Duration};
+use tokio::time::MissedTickBehavior;
+
+use crate::domain::{
+    DispatchDomainEventsUseCase, RunEventCancellationsUseCase, RunImportJobsUseCase,
+    RunPublishingScheduleUseCase,
+};
+
+/// Outbox messages delivered per dispatch run.
+const DISPATCH_BATCH_SIZE: u64 = 100;
+
+/// Background loop that publishes scheduled events, opens ticket sales,
+/// works through cancellations and delivers the resulting domain events.
+/// Queued imports run in a loop of their own, so a large file doesn't hold
+/// up the rest.
+///
+/// Every replica runs its own scheduler. Due rows, import jobs and outbox
+/// messages are claimed with `SKIP LOCKED`, so replicas never transition
+/// the same event or work on the same cancellation or import twice, and
+/// anything missed while the service was down is picked up on the first
+/// tick after a restart.
+pub struct Scheduler {
+    publishing: Arc<RunPublishingScheduleUseCase>,
+    cancellations: Arc<RunEventCancellationsUseCase>,
+    dispatch: Arc<DispatchDomainEventsUseCase>,
+    imports: Arc<RunImportJobsUseCase>,
+    interval: Duration,
+}
+
+impl Scheduler {
+    pub fn new(
+        publishing: Arc<RunPublishingScheduleUseCase>,
+        cancellations: Arc<RunEventCancellationsUseCase>,
+        dispatch: Arc<DispatchDomainEventsUseCase>,
+        imports: Arc<RunImportJobsUseCase>,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            publishing,
+            cancellations,
+            dispatch,
+            imports,
+            interval,
+        }
+    }
+
+    pub async fn run(self) {
+        tokio::spawn(run_imports(self.imports.clone(), self.interval));
+
+        let mut ticker = tokio::time::interval(self.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            self.tick().await;
+        }
+    }
+
+    async fn tick(&self) {
+        match self.publishing.execute(Utc::now()).await {
+            Ok(emitted) if !emitted.is_empty() => {
+                log::info!("Scheduler emitted {} domain event(s)", emitted.len())
+            }
+            Ok(_) => {}
+            Err(e) => log::error!("Scheduled publishing failed: {}", e),
+        }
+
+        match self.cancellations.execute(Utc::now()).await {
+            Ok(completed) if completed > 0 => {
+                log::info!("Scheduler completed {} cancellation(s)", completed)
+            }
+            Ok(_) => {}
+            Err(e) => log::error!("Running cancellations failed: {}", e),
+        }
+
+        // Drain the outbox, including messages left behind by other replicas
+        loop {
+            match self.dispatch.execute(DISPATCH_BATCH_SIZE).await {
+                Ok(count) if count as u64 == DISPATCH_BATCH_SIZE => continue,
+                Ok(_) => break,
+                Err(e) => {
+                    log::error!("Domain event dispatch failed: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+async fn run_imports(imports: Arc<RunImportJobsUseCase>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        match imports.execute(Utc::now()).await {
+            Ok(finished) if finished > 0 => {
+                log::info!("Scheduler finished {} import job(s)", finished)
+            }
+            Ok(_) => {}
+            Err(e) => log::error!("Running import jobs failed: {}", e),
+        }
+    }
+}
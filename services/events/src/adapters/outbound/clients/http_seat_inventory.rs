@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, DomainResult, SeatInventory, SeatStatus};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum TakenSeatState {
+    Held,
+    Sold,
+}
+
+#[derive(Deserialize)]
+struct TakenSeatResponse {
+    seat_id: Uuid,
+    status: TakenSeatState,
+}
+
+#[derive(Deserialize)]
+struct EventSeatsResponse {
+    seats: Vec<TakenSeatResponse>,
+}
+
+/// Reads which seats are held or sold from the tickets service's HTTP API.
+pub struct HttpSeatInventory {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl HttpSeatInventory {
+    /// `base_url` is the service root, e.g. `http://tickets-service:8082`.
+    pub fn new(base_url: &str) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("HTTP client configuration is valid");
+        Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl SeatInventory for HttpSeatInventory {
+    async fn seat_statuses(&self, event_id: Uuid) -> DomainResult<HashMap<Uuid, SeatStatus>> {
+        let response = self
+            .client
+            .get(format!(
+                "{}/tickets/api/events/{}/seats",
+                self.base_url, event_id
+            ))
+            .send()
+            .await
+            .map_err(|e| DomainError::DatabaseError(format!("Tickets service: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(DomainError::DatabaseError(format!(
+                "Tickets service responded with {}",
+                response.status()
+            )));
+        }
+        let seats: EventSeatsResponse = response
+            .json()
+            .await
+            .map_err(|e| DomainError::DatabaseError(format!("Tickets service: {}", e)))?;
+
+        Ok(seats
+            .seats
+            .into_iter()
+            .map(|seat| {
+                let status = match seat.status {
+                    TakenSeatState::Held => SeatStatus::Held,
+                    TakenSeatState::Sold => SeatStatus::Sold,
+                };
+                (seat.seat_id, status)
+            })
+            .collect())
+    }
+}
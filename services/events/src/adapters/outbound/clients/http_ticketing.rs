@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::{ServiceToken, SERVICE_TOKEN_HEADER};
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, DomainResult, Ticketing};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ReleasableState {
+    Held,
+    Confirmed,
+}
+
+#[derive(Serialize)]
+struct ReleaseEventReservationsRequest {
+    status: ReleasableState,
+}
+
+#[derive(Deserialize)]
+struct ReleaseEventReservationsResponse {
+    released: u32,
+}
+
+#[derive(Deserialize)]
+struct EventHoldersResponse {
+    holders: Vec<Uuid>,
+}
+
+/// Calls off an event's ticket sales through the tickets service's HTTP API.
+/// There are no payments, so refunding an order voids it: the reservation is
+/// released and its tickets stop being valid. The tickets service only does
+/// this once it sees the event as cancelled here, and only for callers
+/// sending the services' shared token.
+pub struct HttpTicketing {
+    client: reqwest::Client,
+    base_url: String,
+    token: ServiceToken,
+}
+
+impl HttpTicketing {
+    /// `base_url` is the service root, e.g. `http://tickets-service:8082`.
+    pub fn new(base_url: &str, token: ServiceToken) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("HTTP client configuration is valid");
+        Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token,
+        }
+    }
+
+    /// Releases the event's reservations in `status` and returns how many.
+    async fn release(&self, event_id: Uuid, status: ReleasableState) -> DomainResult<u32> {
+        let response = self
+            .client
+            .post(format!(
+                "{}/tickets/api/events/{}/reservations/release",
+                self.base_url, event_id
+            ))
+            .header(SERVICE_TOKEN_HEADER, self.token.as_str())
+            .json(&ReleaseEventReservationsRequest { status })
+            .send()
+            .await
+            .map_err(|e| DomainError::DatabaseError(format!("Tickets service: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(DomainError::DatabaseError(format!(
+                "Tickets service responded with {}",
+                response.status()
+            )));
+        }
+        let released: ReleaseEventReservationsResponse = response
+            .json()
+            .await
+            .map_err(|e| DomainError::DatabaseError(format!("Tickets service: {}", e)))?;
+        Ok(released.released)
+    }
+}
+
+#[async_trait]
+impl Ticketing for HttpTicketing {
+    async fn stop_sales(&self, event_id: Uuid) -> DomainResult<()> {
+        self.release(event_id, ReleasableState::Held).await?;
+        Ok(())
+    }
+
+    async fn refund_orders(&self, event_id: Uuid) -> DomainResult<u32> {
+        self.release(event_id, ReleasableState::Confirmed).await
+    }
+
+    async fn ticket_holders(&self, event_id: Uuid) -> DomainResult<Vec<Uuid>> {
+        let response = self
+            .client
+            .get(format!(
+                "{}/tickets/api/events/{}/holders",
+                self.base_url, event_id
+            ))
+            .header(SERVICE_TOKEN_HEADER, self.token.as_str())
+            .send()
+            .await
+            .map_err(|e| DomainError::DatabaseError(format!("Tickets service: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(DomainError::DatabaseError(format!(
+                "Tickets service responded with {}",
+                response.status()
+            )));
+        }
+        let holders: EventHoldersResponse = response
+            .json()
+            .await
+            .map_err(|e| DomainError::DatabaseError(format!("Tickets service: {}", e)))?;
+        Ok(holders.holders)
+    }
+}
@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::{entities::OrganizationRole, DomainError, DomainResult, UsersClient};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum MemberRole {
+    Owner,
+    Manager,
+    BoxOffice,
+    Viewer,
+}
+
+impl From<MemberRole> for OrganizationRole {
+    fn from(role: MemberRole) -> Self {
+        match role {
+            MemberRole::Owner => OrganizationRole::Owner,
+            MemberRole::Manager => OrganizationRole::Manager,
+            MemberRole::BoxOffice => OrganizationRole::BoxOffice,
+            MemberRole::Viewer => OrganizationRole::Viewer,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct MemberResponse {
+    role: MemberRole,
+}
+
+/// Calls the users service over its public HTTP API.
+pub struct HttpUsersClient {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl HttpUsersClient {
+    /// `base_url` is the service root, e.g. `http://users-service:8080`.
+    pub fn new(base_url: &str) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("HTTP client configuration is valid");
+        Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Fetches `path`, returning `None` on 404.
+    async fn get(&self, path: &str) -> DomainResult<Option<reqwest::Response>> {
+        let response = self
+            .client
+            .get(format!("{}{}", self.base_url, path))
+            .send()
+            .await
+            .map_err(|e| DomainError::DatabaseError(format!("Users service: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(None),
+            status if status.is_success() => Ok(Some(response)),
+            status => Err(DomainError::DatabaseError(format!(
+                "Users service responded with {}",
+                status
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl UsersClient for HttpUsersClient {
+    async fn user_exists(&self, id: Uuid) -> DomainResult<bool> {
+        Ok(self
+            .get(&format!("/users/api/users/{}", id))
+            .await?
+            .is_some())
+    }
+
+    async fn find_organization_role(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<Option<OrganizationRole>> {
+        let Some(response) = self
+            .get(&format!(
+                "/users/api/organizations/{}/members/{}",
+                organization_id, user_id
+            ))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let member: MemberResponse = response
+            .json()
+            .await
+            .map_err(|e| DomainError::DatabaseError(format!("Users service: {}", e)))?;
+        Ok(Some(member.role.into()))
+    }
+}
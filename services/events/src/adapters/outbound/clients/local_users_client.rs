@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{entities::OrganizationRole, DomainResult, UsersClient};
+
+/// Stand-in for the users service for local development: every ID belongs to
+/// an existing user, and nobody belongs to an organization.
+pub struct LocalUsersClient;
+
+#[async_trait]
+impl UsersClient for LocalUsersClient {
+    async fn user_exists(&self, _id: Uuid) -> DomainResult<bool> {
+        Ok(true)
+    }
+
+    async fn find_organization_role(
+        &self,
+        _organization_id: Uuid,
+        _user_id: Uuid,
+    ) -> DomainResult<Option<OrganizationRole>> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,13 @@
+pub mod http_seat_inventory;
+pub mod http_ticketing;
+pub mod http_users_client;
+pub mod local_seat_inventory;
+pub mod local_ticketing;
+pub mod local_users_client;
+
+pub use http_seat_inventory::HttpSeatInventory;
+pub use http_ticketing::HttpTicketing;
+pub use http_users_client::HttpUsersClient;
+pub use local_seat_inventory::LocalSeatInventory;
+pub use local_ticketing::LocalTicketing;
+pub use local_users_client::LocalUsersClient;
@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use image::{codecs::jpeg::JpegEncoder, DynamicImage, ImageFormat, ImageReader, Limits};
+use std::io::Cursor;
+
+use crate::domain::{
+    entities::MediaFormat, ports::ProcessedImage, DomainError, DomainResult, ImageProcessor,
+};
+
+/// Longest edge of a generated thumbnail, in pixels.
+const THUMBNAIL_SIZE: u32 = 480;
+const THUMBNAIL_QUALITY: u8 = 80;
+/// Longest edge of an image we decode. A few kilobytes of PNG or WebP can
+/// claim far larger dimensions than the upload limit suggests.
+const MAX_DIMENSION: u32 = 10_000;
+/// Memory a single decode may allocate.
+const MAX_DECODE_BYTES: u64 = 128 * 1024 * 1024;
+
+/// Decodes images with the `image` crate and renders JPEG thumbnails on the
+/// blocking thread pool.
+pub struct ImageThumbnailer;
+
+/// Decodes `bytes` within the dimension and memory limits; `None` when the
+/// image is broken or too large to decode.
+fn decode(bytes: &[u8], format: ImageFormat) -> Option<DynamicImage> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+    limits.max_alloc = Some(MAX_DECODE_BYTES);
+
+    let mut reader = ImageReader::with_format(Cursor::new(bytes), format);
+    reader.limits(limits);
+    reader.decode().ok()
+}
+
+fn render(bytes: &[u8], format: ImageFormat) -> Result<Option<ProcessedImage>, String> {
+    let Some(image) = decode(bytes, format) else {
+        return Ok(None);
+    };
+    let (width, height) = (image.width(), image.height());
+
+    let thumbnail = if width > THUMBNAIL_SIZE || height > THUMBNAIL_SIZE {
+        image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+    } else {
+        image
+    };
+    let mut encoded = Vec::new();
+    DynamicImage::ImageRgb8(thumbnail.to_rgb8())
+        .write_with_encoder(JpegEncoder::new_with_quality(
+            &mut encoded,
+            THUMBNAIL_QUALITY,
+        ))
+        .map_err(|e| e.to_string())?;
+
+    Ok(Some(ProcessedImage {
+        width,
+        height,
+        thumbnail: encoded,
+    }))
+}
+
+#[async_trait]
+impl ImageProcessor for ImageThumbnailer {
+    async fn process(
+        &self,
+        bytes: Vec<u8>,
+        format: MediaFormat,
+    ) -> DomainResult<Option<ProcessedImage>> {
+        let format = match format {
+            MediaFormat::Jpeg => ImageFormat::Jpeg,
+            MediaFormat::Png => ImageFormat::Png,
+            MediaFormat::Webp => ImageFormat::WebP,
+            MediaFormat::Mp4 => return Ok(None),
+        };
+
+        tokio::task::spawn_blocking(move || render(&bytes, format))
+            .await
+            .map_err(|e| DomainError::DatabaseError(format!("Thumbnail task failed: {}", e)))?
+            .map_err(|e| DomainError::DatabaseError(format!("Thumbnail encoding failed: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::GrayImage;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        GrayImage::new(width, height)
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_images_beyond_the_decode_limits_are_unreadable() {
+        let processed = render(&png(600, 300), ImageFormat::Png).unwrap().unwrap();
+        assert_eq!((processed.width, processed.height), (600, 300));
+
+        // A blank strip compresses to almost nothing whatever its size
+        let strip = png(MAX_DIMENSION + 1, 1);
+        assert!(strip.len() < 1024);
+        assert!(render(&strip, ImageFormat::Png).unwrap().is_none());
+        assert!(render(b"not an image", ImageFormat::Png).unwrap().is_none());
+    }
+}
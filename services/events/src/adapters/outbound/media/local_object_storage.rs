@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use crate::domain::{DomainError, DomainResult, ObjectStorage};
+
+/// Stores objects as files under a root directory, served by this service
+/// under `base_url`.
+pub struct LocalObjectStorage {
+    root: PathBuf,
+    base_url: String,
+}
+
+impl LocalObjectStorage {
+    pub fn new(root: impl Into<PathBuf>, base_url: &str) -> Self {
+        Self {
+            root: root.into(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn path(&self, key: &str) -> DomainResult<PathBuf> {
+        if key
+            .split('/')
+            .any(|segment| segment.is_empty() || segment == "." || segment == "..")
+        {
+            return Err(DomainError::DatabaseError(format!(
+                "Invalid storage key `{}`",
+                key
+            )));
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+fn storage_error(e: std::io::Error) -> DomainError {
+    DomainError::DatabaseError(format!("Media storage: {}", e))
+}
+
+#[async_trait]
+impl ObjectStorage for LocalObjectStorage {
+    async fn put(&self, key: &str, bytes: Vec<u8>, _content_type: &str) -> DomainResult<()> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(storage_error)?;
+        }
+        tokio::fs::write(&path, bytes).await.map_err(storage_error)
+    }
+
+    async fn copy(&self, from: &str, to: &str) -> DomainResult<()> {
+        let (from, to) = (self.path(from)?, self.path(to)?);
+        if let Some(parent) = to.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(storage_error)?;
+        }
+        tokio::fs::copy(&from, &to)
+            .await
+            .map(|_| ())
+            .map_err(storage_error)
+    }
+
+    async fn delete(&self, key: &str) -> DomainResult<()> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(storage_error(e)),
+            _ => Ok(()),
+        }
+    }
+
+    fn url(&self, key: &str) -> String {
+        format!("{}/{}", self.base_url, key)
+    }
+}
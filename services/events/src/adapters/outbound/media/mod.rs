@@ -0,0 +1,7 @@
+pub mod image_thumbnailer;
+pub mod local_object_storage;
+pub mod s3_object_storage;
+
+pub use image_thumbnailer::ImageThumbnailer;
+pub use local_object_storage::LocalObjectStorage;
+pub use s3_object_storage::{S3Config, S3ObjectStorage};
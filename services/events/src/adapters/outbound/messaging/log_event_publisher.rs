@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::{entities::OutboxMessage, DomainEventPublisher, DomainResult};
+
+/// Stand-in for a message broker: writes each domain event to the log.
+pub struct LogEventPublisher;
+
+#[async_trait]
+impl DomainEventPublisher for LogEventPublisher {
+    async fn publish(&self, message: &OutboxMessage) -> DomainResult<()> {
+        log::info!(
+            "Domain event {} {} for event {}",
+            message.id(),
+            message.event().name(),
+            message.event().event_id()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{DomainResult, Event, HolderNotifier};
+
+/// Stand-in for email and push delivery: writes each notification to the
+/// log.
+pub struct LogHolderNotifier;
+
+#[async_trait]
+impl HolderNotifier for LogHolderNotifier {
+    async fn notify_cancelled(
+        &self,
+        holder_id: Uuid,
+        event: &Event,
+        reason: &str,
+    ) -> DomainResult<()> {
+        log::info!(
+            "Notifying {} that event {} is cancelled: {}",
+            holder_id,
+            event.id(),
+            reason
+        );
+        Ok(())
+    }
+}
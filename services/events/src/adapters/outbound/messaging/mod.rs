@@ -0,0 +1,7 @@
+pub mod log_event_publisher;
+pub mod log_holder_notifier;
+pub mod webhook_event_publisher;
+
+pub use log_event_publisher::LogEventPublisher;
+pub use log_holder_notifier::LogHolderNotifier;
+pub use webhook_event_publisher::WebhookEventPublisher;
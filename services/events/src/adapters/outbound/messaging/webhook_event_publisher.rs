@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{DomainEvent, OutboxMessage},
+    DomainError, DomainEventPublisher, DomainResult,
+};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Body of each delivery: the domain event with its `type`, plus the message
+/// id consumers deduplicate on.
+#[derive(Serialize)]
+struct Delivery<'a> {
+    id: Uuid,
+    occurred_at: DateTime<Utc>,
+    #[serde(flatten)]
+    event: &'a DomainEvent,
+}
+
+/// POSTs each domain event as JSON to a fixed URL; any non-2xx response is
+/// a failed delivery and is retried.
+pub struct WebhookEventPublisher {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl WebhookEventPublisher {
+    pub fn new(url: &str) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("HTTP client configuration is valid");
+        Self {
+            client,
+            url: url.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl DomainEventPublisher for WebhookEventPublisher {
+    async fn publish(&self, message: &OutboxMessage) -> DomainResult<()> {
+        self.client
+            .post(&self.url)
+            .json(&Delivery {
+                id: message.id(),
+                occurred_at: *message.occurred_at(),
+                event: message.event(),
+            })
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| {
+                DomainError::DatabaseError(format!("Domain event delivery failed: {}", e))
+            })?;
+        Ok(())
+    }
+}
@@ -1 +1,4 @@
+pub mod clients;
+pub mod media;
+pub mod messaging;
 pub mod persistence;
@@ -1,4 +1,8 @@
 pub mod models;
 pub mod repositories;
 
-pub use repositories::EventRepositoryImpl;
+pub use repositories::{
+    CancellationRepositoryImpl, CategoryRepositoryImpl, EventRepositoryImpl,
+    EventSeriesRepositoryImpl, EventTemplateRepositoryImpl, ImportJobRepositoryImpl,
+    OutboxRepositoryImpl, VenueRepositoryImpl,
+};
@@ -3,7 +3,30 @@ use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::Event;
+use crate::domain::{
+    entities::{EventSchedule, EventStatus, Publication, RichText},
+    Event,
+};
+
+use super::{parse_locale, parse_timezone};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum Status {
+    #[sea_orm(string_value = "scheduled")]
+    Scheduled,
+    #[sea_orm(string_value = "cancelled")]
+    Cancelled,
+}
+
+impl From<Status> for EventStatus {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Scheduled => EventStatus::Scheduled,
+            Status::Cancelled => EventStatus::Cancelled,
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "events")]
@@ -11,9 +34,32 @@ pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
     pub title: String,
+    /// Markdown source.
     pub description: String,
+    /// Sanitized rendering of `description`; rows written before rich text
+    /// support have none and are rendered when loaded.
+    pub description_html: Option<String>,
+    /// BCP 47 tag of the locale `title` and `description` are in.
+    pub default_locale: String,
     pub event_date: DateTime<Utc>,
+    /// IANA timezone name, e.g. `America/Chicago`.
+    pub timezone: String,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub doors_open_at: Option<DateTime<Utc>>,
     pub venue_name: String,
+    pub venue_id: Option<Uuid>,
+    pub organizer_id: Option<Uuid>,
+    pub organization_id: Option<Uuid>,
+    pub external_uid: Option<String>,
+    pub status: Status,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub on_sale_at: Option<DateTime<Utc>>,
+    /// Set once the event is public; unpublished events are only visible to
+    /// the people who manage them.
+    pub published_at: Option<DateTime<Utc>>,
+    pub sales_opened_at: Option<DateTime<Utc>>,
+    pub sequence: i32,
+    pub version: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -25,14 +71,37 @@ impl ActiveModelBehavior for ActiveModel {}
 
 impl From<Model> for Event {
     fn from(model: Model) -> Self {
+        let schedule = EventSchedule::new(
+            parse_timezone(&model.timezone),
+            model.event_date,
+            model.ends_at,
+            model.doors_open_at,
+        );
+        let publication = Publication::new(
+            model.publish_at,
+            model.on_sale_at,
+            model.published_at,
+            model.sales_opened_at,
+        );
         Event::new(
             model.id,
             model.title,
-            model.description,
-            model.event_date,
+            match model.description_html {
+                Some(html) => RichText::from_parts(model.description, html),
+                None => RichText::from_markdown(&model.description),
+            },
+            schedule,
             model.venue_name,
+            model.venue_id,
+            model.organizer_id,
+            model.organization_id,
+            model.status.into(),
+            model.sequence,
+            model.version,
             model.created_at,
             model.updated_at,
         )
+        .with_publication(publication)
+        .with_default_locale(parse_locale(&model.default_locale))
     }
 }
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{CancellationStep, EventCancellation};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "event_cancellations")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub event_id: Uuid,
+    pub reason: String,
+    pub requested_by: Uuid,
+    /// `CancellationStep::as_str` of the step being worked on; `None` once
+    /// the cancellation is complete.
+    pub step: Option<String>,
+    pub orders_refunded: i32,
+    pub holders_notified: i32,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    /// Also pushed out while a runner holds the cancellation, so other
+    /// runners skip it until the claim lapses.
+    pub next_attempt_at: DateTime<Utc>,
+    /// Set by the runner holding the cancellation; its saves only land
+    /// while the token still matches.
+    pub claim_token: Option<Uuid>,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for EventCancellation {
+    fn from(model: Model) -> Self {
+        let step = model.step.as_deref().map(|step| {
+            CancellationStep::parse(step).unwrap_or_else(|| {
+                log::warn!(
+                    "Unknown cancellation step `{}` for event {}, restarting",
+                    step,
+                    model.event_id
+                );
+                CancellationStep::StopSales
+            })
+        });
+
+        EventCancellation::new(
+            model.event_id,
+            model.reason,
+            model.requested_by,
+            step,
+            model.orders_refunded,
+            model.holders_notified,
+            model.attempts,
+            model.last_error,
+            model.next_attempt_at,
+            model.started_at,
+            model.updated_at,
+            model.completed_at,
+        )
+        .with_claim_token(model.claim_token)
+    }
+}
+
+impl From<&EventCancellation> for ActiveModel {
+    fn from(cancellation: &EventCancellation) -> Self {
+        ActiveModel {
+            event_id: sea_orm::Set(cancellation.event_id()),
+            reason: sea_orm::Set(cancellation.reason().to_string()),
+            requested_by: sea_orm::Set(cancellation.requested_by()),
+            step: sea_orm::Set(cancellation.step().map(|step| step.as_str().to_string())),
+            orders_refunded: sea_orm::Set(cancellation.orders_refunded()),
+            holders_notified: sea_orm::Set(cancellation.holders_notified()),
+            attempts: sea_orm::Set(cancellation.attempts()),
+            last_error: sea_orm::Set(cancellation.last_error().map(str::to_string)),
+            next_attempt_at: sea_orm::Set(*cancellation.next_attempt_at()),
+            claim_token: sea_orm::Set(cancellation.claim_token()),
+            started_at: sea_orm::Set(*cancellation.started_at()),
+            updated_at: sea_orm::Set(*cancellation.updated_at()),
+            completed_at: sea_orm::Set(cancellation.completed_at().copied()),
+        }
+    }
+}
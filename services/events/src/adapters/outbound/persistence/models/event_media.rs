@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{MediaAsset, MediaFormat, MediaKind};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum Kind {
+    #[sea_orm(string_value = "poster")]
+    Poster,
+    #[sea_orm(string_value = "gallery")]
+    Gallery,
+}
+
+impl From<Kind> for MediaKind {
+    fn from(kind: Kind) -> Self {
+        match kind {
+            Kind::Poster => MediaKind::Poster,
+            Kind::Gallery => MediaKind::Gallery,
+        }
+    }
+}
+
+impl From<MediaKind> for Kind {
+    fn from(kind: MediaKind) -> Self {
+        match kind {
+            MediaKind::Poster => Kind::Poster,
+            MediaKind::Gallery => Kind::Gallery,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(50))")]
+pub enum ContentType {
+    #[sea_orm(string_value = "image/jpeg")]
+    Jpeg,
+    #[sea_orm(string_value = "image/png")]
+    Png,
+    #[sea_orm(string_value = "image/webp")]
+    Webp,
+    #[sea_orm(string_value = "video/mp4")]
+    Mp4,
+}
+
+impl From<ContentType> for MediaFormat {
+    fn from(content_type: ContentType) -> Self {
+        match content_type {
+            ContentType::Jpeg => MediaFormat::Jpeg,
+            ContentType::Png => MediaFormat::Png,
+            ContentType::Webp => MediaFormat::Webp,
+            ContentType::Mp4 => MediaFormat::Mp4,
+        }
+    }
+}
+
+impl From<MediaFormat> for ContentType {
+    fn from(format: MediaFormat) -> Self {
+        match format {
+            MediaFormat::Jpeg => ContentType::Jpeg,
+            MediaFormat::Png => ContentType::Png,
+            MediaFormat::Webp => ContentType::Webp,
+            MediaFormat::Mp4 => ContentType::Mp4,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "event_media")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub kind: Kind,
+    pub content_type: ContentType,
+    pub size_bytes: i64,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub storage_key: String,
+    pub thumbnail_key: Option<String>,
+    pub url: String,
+    pub thumbnail_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for MediaAsset {
+    fn from(model: Model) -> Self {
+        MediaAsset::new(
+            model.id,
+            model.event_id,
+            model.kind.into(),
+            model.content_type.into(),
+            model.size_bytes,
+            model.width,
+            model.height,
+            model.storage_key,
+            model.thumbnail_key,
+            model.url,
+            model.thumbnail_url,
+            model.created_at,
+        )
+    }
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{EventRevision, RevisionAction};
+use crate::domain::DomainError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum Action {
+    #[sea_orm(string_value = "created")]
+    Created,
+    #[sea_orm(string_value = "updated")]
+    Updated,
+    #[sea_orm(string_value = "cancelled")]
+    Cancelled,
+}
+
+impl From<Action> for RevisionAction {
+    fn from(action: Action) -> Self {
+        match action {
+            Action::Created => RevisionAction::Created,
+            Action::Updated => RevisionAction::Updated,
+            Action::Cancelled => RevisionAction::Cancelled,
+        }
+    }
+}
+
+impl From<RevisionAction> for Action {
+    fn from(action: RevisionAction) -> Self {
+        match action {
+            RevisionAction::Created => Action::Created,
+            RevisionAction::Updated => Action::Updated,
+            RevisionAction::Cancelled => Action::Cancelled,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "event_revisions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub event_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub revision: i32,
+    pub action: Action,
+    pub actor_id: Option<Uuid>,
+    /// JSON array of `FieldChange`.
+    pub changes: Json,
+    /// JSON object of `EventSnapshot`.
+    pub snapshot: Json,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl TryFrom<Model> for EventRevision {
+    type Error = DomainError;
+
+    fn try_from(model: Model) -> Result<Self, Self::Error> {
+        let unreadable = |e: serde_json::Error| {
+            DomainError::DatabaseError(format!(
+                "Unreadable revision {} of event {}: {}",
+                model.revision, model.event_id, e
+            ))
+        };
+        let changes = serde_json::from_value(model.changes.clone()).map_err(unreadable)?;
+        let snapshot = serde_json::from_value(model.snapshot.clone()).map_err(unreadable)?;
+
+        Ok(EventRevision::new(
+            model.event_id,
+            model.revision,
+            model.action.into(),
+            model.actor_id,
+            changes,
+            snapshot,
+            model.created_at,
+        ))
+    }
+}
+
+impl TryFrom<&EventRevision> for ActiveModel {
+    type Error = DomainError;
+
+    fn try_from(revision: &EventRevision) -> Result<Self, Self::Error> {
+        let to_json = |e: serde_json::Error| DomainError::DatabaseError(e.to_string());
+        Ok(ActiveModel {
+            event_id: sea_orm::Set(revision.event_id()),
+            revision: sea_orm::Set(revision.revision()),
+            action: sea_orm::Set(revision.action().into()),
+            actor_id: sea_orm::Set(revision.actor_id()),
+            changes: sea_orm::Set(serde_json::to_value(revision.changes()).map_err(to_json)?),
+            snapshot: sea_orm::Set(serde_json::to_value(revision.snapshot()).map_err(to_json)?),
+            created_at: sea_orm::Set(*revision.created_at()),
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{entities::RichText, EventSeries};
+
+use super::parse_timezone;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "event_series")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub title: String,
+    pub description: String,
+    /// Sanitized rendering of `description`; rows written before rich text
+    /// was stored are NULL and rendered on load.
+    pub description_html: Option<String>,
+    pub venue_name: String,
+    pub venue_id: Option<Uuid>,
+    pub starts_at: DateTime<Utc>,
+    /// IANA timezone name the rule is expanded in.
+    pub timezone: String,
+    pub rrule: String,
+    pub exdates: Vec<DateTime<Utc>>,
+    pub last_occurrence_at: Option<DateTime<Utc>>,
+    pub organizer_id: Option<Uuid>,
+    pub organization_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for EventSeries {
+    fn from(model: Model) -> Self {
+        EventSeries::new(
+            model.id,
+            model.title,
+            match model.description_html {
+                Some(html) => RichText::from_parts(model.description, html),
+                None => RichText::from_markdown(&model.description),
+            },
+            model.venue_name,
+            model.venue_id,
+            model.starts_at,
+            parse_timezone(&model.timezone),
+            model.rrule,
+            model.exdates,
+            model.last_occurrence_at,
+            model.created_at,
+            model.updated_at,
+        )
+        .with_owner(model.organizer_id, model.organization_id)
+    }
+}
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{OccurrenceOverride, RichText};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "event_series_overrides")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub series_id: Uuid,
+    pub occurrence_start: DateTime<Utc>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// Sanitized rendering of `description`; NULL for overrides written
+    /// before rich text was stored.
+    pub description_html: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub venue_name: Option<String>,
+    pub venue_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for OccurrenceOverride {
+    fn from(model: Model) -> Self {
+        OccurrenceOverride::new(
+            model.series_id,
+            model.occurrence_start,
+            model.title,
+            model
+                .description
+                .map(|description| match model.description_html {
+                    Some(html) => RichText::from_parts(description, html),
+                    None => RichText::from_markdown(&description),
+                }),
+            model.starts_at,
+            model.venue_name,
+            model.venue_id,
+            model.updated_at,
+        )
+    }
+}
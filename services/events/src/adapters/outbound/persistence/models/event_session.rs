@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::EventSession;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "event_sessions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub title: String,
+    pub stage: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub capacity: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for EventSession {
+    fn from(model: Model) -> Self {
+        EventSession::new(
+            model.id,
+            model.event_id,
+            model.title,
+            model.stage,
+            model.starts_at,
+            model.ends_at,
+            model.capacity,
+            model.created_at,
+            model.updated_at,
+        )
+    }
+}
+
+impl From<&EventSession> for ActiveModel {
+    fn from(session: &EventSession) -> Self {
+        Self {
+            id: sea_orm::Set(session.id()),
+            event_id: sea_orm::Set(session.event_id()),
+            title: sea_orm::Set(session.title().to_string()),
+            stage: sea_orm::Set(session.stage().map(str::to_string)),
+            starts_at: sea_orm::Set(*session.starts_at()),
+            ends_at: sea_orm::Set(*session.ends_at()),
+            capacity: sea_orm::Set(session.capacity()),
+            created_at: sea_orm::Set(*session.created_at()),
+            updated_at: sea_orm::Set(*session.updated_at()),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{entities::EventTemplate, DomainError};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "event_templates")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub name: String,
+    pub organizer_id: Uuid,
+    pub organization_id: Option<Uuid>,
+    /// JSON object of `EventBlueprint`.
+    pub blueprint: Json,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl TryFrom<Model> for EventTemplate {
+    type Error = DomainError;
+
+    fn try_from(model: Model) -> Result<Self, Self::Error> {
+        let blueprint = serde_json::from_value(model.blueprint).map_err(|e| {
+            DomainError::DatabaseError(format!("Unreadable event template {}: {}", model.id, e))
+        })?;
+
+        Ok(EventTemplate::new(
+            model.id,
+            model.name,
+            model.organizer_id,
+            model.organization_id,
+            blueprint,
+            model.created_at,
+        ))
+    }
+}
+
+impl TryFrom<&EventTemplate> for ActiveModel {
+    type Error = DomainError;
+
+    fn try_from(template: &EventTemplate) -> Result<Self, Self::Error> {
+        let blueprint = serde_json::to_value(template.blueprint())
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        Ok(ActiveModel {
+            id: sea_orm::Set(template.id()),
+            name: sea_orm::Set(template.name().to_string()),
+            organizer_id: sea_orm::Set(template.organizer_id()),
+            organization_id: sea_orm::Set(template.organization_id()),
+            blueprint: sea_orm::Set(blueprint),
+            created_at: sea_orm::Set(*template.created_at()),
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{EventTranslation, RichText};
+
+use super::parse_locale;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "event_translations")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub event_id: Uuid,
+    /// BCP 47 tag, never the event's default locale.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub locale: String,
+    pub title: String,
+    /// Markdown source.
+    pub description: String,
+    pub description_html: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for EventTranslation {
+    fn from(model: Model) -> Self {
+        EventTranslation::new(
+            model.event_id,
+            parse_locale(&model.locale),
+            model.title,
+            RichText::from_parts(model.description, model.description_html),
+            model.created_at,
+            model.updated_at,
+        )
+    }
+}
+
+impl From<&EventTranslation> for ActiveModel {
+    fn from(translation: &EventTranslation) -> Self {
+        Self {
+            event_id: sea_orm::Set(translation.event_id()),
+            locale: sea_orm::Set(translation.locale().to_string()),
+            title: sea_orm::Set(translation.title().to_string()),
+            description: sea_orm::Set(translation.description().to_string()),
+            description_html: sea_orm::Set(translation.description_html().to_string()),
+            created_at: sea_orm::Set(*translation.created_at()),
+            updated_at: sea_orm::Set(*translation.updated_at()),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{ImportJob, ImportJobStatus, ImportRowResult};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum Status {
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    #[sea_orm(string_value = "running")]
+    Running,
+    #[sea_orm(string_value = "completed")]
+    Completed,
+    #[sea_orm(string_value = "failed")]
+    Failed,
+}
+
+impl From<Status> for ImportJobStatus {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Pending => ImportJobStatus::Pending,
+            Status::Running => ImportJobStatus::Running,
+            Status::Completed => ImportJobStatus::Completed,
+            Status::Failed => ImportJobStatus::Failed,
+        }
+    }
+}
+
+impl From<ImportJobStatus> for Status {
+    fn from(status: ImportJobStatus) -> Self {
+        match status {
+            ImportJobStatus::Pending => Status::Pending,
+            ImportJobStatus::Running => Status::Running,
+            ImportJobStatus::Completed => Status::Completed,
+            ImportJobStatus::Failed => Status::Failed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "import_jobs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub source: String,
+    pub organizer_id: Option<Uuid>,
+    pub status: Status,
+    pub total_rows: i32,
+    /// Per-entry results as a JSON array of `ImportRowResult`.
+    pub results: Json,
+    pub error: Option<String>,
+    /// The file's entries as a JSON array of `ImportEntry`, dropped once
+    /// the job finishes.
+    pub entries: Json,
+    pub claim_token: Option<Uuid>,
+    /// Until when the runner holding `claim_token` is left alone.
+    pub claimed_until: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for ImportJob {
+    fn from(model: Model) -> Self {
+        let results: Vec<ImportRowResult> =
+            serde_json::from_value(model.results).unwrap_or_else(|e| {
+                log::error!("Unreadable results on import job {}: {}", model.id, e);
+                Vec::new()
+            });
+
+        ImportJob::new(
+            model.id,
+            model.source,
+            model.status.into(),
+            model.total_rows.max(0) as usize,
+            results,
+            model.error,
+            model.created_at,
+            model.updated_at,
+            model.completed_at,
+        )
+        .with_organizer(model.organizer_id)
+        .with_claim_token(model.claim_token)
+    }
+}
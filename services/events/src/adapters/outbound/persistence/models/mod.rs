@@ -1 +1,40 @@
+pub mod category;
 pub mod event;
+pub mod event_cancellation;
+pub mod event_category;
+pub mod event_media;
+pub mod event_revision;
+pub mod event_series;
+pub mod event_series_override;
+pub mod event_session;
+pub mod event_tag;
+pub mod event_template;
+pub mod event_translation;
+pub mod import_job;
+pub mod outbox_message;
+pub mod ticket_type;
+pub mod venue;
+pub mod venue_seat;
+pub mod venue_section;
+
+use chrono_tz::Tz;
+
+use crate::domain::entities::Locale;
+
+/// Parses a stored IANA timezone name, falling back to UTC so that a bad row
+/// still loads.
+fn parse_timezone(name: &str) -> Tz {
+    name.parse().unwrap_or_else(|_| {
+        log::warn!("Unknown timezone `{}` in database, using UTC", name);
+        Tz::UTC
+    })
+}
+
+/// Parses a stored locale, falling back to the default one so that a bad
+/// row still loads.
+fn parse_locale(tag: &str) -> Locale {
+    Locale::parse(tag).unwrap_or_else(|_| {
+        log::warn!("Unknown locale `{}` in database, using the default", tag);
+        Locale::default()
+    })
+}
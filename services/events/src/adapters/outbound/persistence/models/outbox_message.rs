@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{entities::OutboxMessage, DomainError};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "outbox_messages")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    /// `DomainEvent::name`, for querying without parsing the payload.
+    pub event_type: String,
+    pub event_id: Uuid,
+    /// JSON object of `DomainEvent`.
+    pub payload: Json,
+    pub occurred_at: DateTime<Utc>,
+    /// Set while a dispatcher is delivering the message; others skip it
+    /// until then, and pick it up again if that dispatcher dies.
+    pub claimed_until: Option<DateTime<Utc>>,
+    pub dispatched_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl TryFrom<Model> for OutboxMessage {
+    type Error = DomainError;
+
+    fn try_from(model: Model) -> Result<Self, Self::Error> {
+        let event = serde_json::from_value(model.payload).map_err(|e| {
+            DomainError::DatabaseError(format!("Unreadable outbox message {}: {}", model.id, e))
+        })?;
+
+        Ok(OutboxMessage::new(model.id, event, model.occurred_at))
+    }
+}
+
+impl TryFrom<&OutboxMessage> for ActiveModel {
+    type Error = DomainError;
+
+    fn try_from(message: &OutboxMessage) -> Result<Self, Self::Error> {
+        let payload = serde_json::to_value(message.event())
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        Ok(ActiveModel {
+            id: sea_orm::Set(message.id()),
+            event_type: sea_orm::Set(message.event().name().to_string()),
+            event_id: sea_orm::Set(message.event().event_id()),
+            payload: sea_orm::Set(payload),
+            occurred_at: sea_orm::Set(*message.occurred_at()),
+            claimed_until: sea_orm::Set(None),
+            dispatched_at: sea_orm::Set(None),
+        })
+    }
+}
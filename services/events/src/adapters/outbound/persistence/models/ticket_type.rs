@@ -0,0 +1,113 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{Admission, TicketTypeVisibility},
+    TicketType,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum Visibility {
+    #[sea_orm(string_value = "public")]
+    Public,
+    #[sea_orm(string_value = "hidden")]
+    Hidden,
+}
+
+impl From<Visibility> for TicketTypeVisibility {
+    fn from(visibility: Visibility) -> Self {
+        match visibility {
+            Visibility::Public => TicketTypeVisibility::Public,
+            Visibility::Hidden => TicketTypeVisibility::Hidden,
+        }
+    }
+}
+
+impl From<TicketTypeVisibility> for Visibility {
+    fn from(visibility: TicketTypeVisibility) -> Self {
+        match visibility {
+            TicketTypeVisibility::Public => Visibility::Public,
+            TicketTypeVisibility::Hidden => Visibility::Hidden,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum AdmissionKind {
+    #[sea_orm(string_value = "event")]
+    Event,
+    #[sea_orm(string_value = "day")]
+    Day,
+    #[sea_orm(string_value = "session")]
+    Session,
+}
+
+/// Column values for `admission`: its kind, day and session.
+pub fn admission_columns(admission: Admission) -> (AdmissionKind, Option<NaiveDate>, Option<Uuid>) {
+    match admission {
+        Admission::Event => (AdmissionKind::Event, None, None),
+        Admission::Day(date) => (AdmissionKind::Day, Some(date), None),
+        Admission::Session(id) => (AdmissionKind::Session, None, Some(id)),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "ticket_types")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub name: String,
+    pub price_minor: i64,
+    pub currency: String,
+    pub quantity: i32,
+    pub min_per_order: i32,
+    pub max_per_order: i32,
+    pub sales_start: Option<DateTime<Utc>>,
+    pub sales_end: Option<DateTime<Utc>>,
+    pub visibility: Visibility,
+    pub admission: AdmissionKind,
+    pub admission_day: Option<NaiveDate>,
+    pub session_id: Option<Uuid>,
+    pub section_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for TicketType {
+    fn from(model: Model) -> Self {
+        // The table's check constraint keeps the day and session in step
+        // with the kind
+        let admission = match (model.admission, model.admission_day, model.session_id) {
+            (AdmissionKind::Day, Some(date), _) => Admission::Day(date),
+            (AdmissionKind::Session, _, Some(id)) => Admission::Session(id),
+            _ => Admission::Event,
+        };
+        TicketType::new(
+            model.id,
+            model.event_id,
+            model.name,
+            model.price_minor,
+            model.currency,
+            model.quantity,
+            model.min_per_order,
+            model.max_per_order,
+            model.sales_start,
+            model.sales_end,
+            model.visibility.into(),
+            admission,
+            model.created_at,
+            model.updated_at,
+        )
+        .with_section(model.section_id)
+    }
+}
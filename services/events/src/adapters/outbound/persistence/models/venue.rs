@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{entities::GeoPoint, Venue};
+
+use super::parse_timezone;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "venues")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub name: String,
+    /// IANA timezone name, e.g. `America/Chicago`.
+    pub timezone: String,
+    /// Decimal degrees; set together with `longitude` or not at all.
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub organizer_id: Option<Uuid>,
+    pub organization_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for Venue {
+    fn from(model: Model) -> Self {
+        let location = match (model.latitude, model.longitude) {
+            (Some(latitude), Some(longitude)) => GeoPoint::new(latitude, longitude)
+                .inspect_err(|e| log::warn!("Ignoring location of venue {}: {}", model.id, e))
+                .ok(),
+            _ => None,
+        };
+
+        Venue::new(
+            model.id,
+            model.name,
+            parse_timezone(&model.timezone),
+            model.created_at,
+            model.updated_at,
+        )
+        .with_location(location)
+        .with_owner(model.organizer_id, model.organization_id)
+    }
+}
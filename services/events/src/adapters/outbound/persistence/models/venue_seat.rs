@@ -0,0 +1,40 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::Seat;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "venue_seats")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub venue_id: Uuid,
+    pub section_id: Uuid,
+    pub row_label: String,
+    pub row_position: i32,
+    pub label: String,
+    pub position: i32,
+    pub x: f64,
+    pub y: f64,
+    pub accessible: bool,
+    pub obstructed: bool,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for Seat {
+    fn from(model: Model) -> Self {
+        Seat::new(
+            model.id,
+            model.label,
+            model.x,
+            model.y,
+            model.accessible,
+            model.obstructed,
+        )
+    }
+}
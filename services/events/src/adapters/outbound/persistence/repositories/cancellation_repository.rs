@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::{
+    sea_query::{Expr, LockBehavior, LockType, Query},
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set,
+};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::EventCancellation, CancellationRepository, DomainError, DomainResult,
+};
+
+use super::super::models::event_cancellation::{self, Entity as EventCancellationEntity};
+
+pub struct CancellationRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl CancellationRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Writes `model` if `cancellation`'s claim is still the current one.
+    async fn update_claimed(
+        &self,
+        cancellation: &EventCancellation,
+        model: event_cancellation::ActiveModel,
+    ) -> DomainResult<bool> {
+        let claim = event_cancellation::Column::ClaimToken;
+        let result = EventCancellationEntity::update_many()
+            .set(model)
+            .filter(event_cancellation::Column::EventId.eq(cancellation.event_id()))
+            .filter(match cancellation.claim_token() {
+                Some(token) => claim.eq(token),
+                None => claim.is_null(),
+            })
+            .exec(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        Ok(result.rows_affected > 0)
+    }
+}
+
+#[async_trait]
+impl CancellationRepository for CancellationRepositoryImpl {
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<EventCancellation> {
+        EventCancellationEntity::find_by_id(event_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .map(Into::into)
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("Cancellation of event {} not found", event_id))
+            })
+    }
+
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u64,
+        lease: Duration,
+    ) -> DomainResult<Vec<EventCancellation>> {
+        let claimable = Query::select()
+            .column(event_cancellation::Column::EventId)
+            .from(EventCancellationEntity)
+            .and_where(event_cancellation::Column::CompletedAt.is_null())
+            .and_where(event_cancellation::Column::NextAttemptAt.lte(now))
+            .order_by(
+                event_cancellation::Column::NextAttemptAt,
+                sea_orm::Order::Asc,
+            )
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .to_owned();
+
+        let mut claimed = EventCancellationEntity::update_many()
+            .col_expr(
+                event_cancellation::Column::NextAttemptAt,
+                Expr::value(now + lease),
+            )
+            .col_expr(
+                event_cancellation::Column::ClaimToken,
+                Expr::value(Uuid::new_v4()),
+            )
+            .filter(event_cancellation::Column::EventId.in_subquery(claimable))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        claimed.sort_by_key(|model| model.started_at);
+
+        Ok(claimed.into_iter().map(Into::into).collect())
+    }
+
+    async fn save(&self, cancellation: &EventCancellation) -> DomainResult<bool> {
+        let mut model = event_cancellation::ActiveModel::from(cancellation);
+        model.claim_token = Set(None);
+        self.update_claimed(cancellation, model).await
+    }
+
+    async fn save_progress(
+        &self,
+        cancellation: &EventCancellation,
+        held_until: DateTime<Utc>,
+    ) -> DomainResult<bool> {
+        let mut model = event_cancellation::ActiveModel::from(cancellation);
+        model.next_attempt_at = Set(held_until);
+        self.update_claimed(cancellation, model).await
+    }
+}
@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{Category, Taxonomy},
+    use_cases::{CreateCategoryParams, UpdateCategoryParams},
+    CategoryRepository, DomainError, DomainResult,
+};
+
+use super::super::models::{
+    category::{self, ActiveModel, Entity as CategoryEntity},
+    event_category::{self, Entity as EventCategoryEntity},
+};
+
+pub struct CategoryRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl CategoryRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for CategoryRepositoryImpl {
+    async fn find_all(&self) -> DomainResult<Taxonomy> {
+        let categories = CategoryEntity::find()
+            .order_by_asc(category::Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(Taxonomy::new(
+            categories.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    async fn create(&self, data: CreateCategoryParams) -> DomainResult<Category> {
+        let now = Utc::now();
+
+        let category = ActiveModel {
+            id: Set(Uuid::new_v4()),
+            parent_id: Set(data.parent_id),
+            name: Set(data.name),
+            slug: Set(data.slug),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        let inserted = category
+            .insert(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(inserted.into())
+    }
+
+    async fn update(&self, data: UpdateCategoryParams) -> DomainResult<Category> {
+        let mut category: ActiveModel = CategoryEntity::find_by_id(data.id)
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("Category with id {} not found", data.id))
+            })?
+            .into();
+
+        if let Some(name) = data.name {
+            category.name = Set(name);
+        }
+        if let Some(slug) = data.slug {
+            category.slug = Set(slug);
+        }
+        if let Some(parent_id) = data.parent_id {
+            category.parent_id = Set(parent_id);
+        }
+        category.updated_at = Set(Utc::now());
+
+        let updated = category
+            .update(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(updated.into())
+    }
+
+    async fn is_in_use(&self, id: Uuid) -> DomainResult<bool> {
+        let filed = EventCategoryEntity::find()
+            .filter(event_category::Column::CategoryId.eq(id))
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(filed.is_some())
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        let result = CategoryEntity::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Category with id {} not found",
+                id
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -1,13 +1,35 @@
 use async_trait::async_trait;
-use chrono::Utc;
-use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, Set};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    sea_query::{Expr, LockBehavior, LockType, OnConflict, Query, SimpleExpr},
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, Order,
+    QueryFilter, QueryOrder, QuerySelect, QueryTrait, Select, Set, TransactionTrait,
+};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::domain::{
-    use_cases::CreateEventParams, DomainError, DomainResult, Event, EventRepository,
+    entities::{
+        DomainEvent, EventCancellation, EventRevision, EventSchedule, EventSession,
+        EventTranslation, Locale, MediaAsset, MediaKind, OutboxMessage, Publication,
+        RevisionAction,
+    },
+    use_cases::{CreateEventParams, EventFilter, TicketTypeParams, UpdateEventParams},
+    DomainError, DomainResult, Event, EventRepository, TicketType,
 };
 
-use super::super::models::event::{ActiveModel, Entity as EventEntity};
+use super::super::models::{
+    event::{self, ActiveModel, Entity as EventEntity},
+    event_cancellation,
+    event_category::{self, Entity as EventCategoryEntity},
+    event_media::{self, Entity as EventMediaEntity},
+    event_revision::{self, Entity as EventRevisionEntity},
+    event_session::{self, Entity as EventSessionEntity},
+    event_tag::{self, Entity as EventTagEntity},
+    event_translation::{self, Entity as EventTranslationEntity},
+    outbox_message,
+    ticket_type::{self, admission_columns, Entity as TicketTypeEntity},
+};
 
 pub struct EventRepositoryImpl {
     db: DatabaseConnection,
@@ -17,6 +39,511 @@ impl EventRepositoryImpl {
     pub fn new(db: DatabaseConnection) -> Self {
         Self { db }
     }
+
+    async fn find_ticket_type_model(
+        &self,
+        event_id: Uuid,
+        id: Uuid,
+    ) -> DomainResult<ticket_type::Model> {
+        TicketTypeEntity::find_by_id(id)
+            .filter(ticket_type::Column::EventId.eq(event_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| DomainError::NotFound(format!("Ticket type with id {} not found", id)))
+    }
+
+    /// Runs `update` against the event, bumping its sequence and version in
+    /// the same statement so concurrent changes never share either number.
+    async fn update_and_bump<C: ConnectionTrait>(
+        conn: &C,
+        id: Uuid,
+        update: sea_orm::UpdateMany<EventEntity>,
+    ) -> DomainResult<event::Model> {
+        update
+            .col_expr(
+                event::Column::Sequence,
+                Expr::col(event::Column::Sequence).add(1),
+            )
+            .col_expr(
+                event::Column::Version,
+                Expr::col(event::Column::Version).add(1),
+            )
+            .col_expr(event::Column::UpdatedAt, Expr::value(Utc::now()))
+            .filter(event::Column::Id.eq(id))
+            .exec_with_returning(conn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .next()
+            .ok_or_else(|| DomainError::NotFound(format!("Event with id {} not found", id)))
+    }
+
+    async fn find_sessions(&self, event_id: Uuid) -> DomainResult<Vec<EventSession>> {
+        let sessions = EventSessionEntity::find()
+            .filter(event_session::Column::EventId.eq(event_id))
+            .order_by_asc(event_session::Column::StartsAt)
+            .order_by_asc(event_session::Column::Stage)
+            .order_by_asc(event_session::Column::Title)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(sessions.into_iter().map(Into::into).collect())
+    }
+
+    /// Builds the aggregate from a stored event, with its ticket types,
+    /// categories, tags, media, sessions and translations.
+    async fn load(&self, model: event::Model) -> DomainResult<Event> {
+        let ticket_types = self.find_ticket_types(model.id).await?;
+        let media = self.find_media(model.id).await?;
+        let sessions = self.find_sessions(model.id).await?;
+        let (category_ids, tags) = find_classifications(&self.db, &[model.id])
+            .await?
+            .remove(&model.id)
+            .unwrap_or_default();
+        let translations = find_translations(&self.db, &[model.id])
+            .await?
+            .remove(&model.id)
+            .unwrap_or_default();
+
+        Ok(Event::from(model)
+            .with_ticket_types(ticket_types)
+            .with_classification(category_ids, tags)
+            .with_media(media)
+            .with_sessions(sessions)
+            .with_translations(translations))
+    }
+
+    /// Attaches categories, tags and translations to events loaded without
+    /// them.
+    async fn classify(&self, events: Vec<Event>) -> DomainResult<Vec<Event>> {
+        let ids: Vec<Uuid> = events.iter().map(Event::id).collect();
+        let mut classifications = find_classifications(&self.db, &ids).await?;
+        let mut translations = find_translations(&self.db, &ids).await?;
+
+        Ok(events
+            .into_iter()
+            .map(|event| {
+                let (category_ids, tags) = classifications.remove(&event.id()).unwrap_or_default();
+                let translations = translations.remove(&event.id()).unwrap_or_default();
+                event
+                    .with_classification(category_ids, tags)
+                    .with_translations(translations)
+            })
+            .collect())
+    }
+}
+
+/// Bumps the event's version after a change to one of its ticket types,
+/// sessions, media or translations, which doesn't touch the event row
+/// itself. Its sequence stays, so no revision is recorded; see
+/// `EventSnapshot`.
+async fn touch<C: ConnectionTrait>(conn: &C, event_id: Uuid) -> DomainResult<()> {
+    EventEntity::update_many()
+        .col_expr(
+            event::Column::Version,
+            Expr::col(event::Column::Version).add(1),
+        )
+        .col_expr(event::Column::UpdatedAt, Expr::value(Utc::now()))
+        .filter(event::Column::Id.eq(event_id))
+        .exec(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Moves the event's sessions by as much as its start moved, and its day
+/// passes by as many days.
+async fn shift_sessions<C: ConnectionTrait>(
+    conn: &C,
+    before: &Event,
+    schedule: &EventSchedule,
+) -> DomainResult<()> {
+    let shift = *schedule.starts_at() - *before.event_date();
+    if !shift.is_zero() {
+        let sessions = EventSessionEntity::find()
+            .filter(event_session::Column::EventId.eq(before.id()))
+            .all(conn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        for session in sessions {
+            let starts_at = session.starts_at + shift;
+            let ends_at = session.ends_at + shift;
+            let mut session: event_session::ActiveModel = session.into();
+            session.starts_at = Set(starts_at);
+            session.ends_at = Set(ends_at);
+            session
+                .update(conn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+    }
+
+    let day_shift = *schedule.local_days().start() - *before.schedule().local_days().start();
+    if !day_shift.is_zero() {
+        let day_passes = TicketTypeEntity::find()
+            .filter(ticket_type::Column::EventId.eq(before.id()))
+            .filter(ticket_type::Column::AdmissionDay.is_not_null())
+            .all(conn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        for day_pass in day_passes {
+            let admission_day = day_pass.admission_day.map(|date| date + day_shift);
+            let mut day_pass: ticket_type::ActiveModel = day_pass.into();
+            day_pass.admission_day = Set(admission_day);
+            day_pass
+                .update(conn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Records domain events in the outbox, to be dispatched once the
+/// transaction they belong to commits.
+async fn record_domain_events<C: ConnectionTrait>(
+    conn: &C,
+    events: &[DomainEvent],
+    occurred_at: DateTime<Utc>,
+) -> DomainResult<()> {
+    for event in events {
+        outbox_message::ActiveModel::try_from(&OutboxMessage::new(
+            Uuid::new_v4(),
+            event.clone(),
+            occurred_at,
+        ))?
+        .insert(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    }
+    Ok(())
+}
+
+/// Inserts the event row with its categories and tags and records it as
+/// revision 0.
+async fn insert_event<C: ConnectionTrait>(
+    conn: &C,
+    event_id: Uuid,
+    data: CreateEventParams,
+    schedule: EventSchedule,
+    publication: Publication,
+    now: DateTime<Utc>,
+) -> DomainResult<Event> {
+    let event = ActiveModel {
+        id: Set(event_id),
+        title: Set(data.title),
+        description: Set(data.description.markdown().to_string()),
+        description_html: Set(Some(data.description.html().to_string())),
+        default_locale: Set(data.default_locale.to_string()),
+        event_date: Set(*schedule.starts_at()),
+        timezone: Set(schedule.timezone().name().to_string()),
+        ends_at: Set(schedule.ends_at().copied()),
+        doors_open_at: Set(schedule.doors_open_at().copied()),
+        venue_name: Set(data.venue_name),
+        venue_id: Set(data.venue_id),
+        organizer_id: Set(data.organizer_id),
+        organization_id: Set(data.organization_id),
+        external_uid: Set(data.external_uid),
+        status: Set(event::Status::Scheduled),
+        publish_at: Set(publication.publish_at().copied()),
+        on_sale_at: Set(publication.on_sale_at().copied()),
+        published_at: Set(publication.published_at().copied()),
+        sales_opened_at: Set(publication.sales_opened_at().copied()),
+        sequence: Set(0),
+        version: Set(1),
+        created_at: Set(now),
+        updated_at: Set(now),
+    };
+
+    let inserted = event
+        .insert(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    replace_categories(conn, event_id, Some(data.category_ids.clone())).await?;
+    replace_tags(conn, event_id, Some(data.tags.clone())).await?;
+
+    let event = Event::from(inserted).with_classification(data.category_ids, data.tags);
+    insert_revision(
+        conn,
+        &EventRevision::record(None, &event, RevisionAction::Created, data.organizer_id),
+    )
+    .await?;
+
+    Ok(event)
+}
+
+fn ticket_type_model(
+    event_id: Uuid,
+    data: TicketTypeParams,
+    now: DateTime<Utc>,
+) -> ticket_type::ActiveModel {
+    let (admission, admission_day, session_id) = admission_columns(data.admission);
+    ticket_type::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        event_id: Set(event_id),
+        name: Set(data.name),
+        price_minor: Set(data.price_minor),
+        currency: Set(data.currency),
+        quantity: Set(data.quantity),
+        min_per_order: Set(data.min_per_order),
+        max_per_order: Set(data.max_per_order),
+        sales_start: Set(data.sales_start),
+        sales_end: Set(data.sales_end),
+        visibility: Set(data.visibility.into()),
+        admission: Set(admission),
+        admission_day: Set(admission_day),
+        session_id: Set(session_id),
+        section_id: Set(data.section_id),
+        created_at: Set(now),
+        updated_at: Set(now),
+    }
+}
+
+fn media_model(asset: &MediaAsset) -> event_media::ActiveModel {
+    event_media::ActiveModel {
+        id: Set(asset.id()),
+        event_id: Set(asset.event_id()),
+        kind: Set(asset.kind().into()),
+        content_type: Set(asset.format().into()),
+        size_bytes: Set(asset.size_bytes()),
+        width: Set(asset.width()),
+        height: Set(asset.height()),
+        storage_key: Set(asset.storage_key().to_string()),
+        thumbnail_key: Set(asset.thumbnail_key().map(str::to_string)),
+        url: Set(asset.url().to_string()),
+        thumbnail_url: Set(asset.thumbnail_url().map(str::to_string)),
+        created_at: Set(*asset.created_at()),
+    }
+}
+
+/// Publishes the locked, unpublished event, opening its sales too unless
+/// they are scheduled for later, and returns the domain events for it.
+async fn mark_published<C: ConnectionTrait>(
+    conn: &C,
+    model: &event::Model,
+    now: DateTime<Utc>,
+) -> DomainResult<Vec<DomainEvent>> {
+    let mut update =
+        EventEntity::update_many().col_expr(event::Column::PublishedAt, Expr::value(now));
+    let mut domain_events = vec![DomainEvent::EventPublished {
+        event_id: model.id,
+        published_at: now,
+    }];
+    if model.on_sale_at.is_none_or(|at| at <= now) {
+        update = update.col_expr(event::Column::SalesOpenedAt, Expr::value(now));
+        domain_events.push(DomainEvent::TicketSalesOpened {
+            event_id: model.id,
+            opened_at: now,
+        });
+    }
+    update
+        .col_expr(
+            event::Column::Version,
+            Expr::col(event::Column::Version).add(1),
+        )
+        .col_expr(event::Column::UpdatedAt, Expr::value(now))
+        .filter(event::Column::Id.eq(model.id))
+        .exec(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    Ok(domain_events)
+}
+
+/// Category ids and tags per event, for the given events.
+async fn find_classifications<C: ConnectionTrait>(
+    conn: &C,
+    event_ids: &[Uuid],
+) -> DomainResult<HashMap<Uuid, (Vec<Uuid>, Vec<String>)>> {
+    let mut classifications: HashMap<Uuid, (Vec<Uuid>, Vec<String>)> = HashMap::new();
+    if event_ids.is_empty() {
+        return Ok(classifications);
+    }
+
+    for link in EventCategoryEntity::find()
+        .filter(event_category::Column::EventId.is_in(event_ids.iter().copied()))
+        .all(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+    {
+        classifications
+            .entry(link.event_id)
+            .or_default()
+            .0
+            .push(link.category_id);
+    }
+    for link in EventTagEntity::find()
+        .filter(event_tag::Column::EventId.is_in(event_ids.iter().copied()))
+        .order_by_asc(event_tag::Column::Tag)
+        .all(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+    {
+        classifications
+            .entry(link.event_id)
+            .or_default()
+            .1
+            .push(link.tag);
+    }
+
+    Ok(classifications)
+}
+
+/// Translations of the events, by event and then by locale.
+async fn find_translations<C: ConnectionTrait>(
+    conn: &C,
+    event_ids: &[Uuid],
+) -> DomainResult<HashMap<Uuid, Vec<EventTranslation>>> {
+    let mut translations: HashMap<Uuid, Vec<EventTranslation>> = HashMap::new();
+    if event_ids.is_empty() {
+        return Ok(translations);
+    }
+
+    for translation in EventTranslationEntity::find()
+        .filter(event_translation::Column::EventId.is_in(event_ids.iter().copied()))
+        .order_by_asc(event_translation::Column::Locale)
+        .all(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+    {
+        translations
+            .entry(translation.event_id)
+            .or_default()
+            .push(translation.into());
+    }
+
+    Ok(translations)
+}
+
+/// Locks the event row for the rest of the transaction and loads its
+/// current state, for diffing against once the change is applied.
+async fn lock_for_revision<C: ConnectionTrait>(conn: &C, id: Uuid) -> DomainResult<Event> {
+    let model = EventEntity::find_by_id(id)
+        .lock_exclusive()
+        .one(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+        .ok_or_else(|| DomainError::NotFound(format!("Event with id {} not found", id)))?;
+    let (category_ids, tags) = find_classifications(conn, &[id])
+        .await?
+        .remove(&id)
+        .unwrap_or_default();
+
+    Ok(Event::from(model).with_classification(category_ids, tags))
+}
+
+async fn insert_revision<C: ConnectionTrait>(
+    conn: &C,
+    revision: &EventRevision,
+) -> DomainResult<()> {
+    event_revision::ActiveModel::try_from(revision)?
+        .insert(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    Ok(())
+}
+
+/// Replaces the links of the event with the given categories, leaving them
+/// alone when `None`.
+async fn replace_categories<C: ConnectionTrait>(
+    conn: &C,
+    event_id: Uuid,
+    category_ids: Option<Vec<Uuid>>,
+) -> DomainResult<()> {
+    let Some(category_ids) = category_ids else {
+        return Ok(());
+    };
+
+    EventCategoryEntity::delete_many()
+        .filter(event_category::Column::EventId.eq(event_id))
+        .exec(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    if !category_ids.is_empty() {
+        EventCategoryEntity::insert_many(category_ids.into_iter().map(|category_id| {
+            event_category::ActiveModel {
+                event_id: Set(event_id),
+                category_id: Set(category_id),
+            }
+        }))
+        .exec(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// Replaces the tags of the event, leaving them alone when `None`.
+async fn replace_tags<C: ConnectionTrait>(
+    conn: &C,
+    event_id: Uuid,
+    tags: Option<Vec<String>>,
+) -> DomainResult<()> {
+    let Some(tags) = tags else {
+        return Ok(());
+    };
+
+    EventTagEntity::delete_many()
+        .filter(event_tag::Column::EventId.eq(event_id))
+        .exec(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    if !tags.is_empty() {
+        EventTagEntity::insert_many(tags.into_iter().map(|tag| event_tag::ActiveModel {
+            event_id: Set(event_id),
+            tag: Set(tag),
+        }))
+        .exec(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// Restricts `query` to the published events matching `filter`.
+fn apply_filter(query: Select<EventEntity>, filter: &EventFilter) -> Select<EventEntity> {
+    let mut query = query.filter(event::Column::PublishedAt.is_not_null());
+    if !filter.category_ids.is_empty() {
+        query = query.filter(
+            event::Column::Id.in_subquery(
+                Query::select()
+                    .column(event_category::Column::EventId)
+                    .from(EventCategoryEntity)
+                    .and_where(
+                        event_category::Column::CategoryId
+                            .is_in(filter.category_ids.iter().copied()),
+                    )
+                    .to_owned(),
+            ),
+        );
+    }
+    if !filter.tags.is_empty() {
+        query = query.filter(
+            event::Column::Id.in_subquery(
+                Query::select()
+                    .column(event_tag::Column::EventId)
+                    .from(EventTagEntity)
+                    .and_where(event_tag::Column::Tag.is_in(filter.tags.iter().cloned()))
+                    .group_by_col(event_tag::Column::EventId)
+                    .and_having(
+                        Expr::col(event_tag::Column::Tag)
+                            .count_distinct()
+                            .eq(filter.tags.len() as i64),
+                    )
+                    .to_owned(),
+            ),
+        );
+    }
+    if let Some(venue_ids) = &filter.venue_ids {
+        query = query.filter(event::Column::VenueId.is_in(venue_ids.iter().copied()));
+    }
+    query
 }
 
 #[async_trait]
@@ -28,28 +555,869 @@ impl EventRepository for EventRepositoryImpl {
             .map_err(|e| DomainError::DatabaseError(e.to_string()))?
             .ok_or_else(|| DomainError::NotFound(format!("Event with id {} not found", id)))?;
 
-        Ok(event.into())
+        self.load(event).await
     }
 
-    async fn create(&self, data: CreateEventParams) -> DomainResult<Event> {
+    async fn create(
+        &self,
+        data: CreateEventParams,
+        schedule: EventSchedule,
+        publication: Publication,
+    ) -> DomainResult<Event> {
         let now = Utc::now();
         let event_id = Uuid::new_v4();
 
-        let event = ActiveModel {
-            id: Set(event_id),
-            title: Set(data.title),
-            description: Set(data.description),
-            event_date: Set(data.event_date),
-            venue_name: Set(data.venue_name),
-            created_at: Set(now),
-            updated_at: Set(now),
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let event = insert_event(&txn, event_id, data, schedule, publication, now).await?;
+
+        let mut domain_events = Vec::new();
+        if let Some(published_at) = publication.published_at() {
+            domain_events.push(DomainEvent::EventPublished {
+                event_id,
+                published_at: *published_at,
+            });
+        }
+        if let Some(opened_at) = publication.sales_opened_at() {
+            domain_events.push(DomainEvent::TicketSalesOpened {
+                event_id,
+                opened_at: *opened_at,
+            });
+        }
+        record_domain_events(&txn, &domain_events, now).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(event)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn create_draft(
+        &self,
+        id: Uuid,
+        data: CreateEventParams,
+        schedule: EventSchedule,
+        sessions: Vec<EventSession>,
+        translations: Vec<EventTranslation>,
+        ticket_types: Vec<TicketTypeParams>,
+        media: Vec<MediaAsset>,
+    ) -> DomainResult<Event> {
+        let now = Utc::now();
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        insert_event(&txn, id, data, schedule, Publication::default(), now).await?;
+        for session in &sessions {
+            event_session::ActiveModel::from(session)
+                .insert(&txn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+        for translation in &translations {
+            event_translation::ActiveModel::from(translation)
+                .insert(&txn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+        for data in ticket_types {
+            ticket_type_model(id, data, now)
+                .insert(&txn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+        for asset in &media {
+            media_model(asset)
+                .insert(&txn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        self.find_by_id(id).await
+    }
+
+    async fn find_by_external_uid(
+        &self,
+        organizer_id: Option<Uuid>,
+        external_uid: &str,
+    ) -> DomainResult<Event> {
+        let organizer = match organizer_id {
+            Some(id) => event::Column::OrganizerId.eq(id),
+            None => event::Column::OrganizerId.is_null(),
+        };
+        EventEntity::find()
+            .filter(organizer)
+            .filter(event::Column::ExternalUid.eq(external_uid))
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .map(Into::into)
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("Event with UID {} not found", external_uid))
+            })
+    }
+
+    async fn update(
+        &self,
+        data: UpdateEventParams,
+        schedule: EventSchedule,
+        publication: Publication,
+    ) -> DomainResult<Option<Event>> {
+        let mut update = EventEntity::update_many()
+            .col_expr(
+                event::Column::PublishAt,
+                Expr::value(publication.publish_at().copied()),
+            )
+            .col_expr(
+                event::Column::OnSaleAt,
+                Expr::value(publication.on_sale_at().copied()),
+            )
+            .col_expr(event::Column::EventDate, Expr::value(*schedule.starts_at()))
+            .col_expr(
+                event::Column::Timezone,
+                Expr::value(schedule.timezone().name()),
+            )
+            .col_expr(
+                event::Column::EndsAt,
+                Expr::value(schedule.ends_at().copied()),
+            )
+            .col_expr(
+                event::Column::DoorsOpenAt,
+                Expr::value(schedule.doors_open_at().copied()),
+            );
+        if let Some(title) = data.title {
+            update = update.col_expr(event::Column::Title, Expr::value(title));
+        }
+        if let Some(locale) = &data.default_locale {
+            update = update.col_expr(event::Column::DefaultLocale, Expr::value(locale.as_str()));
+        }
+        if let Some(description) = data.description {
+            update = update
+                .col_expr(
+                    event::Column::Description,
+                    Expr::value(description.markdown()),
+                )
+                .col_expr(
+                    event::Column::DescriptionHtml,
+                    Expr::value(description.html()),
+                );
+        }
+        if let Some(venue_name) = data.venue_name {
+            update = update.col_expr(event::Column::VenueName, Expr::value(venue_name));
+        }
+        if let Some(venue_id) = data.venue_id {
+            update = update.col_expr(event::Column::VenueId, Expr::value(venue_id));
+        }
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let before = lock_for_revision(&txn, data.id).await?;
+        if data
+            .expected_version
+            .is_some_and(|version| version != before.version())
+        {
+            return Ok(None);
+        }
+        let updated = Self::update_and_bump(&txn, data.id, update).await?;
+        shift_sessions(&txn, &before, &schedule).await?;
+        replace_categories(&txn, data.id, data.category_ids.clone()).await?;
+        replace_tags(&txn, data.id, data.tags.clone()).await?;
+
+        let after = Event::from(updated.clone()).with_classification(
+            data.category_ids
+                .unwrap_or_else(|| before.category_ids().to_vec()),
+            data.tags.unwrap_or_else(|| before.tags().to_vec()),
+        );
+        insert_revision(
+            &txn,
+            &EventRevision::record(
+                Some(&before),
+                &after,
+                RevisionAction::Updated,
+                Some(data.requested_by),
+            ),
+        )
+        .await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        self.load(updated).await.map(Some)
+    }
+
+    async fn cancel(&self, cancellation: &EventCancellation) -> DomainResult<Event> {
+        let id = cancellation.event_id();
+        let update = EventEntity::update_many()
+            .col_expr(event::Column::Status, Expr::value(event::Status::Cancelled));
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let before = lock_for_revision(&txn, id).await?;
+        let cancelled = Self::update_and_bump(&txn, id, update).await?;
+        let after = Event::from(cancelled.clone())
+            .with_classification(before.category_ids().to_vec(), before.tags().to_vec());
+        insert_revision(
+            &txn,
+            &EventRevision::record(
+                Some(&before),
+                &after,
+                RevisionAction::Cancelled,
+                Some(cancellation.requested_by()),
+            ),
+        )
+        .await?;
+        record_domain_events(
+            &txn,
+            &[DomainEvent::EventCancelled {
+                event_id: id,
+                reason: cancellation.reason().to_string(),
+                cancelled_at: *cancellation.started_at(),
+            }],
+            *cancellation.started_at(),
+        )
+        .await?;
+        event_cancellation::ActiveModel::from(cancellation)
+            .insert(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        self.load(cancelled).await
+    }
+
+    async fn find_revisions(&self, event_id: Uuid) -> DomainResult<Vec<EventRevision>> {
+        EventRevisionEntity::find()
+            .filter(event_revision::Column::EventId.eq(event_id))
+            .order_by_asc(event_revision::Column::Revision)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect()
+    }
+
+    async fn find_revision(&self, event_id: Uuid, revision: i32) -> DomainResult<EventRevision> {
+        EventRevisionEntity::find_by_id((event_id, revision))
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!(
+                    "Revision {} of event {} not found",
+                    revision, event_id
+                ))
+            })?
+            .try_into()
+    }
+
+    async fn publish_due(&self, now: DateTime<Utc>, limit: u64) -> DomainResult<Vec<DomainEvent>> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let due = EventEntity::find()
+            .filter(event::Column::PublishedAt.is_null())
+            .filter(event::Column::PublishAt.lte(now))
+            .filter(event::Column::Status.eq(event::Status::Scheduled))
+            .order_by_asc(event::Column::PublishAt)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut domain_events = Vec::new();
+        for model in due {
+            domain_events.extend(mark_published(&txn, &model, now).await?);
+        }
+        record_domain_events(&txn, &domain_events, now).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(domain_events)
+    }
+
+    async fn publish(&self, id: Uuid, now: DateTime<Utc>) -> DomainResult<Option<Event>> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let model = EventEntity::find_by_id(id)
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| DomainError::NotFound(format!("Event with id {} not found", id)))?;
+        if model.published_at.is_some() {
+            return Ok(None);
+        }
+        let domain_events = mark_published(&txn, &model, now).await?;
+        record_domain_events(&txn, &domain_events, now).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        self.find_by_id(id).await.map(Some)
+    }
+
+    async fn open_sales_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u64,
+    ) -> DomainResult<Vec<DomainEvent>> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let due: Vec<Uuid> = EventEntity::find()
+            .filter(event::Column::SalesOpenedAt.is_null())
+            .filter(event::Column::PublishedAt.is_not_null())
+            .filter(event::Column::OnSaleAt.lte(now))
+            .filter(event::Column::Status.eq(event::Status::Scheduled))
+            .order_by_asc(event::Column::OnSaleAt)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(|model| model.id)
+            .collect();
+        if due.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        EventEntity::update_many()
+            .col_expr(event::Column::SalesOpenedAt, Expr::value(now))
+            .col_expr(
+                event::Column::Version,
+                Expr::col(event::Column::Version).add(1),
+            )
+            .col_expr(event::Column::UpdatedAt, Expr::value(now))
+            .filter(event::Column::Id.is_in(due.iter().copied()))
+            .exec(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let domain_events: Vec<DomainEvent> = due
+            .into_iter()
+            .map(|event_id| DomainEvent::TicketSalesOpened {
+                event_id,
+                opened_at: now,
+            })
+            .collect();
+        record_domain_events(&txn, &domain_events, now).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(domain_events)
+    }
+
+    async fn find_by_venue(
+        &self,
+        venue_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<Event>> {
+        let events = EventEntity::find()
+            .filter(event::Column::VenueId.eq(venue_id))
+            .filter(event::Column::PublishedAt.is_not_null())
+            .filter(event::Column::EventDate.gte(since))
+            .order_by_asc(event::Column::EventDate)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(events.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_organizer(
+        &self,
+        organizer_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<Event>> {
+        let events = EventEntity::find()
+            .filter(event::Column::OrganizerId.eq(organizer_id))
+            .filter(event::Column::PublishedAt.is_not_null())
+            .filter(event::Column::EventDate.gte(since))
+            .order_by_asc(event::Column::EventDate)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(events.into_iter().map(Into::into).collect())
+    }
+
+    async fn list_by_organizer(
+        &self,
+        organizer_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: u64,
+    ) -> DomainResult<Vec<Event>> {
+        let events = EventEntity::find()
+            .filter(event::Column::OrganizerId.eq(organizer_id))
+            .filter(event::Column::PublishedAt.is_not_null())
+            .filter(event::Column::EventDate.gte(from))
+            .filter(event::Column::EventDate.lt(to))
+            .order_by_asc(event::Column::EventDate)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut ticket_types: HashMap<Uuid, Vec<TicketType>> = HashMap::new();
+        for model in TicketTypeEntity::find()
+            .filter(ticket_type::Column::EventId.is_in(events.iter().map(|event| event.id)))
+            .order_by_asc(ticket_type::Column::PriceMinor)
+            .order_by_asc(ticket_type::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+        {
+            ticket_types
+                .entry(model.event_id)
+                .or_default()
+                .push(model.into());
+        }
+
+        let events = events
+            .into_iter()
+            .map(|model| {
+                let ticket_types = ticket_types.remove(&model.id).unwrap_or_default();
+                Event::from(model).with_ticket_types(ticket_types)
+            })
+            .collect();
+
+        self.classify(events).await
+    }
+
+    async fn list_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: u64,
+        filter: &EventFilter,
+    ) -> DomainResult<Vec<Event>> {
+        let mut query = apply_filter(
+            EventEntity::find()
+                .filter(event::Column::EventDate.gte(from))
+                .filter(event::Column::EventDate.lt(to)),
+            filter,
+        );
+        // Events at the nearest venues come first
+        if let Some(venue_ids) = filter.venue_ids.as_ref().filter(|ids| !ids.is_empty()) {
+            let mut rank = Expr::case(event::Column::VenueId.eq(venue_ids[0]), 0);
+            for (position, venue_id) in venue_ids.iter().enumerate().skip(1) {
+                rank = rank.case(event::Column::VenueId.eq(*venue_id), position as i32);
+            }
+            let rank: SimpleExpr = rank.finally(venue_ids.len() as i32).into();
+            query = query.order_by(rank, Order::Asc);
+        }
+
+        let events = query
+            .order_by_asc(event::Column::EventDate)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        self.classify(events.into_iter().map(Into::into).collect())
+            .await
+    }
+
+    async fn find_category_assignments(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        filter: &EventFilter,
+    ) -> DomainResult<Vec<(Uuid, Uuid)>> {
+        let events = apply_filter(
+            EventEntity::find()
+                .filter(event::Column::EventDate.gte(from))
+                .filter(event::Column::EventDate.lt(to)),
+            filter,
+        )
+        .select_only()
+        .column(event::Column::Id);
+
+        let links = EventCategoryEntity::find()
+            .filter(event_category::Column::EventId.in_subquery(events.into_query()))
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(links
+            .into_iter()
+            .map(|link| (link.event_id, link.category_id))
+            .collect())
+    }
+
+    async fn find_ticket_types(&self, event_id: Uuid) -> DomainResult<Vec<TicketType>> {
+        let ticket_types = TicketTypeEntity::find()
+            .filter(ticket_type::Column::EventId.eq(event_id))
+            .order_by_asc(ticket_type::Column::PriceMinor)
+            .order_by_asc(ticket_type::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(ticket_types.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_ticket_type(&self, event_id: Uuid, id: Uuid) -> DomainResult<TicketType> {
+        Ok(self.find_ticket_type_model(event_id, id).await?.into())
+    }
+
+    async fn create_ticket_type(
+        &self,
+        event_id: Uuid,
+        data: TicketTypeParams,
+    ) -> DomainResult<TicketType> {
+        let now = Utc::now();
+
+        let ticket_type = ticket_type_model(event_id, data, now);
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let inserted = ticket_type
+            .insert(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        touch(&txn, event_id).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(inserted.into())
+    }
+
+    async fn update_ticket_type(
+        &self,
+        event_id: Uuid,
+        id: Uuid,
+        data: TicketTypeParams,
+    ) -> DomainResult<TicketType> {
+        let mut ticket_type: ticket_type::ActiveModel =
+            self.find_ticket_type_model(event_id, id).await?.into();
+
+        ticket_type.name = Set(data.name);
+        ticket_type.price_minor = Set(data.price_minor);
+        ticket_type.currency = Set(data.currency);
+        ticket_type.quantity = Set(data.quantity);
+        ticket_type.min_per_order = Set(data.min_per_order);
+        ticket_type.max_per_order = Set(data.max_per_order);
+        ticket_type.sales_start = Set(data.sales_start);
+        ticket_type.sales_end = Set(data.sales_end);
+        ticket_type.visibility = Set(data.visibility.into());
+        let (admission, admission_day, session_id) = admission_columns(data.admission);
+        ticket_type.admission = Set(admission);
+        ticket_type.admission_day = Set(admission_day);
+        ticket_type.session_id = Set(session_id);
+        ticket_type.section_id = Set(data.section_id);
+        ticket_type.updated_at = Set(Utc::now());
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let updated = ticket_type
+            .update(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        touch(&txn, event_id).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(updated.into())
+    }
+
+    async fn delete_ticket_type(&self, event_id: Uuid, id: Uuid) -> DomainResult<()> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let result = TicketTypeEntity::delete_by_id(id)
+            .filter(ticket_type::Column::EventId.eq(event_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Ticket type with id {} not found",
+                id
+            )));
+        }
+        touch(&txn, event_id).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_media(&self, event_id: Uuid) -> DomainResult<Vec<MediaAsset>> {
+        let media = EventMediaEntity::find()
+            .filter(event_media::Column::EventId.eq(event_id))
+            .order_by_asc(event_media::Column::CreatedAt)
+            .order_by_asc(event_media::Column::Id)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(media.into_iter().map(Into::into).collect())
+    }
+
+    async fn add_media(&self, asset: MediaAsset) -> DomainResult<Option<MediaAsset>> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let replaced = if asset.kind() == MediaKind::Poster {
+            EventMediaEntity::delete_many()
+                .filter(event_media::Column::EventId.eq(asset.event_id()))
+                .filter(event_media::Column::Kind.eq(event_media::Kind::Poster))
+                .exec_with_returning(&txn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+                .into_iter()
+                .next()
+                .map(Into::into)
+        } else {
+            None
         };
 
-        let inserted = event
-            .insert(&self.db)
+        media_model(&asset)
+            .insert(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        touch(&txn, asset.event_id()).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(replaced)
+    }
+
+    async fn delete_media(&self, event_id: Uuid, id: Uuid) -> DomainResult<MediaAsset> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let deleted = EventMediaEntity::delete_many()
+            .filter(event_media::Column::Id.eq(id))
+            .filter(event_media::Column::EventId.eq(event_id))
+            .exec_with_returning(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .next()
+            .map(Into::into)
+            .ok_or_else(|| DomainError::NotFound(format!("Media with id {} not found", id)))?;
+        touch(&txn, event_id).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted)
+    }
+
+    async fn create_session(&self, session: &EventSession) -> DomainResult<EventSession> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let inserted = event_session::ActiveModel::from(session)
+            .insert(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        touch(&txn, session.event_id()).await?;
+
+        txn.commit()
             .await
             .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
         Ok(inserted.into())
     }
+
+    async fn update_session(&self, session: &EventSession) -> DomainResult<EventSession> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut model = event_session::ActiveModel::from(session);
+        model.created_at = sea_orm::ActiveValue::NotSet;
+        let updated = EventSessionEntity::update_many()
+            .set(model)
+            .filter(event_session::Column::Id.eq(session.id()))
+            .filter(event_session::Column::EventId.eq(session.event_id()))
+            .exec_with_returning(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("Session with id {} not found", session.id()))
+            })?;
+        touch(&txn, session.event_id()).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(updated.into())
+    }
+
+    async fn delete_session(&self, event_id: Uuid, id: Uuid) -> DomainResult<()> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let result = EventSessionEntity::delete_by_id(id)
+            .filter(event_session::Column::EventId.eq(event_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Session with id {} not found",
+                id
+            )));
+        }
+        touch(&txn, event_id).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn save_translation(
+        &self,
+        translation: &EventTranslation,
+    ) -> DomainResult<EventTranslation> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let saved =
+            EventTranslationEntity::insert(event_translation::ActiveModel::from(translation))
+                .on_conflict(
+                    OnConflict::columns([
+                        event_translation::Column::EventId,
+                        event_translation::Column::Locale,
+                    ])
+                    .update_columns([
+                        event_translation::Column::Title,
+                        event_translation::Column::Description,
+                        event_translation::Column::DescriptionHtml,
+                        event_translation::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+                )
+                .exec_with_returning(&txn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        touch(&txn, translation.event_id()).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(saved.into())
+    }
+
+    async fn delete_translation(&self, event_id: Uuid, locale: &Locale) -> DomainResult<()> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let result = EventTranslationEntity::delete_many()
+            .filter(event_translation::Column::EventId.eq(event_id))
+            .filter(event_translation::Column::Locale.eq(locale.as_str()))
+            .exec(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Translation into {} not found",
+                locale
+            )));
+        }
+        touch(&txn, event_id).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
 }
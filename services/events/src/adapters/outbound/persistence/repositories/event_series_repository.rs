@@ -0,0 +1,202 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use sea_orm::{
+    sea_query::{Condition, OnConflict},
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::OccurrenceOverride,
+    use_cases::{CreateEventSeriesParams, OverrideOccurrenceParams},
+    DomainError, DomainResult, EventSeries, EventSeriesRepository,
+};
+
+use super::super::models::{
+    event_series::{self, Entity as EventSeriesEntity},
+    event_series_override::{self, Entity as OverrideEntity},
+};
+
+pub struct EventSeriesRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl EventSeriesRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    async fn find_overrides(
+        &self,
+        series_ids: Vec<Uuid>,
+    ) -> DomainResult<HashMap<Uuid, Vec<OccurrenceOverride>>> {
+        let overrides = OverrideEntity::find()
+            .filter(event_series_override::Column::SeriesId.is_in(series_ids))
+            .order_by_asc(event_series_override::Column::OccurrenceStart)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut grouped: HashMap<Uuid, Vec<OccurrenceOverride>> = HashMap::new();
+        for model in overrides {
+            grouped
+                .entry(model.series_id)
+                .or_default()
+                .push(model.into());
+        }
+
+        Ok(grouped)
+    }
+}
+
+#[async_trait]
+impl EventSeriesRepository for EventSeriesRepositoryImpl {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<EventSeries> {
+        let series = EventSeriesEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("Event series with id {} not found", id))
+            })?;
+
+        let overrides = self
+            .find_overrides(vec![id])
+            .await?
+            .remove(&id)
+            .unwrap_or_default();
+
+        Ok(EventSeries::from(series).with_overrides(overrides))
+    }
+
+    async fn create(
+        &self,
+        data: CreateEventSeriesParams,
+        last_occurrence_at: Option<DateTime<Utc>>,
+    ) -> DomainResult<EventSeries> {
+        let now = Utc::now();
+
+        let series = event_series::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            title: Set(data.title),
+            description: Set(data.description.markdown().to_string()),
+            description_html: Set(Some(data.description.html().to_string())),
+            venue_name: Set(data.venue_name),
+            venue_id: Set(data.venue_id),
+            starts_at: Set(data.starts_at),
+            timezone: Set(data.timezone.unwrap_or(Tz::UTC).name().to_string()),
+            rrule: Set(data.rrule),
+            exdates: Set(data.exdates),
+            last_occurrence_at: Set(last_occurrence_at),
+            organizer_id: Set(Some(data.requested_by)),
+            organization_id: Set(data.organization_id),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        let inserted = series
+            .insert(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(inserted.into())
+    }
+
+    async fn find_active_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<EventSeries>> {
+        let series = EventSeriesEntity::find()
+            .filter(event_series::Column::StartsAt.lt(to))
+            .filter(
+                Condition::any()
+                    .add(event_series::Column::LastOccurrenceAt.is_null())
+                    .add(event_series::Column::LastOccurrenceAt.gte(from)),
+            )
+            .order_by_asc(event_series::Column::StartsAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut overrides = self
+            .find_overrides(series.iter().map(|s| s.id).collect())
+            .await?;
+
+        Ok(series
+            .into_iter()
+            .map(|model| {
+                let series_overrides = overrides.remove(&model.id).unwrap_or_default();
+                EventSeries::from(model).with_overrides(series_overrides)
+            })
+            .collect())
+    }
+
+    async fn upsert_override(
+        &self,
+        data: OverrideOccurrenceParams,
+    ) -> DomainResult<OccurrenceOverride> {
+        let now = Utc::now();
+
+        let model = event_series_override::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            series_id: Set(data.series_id),
+            occurrence_start: Set(data.occurrence_start),
+            title: Set(data.title),
+            description: Set(data.description.as_ref().map(|d| d.markdown().to_string())),
+            description_html: Set(data.description.as_ref().map(|d| d.html().to_string())),
+            starts_at: Set(data.starts_at),
+            venue_name: Set(data.venue_name),
+            venue_id: Set(data.venue_id),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        let upserted = OverrideEntity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    event_series_override::Column::SeriesId,
+                    event_series_override::Column::OccurrenceStart,
+                ])
+                .update_columns([
+                    event_series_override::Column::Title,
+                    event_series_override::Column::Description,
+                    event_series_override::Column::DescriptionHtml,
+                    event_series_override::Column::StartsAt,
+                    event_series_override::Column::VenueName,
+                    event_series_override::Column::VenueId,
+                    event_series_override::Column::UpdatedAt,
+                ])
+                .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(upserted.into())
+    }
+
+    async fn delete_override(
+        &self,
+        series_id: Uuid,
+        occurrence_start: DateTime<Utc>,
+    ) -> DomainResult<()> {
+        let result = OverrideEntity::delete_many()
+            .filter(event_series_override::Column::SeriesId.eq(series_id))
+            .filter(event_series_override::Column::OccurrenceStart.eq(occurrence_start))
+            .exec(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "No override for occurrence {} of series {}",
+                occurrence_start, series_id
+            )));
+        }
+
+        Ok(())
+    }
+}
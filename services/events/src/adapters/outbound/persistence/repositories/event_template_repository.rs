@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+};
+use uuid::Uuid;
+
+use crate::domain::{entities::EventTemplate, DomainError, DomainResult, EventTemplateRepository};
+
+use super::super::models::event_template::{self, Entity as EventTemplateEntity};
+
+pub struct EventTemplateRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl EventTemplateRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl EventTemplateRepository for EventTemplateRepositoryImpl {
+    async fn create(&self, template: &EventTemplate) -> DomainResult<()> {
+        event_template::ActiveModel::try_from(template)?
+            .insert(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<EventTemplate> {
+        EventTemplateEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("Event template with id {} not found", id))
+            })?
+            .try_into()
+    }
+
+    async fn list(
+        &self,
+        organizer_id: Uuid,
+        organization_id: Option<Uuid>,
+    ) -> DomainResult<Vec<EventTemplate>> {
+        let query = match organization_id {
+            Some(organization_id) => EventTemplateEntity::find()
+                .filter(event_template::Column::OrganizationId.eq(organization_id)),
+            None => EventTemplateEntity::find()
+                .filter(event_template::Column::OrganizerId.eq(organizer_id))
+                .filter(event_template::Column::OrganizationId.is_null()),
+        };
+
+        query
+            .order_by_asc(event_template::Column::Name)
+            .order_by_asc(event_template::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect()
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<EventTemplate> {
+        EventTemplateEntity::delete_many()
+            .filter(event_template::Column::Id.eq(id))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("Event template with id {} not found", id))
+            })?
+            .try_into()
+    }
+}
@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::{
+    sea_query::{Expr, LockBehavior, LockType, Query},
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter, Set,
+};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ImportJob, use_cases::ImportEntry, DomainError, DomainResult, ImportJobRepository,
+};
+
+use super::super::models::import_job::{self, Entity as ImportJobEntity};
+
+pub struct ImportJobRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl ImportJobRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ImportJobRepository for ImportJobRepositoryImpl {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<ImportJob> {
+        ImportJobEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .map(Into::into)
+            .ok_or_else(|| DomainError::NotFound(format!("Import job with id {} not found", id)))
+    }
+
+    async fn create(
+        &self,
+        source: String,
+        organizer_id: Option<Uuid>,
+        entries: &[ImportEntry],
+        held_until: Option<DateTime<Utc>>,
+    ) -> DomainResult<ImportJob> {
+        let now = Utc::now();
+        let entries =
+            serde_json::to_value(entries).map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        let total_rows = entries.as_array().map_or(0, Vec::len);
+
+        let job = import_job::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            source: Set(source),
+            organizer_id: Set(organizer_id),
+            status: Set(import_job::Status::Pending),
+            total_rows: Set(i32::try_from(total_rows).unwrap_or(i32::MAX)),
+            results: Set(serde_json::Value::Array(Vec::new())),
+            error: Set(None),
+            entries: Set(entries),
+            claim_token: Set(held_until.map(|_| Uuid::new_v4())),
+            claimed_until: Set(held_until),
+            created_at: Set(now),
+            updated_at: Set(now),
+            completed_at: Set(None),
+        };
+
+        let inserted = job
+            .insert(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(inserted.into())
+    }
+
+    async fn claim_next(
+        &self,
+        now: DateTime<Utc>,
+        lease: Duration,
+    ) -> DomainResult<Option<(ImportJob, Vec<ImportEntry>)>> {
+        let claimable = Query::select()
+            .column(import_job::Column::Id)
+            .from(ImportJobEntity)
+            .and_where(import_job::Column::CompletedAt.is_null())
+            .cond_where(
+                Condition::any()
+                    .add(import_job::Column::ClaimedUntil.is_null())
+                    .add(import_job::Column::ClaimedUntil.lte(now)),
+            )
+            .order_by(import_job::Column::CreatedAt, sea_orm::Order::Asc)
+            .limit(1)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .to_owned();
+
+        let claimed = ImportJobEntity::update_many()
+            .col_expr(import_job::Column::ClaimedUntil, Expr::value(now + lease))
+            .col_expr(import_job::Column::ClaimToken, Expr::value(Uuid::new_v4()))
+            .filter(import_job::Column::Id.in_subquery(claimable))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let Some(model) = claimed.into_iter().next() else {
+            return Ok(None);
+        };
+        let entries: Vec<ImportEntry> = serde_json::from_value(model.entries.clone())
+            .unwrap_or_else(|e| {
+                log::error!("Unreadable entries on import job {}: {}", model.id, e);
+                Vec::new()
+            });
+
+        Ok(Some((model.into(), entries)))
+    }
+
+    async fn save(&self, job: &ImportJob, held_until: DateTime<Utc>) -> DomainResult<bool> {
+        let results = serde_json::to_value(job.results())
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut model = import_job::ActiveModel {
+            status: Set(job.status().into()),
+            results: Set(results),
+            error: Set(job.error().map(str::to_string)),
+            claimed_until: Set(Some(held_until)),
+            updated_at: Set(Utc::now()),
+            completed_at: Set(job.completed_at().copied()),
+            ..Default::default()
+        };
+        if job.is_finished() {
+            model.entries = Set(serde_json::Value::Array(Vec::new()));
+            model.claim_token = Set(None);
+            model.claimed_until = Set(None);
+        }
+
+        let claim = import_job::Column::ClaimToken;
+        let result = ImportJobEntity::update_many()
+            .set(model)
+            .filter(import_job::Column::Id.eq(job.id()))
+            .filter(match job.claim_token() {
+                Some(token) => claim.eq(token),
+                None => claim.is_null(),
+            })
+            .exec(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
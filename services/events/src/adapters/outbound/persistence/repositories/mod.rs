@@ -1,3 +1,17 @@
+pub mod cancellation_repository;
+pub mod category_repository;
 pub mod event_repository;
+pub mod event_series_repository;
+pub mod event_template_repository;
+pub mod import_job_repository;
+pub mod outbox_repository;
+pub mod venue_repository;
 
+pub use cancellation_repository::CancellationRepositoryImpl;
+pub use category_repository::CategoryRepositoryImpl;
 pub use event_repository::EventRepositoryImpl;
+pub use event_series_repository::EventSeriesRepositoryImpl;
+pub use event_template_repository::EventTemplateRepositoryImpl;
+pub use import_job_repository::ImportJobRepositoryImpl;
+pub use outbox_repository::OutboxRepositoryImpl;
+pub use venue_repository::VenueRepositoryImpl;
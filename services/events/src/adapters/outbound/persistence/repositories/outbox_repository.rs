@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use sea_orm::{
+    sea_query::{Expr, LockBehavior, LockType, Query},
+    ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
+};
+use uuid::Uuid;
+
+use crate::domain::{entities::OutboxMessage, DomainError, DomainResult, OutboxRepository};
+
+use super::super::models::outbox_message::{self, Entity as OutboxMessageEntity};
+
+pub struct OutboxRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl OutboxRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for OutboxRepositoryImpl {
+    async fn claim_pending(&self, limit: u64, lease: Duration) -> DomainResult<Vec<OutboxMessage>> {
+        let now = Utc::now();
+        let claimable = Query::select()
+            .column(outbox_message::Column::Id)
+            .from(OutboxMessageEntity)
+            .and_where(outbox_message::Column::DispatchedAt.is_null())
+            .cond_where(
+                Condition::any()
+                    .add(outbox_message::Column::ClaimedUntil.is_null())
+                    .add(outbox_message::Column::ClaimedUntil.lt(now)),
+            )
+            .order_by(outbox_message::Column::OccurredAt, sea_orm::Order::Asc)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .to_owned();
+
+        let mut claimed = OutboxMessageEntity::update_many()
+            .col_expr(
+                outbox_message::Column::ClaimedUntil,
+                Expr::value(now + lease),
+            )
+            .filter(outbox_message::Column::Id.in_subquery(claimable))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        claimed.sort_by_key(|model| model.occurred_at);
+
+        claimed.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn mark_dispatched(&self, id: Uuid) -> DomainResult<()> {
+        OutboxMessageEntity::update_many()
+            .col_expr(
+                outbox_message::Column::DispatchedAt,
+                Expr::value(Utc::now()),
+            )
+            .filter(outbox_message::Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,319 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, Order,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{GeoRadius, Seat, SeatingRow, SeatingSection},
+    use_cases::{CreateVenueParams, ImportSeatingChartParams},
+    DomainError, DomainResult, SeatingChart, Venue, VenueRepository,
+};
+
+use super::super::models::{
+    ticket_type::{self, Entity as TicketTypeEntity},
+    venue::{self, ActiveModel, Entity as VenueEntity},
+    venue_seat::{self, Entity as VenueSeatEntity},
+    venue_section::{self, Entity as VenueSectionEntity},
+};
+
+pub struct VenueRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl VenueRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+/// Groups seat rows, already ordered by row and seat position, into rows.
+fn group_rows(seats: Vec<venue_seat::Model>) -> Vec<SeatingRow> {
+    let mut rows: Vec<SeatingRow> = Vec::new();
+    let mut current_label: Option<String> = None;
+    let mut current_seats: Vec<Seat> = Vec::new();
+
+    for seat in seats {
+        if current_label.as_deref() != Some(seat.row_label.as_str()) {
+            if let Some(label) = current_label.take() {
+                rows.push(SeatingRow::new(label, std::mem::take(&mut current_seats)));
+            }
+            current_label = Some(seat.row_label.clone());
+        }
+        current_seats.push(seat.into());
+    }
+    if let Some(label) = current_label {
+        rows.push(SeatingRow::new(label, current_seats));
+    }
+
+    rows
+}
+
+#[async_trait]
+impl VenueRepository for VenueRepositoryImpl {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Venue> {
+        let venue = VenueEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| DomainError::NotFound(format!("Venue with id {} not found", id)))?;
+
+        Ok(venue.into())
+    }
+
+    async fn create(&self, data: CreateVenueParams) -> DomainResult<Venue> {
+        let now = Utc::now();
+
+        let venue = ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(data.name),
+            timezone: Set(data.timezone.name().to_string()),
+            latitude: Set(data.location.map(|location| location.latitude())),
+            longitude: Set(data.location.map(|location| location.longitude())),
+            organizer_id: Set(Some(data.requested_by)),
+            organization_id: Set(data.organization_id),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        let inserted = venue
+            .insert(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(inserted.into())
+    }
+
+    async fn find_within(&self, area: &GeoRadius) -> DomainResult<Vec<Venue>> {
+        let (latitude, longitude) = (area.center.latitude(), area.center.longitude());
+        let radius_m = area.radius_km * 1000.0;
+        let distance = || {
+            Expr::cust_with_values(
+                "earth_distance(ll_to_earth($1, $2), ll_to_earth(latitude, longitude))",
+                [latitude, longitude],
+            )
+        };
+
+        // The bounding cube uses idx_venues_location; the exact distance
+        // check then trims its corners
+        let venues = VenueEntity::find()
+            .filter(venue::Column::Latitude.is_not_null())
+            .filter(venue::Column::Longitude.is_not_null())
+            .filter(Expr::cust_with_values(
+                "earth_box(ll_to_earth($1, $2), $3) @> ll_to_earth(latitude, longitude)",
+                [latitude, longitude, radius_m],
+            ))
+            .filter(Expr::expr(distance()).lte(radius_m))
+            .order_by(distance(), Order::Asc)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(venues.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_seating_chart(&self, venue_id: Uuid) -> DomainResult<SeatingChart> {
+        self.find_by_id(venue_id).await?;
+
+        let sections = VenueSectionEntity::find()
+            .filter(venue_section::Column::VenueId.eq(venue_id))
+            .order_by_asc(venue_section::Column::Position)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let seats = VenueSeatEntity::find()
+            .filter(venue_seat::Column::VenueId.eq(venue_id))
+            .order_by_asc(venue_seat::Column::RowPosition)
+            .order_by_asc(venue_seat::Column::Position)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut seats_by_section: HashMap<Uuid, Vec<venue_seat::Model>> = HashMap::new();
+        for seat in seats {
+            seats_by_section
+                .entry(seat.section_id)
+                .or_default()
+                .push(seat);
+        }
+
+        let sections = sections
+            .into_iter()
+            .map(|section| {
+                let section_seats = seats_by_section.remove(&section.id).unwrap_or_default();
+                SeatingSection::new(section.id, section.name, group_rows(section_seats))
+            })
+            .collect();
+
+        Ok(SeatingChart::new(venue_id, sections))
+    }
+
+    async fn find_sections_in_use(&self, venue_id: Uuid) -> DomainResult<HashSet<Uuid>> {
+        let section_ids: Vec<Uuid> = VenueSectionEntity::find()
+            .select_only()
+            .column(venue_section::Column::Id)
+            .filter(venue_section::Column::VenueId.eq(venue_id))
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        if section_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let in_use: Vec<Option<Uuid>> = TicketTypeEntity::find()
+            .select_only()
+            .column(ticket_type::Column::SectionId)
+            .filter(ticket_type::Column::SectionId.is_in(section_ids))
+            .distinct()
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(in_use.into_iter().flatten().collect())
+    }
+
+    async fn replace_seating_chart(
+        &self,
+        data: ImportSeatingChartParams,
+    ) -> DomainResult<SeatingChart> {
+        let venue_id = data.venue_id;
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut current_sections: HashMap<String, Uuid> = VenueSectionEntity::find()
+            .filter(venue_section::Column::VenueId.eq(venue_id))
+            .all(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(|section| (section.name, section.id))
+            .collect();
+        let mut current_seats: HashMap<(Uuid, String, String), Uuid> = VenueSeatEntity::find()
+            .filter(venue_seat::Column::VenueId.eq(venue_id))
+            .all(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(|seat| ((seat.section_id, seat.row_label, seat.label), seat.id))
+            .collect();
+
+        let mut sections = Vec::with_capacity(data.sections.len());
+        let mut new_seats = Vec::new();
+        for (section_position, section) in data.sections.into_iter().enumerate() {
+            let section_model = venue_section::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                venue_id: Set(venue_id),
+                name: Set(section.name.clone()),
+                position: Set(section_position as i32),
+            };
+            let section_id = match current_sections.remove(&section.name) {
+                Some(id) => {
+                    venue_section::ActiveModel {
+                        id: Set(id),
+                        ..section_model
+                    }
+                    .update(&txn)
+                    .await
+                    .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+                    id
+                }
+                None => {
+                    section_model
+                        .insert(&txn)
+                        .await
+                        .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+                        .id
+                }
+            };
+
+            let mut rows = Vec::with_capacity(section.rows.len());
+            for (row_position, row) in section.rows.into_iter().enumerate() {
+                let mut seats = Vec::with_capacity(row.seats.len());
+                for (position, seat) in row.seats.into_iter().enumerate() {
+                    let existing =
+                        current_seats.remove(&(section_id, row.label.clone(), seat.label.clone()));
+                    let seat_model = venue_seat::ActiveModel {
+                        id: Set(existing.unwrap_or_else(Uuid::new_v4)),
+                        venue_id: Set(venue_id),
+                        section_id: Set(section_id),
+                        row_label: Set(row.label.clone()),
+                        row_position: Set(row_position as i32),
+                        label: Set(seat.label.clone()),
+                        position: Set(position as i32),
+                        x: Set(seat.x),
+                        y: Set(seat.y),
+                        accessible: Set(seat.accessible),
+                        obstructed: Set(seat.obstructed),
+                    };
+                    let seat_id = match existing {
+                        Some(_) => {
+                            seat_model
+                                .update(&txn)
+                                .await
+                                .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+                                .id
+                        }
+                        None => {
+                            let id = Uuid::new_v4();
+                            new_seats.push(venue_seat::ActiveModel {
+                                id: Set(id),
+                                ..seat_model
+                            });
+                            id
+                        }
+                    };
+                    seats.push(Seat::new(
+                        seat_id,
+                        seat.label,
+                        seat.x,
+                        seat.y,
+                        seat.accessible,
+                        seat.obstructed,
+                    ));
+                }
+                rows.push(SeatingRow::new(row.label, seats));
+            }
+
+            sections.push(SeatingSection::new(section_id, section.name, rows));
+        }
+
+        // Whatever is left is missing from the new layout
+        let removed_seats: Vec<Uuid> = current_seats.into_values().collect();
+        if !removed_seats.is_empty() {
+            VenueSeatEntity::delete_many()
+                .filter(venue_seat::Column::Id.is_in(removed_seats))
+                .exec(&txn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+        let removed_sections: Vec<Uuid> = current_sections.into_values().collect();
+        if !removed_sections.is_empty() {
+            VenueSectionEntity::delete_many()
+                .filter(venue_section::Column::Id.is_in(removed_sections))
+                .exec(&txn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+        if !new_seats.is_empty() {
+            VenueSeatEntity::insert_many(new_seats)
+                .exec(&txn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(SeatingChart::new(venue_id, sections))
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Users allowed to change platform-wide data such as the category taxonomy.
+#[derive(Debug, Clone)]
+pub struct Administrators {
+    user_ids: HashSet<Uuid>,
+}
+
+impl Administrators {
+    /// Parses a comma-separated list of user ids, ignoring blank entries.
+    pub fn parse(list: &str) -> Result<Self, uuid::Error> {
+        list.split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(Uuid::parse_str)
+            .collect::<Result<HashSet<_>, _>>()
+            .map(|user_ids| Self { user_ids })
+    }
+
+    pub fn contains(&self, user_id: Uuid) -> bool {
+        self.user_ids.contains(&user_id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.user_ids.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let id = Uuid::new_v4();
+        let admins = Administrators::parse(&format!(" {id}, ,")).unwrap();
+        assert!(admins.contains(id));
+        assert!(!admins.contains(Uuid::new_v4()));
+        assert!(Administrators::parse("").unwrap().is_empty());
+        assert!(Administrators::parse("not-a-uuid").is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use super::Event;
+
+/// A named set of events published as a subscribable calendar.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarFeed {
+    name: String,
+    events: Vec<Event>,
+}
+
+impl CalendarFeed {
+    pub fn new(name: String, events: Vec<Event>) -> Self {
+        Self { name, events }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+}
@@ -0,0 +1,292 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Wait before retrying a failed step the first time; doubles with every
+/// further failure up to `MAX_RETRY_DELAY_SECONDS`.
+const FIRST_RETRY_DELAY_SECONDS: i64 = 30;
+const MAX_RETRY_DELAY_SECONDS: i64 = 60 * 60;
+
+/// The work that follows cancelling an event, in the order it is done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CancellationStep {
+    /// No more tickets are sold.
+    StopSales,
+    /// Every order is refunded.
+    RefundOrders,
+    /// Everyone who held a ticket is told why the event is off.
+    NotifyHolders,
+}
+
+impl CancellationStep {
+    pub const ALL: [CancellationStep; 3] = [
+        CancellationStep::StopSales,
+        CancellationStep::RefundOrders,
+        CancellationStep::NotifyHolders,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CancellationStep::StopSales => "stop_sales",
+            CancellationStep::RefundOrders => "refund_orders",
+            CancellationStep::NotifyHolders => "notify_holders",
+        }
+    }
+
+    pub fn parse(step: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.as_str() == step)
+    }
+
+    fn next(self) -> Option<Self> {
+        match self {
+            CancellationStep::StopSales => Some(CancellationStep::RefundOrders),
+            CancellationStep::RefundOrders => Some(CancellationStep::NotifyHolders),
+            CancellationStep::NotifyHolders => None,
+        }
+    }
+}
+
+/// Where a step of a cancellation stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepState {
+    Done,
+    InProgress,
+    Pending,
+}
+
+/// A cancelled event's progress through stopping sales, refunding orders
+/// and notifying ticket holders.
+///
+/// Steps run one after another and each is safe to repeat, so a step that
+/// fails is retried with backoff until it succeeds; nothing is rolled back,
+/// because an event can't be uncancelled.
+#[derive(Debug, Clone)]
+pub struct EventCancellation {
+    event_id: Uuid,
+    reason: String,
+    requested_by: Uuid,
+    /// `None` once every step is done.
+    step: Option<CancellationStep>,
+    orders_refunded: i32,
+    /// Holders notified so far, in the order the ticketing side lists them;
+    /// a retried notification step resumes after them.
+    holders_notified: i32,
+    /// Failed attempts at the current step.
+    attempts: i32,
+    last_error: Option<String>,
+    next_attempt_at: DateTime<Utc>,
+    /// Held by the runner working on the cancellation, if any.
+    claim_token: Option<Uuid>,
+    started_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    completed_at: Option<DateTime<Utc>>,
+}
+
+impl EventCancellation {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        event_id: Uuid,
+        reason: String,
+        requested_by: Uuid,
+        step: Option<CancellationStep>,
+        orders_refunded: i32,
+        holders_notified: i32,
+        attempts: i32,
+        last_error: Option<String>,
+        next_attempt_at: DateTime<Utc>,
+        started_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+        completed_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            event_id,
+            reason,
+            requested_by,
+            step,
+            orders_refunded,
+            holders_notified,
+            attempts,
+            last_error,
+            next_attempt_at,
+            claim_token: None,
+            started_at,
+            updated_at,
+            completed_at,
+        }
+    }
+
+    pub fn with_claim_token(mut self, claim_token: Option<Uuid>) -> Self {
+        self.claim_token = claim_token;
+        self
+    }
+
+    /// A cancellation that has just been requested, due right away.
+    pub fn start(event_id: Uuid, reason: String, requested_by: Uuid, now: DateTime<Utc>) -> Self {
+        Self::new(
+            event_id,
+            reason,
+            requested_by,
+            Some(CancellationStep::StopSales),
+            0,
+            0,
+            0,
+            None,
+            now,
+            now,
+            now,
+            None,
+        )
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub fn requested_by(&self) -> Uuid {
+        self.requested_by
+    }
+
+    pub fn step(&self) -> Option<CancellationStep> {
+        self.step
+    }
+
+    pub fn orders_refunded(&self) -> i32 {
+        self.orders_refunded
+    }
+
+    pub fn holders_notified(&self) -> i32 {
+        self.holders_notified
+    }
+
+    pub fn attempts(&self) -> i32 {
+        self.attempts
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    pub fn next_attempt_at(&self) -> &DateTime<Utc> {
+        &self.next_attempt_at
+    }
+
+    pub fn claim_token(&self) -> Option<Uuid> {
+        self.claim_token
+    }
+
+    pub fn started_at(&self) -> &DateTime<Utc> {
+        &self.started_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    pub fn completed_at(&self) -> Option<&DateTime<Utc>> {
+        self.completed_at.as_ref()
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.step.is_none()
+    }
+
+    pub fn step_state(&self, step: CancellationStep) -> StepState {
+        match self.step {
+            None => StepState::Done,
+            Some(current) if current == step => StepState::InProgress,
+            Some(current) => {
+                let position = |s| CancellationStep::ALL.iter().position(|&x| x == s);
+                if position(step) < position(current) {
+                    StepState::Done
+                } else {
+                    StepState::Pending
+                }
+            }
+        }
+    }
+
+    pub fn record_refunds(&mut self, count: u32) {
+        self.orders_refunded = self.orders_refunded.saturating_add_unsigned(count);
+    }
+
+    pub fn record_notified(&mut self) {
+        self.holders_notified += 1;
+    }
+
+    /// Finishes the current step and makes the next one due right away.
+    pub fn advance(&mut self, now: DateTime<Utc>) {
+        self.step = self.step.and_then(CancellationStep::next);
+        self.attempts = 0;
+        self.last_error = None;
+        self.next_attempt_at = now;
+        self.updated_at = now;
+        if self.step.is_none() {
+            self.completed_at = Some(now);
+        }
+    }
+
+    /// Records a failed attempt at the current step and schedules a retry,
+    /// waiting longer after every failure.
+    pub fn fail(&mut self, error: String, now: DateTime<Utc>) {
+        self.attempts += 1;
+        let delay =
+            (FIRST_RETRY_DELAY_SECONDS << (self.attempts - 1).min(16)).min(MAX_RETRY_DELAY_SECONDS);
+        self.last_error = Some(error);
+        self.next_attempt_at = now + Duration::seconds(delay);
+        self.updated_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_steps_advance_in_order_and_retry_with_backoff() {
+        let now = Utc.with_ymd_and_hms(2026, 11, 1, 12, 0, 0).unwrap();
+        let mut cancellation =
+            EventCancellation::start(Uuid::nil(), "Storm".to_string(), Uuid::nil(), now);
+        assert_eq!(cancellation.step(), Some(CancellationStep::StopSales));
+
+        cancellation.advance(now);
+        cancellation.fail("Payments are down".to_string(), now);
+        cancellation.fail("Payments are down".to_string(), now);
+        assert_eq!(cancellation.attempts(), 2);
+        assert_eq!(*cancellation.next_attempt_at(), now + Duration::seconds(60));
+        assert_eq!(
+            cancellation.step_state(CancellationStep::StopSales),
+            StepState::Done
+        );
+        assert_eq!(
+            cancellation.step_state(CancellationStep::RefundOrders),
+            StepState::InProgress
+        );
+        assert_eq!(
+            cancellation.step_state(CancellationStep::NotifyHolders),
+            StepState::Pending
+        );
+
+        for _ in 0..20 {
+            cancellation.fail("Payments are down".to_string(), now);
+        }
+        assert_eq!(
+            *cancellation.next_attempt_at(),
+            now + Duration::seconds(MAX_RETRY_DELAY_SECONDS)
+        );
+
+        cancellation.advance(now);
+        assert_eq!(cancellation.attempts(), 0);
+        assert_eq!(cancellation.last_error(), None);
+        cancellation.advance(now);
+        assert!(cancellation.is_completed());
+        assert_eq!(cancellation.completed_at(), Some(&now));
+    }
+}
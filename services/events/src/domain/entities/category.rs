@@ -0,0 +1,241 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// A node of the discovery taxonomy, e.g. Music → Jazz.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Category {
+    id: Uuid,
+    parent_id: Option<Uuid>,
+    name: String,
+    slug: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Category {
+    pub fn new(
+        id: Uuid,
+        parent_id: Option<Uuid>,
+        name: String,
+        slug: String,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            parent_id,
+            name,
+            slug,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn parent_id(&self) -> Option<Uuid> {
+        self.parent_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+/// Number of events in a category, directly and including its subcategories.
+#[derive(Debug, Clone)]
+pub struct CategoryFacet {
+    category: Category,
+    count: usize,
+    total: usize,
+}
+
+impl CategoryFacet {
+    pub fn category(&self) -> &Category {
+        &self.category
+    }
+
+    /// Events filed directly under the category.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Distinct events in the category or any of its descendants.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+/// The whole category tree. It is small enough to load at once, which keeps
+/// traversal out of SQL.
+#[derive(Debug, Clone)]
+pub struct Taxonomy {
+    categories: Vec<Category>,
+}
+
+impl Taxonomy {
+    /// `categories` are kept in the given order, which is the order children
+    /// are listed in.
+    pub fn new(categories: Vec<Category>) -> Self {
+        Self { categories }
+    }
+
+    pub fn get(&self, id: Uuid) -> Option<&Category> {
+        self.categories.iter().find(|category| category.id == id)
+    }
+
+    pub fn categories(&self) -> &[Category] {
+        &self.categories
+    }
+
+    pub fn roots(&self) -> impl Iterator<Item = &Category> {
+        self.categories
+            .iter()
+            .filter(|category| category.parent_id.is_none())
+    }
+
+    pub fn children(&self, id: Uuid) -> impl Iterator<Item = &Category> {
+        self.categories
+            .iter()
+            .filter(move |category| category.parent_id == Some(id))
+    }
+
+    /// `id` followed by every category below it.
+    pub fn subtree(&self, id: Uuid) -> Vec<Uuid> {
+        let mut ids = vec![id];
+        let mut i = 0;
+        while i < ids.len() {
+            let parent = ids[i];
+            ids.extend(self.children(parent).map(Category::id));
+            i += 1;
+        }
+        ids
+    }
+
+    /// Whether `id` is `ancestor` or lies below it; moving `ancestor` under
+    /// such a category would create a cycle.
+    pub fn is_within(&self, id: Uuid, ancestor: Uuid) -> bool {
+        let mut current = Some(id);
+        let mut seen = HashSet::new();
+        while let Some(category_id) = current {
+            if category_id == ancestor {
+                return true;
+            }
+            if !seen.insert(category_id) {
+                return false;
+            }
+            current = self.get(category_id).and_then(Category::parent_id);
+        }
+        false
+    }
+
+    /// Counts per category from `(event_id, category_id)` assignments. An
+    /// event filed under both Music and Jazz counts once towards Music's
+    /// total.
+    pub fn facets(&self, assignments: &[(Uuid, Uuid)]) -> Vec<CategoryFacet> {
+        let mut direct: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+        for (event_id, category_id) in assignments {
+            direct.entry(*category_id).or_default().insert(*event_id);
+        }
+
+        self.categories
+            .iter()
+            .map(|category| {
+                let total: HashSet<Uuid> = self
+                    .subtree(category.id)
+                    .iter()
+                    .filter_map(|id| direct.get(id))
+                    .flatten()
+                    .copied()
+                    .collect();
+                CategoryFacet {
+                    category: category.clone(),
+                    count: direct.get(&category.id).map_or(0, HashSet::len),
+                    total: total.len(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(name: &str, parent: Option<&Category>) -> Category {
+        let now = Utc::now();
+        Category::new(
+            Uuid::new_v4(),
+            parent.map(Category::id),
+            name.to_string(),
+            name.to_lowercase(),
+            now,
+            now,
+        )
+    }
+
+    #[test]
+    fn test_subtree_and_cycle_detection() {
+        let music = category("Music", None);
+        let jazz = category("Jazz", Some(&music));
+        let bebop = category("Bebop", Some(&jazz));
+        let comedy = category("Comedy", None);
+        let taxonomy = Taxonomy::new(vec![
+            music.clone(),
+            jazz.clone(),
+            bebop.clone(),
+            comedy.clone(),
+        ]);
+
+        assert_eq!(
+            taxonomy.subtree(music.id()),
+            vec![music.id(), jazz.id(), bebop.id()]
+        );
+        assert_eq!(taxonomy.subtree(comedy.id()), vec![comedy.id()]);
+        assert!(taxonomy.is_within(bebop.id(), music.id()));
+        assert!(taxonomy.is_within(music.id(), music.id()));
+        assert!(!taxonomy.is_within(music.id(), bebop.id()));
+        assert!(!taxonomy.is_within(comedy.id(), music.id()));
+    }
+
+    #[test]
+    fn test_facets_roll_up_distinct_events() {
+        let music = category("Music", None);
+        let jazz = category("Jazz", Some(&music));
+        let rock = category("Rock", Some(&music));
+        let taxonomy = Taxonomy::new(vec![music.clone(), jazz.clone(), rock.clone()]);
+        let (gig, festival) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let facets = taxonomy.facets(&[
+            (gig, jazz.id()),
+            (festival, music.id()),
+            (festival, jazz.id()),
+            (festival, rock.id()),
+        ]);
+
+        let counts: Vec<_> = facets
+            .iter()
+            .map(|facet| (facet.category().name(), facet.count(), facet.total()))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![("Music", 1, 2), ("Jazz", 2, 2), ("Rock", 1, 1)]
+        );
+    }
+}
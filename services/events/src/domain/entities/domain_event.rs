@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Something that happened to an event that other services may react to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DomainEvent {
+    EventPublished {
+        event_id: Uuid,
+        published_at: DateTime<Utc>,
+    },
+    TicketSalesOpened {
+        event_id: Uuid,
+        opened_at: DateTime<Utc>,
+    },
+    EventCancelled {
+        event_id: Uuid,
+        reason: String,
+        cancelled_at: DateTime<Utc>,
+    },
+}
+
+impl DomainEvent {
+    /// Name of the event type, as in its `type` field.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DomainEvent::EventPublished { .. } => "event_published",
+            DomainEvent::TicketSalesOpened { .. } => "ticket_sales_opened",
+            DomainEvent::EventCancelled { .. } => "event_cancelled",
+        }
+    }
+
+    /// The event this happened to.
+    pub fn event_id(&self) -> Uuid {
+        match self {
+            DomainEvent::EventPublished { event_id, .. }
+            | DomainEvent::TicketSalesOpened { event_id, .. }
+            | DomainEvent::EventCancelled { event_id, .. } => *event_id,
+        }
+    }
+}
+
+/// A domain event as recorded in the outbox, in the same transaction as the
+/// change it describes, waiting to be dispatched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxMessage {
+    id: Uuid,
+    event: DomainEvent,
+    occurred_at: DateTime<Utc>,
+}
+
+impl OutboxMessage {
+    pub fn new(id: Uuid, event: DomainEvent, occurred_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            event,
+            occurred_at,
+        }
+    }
+
+    /// Stable across redeliveries, so consumers can drop duplicates.
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn event(&self) -> &DomainEvent {
+        &self.event
+    }
+
+    pub fn occurred_at(&self) -> &DateTime<Utc> {
+        &self.occurred_at
+    }
+}
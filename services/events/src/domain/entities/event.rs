@@ -2,24 +2,68 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::{
+    Admission, AdmissionError, AgendaDay, EventSchedule, EventSession, EventTranslation, Locale,
+    MediaAsset, MediaKind, Publication, RichText, TicketType,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventStatus {
+    Scheduled,
+    Cancelled,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Event {
     id: Uuid,
     title: String,
-    description: String,
-    event_date: DateTime<Utc>,
+    description: RichText,
+    /// Locale `title` and `description` are in; the default locale unless
+    /// the event has been localized.
+    locale: Locale,
+    /// Locale the organizer writes the event in.
+    default_locale: Locale,
+    schedule: EventSchedule,
     venue_name: String,
+    venue_id: Option<Uuid>,
+    organizer_id: Option<Uuid>,
+    /// Organization whose owners and managers manage the event.
+    organization_id: Option<Uuid>,
+    status: EventStatus,
+    publication: Publication,
+    /// Incremented on every change so calendar clients replace stale copies
+    /// (iCalendar `SEQUENCE`).
+    sequence: i32,
+    /// Incremented on every change to the event, its ticket types or its
+    /// media; the event's ETag.
+    version: i32,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
+    ticket_types: Vec<TicketType>,
+    category_ids: Vec<Uuid>,
+    tags: Vec<String>,
+    media: Vec<MediaAsset>,
+    /// Earliest first.
+    sessions: Vec<EventSession>,
+    /// Into locales other than the default one, by locale.
+    translations: Vec<EventTranslation>,
 }
 
 impl Event {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: Uuid,
         title: String,
-        description: String,
-        event_date: DateTime<Utc>,
+        description: RichText,
+        schedule: EventSchedule,
         venue_name: String,
+        venue_id: Option<Uuid>,
+        organizer_id: Option<Uuid>,
+        organization_id: Option<Uuid>,
+        status: EventStatus,
+        sequence: i32,
+        version: i32,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Self {
@@ -27,13 +71,65 @@ impl Event {
             id,
             title,
             description,
-            event_date,
+            locale: Locale::default(),
+            default_locale: Locale::default(),
+            schedule,
             venue_name,
+            venue_id,
+            organizer_id,
+            organization_id,
+            status,
+            publication: Publication::default(),
+            sequence,
+            version,
             created_at,
             updated_at,
+            ticket_types: Vec::new(),
+            category_ids: Vec::new(),
+            tags: Vec::new(),
+            media: Vec::new(),
+            sessions: Vec::new(),
+            translations: Vec::new(),
         }
     }
 
+    pub fn with_default_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale.clone();
+        self.default_locale = locale;
+        self
+    }
+
+    pub fn with_translations(mut self, translations: Vec<EventTranslation>) -> Self {
+        self.translations = translations;
+        self
+    }
+
+    pub fn with_ticket_types(mut self, ticket_types: Vec<TicketType>) -> Self {
+        self.ticket_types = ticket_types;
+        self
+    }
+
+    pub fn with_classification(mut self, category_ids: Vec<Uuid>, tags: Vec<String>) -> Self {
+        self.category_ids = category_ids;
+        self.tags = tags;
+        self
+    }
+
+    pub fn with_media(mut self, media: Vec<MediaAsset>) -> Self {
+        self.media = media;
+        self
+    }
+
+    pub fn with_sessions(mut self, sessions: Vec<EventSession>) -> Self {
+        self.sessions = sessions;
+        self
+    }
+
+    pub fn with_publication(mut self, publication: Publication) -> Self {
+        self.publication = publication;
+        self
+    }
+
     pub fn id(&self) -> Uuid {
         self.id
     }
@@ -42,18 +138,106 @@ impl Event {
         &self.title
     }
 
+    /// Markdown source of the description, as entered.
     pub fn description(&self) -> &str {
-        &self.description
+        self.description.markdown()
     }
 
+    /// Description rendered to sanitized HTML.
+    pub fn description_html(&self) -> &str {
+        self.description.html()
+    }
+
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    pub fn default_locale(&self) -> &Locale {
+        &self.default_locale
+    }
+
+    pub fn translations(&self) -> &[EventTranslation] {
+        &self.translations
+    }
+
+    pub fn translation(&self, locale: &Locale) -> Option<&EventTranslation> {
+        self.translations
+            .iter()
+            .find(|translation| translation.locale() == locale)
+    }
+
+    /// The default locale, then those of the translations.
+    pub fn locales(&self) -> Vec<Locale> {
+        std::iter::once(&self.default_locale)
+            .chain(self.translations.iter().map(EventTranslation::locale))
+            .cloned()
+            .collect()
+    }
+
+    /// Switches the title and description to the locale that best matches
+    /// the client's language `ranges`, most preferred first, keeping the
+    /// default locale when none does.
+    pub fn localize(mut self, ranges: &[String]) -> Self {
+        let locales = self.locales();
+        let Some(locale) = Locale::negotiate(ranges, &locales) else {
+            return self;
+        };
+        if let Some(translation) = self.translation(locale).cloned() {
+            self.title = translation.title().to_string();
+            self.description = RichText::from_parts(
+                translation.description().to_string(),
+                translation.description_html().to_string(),
+            );
+            self.locale = locale.clone();
+        }
+        self
+    }
+
+    /// Start of the event.
     pub fn event_date(&self) -> &DateTime<Utc> {
-        &self.event_date
+        self.schedule.starts_at()
+    }
+
+    pub fn schedule(&self) -> &EventSchedule {
+        &self.schedule
     }
 
     pub fn venue_name(&self) -> &str {
         &self.venue_name
     }
 
+    pub fn venue_id(&self) -> Option<Uuid> {
+        self.venue_id
+    }
+
+    pub fn organizer_id(&self) -> Option<Uuid> {
+        self.organizer_id
+    }
+
+    pub fn organization_id(&self) -> Option<Uuid> {
+        self.organization_id
+    }
+
+    pub fn status(&self) -> EventStatus {
+        self.status
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.status == EventStatus::Cancelled
+    }
+
+    pub fn publication(&self) -> &Publication {
+        &self.publication
+    }
+
+    pub fn sequence(&self) -> i32 {
+        self.sequence
+    }
+
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
     pub fn created_at(&self) -> &DateTime<Utc> {
         &self.created_at
     }
@@ -61,4 +245,255 @@ impl Event {
     pub fn updated_at(&self) -> &DateTime<Utc> {
         &self.updated_at
     }
+
+    pub fn ticket_types(&self) -> &[TicketType] {
+        &self.ticket_types
+    }
+
+    /// Categories the event is filed under; browsing a parent category
+    /// includes them.
+    pub fn category_ids(&self) -> &[Uuid] {
+        &self.category_ids
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn poster(&self) -> Option<&MediaAsset> {
+        self.media
+            .iter()
+            .find(|asset| asset.kind() == MediaKind::Poster)
+    }
+
+    /// Gallery items in upload order.
+    pub fn gallery(&self) -> impl Iterator<Item = &MediaAsset> {
+        self.media
+            .iter()
+            .filter(|asset| asset.kind() == MediaKind::Gallery)
+    }
+
+    /// Sessions of a multi-session event, earliest first.
+    pub fn sessions(&self) -> &[EventSession] {
+        &self.sessions
+    }
+
+    pub fn session(&self, id: Uuid) -> Option<&EventSession> {
+        self.sessions.iter().find(|session| session.id() == id)
+    }
+
+    /// Sessions grouped by day and stage.
+    pub fn agenda(&self) -> Vec<AgendaDay> {
+        AgendaDay::group(&self.sessions, self.schedule.timezone())
+    }
+
+    /// When what `admission` admits to begins; ticket sales end by then.
+    pub fn admission_starts_at(&self, admission: Admission) -> Option<DateTime<Utc>> {
+        match admission {
+            Admission::Event => Some(*self.schedule.starts_at()),
+            Admission::Day(date) => Some(self.schedule.day_starts_at(date)),
+            Admission::Session(id) => self.session(id).map(|session| *session.starts_at()),
+        }
+    }
+
+    /// Whether `admission` lets its holders into the session `session_id`
+    /// when that starts at `starts_at`.
+    fn admits_to_session(
+        &self,
+        admission: Admission,
+        session_id: Uuid,
+        starts_at: &DateTime<Utc>,
+    ) -> bool {
+        match admission {
+            Admission::Event => true,
+            Admission::Day(date) => {
+                starts_at
+                    .with_timezone(&self.schedule.timezone())
+                    .date_naive()
+                    == date
+            }
+            Admission::Session(id) => id == session_id,
+        }
+    }
+
+    /// Tickets that admit to the session `session_id` if it starts at
+    /// `starts_at`: its own, day passes for its local day and whole-event
+    /// tickets. The ticket type `replacing` is left out.
+    pub fn tickets_admitted_to_session(
+        &self,
+        session_id: Uuid,
+        starts_at: &DateTime<Utc>,
+        replacing: Option<Uuid>,
+    ) -> i64 {
+        self.ticket_types
+            .iter()
+            .filter(|ticket_type| {
+                Some(ticket_type.id()) != replacing
+                    && self.admits_to_session(ticket_type.admission(), session_id, starts_at)
+            })
+            .map(|ticket_type| i64::from(ticket_type.quantity()))
+            .sum()
+    }
+
+    /// Checks that a ticket type with `quantity` tickets can admit to
+    /// `admission`: days must be ones the event runs on, and every session
+    /// it admits to must still fit everyone holding a ticket, counting
+    /// `replacing` as gone.
+    pub fn check_admission(
+        &self,
+        admission: Admission,
+        quantity: i32,
+        replacing: Option<Uuid>,
+    ) -> Result<(), AdmissionError> {
+        match admission {
+            Admission::Day(date) if !self.schedule.local_days().contains(&date) => {
+                return Err(AdmissionError::DayOutsideEvent(date));
+            }
+            Admission::Session(id) if self.session(id).is_none() => {
+                return Err(AdmissionError::SessionNotFound(id));
+            }
+            _ => {}
+        }
+        for session in &self.sessions {
+            let Some(capacity) = session.capacity() else {
+                continue;
+            };
+            if !self.admits_to_session(admission, session.id(), session.starts_at()) {
+                continue;
+            }
+            let admitted =
+                self.tickets_admitted_to_session(session.id(), session.starts_at(), replacing);
+            if admitted + i64::from(quantity) > i64::from(capacity) {
+                return Err(AdmissionError::OverSessionCapacity(capacity));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::TicketTypeVisibility;
+    use crate::domain::fixtures::EventFixture;
+    use chrono::{NaiveDate, TimeZone};
+    use chrono_tz::Europe::Berlin;
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 7, day, hour, 0, 0).unwrap()
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, day).unwrap()
+    }
+
+    fn session(event_id: Uuid, starts_at: DateTime<Utc>, capacity: Option<i32>) -> EventSession {
+        EventSession::new(
+            Uuid::new_v4(),
+            event_id,
+            "Session".to_string(),
+            None,
+            starts_at,
+            starts_at + chrono::Duration::hours(1),
+            capacity,
+            starts_at,
+            starts_at,
+        )
+    }
+
+    fn ticket_type(event_id: Uuid, admission: Admission, quantity: i32) -> TicketType {
+        TicketType::new(
+            Uuid::new_v4(),
+            event_id,
+            "Ticket".to_string(),
+            2500,
+            "EUR".to_string(),
+            quantity,
+            1,
+            4,
+            None,
+            None,
+            TicketTypeVisibility::Public,
+            admission,
+            at(1, 0),
+            at(1, 0),
+        )
+    }
+
+    /// A festival in Berlin from the 17th to the 19th with a workshop for
+    /// 200 starting at 23:00 UTC on the 17th, already the 18th locally.
+    fn festival() -> (Event, EventSession, TicketType) {
+        let id = Uuid::new_v4();
+        let workshop = session(id, at(17, 23), Some(200));
+        let talk = session(id, at(17, 12), None);
+        let workshop_tickets = ticket_type(id, Admission::Session(workshop.id()), 150);
+        let event = EventFixture {
+            id,
+            schedule: EventSchedule::new(Berlin, at(17, 8), Some(at(19, 20)), None),
+            ..EventFixture::at(at(17, 8))
+        }
+        .build()
+        .with_sessions(vec![talk, workshop.clone()])
+        .with_ticket_types(vec![
+            workshop_tickets.clone(),
+            ticket_type(id, Admission::Event, 30),
+            ticket_type(id, Admission::Day(date(17)), 500),
+        ]);
+        (event, workshop, workshop_tickets)
+    }
+
+    #[test]
+    fn test_session_tickets_include_passes_for_its_local_day() {
+        let (event, workshop, _) = festival();
+
+        // Its own tickets and the whole-event passes, not the 17th's day passes
+        assert_eq!(
+            event.tickets_admitted_to_session(workshop.id(), workshop.starts_at(), None),
+            180
+        );
+        // Moved to the afternoon of the 17th, the day passes admit to it too
+        assert_eq!(
+            event.tickets_admitted_to_session(workshop.id(), &at(17, 14), None),
+            680
+        );
+    }
+
+    #[test]
+    fn test_check_admission_counts_every_ticket_admitting_to_a_session() {
+        let (event, workshop, workshop_tickets) = festival();
+        let full = Err(AdmissionError::OverSessionCapacity(200));
+
+        assert_eq!(event.check_admission(Admission::Event, 20, None), Ok(()));
+        assert_eq!(event.check_admission(Admission::Event, 21, None), full);
+        assert_eq!(
+            event.check_admission(Admission::Day(date(18)), 21, None),
+            full
+        );
+        // The 17th's sessions have no capacity
+        assert_eq!(
+            event.check_admission(Admission::Day(date(17)), 1000, None),
+            Ok(())
+        );
+        let workshop_only = Admission::Session(workshop.id());
+        assert_eq!(event.check_admission(workshop_only, 21, None), full);
+        assert_eq!(
+            event.check_admission(workshop_only, 170, Some(workshop_tickets.id())),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_check_admission_rejects_unknown_days_and_sessions() {
+        let (event, _, _) = festival();
+        let missing = Uuid::new_v4();
+
+        assert_eq!(
+            event.check_admission(Admission::Day(date(20)), 1, None),
+            Err(AdmissionError::DayOutsideEvent(date(20)))
+        );
+        assert_eq!(
+            event.check_admission(Admission::Session(missing), 1, None),
+            Err(AdmissionError::SessionNotFound(missing))
+        );
+    }
 }
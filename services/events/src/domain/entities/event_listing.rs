@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+
+use super::{Event, Occurrence};
+
+/// An entry of the event listing: either a one-off event or one occurrence of
+/// a recurring series.
+#[derive(Debug, Clone)]
+pub enum EventListing {
+    Single(Box<Event>),
+    Occurrence(Occurrence),
+}
+
+impl EventListing {
+    pub fn starts_at(&self) -> &DateTime<Utc> {
+        match self {
+            EventListing::Single(event) => event.event_date(),
+            EventListing::Occurrence(occurrence) => occurrence.starts_at(),
+        }
+    }
+}
+
+/// A listing entry, with its venue's distance from the searched point when
+/// listing events near one.
+#[derive(Debug, Clone)]
+pub struct ListingEntry {
+    pub listing: EventListing,
+    pub distance_km: Option<f64>,
+}
@@ -0,0 +1,185 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Occurrence, OccurrenceOverride, Recurrence, RecurrenceError, RichText};
+
+/// A repeating event described by an RFC 5545 rule. Occurrences are not
+/// stored; they are expanded on demand and patched by per-occurrence overrides.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventSeries {
+    id: Uuid,
+    title: String,
+    description: RichText,
+    venue_name: String,
+    venue_id: Option<Uuid>,
+    starts_at: DateTime<Utc>,
+    /// IANA timezone the rule is expanded in.
+    timezone: Tz,
+    rrule: String,
+    exdates: Vec<DateTime<Utc>>,
+    last_occurrence_at: Option<DateTime<Utc>>,
+    organizer_id: Option<Uuid>,
+    organization_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    overrides: Vec<OccurrenceOverride>,
+}
+
+impl EventSeries {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        title: String,
+        description: RichText,
+        venue_name: String,
+        venue_id: Option<Uuid>,
+        starts_at: DateTime<Utc>,
+        timezone: Tz,
+        rrule: String,
+        exdates: Vec<DateTime<Utc>>,
+        last_occurrence_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            title,
+            description,
+            venue_name,
+            venue_id,
+            starts_at,
+            timezone,
+            rrule,
+            exdates,
+            last_occurrence_at,
+            organizer_id: None,
+            organization_id: None,
+            created_at,
+            updated_at,
+            overrides: Vec::new(),
+        }
+    }
+
+    pub fn with_owner(mut self, organizer_id: Option<Uuid>, organization_id: Option<Uuid>) -> Self {
+        self.organizer_id = organizer_id;
+        self.organization_id = organization_id;
+        self
+    }
+
+    pub fn with_overrides(mut self, overrides: Vec<OccurrenceOverride>) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Markdown source of the description, as entered.
+    pub fn description(&self) -> &str {
+        self.description.markdown()
+    }
+
+    /// Sanitized HTML rendering of the description.
+    pub fn description_html(&self) -> &str {
+        self.description.html()
+    }
+
+    pub fn venue_name(&self) -> &str {
+        &self.venue_name
+    }
+
+    pub fn venue_id(&self) -> Option<Uuid> {
+        self.venue_id
+    }
+
+    pub fn starts_at(&self) -> &DateTime<Utc> {
+        &self.starts_at
+    }
+
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    pub fn rrule(&self) -> &str {
+        &self.rrule
+    }
+
+    pub fn exdates(&self) -> &[DateTime<Utc>] {
+        &self.exdates
+    }
+
+    pub fn last_occurrence_at(&self) -> Option<&DateTime<Utc>> {
+        self.last_occurrence_at.as_ref()
+    }
+
+    /// Who created the series; series created before series had owners
+    /// have none.
+    pub fn organizer_id(&self) -> Option<Uuid> {
+        self.organizer_id
+    }
+
+    pub fn organization_id(&self) -> Option<Uuid> {
+        self.organization_id
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    pub fn overrides(&self) -> &[OccurrenceOverride] {
+        &self.overrides
+    }
+
+    pub fn recurrence(&self) -> Result<Recurrence, RecurrenceError> {
+        Recurrence::parse(self.starts_at, self.timezone, &self.rrule, &self.exdates)
+    }
+
+    fn occurrence(&self, original_start: DateTime<Utc>) -> Occurrence {
+        let override_ = self
+            .overrides
+            .iter()
+            .find(|o| *o.occurrence_start() == original_start);
+
+        Occurrence::new(self, original_start, override_)
+    }
+
+    /// Expands the occurrences starting in `[from, to)`, after applying
+    /// overrides. An occurrence rescheduled into the window is included even
+    /// if its original slot lies outside it, and vice versa.
+    pub fn occurrences_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Occurrence>, RecurrenceError> {
+        let recurrence = self.recurrence()?;
+        let in_window = |dt: &DateTime<Utc>| *dt >= from && *dt < to;
+
+        let mut occurrences: Vec<Occurrence> = recurrence
+            .starts_between(from, to)
+            .map(|start| self.occurrence(start))
+            .filter(|occurrence| in_window(occurrence.starts_at()))
+            .collect();
+
+        for override_ in &self.overrides {
+            let original = *override_.occurrence_start();
+            let moved_in = override_.starts_at().is_some_and(&in_window);
+            if moved_in && !in_window(&original) && recurrence.contains(original) {
+                occurrences.push(self.occurrence(original));
+            }
+        }
+
+        occurrences.sort_by_key(|occurrence| *occurrence.starts_at());
+        Ok(occurrences)
+    }
+}
@@ -0,0 +1,262 @@
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Admission, Event, Locale, MediaFormat, MediaKind, TicketTypeVisibility};
+
+/// What a blueprint ticket type admits to, relative to the new event.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdmissionBlueprint {
+    #[default]
+    Event,
+    /// Days after the event's first local day.
+    Day(i64),
+    /// Index into the blueprint's sessions.
+    Session(usize),
+}
+
+/// A session to set up on new events, kept relative to the event's start.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionBlueprint {
+    pub title: String,
+    pub stage: Option<String>,
+    pub starts_minutes_after: i64,
+    pub duration_minutes: i64,
+    pub capacity: Option<i32>,
+}
+
+/// A ticket type to set up on new events. Sales windows are kept as minutes
+/// before the event starts, so they move with each new event's date.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TicketTypeBlueprint {
+    pub name: String,
+    pub price_minor: i64,
+    pub currency: String,
+    pub quantity: i32,
+    pub min_per_order: i32,
+    pub max_per_order: i32,
+    pub sales_start_minutes_before: Option<i64>,
+    pub sales_end_minutes_before: Option<i64>,
+    pub visibility: TicketTypeVisibility,
+    #[serde(default)]
+    pub admission: AdmissionBlueprint,
+    /// Seating-chart section of the blueprint's venue.
+    #[serde(default)]
+    pub section_id: Option<Uuid>,
+}
+
+impl TicketTypeBlueprint {
+    pub fn sales_start(&self, starts_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.sales_start_minutes_before
+            .map(|minutes| starts_at - Duration::minutes(minutes))
+    }
+
+    pub fn sales_end(&self, starts_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.sales_end_minutes_before
+            .map(|minutes| starts_at - Duration::minutes(minutes))
+    }
+}
+
+/// The title and description in another locale.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TranslationBlueprint {
+    pub locale: Locale,
+    pub title: String,
+    /// Markdown source of the description.
+    pub description: String,
+}
+
+/// A stored media file to copy onto new events.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MediaBlueprint {
+    pub kind: MediaKind,
+    pub format: MediaFormat,
+    pub size_bytes: i64,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub storage_key: String,
+    pub thumbnail_key: Option<String>,
+    /// Public URL of the file, resolved when it was stored.
+    pub url: String,
+    pub thumbnail_url: Option<String>,
+}
+
+impl MediaBlueprint {
+    /// Object storage keys of the file and its thumbnail.
+    pub fn storage_keys(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.storage_key.as_str()).chain(self.thumbnail_key.as_deref())
+    }
+}
+
+/// Everything needed to set up another event like an existing one, apart
+/// from when it starts. Times are kept relative to the start.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventBlueprint {
+    pub title: String,
+    /// Markdown source of the description.
+    pub description: String,
+    #[serde(default)]
+    pub default_locale: Locale,
+    #[serde(default)]
+    pub translations: Vec<TranslationBlueprint>,
+    pub timezone: Tz,
+    pub duration_minutes: Option<i64>,
+    pub doors_open_minutes_before: Option<i64>,
+    pub venue_name: String,
+    pub venue_id: Option<Uuid>,
+    pub category_ids: Vec<Uuid>,
+    pub tags: Vec<String>,
+    /// Earliest first.
+    #[serde(default)]
+    pub sessions: Vec<SessionBlueprint>,
+    pub ticket_types: Vec<TicketTypeBlueprint>,
+    /// Poster first, then the gallery in upload order.
+    pub media: Vec<MediaBlueprint>,
+}
+
+impl EventBlueprint {
+    /// Blueprint of the event as it is now, including hidden ticket types.
+    /// Media still points at the event's stored files.
+    pub fn of(event: &Event) -> Self {
+        let schedule = event.schedule();
+        let starts_at = *schedule.starts_at();
+        let minutes_before = |at: &DateTime<Utc>| (starts_at - *at).num_minutes();
+        let first_day = *schedule.local_days().start();
+        let admission = |admission: Admission| match admission {
+            Admission::Event => AdmissionBlueprint::Event,
+            Admission::Day(date) => AdmissionBlueprint::Day((date - first_day).num_days()),
+            Admission::Session(id) => event
+                .sessions()
+                .iter()
+                .position(|session| session.id() == id)
+                .map_or(AdmissionBlueprint::Event, AdmissionBlueprint::Session),
+        };
+
+        Self {
+            title: event.title().to_string(),
+            description: event.description().to_string(),
+            default_locale: event.default_locale().clone(),
+            translations: event
+                .translations()
+                .iter()
+                .map(|translation| TranslationBlueprint {
+                    locale: translation.locale().clone(),
+                    title: translation.title().to_string(),
+                    description: translation.description().to_string(),
+                })
+                .collect(),
+            timezone: schedule.timezone(),
+            duration_minutes: schedule.duration().map(|duration| duration.num_minutes()),
+            doors_open_minutes_before: schedule.doors_open_at().map(minutes_before),
+            venue_name: event.venue_name().to_string(),
+            venue_id: event.venue_id(),
+            category_ids: event.category_ids().to_vec(),
+            tags: event.tags().to_vec(),
+            sessions: event
+                .sessions()
+                .iter()
+                .map(|session| SessionBlueprint {
+                    title: session.title().to_string(),
+                    stage: session.stage().map(str::to_string),
+                    starts_minutes_after: (*session.starts_at() - starts_at).num_minutes(),
+                    duration_minutes: (*session.ends_at() - *session.starts_at()).num_minutes(),
+                    capacity: session.capacity(),
+                })
+                .collect(),
+            ticket_types: event
+                .ticket_types()
+                .iter()
+                .map(|ticket_type| TicketTypeBlueprint {
+                    name: ticket_type.name().to_string(),
+                    price_minor: ticket_type.price_minor(),
+                    currency: ticket_type.currency().to_string(),
+                    quantity: ticket_type.quantity(),
+                    min_per_order: ticket_type.min_per_order(),
+                    max_per_order: ticket_type.max_per_order(),
+                    sales_start_minutes_before: ticket_type.sales_start().map(minutes_before),
+                    sales_end_minutes_before: ticket_type.sales_end().map(minutes_before),
+                    visibility: ticket_type.visibility(),
+                    admission: admission(ticket_type.admission()),
+                    section_id: ticket_type.section_id(),
+                })
+                .collect(),
+            media: event
+                .poster()
+                .into_iter()
+                .chain(event.gallery())
+                .map(|asset| MediaBlueprint {
+                    kind: asset.kind(),
+                    format: asset.format(),
+                    size_bytes: asset.size_bytes(),
+                    width: asset.width(),
+                    height: asset.height(),
+                    storage_key: asset.storage_key().to_string(),
+                    thumbnail_key: asset.thumbnail_key().map(str::to_string),
+                    url: asset.url().to_string(),
+                    thumbnail_url: asset.thumbnail_url().map(str::to_string),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A saved event setup organizers can create new events from, e.g. one stop
+/// of a tour. Templates of an organization are shared by its owners and
+/// managers; others belong to the organizer who saved them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventTemplate {
+    id: Uuid,
+    name: String,
+    organizer_id: Uuid,
+    organization_id: Option<Uuid>,
+    blueprint: EventBlueprint,
+    created_at: DateTime<Utc>,
+}
+
+impl EventTemplate {
+    pub fn new(
+        id: Uuid,
+        name: String,
+        organizer_id: Uuid,
+        organization_id: Option<Uuid>,
+        blueprint: EventBlueprint,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            organizer_id,
+            organization_id,
+            blueprint,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The user who saved the template.
+    pub fn organizer_id(&self) -> Uuid {
+        self.organizer_id
+    }
+
+    pub fn organization_id(&self) -> Option<Uuid> {
+        self.organization_id
+    }
+
+    /// Media points at copies owned by the template.
+    pub fn blueprint(&self) -> &EventBlueprint {
+        &self.blueprint
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
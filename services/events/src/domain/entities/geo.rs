@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+/// Mean radius used by Postgres' earthdistance `earth()`, so distances
+/// computed here agree with the ones the database filters and sorts by.
+const EARTH_RADIUS_KM: f64 = 6378.168;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum GeoPointError {
+    #[error("Latitude must be between -90 and 90")]
+    LatitudeOutOfRange,
+    #[error("Longitude must be between -180 and 180")]
+    LongitudeOutOfRange,
+}
+
+/// A position on the earth in decimal degrees (WGS 84).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeoPoint {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl GeoPoint {
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, GeoPointError> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(GeoPointError::LatitudeOutOfRange);
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(GeoPointError::LongitudeOutOfRange);
+        }
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Great-circle distance in kilometres.
+    pub fn distance_km(&self, other: &GeoPoint) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lng = (other.longitude - self.longitude).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lng / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+    }
+}
+
+/// The area within `radius_km` of `center`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeoRadius {
+    pub center: GeoPoint,
+    pub radius_km: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance_km() {
+        let chicago = GeoPoint::new(41.8781, -87.6298).unwrap();
+        let milwaukee = GeoPoint::new(43.0389, -87.9065).unwrap();
+
+        let distance = chicago.distance_km(&milwaukee);
+        assert!((distance - 130.6).abs() < 1.0, "got {}", distance);
+        assert_eq!(chicago.distance_km(&chicago), 0.0);
+        assert_eq!(
+            GeoPoint::new(91.0, 0.0),
+            Err(GeoPointError::LatitudeOutOfRange)
+        );
+    }
+}
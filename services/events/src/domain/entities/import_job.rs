@@ -0,0 +1,189 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImportJobStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// What happened to a single entry of an import file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum ImportRowOutcome {
+    Created {
+        event_id: Uuid,
+    },
+    /// An event with the same UID already exists or appeared earlier in the
+    /// file.
+    Duplicate {
+        event_id: Uuid,
+    },
+    /// The entry failed validation and was not imported.
+    Invalid {
+        error: String,
+    },
+    /// The entry was valid but could not be stored.
+    Failed {
+        error: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportRowResult {
+    /// Line in the source file where the entry starts.
+    pub line: usize,
+    pub uid: Option<String>,
+    #[serde(flatten)]
+    pub outcome: ImportRowOutcome,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportJob {
+    id: Uuid,
+    source: String,
+    /// Owner of the imported events; only they can follow the job.
+    organizer_id: Option<Uuid>,
+    status: ImportJobStatus,
+    total_rows: usize,
+    results: Vec<ImportRowResult>,
+    error: Option<String>,
+    /// Held by the runner importing the job's entries, if any.
+    claim_token: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    completed_at: Option<DateTime<Utc>>,
+}
+
+impl ImportJob {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        source: String,
+        status: ImportJobStatus,
+        total_rows: usize,
+        results: Vec<ImportRowResult>,
+        error: Option<String>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+        completed_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            source,
+            organizer_id: None,
+            status,
+            total_rows,
+            results,
+            error,
+            claim_token: None,
+            created_at,
+            updated_at,
+            completed_at,
+        }
+    }
+
+    pub fn with_organizer(mut self, organizer_id: Option<Uuid>) -> Self {
+        self.organizer_id = organizer_id;
+        self
+    }
+
+    pub fn with_claim_token(mut self, claim_token: Option<Uuid>) -> Self {
+        self.claim_token = claim_token;
+        self
+    }
+
+    pub fn start(&mut self) {
+        self.status = ImportJobStatus::Running;
+    }
+
+    pub fn record(&mut self, result: ImportRowResult) {
+        self.results.push(result);
+    }
+
+    pub fn complete(&mut self) {
+        self.status = ImportJobStatus::Completed;
+        self.completed_at = Some(Utc::now());
+    }
+
+    pub fn fail(&mut self, error: String) {
+        self.status = ImportJobStatus::Failed;
+        self.error = Some(error);
+        self.completed_at = Some(Utc::now());
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn organizer_id(&self) -> Option<Uuid> {
+        self.organizer_id
+    }
+
+    pub fn status(&self) -> ImportJobStatus {
+        self.status
+    }
+
+    pub fn total_rows(&self) -> usize {
+        self.total_rows
+    }
+
+    pub fn results(&self) -> &[ImportRowResult] {
+        &self.results
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn claim_token(&self) -> Option<Uuid> {
+        self.claim_token
+    }
+
+    /// Whether every entry has been imported or the job was given up on.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.status,
+            ImportJobStatus::Completed | ImportJobStatus::Failed
+        )
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    pub fn completed_at(&self) -> Option<&DateTime<Utc>> {
+        self.completed_at.as_ref()
+    }
+
+    fn count(&self, matches: impl Fn(&ImportRowOutcome) -> bool) -> usize {
+        self.results.iter().filter(|r| matches(&r.outcome)).count()
+    }
+
+    pub fn created_count(&self) -> usize {
+        self.count(|o| matches!(o, ImportRowOutcome::Created { .. }))
+    }
+
+    pub fn duplicate_count(&self) -> usize {
+        self.count(|o| matches!(o, ImportRowOutcome::Duplicate { .. }))
+    }
+
+    pub fn invalid_count(&self) -> usize {
+        self.count(|o| matches!(o, ImportRowOutcome::Invalid { .. }))
+    }
+
+    pub fn failed_count(&self) -> usize {
+        self.count(|o| matches!(o, ImportRowOutcome::Failed { .. }))
+    }
+}
@@ -0,0 +1,220 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+use super::RichText;
+
+/// Language the event's own title and description are in unless its
+/// organizer says otherwise.
+const DEFAULT_LOCALE: &str = "en";
+
+/// A language, optionally narrowed to a script and region, as a normalized
+/// BCP 47 tag such as `de`, `pt-BR` or `zh-Hant-TW`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Locale(String);
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("`{0}` is not a language tag like `de` or `pt-BR`")]
+pub struct LocaleError(pub String);
+
+impl Locale {
+    /// Accepts `_` as a separator and any letter case, so `pt_br` becomes
+    /// `pt-BR`. Tags with variants or extensions are not supported.
+    pub fn parse(tag: &str) -> Result<Self, LocaleError> {
+        let invalid = || LocaleError(tag.to_string());
+        let mut subtags = tag.trim().split(['-', '_']);
+
+        let language = subtags.next().ok_or_else(invalid)?;
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return Err(invalid());
+        }
+        let mut normalized = language.to_ascii_lowercase();
+
+        let mut next = subtags.next();
+        if let Some(script) =
+            next.filter(|s| s.len() == 4 && s.chars().all(|c| c.is_ascii_alphabetic()))
+        {
+            normalized.push('-');
+            normalized.push_str(&script[..1].to_ascii_uppercase());
+            normalized.push_str(&script[1..].to_ascii_lowercase());
+            next = subtags.next();
+        }
+        if let Some(region) = next {
+            let alpha = region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic());
+            let numeric = region.len() == 3 && region.chars().all(|c| c.is_ascii_digit());
+            if !(alpha || numeric) {
+                return Err(invalid());
+            }
+            normalized.push('-');
+            normalized.push_str(&region.to_ascii_uppercase());
+        }
+        if subtags.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(Self(normalized))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The primary language subtag, e.g. `pt` for `pt-BR`.
+    pub fn language(&self) -> &str {
+        self.0.split('-').next().unwrap_or(&self.0)
+    }
+
+    /// Picks the best of `available` for the client's language `ranges`,
+    /// most preferred first. For each range it tries the exact locale, then
+    /// the range with subtags dropped from the end (`de-AT` falls back to
+    /// `de`), then any locale of the same language. Ranges that are not
+    /// language tags are skipped; `None` when nothing matches.
+    pub fn negotiate<'a>(ranges: &[String], available: &'a [Locale]) -> Option<&'a Locale> {
+        ranges
+            .iter()
+            .filter_map(|range| Locale::parse(range).ok())
+            .find_map(|wanted| {
+                let mut candidate = wanted.0.as_str();
+                loop {
+                    if let Some(found) = available.iter().find(|locale| locale.0 == candidate) {
+                        return Some(found);
+                    }
+                    match candidate.rfind('-') {
+                        Some(end) => candidate = &candidate[..end],
+                        None => break,
+                    }
+                }
+                available
+                    .iter()
+                    .find(|locale| locale.language() == wanted.language())
+            })
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self(DEFAULT_LOCALE.to_string())
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for Locale {
+    type Error = LocaleError;
+
+    fn try_from(tag: String) -> Result<Self, Self::Error> {
+        Self::parse(&tag)
+    }
+}
+
+impl From<Locale> for String {
+    fn from(locale: Locale) -> Self {
+        locale.0
+    }
+}
+
+/// The event's title and description in a locale other than its default
+/// one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventTranslation {
+    event_id: Uuid,
+    locale: Locale,
+    title: String,
+    description: RichText,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl EventTranslation {
+    pub fn new(
+        event_id: Uuid,
+        locale: Locale,
+        title: String,
+        description: RichText,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            event_id,
+            locale,
+            title,
+            description,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Markdown source of the description, as entered.
+    pub fn description(&self) -> &str {
+        self.description.markdown()
+    }
+
+    pub fn description_html(&self) -> &str {
+        self.description.html()
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locales(tags: &[&str]) -> Vec<Locale> {
+        tags.iter().map(|tag| Locale::parse(tag).unwrap()).collect()
+    }
+
+    fn ranges(ranges: &[&str]) -> Vec<String> {
+        ranges.iter().map(|range| range.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_normalizes_tags() {
+        assert_eq!(Locale::parse("pt_br").unwrap().as_str(), "pt-BR");
+        assert_eq!(Locale::parse("ZH-hant-tw").unwrap().as_str(), "zh-Hant-TW");
+        assert_eq!(Locale::parse("es-419").unwrap().as_str(), "es-419");
+        assert_eq!(Locale::parse("pt-BR").unwrap().language(), "pt");
+        assert!(Locale::parse("english").is_err());
+        assert!(Locale::parse("de-DE-1996").is_err());
+        assert!(Locale::parse("").is_err());
+    }
+
+    #[test]
+    fn test_negotiate_falls_back_to_the_language() {
+        let available = locales(&["en", "de-CH", "fr", "pt-BR"]);
+        let pick =
+            |wanted: &[&str]| Locale::negotiate(&ranges(wanted), &available).map(Locale::as_str);
+
+        assert_eq!(pick(&["fr-CA", "en"]), Some("fr"));
+        assert_eq!(pick(&["de-AT", "en"]), Some("de-CH"));
+        assert_eq!(pick(&["pt"]), Some("pt-BR"));
+        assert_eq!(pick(&["x-klingon", "it", "en"]), Some("en"));
+        assert_eq!(pick(&["it"]), None);
+        assert_eq!(pick(&[]), None);
+    }
+}
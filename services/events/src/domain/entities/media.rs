@@ -0,0 +1,222 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaKind {
+    /// The event's main image; an event has at most one.
+    Poster,
+    /// Additional images and clips shown on the event page.
+    Gallery,
+}
+
+/// File formats accepted for event media.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MediaFormat {
+    Jpeg,
+    Png,
+    Webp,
+    Mp4,
+}
+
+const MAX_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+const MAX_VIDEO_BYTES: usize = 50 * 1024 * 1024;
+
+impl MediaFormat {
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let essence = content_type.split(';').next().unwrap_or_default().trim();
+        match essence.to_ascii_lowercase().as_str() {
+            "image/jpeg" => Some(Self::Jpeg),
+            "image/png" => Some(Self::Png),
+            "image/webp" => Some(Self::Webp),
+            "video/mp4" => Some(Self::Mp4),
+            _ => None,
+        }
+    }
+
+    /// Recognizes a format from the file's leading bytes, so a file's
+    /// declared type can be checked against its content.
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(Self::Jpeg)
+        } else if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(Self::Png)
+        } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+            Some(Self::Webp)
+        } else if bytes.len() >= 8 && &bytes[4..8] == b"ftyp" {
+            Some(Self::Mp4)
+        } else {
+            None
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::Webp => "image/webp",
+            Self::Mp4 => "video/mp4",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::Webp => "webp",
+            Self::Mp4 => "mp4",
+        }
+    }
+
+    pub fn is_image(&self) -> bool {
+        !matches!(self, Self::Mp4)
+    }
+
+    /// Largest accepted upload of this format.
+    pub fn max_bytes(&self) -> usize {
+        if self.is_image() {
+            MAX_IMAGE_BYTES
+        } else {
+            MAX_VIDEO_BYTES
+        }
+    }
+
+    /// Whether media of `kind` may have this format; posters must be images.
+    pub fn allowed_for(&self, kind: MediaKind) -> bool {
+        kind == MediaKind::Gallery || self.is_image()
+    }
+}
+
+/// An uploaded file attached to an event, with a thumbnail for images.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaAsset {
+    id: Uuid,
+    event_id: Uuid,
+    kind: MediaKind,
+    format: MediaFormat,
+    size_bytes: i64,
+    width: Option<i32>,
+    height: Option<i32>,
+    storage_key: String,
+    thumbnail_key: Option<String>,
+    url: String,
+    thumbnail_url: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl MediaAsset {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        event_id: Uuid,
+        kind: MediaKind,
+        format: MediaFormat,
+        size_bytes: i64,
+        width: Option<i32>,
+        height: Option<i32>,
+        storage_key: String,
+        thumbnail_key: Option<String>,
+        url: String,
+        thumbnail_url: Option<String>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            event_id,
+            kind,
+            format,
+            size_bytes,
+            width,
+            height,
+            storage_key,
+            thumbnail_key,
+            url,
+            thumbnail_url,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn kind(&self) -> MediaKind {
+        self.kind
+    }
+
+    pub fn format(&self) -> MediaFormat {
+        self.format
+    }
+
+    pub fn size_bytes(&self) -> i64 {
+        self.size_bytes
+    }
+
+    pub fn width(&self) -> Option<i32> {
+        self.width
+    }
+
+    pub fn height(&self) -> Option<i32> {
+        self.height
+    }
+
+    /// Object storage keys of the file and its thumbnail.
+    pub fn storage_keys(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.storage_key.as_str()).chain(self.thumbnail_key.as_deref())
+    }
+
+    pub fn storage_key(&self) -> &str {
+        &self.storage_key
+    }
+
+    pub fn thumbnail_key(&self) -> Option<&str> {
+        self.thumbnail_key.as_deref()
+    }
+
+    /// Public URL of the file, resolved when it was stored.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn thumbnail_url(&self) -> Option<&str> {
+        self.thumbnail_url.as_deref()
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sniff_and_content_type() {
+        assert_eq!(
+            MediaFormat::sniff(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"),
+            Some(MediaFormat::Png)
+        );
+        assert_eq!(
+            MediaFormat::sniff(b"RIFF\x24\0\0\0WEBPVP8 "),
+            Some(MediaFormat::Webp)
+        );
+        assert_eq!(
+            MediaFormat::sniff(b"\0\0\0\x20ftypisom"),
+            Some(MediaFormat::Mp4)
+        );
+        assert_eq!(MediaFormat::sniff(b"GIF89a"), None);
+
+        assert_eq!(
+            MediaFormat::from_content_type("Image/JPEG; charset=binary"),
+            Some(MediaFormat::Jpeg)
+        );
+        assert!(!MediaFormat::Mp4.allowed_for(MediaKind::Poster));
+        assert!(MediaFormat::Mp4.allowed_for(MediaKind::Gallery));
+    }
+}
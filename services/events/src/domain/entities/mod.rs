@@ -1,3 +1,54 @@
+pub mod administrators;
+pub mod calendar_feed;
+pub mod cancellation;
+pub mod category;
+pub mod domain_event;
 pub mod event;
+pub mod event_listing;
+pub mod event_series;
+pub mod event_template;
+pub mod geo;
+pub mod import_job;
+pub mod locale;
+pub mod media;
+pub mod occurrence;
+pub mod organization_role;
+pub mod publication;
+pub mod recurrence;
+pub mod revision;
+pub mod rich_text;
+pub mod schedule;
+pub mod seat_map;
+pub mod seating_chart;
+pub mod session;
+pub mod ticket_type;
+pub mod venue;
 
-pub use event::Event;
+pub use administrators::Administrators;
+pub use calendar_feed::CalendarFeed;
+pub use cancellation::{CancellationStep, EventCancellation, StepState};
+pub use category::{Category, CategoryFacet, Taxonomy};
+pub use domain_event::{DomainEvent, OutboxMessage};
+pub use event::{Event, EventStatus};
+pub use event_listing::{EventListing, ListingEntry};
+pub use event_series::EventSeries;
+pub use event_template::{
+    AdmissionBlueprint, EventBlueprint, EventTemplate, MediaBlueprint, SessionBlueprint,
+    TicketTypeBlueprint,
+};
+pub use geo::{GeoPoint, GeoPointError, GeoRadius};
+pub use import_job::{ImportJob, ImportJobStatus, ImportRowOutcome, ImportRowResult};
+pub use locale::{EventTranslation, Locale, LocaleError};
+pub use media::{MediaAsset, MediaFormat, MediaKind};
+pub use occurrence::{Occurrence, OccurrenceOverride};
+pub use organization_role::OrganizationRole;
+pub use publication::{Publication, PublicationError};
+pub use recurrence::{Recurrence, RecurrenceError};
+pub use revision::{EventRevision, EventSnapshot, FieldChange, RevisionAction};
+pub use rich_text::RichText;
+pub use schedule::{EventSchedule, ScheduleEnd, ScheduleError, ScheduleTime};
+pub use seat_map::{SeatMap, SeatStatus};
+pub use seating_chart::{Seat, SeatingChart, SeatingRow, SeatingSection};
+pub use session::{Admission, AdmissionError, AgendaDay, AgendaStage, EventSession};
+pub use ticket_type::{TicketType, TicketTypeVisibility};
+pub use venue::Venue;
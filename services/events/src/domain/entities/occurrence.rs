@@ -0,0 +1,169 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{EventSeries, RichText};
+
+/// Per-occurrence changes to a series. Unset fields fall back to the series.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OccurrenceOverride {
+    series_id: Uuid,
+    occurrence_start: DateTime<Utc>,
+    title: Option<String>,
+    description: Option<RichText>,
+    starts_at: Option<DateTime<Utc>>,
+    venue_name: Option<String>,
+    venue_id: Option<Uuid>,
+    updated_at: DateTime<Utc>,
+}
+
+impl OccurrenceOverride {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        series_id: Uuid,
+        occurrence_start: DateTime<Utc>,
+        title: Option<String>,
+        description: Option<RichText>,
+        starts_at: Option<DateTime<Utc>>,
+        venue_name: Option<String>,
+        venue_id: Option<Uuid>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            series_id,
+            occurrence_start,
+            title,
+            description,
+            starts_at,
+            venue_name,
+            venue_id,
+            updated_at,
+        }
+    }
+
+    pub fn series_id(&self) -> Uuid {
+        self.series_id
+    }
+
+    /// The original, rule-generated start that identifies the occurrence.
+    pub fn occurrence_start(&self) -> &DateTime<Utc> {
+        &self.occurrence_start
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_ref().map(RichText::markdown)
+    }
+
+    pub fn description_html(&self) -> Option<&str> {
+        self.description.as_ref().map(RichText::html)
+    }
+
+    pub fn starts_at(&self) -> Option<&DateTime<Utc>> {
+        self.starts_at.as_ref()
+    }
+
+    pub fn venue_name(&self) -> Option<&str> {
+        self.venue_name.as_deref()
+    }
+
+    pub fn venue_id(&self) -> Option<Uuid> {
+        self.venue_id
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+/// One concrete date of an event series, with any override applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Occurrence {
+    series_id: Uuid,
+    original_start: DateTime<Utc>,
+    starts_at: DateTime<Utc>,
+    timezone: Tz,
+    title: String,
+    description: RichText,
+    venue_name: String,
+    venue_id: Option<Uuid>,
+    overridden: bool,
+}
+
+impl Occurrence {
+    pub fn new(
+        series: &EventSeries,
+        original_start: DateTime<Utc>,
+        override_: Option<&OccurrenceOverride>,
+    ) -> Self {
+        Self {
+            series_id: series.id(),
+            original_start,
+            starts_at: override_
+                .and_then(|o| o.starts_at().copied())
+                .unwrap_or(original_start),
+            timezone: series.timezone(),
+            title: override_
+                .and_then(|o| o.title())
+                .unwrap_or(series.title())
+                .to_string(),
+            description: match override_.and_then(|o| o.description.clone()) {
+                Some(description) => description,
+                None => RichText::from_parts(
+                    series.description().to_string(),
+                    series.description_html().to_string(),
+                ),
+            },
+            venue_name: override_
+                .and_then(|o| o.venue_name())
+                .unwrap_or(series.venue_name())
+                .to_string(),
+            venue_id: override_.and_then(|o| o.venue_id()).or(series.venue_id()),
+            overridden: override_.is_some(),
+        }
+    }
+
+    pub fn series_id(&self) -> Uuid {
+        self.series_id
+    }
+
+    pub fn original_start(&self) -> &DateTime<Utc> {
+        &self.original_start
+    }
+
+    pub fn starts_at(&self) -> &DateTime<Utc> {
+        &self.starts_at
+    }
+
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn description(&self) -> &str {
+        self.description.markdown()
+    }
+
+    pub fn description_html(&self) -> &str {
+        self.description.html()
+    }
+
+    pub fn venue_name(&self) -> &str {
+        &self.venue_name
+    }
+
+    pub fn venue_id(&self) -> Option<Uuid> {
+        self.venue_id
+    }
+
+    pub fn overridden(&self) -> bool {
+        self.overridden
+    }
+}
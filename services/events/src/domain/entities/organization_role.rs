@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// A user's role in an organization, as granted in the users service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrganizationRole {
+    Owner,
+    Manager,
+    BoxOffice,
+    Viewer,
+}
+
+impl OrganizationRole {
+    /// Owners and managers create and change the organization's events; box
+    /// office staff and viewers only see them.
+    pub fn can_manage_events(&self) -> bool {
+        matches!(self, Self::Owner | Self::Manager)
+    }
+}
@@ -0,0 +1,211 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum PublicationError {
+    #[error("publish_at must be in the future; omit it to publish right away")]
+    PublishAtInPast,
+    #[error("on_sale_at must be in the future; omit it to open sales on publication")]
+    OnSaleAtInPast,
+    #[error("publish_at must be before the event starts")]
+    PublishAfterStart,
+    #[error("on_sale_at must be before the event starts")]
+    OnSaleAfterStart,
+    #[error("Ticket sales cannot open before the event is published")]
+    OnSaleBeforePublish,
+    #[error("Event is already published")]
+    AlreadyPublished,
+    #[error("Ticket sales are already open")]
+    SalesAlreadyOpen,
+}
+
+/// When an event is announced and its tickets go on sale, and when either
+/// actually happened. Unpublished events are only visible to the people who
+/// manage them; the scheduler publishes them and opens their sales once due.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Publication {
+    publish_at: Option<DateTime<Utc>>,
+    on_sale_at: Option<DateTime<Utc>>,
+    published_at: Option<DateTime<Utc>>,
+    sales_opened_at: Option<DateTime<Utc>>,
+}
+
+impl Publication {
+    /// Builds a publication from stored state.
+    pub fn new(
+        publish_at: Option<DateTime<Utc>>,
+        on_sale_at: Option<DateTime<Utc>>,
+        published_at: Option<DateTime<Utc>>,
+        sales_opened_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            publish_at,
+            on_sale_at,
+            published_at,
+            sales_opened_at,
+        }
+    }
+
+    /// Publication of a new event. Without `publish_at` the event is
+    /// published at `now`; without `on_sale_at` sales open on publication.
+    pub fn schedule(
+        publish_at: Option<DateTime<Utc>>,
+        on_sale_at: Option<DateTime<Utc>>,
+        starts_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, PublicationError> {
+        if publish_at.is_some_and(|at| at <= now) {
+            return Err(PublicationError::PublishAtInPast);
+        }
+        if on_sale_at.is_some_and(|at| at <= now) {
+            return Err(PublicationError::OnSaleAtInPast);
+        }
+
+        let published_at = publish_at.is_none().then_some(now);
+        let publication = Self {
+            publish_at,
+            on_sale_at,
+            published_at,
+            sales_opened_at: published_at.filter(|_| on_sale_at.is_none()),
+        };
+        publication.check(starts_at)?;
+        Ok(publication)
+    }
+
+    /// Moves the times of an event that hasn't reached them yet; `None`
+    /// keeps the current time. Also checks the times still fit an event
+    /// starting at `starts_at`.
+    pub fn reschedule(
+        &self,
+        publish_at: Option<DateTime<Utc>>,
+        on_sale_at: Option<DateTime<Utc>>,
+        starts_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, PublicationError> {
+        if publish_at.is_some() && self.is_published() {
+            return Err(PublicationError::AlreadyPublished);
+        }
+        if on_sale_at.is_some() && self.is_on_sale() {
+            return Err(PublicationError::SalesAlreadyOpen);
+        }
+        if publish_at.is_some_and(|at| at <= now) {
+            return Err(PublicationError::PublishAtInPast);
+        }
+        if on_sale_at.is_some_and(|at| at <= now) {
+            return Err(PublicationError::OnSaleAtInPast);
+        }
+
+        let publication = Self {
+            publish_at: publish_at.or(self.publish_at),
+            on_sale_at: on_sale_at.or(self.on_sale_at),
+            ..*self
+        };
+        publication.check(starts_at)?;
+        Ok(publication)
+    }
+
+    /// Checks the times that are still pending against each other and the
+    /// event's start.
+    fn check(&self, starts_at: DateTime<Utc>) -> Result<(), PublicationError> {
+        if !self.is_published() && self.publish_at.is_some_and(|at| at >= starts_at) {
+            return Err(PublicationError::PublishAfterStart);
+        }
+        if !self.is_on_sale() {
+            if self.on_sale_at.is_some_and(|at| at >= starts_at) {
+                return Err(PublicationError::OnSaleAfterStart);
+            }
+            let publishes_at = self.published_at.or(self.publish_at);
+            if let (Some(on_sale_at), Some(publishes_at)) = (self.on_sale_at, publishes_at) {
+                if on_sale_at < publishes_at {
+                    return Err(PublicationError::OnSaleBeforePublish);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// When the event is to be published; `None` when it was published on
+    /// creation.
+    pub fn publish_at(&self) -> Option<&DateTime<Utc>> {
+        self.publish_at.as_ref()
+    }
+
+    /// When ticket sales are to open; `None` opens them on publication.
+    pub fn on_sale_at(&self) -> Option<&DateTime<Utc>> {
+        self.on_sale_at.as_ref()
+    }
+
+    pub fn published_at(&self) -> Option<&DateTime<Utc>> {
+        self.published_at.as_ref()
+    }
+
+    pub fn sales_opened_at(&self) -> Option<&DateTime<Utc>> {
+        self.sales_opened_at.as_ref()
+    }
+
+    pub fn is_published(&self) -> bool {
+        self.published_at.is_some()
+    }
+
+    pub fn is_on_sale(&self) -> bool {
+        self.sales_opened_at.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_schedule_and_reschedule() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap();
+        let starts_at = now + Duration::days(30);
+        let friday = now + Duration::days(3);
+
+        let immediate = Publication::schedule(None, None, starts_at, now).unwrap();
+        assert_eq!(immediate.published_at(), Some(&now));
+        assert_eq!(immediate.sales_opened_at(), Some(&now));
+
+        let announced = Publication::schedule(None, Some(friday), starts_at, now).unwrap();
+        assert!(announced.is_published() && !announced.is_on_sale());
+
+        let pending = Publication::schedule(Some(friday), None, starts_at, now).unwrap();
+        assert!(!pending.is_published() && !pending.is_on_sale());
+
+        assert_eq!(
+            Publication::schedule(Some(now), None, starts_at, now),
+            Err(PublicationError::PublishAtInPast)
+        );
+        assert_eq!(
+            Publication::schedule(
+                Some(friday),
+                Some(friday - Duration::hours(1)),
+                starts_at,
+                now
+            ),
+            Err(PublicationError::OnSaleBeforePublish)
+        );
+        assert_eq!(
+            Publication::schedule(None, Some(starts_at), starts_at, now),
+            Err(PublicationError::OnSaleAfterStart)
+        );
+
+        assert_eq!(
+            immediate.reschedule(Some(friday), None, starts_at, now),
+            Err(PublicationError::AlreadyPublished)
+        );
+        assert_eq!(
+            announced.reschedule(None, Some(friday + Duration::days(1)), starts_at, now),
+            Ok(Publication {
+                on_sale_at: Some(friday + Duration::days(1)),
+                ..announced
+            })
+        );
+        // Moving the event before a pending publication is caught too
+        assert_eq!(
+            pending.reschedule(None, None, friday, now),
+            Err(PublicationError::PublishAfterStart)
+        );
+    }
+}
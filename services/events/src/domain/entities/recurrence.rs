@@ -0,0 +1,207 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use rrule::{Frequency, RRuleSet, Tz as RRuleTz};
+
+/// Upper bound on occurrences generated when looking for the end of a bounded
+/// rule, so that a COUNT typo cannot stall a request.
+const MAX_BOUNDED_OCCURRENCES: u16 = 5000;
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum RecurrenceError {
+    #[error("Invalid recurrence rule: {0}")]
+    InvalidRule(String),
+    #[error("Recurrence rules may repeat at most daily")]
+    FrequencyTooHigh,
+    #[error("Recurrence rule must contain only an RRULE value, without DTSTART")]
+    UnexpectedDtStart,
+}
+
+/// An RFC 5545 recurrence (DTSTART + RRULE + EXDATE). The rule is expanded
+/// in the series' timezone, so a weekly 8pm show stays at 8pm local time
+/// across daylight saving transitions; occurrences are reported in UTC.
+///
+/// Occurrences are produced lazily, so unbounded rules are fine as long as
+/// callers always ask for a window.
+#[derive(Debug, Clone)]
+pub struct Recurrence {
+    set: RRuleSet,
+}
+
+fn to_rrule_tz(dt: &DateTime<Utc>, timezone: Tz) -> DateTime<RRuleTz> {
+    dt.with_timezone(&RRuleTz::Tz(timezone))
+}
+
+fn format_dt_start(dt_start: &DateTime<Utc>, timezone: Tz) -> String {
+    if timezone == Tz::UTC {
+        return format!("DTSTART:{}", dt_start.format("%Y%m%dT%H%M%SZ"));
+    }
+    format!(
+        "DTSTART;TZID={}:{}",
+        timezone.name(),
+        dt_start.with_timezone(&timezone).format("%Y%m%dT%H%M%S")
+    )
+}
+
+impl Recurrence {
+    /// Parses `rule` (with or without the `RRULE:` prefix) starting at
+    /// `dt_start`, expanding it in `timezone`.
+    pub fn parse(
+        dt_start: DateTime<Utc>,
+        timezone: Tz,
+        rule: &str,
+        exdates: &[DateTime<Utc>],
+    ) -> Result<Self, RecurrenceError> {
+        let rule = rule.trim();
+        let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+        if rule.contains("DTSTART") || rule.contains('\n') {
+            return Err(RecurrenceError::UnexpectedDtStart);
+        }
+
+        let source = format!("{}\nRRULE:{}", format_dt_start(&dt_start, timezone), rule);
+        let set: RRuleSet = source
+            .parse()
+            .map_err(|e: rrule::RRuleError| RecurrenceError::InvalidRule(e.to_string()))?;
+
+        if set
+            .get_rrule()
+            .iter()
+            .any(|rrule| rrule.get_freq() > Frequency::Daily)
+        {
+            return Err(RecurrenceError::FrequencyTooHigh);
+        }
+
+        let set = set.set_exdates(exdates.iter().map(|dt| to_rrule_tz(dt, timezone)).collect());
+
+        Ok(Self { set })
+    }
+
+    /// Whether the rule ends through COUNT or UNTIL.
+    pub fn is_bounded(&self) -> bool {
+        self.set
+            .get_rrule()
+            .iter()
+            .all(|rrule| rrule.get_count().is_some() || rrule.get_until().is_some())
+    }
+
+    /// Start of the final occurrence for bounded rules, `None` for open-ended ones.
+    pub fn last_start(&self) -> Option<DateTime<Utc>> {
+        if !self.is_bounded() {
+            return None;
+        }
+        self.set
+            .clone()
+            .all(MAX_BOUNDED_OCCURRENCES)
+            .dates
+            .last()
+            .map(|dt| dt.with_timezone(&Utc))
+    }
+
+    /// Occurrence starts in `[from, to)`, in chronological order.
+    pub fn starts_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> impl Iterator<Item = DateTime<Utc>> + '_ {
+        self.set
+            .into_iter()
+            .map(|dt| dt.with_timezone(&Utc))
+            .skip_while(move |dt| *dt < from)
+            .take_while(move |dt| *dt < to)
+    }
+
+    /// Whether `start` is one of the rule's (non-excluded) occurrences.
+    pub fn contains(&self, start: DateTime<Utc>) -> bool {
+        self.starts_between(start, start + chrono::Duration::seconds(1))
+            .next()
+            .is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_weekly_rule_expands_within_window() {
+        let recurrence =
+            Recurrence::parse(at(2030, 1, 3, 20), Tz::UTC, "FREQ=WEEKLY;BYDAY=TH", &[]).unwrap();
+
+        let starts: Vec<_> = recurrence
+            .starts_between(at(2030, 1, 10, 0), at(2030, 1, 25, 0))
+            .collect();
+
+        assert_eq!(
+            starts,
+            vec![
+                at(2030, 1, 10, 20),
+                at(2030, 1, 17, 20),
+                at(2030, 1, 24, 20)
+            ]
+        );
+        assert!(!recurrence.is_bounded());
+        assert_eq!(recurrence.last_start(), None);
+    }
+
+    #[test]
+    fn test_exdates_are_skipped() {
+        let recurrence = Recurrence::parse(
+            at(2030, 1, 3, 20),
+            Tz::UTC,
+            "RRULE:FREQ=WEEKLY;COUNT=3",
+            &[at(2030, 1, 10, 20)],
+        )
+        .unwrap();
+
+        let starts: Vec<_> = recurrence
+            .starts_between(at(2030, 1, 1, 0), at(2031, 1, 1, 0))
+            .collect();
+
+        assert_eq!(starts, vec![at(2030, 1, 3, 20), at(2030, 1, 17, 20)]);
+        assert!(!recurrence.contains(at(2030, 1, 10, 20)));
+        assert!(recurrence.contains(at(2030, 1, 17, 20)));
+        assert_eq!(recurrence.last_start(), Some(at(2030, 1, 17, 20)));
+    }
+
+    #[test]
+    fn test_local_time_is_kept_across_daylight_saving() {
+        // 8pm in Chicago is 01:00Z under CDT and 02:00Z under CST
+        let recurrence = Recurrence::parse(
+            at(2026, 10, 23, 1),
+            chrono_tz::America::Chicago,
+            "FREQ=WEEKLY;COUNT=3",
+            &[],
+        )
+        .unwrap();
+
+        let starts: Vec<_> = recurrence
+            .starts_between(at(2026, 1, 1, 0), at(2027, 1, 1, 0))
+            .collect();
+
+        assert_eq!(
+            starts,
+            vec![at(2026, 10, 23, 1), at(2026, 10, 30, 1), at(2026, 11, 6, 2)]
+        );
+    }
+
+    #[test]
+    fn test_rejects_sub_daily_and_malformed_rules() {
+        assert_eq!(
+            Recurrence::parse(at(2030, 1, 1, 0), Tz::UTC, "FREQ=HOURLY", &[]).unwrap_err(),
+            RecurrenceError::FrequencyTooHigh
+        );
+        assert!(matches!(
+            Recurrence::parse(at(2030, 1, 1, 0), Tz::UTC, "FREQ=SOMETIMES", &[]),
+            Err(RecurrenceError::InvalidRule(_))
+        ));
+        assert_eq!(
+            Recurrence::parse(at(2030, 1, 1, 0), Tz::UTC, "DTSTART:20300101T000000Z", &[])
+                .unwrap_err(),
+            RecurrenceError::UnexpectedDtStart
+        );
+    }
+}
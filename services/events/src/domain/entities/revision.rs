@@ -0,0 +1,285 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::{Event, EventStatus, Locale};
+
+/// The fields of an event that revisions track, as they were at one point.
+///
+/// Only the event's own fields are tracked. Ticket types, sessions, media
+/// and translations change without a revision: revision numbers follow the
+/// event's iCalendar `SEQUENCE`, which subscribers treat as a reschedule and
+/// must not move for a price change or a new poster.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventSnapshot {
+    pub title: String,
+    /// Markdown source.
+    pub description: String,
+    /// Absent from revisions recorded before localized content, when every
+    /// event was in the default locale.
+    #[serde(default)]
+    pub default_locale: Locale,
+    pub starts_at: DateTime<Utc>,
+    pub timezone: Tz,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub doors_open_at: Option<DateTime<Utc>>,
+    pub venue_name: String,
+    pub venue_id: Option<Uuid>,
+    pub organizer_id: Option<Uuid>,
+    pub organization_id: Option<Uuid>,
+    pub status: EventStatus,
+    /// Absent from revisions recorded before scheduled publishing.
+    #[serde(default)]
+    pub publish_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub on_sale_at: Option<DateTime<Utc>>,
+    pub category_ids: Vec<Uuid>,
+    pub tags: Vec<String>,
+}
+
+impl From<&Event> for EventSnapshot {
+    fn from(event: &Event) -> Self {
+        let schedule = event.schedule();
+        let mut category_ids = event.category_ids().to_vec();
+        category_ids.sort();
+        Self {
+            title: event.title().to_string(),
+            description: event.description().to_string(),
+            default_locale: event.default_locale().clone(),
+            starts_at: *schedule.starts_at(),
+            timezone: schedule.timezone(),
+            ends_at: schedule.ends_at().copied(),
+            doors_open_at: schedule.doors_open_at().copied(),
+            venue_name: event.venue_name().to_string(),
+            venue_id: event.venue_id(),
+            organizer_id: event.organizer_id(),
+            organization_id: event.organization_id(),
+            status: event.status(),
+            publish_at: event.publication().publish_at().copied(),
+            on_sale_at: event.publication().on_sale_at().copied(),
+            category_ids,
+            tags: event.tags().to_vec(),
+        }
+    }
+}
+
+impl EventSnapshot {
+    fn fields(&self) -> serde_json::Map<String, Value> {
+        match serde_json::to_value(self) {
+            Ok(Value::Object(fields)) => fields,
+            _ => unreachable!("snapshots serialize to JSON objects"),
+        }
+    }
+
+    /// Fields that differ from `before`, by field name. Without a
+    /// previous state every field counts as set from `null`.
+    pub fn changes_since(&self, before: Option<&EventSnapshot>) -> Vec<FieldChange> {
+        let before = before.map(Self::fields).unwrap_or_default();
+        self.fields()
+            .into_iter()
+            .filter_map(|(field, to)| {
+                let from = before.get(&field).cloned().unwrap_or(Value::Null);
+                (from != to).then_some(FieldChange { field, from, to })
+            })
+            .collect()
+    }
+}
+
+/// One field's value before and after a revision, as JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub from: Value,
+    pub to: Value,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionAction {
+    Created,
+    Updated,
+    Cancelled,
+}
+
+/// An immutable record of one change to an event. Revision numbers follow
+/// the event's sequence, so revision 0 is the event as created.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventRevision {
+    event_id: Uuid,
+    revision: i32,
+    action: RevisionAction,
+    /// User who made the change; `None` for changes made outside the API,
+    /// such as command-line imports.
+    actor_id: Option<Uuid>,
+    changes: Vec<FieldChange>,
+    snapshot: EventSnapshot,
+    created_at: DateTime<Utc>,
+}
+
+impl EventRevision {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        event_id: Uuid,
+        revision: i32,
+        action: RevisionAction,
+        actor_id: Option<Uuid>,
+        changes: Vec<FieldChange>,
+        snapshot: EventSnapshot,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            event_id,
+            revision,
+            action,
+            actor_id,
+            changes,
+            snapshot,
+            created_at,
+        }
+    }
+
+    /// Records `after` as the next revision, diffing it against `before`.
+    pub fn record(
+        before: Option<&Event>,
+        after: &Event,
+        action: RevisionAction,
+        actor_id: Option<Uuid>,
+    ) -> Self {
+        let snapshot = EventSnapshot::from(after);
+        let changes = snapshot.changes_since(before.map(EventSnapshot::from).as_ref());
+        Self::new(
+            after.id(),
+            after.sequence(),
+            action,
+            actor_id,
+            changes,
+            snapshot,
+            *after.updated_at(),
+        )
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn revision(&self) -> i32 {
+        self.revision
+    }
+
+    pub fn action(&self) -> RevisionAction {
+        self.action
+    }
+
+    pub fn actor_id(&self) -> Option<Uuid> {
+        self.actor_id
+    }
+
+    pub fn changes(&self) -> &[FieldChange] {
+        &self.changes
+    }
+
+    /// The event as it was right after this revision.
+    pub fn snapshot(&self) -> &EventSnapshot {
+        &self.snapshot
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{EventSession, EventTranslation, Locale, RichText};
+    use crate::domain::fixtures::EventFixture;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn event(venue_name: &str, at: DateTime<Utc>, status: EventStatus) -> Event {
+        EventFixture {
+            id: Uuid::nil(),
+            venue_name,
+            status,
+            ..EventFixture::at(at)
+        }
+        .build()
+    }
+
+    #[test]
+    fn test_revision_records_changed_fields_only() {
+        let at = Utc.with_ymd_and_hms(2026, 11, 5, 20, 0, 0).unwrap();
+        let before = event("Cellar", at, EventStatus::Scheduled);
+        let after = event(
+            "Rooftop",
+            at + chrono::Duration::days(1),
+            EventStatus::Scheduled,
+        );
+
+        let revision = EventRevision::record(
+            Some(&before),
+            &after,
+            RevisionAction::Updated,
+            Some(Uuid::nil()),
+        );
+
+        assert_eq!(
+            revision.changes(),
+            [
+                FieldChange {
+                    field: "starts_at".to_string(),
+                    from: json!("2026-11-05T20:00:00Z"),
+                    to: json!("2026-11-06T20:00:00Z"),
+                },
+                FieldChange {
+                    field: "venue_name".to_string(),
+                    from: json!("Cellar"),
+                    to: json!("Rooftop"),
+                },
+            ]
+        );
+        assert_eq!(revision.snapshot().venue_name, "Rooftop");
+
+        let created = EventRevision::record(None, &before, RevisionAction::Created, None);
+        assert!(created
+            .changes()
+            .iter()
+            .any(|change| change.field == "status" && change.to == json!("scheduled")));
+        assert!(created
+            .changes()
+            .iter()
+            .all(|change| change.to != Value::Null));
+    }
+
+    #[test]
+    fn test_snapshot_leaves_out_the_events_parts() {
+        let at = Utc.with_ymd_and_hms(2026, 11, 5, 20, 0, 0).unwrap();
+        let bare = event("Cellar", at, EventStatus::Scheduled);
+        let session = EventSession::new(
+            Uuid::new_v4(),
+            bare.id(),
+            "Opening set".to_string(),
+            None,
+            at,
+            at + chrono::Duration::hours(1),
+            None,
+            at,
+            at,
+        );
+        let translation = EventTranslation::new(
+            bare.id(),
+            Locale::parse("de").unwrap(),
+            "Jazzabend".to_string(),
+            RichText::from_markdown("Live-Jazz"),
+            at,
+            at,
+        );
+        let full = event("Cellar", at, EventStatus::Scheduled)
+            .with_sessions(vec![session])
+            .with_translations(vec![translation]);
+
+        assert_eq!(EventSnapshot::from(&full), EventSnapshot::from(&bare));
+    }
+}
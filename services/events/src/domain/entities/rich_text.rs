@@ -0,0 +1,113 @@
+use ammonia::{Builder, UrlRelative};
+use pulldown_cmark::{html, Options, Parser};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+/// Tags that survive sanitization; everything else is stripped, keeping its
+/// text content. Images are left out so event pages cannot embed trackers.
+const ALLOWED_TAGS: &[&str] = &[
+    "a",
+    "blockquote",
+    "br",
+    "code",
+    "del",
+    "em",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "strong",
+    "table",
+    "tbody",
+    "td",
+    "th",
+    "thead",
+    "tr",
+    "ul",
+];
+
+static SANITIZER: LazyLock<Builder<'static>> = LazyLock::new(|| {
+    let mut builder = Builder::empty();
+    builder
+        .add_tags(ALLOWED_TAGS)
+        .add_tag_attributes("a", &["href", "title"])
+        .url_schemes(HashSet::from(["http", "https", "mailto"]))
+        .url_relative(UrlRelative::Deny)
+        .link_rel(Some("noopener noreferrer nofollow"));
+    builder
+});
+
+/// Organizer-written text: the Markdown source as entered, and the HTML it
+/// renders to with anything unsafe removed. Raw HTML in the source is
+/// accepted and sanitized like the rest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RichText {
+    markdown: String,
+    html: String,
+}
+
+impl RichText {
+    pub fn from_markdown(markdown: &str) -> Self {
+        let parser = Parser::new_ext(
+            markdown,
+            Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TABLES,
+        );
+        let mut rendered = String::with_capacity(markdown.len() * 3 / 2);
+        html::push_html(&mut rendered, parser);
+
+        Self {
+            markdown: markdown.to_string(),
+            html: SANITIZER.clean(&rendered).to_string(),
+        }
+    }
+
+    /// Restores text rendered earlier, without rendering it again.
+    pub fn from_parts(markdown: String, html: String) -> Self {
+        Self { markdown, html }
+    }
+
+    pub fn markdown(&self) -> &str {
+        &self.markdown
+    }
+
+    /// Sanitized HTML, safe to embed in a page.
+    pub fn html(&self) -> &str {
+        &self.html
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_renders_markdown_and_strips_unsafe_html() {
+        let text = RichText::from_markdown(
+            "**Doors** at [7pm](https://example.com/doors)\n\n\
+             <script>alert(1)</script><img src=x onerror=alert(2)>\n\n\
+             [click](javascript:alert(3)) <b onclick=\"x()\">bold</b>",
+        );
+        let html = text.html();
+
+        assert!(html.contains("<strong>Doors</strong>"));
+        assert!(html.contains(
+            "<a href=\"https://example.com/doors\" rel=\"noopener noreferrer nofollow\">7pm</a>"
+        ));
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("alert(1)"));
+        assert!(!html.contains("<img"));
+        assert!(!html.contains("onerror"));
+        assert!(!html.contains("href=\"javascript"));
+        assert!(html.contains("<a rel=\"noopener noreferrer nofollow\">click</a> bold"));
+        assert!(!html.contains("onclick"));
+        assert!(text.markdown().starts_with("**Doors**"));
+    }
+}
@@ -0,0 +1,323 @@
+use chrono::{DateTime, Duration, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+
+/// A time as given by a client: an absolute instant, or a wall-clock time in
+/// the event's timezone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScheduleTime {
+    Instant(DateTime<Utc>),
+    Local(NaiveDateTime),
+}
+
+/// How the end of an event is given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScheduleEnd {
+    At(ScheduleTime),
+    After(Duration),
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ScheduleError {
+    #[error("{0} does not exist in {1} (clocks skip it for daylight saving time)")]
+    NonexistentLocalTime(NaiveDateTime, Tz),
+    #[error("Event must end after it starts")]
+    EndsBeforeStart,
+    #[error("Doors must open before the event starts")]
+    DoorsOpenAfterStart,
+}
+
+/// When an event happens, stored as UTC instants together with the IANA
+/// timezone the event is held in, so it can be shown in local time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventSchedule {
+    timezone: Tz,
+    starts_at: DateTime<Utc>,
+    ends_at: Option<DateTime<Utc>>,
+    doors_open_at: Option<DateTime<Utc>>,
+}
+
+/// Converts `time` to UTC. Wall-clock times repeated when clocks fall back
+/// resolve to the first occurrence, as RFC 5545 does; times skipped when
+/// clocks spring forward are rejected.
+fn to_utc(timezone: Tz, time: ScheduleTime) -> Result<DateTime<Utc>, ScheduleError> {
+    match time {
+        ScheduleTime::Instant(instant) => Ok(instant),
+        ScheduleTime::Local(local) => match timezone.from_local_datetime(&local) {
+            LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => Ok(dt.with_timezone(&Utc)),
+            LocalResult::None => Err(ScheduleError::NonexistentLocalTime(local, timezone)),
+        },
+    }
+}
+
+impl EventSchedule {
+    /// Builds a schedule from already-resolved instants, e.g. when loading
+    /// from storage.
+    pub fn new(
+        timezone: Tz,
+        starts_at: DateTime<Utc>,
+        ends_at: Option<DateTime<Utc>>,
+        doors_open_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            timezone,
+            starts_at,
+            ends_at,
+            doors_open_at,
+        }
+    }
+
+    /// Resolves client-given times in `timezone` and checks they are in order.
+    pub fn resolve(
+        timezone: Tz,
+        starts_at: ScheduleTime,
+        end: Option<ScheduleEnd>,
+        doors_open_at: Option<ScheduleTime>,
+    ) -> Result<Self, ScheduleError> {
+        let starts_at = to_utc(timezone, starts_at)?;
+        let ends_at = match end {
+            Some(ScheduleEnd::At(time)) => Some(to_utc(timezone, time)?),
+            Some(ScheduleEnd::After(duration)) => Some(starts_at + duration),
+            None => None,
+        };
+        let doors_open_at = doors_open_at
+            .map(|time| to_utc(timezone, time))
+            .transpose()?;
+
+        Self::new(timezone, starts_at, ends_at, doors_open_at).validated()
+    }
+
+    /// Applies a partial change. When the start moves and no new end or
+    /// doors-open time is given, both keep their offset from the start; a
+    /// new timezone re-reads wall-clock times but leaves instants alone.
+    pub fn reschedule(
+        &self,
+        timezone: Option<Tz>,
+        starts_at: Option<ScheduleTime>,
+        end: Option<ScheduleEnd>,
+        doors_open_at: Option<ScheduleTime>,
+    ) -> Result<Self, ScheduleError> {
+        let timezone = timezone.unwrap_or(self.timezone);
+        let new_start = starts_at
+            .map(|time| to_utc(timezone, time))
+            .transpose()?
+            .unwrap_or(self.starts_at);
+        let shift = new_start - self.starts_at;
+
+        let ends_at = match end {
+            Some(ScheduleEnd::At(time)) => Some(to_utc(timezone, time)?),
+            Some(ScheduleEnd::After(duration)) => Some(new_start + duration),
+            None => self.ends_at.map(|ends_at| ends_at + shift),
+        };
+        let doors_open_at = match doors_open_at {
+            Some(time) => Some(to_utc(timezone, time)?),
+            None => self.doors_open_at.map(|doors| doors + shift),
+        };
+
+        Self::new(timezone, new_start, ends_at, doors_open_at).validated()
+    }
+
+    fn validated(self) -> Result<Self, ScheduleError> {
+        if self
+            .ends_at
+            .is_some_and(|ends_at| ends_at <= self.starts_at)
+        {
+            return Err(ScheduleError::EndsBeforeStart);
+        }
+        if self
+            .doors_open_at
+            .is_some_and(|doors| doors > self.starts_at)
+        {
+            return Err(ScheduleError::DoorsOpenAfterStart);
+        }
+        Ok(self)
+    }
+
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    pub fn starts_at(&self) -> &DateTime<Utc> {
+        &self.starts_at
+    }
+
+    pub fn ends_at(&self) -> Option<&DateTime<Utc>> {
+        self.ends_at.as_ref()
+    }
+
+    pub fn doors_open_at(&self) -> Option<&DateTime<Utc>> {
+        self.doors_open_at.as_ref()
+    }
+
+    pub fn duration(&self) -> Option<Duration> {
+        self.ends_at.map(|ends_at| ends_at - self.starts_at)
+    }
+
+    /// `instant` as wall-clock time in the event's timezone.
+    pub fn local(&self, instant: &DateTime<Utc>) -> DateTime<Tz> {
+        instant.with_timezone(&self.timezone)
+    }
+
+    /// Converts a client-given time, e.g. a session start, to UTC in the
+    /// event's timezone.
+    pub fn resolve_time(&self, time: ScheduleTime) -> Result<DateTime<Utc>, ScheduleError> {
+        to_utc(self.timezone, time)
+    }
+
+    /// Whether `[starts_at, ends_at)` lies within the event. Without an end
+    /// the event runs on indefinitely.
+    pub fn covers(&self, starts_at: &DateTime<Utc>, ends_at: &DateTime<Utc>) -> bool {
+        *starts_at >= self.starts_at && self.ends_at.is_none_or(|end| *ends_at <= end)
+    }
+
+    /// Local dates the event runs on; an event ending at midnight does not
+    /// run on the following day.
+    pub fn local_days(&self) -> RangeInclusive<NaiveDate> {
+        let first = self.local(&self.starts_at).date_naive();
+        let last = self
+            .ends_at
+            .map(|end| self.local(&(end - Duration::nanoseconds(1))).date_naive())
+            .unwrap_or(first);
+        first..=last.max(first)
+    }
+
+    /// Start of `date` in the event's timezone, but no earlier than the
+    /// event itself.
+    pub fn day_starts_at(&self, date: NaiveDate) -> DateTime<Utc> {
+        self.timezone
+            .from_local_datetime(&date.and_time(chrono::NaiveTime::MIN))
+            .earliest()
+            .map(|midnight| midnight.with_timezone(&Utc))
+            .unwrap_or(self.starts_at)
+            .max(self.starts_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use chrono_tz::America::Chicago;
+
+    fn local(m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    fn utc(m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_resolves_wall_clock_times_in_the_event_timezone() {
+        let schedule = EventSchedule::resolve(
+            Chicago,
+            ScheduleTime::Local(local(7, 15, 19, 0)),
+            Some(ScheduleEnd::After(Duration::minutes(150))),
+            Some(ScheduleTime::Local(local(7, 15, 18, 0))),
+        )
+        .unwrap();
+
+        // Chicago is UTC-5 in summer
+        assert_eq!(*schedule.starts_at(), utc(7, 16, 0, 0));
+        assert_eq!(schedule.ends_at(), Some(&utc(7, 16, 2, 30)));
+        assert_eq!(schedule.doors_open_at(), Some(&utc(7, 15, 23, 0)));
+        assert_eq!(
+            schedule.local(schedule.starts_at()).naive_local(),
+            local(7, 15, 19, 0)
+        );
+    }
+
+    #[test]
+    fn test_daylight_saving_transitions() {
+        // 2:30am does not exist on the spring-forward date
+        assert_eq!(
+            EventSchedule::resolve(Chicago, ScheduleTime::Local(local(3, 8, 2, 30)), None, None)
+                .unwrap_err(),
+            ScheduleError::NonexistentLocalTime(local(3, 8, 2, 30), Chicago)
+        );
+
+        // 1:30am happens twice on the fall-back date; the first (CDT) wins
+        let schedule = EventSchedule::resolve(
+            Chicago,
+            ScheduleTime::Local(local(11, 1, 1, 30)),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(*schedule.starts_at(), utc(11, 1, 6, 30));
+    }
+
+    #[test]
+    fn test_rejects_out_of_order_times() {
+        let start = ScheduleTime::Instant(utc(7, 16, 0, 0));
+        assert_eq!(
+            EventSchedule::resolve(
+                Tz::UTC,
+                start,
+                Some(ScheduleEnd::At(ScheduleTime::Instant(utc(7, 15, 23, 0)))),
+                None,
+            )
+            .unwrap_err(),
+            ScheduleError::EndsBeforeStart
+        );
+        assert_eq!(
+            EventSchedule::resolve(
+                Tz::UTC,
+                start,
+                None,
+                Some(ScheduleTime::Instant(utc(7, 16, 1, 0))),
+            )
+            .unwrap_err(),
+            ScheduleError::DoorsOpenAfterStart
+        );
+    }
+
+    #[test]
+    fn test_reschedule_keeps_duration_and_doors_lead() {
+        let schedule = EventSchedule::new(
+            Chicago,
+            utc(7, 16, 0, 0),
+            Some(utc(7, 16, 2, 0)),
+            Some(utc(7, 15, 23, 0)),
+        );
+
+        let moved = schedule
+            .reschedule(
+                None,
+                Some(ScheduleTime::Local(local(7, 17, 20, 0))),
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(*moved.starts_at(), utc(7, 18, 1, 0));
+        assert_eq!(moved.duration(), Some(Duration::hours(2)));
+        assert_eq!(moved.doors_open_at(), Some(&utc(7, 18, 0, 0)));
+    }
+
+    #[test]
+    fn test_local_days_of_a_multi_day_event() {
+        // Fri 12:00 to Sun 24:00 in Chicago
+        let schedule = EventSchedule::resolve(
+            Chicago,
+            ScheduleTime::Local(local(7, 17, 12, 0)),
+            Some(ScheduleEnd::At(ScheduleTime::Local(local(7, 20, 0, 0)))),
+            None,
+        )
+        .unwrap();
+
+        let days = schedule.local_days();
+        assert_eq!(*days.start(), NaiveDate::from_ymd_opt(2026, 7, 17).unwrap());
+        assert_eq!(*days.end(), NaiveDate::from_ymd_opt(2026, 7, 19).unwrap());
+        assert_eq!(
+            schedule.day_starts_at(NaiveDate::from_ymd_opt(2026, 7, 18).unwrap()),
+            utc(7, 18, 5, 0)
+        );
+        // The first day starts with the event, not at midnight
+        assert_eq!(schedule.day_starts_at(*days.start()), *schedule.starts_at());
+    }
+}
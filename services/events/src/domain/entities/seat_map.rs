@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::SeatingChart;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SeatStatus {
+    Available,
+    Held,
+    Sold,
+}
+
+/// A venue's seating chart combined with the seat availability of one event.
+#[derive(Debug, Clone)]
+pub struct SeatMap {
+    event_id: Uuid,
+    chart: SeatingChart,
+    statuses: HashMap<Uuid, SeatStatus>,
+}
+
+impl SeatMap {
+    pub fn new(event_id: Uuid, chart: SeatingChart, statuses: HashMap<Uuid, SeatStatus>) -> Self {
+        Self {
+            event_id,
+            chart,
+            statuses,
+        }
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn chart(&self) -> &SeatingChart {
+        &self.chart
+    }
+
+    /// Seats without a recorded status are available.
+    pub fn status_of(&self, seat_id: Uuid) -> SeatStatus {
+        self.statuses
+            .get(&seat_id)
+            .copied()
+            .unwrap_or(SeatStatus::Available)
+    }
+}
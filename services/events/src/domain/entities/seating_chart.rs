@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The seating layout of a venue, grouped into sections and rows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeatingChart {
+    venue_id: Uuid,
+    sections: Vec<SeatingSection>,
+}
+
+impl SeatingChart {
+    pub fn new(venue_id: Uuid, sections: Vec<SeatingSection>) -> Self {
+        Self { venue_id, sections }
+    }
+
+    pub fn venue_id(&self) -> Uuid {
+        self.venue_id
+    }
+
+    pub fn sections(&self) -> &[SeatingSection] {
+        &self.sections
+    }
+
+    pub fn seats(&self) -> impl Iterator<Item = &Seat> {
+        self.sections
+            .iter()
+            .flat_map(|section| section.rows().iter())
+            .flat_map(|row| row.seats().iter())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeatingSection {
+    id: Uuid,
+    name: String,
+    rows: Vec<SeatingRow>,
+}
+
+impl SeatingSection {
+    pub fn new(id: Uuid, name: String, rows: Vec<SeatingRow>) -> Self {
+        Self { id, name, rows }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn rows(&self) -> &[SeatingRow] {
+        &self.rows
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeatingRow {
+    label: String,
+    seats: Vec<Seat>,
+}
+
+impl SeatingRow {
+    pub fn new(label: String, seats: Vec<Seat>) -> Self {
+        Self { label, seats }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn seats(&self) -> &[Seat] {
+        &self.seats
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Seat {
+    id: Uuid,
+    label: String,
+    x: f64,
+    y: f64,
+    accessible: bool,
+    obstructed: bool,
+}
+
+impl Seat {
+    pub fn new(
+        id: Uuid,
+        label: String,
+        x: f64,
+        y: f64,
+        accessible: bool,
+        obstructed: bool,
+    ) -> Self {
+        Self {
+            id,
+            label,
+            x,
+            y,
+            accessible,
+            obstructed,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn accessible(&self) -> bool {
+        self.accessible
+    }
+
+    pub fn obstructed(&self) -> bool {
+        self.obstructed
+    }
+}
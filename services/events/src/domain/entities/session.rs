@@ -0,0 +1,221 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// One part of a multi-session event, e.g. a set on a festival stage or a
+/// conference talk. Sessions lie within the event's schedule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventSession {
+    id: Uuid,
+    event_id: Uuid,
+    title: String,
+    stage: Option<String>,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    /// Seats or standing room; caps the tickets sold for this session alone.
+    capacity: Option<i32>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl EventSession {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        event_id: Uuid,
+        title: String,
+        stage: Option<String>,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        capacity: Option<i32>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            event_id,
+            title,
+            stage,
+            starts_at,
+            ends_at,
+            capacity,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn stage(&self) -> Option<&str> {
+        self.stage.as_deref()
+    }
+
+    pub fn starts_at(&self) -> &DateTime<Utc> {
+        &self.starts_at
+    }
+
+    pub fn ends_at(&self) -> &DateTime<Utc> {
+        &self.ends_at
+    }
+
+    pub fn capacity(&self) -> Option<i32> {
+        self.capacity
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+/// What a ticket type lets its holders into.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Admission {
+    /// The whole event, including every session.
+    #[default]
+    Event,
+    /// Every session on this local date of the event, e.g. a day pass.
+    Day(NaiveDate),
+    /// A single session.
+    Session(Uuid),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum AdmissionError {
+    #[error("{0} is not one of the event's days")]
+    DayOutsideEvent(NaiveDate),
+    #[error("Session {0} not found")]
+    SessionNotFound(Uuid),
+    #[error("Tickets admitting to a session would exceed its capacity of {0}")]
+    OverSessionCapacity(i32),
+}
+
+/// Sessions held on one stage on one day, earliest first.
+#[derive(Debug, Clone)]
+pub struct AgendaStage {
+    /// `None` for sessions without a stage, e.g. a keynote on the main floor.
+    pub stage: Option<String>,
+    pub sessions: Vec<EventSession>,
+}
+
+/// One day of an event's agenda.
+#[derive(Debug, Clone)]
+pub struct AgendaDay {
+    /// Local date in the event's timezone.
+    pub date: NaiveDate,
+    /// Stages in the order their first session of the day starts.
+    pub stages: Vec<AgendaStage>,
+}
+
+impl AgendaDay {
+    /// Groups sessions by the local date they start on, then by stage.
+    pub fn group(sessions: &[EventSession], timezone: Tz) -> Vec<Self> {
+        let mut sorted: Vec<&EventSession> = sessions.iter().collect();
+        sorted.sort_by(|a, b| {
+            (a.starts_at, a.stage(), a.title()).cmp(&(b.starts_at, b.stage(), b.title()))
+        });
+
+        let mut days: Vec<Self> = Vec::new();
+        for session in sorted {
+            let date = session.starts_at.with_timezone(&timezone).date_naive();
+            if days.last().is_none_or(|day| day.date != date) {
+                days.push(Self {
+                    date,
+                    stages: Vec::new(),
+                });
+            }
+            let stages = &mut days.last_mut().expect("day was just pushed").stages;
+            match stages
+                .iter_mut()
+                .find(|stage| stage.stage.as_deref() == session.stage())
+            {
+                Some(stage) => stage.sessions.push(session.clone()),
+                None => stages.push(AgendaStage {
+                    stage: session.stage.clone(),
+                    sessions: vec![session.clone()],
+                }),
+            }
+        }
+        days
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use chrono_tz::Europe::Berlin;
+
+    fn session(stage: Option<&str>, title: &str, day: u32, hour: u32) -> EventSession {
+        let starts_at = Utc.with_ymd_and_hms(2026, 7, day, hour, 0, 0).unwrap();
+        EventSession::new(
+            Uuid::new_v4(),
+            Uuid::nil(),
+            title.to_string(),
+            stage.map(str::to_string),
+            starts_at,
+            starts_at + chrono::Duration::hours(1),
+            None,
+            starts_at,
+            starts_at,
+        )
+    }
+
+    #[test]
+    fn test_agenda_groups_by_local_day_then_stage() {
+        let sessions = vec![
+            session(Some("Tent"), "Late set", 17, 21),
+            session(Some("Main"), "Opener", 17, 16),
+            session(Some("Tent"), "Warm-up", 17, 17),
+            // 23:00 UTC is already the next day in Berlin
+            session(Some("Main"), "Sunrise", 17, 23),
+            session(None, "Keynote", 18, 9),
+        ];
+
+        let agenda = AgendaDay::group(&sessions, Berlin);
+
+        assert_eq!(agenda.len(), 2);
+        assert_eq!(
+            agenda[0].date,
+            NaiveDate::from_ymd_opt(2026, 7, 17).unwrap()
+        );
+        let first: Vec<(Option<&str>, Vec<&str>)> = agenda[0]
+            .stages
+            .iter()
+            .map(|stage| {
+                (
+                    stage.stage.as_deref(),
+                    stage.sessions.iter().map(EventSession::title).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            first,
+            vec![
+                (Some("Main"), vec!["Opener"]),
+                (Some("Tent"), vec!["Warm-up", "Late set"]),
+            ]
+        );
+        let second: Vec<Option<&str>> = agenda[1]
+            .stages
+            .iter()
+            .map(|stage| stage.stage.as_deref())
+            .collect();
+        assert_eq!(second, vec![Some("Main"), None]);
+    }
+}
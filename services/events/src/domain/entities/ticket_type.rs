@@ -0,0 +1,140 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::Admission;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TicketTypeVisibility {
+    /// Listed to buyers on the event page.
+    Public,
+    /// Only sold through direct links or by the box office.
+    Hidden,
+}
+
+/// A sellable kind of ticket for an event, e.g. GA, VIP or early-bird.
+/// Prices are stored in minor units (cents) of `currency`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketType {
+    id: Uuid,
+    event_id: Uuid,
+    name: String,
+    price_minor: i64,
+    currency: String,
+    quantity: i32,
+    min_per_order: i32,
+    max_per_order: i32,
+    sales_start: Option<DateTime<Utc>>,
+    sales_end: Option<DateTime<Utc>>,
+    visibility: TicketTypeVisibility,
+    admission: Admission,
+    /// Seating-chart section the ticket type sells seats in; `None` for
+    /// general admission or any seat.
+    section_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TicketType {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        event_id: Uuid,
+        name: String,
+        price_minor: i64,
+        currency: String,
+        quantity: i32,
+        min_per_order: i32,
+        max_per_order: i32,
+        sales_start: Option<DateTime<Utc>>,
+        sales_end: Option<DateTime<Utc>>,
+        visibility: TicketTypeVisibility,
+        admission: Admission,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            event_id,
+            name,
+            price_minor,
+            currency,
+            quantity,
+            min_per_order,
+            max_per_order,
+            sales_start,
+            sales_end,
+            visibility,
+            admission,
+            section_id: None,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn with_section(mut self, section_id: Option<Uuid>) -> Self {
+        self.section_id = section_id;
+        self
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn price_minor(&self) -> i64 {
+        self.price_minor
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn quantity(&self) -> i32 {
+        self.quantity
+    }
+
+    pub fn min_per_order(&self) -> i32 {
+        self.min_per_order
+    }
+
+    pub fn max_per_order(&self) -> i32 {
+        self.max_per_order
+    }
+
+    pub fn sales_start(&self) -> Option<&DateTime<Utc>> {
+        self.sales_start.as_ref()
+    }
+
+    pub fn sales_end(&self) -> Option<&DateTime<Utc>> {
+        self.sales_end.as_ref()
+    }
+
+    pub fn visibility(&self) -> TicketTypeVisibility {
+        self.visibility
+    }
+
+    pub fn admission(&self) -> Admission {
+        self.admission
+    }
+
+    pub fn section_id(&self) -> Option<Uuid> {
+        self.section_id
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::GeoPoint;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Venue {
+    id: Uuid,
+    name: String,
+    /// IANA timezone events at the venue default to.
+    timezone: Tz,
+    location: Option<GeoPoint>,
+    organizer_id: Option<Uuid>,
+    organization_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Venue {
+    pub fn new(
+        id: Uuid,
+        name: String,
+        timezone: Tz,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            timezone,
+            location: None,
+            organizer_id: None,
+            organization_id: None,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn with_location(mut self, location: Option<GeoPoint>) -> Self {
+        self.location = location;
+        self
+    }
+
+    pub fn with_owner(mut self, organizer_id: Option<Uuid>, organization_id: Option<Uuid>) -> Self {
+        self.organizer_id = organizer_id;
+        self.organization_id = organization_id;
+        self
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Where the venue is; venues without one never match a search by
+    /// distance.
+    pub fn location(&self) -> Option<GeoPoint> {
+        self.location
+    }
+
+    /// Who created the venue; venues created before venues had owners have
+    /// none.
+    pub fn organizer_id(&self) -> Option<Uuid> {
+        self.organizer_id
+    }
+
+    pub fn organization_id(&self) -> Option<Uuid> {
+        self.organization_id
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
@@ -0,0 +1,62 @@
+//! Test fixtures shared by the domain and adapter tests.
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use uuid::Uuid;
+
+use super::entities::{Event, EventSchedule, EventStatus, RichText};
+
+/// The arguments of `Event::new`, with defaults for everything a test
+/// doesn't care about. Override fields with struct update syntax:
+/// `EventFixture { venue_name: "Rooftop", ..EventFixture::at(starts_at) }`.
+pub struct EventFixture<'a> {
+    pub id: Uuid,
+    pub title: &'a str,
+    /// Markdown source.
+    pub description: &'a str,
+    pub schedule: EventSchedule,
+    pub venue_name: &'a str,
+    pub organizer_id: Option<Uuid>,
+    pub organization_id: Option<Uuid>,
+    pub status: EventStatus,
+    pub sequence: i32,
+}
+
+impl EventFixture<'static> {
+    /// A scheduled event in UTC starting at `starts_at`, with no end and no
+    /// owner.
+    pub fn at(starts_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            title: "Jazz night",
+            description: "Live jazz",
+            schedule: EventSchedule::new(Tz::UTC, starts_at, None, None),
+            venue_name: "Cellar",
+            organizer_id: None,
+            organization_id: None,
+            status: EventStatus::Scheduled,
+            sequence: 1,
+        }
+    }
+}
+
+impl EventFixture<'_> {
+    pub fn build(self) -> Event {
+        let created_at = *self.schedule.starts_at();
+        Event::new(
+            self.id,
+            self.title.to_string(),
+            RichText::from_markdown(self.description),
+            self.schedule,
+            self.venue_name.to_string(),
+            None,
+            self.organizer_id,
+            self.organization_id,
+            self.status,
+            self.sequence,
+            1,
+            created_at,
+            created_at,
+        )
+    }
+}
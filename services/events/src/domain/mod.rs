@@ -1,11 +1,52 @@
 pub mod entities;
 pub mod errors;
+#[cfg(test)]
+pub(crate) mod fixtures;
+pub mod ports;
 pub mod repositories;
 pub mod use_cases;
 
-pub use entities::Event;
+pub use entities::{Event, EventSeries, SeatMap, SeatStatus, SeatingChart, TicketType, Venue};
 pub use errors::{DomainError, DomainResult};
-pub use repositories::EventRepository;
+pub use ports::{
+    DomainEventPublisher, HolderNotifier, ImageProcessor, ObjectStorage, SeatInventory, Ticketing,
+    UsersClient,
+};
+pub use repositories::{
+    CancellationRepository, CategoryRepository, EventRepository, EventSeriesRepository,
+    EventTemplateRepository, ImportJobRepository, OutboxRepository, VenueRepository,
+};
 pub use use_cases::{
-    CreateEventUseCase, CreateEventUseCaseError, GetEventUseCase, GetEventUseCaseError,
+    CancelEventUseCase, CancelEventUseCaseError, CreateCategoryUseCase, CreateCategoryUseCaseError,
+    CreateEventSeriesUseCase, CreateEventSeriesUseCaseError, CreateEventSessionUseCase,
+    CreateEventSessionUseCaseError, CreateEventTemplateUseCase, CreateEventTemplateUseCaseError,
+    CreateEventUseCase, CreateEventUseCaseError, CreateTicketTypeUseCase,
+    CreateTicketTypeUseCaseError, CreateVenueUseCase, CreateVenueUseCaseError,
+    DeleteCategoryUseCase, DeleteCategoryUseCaseError, DeleteEventMediaUseCase,
+    DeleteEventMediaUseCaseError, DeleteEventSessionUseCase, DeleteEventSessionUseCaseError,
+    DeleteEventTemplateUseCase, DeleteEventTemplateUseCaseError, DeleteEventTranslationUseCase,
+    DeleteEventTranslationUseCaseError, DeleteOccurrenceOverrideUseCase,
+    DeleteOccurrenceOverrideUseCaseError, DeleteTicketTypeUseCase, DeleteTicketTypeUseCaseError,
+    DispatchDomainEventsUseCase, DuplicateEventUseCase, DuplicateEventUseCaseError,
+    GetEventCancellationUseCase, GetEventCancellationUseCaseError, GetEventFacetsUseCase,
+    GetEventFacetsUseCaseError, GetEventHistoryUseCase, GetEventHistoryUseCaseError,
+    GetEventRevisionUseCase, GetEventRevisionUseCaseError, GetEventSeriesUseCase,
+    GetEventSeriesUseCaseError, GetEventTemplateUseCase, GetEventTemplateUseCaseError,
+    GetEventUseCase, GetEventUseCaseError, GetImportJobUseCase, GetImportJobUseCaseError,
+    GetOrganizerCalendarUseCase, GetOrganizerCalendarUseCaseError, GetSeatMapUseCase,
+    GetSeatMapUseCaseError, GetSeatingChartUseCase, GetSeatingChartUseCaseError,
+    GetTicketTypeUseCase, GetTicketTypeUseCaseError, GetVenueCalendarUseCase,
+    GetVenueCalendarUseCaseError, GetVenueUseCase, GetVenueUseCaseError, ImportEventsUseCase,
+    ImportEventsUseCaseError, ImportSeatingChartUseCase, ImportSeatingChartUseCaseError,
+    InstantiateEventTemplateUseCase, InstantiateEventTemplateUseCaseError, ListCategoriesUseCase,
+    ListCategoriesUseCaseError, ListEventTemplatesUseCase, ListEventTemplatesUseCaseError,
+    ListEventsUseCase, ListEventsUseCaseError, ListOccurrencesUseCase, ListOccurrencesUseCaseError,
+    ListOrganizerEventsUseCase, ListOrganizerEventsUseCaseError, ListTicketTypesUseCase,
+    ListTicketTypesUseCaseError, OverrideOccurrenceUseCase, OverrideOccurrenceUseCaseError,
+    PublishEventUseCase, PublishEventUseCaseError, RunEventCancellationsUseCase,
+    RunImportJobsUseCase, RunPublishingScheduleUseCase, SaveEventTranslationUseCase,
+    SaveEventTranslationUseCaseError, UpdateCategoryUseCase, UpdateCategoryUseCaseError,
+    UpdateEventSessionUseCase, UpdateEventSessionUseCaseError, UpdateEventUseCase,
+    UpdateEventUseCaseError, UpdateTicketTypeUseCase, UpdateTicketTypeUseCaseError,
+    UploadEventMediaUseCase, UploadEventMediaUseCaseError,
 };
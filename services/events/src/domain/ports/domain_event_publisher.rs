@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::domain::{entities::OutboxMessage, errors::DomainResult};
+
+/// Delivers domain events to the services that react to them.
+#[async_trait]
+pub trait DomainEventPublisher: Send + Sync {
+    /// Delivers the message. Delivery is at least once: a message may be
+    /// published again if the dispatcher dies before recording it as sent.
+    async fn publish(&self, message: &OutboxMessage) -> DomainResult<()>;
+}
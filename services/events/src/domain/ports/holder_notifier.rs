@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{errors::DomainResult, Event};
+
+/// Tells ticket holders about changes to events they have tickets for.
+#[async_trait]
+pub trait HolderNotifier: Send + Sync {
+    /// Tells the holder the event is off and why. Delivery is at least once:
+    /// a holder may be told twice if the cancellation is retried.
+    async fn notify_cancelled(
+        &self,
+        holder_id: Uuid,
+        event: &Event,
+        reason: &str,
+    ) -> DomainResult<()>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::domain::{entities::MediaFormat, errors::DomainResult};
+
+/// A decoded image's dimensions and a JPEG thumbnail of it.
+#[derive(Debug, Clone)]
+pub struct ProcessedImage {
+    pub width: u32,
+    pub height: u32,
+    pub thumbnail: Vec<u8>,
+}
+
+/// Decodes uploaded images and renders thumbnails.
+#[async_trait]
+pub trait ImageProcessor: Send + Sync {
+    /// Returns `None` when the bytes cannot be decoded as `format`.
+    async fn process(
+        &self,
+        bytes: Vec<u8>,
+        format: MediaFormat,
+    ) -> DomainResult<Option<ProcessedImage>>;
+}
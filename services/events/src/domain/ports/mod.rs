@@ -0,0 +1,15 @@
+pub mod domain_event_publisher;
+pub mod holder_notifier;
+pub mod image_processor;
+pub mod object_storage;
+pub mod seat_inventory;
+pub mod ticketing;
+pub mod users_client;
+
+pub use domain_event_publisher::DomainEventPublisher;
+pub use holder_notifier::HolderNotifier;
+pub use image_processor::{ImageProcessor, ProcessedImage};
+pub use object_storage::ObjectStorage;
+pub use seat_inventory::SeatInventory;
+pub use ticketing::Ticketing;
+pub use users_client::UsersClient;
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainResult;
+
+/// Blob store for uploaded media, addressed by key.
+#[async_trait]
+pub trait ObjectStorage: Send + Sync {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> DomainResult<()>;
+    /// Stores a copy of the object at `from` under `to`.
+    async fn copy(&self, from: &str, to: &str) -> DomainResult<()>;
+    /// Removes the object; deleting a missing key is not an error.
+    async fn delete(&self, key: &str) -> DomainResult<()>;
+    /// Public URL the object is served from.
+    fn url(&self, key: &str) -> String;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::{entities::SeatStatus, errors::DomainResult};
+
+/// Source of per-event seat availability, owned by the ticketing side.
+#[async_trait]
+pub trait SeatInventory: Send + Sync {
+    /// Returns the status of every seat that is not available. Seats missing
+    /// from the map are treated as available.
+    async fn seat_statuses(&self, event_id: Uuid) -> DomainResult<HashMap<Uuid, SeatStatus>>;
+}
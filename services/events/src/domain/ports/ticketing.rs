@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+
+/// Ticket sales and orders of events, owned by the ticketing side. Every
+/// call is safe to repeat, so a cancellation can retry it after a failure.
+#[async_trait]
+pub trait Ticketing: Send + Sync {
+    /// Stops selling tickets for the event; tickets on hold can no longer be
+    /// bought.
+    async fn stop_sales(&self, event_id: Uuid) -> DomainResult<()>;
+    /// Refunds every order for the event that isn't refunded yet and returns
+    /// how many this call refunded.
+    async fn refund_orders(&self, event_id: Uuid) -> DomainResult<u32>;
+    /// Everyone who holds or held a ticket for the event, refunded or not,
+    /// ordered by user id.
+    async fn ticket_holders(&self, event_id: Uuid) -> DomainResult<Vec<Uuid>>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{entities::OrganizationRole, errors::DomainResult};
+
+/// Read access to accounts and organizations owned by the users service.
+#[async_trait]
+pub trait UsersClient: Send + Sync {
+    async fn user_exists(&self, id: Uuid) -> DomainResult<bool>;
+    /// The user's role in the organization, or `None` when they are not a
+    /// member or the organization does not exist.
+    async fn find_organization_role(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<Option<OrganizationRole>>;
+}
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::{entities::EventCancellation, errors::DomainResult};
+
+/// Progress of cancelled events through their follow-up steps. Cancellations
+/// are started by `EventRepository::cancel`, together with the event's
+/// transition.
+#[async_trait]
+pub trait CancellationRepository: Send + Sync {
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<EventCancellation>;
+    /// Claims up to `limit` unfinished cancellations whose next attempt is
+    /// due at `now`, holding them for `lease` under a new claim token.
+    /// Cancellations another runner holds are skipped.
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u64,
+        lease: Duration,
+    ) -> DomainResult<Vec<EventCancellation>>;
+    /// Stores the cancellation's progress and releases its claim. Returns
+    /// `false`, storing nothing, when another runner has claimed it since.
+    async fn save(&self, cancellation: &EventCancellation) -> DomainResult<bool>;
+    /// Stores progress made so far while keeping the claim, now held until
+    /// `held_until`. Returns `false`, storing nothing, when another runner
+    /// has claimed the cancellation since.
+    async fn save_progress(
+        &self,
+        cancellation: &EventCancellation,
+        held_until: DateTime<Utc>,
+    ) -> DomainResult<bool>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{Category, Taxonomy},
+    errors::DomainResult,
+    use_cases::{CreateCategoryParams, UpdateCategoryParams},
+};
+
+#[async_trait]
+pub trait CategoryRepository: Send + Sync {
+    /// The whole taxonomy, with categories ordered by name.
+    async fn find_all(&self) -> DomainResult<Taxonomy>;
+    async fn create(&self, data: CreateCategoryParams) -> DomainResult<Category>;
+    async fn update(&self, data: UpdateCategoryParams) -> DomainResult<Category>;
+    /// Whether any event is filed under the category.
+    async fn is_in_use(&self, id: Uuid) -> DomainResult<bool>;
+    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+}
@@ -1,10 +1,156 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::domain::{entities::Event, errors::DomainResult, use_cases::CreateEventParams};
+use crate::domain::{
+    entities::{
+        DomainEvent, Event, EventCancellation, EventRevision, EventSchedule, EventSession,
+        EventTranslation, Locale, MediaAsset, Publication, TicketType,
+    },
+    errors::DomainResult,
+    use_cases::{CreateEventParams, EventFilter, TicketTypeParams, UpdateEventParams},
+};
 
 #[async_trait]
 pub trait EventRepository: Send + Sync {
+    /// Loads the event aggregate, including its ticket types, media,
+    /// sessions and translations.
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Event>;
-    async fn create(&self, data: CreateEventParams) -> DomainResult<Event>;
+    /// Stores a new event at the times already resolved into `schedule` and
+    /// `publication`, recording it as revision 0. Publishing it or opening
+    /// its sales right away records the domain events for that.
+    async fn create(
+        &self,
+        data: CreateEventParams,
+        schedule: EventSchedule,
+        publication: Publication,
+    ) -> DomainResult<Event>;
+    /// Stores a new unpublished event under `id` together with its sessions,
+    /// translations, ticket types and already stored media, recording it as
+    /// revision 0.
+    #[allow(clippy::too_many_arguments)]
+    async fn create_draft(
+        &self,
+        id: Uuid,
+        data: CreateEventParams,
+        schedule: EventSchedule,
+        sessions: Vec<EventSession>,
+        translations: Vec<EventTranslation>,
+        ticket_types: Vec<TicketTypeParams>,
+        media: Vec<MediaAsset>,
+    ) -> DomainResult<Event>;
+    /// The organizer's event imported with the UID `external_uid`; `None`
+    /// looks among events without an organizer.
+    async fn find_by_external_uid(
+        &self,
+        organizer_id: Option<Uuid>,
+        external_uid: &str,
+    ) -> DomainResult<Event>;
+    /// Applies the changes, stores `schedule` and `publication`, bumps the
+    /// event's sequence and version and records the change as a revision.
+    /// Sessions and day passes move along with the start.
+    /// Returns `None`, changing nothing, when the event is no longer at
+    /// `data.expected_version`.
+    async fn update(
+        &self,
+        data: UpdateEventParams,
+        schedule: EventSchedule,
+        publication: Publication,
+    ) -> DomainResult<Option<Event>>;
+    /// Publishes up to `limit` scheduled events whose `publish_at` has
+    /// passed, opening their sales too unless they have an `on_sale_at`, and
+    /// records the domain events for it. Events being published by another
+    /// caller are skipped rather than waited for.
+    async fn publish_due(&self, now: DateTime<Utc>, limit: u64) -> DomainResult<Vec<DomainEvent>>;
+    /// Publishes the event right away, opening its sales too unless they are
+    /// scheduled for later, and records the domain events for it. Returns
+    /// `None`, changing nothing, when the event is already published.
+    async fn publish(&self, id: Uuid, now: DateTime<Utc>) -> DomainResult<Option<Event>>;
+    /// Opens sales of up to `limit` published events whose `on_sale_at` has
+    /// passed, like `publish_due`.
+    async fn open_sales_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u64,
+    ) -> DomainResult<Vec<DomainEvent>>;
+    /// Marks the event cancelled, bumps its sequence and version, records the
+    /// change as a revision and `EventCancelled` in the outbox, and starts
+    /// the cancellation's follow-up steps.
+    async fn cancel(&self, cancellation: &EventCancellation) -> DomainResult<Event>;
+    /// Recorded revisions of the event, oldest first. Changes to its ticket
+    /// types, sessions, media and translations are not among them.
+    async fn find_revisions(&self, event_id: Uuid) -> DomainResult<Vec<EventRevision>>;
+    async fn find_revision(&self, event_id: Uuid, revision: i32) -> DomainResult<EventRevision>;
+    /// Events at the venue starting at or after `since`, including cancelled
+    /// ones, earliest first.
+    async fn find_by_venue(&self, venue_id: Uuid, since: DateTime<Utc>)
+        -> DomainResult<Vec<Event>>;
+    /// Events of the organizer starting at or after `since`, including
+    /// cancelled ones, earliest first.
+    async fn find_by_organizer(
+        &self,
+        organizer_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<Event>>;
+    /// Events of the organizer starting in `[from, to)`, including cancelled
+    /// ones, earliest first, with their ticket types and translations.
+    async fn list_by_organizer(
+        &self,
+        organizer_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: u64,
+    ) -> DomainResult<Vec<Event>>;
+    /// One-off events starting in `[from, to)` that match `filter`, with
+    /// their translations but without their ticket types. Earliest first, or by venue in the order of
+    /// `filter.venue_ids` and then earliest first when that is set.
+    async fn list_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: u64,
+        filter: &EventFilter,
+    ) -> DomainResult<Vec<Event>>;
+    /// `(event_id, category_id)` pairs of the one-off events starting in
+    /// `[from, to)` that match `filter`.
+    async fn find_category_assignments(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        filter: &EventFilter,
+    ) -> DomainResult<Vec<(Uuid, Uuid)>>;
+    async fn find_ticket_types(&self, event_id: Uuid) -> DomainResult<Vec<TicketType>>;
+    async fn find_ticket_type(&self, event_id: Uuid, id: Uuid) -> DomainResult<TicketType>;
+    /// Ticket type and media changes bump the event's version, as they are
+    /// part of the event's representation.
+    async fn create_ticket_type(
+        &self,
+        event_id: Uuid,
+        data: TicketTypeParams,
+    ) -> DomainResult<TicketType>;
+    async fn update_ticket_type(
+        &self,
+        event_id: Uuid,
+        id: Uuid,
+        data: TicketTypeParams,
+    ) -> DomainResult<TicketType>;
+    async fn delete_ticket_type(&self, event_id: Uuid, id: Uuid) -> DomainResult<()>;
+    /// Media of the event, oldest first.
+    async fn find_media(&self, event_id: Uuid) -> DomainResult<Vec<MediaAsset>>;
+    /// Stores the asset. A new poster replaces the event's current one, which
+    /// is returned so its stored objects can be removed.
+    async fn add_media(&self, asset: MediaAsset) -> DomainResult<Option<MediaAsset>>;
+    /// Removes the asset and returns it so its stored objects can be removed.
+    async fn delete_media(&self, event_id: Uuid, id: Uuid) -> DomainResult<MediaAsset>;
+    /// Session changes bump the event's version like ticket type changes.
+    async fn create_session(&self, session: &EventSession) -> DomainResult<EventSession>;
+    async fn update_session(&self, session: &EventSession) -> DomainResult<EventSession>;
+    async fn delete_session(&self, event_id: Uuid, id: Uuid) -> DomainResult<()>;
+    /// Creates or replaces the translation into its locale; bumps the
+    /// event's version.
+    async fn save_translation(
+        &self,
+        translation: &EventTranslation,
+    ) -> DomainResult<EventTranslation>;
+    async fn delete_translation(&self, event_id: Uuid, locale: &Locale) -> DomainResult<()>;
 }
@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{EventSeries, OccurrenceOverride},
+    errors::DomainResult,
+    use_cases::{CreateEventSeriesParams, OverrideOccurrenceParams},
+};
+
+#[async_trait]
+pub trait EventSeriesRepository: Send + Sync {
+    /// Loads the series together with its occurrence overrides.
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<EventSeries>;
+    async fn create(
+        &self,
+        data: CreateEventSeriesParams,
+        last_occurrence_at: Option<DateTime<Utc>>,
+    ) -> DomainResult<EventSeries>;
+    /// Series that may have occurrences in `[from, to)`, with their overrides.
+    async fn find_active_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<EventSeries>>;
+    async fn upsert_override(
+        &self,
+        data: OverrideOccurrenceParams,
+    ) -> DomainResult<OccurrenceOverride>;
+    async fn delete_override(
+        &self,
+        series_id: Uuid,
+        occurrence_start: DateTime<Utc>,
+    ) -> DomainResult<()>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{entities::EventTemplate, errors::DomainResult};
+
+#[async_trait]
+pub trait EventTemplateRepository: Send + Sync {
+    async fn create(&self, template: &EventTemplate) -> DomainResult<()>;
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<EventTemplate>;
+    /// Templates of the organization, or the organizer's own templates
+    /// outside any organization when `organization_id` is `None`; by name.
+    async fn list(
+        &self,
+        organizer_id: Uuid,
+        organization_id: Option<Uuid>,
+    ) -> DomainResult<Vec<EventTemplate>>;
+    /// Removes the template and returns it so its stored media can be removed.
+    async fn delete(&self, id: Uuid) -> DomainResult<EventTemplate>;
+}
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::{entities::ImportJob, errors::DomainResult, use_cases::ImportEntry};
+
+#[async_trait]
+pub trait ImportJobRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<ImportJob>;
+    /// Creates a pending job for `entries` from `source`, importing events
+    /// for `organizer_id`. With `held_until` the caller holds the new job
+    /// until then; without, it waits for a runner to claim it.
+    async fn create(
+        &self,
+        source: String,
+        organizer_id: Option<Uuid>,
+        entries: &[ImportEntry],
+        held_until: Option<DateTime<Utc>>,
+    ) -> DomainResult<ImportJob>;
+    /// Claims the oldest unfinished job that nobody holds at `now`, holding
+    /// it for `lease` under a new claim token, and returns it with its
+    /// entries. Jobs another runner holds are skipped.
+    async fn claim_next(
+        &self,
+        now: DateTime<Utc>,
+        lease: Duration,
+    ) -> DomainResult<Option<(ImportJob, Vec<ImportEntry>)>>;
+    /// Persists the job's status, results and completion time, holding it
+    /// until `held_until`; finished jobs release their claim and drop their
+    /// entries. Returns `false`, storing nothing, when another runner has
+    /// claimed the job since.
+    async fn save(&self, job: &ImportJob, held_until: DateTime<Utc>) -> DomainResult<bool>;
+}
@@ -1,3 +1,17 @@
+pub mod cancellation_repository;
+pub mod category_repository;
 pub mod event_repository;
+pub mod event_series_repository;
+pub mod event_template_repository;
+pub mod import_job_repository;
+pub mod outbox_repository;
+pub mod venue_repository;
 
+pub use cancellation_repository::CancellationRepository;
+pub use category_repository::CategoryRepository;
 pub use event_repository::EventRepository;
+pub use event_series_repository::EventSeriesRepository;
+pub use event_template_repository::EventTemplateRepository;
+pub use import_job_repository::ImportJobRepository;
+pub use outbox_repository::OutboxRepository;
+pub use venue_repository::VenueRepository;
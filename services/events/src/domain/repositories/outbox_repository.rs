@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::domain::{entities::OutboxMessage, errors::DomainResult};
+
+/// Domain events recorded in the same transaction as the changes they
+/// describe, waiting to be dispatched.
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// Claims up to `limit` undispatched messages, oldest first, for `lease`.
+    /// Messages another dispatcher holds a live claim on are skipped, so
+    /// concurrent dispatchers never deliver the same message at once.
+    async fn claim_pending(&self, limit: u64, lease: Duration) -> DomainResult<Vec<OutboxMessage>>;
+    async fn mark_dispatched(&self, id: Uuid) -> DomainResult<()>;
+}
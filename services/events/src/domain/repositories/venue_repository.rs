@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{GeoRadius, SeatingChart, Venue},
+    errors::DomainResult,
+    use_cases::{CreateVenueParams, ImportSeatingChartParams},
+};
+
+#[async_trait]
+pub trait VenueRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Venue>;
+    async fn create(&self, data: CreateVenueParams) -> DomainResult<Venue>;
+    /// Venues located within `area`, nearest first.
+    async fn find_within(&self, area: &GeoRadius) -> DomainResult<Vec<Venue>>;
+    async fn find_seating_chart(&self, venue_id: Uuid) -> DomainResult<SeatingChart>;
+    /// Sections of the venue that ticket types sell seats in.
+    async fn find_sections_in_use(&self, venue_id: Uuid) -> DomainResult<HashSet<Uuid>>;
+    /// Makes the venue's seating chart match the given layout. Sections are
+    /// matched by name and seats by section, row and seat label, keeping
+    /// their ids; the rest are added or removed.
+    async fn replace_seating_chart(
+        &self,
+        data: ImportSeatingChartParams,
+    ) -> DomainResult<SeatingChart>;
+}
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use crate::domain::{
+    entities::EventCancellation, DomainError, Event, EventRepository, UsersClient,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CancelEventParams {
+    pub id: Uuid,
+    /// Shown to ticket holders when they are notified.
+    pub reason: String,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CancelEventUseCaseError {
+    #[error("Event not found")]
+    NotFound,
+    #[error("Only the organizer or the organization's owners and managers can cancel this event")]
+    Forbidden,
+    #[error("Event is already cancelled")]
+    AlreadyCancelled,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct CancelEventUseCase {
+    repository: Arc<dyn EventRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl CancelEventUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<CancelEventParams, Result<Event, CancelEventUseCaseError>> for CancelEventUseCase {
+    async fn execute(&self, data: CancelEventParams) -> Result<Event, CancelEventUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => CancelEventUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => CancelEventUseCaseError::DatabaseError(msg),
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| CancelEventUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(CancelEventUseCaseError::Forbidden);
+        }
+
+        if event.is_cancelled() {
+            return Err(CancelEventUseCaseError::AlreadyCancelled);
+        }
+
+        let cancellation =
+            EventCancellation::start(data.id, data.reason, data.requested_by, Utc::now());
+        self.repository
+            .cancel(&cancellation)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => CancelEventUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => CancelEventUseCaseError::DatabaseError(msg),
+            })
+    }
+}
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{Administrators, Category},
+    CategoryRepository,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCategoryParams {
+    pub name: String,
+    pub slug: String,
+    /// Category to nest under; `None` creates a top-level category.
+    pub parent_id: Option<Uuid>,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateCategoryUseCaseError {
+    #[error("Only administrators can change categories")]
+    Forbidden,
+    #[error("Parent category not found")]
+    ParentNotFound,
+    #[error("Slug `{0}` is already in use")]
+    SlugTaken(String),
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct CreateCategoryUseCase {
+    repository: Arc<dyn CategoryRepository>,
+    admins: Administrators,
+}
+
+impl CreateCategoryUseCase {
+    pub fn new(repository: Arc<dyn CategoryRepository>, admins: Administrators) -> Self {
+        Self { repository, admins }
+    }
+}
+
+#[async_trait]
+impl UseCase<CreateCategoryParams, Result<Category, CreateCategoryUseCaseError>>
+    for CreateCategoryUseCase
+{
+    async fn execute(
+        &self,
+        data: CreateCategoryParams,
+    ) -> Result<Category, CreateCategoryUseCaseError> {
+        if !self.admins.contains(data.requested_by) {
+            return Err(CreateCategoryUseCaseError::Forbidden);
+        }
+
+        let taxonomy = self
+            .repository
+            .find_all()
+            .await
+            .map_err(|e| CreateCategoryUseCaseError::DatabaseError(e.to_string()))?;
+
+        if data
+            .parent_id
+            .is_some_and(|parent_id| taxonomy.get(parent_id).is_none())
+        {
+            return Err(CreateCategoryUseCaseError::ParentNotFound);
+        }
+        if taxonomy
+            .categories()
+            .iter()
+            .any(|category| category.slug() == data.slug)
+        {
+            return Err(CreateCategoryUseCaseError::SlugTaken(data.slug));
+        }
+
+        self.repository
+            .create(data)
+            .await
+            .map_err(|e| CreateCategoryUseCaseError::DatabaseError(e.to_string()))
+    }
+}
@@ -1,44 +1,167 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use shared::UseCase;
 use std::sync::Arc;
+use uuid::Uuid;
 
-use crate::domain::{DomainError, Event, EventRepository};
+use super::event_access::can_create_events;
+use crate::domain::{
+    entities::{
+        EventSchedule, Locale, Publication, PublicationError, RichText, ScheduleEnd, ScheduleError,
+        ScheduleTime,
+    },
+    CategoryRepository, DomainError, Event, EventRepository, UsersClient, VenueRepository,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateEventParams {
     pub title: String,
-    pub description: String,
-    pub event_date: DateTime<Utc>,
+    pub description: RichText,
+    /// Locale `title` and `description` are written in.
+    pub default_locale: Locale,
+    pub starts_at: ScheduleTime,
+    /// IANA timezone; `None` uses the venue's, or UTC without a venue.
+    pub timezone: Option<Tz>,
+    pub end: Option<ScheduleEnd>,
+    pub doors_open_at: Option<ScheduleTime>,
     pub venue_name: String,
+    pub venue_id: Option<Uuid>,
+    pub category_ids: Vec<Uuid>,
+    /// Normalized tags: trimmed, lowercase and distinct.
+    pub tags: Vec<String>,
+    /// When to make the event public; `None` publishes it right away.
+    pub publish_at: Option<DateTime<Utc>>,
+    /// When tickets go on sale; `None` opens sales on publication.
+    pub on_sale_at: Option<DateTime<Utc>>,
+    /// The user who owns the event; set from the authenticated caller.
+    pub organizer_id: Option<Uuid>,
+    /// Organization the event is created for; the organizer must be one of
+    /// its owners or managers.
+    pub organization_id: Option<Uuid>,
+    /// UID from an imported calendar or spreadsheet, used to skip duplicates.
+    pub external_uid: Option<String>,
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum CreateEventUseCaseError {
+    #[error("{0}")]
+    InvalidSchedule(#[from] ScheduleError),
+    #[error("Event date must be in the future")]
+    EventDateInPast,
+    #[error("{0}")]
+    InvalidPublication(#[from] PublicationError),
+    #[error("Venue not found")]
+    VenueNotFound,
+    #[error("Category {0} not found")]
+    CategoryNotFound(Uuid),
+    #[error("Organizer not found")]
+    OrganizerNotFound,
+    #[error("Only owners and managers can create events for this organization")]
+    Forbidden,
     #[error("Database error: {0}")]
     DatabaseError(String),
 }
 
 pub struct CreateEventUseCase {
     repository: Arc<dyn EventRepository>,
+    venue_repository: Arc<dyn VenueRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    users: Arc<dyn UsersClient>,
 }
 
 impl CreateEventUseCase {
-    pub fn new(repository: Arc<dyn EventRepository>) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: Arc<dyn EventRepository>,
+        venue_repository: Arc<dyn VenueRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            repository,
+            venue_repository,
+            category_repository,
+            users,
+        }
     }
 }
 
 #[async_trait]
 impl UseCase<CreateEventParams, Result<Event, CreateEventUseCaseError>> for CreateEventUseCase {
     async fn execute(&self, data: CreateEventParams) -> Result<Event, CreateEventUseCaseError> {
-        self.repository.create(data).await.map_err(|e| match e {
-            DomainError::DatabaseError(msg) => CreateEventUseCaseError::DatabaseError(msg),
-            _ => {
-                log::error!("Unexpected error in CreateEventUseCase: {:?}", e);
-                CreateEventUseCaseError::DatabaseError("Unexpected error".to_string())
+        let venue = match data.venue_id {
+            Some(venue_id) => Some(self.venue_repository.find_by_id(venue_id).await.map_err(
+                |e| match e {
+                    DomainError::NotFound(_) => CreateEventUseCaseError::VenueNotFound,
+                    DomainError::DatabaseError(msg) => CreateEventUseCaseError::DatabaseError(msg),
+                },
+            )?),
+            None => None,
+        };
+
+        let timezone = data
+            .timezone
+            .or(venue.as_ref().map(|venue| venue.timezone()))
+            .unwrap_or(Tz::UTC);
+        let schedule =
+            EventSchedule::resolve(timezone, data.starts_at, data.end, data.doors_open_at)?;
+        let now = Utc::now();
+        if *schedule.starts_at() < now {
+            return Err(CreateEventUseCaseError::EventDateInPast);
+        }
+        let publication =
+            Publication::schedule(data.publish_at, data.on_sale_at, *schedule.starts_at(), now)?;
+
+        if !data.category_ids.is_empty() {
+            let taxonomy = self
+                .category_repository
+                .find_all()
+                .await
+                .map_err(|e| CreateEventUseCaseError::DatabaseError(e.to_string()))?;
+            if let Some(unknown) = data
+                .category_ids
+                .iter()
+                .find(|id| taxonomy.get(**id).is_none())
+            {
+                return Err(CreateEventUseCaseError::CategoryNotFound(*unknown));
+            }
+        }
+
+        if let Some(organizer_id) = data.organizer_id {
+            let exists = self
+                .users
+                .user_exists(organizer_id)
+                .await
+                .map_err(|e| CreateEventUseCaseError::DatabaseError(e.to_string()))?;
+            if !exists {
+                return Err(CreateEventUseCaseError::OrganizerNotFound);
             }
-        })
+        }
+
+        if let Some(organization_id) = data.organization_id {
+            let allowed = match data.organizer_id {
+                Some(organizer_id) => {
+                    can_create_events(self.users.as_ref(), organization_id, organizer_id)
+                        .await
+                        .map_err(|e| CreateEventUseCaseError::DatabaseError(e.to_string()))?
+                }
+                None => false,
+            };
+            if !allowed {
+                return Err(CreateEventUseCaseError::Forbidden);
+            }
+        }
+
+        self.repository
+            .create(data, schedule, publication)
+            .await
+            .map_err(|e| match e {
+                DomainError::DatabaseError(msg) => CreateEventUseCaseError::DatabaseError(msg),
+                _ => {
+                    log::error!("Unexpected error in CreateEventUseCase: {:?}", e);
+                    CreateEventUseCaseError::DatabaseError("Unexpected error".to_string())
+                }
+            })
     }
 }
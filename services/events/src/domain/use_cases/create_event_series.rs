@@ -0,0 +1,116 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_create_events;
+use crate::domain::{
+    entities::{Recurrence, RichText},
+    DomainError, EventSeries, EventSeriesRepository, UsersClient, VenueRepository,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateEventSeriesParams {
+    pub title: String,
+    pub description: RichText,
+    pub venue_name: String,
+    pub venue_id: Option<Uuid>,
+    pub starts_at: DateTime<Utc>,
+    /// IANA timezone the rule repeats in; `None` uses the venue's, or UTC
+    /// without a venue.
+    pub timezone: Option<Tz>,
+    pub rrule: String,
+    pub exdates: Vec<DateTime<Utc>>,
+    /// Organization the series belongs to; the caller's own series when not
+    /// set.
+    pub organization_id: Option<Uuid>,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateEventSeriesUseCaseError {
+    #[error("{0}")]
+    InvalidRecurrence(String),
+    #[error("Venue not found")]
+    VenueNotFound,
+    #[error("Only the organization's owners and managers can create series for it")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct CreateEventSeriesUseCase {
+    repository: Arc<dyn EventSeriesRepository>,
+    venue_repository: Arc<dyn VenueRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl CreateEventSeriesUseCase {
+    pub fn new(
+        repository: Arc<dyn EventSeriesRepository>,
+        venue_repository: Arc<dyn VenueRepository>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            repository,
+            venue_repository,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<CreateEventSeriesParams, Result<EventSeries, CreateEventSeriesUseCaseError>>
+    for CreateEventSeriesUseCase
+{
+    async fn execute(
+        &self,
+        mut data: CreateEventSeriesParams,
+    ) -> Result<EventSeries, CreateEventSeriesUseCaseError> {
+        if let Some(organization_id) = data.organization_id {
+            if !can_create_events(self.users.as_ref(), organization_id, data.requested_by)
+                .await
+                .map_err(|e| CreateEventSeriesUseCaseError::DatabaseError(e.to_string()))?
+            {
+                return Err(CreateEventSeriesUseCaseError::Forbidden);
+            }
+        }
+
+        let venue = match data.venue_id {
+            Some(venue_id) => Some(self.venue_repository.find_by_id(venue_id).await.map_err(
+                |e| match e {
+                    DomainError::NotFound(_) => CreateEventSeriesUseCaseError::VenueNotFound,
+                    DomainError::DatabaseError(msg) => {
+                        CreateEventSeriesUseCaseError::DatabaseError(msg)
+                    }
+                },
+            )?),
+            None => None,
+        };
+
+        let timezone = data
+            .timezone
+            .or(venue.as_ref().map(|venue| venue.timezone()))
+            .unwrap_or(Tz::UTC);
+        data.timezone = Some(timezone);
+
+        let recurrence = Recurrence::parse(data.starts_at, timezone, &data.rrule, &data.exdates)
+            .map_err(|e| CreateEventSeriesUseCaseError::InvalidRecurrence(e.to_string()))?;
+
+        self.repository
+            .create(data, recurrence.last_start())
+            .await
+            .map_err(|e| match e {
+                DomainError::DatabaseError(msg) => {
+                    CreateEventSeriesUseCaseError::DatabaseError(msg)
+                }
+                _ => {
+                    log::error!("Unexpected error in CreateEventSeriesUseCase: {:?}", e);
+                    CreateEventSeriesUseCaseError::DatabaseError("Unexpected error".to_string())
+                }
+            })
+    }
+}
@@ -0,0 +1,160 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use crate::domain::{
+    entities::{EventSession, ScheduleError, ScheduleTime},
+    DomainError, Event, EventRepository, UsersClient,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventSessionParams {
+    pub title: String,
+    pub stage: Option<String>,
+    /// Wall-clock times are read in the event's timezone.
+    pub starts_at: ScheduleTime,
+    pub ends_at: ScheduleTime,
+    pub capacity: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateEventSessionParams {
+    pub event_id: Uuid,
+    pub session: EventSessionParams,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateEventSessionUseCaseError {
+    #[error("Event not found")]
+    EventNotFound,
+    #[error("Only the organizer or the organization's owners and managers can change this event")]
+    Forbidden,
+    #[error("{0}")]
+    InvalidSchedule(ScheduleError),
+    #[error("Session must end after it starts")]
+    EndsBeforeStart,
+    #[error("Session must take place within the event")]
+    OutsideEvent,
+    #[error("{0} tickets already admit to the session, more than its capacity")]
+    CapacityBelowTickets(i64),
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub(super) enum SessionTimesError {
+    InvalidSchedule(ScheduleError),
+    EndsBeforeStart,
+    OutsideEvent,
+}
+
+impl From<ScheduleError> for SessionTimesError {
+    fn from(e: ScheduleError) -> Self {
+        Self::InvalidSchedule(e)
+    }
+}
+
+/// Resolves the session's times in the event's timezone and checks they
+/// are in order and within the event.
+pub(super) fn resolve_session_times(
+    event: &Event,
+    data: &EventSessionParams,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), SessionTimesError> {
+    let starts_at = event.schedule().resolve_time(data.starts_at)?;
+    let ends_at = event.schedule().resolve_time(data.ends_at)?;
+    if ends_at <= starts_at {
+        return Err(SessionTimesError::EndsBeforeStart);
+    }
+    if !event.schedule().covers(&starts_at, &ends_at) {
+        return Err(SessionTimesError::OutsideEvent);
+    }
+    Ok((starts_at, ends_at))
+}
+
+impl From<SessionTimesError> for CreateEventSessionUseCaseError {
+    fn from(e: SessionTimesError) -> Self {
+        match e {
+            SessionTimesError::InvalidSchedule(e) => Self::InvalidSchedule(e),
+            SessionTimesError::EndsBeforeStart => Self::EndsBeforeStart,
+            SessionTimesError::OutsideEvent => Self::OutsideEvent,
+        }
+    }
+}
+
+pub struct CreateEventSessionUseCase {
+    repository: Arc<dyn EventRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl CreateEventSessionUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<CreateEventSessionParams, Result<EventSession, CreateEventSessionUseCaseError>>
+    for CreateEventSessionUseCase
+{
+    async fn execute(
+        &self,
+        data: CreateEventSessionParams,
+    ) -> Result<EventSession, CreateEventSessionUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => CreateEventSessionUseCaseError::EventNotFound,
+                DomainError::DatabaseError(msg) => {
+                    CreateEventSessionUseCaseError::DatabaseError(msg)
+                }
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| CreateEventSessionUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(CreateEventSessionUseCaseError::Forbidden);
+        }
+
+        let (starts_at, ends_at) = resolve_session_times(&event, &data.session)?;
+        let session_id = Uuid::new_v4();
+        if let Some(capacity) = data.session.capacity {
+            // Day passes and whole-event tickets admit to new sessions too
+            let tickets = event.tickets_admitted_to_session(session_id, &starts_at, None);
+            if tickets > i64::from(capacity) {
+                return Err(CreateEventSessionUseCaseError::CapacityBelowTickets(
+                    tickets,
+                ));
+            }
+        }
+
+        let now = Utc::now();
+        let session = EventSession::new(
+            session_id,
+            data.event_id,
+            data.session.title,
+            data.session.stage,
+            starts_at,
+            ends_at,
+            data.session.capacity,
+            now,
+            now,
+        );
+
+        self.repository
+            .create_session(&session)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => CreateEventSessionUseCaseError::EventNotFound,
+                DomainError::DatabaseError(msg) => {
+                    CreateEventSessionUseCaseError::DatabaseError(msg)
+                }
+            })
+    }
+}
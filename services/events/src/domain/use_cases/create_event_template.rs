@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use super::event_copy::{copy_media, discard_media};
+use crate::domain::{
+    entities::{EventBlueprint, EventTemplate},
+    DomainError, EventRepository, EventTemplateRepository, ObjectStorage, UsersClient,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateEventTemplateParams {
+    /// Event whose current setup is saved.
+    pub event_id: Uuid,
+    pub name: String,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateEventTemplateUseCaseError {
+    #[error("Event not found")]
+    EventNotFound,
+    #[error("Only the organizer or the organization's owners and managers can save this event as a template")]
+    Forbidden,
+    #[error("Storage error: {0}")]
+    StorageError(String),
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Saves an event's setup as a template of the event's organization, or of
+/// the caller for events outside one. The template keeps its own copies of
+/// the event's media, so later changes to the event don't affect it.
+pub struct CreateEventTemplateUseCase {
+    repository: Arc<dyn EventRepository>,
+    template_repository: Arc<dyn EventTemplateRepository>,
+    storage: Arc<dyn ObjectStorage>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl CreateEventTemplateUseCase {
+    pub fn new(
+        repository: Arc<dyn EventRepository>,
+        template_repository: Arc<dyn EventTemplateRepository>,
+        storage: Arc<dyn ObjectStorage>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            repository,
+            template_repository,
+            storage,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<CreateEventTemplateParams, Result<EventTemplate, CreateEventTemplateUseCaseError>>
+    for CreateEventTemplateUseCase
+{
+    async fn execute(
+        &self,
+        data: CreateEventTemplateParams,
+    ) -> Result<EventTemplate, CreateEventTemplateUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => CreateEventTemplateUseCaseError::EventNotFound,
+                DomainError::DatabaseError(msg) => {
+                    CreateEventTemplateUseCaseError::DatabaseError(msg)
+                }
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| CreateEventTemplateUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(CreateEventTemplateUseCaseError::Forbidden);
+        }
+
+        let id = Uuid::new_v4();
+        let mut blueprint = EventBlueprint::of(&event);
+        blueprint.media = copy_media(
+            self.storage.as_ref(),
+            &blueprint.media,
+            &format!("templates/{}", id),
+        )
+        .await
+        .map_err(|e| CreateEventTemplateUseCaseError::StorageError(e.to_string()))?
+        .into_iter()
+        .map(|(_, copy)| copy)
+        .collect();
+
+        let template = EventTemplate::new(
+            id,
+            data.name,
+            data.requested_by,
+            event.organization_id(),
+            blueprint,
+            Utc::now(),
+        );
+        if let Err(e) = self.template_repository.create(&template).await {
+            discard_media(self.storage.as_ref(), template.blueprint().media.iter()).await;
+            return Err(CreateEventTemplateUseCaseError::DatabaseError(
+                e.to_string(),
+            ));
+        }
+
+        Ok(template)
+    }
+}
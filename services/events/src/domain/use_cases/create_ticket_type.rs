@@ -0,0 +1,241 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use crate::domain::{
+    entities::{Admission, AdmissionError, TicketTypeVisibility},
+    DomainError, DomainResult, Event, EventRepository, TicketType, UsersClient, VenueRepository,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketTypeParams {
+    pub name: String,
+    pub price_minor: i64,
+    pub currency: String,
+    pub quantity: i32,
+    pub min_per_order: i32,
+    pub max_per_order: i32,
+    pub sales_start: Option<DateTime<Utc>>,
+    pub sales_end: Option<DateTime<Utc>>,
+    pub visibility: TicketTypeVisibility,
+    pub admission: Admission,
+    /// Seating-chart section of the event's venue the ticket type sells.
+    pub section_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTicketTypeParams {
+    pub event_id: Uuid,
+    pub ticket_type: TicketTypeParams,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateTicketTypeUseCaseError {
+    #[error("Event not found")]
+    EventNotFound,
+    #[error("Only the organizer or the organization's owners and managers can change this event")]
+    Forbidden,
+    #[error("Ticket sales must end before the event, day or session they admit to starts")]
+    SalesEndAfterEvent,
+    #[error("{0}")]
+    InvalidAdmission(#[from] AdmissionError),
+    #[error("Section is not part of the event venue's seating chart")]
+    UnknownSection,
+    #[error("The event's venue has a seating chart, so the ticket type must name a section")]
+    SectionRequired,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Whether sales of `ticket_type`, if they end at all, end by the time the
+/// event, day or session it admits to starts.
+pub(super) fn sales_end_in_time(event: &Event, ticket_type: &TicketTypeParams) -> bool {
+    match (
+        ticket_type.sales_end,
+        event.admission_starts_at(ticket_type.admission),
+    ) {
+        (Some(sales_end), Some(admission_starts_at)) => sales_end <= admission_starts_at,
+        _ => true,
+    }
+}
+
+/// Why a ticket type's section doesn't fit the event's venue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SectionMismatch {
+    /// Not a section of the venue's seating chart.
+    Unknown,
+    /// The venue has a seating chart but no section was given. Such a
+    /// ticket type could pick seats in every section, the best included.
+    Required,
+}
+
+/// Checks `section_id` against the seating chart of the event's venue.
+pub(super) async fn check_section(
+    venues: &dyn VenueRepository,
+    event: &Event,
+    section_id: Option<Uuid>,
+) -> DomainResult<Option<SectionMismatch>> {
+    let chart = match event.venue_id() {
+        Some(venue_id) => match venues.find_seating_chart(venue_id).await {
+            Ok(chart) => Some(chart),
+            Err(DomainError::NotFound(_)) => None,
+            Err(e) => return Err(e),
+        },
+        None => None,
+    };
+    let sections = chart.as_ref().map(|chart| chart.sections()).unwrap_or(&[]);
+
+    Ok(match section_id {
+        None if !sections.is_empty() => Some(SectionMismatch::Required),
+        None => None,
+        Some(section_id) if sections.iter().any(|section| section.id() == section_id) => None,
+        Some(_) => Some(SectionMismatch::Unknown),
+    })
+}
+
+pub struct CreateTicketTypeUseCase {
+    repository: Arc<dyn EventRepository>,
+    venues: Arc<dyn VenueRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl CreateTicketTypeUseCase {
+    pub fn new(
+        repository: Arc<dyn EventRepository>,
+        venues: Arc<dyn VenueRepository>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            repository,
+            venues,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<CreateTicketTypeParams, Result<TicketType, CreateTicketTypeUseCaseError>>
+    for CreateTicketTypeUseCase
+{
+    async fn execute(
+        &self,
+        data: CreateTicketTypeParams,
+    ) -> Result<TicketType, CreateTicketTypeUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => CreateTicketTypeUseCaseError::EventNotFound,
+                DomainError::DatabaseError(msg) => CreateTicketTypeUseCaseError::DatabaseError(msg),
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| CreateTicketTypeUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(CreateTicketTypeUseCaseError::Forbidden);
+        }
+
+        let ticket_type = &data.ticket_type;
+        event.check_admission(ticket_type.admission, ticket_type.quantity, None)?;
+        if !sales_end_in_time(&event, ticket_type) {
+            return Err(CreateTicketTypeUseCaseError::SalesEndAfterEvent);
+        }
+        match check_section(self.venues.as_ref(), &event, ticket_type.section_id)
+            .await
+            .map_err(|e| CreateTicketTypeUseCaseError::DatabaseError(e.to_string()))?
+        {
+            None => {}
+            Some(SectionMismatch::Unknown) => {
+                return Err(CreateTicketTypeUseCaseError::UnknownSection)
+            }
+            Some(SectionMismatch::Required) => {
+                return Err(CreateTicketTypeUseCaseError::SectionRequired)
+            }
+        }
+
+        self.repository
+            .create_ticket_type(data.event_id, data.ticket_type)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => CreateTicketTypeUseCaseError::EventNotFound,
+                DomainError::DatabaseError(msg) => CreateTicketTypeUseCaseError::DatabaseError(msg),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{EventSchedule, EventSession};
+    use crate::domain::fixtures::EventFixture;
+    use chrono::{Duration, NaiveDate, TimeZone};
+    use chrono_tz::Tz;
+
+    #[test]
+    fn test_sales_end_by_the_start_of_what_the_ticket_admits_to() {
+        let at = |day, hour| Utc.with_ymd_and_hms(2026, 7, day, hour, 0, 0).unwrap();
+        let id = Uuid::new_v4();
+        let session = EventSession::new(
+            Uuid::new_v4(),
+            id,
+            "Closing concert".to_string(),
+            None,
+            at(18, 20),
+            at(18, 22),
+            None,
+            at(1, 0),
+            at(1, 0),
+        );
+        let event = EventFixture {
+            id,
+            schedule: EventSchedule::new(Tz::UTC, at(17, 10), Some(at(18, 23)), None),
+            ..EventFixture::at(at(17, 10))
+        }
+        .build()
+        .with_sessions(vec![session.clone()]);
+        let second_day = Admission::Day(NaiveDate::from_ymd_opt(2026, 7, 18).unwrap());
+        let ticket_type = |admission, sales_end| TicketTypeParams {
+            name: "Ticket".to_string(),
+            price_minor: 2500,
+            currency: "EUR".to_string(),
+            quantity: 100,
+            min_per_order: 1,
+            max_per_order: 10,
+            sales_start: None,
+            sales_end,
+            visibility: TicketTypeVisibility::Public,
+            admission,
+            section_id: None,
+        };
+
+        let cases = [
+            (Admission::Event, None, true),
+            (Admission::Event, Some(at(17, 10)), true),
+            (Admission::Event, Some(at(17, 11)), false),
+            (second_day, Some(at(17, 23)), true),
+            (second_day, Some(at(18, 1)), false),
+            (Admission::Session(session.id()), Some(at(18, 19)), true),
+            (
+                Admission::Session(session.id()),
+                Some(at(18, 20) + Duration::minutes(1)),
+                false,
+            ),
+        ];
+        for (admission, sales_end, expected) in cases {
+            assert_eq!(
+                sales_end_in_time(&event, &ticket_type(admission, sales_end)),
+                expected,
+                "{:?} ending {:?}",
+                admission,
+                sales_end
+            );
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_create_events;
+use crate::domain::{entities::GeoPoint, DomainError, UsersClient, Venue, VenueRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateVenueParams {
+    pub name: String,
+    pub timezone: Tz,
+    pub location: Option<GeoPoint>,
+    /// Organization the venue belongs to; the caller's own venue when not set.
+    pub organization_id: Option<Uuid>,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateVenueUseCaseError {
+    #[error("Only the organization's owners and managers can add venues for it")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Adds a venue owned by the caller, or by an organization the caller can
+/// create events for.
+pub struct CreateVenueUseCase {
+    repository: Arc<dyn VenueRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl CreateVenueUseCase {
+    pub fn new(repository: Arc<dyn VenueRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<CreateVenueParams, Result<Venue, CreateVenueUseCaseError>> for CreateVenueUseCase {
+    async fn execute(&self, data: CreateVenueParams) -> Result<Venue, CreateVenueUseCaseError> {
+        if let Some(organization_id) = data.organization_id {
+            if !can_create_events(self.users.as_ref(), organization_id, data.requested_by)
+                .await
+                .map_err(|e| CreateVenueUseCaseError::DatabaseError(e.to_string()))?
+            {
+                return Err(CreateVenueUseCaseError::Forbidden);
+            }
+        }
+
+        self.repository.create(data).await.map_err(|e| match e {
+            DomainError::DatabaseError(msg) => CreateVenueUseCaseError::DatabaseError(msg),
+            _ => {
+                log::error!("Unexpected error in CreateVenueUseCase: {:?}", e);
+                CreateVenueUseCaseError::DatabaseError("Unexpected error".to_string())
+            }
+        })
+    }
+}
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{entities::Administrators, CategoryRepository, DomainError};
+
+#[derive(Debug, Clone)]
+pub struct DeleteCategoryParams {
+    pub id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteCategoryUseCaseError {
+    #[error("Category not found")]
+    NotFound,
+    #[error("Category has subcategories; move or delete them first")]
+    HasSubcategories,
+    #[error("Events are still filed under this category; move them first")]
+    InUse,
+    #[error("Only administrators can change categories")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct DeleteCategoryUseCase {
+    repository: Arc<dyn CategoryRepository>,
+    admins: Administrators,
+}
+
+impl DeleteCategoryUseCase {
+    pub fn new(repository: Arc<dyn CategoryRepository>, admins: Administrators) -> Self {
+        Self { repository, admins }
+    }
+}
+
+#[async_trait]
+impl UseCase<DeleteCategoryParams, Result<(), DeleteCategoryUseCaseError>>
+    for DeleteCategoryUseCase
+{
+    async fn execute(&self, data: DeleteCategoryParams) -> Result<(), DeleteCategoryUseCaseError> {
+        if !self.admins.contains(data.requested_by) {
+            return Err(DeleteCategoryUseCaseError::Forbidden);
+        }
+
+        let id = data.id;
+        let taxonomy = self
+            .repository
+            .find_all()
+            .await
+            .map_err(|e| DeleteCategoryUseCaseError::DatabaseError(e.to_string()))?;
+
+        if taxonomy.get(id).is_none() {
+            return Err(DeleteCategoryUseCaseError::NotFound);
+        }
+        if taxonomy.children(id).next().is_some() {
+            return Err(DeleteCategoryUseCaseError::HasSubcategories);
+        }
+        // The foreign key refuses the delete as well; checking first gives
+        // the caller a conflict instead of a database error
+        if self
+            .repository
+            .is_in_use(id)
+            .await
+            .map_err(|e| DeleteCategoryUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(DeleteCategoryUseCaseError::InUse);
+        }
+
+        self.repository.delete(id).await.map_err(|e| match e {
+            DomainError::NotFound(_) => DeleteCategoryUseCaseError::NotFound,
+            DomainError::DatabaseError(msg) => DeleteCategoryUseCaseError::DatabaseError(msg),
+        })
+    }
+}
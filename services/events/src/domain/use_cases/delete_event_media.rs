@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use crate::domain::{DomainError, EventRepository, ObjectStorage, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteEventMediaParams {
+    pub event_id: Uuid,
+    pub media_id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteEventMediaUseCaseError {
+    #[error("Media not found")]
+    NotFound,
+    #[error("Only the organizer or the organization's owners and managers can change this event")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct DeleteEventMediaUseCase {
+    repository: Arc<dyn EventRepository>,
+    storage: Arc<dyn ObjectStorage>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl DeleteEventMediaUseCase {
+    pub fn new(
+        repository: Arc<dyn EventRepository>,
+        storage: Arc<dyn ObjectStorage>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            repository,
+            storage,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<DeleteEventMediaParams, Result<(), DeleteEventMediaUseCaseError>>
+    for DeleteEventMediaUseCase
+{
+    async fn execute(
+        &self,
+        data: DeleteEventMediaParams,
+    ) -> Result<(), DeleteEventMediaUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => DeleteEventMediaUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => DeleteEventMediaUseCaseError::DatabaseError(msg),
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| DeleteEventMediaUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(DeleteEventMediaUseCaseError::Forbidden);
+        }
+
+        let asset = self
+            .repository
+            .delete_media(data.event_id, data.media_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => DeleteEventMediaUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => DeleteEventMediaUseCaseError::DatabaseError(msg),
+            })?;
+
+        // The row is gone, so a failed delete only leaves an orphaned object
+        for key in asset.storage_keys() {
+            if let Err(e) = self.storage.delete(key).await {
+                log::warn!("Failed to delete media object `{}`: {}", key, e);
+            }
+        }
+
+        Ok(())
+    }
+}
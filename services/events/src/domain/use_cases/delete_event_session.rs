@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use crate::domain::{entities::Admission, DomainError, EventRepository, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteEventSessionParams {
+    pub event_id: Uuid,
+    pub session_id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteEventSessionUseCaseError {
+    #[error("Session not found")]
+    NotFound,
+    #[error("Only the organizer or the organization's owners and managers can change this event")]
+    Forbidden,
+    #[error("Session still has ticket types; delete or change them first")]
+    HasTicketTypes,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct DeleteEventSessionUseCase {
+    repository: Arc<dyn EventRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl DeleteEventSessionUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<DeleteEventSessionParams, Result<(), DeleteEventSessionUseCaseError>>
+    for DeleteEventSessionUseCase
+{
+    async fn execute(
+        &self,
+        data: DeleteEventSessionParams,
+    ) -> Result<(), DeleteEventSessionUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => DeleteEventSessionUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => {
+                    DeleteEventSessionUseCaseError::DatabaseError(msg)
+                }
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| DeleteEventSessionUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(DeleteEventSessionUseCaseError::Forbidden);
+        }
+
+        if event
+            .ticket_types()
+            .iter()
+            .any(|ticket_type| ticket_type.admission() == Admission::Session(data.session_id))
+        {
+            return Err(DeleteEventSessionUseCaseError::HasTicketTypes);
+        }
+
+        self.repository
+            .delete_session(data.event_id, data.session_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => DeleteEventSessionUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => {
+                    DeleteEventSessionUseCaseError::DatabaseError(msg)
+                }
+            })
+    }
+}
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_use_template;
+use super::event_copy::discard_media;
+use crate::domain::{DomainError, EventTemplateRepository, ObjectStorage, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteEventTemplateParams {
+    pub id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteEventTemplateUseCaseError {
+    #[error("Event template not found")]
+    NotFound,
+    #[error("Only the organization's owners and managers, or the organizer who saved it, can delete this template")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Deletes a template and its copies of media. Events created from it are
+/// not affected.
+pub struct DeleteEventTemplateUseCase {
+    template_repository: Arc<dyn EventTemplateRepository>,
+    storage: Arc<dyn ObjectStorage>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl DeleteEventTemplateUseCase {
+    pub fn new(
+        template_repository: Arc<dyn EventTemplateRepository>,
+        storage: Arc<dyn ObjectStorage>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            template_repository,
+            storage,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<DeleteEventTemplateParams, Result<(), DeleteEventTemplateUseCaseError>>
+    for DeleteEventTemplateUseCase
+{
+    async fn execute(
+        &self,
+        data: DeleteEventTemplateParams,
+    ) -> Result<(), DeleteEventTemplateUseCaseError> {
+        let map_error = |e: DomainError| match e {
+            DomainError::NotFound(_) => DeleteEventTemplateUseCaseError::NotFound,
+            DomainError::DatabaseError(msg) => DeleteEventTemplateUseCaseError::DatabaseError(msg),
+        };
+
+        let template = self
+            .template_repository
+            .find_by_id(data.id)
+            .await
+            .map_err(map_error)?;
+        if !can_use_template(self.users.as_ref(), &template, data.requested_by)
+            .await
+            .map_err(|e| DeleteEventTemplateUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(DeleteEventTemplateUseCaseError::Forbidden);
+        }
+
+        let deleted = self
+            .template_repository
+            .delete(data.id)
+            .await
+            .map_err(map_error)?;
+        discard_media(self.storage.as_ref(), deleted.blueprint().media.iter()).await;
+
+        Ok(())
+    }
+}
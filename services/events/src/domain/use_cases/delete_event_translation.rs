@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use crate::domain::{entities::Locale, DomainError, EventRepository, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteEventTranslationParams {
+    pub event_id: Uuid,
+    pub locale: Locale,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteEventTranslationUseCaseError {
+    #[error("Event not found")]
+    EventNotFound,
+    #[error("Translation not found")]
+    NotFound,
+    #[error("Only the organizer or the organization's owners and managers can change this event")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct DeleteEventTranslationUseCase {
+    repository: Arc<dyn EventRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl DeleteEventTranslationUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<DeleteEventTranslationParams, Result<(), DeleteEventTranslationUseCaseError>>
+    for DeleteEventTranslationUseCase
+{
+    async fn execute(
+        &self,
+        data: DeleteEventTranslationParams,
+    ) -> Result<(), DeleteEventTranslationUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => DeleteEventTranslationUseCaseError::EventNotFound,
+                DomainError::DatabaseError(msg) => {
+                    DeleteEventTranslationUseCaseError::DatabaseError(msg)
+                }
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| DeleteEventTranslationUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(DeleteEventTranslationUseCaseError::Forbidden);
+        }
+
+        self.repository
+            .delete_translation(data.event_id, &data.locale)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => DeleteEventTranslationUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => {
+                    DeleteEventTranslationUseCaseError::DatabaseError(msg)
+                }
+            })
+    }
+}
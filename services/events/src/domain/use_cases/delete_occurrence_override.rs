@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage_series;
+use crate::domain::{DomainError, EventSeriesRepository, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteOccurrenceOverrideParams {
+    pub series_id: Uuid,
+    pub occurrence_start: DateTime<Utc>,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteOccurrenceOverrideUseCaseError {
+    #[error("Event series not found")]
+    SeriesNotFound,
+    #[error("Occurrence override not found")]
+    NotFound,
+    #[error("Only the series' organizer or its organization's owners and managers can change its occurrences")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct DeleteOccurrenceOverrideUseCase {
+    repository: Arc<dyn EventSeriesRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl DeleteOccurrenceOverrideUseCase {
+    pub fn new(repository: Arc<dyn EventSeriesRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<DeleteOccurrenceOverrideParams, Result<(), DeleteOccurrenceOverrideUseCaseError>>
+    for DeleteOccurrenceOverrideUseCase
+{
+    async fn execute(
+        &self,
+        data: DeleteOccurrenceOverrideParams,
+    ) -> Result<(), DeleteOccurrenceOverrideUseCaseError> {
+        let series = self
+            .repository
+            .find_by_id(data.series_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => DeleteOccurrenceOverrideUseCaseError::SeriesNotFound,
+                DomainError::DatabaseError(msg) => {
+                    DeleteOccurrenceOverrideUseCaseError::DatabaseError(msg)
+                }
+            })?;
+
+        if !can_manage_series(self.users.as_ref(), &series, data.requested_by)
+            .await
+            .map_err(|e| DeleteOccurrenceOverrideUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(DeleteOccurrenceOverrideUseCaseError::Forbidden);
+        }
+
+        self.repository
+            .delete_override(data.series_id, data.occurrence_start)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => DeleteOccurrenceOverrideUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => {
+                    DeleteOccurrenceOverrideUseCaseError::DatabaseError(msg)
+                }
+            })
+    }
+}
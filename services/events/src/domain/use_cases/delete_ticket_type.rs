@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use crate::domain::{DomainError, EventRepository, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteTicketTypeParams {
+    pub event_id: Uuid,
+    pub ticket_type_id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteTicketTypeUseCaseError {
+    #[error("Ticket type not found")]
+    NotFound,
+    #[error("Only the organizer or the organization's owners and managers can change this event")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct DeleteTicketTypeUseCase {
+    repository: Arc<dyn EventRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl DeleteTicketTypeUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<DeleteTicketTypeParams, Result<(), DeleteTicketTypeUseCaseError>>
+    for DeleteTicketTypeUseCase
+{
+    async fn execute(
+        &self,
+        data: DeleteTicketTypeParams,
+    ) -> Result<(), DeleteTicketTypeUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => DeleteTicketTypeUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => DeleteTicketTypeUseCaseError::DatabaseError(msg),
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| DeleteTicketTypeUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(DeleteTicketTypeUseCaseError::Forbidden);
+        }
+
+        self.repository
+            .delete_ticket_type(data.event_id, data.ticket_type_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => DeleteTicketTypeUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => DeleteTicketTypeUseCaseError::DatabaseError(msg),
+            })
+    }
+}
@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use chrono::Duration;
+use shared::UseCase;
+use std::sync::Arc;
+
+use crate::domain::{DomainEventPublisher, OutboxRepository};
+
+/// How long a claimed message is left alone before another dispatcher may
+/// retry it; well above the publisher's request timeout.
+const CLAIM_LEASE_SECONDS: i64 = 60;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DispatchDomainEventsUseCaseError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Publishes up to the given number of pending outbox messages and returns
+/// how many were delivered. Failed deliveries stay claimed until their lease
+/// runs out and are retried by a later run.
+pub struct DispatchDomainEventsUseCase {
+    outbox: Arc<dyn OutboxRepository>,
+    publisher: Arc<dyn DomainEventPublisher>,
+}
+
+impl DispatchDomainEventsUseCase {
+    pub fn new(
+        outbox: Arc<dyn OutboxRepository>,
+        publisher: Arc<dyn DomainEventPublisher>,
+    ) -> Self {
+        Self { outbox, publisher }
+    }
+}
+
+#[async_trait]
+impl UseCase<u64, Result<usize, DispatchDomainEventsUseCaseError>> for DispatchDomainEventsUseCase {
+    async fn execute(&self, limit: u64) -> Result<usize, DispatchDomainEventsUseCaseError> {
+        let messages = self
+            .outbox
+            .claim_pending(limit, Duration::seconds(CLAIM_LEASE_SECONDS))
+            .await
+            .map_err(|e| DispatchDomainEventsUseCaseError::DatabaseError(e.to_string()))?;
+
+        let mut dispatched = 0;
+        for message in messages {
+            if let Err(e) = self.publisher.publish(&message).await {
+                log::warn!("Failed to dispatch domain event {}: {}", message.id(), e);
+                continue;
+            }
+            self.outbox
+                .mark_dispatched(message.id())
+                .await
+                .map_err(|e| DispatchDomainEventsUseCaseError::DatabaseError(e.to_string()))?;
+            dispatched += 1;
+        }
+
+        Ok(dispatched)
+    }
+}
@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use super::event_copy::{EventCopier, EventCopyError, EventCopyOverrides};
+use crate::domain::{
+    entities::{EventBlueprint, ScheduleError, ScheduleTime},
+    CategoryRepository, DomainError, Event, EventRepository, ObjectStorage, UsersClient,
+    VenueRepository,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateEventParams {
+    pub event_id: Uuid,
+    /// Start of the copy; `None` keeps the original's.
+    pub starts_at: Option<ScheduleTime>,
+    pub overrides: EventCopyOverrides,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DuplicateEventUseCaseError {
+    #[error("Event not found")]
+    NotFound,
+    #[error(
+        "Only the organizer or the organization's owners and managers can duplicate this event"
+    )]
+    Forbidden,
+    #[error("{0}")]
+    InvalidSchedule(ScheduleError),
+    #[error("Event date must be in the future")]
+    EventDateInPast,
+    #[error("Venue not found")]
+    VenueNotFound,
+    #[error("Storage error: {0}")]
+    StorageError(String),
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+impl From<EventCopyError> for DuplicateEventUseCaseError {
+    fn from(e: EventCopyError) -> Self {
+        match e {
+            EventCopyError::InvalidSchedule(e) => Self::InvalidSchedule(e),
+            EventCopyError::EventDateInPast => Self::EventDateInPast,
+            EventCopyError::VenueNotFound => Self::VenueNotFound,
+            EventCopyError::StorageError(msg) => Self::StorageError(msg),
+            EventCopyError::DatabaseError(msg) => Self::DatabaseError(msg),
+        }
+    }
+}
+
+/// Copies an event with its ticket types, categories, tags and media into a
+/// new unpublished event owned by the caller.
+pub struct DuplicateEventUseCase {
+    repository: Arc<dyn EventRepository>,
+    venue_repository: Arc<dyn VenueRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    storage: Arc<dyn ObjectStorage>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl DuplicateEventUseCase {
+    pub fn new(
+        repository: Arc<dyn EventRepository>,
+        venue_repository: Arc<dyn VenueRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+        storage: Arc<dyn ObjectStorage>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            repository,
+            venue_repository,
+            category_repository,
+            storage,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<DuplicateEventParams, Result<Event, DuplicateEventUseCaseError>>
+    for DuplicateEventUseCase
+{
+    async fn execute(
+        &self,
+        data: DuplicateEventParams,
+    ) -> Result<Event, DuplicateEventUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => DuplicateEventUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => DuplicateEventUseCaseError::DatabaseError(msg),
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| DuplicateEventUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(DuplicateEventUseCaseError::Forbidden);
+        }
+
+        let copier = EventCopier {
+            events: self.repository.as_ref(),
+            venues: self.venue_repository.as_ref(),
+            categories: self.category_repository.as_ref(),
+            storage: self.storage.as_ref(),
+        };
+        Ok(copier
+            .create_draft(
+                &EventBlueprint::of(&event),
+                data.starts_at
+                    .unwrap_or(ScheduleTime::Instant(*event.event_date())),
+                data.overrides,
+                data.requested_by,
+                event.organization_id(),
+            )
+            .await?)
+    }
+}
@@ -0,0 +1,201 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::EventTemplate, DomainResult, Event, EventSeries, UsersClient, Venue,
+};
+
+/// Whether the user may create events for the organization: its owners and
+/// managers.
+pub(super) async fn can_create_events(
+    users: &dyn UsersClient,
+    organization_id: Uuid,
+    user_id: Uuid,
+) -> DomainResult<bool> {
+    Ok(users
+        .find_organization_role(organization_id, user_id)
+        .await?
+        .is_some_and(|role| role.can_manage_events()))
+}
+
+/// Whether the user may change the event. Events of an organization can be
+/// changed by its owners and managers; events without one only by their
+/// organizer. Events with neither predate ownership and cannot be changed
+/// through the API.
+pub(super) async fn can_manage(
+    users: &dyn UsersClient,
+    event: &Event,
+    user_id: Uuid,
+) -> DomainResult<bool> {
+    match event.organization_id() {
+        Some(organization_id) => Ok(users
+            .find_organization_role(organization_id, user_id)
+            .await?
+            .is_some_and(|role| role.can_manage_events())),
+        None => Ok(event.organizer_id() == Some(user_id)),
+    }
+}
+
+/// Whether the user may see the event's internals, such as its change
+/// history: anyone who can manage it, plus the organization's box office
+/// staff and viewers.
+pub(super) async fn can_view_internals(
+    users: &dyn UsersClient,
+    event: &Event,
+    user_id: Uuid,
+) -> DomainResult<bool> {
+    match event.organization_id() {
+        Some(organization_id) => Ok(users
+            .find_organization_role(organization_id, user_id)
+            .await?
+            .is_some()),
+        None => Ok(event.organizer_id() == Some(user_id)),
+    }
+}
+
+/// Whether the user may use, change or delete the template: the owners and
+/// managers of its organization, or the organizer who saved a template
+/// outside any organization.
+pub(super) async fn can_use_template(
+    users: &dyn UsersClient,
+    template: &EventTemplate,
+    user_id: Uuid,
+) -> DomainResult<bool> {
+    match template.organization_id() {
+        Some(organization_id) => Ok(users
+            .find_organization_role(organization_id, user_id)
+            .await?
+            .is_some_and(|role| role.can_manage_events())),
+        None => Ok(template.organizer_id() == user_id),
+    }
+}
+
+/// Whether the user may change the venue and its seating chart, under the
+/// same rules as its events. Venues created before venues had owners cannot
+/// be changed through the API.
+pub(super) async fn can_manage_venue(
+    users: &dyn UsersClient,
+    venue: &Venue,
+    user_id: Uuid,
+) -> DomainResult<bool> {
+    match venue.organization_id() {
+        Some(organization_id) => can_create_events(users, organization_id, user_id).await,
+        None => Ok(venue.organizer_id() == Some(user_id)),
+    }
+}
+
+/// Whether the user may change the series and override its occurrences,
+/// under the same rules as single events.
+pub(super) async fn can_manage_series(
+    users: &dyn UsersClient,
+    series: &EventSeries,
+    user_id: Uuid,
+) -> DomainResult<bool> {
+    match series.organization_id() {
+        Some(organization_id) => can_create_events(users, organization_id, user_id).await,
+        None => Ok(series.organizer_id() == Some(user_id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::OrganizationRole;
+    use crate::domain::fixtures::EventFixture;
+    use async_trait::async_trait;
+    use chrono::{TimeZone, Utc};
+    use std::collections::HashMap;
+
+    /// Organization roles by organization and user.
+    struct StubUsers(HashMap<(Uuid, Uuid), OrganizationRole>);
+
+    #[async_trait]
+    impl UsersClient for StubUsers {
+        async fn user_exists(&self, _id: Uuid) -> DomainResult<bool> {
+            Ok(true)
+        }
+
+        async fn find_organization_role(
+            &self,
+            organization_id: Uuid,
+            user_id: Uuid,
+        ) -> DomainResult<Option<OrganizationRole>> {
+            Ok(self.0.get(&(organization_id, user_id)).copied())
+        }
+    }
+
+    fn event(organizer_id: Option<Uuid>, organization_id: Option<Uuid>) -> Event {
+        EventFixture {
+            organizer_id,
+            organization_id,
+            ..EventFixture::at(Utc.with_ymd_and_hms(2026, 11, 5, 20, 0, 0).unwrap())
+        }
+        .build()
+    }
+
+    #[tokio::test]
+    async fn test_only_the_organizer_manages_events_outside_organizations() {
+        let organizer = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let users = StubUsers(HashMap::new());
+        let owned = event(Some(organizer), None);
+
+        assert!(can_manage(&users, &owned, organizer).await.unwrap());
+        assert!(!can_manage(&users, &owned, other).await.unwrap());
+        assert!(can_view_internals(&users, &owned, organizer).await.unwrap());
+        assert!(!can_view_internals(&users, &owned, other).await.unwrap());
+
+        // Events from before ownership belong to nobody.
+        let unowned = event(None, None);
+        assert!(!can_manage(&users, &unowned, organizer).await.unwrap());
+        assert!(!can_view_internals(&users, &unowned, organizer)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_organization_roles_decide_access_to_its_events() {
+        let organization = Uuid::new_v4();
+        let organizer = Uuid::new_v4();
+        let outsider = Uuid::new_v4();
+        let roles = [
+            OrganizationRole::Owner,
+            OrganizationRole::Manager,
+            OrganizationRole::BoxOffice,
+            OrganizationRole::Viewer,
+        ]
+        .map(|role| (Uuid::new_v4(), role));
+        let users = StubUsers(
+            roles
+                .iter()
+                .map(|(user, role)| ((organization, *user), *role))
+                .collect(),
+        );
+        let event = event(Some(organizer), Some(organization));
+
+        for (user, role) in roles {
+            let manages = matches!(role, OrganizationRole::Owner | OrganizationRole::Manager);
+            assert_eq!(
+                can_create_events(&users, organization, user).await.unwrap(),
+                manages,
+                "{:?}",
+                role
+            );
+            assert_eq!(
+                can_manage(&users, &event, user).await.unwrap(),
+                manages,
+                "{:?}",
+                role
+            );
+            assert!(can_view_internals(&users, &event, user).await.unwrap());
+        }
+
+        // The organization decides, so an organizer who left it loses access.
+        assert!(!can_manage(&users, &event, organizer).await.unwrap());
+        assert!(!can_view_internals(&users, &event, organizer).await.unwrap());
+        assert!(!can_create_events(&users, organization, outsider)
+            .await
+            .unwrap());
+        assert!(!can_manage(&users, &event, outsider).await.unwrap());
+        assert!(!can_view_internals(&users, &event, outsider).await.unwrap());
+    }
+}
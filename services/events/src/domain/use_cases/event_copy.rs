@@ -0,0 +1,642 @@
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{CreateEventParams, TicketTypeParams};
+use crate::domain::{
+    entities::{
+        Admission, AdmissionBlueprint, EventBlueprint, EventSchedule, EventSession,
+        EventTranslation, MediaAsset, MediaBlueprint, RichText, ScheduleEnd, ScheduleError,
+        ScheduleTime, Venue,
+    },
+    CategoryRepository, DomainError, Event, EventRepository, ObjectStorage, VenueRepository,
+};
+
+/// Changes to make when creating an event from a blueprint; anything left
+/// out is taken from the blueprint. A new venue also brings its name and
+/// timezone unless those are given too.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventCopyOverrides {
+    pub title: Option<String>,
+    pub timezone: Option<Tz>,
+    pub venue_name: Option<String>,
+    pub venue_id: Option<Uuid>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(super) enum EventCopyError {
+    #[error("{0}")]
+    InvalidSchedule(#[from] ScheduleError),
+    #[error("Event date must be in the future")]
+    EventDateInPast,
+    #[error("Venue not found")]
+    VenueNotFound,
+    #[error("Storage error: {0}")]
+    StorageError(String),
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Copies the stored files of `media` under `prefix`, giving each copy a
+/// new id. Nothing is left behind when a copy fails.
+pub(super) async fn copy_media(
+    storage: &dyn ObjectStorage,
+    media: &[MediaBlueprint],
+    prefix: &str,
+) -> Result<Vec<(Uuid, MediaBlueprint)>, EventCopyError> {
+    let mut copies: Vec<(Uuid, MediaBlueprint)> = Vec::with_capacity(media.len());
+    for item in media {
+        let id = Uuid::new_v4();
+        let storage_key = format!("{}/{}.{}", prefix, id, item.format.extension());
+        let thumbnail_key = item
+            .thumbnail_key
+            .as_ref()
+            .map(|_| format!("{}/{}_thumb.jpg", prefix, id));
+        let copy = MediaBlueprint {
+            url: storage.url(&storage_key),
+            thumbnail_url: thumbnail_key.as_deref().map(|key| storage.url(key)),
+            storage_key,
+            thumbnail_key,
+            ..item.clone()
+        };
+
+        let mut copied = storage.copy(&item.storage_key, &copy.storage_key).await;
+        if let (Ok(()), Some(from), Some(to)) = (&copied, &item.thumbnail_key, &copy.thumbnail_key)
+        {
+            copied = storage.copy(from, to).await;
+        }
+        if let Err(e) = copied {
+            discard_media(storage, copies.iter().map(|(_, copy)| copy).chain([&copy])).await;
+            return Err(EventCopyError::StorageError(e.to_string()));
+        }
+        copies.push((id, copy));
+    }
+    Ok(copies)
+}
+
+/// Best-effort removal of stored files that are no longer referenced.
+pub(super) async fn discard_media<'a>(
+    storage: &dyn ObjectStorage,
+    media: impl Iterator<Item = &'a MediaBlueprint>,
+) {
+    for key in media.flat_map(MediaBlueprint::storage_keys) {
+        if let Err(e) = storage.delete(key).await {
+            log::warn!("Failed to delete media object `{}`: {}", key, e);
+        }
+    }
+}
+
+/// Creates unpublished events from blueprints, with their own copies of
+/// the blueprint's media.
+pub(super) struct EventCopier<'a> {
+    pub events: &'a dyn EventRepository,
+    pub venues: &'a dyn VenueRepository,
+    pub categories: &'a dyn CategoryRepository,
+    pub storage: &'a dyn ObjectStorage,
+}
+
+impl EventCopier<'_> {
+    /// Creates a draft from `blueprint` starting at `starts_at`, owned by
+    /// `organizer_id` and `organization_id`. Categories deleted since the
+    /// blueprint was taken are left out.
+    pub async fn create_draft(
+        &self,
+        blueprint: &EventBlueprint,
+        starts_at: ScheduleTime,
+        overrides: EventCopyOverrides,
+        organizer_id: Uuid,
+        organization_id: Option<Uuid>,
+    ) -> Result<Event, EventCopyError> {
+        let venue = match overrides.venue_id {
+            Some(venue_id) => Some(self.venues.find_by_id(venue_id).await.map_err(
+                |e| match e {
+                    DomainError::NotFound(_) => EventCopyError::VenueNotFound,
+                    DomainError::DatabaseError(msg) => EventCopyError::DatabaseError(msg),
+                },
+            )?),
+            None => None,
+        };
+
+        let taxonomy = self
+            .categories
+            .find_all()
+            .await
+            .map_err(|e| EventCopyError::DatabaseError(e.to_string()))?;
+        let category_ids = blueprint
+            .category_ids
+            .iter()
+            .copied()
+            .filter(|id| taxonomy.get(*id).is_some())
+            .collect();
+
+        let event_id = Uuid::new_v4();
+        let now = Utc::now();
+        let draft = plan_draft(
+            event_id,
+            blueprint,
+            starts_at,
+            overrides,
+            venue.as_ref(),
+            category_ids,
+            organizer_id,
+            organization_id,
+            now,
+        )?;
+
+        let copies = copy_media(
+            self.storage,
+            &blueprint.media,
+            &format!("events/{}", event_id),
+        )
+        .await?;
+        let media = copies
+            .iter()
+            .map(|(id, copy)| {
+                MediaAsset::new(
+                    *id,
+                    event_id,
+                    copy.kind,
+                    copy.format,
+                    copy.size_bytes,
+                    copy.width,
+                    copy.height,
+                    copy.storage_key.clone(),
+                    copy.thumbnail_key.clone(),
+                    copy.url.clone(),
+                    copy.thumbnail_url.clone(),
+                    now,
+                )
+            })
+            .collect();
+
+        match self
+            .events
+            .create_draft(
+                event_id,
+                draft.data,
+                draft.schedule,
+                draft.sessions,
+                draft.translations,
+                draft.ticket_types,
+                media,
+            )
+            .await
+        {
+            Ok(event) => Ok(event),
+            Err(e) => {
+                discard_media(self.storage, copies.iter().map(|(_, copy)| copy)).await;
+                Err(EventCopyError::DatabaseError(e.to_string()))
+            }
+        }
+    }
+}
+
+/// Everything a draft is stored with apart from its media.
+struct DraftPlan {
+    data: CreateEventParams,
+    schedule: EventSchedule,
+    sessions: Vec<EventSession>,
+    translations: Vec<EventTranslation>,
+    ticket_types: Vec<TicketTypeParams>,
+}
+
+/// Lays out the draft `event_id` of `blueprint`, moving every time kept
+/// relative to the start onto `starts_at`. `venue` is the venue named by
+/// the overrides, if any.
+#[allow(clippy::too_many_arguments)]
+fn plan_draft(
+    event_id: Uuid,
+    blueprint: &EventBlueprint,
+    starts_at: ScheduleTime,
+    overrides: EventCopyOverrides,
+    venue: Option<&Venue>,
+    category_ids: Vec<Uuid>,
+    organizer_id: Uuid,
+    organization_id: Option<Uuid>,
+    now: DateTime<Utc>,
+) -> Result<DraftPlan, EventCopyError> {
+    let timezone = overrides
+        .timezone
+        .or(venue.map(Venue::timezone))
+        .unwrap_or(blueprint.timezone);
+    let venue_name = overrides
+        .venue_name
+        .or(venue.map(|venue| venue.name().to_string()))
+        .unwrap_or_else(|| blueprint.venue_name.clone());
+
+    let end = blueprint
+        .duration_minutes
+        .map(|minutes| ScheduleEnd::After(Duration::minutes(minutes)));
+    let start = *EventSchedule::resolve(timezone, starts_at, None, None)?.starts_at();
+    let doors_open_at = blueprint
+        .doors_open_minutes_before
+        .map(|minutes| ScheduleTime::Instant(start - Duration::minutes(minutes)));
+    let schedule =
+        EventSchedule::resolve(timezone, ScheduleTime::Instant(start), end, doors_open_at)?;
+    if start < now {
+        return Err(EventCopyError::EventDateInPast);
+    }
+
+    let sessions: Vec<EventSession> = blueprint
+        .sessions
+        .iter()
+        .map(|session| {
+            let starts_at = start + Duration::minutes(session.starts_minutes_after);
+            EventSession::new(
+                Uuid::new_v4(),
+                event_id,
+                session.title.clone(),
+                session.stage.clone(),
+                starts_at,
+                starts_at + Duration::minutes(session.duration_minutes),
+                session.capacity,
+                now,
+                now,
+            )
+        })
+        .collect();
+    let translations = blueprint
+        .translations
+        .iter()
+        .map(|translation| {
+            EventTranslation::new(
+                event_id,
+                translation.locale.clone(),
+                translation.title.clone(),
+                RichText::from_markdown(&translation.description),
+                now,
+                now,
+            )
+        })
+        .collect();
+    let venue_id = venue.map(Venue::id);
+    let same_venue = venue_id.is_none_or(|venue_id| blueprint.venue_id == Some(venue_id));
+    let first_day = *schedule.local_days().start();
+    let ticket_types = blueprint
+        .ticket_types
+        .iter()
+        .map(|ticket_type| TicketTypeParams {
+            name: ticket_type.name.clone(),
+            price_minor: ticket_type.price_minor,
+            currency: ticket_type.currency.clone(),
+            quantity: ticket_type.quantity,
+            min_per_order: ticket_type.min_per_order,
+            max_per_order: ticket_type.max_per_order,
+            sales_start: ticket_type.sales_start(start),
+            sales_end: ticket_type.sales_end(start),
+            visibility: ticket_type.visibility,
+            admission: match ticket_type.admission {
+                AdmissionBlueprint::Event => Admission::Event,
+                AdmissionBlueprint::Day(days) => Admission::Day(first_day + Duration::days(days)),
+                AdmissionBlueprint::Session(index) => sessions
+                    .get(index)
+                    .map_or(Admission::Event, |session| Admission::Session(session.id())),
+            },
+            // Sections belong to the blueprint's venue's seating chart
+            section_id: ticket_type.section_id.filter(|_| same_venue),
+        })
+        .collect();
+
+    let data = CreateEventParams {
+        title: overrides.title.unwrap_or_else(|| blueprint.title.clone()),
+        description: RichText::from_markdown(&blueprint.description),
+        default_locale: blueprint.default_locale.clone(),
+        starts_at: ScheduleTime::Instant(start),
+        timezone: Some(timezone),
+        end,
+        doors_open_at,
+        venue_name,
+        venue_id: venue_id.or(blueprint.venue_id),
+        category_ids,
+        tags: blueprint.tags.clone(),
+        publish_at: None,
+        on_sale_at: None,
+        organizer_id: Some(organizer_id),
+        organization_id,
+        external_uid: None,
+    };
+
+    Ok(DraftPlan {
+        data,
+        schedule,
+        sessions,
+        translations,
+        ticket_types,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::{
+            MediaFormat, MediaKind, SessionBlueprint, TicketTypeBlueprint, TicketTypeVisibility,
+        },
+        DomainResult,
+    };
+    use async_trait::async_trait;
+    use chrono::{NaiveDate, TimeZone};
+    use std::sync::Mutex;
+
+    /// Records copies and deletions; copying `fail_from` fails.
+    #[derive(Default)]
+    struct RecordingStorage {
+        fail_from: Option<String>,
+        copies: Mutex<Vec<(String, String)>>,
+        deleted: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl ObjectStorage for RecordingStorage {
+        async fn put(&self, _key: &str, _bytes: Vec<u8>, _content_type: &str) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn copy(&self, from: &str, to: &str) -> DomainResult<()> {
+            if self.fail_from.as_deref() == Some(from) {
+                return Err(DomainError::DatabaseError("copy failed".to_string()));
+            }
+            self.copies
+                .lock()
+                .unwrap()
+                .push((from.to_string(), to.to_string()));
+            Ok(())
+        }
+
+        async fn delete(&self, key: &str) -> DomainResult<()> {
+            self.deleted.lock().unwrap().push(key.to_string());
+            Ok(())
+        }
+
+        fn url(&self, key: &str) -> String {
+            format!("https://cdn.test/{}", key)
+        }
+    }
+
+    fn media(storage_key: &str, thumbnail_key: Option<&str>) -> MediaBlueprint {
+        MediaBlueprint {
+            kind: MediaKind::Gallery,
+            format: MediaFormat::Png,
+            size_bytes: 1024,
+            width: Some(800),
+            height: Some(600),
+            storage_key: storage_key.to_string(),
+            thumbnail_key: thumbnail_key.map(str::to_string),
+            url: format!("https://cdn.test/{}", storage_key),
+            thumbnail_url: thumbnail_key.map(|key| format!("https://cdn.test/{}", key)),
+        }
+    }
+
+    fn ticket_type(name: &str, admission: AdmissionBlueprint) -> TicketTypeBlueprint {
+        TicketTypeBlueprint {
+            name: name.to_string(),
+            price_minor: 2500,
+            currency: "EUR".to_string(),
+            quantity: 100,
+            min_per_order: 1,
+            max_per_order: 4,
+            sales_start_minutes_before: Some(7 * 24 * 60),
+            sales_end_minutes_before: Some(60),
+            visibility: TicketTypeVisibility::Public,
+            admission,
+            section_id: None,
+        }
+    }
+
+    fn blueprint() -> EventBlueprint {
+        EventBlueprint {
+            title: "Winter festival".to_string(),
+            description: "Two days of music".to_string(),
+            default_locale: Default::default(),
+            translations: Vec::new(),
+            timezone: Tz::Europe__Berlin,
+            duration_minutes: Some(2 * 24 * 60),
+            doors_open_minutes_before: Some(30),
+            venue_name: "Old hall".to_string(),
+            venue_id: Some(Uuid::new_v4()),
+            category_ids: Vec::new(),
+            tags: vec!["festival".to_string()],
+            sessions: vec![SessionBlueprint {
+                title: "Opening".to_string(),
+                stage: Some("Main".to_string()),
+                starts_minutes_after: 90,
+                duration_minutes: 45,
+                capacity: Some(200),
+            }],
+            ticket_types: vec![
+                TicketTypeBlueprint {
+                    section_id: Some(Uuid::new_v4()),
+                    ..ticket_type("Full pass", AdmissionBlueprint::Event)
+                },
+                ticket_type("Second day", AdmissionBlueprint::Day(1)),
+                ticket_type("Opening only", AdmissionBlueprint::Session(0)),
+            ],
+            media: vec![media("events/original/poster.png", None)],
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 11, 1, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_draft_moves_sessions_and_sales_windows_to_the_new_start() {
+        let blueprint = blueprint();
+        let start = Utc.with_ymd_and_hms(2026, 12, 5, 19, 0, 0).unwrap();
+        let draft = plan_draft(
+            Uuid::new_v4(),
+            &blueprint,
+            ScheduleTime::Instant(start),
+            EventCopyOverrides::default(),
+            None,
+            Vec::new(),
+            Uuid::new_v4(),
+            None,
+            now(),
+        )
+        .unwrap();
+
+        assert_eq!(*draft.schedule.starts_at(), start);
+        let session = &draft.sessions[0];
+        assert_eq!(*session.starts_at(), start + Duration::minutes(90));
+        assert_eq!(*session.ends_at(), start + Duration::minutes(135));
+
+        let [full, day, opening] = &draft.ticket_types[..] else {
+            panic!("expected three ticket types");
+        };
+        assert_eq!(full.sales_start, Some(start - Duration::days(7)));
+        assert_eq!(full.sales_end, Some(start - Duration::hours(1)));
+        // Same venue, so the seating section still applies
+        assert_eq!(full.section_id, blueprint.ticket_types[0].section_id);
+        assert_eq!(
+            day.admission,
+            Admission::Day(NaiveDate::from_ymd_opt(2026, 12, 6).unwrap())
+        );
+        assert_eq!(opening.admission, Admission::Session(session.id()));
+    }
+
+    #[test]
+    fn test_draft_is_unpublished_and_rejects_past_starts() {
+        let blueprint = blueprint();
+        let organizer = Uuid::new_v4();
+        let start = Utc.with_ymd_and_hms(2026, 12, 5, 19, 0, 0).unwrap();
+        let draft = plan_draft(
+            Uuid::new_v4(),
+            &blueprint,
+            ScheduleTime::Instant(start),
+            EventCopyOverrides::default(),
+            None,
+            Vec::new(),
+            organizer,
+            None,
+            now(),
+        )
+        .unwrap();
+
+        assert_eq!(draft.data.publish_at, None);
+        assert_eq!(draft.data.on_sale_at, None);
+        assert_eq!(draft.data.external_uid, None);
+        assert_eq!(draft.data.organizer_id, Some(organizer));
+
+        let past = plan_draft(
+            Uuid::new_v4(),
+            &blueprint,
+            ScheduleTime::Instant(now() - Duration::days(1)),
+            EventCopyOverrides::default(),
+            None,
+            Vec::new(),
+            organizer,
+            None,
+            now(),
+        );
+        assert!(matches!(past, Err(EventCopyError::EventDateInPast)));
+    }
+
+    #[test]
+    fn test_venue_override_brings_its_name_timezone_and_drops_sections() {
+        let blueprint = blueprint();
+        let venue = Venue::new(
+            Uuid::new_v4(),
+            "Harbour arena".to_string(),
+            Tz::America__New_York,
+            now(),
+            now(),
+        );
+        let local_start = NaiveDate::from_ymd_opt(2026, 12, 5)
+            .unwrap()
+            .and_hms_opt(19, 0, 0)
+            .unwrap();
+        let draft = plan_draft(
+            Uuid::new_v4(),
+            &blueprint,
+            ScheduleTime::Local(local_start),
+            EventCopyOverrides {
+                venue_id: Some(venue.id()),
+                ..Default::default()
+            },
+            Some(&venue),
+            Vec::new(),
+            Uuid::new_v4(),
+            None,
+            now(),
+        )
+        .unwrap();
+
+        assert_eq!(draft.data.venue_id, Some(venue.id()));
+        assert_eq!(draft.data.venue_name, "Harbour arena");
+        assert_eq!(draft.schedule.timezone(), Tz::America__New_York);
+        // 19:00 in New York is midnight UTC
+        assert_eq!(
+            *draft.schedule.starts_at(),
+            Utc.with_ymd_and_hms(2026, 12, 6, 0, 0, 0).unwrap()
+        );
+        // Seating sections belong to the old venue's chart
+        assert!(draft.ticket_types.iter().all(|t| t.section_id.is_none()));
+
+        // Explicit overrides still beat the venue's own details
+        let renamed = plan_draft(
+            Uuid::new_v4(),
+            &blueprint,
+            ScheduleTime::Local(local_start),
+            EventCopyOverrides {
+                title: Some("Winter festival II".to_string()),
+                timezone: Some(Tz::UTC),
+                venue_name: Some("Arena, hall B".to_string()),
+                venue_id: Some(venue.id()),
+            },
+            Some(&venue),
+            Vec::new(),
+            Uuid::new_v4(),
+            None,
+            now(),
+        )
+        .unwrap();
+        assert_eq!(renamed.data.title, "Winter festival II");
+        assert_eq!(renamed.data.venue_name, "Arena, hall B");
+        assert_eq!(renamed.schedule.timezone(), Tz::UTC);
+    }
+
+    #[tokio::test]
+    async fn test_media_is_copied_under_new_keys() {
+        let storage = RecordingStorage::default();
+        let originals = vec![
+            media(
+                "events/original/poster.png",
+                Some("events/original/poster_thumb.jpg"),
+            ),
+            media("events/original/gallery.png", None),
+        ];
+
+        let copies = copy_media(&storage, &originals, "events/copy")
+            .await
+            .unwrap();
+
+        assert_eq!(copies.len(), 2);
+        let (id, poster) = &copies[0];
+        assert_eq!(poster.storage_key, format!("events/copy/{}.png", id));
+        assert_eq!(
+            poster.thumbnail_key,
+            Some(format!("events/copy/{}_thumb.jpg", id))
+        );
+        assert_eq!(
+            poster.url,
+            format!("https://cdn.test/events/copy/{}.png", id)
+        );
+        assert_ne!(copies[0].0, copies[1].0);
+        assert_eq!(
+            *storage.copies.lock().unwrap(),
+            vec![
+                (originals[0].storage_key.clone(), poster.storage_key.clone()),
+                (
+                    "events/original/poster_thumb.jpg".to_string(),
+                    poster.thumbnail_key.clone().unwrap()
+                ),
+                (
+                    originals[1].storage_key.clone(),
+                    copies[1].1.storage_key.clone()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_media_copy_leaves_nothing_behind() {
+        let storage = RecordingStorage {
+            fail_from: Some("events/original/gallery.png".to_string()),
+            ..Default::default()
+        };
+        let originals = vec![
+            media("events/original/poster.png", None),
+            media("events/original/gallery.png", None),
+        ];
+
+        let result = copy_media(&storage, &originals, "events/copy").await;
+
+        assert!(matches!(result, Err(EventCopyError::StorageError(_))));
+        let copied = storage.copies.lock().unwrap()[0].1.clone();
+        let deleted = storage.deleted.lock().unwrap();
+        assert!(deleted.contains(&copied));
+        assert!(deleted.iter().all(|key| key.starts_with("events/copy/")));
+    }
+}
@@ -1,9 +1,21 @@
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use shared::UseCase;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::domain::{DomainError, Event, EventRepository};
+use super::event_access::can_view_internals;
+use crate::domain::{DomainError, Event, EventRepository, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEventParams {
+    pub id: Uuid,
+    /// The caller, if known; needed to see events that aren't published yet.
+    pub requested_by: Option<Uuid>,
+    /// Languages the caller prefers, most preferred first; the event comes
+    /// back in the best matching locale.
+    pub languages: Vec<String>,
+}
 
 #[derive(Debug, thiserror::Error)]
 pub enum GetEventUseCaseError {
@@ -15,20 +27,40 @@ pub enum GetEventUseCaseError {
 
 pub struct GetEventUseCase {
     repository: Arc<dyn EventRepository>,
+    users: Arc<dyn UsersClient>,
 }
 
 impl GetEventUseCase {
-    pub fn new(repository: Arc<dyn EventRepository>) -> Self {
-        Self { repository }
+    pub fn new(repository: Arc<dyn EventRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
     }
 }
 
 #[async_trait]
-impl UseCase<Uuid, Result<Event, GetEventUseCaseError>> for GetEventUseCase {
-    async fn execute(&self, id: Uuid) -> Result<Event, GetEventUseCaseError> {
-        self.repository.find_by_id(id).await.map_err(|e| match e {
-            DomainError::NotFound(_) => GetEventUseCaseError::NotFound,
-            DomainError::DatabaseError(msg) => GetEventUseCaseError::DatabaseError(msg),
-        })
+impl UseCase<GetEventParams, Result<Event, GetEventUseCaseError>> for GetEventUseCase {
+    async fn execute(&self, data: GetEventParams) -> Result<Event, GetEventUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetEventUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => GetEventUseCaseError::DatabaseError(msg),
+            })?;
+
+        // Unpublished events don't exist as far as the public is concerned
+        if !event.publication().is_published() {
+            let visible = match data.requested_by {
+                Some(user_id) => can_view_internals(self.users.as_ref(), &event, user_id)
+                    .await
+                    .map_err(|e| GetEventUseCaseError::DatabaseError(e.to_string()))?,
+                None => false,
+            };
+            if !visible {
+                return Err(GetEventUseCaseError::NotFound);
+            }
+        }
+
+        Ok(event.localize(&data.languages))
     }
 }
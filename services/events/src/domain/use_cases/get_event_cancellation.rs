@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_view_internals;
+use crate::domain::{
+    entities::EventCancellation, CancellationRepository, DomainError, EventRepository, UsersClient,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEventCancellationParams {
+    pub event_id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetEventCancellationUseCaseError {
+    #[error("Event not found")]
+    EventNotFound,
+    #[error("The event has not been cancelled")]
+    NotCancelled,
+    #[error(
+        "Only the organizer or members of the event's organization can follow its cancellation"
+    )]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetEventCancellationUseCase {
+    repository: Arc<dyn EventRepository>,
+    cancellations: Arc<dyn CancellationRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl GetEventCancellationUseCase {
+    pub fn new(
+        repository: Arc<dyn EventRepository>,
+        cancellations: Arc<dyn CancellationRepository>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            repository,
+            cancellations,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl
+    UseCase<GetEventCancellationParams, Result<EventCancellation, GetEventCancellationUseCaseError>>
+    for GetEventCancellationUseCase
+{
+    async fn execute(
+        &self,
+        data: GetEventCancellationParams,
+    ) -> Result<EventCancellation, GetEventCancellationUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetEventCancellationUseCaseError::EventNotFound,
+                DomainError::DatabaseError(msg) => {
+                    GetEventCancellationUseCaseError::DatabaseError(msg)
+                }
+            })?;
+
+        if !can_view_internals(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| GetEventCancellationUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(GetEventCancellationUseCaseError::Forbidden);
+        }
+
+        self.cancellations
+            .find_by_event_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetEventCancellationUseCaseError::NotCancelled,
+                DomainError::DatabaseError(msg) => {
+                    GetEventCancellationUseCaseError::DatabaseError(msg)
+                }
+            })
+    }
+}
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+
+use super::EventFilter;
+use crate::domain::{
+    entities::{CategoryFacet, GeoRadius},
+    CategoryRepository, EventRepository, VenueRepository,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEventFacetsParams {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Only count events carrying every one of these tags.
+    pub tags: Vec<String>,
+    /// Only count events at venues in this area.
+    pub near: Option<GeoRadius>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetEventFacetsUseCaseError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetEventFacetsUseCase {
+    repository: Arc<dyn EventRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    venue_repository: Arc<dyn VenueRepository>,
+}
+
+impl GetEventFacetsUseCase {
+    pub fn new(
+        repository: Arc<dyn EventRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+        venue_repository: Arc<dyn VenueRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            category_repository,
+            venue_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<GetEventFacetsParams, Result<Vec<CategoryFacet>, GetEventFacetsUseCaseError>>
+    for GetEventFacetsUseCase
+{
+    async fn execute(
+        &self,
+        data: GetEventFacetsParams,
+    ) -> Result<Vec<CategoryFacet>, GetEventFacetsUseCaseError> {
+        let taxonomy = self
+            .category_repository
+            .find_all()
+            .await
+            .map_err(|e| GetEventFacetsUseCaseError::DatabaseError(e.to_string()))?;
+
+        let venue_ids = match data.near {
+            Some(near) => Some(
+                self.venue_repository
+                    .find_within(&near)
+                    .await
+                    .map_err(|e| GetEventFacetsUseCaseError::DatabaseError(e.to_string()))?
+                    .iter()
+                    .map(|venue| venue.id())
+                    .collect(),
+            ),
+            None => None,
+        };
+        let filter = EventFilter {
+            category_ids: Vec::new(),
+            tags: data.tags,
+            venue_ids,
+        };
+
+        let assignments = self
+            .repository
+            .find_category_assignments(data.from, data.to, &filter)
+            .await
+            .map_err(|e| GetEventFacetsUseCaseError::DatabaseError(e.to_string()))?;
+
+        Ok(taxonomy.facets(&assignments))
+    }
+}
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_view_internals;
+use crate::domain::{entities::EventRevision, DomainError, EventRepository, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEventHistoryParams {
+    pub event_id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetEventHistoryUseCaseError {
+    #[error("Event not found")]
+    NotFound,
+    #[error("Only the organizer or members of the event's organization can see its history")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetEventHistoryUseCase {
+    repository: Arc<dyn EventRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl GetEventHistoryUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<GetEventHistoryParams, Result<Vec<EventRevision>, GetEventHistoryUseCaseError>>
+    for GetEventHistoryUseCase
+{
+    async fn execute(
+        &self,
+        data: GetEventHistoryParams,
+    ) -> Result<Vec<EventRevision>, GetEventHistoryUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetEventHistoryUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => GetEventHistoryUseCaseError::DatabaseError(msg),
+            })?;
+
+        if !can_view_internals(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| GetEventHistoryUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(GetEventHistoryUseCaseError::Forbidden);
+        }
+
+        self.repository
+            .find_revisions(data.event_id)
+            .await
+            .map_err(|e| GetEventHistoryUseCaseError::DatabaseError(e.to_string()))
+    }
+}
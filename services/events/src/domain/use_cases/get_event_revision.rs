@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_view_internals;
+use crate::domain::{entities::EventRevision, DomainError, EventRepository, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEventRevisionParams {
+    pub event_id: Uuid,
+    pub revision: i32,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetEventRevisionUseCaseError {
+    #[error("Event not found")]
+    NotFound,
+    #[error("Revision {0} not found")]
+    RevisionNotFound(i32),
+    #[error("Only the organizer or members of the event's organization can see its history")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetEventRevisionUseCase {
+    repository: Arc<dyn EventRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl GetEventRevisionUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<GetEventRevisionParams, Result<EventRevision, GetEventRevisionUseCaseError>>
+    for GetEventRevisionUseCase
+{
+    async fn execute(
+        &self,
+        data: GetEventRevisionParams,
+    ) -> Result<EventRevision, GetEventRevisionUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetEventRevisionUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => GetEventRevisionUseCaseError::DatabaseError(msg),
+            })?;
+
+        if !can_view_internals(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| GetEventRevisionUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(GetEventRevisionUseCaseError::Forbidden);
+        }
+
+        self.repository
+            .find_revision(data.event_id, data.revision)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => {
+                    GetEventRevisionUseCaseError::RevisionNotFound(data.revision)
+                }
+                DomainError::DatabaseError(msg) => GetEventRevisionUseCaseError::DatabaseError(msg),
+            })
+    }
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, EventSeries, EventSeriesRepository};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetEventSeriesUseCaseError {
+    #[error("Event series not found")]
+    NotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetEventSeriesUseCase {
+    repository: Arc<dyn EventSeriesRepository>,
+}
+
+impl GetEventSeriesUseCase {
+    pub fn new(repository: Arc<dyn EventSeriesRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<Uuid, Result<EventSeries, GetEventSeriesUseCaseError>> for GetEventSeriesUseCase {
+    async fn execute(&self, id: Uuid) -> Result<EventSeries, GetEventSeriesUseCaseError> {
+        self.repository.find_by_id(id).await.map_err(|e| match e {
+            DomainError::NotFound(_) => GetEventSeriesUseCaseError::NotFound,
+            DomainError::DatabaseError(msg) => GetEventSeriesUseCaseError::DatabaseError(msg),
+        })
+    }
+}
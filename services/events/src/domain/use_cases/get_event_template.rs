@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_use_template;
+use crate::domain::{entities::EventTemplate, DomainError, EventTemplateRepository, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEventTemplateParams {
+    pub id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetEventTemplateUseCaseError {
+    #[error("Event template not found")]
+    NotFound,
+    #[error("Only the organization's owners and managers, or the organizer who saved it, can use this template")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetEventTemplateUseCase {
+    template_repository: Arc<dyn EventTemplateRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl GetEventTemplateUseCase {
+    pub fn new(
+        template_repository: Arc<dyn EventTemplateRepository>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            template_repository,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<GetEventTemplateParams, Result<EventTemplate, GetEventTemplateUseCaseError>>
+    for GetEventTemplateUseCase
+{
+    async fn execute(
+        &self,
+        data: GetEventTemplateParams,
+    ) -> Result<EventTemplate, GetEventTemplateUseCaseError> {
+        let template = self
+            .template_repository
+            .find_by_id(data.id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetEventTemplateUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => GetEventTemplateUseCaseError::DatabaseError(msg),
+            })?;
+
+        if !can_use_template(self.users.as_ref(), &template, data.requested_by)
+            .await
+            .map_err(|e| GetEventTemplateUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(GetEventTemplateUseCaseError::Forbidden);
+        }
+
+        Ok(template)
+    }
+}
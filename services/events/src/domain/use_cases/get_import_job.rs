@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{entities::ImportJob, DomainError, ImportJobRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetImportJobParams {
+    pub id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetImportJobUseCaseError {
+    #[error("Import job not found")]
+    NotFound,
+    #[error("Only the organizer the events were imported for can follow the import")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetImportJobUseCase {
+    repository: Arc<dyn ImportJobRepository>,
+}
+
+impl GetImportJobUseCase {
+    pub fn new(repository: Arc<dyn ImportJobRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<GetImportJobParams, Result<ImportJob, GetImportJobUseCaseError>>
+    for GetImportJobUseCase
+{
+    async fn execute(
+        &self,
+        data: GetImportJobParams,
+    ) -> Result<ImportJob, GetImportJobUseCaseError> {
+        let job = self
+            .repository
+            .find_by_id(data.id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetImportJobUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => GetImportJobUseCaseError::DatabaseError(msg),
+            })?;
+
+        // The results name the events created, so they are as private as
+        // the events' drafts.
+        if job.organizer_id() != Some(data.requested_by) {
+            return Err(GetImportJobUseCaseError::Forbidden);
+        }
+        Ok(job)
+    }
+}
@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::get_venue_calendar::FEED_LOOKBACK_DAYS;
+use crate::domain::{entities::CalendarFeed, EventRepository};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetOrganizerCalendarUseCaseError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetOrganizerCalendarUseCase {
+    repository: Arc<dyn EventRepository>,
+}
+
+impl GetOrganizerCalendarUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<Uuid, Result<CalendarFeed, GetOrganizerCalendarUseCaseError>>
+    for GetOrganizerCalendarUseCase
+{
+    async fn execute(
+        &self,
+        organizer_id: Uuid,
+    ) -> Result<CalendarFeed, GetOrganizerCalendarUseCaseError> {
+        let since = Utc::now() - Duration::days(FEED_LOOKBACK_DAYS);
+        let events = self
+            .repository
+            .find_by_organizer(organizer_id, since)
+            .await
+            .map_err(|e| GetOrganizerCalendarUseCaseError::DatabaseError(e.to_string()))?;
+
+        Ok(CalendarFeed::new(
+            format!("Events by organizer {}", organizer_id),
+            events,
+        ))
+    }
+}
@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, EventRepository, SeatInventory, SeatMap, VenueRepository};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetSeatMapUseCaseError {
+    #[error("Event not found")]
+    EventNotFound,
+    #[error("Event has no seating chart")]
+    NoSeatingChart,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetSeatMapUseCase {
+    event_repository: Arc<dyn EventRepository>,
+    venue_repository: Arc<dyn VenueRepository>,
+    seat_inventory: Arc<dyn SeatInventory>,
+}
+
+impl GetSeatMapUseCase {
+    pub fn new(
+        event_repository: Arc<dyn EventRepository>,
+        venue_repository: Arc<dyn VenueRepository>,
+        seat_inventory: Arc<dyn SeatInventory>,
+    ) -> Self {
+        Self {
+            event_repository,
+            venue_repository,
+            seat_inventory,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<Uuid, Result<SeatMap, GetSeatMapUseCaseError>> for GetSeatMapUseCase {
+    async fn execute(&self, event_id: Uuid) -> Result<SeatMap, GetSeatMapUseCaseError> {
+        let event = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetSeatMapUseCaseError::EventNotFound,
+                DomainError::DatabaseError(msg) => GetSeatMapUseCaseError::DatabaseError(msg),
+            })?;
+
+        let venue_id = event
+            .venue_id()
+            .ok_or(GetSeatMapUseCaseError::NoSeatingChart)?;
+
+        let chart = self
+            .venue_repository
+            .find_seating_chart(venue_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetSeatMapUseCaseError::NoSeatingChart,
+                DomainError::DatabaseError(msg) => GetSeatMapUseCaseError::DatabaseError(msg),
+            })?;
+
+        if chart.sections().is_empty() {
+            return Err(GetSeatMapUseCaseError::NoSeatingChart);
+        }
+
+        let statuses = self
+            .seat_inventory
+            .seat_statuses(event_id)
+            .await
+            .map_err(|e| GetSeatMapUseCaseError::DatabaseError(e.to_string()))?;
+
+        Ok(SeatMap::new(event_id, chart, statuses))
+    }
+}
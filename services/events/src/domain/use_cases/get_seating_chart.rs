@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, SeatingChart, VenueRepository};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetSeatingChartUseCaseError {
+    #[error("Venue not found")]
+    VenueNotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetSeatingChartUseCase {
+    repository: Arc<dyn VenueRepository>,
+}
+
+impl GetSeatingChartUseCase {
+    pub fn new(repository: Arc<dyn VenueRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<Uuid, Result<SeatingChart, GetSeatingChartUseCaseError>> for GetSeatingChartUseCase {
+    async fn execute(&self, venue_id: Uuid) -> Result<SeatingChart, GetSeatingChartUseCaseError> {
+        self.repository
+            .find_seating_chart(venue_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetSeatingChartUseCaseError::VenueNotFound,
+                DomainError::DatabaseError(msg) => GetSeatingChartUseCaseError::DatabaseError(msg),
+            })
+    }
+}
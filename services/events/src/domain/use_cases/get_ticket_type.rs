@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, EventRepository, TicketType};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetTicketTypeUseCaseError {
+    #[error("Ticket type not found")]
+    NotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetTicketTypeUseCase {
+    repository: Arc<dyn EventRepository>,
+}
+
+impl GetTicketTypeUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<(Uuid, Uuid), Result<TicketType, GetTicketTypeUseCaseError>> for GetTicketTypeUseCase {
+    async fn execute(
+        &self,
+        (event_id, id): (Uuid, Uuid),
+    ) -> Result<TicketType, GetTicketTypeUseCaseError> {
+        self.repository
+            .find_ticket_type(event_id, id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetTicketTypeUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => GetTicketTypeUseCaseError::DatabaseError(msg),
+            })
+    }
+}
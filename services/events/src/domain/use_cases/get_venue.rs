@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, Venue, VenueRepository};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetVenueUseCaseError {
+    #[error("Venue not found")]
+    NotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetVenueUseCase {
+    repository: Arc<dyn VenueRepository>,
+}
+
+impl GetVenueUseCase {
+    pub fn new(repository: Arc<dyn VenueRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<Uuid, Result<Venue, GetVenueUseCaseError>> for GetVenueUseCase {
+    async fn execute(&self, id: Uuid) -> Result<Venue, GetVenueUseCaseError> {
+        self.repository.find_by_id(id).await.map_err(|e| match e {
+            DomainError::NotFound(_) => GetVenueUseCaseError::NotFound,
+            DomainError::DatabaseError(msg) => GetVenueUseCaseError::DatabaseError(msg),
+        })
+    }
+}
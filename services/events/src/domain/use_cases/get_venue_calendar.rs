@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{entities::CalendarFeed, DomainError, EventRepository, VenueRepository};
+
+/// How far back feeds reach, so recently finished or cancelled events stay
+/// visible to subscribers.
+pub(crate) const FEED_LOOKBACK_DAYS: i64 = 90;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetVenueCalendarUseCaseError {
+    #[error("Venue not found")]
+    VenueNotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetVenueCalendarUseCase {
+    repository: Arc<dyn EventRepository>,
+    venue_repository: Arc<dyn VenueRepository>,
+}
+
+impl GetVenueCalendarUseCase {
+    pub fn new(
+        repository: Arc<dyn EventRepository>,
+        venue_repository: Arc<dyn VenueRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            venue_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<Uuid, Result<CalendarFeed, GetVenueCalendarUseCaseError>> for GetVenueCalendarUseCase {
+    async fn execute(&self, venue_id: Uuid) -> Result<CalendarFeed, GetVenueCalendarUseCaseError> {
+        let venue = self
+            .venue_repository
+            .find_by_id(venue_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetVenueCalendarUseCaseError::VenueNotFound,
+                DomainError::DatabaseError(msg) => GetVenueCalendarUseCaseError::DatabaseError(msg),
+            })?;
+
+        let since = Utc::now() - Duration::days(FEED_LOOKBACK_DAYS);
+        let events = self
+            .repository
+            .find_by_venue(venue_id, since)
+            .await
+            .map_err(|e| GetVenueCalendarUseCaseError::DatabaseError(e.to_string()))?;
+
+        Ok(CalendarFeed::new(venue.name().to_string(), events))
+    }
+}
@@ -0,0 +1,281 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{ImportJob, ImportRowOutcome, ImportRowResult},
+    DomainError, EventRepository, ImportJobRepository,
+};
+
+use super::{CreateEventParams, CreateEventUseCase, CreateEventUseCaseError};
+
+/// Progress is persisted after this many entries so pollers see it advance.
+/// A runner that dies mid-job leaves at most this many entries to be
+/// imported again; entries with a UID are then found as duplicates.
+const PROGRESS_INTERVAL: usize = 25;
+
+/// How long a job is left alone before another runner may take it over.
+/// Every progress save renews it.
+const CLAIM_LEASE_SECONDS: i64 = 5 * 60;
+
+/// One entry of an import file, already run through request validation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ImportEntry {
+    Valid {
+        line: usize,
+        uid: Option<String>,
+        params: Box<CreateEventParams>,
+    },
+    Invalid {
+        line: usize,
+        uid: Option<String>,
+        error: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportEventsParams {
+    /// File name or other label identifying where the entries came from.
+    pub source: String,
+    pub entries: Vec<ImportEntry>,
+    /// Owner of every imported event. Duplicates are only looked for among
+    /// their events.
+    pub organizer_id: Option<Uuid>,
+    /// Return the pending job immediately and leave the import to the
+    /// background runner.
+    pub run_in_background: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportEventsUseCaseError {
+    #[error("The file contains no events")]
+    NoEntries,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Imports the entries of a job the caller holds, resuming after the
+/// entries it already has results for.
+pub(super) struct ImportRunner {
+    create_event: Arc<CreateEventUseCase>,
+    repository: Arc<dyn EventRepository>,
+    job_repository: Arc<dyn ImportJobRepository>,
+}
+
+impl ImportRunner {
+    pub(super) fn new(
+        create_event: Arc<CreateEventUseCase>,
+        repository: Arc<dyn EventRepository>,
+        job_repository: Arc<dyn ImportJobRepository>,
+    ) -> Self {
+        Self {
+            create_event,
+            repository,
+            job_repository,
+        }
+    }
+
+    pub(super) fn lease() -> Duration {
+        Duration::seconds(CLAIM_LEASE_SECONDS)
+    }
+
+    /// Imports the job's remaining entries. Stops early, leaving the job to
+    /// its new runner, when the claim on it is lost.
+    pub(super) async fn run(&self, mut job: ImportJob, entries: Vec<ImportEntry>) -> ImportJob {
+        if entries.len() != job.total_rows() {
+            job.fail("The job's entries could not be read".to_string());
+            self.save(&job).await;
+            return job;
+        }
+
+        job.start();
+        if !self.save(&job).await {
+            return job;
+        }
+
+        let organizer_id = job.organizer_id();
+        let mut seen: HashMap<String, Uuid> = job
+            .results()
+            .iter()
+            .filter_map(|result| match (&result.uid, &result.outcome) {
+                (
+                    Some(uid),
+                    ImportRowOutcome::Created { event_id }
+                    | ImportRowOutcome::Duplicate { event_id },
+                ) => Some((uid.clone(), *event_id)),
+                _ => None,
+            })
+            .collect();
+        let done = job.results().len();
+        for entry in entries.into_iter().skip(done) {
+            let result = self.import_entry(entry, organizer_id, &mut seen).await;
+            job.record(result);
+            if job.results().len().is_multiple_of(PROGRESS_INTERVAL) && !self.save(&job).await {
+                return job;
+            }
+        }
+
+        job.complete();
+        self.save(&job).await;
+        job
+    }
+
+    /// Saves the job's progress and renews the claim on it. Returns `false`
+    /// when another runner has taken the job over.
+    async fn save(&self, job: &ImportJob) -> bool {
+        match self
+            .job_repository
+            .save(job, Utc::now() + Self::lease())
+            .await
+        {
+            Ok(true) => true,
+            Ok(false) => {
+                log::warn!(
+                    "Lost the claim on import job {}; progress left to its new runner",
+                    job.id()
+                );
+                false
+            }
+            Err(e) => {
+                log::error!("Failed to save progress of import job {}: {}", job.id(), e);
+                true
+            }
+        }
+    }
+
+    async fn import_entry(
+        &self,
+        entry: ImportEntry,
+        organizer_id: Option<Uuid>,
+        seen: &mut HashMap<String, Uuid>,
+    ) -> ImportRowResult {
+        let (line, uid, mut params) = match entry {
+            ImportEntry::Invalid { line, uid, error } => {
+                return ImportRowResult {
+                    line,
+                    uid,
+                    outcome: ImportRowOutcome::Invalid { error },
+                };
+            }
+            ImportEntry::Valid { line, uid, params } => (line, uid, params),
+        };
+
+        let outcome = match self
+            .find_duplicate(organizer_id, uid.as_deref(), seen)
+            .await
+        {
+            Ok(Some(event_id)) => ImportRowOutcome::Duplicate { event_id },
+            Ok(None) => {
+                params.external_uid = uid.clone();
+                match self.create_event.execute(*params).await {
+                    Ok(event) => {
+                        if let Some(uid) = &uid {
+                            seen.insert(uid.clone(), event.id());
+                        }
+                        ImportRowOutcome::Created {
+                            event_id: event.id(),
+                        }
+                    }
+                    Err(
+                        e @ (CreateEventUseCaseError::VenueNotFound
+                        | CreateEventUseCaseError::OrganizerNotFound
+                        | CreateEventUseCaseError::CategoryNotFound(_)
+                        | CreateEventUseCaseError::InvalidSchedule(_)
+                        | CreateEventUseCaseError::EventDateInPast
+                        | CreateEventUseCaseError::InvalidPublication(_)
+                        | CreateEventUseCaseError::Forbidden),
+                    ) => ImportRowOutcome::Invalid {
+                        error: e.to_string(),
+                    },
+                    Err(CreateEventUseCaseError::DatabaseError(error)) => {
+                        ImportRowOutcome::Failed { error }
+                    }
+                }
+            }
+            Err(error) => ImportRowOutcome::Failed { error },
+        };
+
+        ImportRowResult { line, uid, outcome }
+    }
+
+    async fn find_duplicate(
+        &self,
+        organizer_id: Option<Uuid>,
+        uid: Option<&str>,
+        seen: &HashMap<String, Uuid>,
+    ) -> Result<Option<Uuid>, String> {
+        let Some(uid) = uid else {
+            return Ok(None);
+        };
+        if let Some(event_id) = seen.get(uid) {
+            return Ok(Some(*event_id));
+        }
+        match self
+            .repository
+            .find_by_external_uid(organizer_id, uid)
+            .await
+        {
+            Ok(event) => Ok(Some(event.id())),
+            Err(DomainError::NotFound(_)) => Ok(None),
+            Err(DomainError::DatabaseError(msg)) => Err(msg),
+        }
+    }
+}
+
+/// Creates an import job for the entries. Small imports are run right away;
+/// large ones are left to `RunImportJobsUseCase`, so they survive restarts
+/// and can be picked up by any replica.
+pub struct ImportEventsUseCase {
+    runner: ImportRunner,
+}
+
+impl ImportEventsUseCase {
+    pub fn new(
+        create_event: Arc<CreateEventUseCase>,
+        repository: Arc<dyn EventRepository>,
+        job_repository: Arc<dyn ImportJobRepository>,
+    ) -> Self {
+        Self {
+            runner: ImportRunner::new(create_event, repository, job_repository),
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<ImportEventsParams, Result<ImportJob, ImportEventsUseCaseError>>
+    for ImportEventsUseCase
+{
+    async fn execute(
+        &self,
+        data: ImportEventsParams,
+    ) -> Result<ImportJob, ImportEventsUseCaseError> {
+        if data.entries.is_empty() {
+            return Err(ImportEventsUseCaseError::NoEntries);
+        }
+
+        let mut entries = data.entries;
+        for entry in &mut entries {
+            if let ImportEntry::Valid { params, .. } = entry {
+                params.organizer_id = data.organizer_id;
+            }
+        }
+
+        let held_until = (!data.run_in_background).then(|| Utc::now() + ImportRunner::lease());
+        let job = self
+            .runner
+            .job_repository
+            .create(data.source, data.organizer_id, &entries, held_until)
+            .await
+            .map_err(|e| ImportEventsUseCaseError::DatabaseError(e.to_string()))?;
+
+        if data.run_in_background {
+            return Ok(job);
+        }
+
+        Ok(self.runner.run(job, entries).await)
+    }
+}
@@ -0,0 +1,220 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage_venue;
+use crate::domain::{
+    DomainError, EventRepository, SeatInventory, SeatingChart, UsersClient, VenueRepository,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSeatingChartParams {
+    pub venue_id: Uuid,
+    pub sections: Vec<SectionLayout>,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionLayout {
+    pub name: String,
+    pub rows: Vec<RowLayout>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RowLayout {
+    pub label: String,
+    pub seats: Vec<SeatLayout>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeatLayout {
+    pub label: String,
+    pub x: f64,
+    pub y: f64,
+    pub accessible: bool,
+    pub obstructed: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportSeatingChartUseCaseError {
+    #[error("Venue not found")]
+    VenueNotFound,
+    #[error("Only the venue's organizer or its organization's owners and managers can change its seating chart")]
+    Forbidden,
+    #[error("Section {0} is used by ticket types and cannot be removed")]
+    SectionInUse(String),
+    #[error(
+        "Seat {2} in section {0}, row {1} is reserved for an upcoming event and cannot be removed"
+    )]
+    SeatReserved(String, String, String),
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// How long after its start an event's reservations still keep its seats
+/// from being removed, so events under way count too.
+const RESERVATIONS_KEPT_FOR: Duration = Duration::days(1);
+
+/// Imports a venue's seating chart. Sections are matched to the current ones
+/// by name and seats by row and seat label, so they keep their ids; only
+/// sections and seats missing from the new layout are removed, and only when
+/// no ticket type sells the section and no reservation holds the seat.
+pub struct ImportSeatingChartUseCase {
+    repository: Arc<dyn VenueRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    seat_inventory: Arc<dyn SeatInventory>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl ImportSeatingChartUseCase {
+    pub fn new(
+        repository: Arc<dyn VenueRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        seat_inventory: Arc<dyn SeatInventory>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            repository,
+            event_repository,
+            seat_inventory,
+            users,
+        }
+    }
+
+    /// Fails with the first section or seat of the current chart that the
+    /// new layout would remove while it is still in use.
+    async fn check_removals(
+        &self,
+        current: &SeatingChart,
+        data: &ImportSeatingChartParams,
+    ) -> Result<(), ImportSeatingChartUseCaseError> {
+        let kept: HashMap<&str, HashSet<(&str, &str)>> = data
+            .sections
+            .iter()
+            .map(|section| {
+                let seats = section
+                    .rows
+                    .iter()
+                    .flat_map(|row| {
+                        row.seats
+                            .iter()
+                            .map(|seat| (row.label.as_str(), seat.label.as_str()))
+                    })
+                    .collect();
+                (section.name.as_str(), seats)
+            })
+            .collect();
+
+        let mut removed_seats = Vec::new();
+        let mut removed_sections = Vec::new();
+        for section in current.sections() {
+            let kept_seats = kept.get(section.name());
+            if kept_seats.is_none() {
+                removed_sections.push(section);
+            }
+            for row in section.rows() {
+                for seat in row.seats() {
+                    if !kept_seats.is_some_and(|seats| seats.contains(&(row.label(), seat.label())))
+                    {
+                        removed_seats.push((section.name(), row.label(), seat));
+                    }
+                }
+            }
+        }
+        if removed_seats.is_empty() && removed_sections.is_empty() {
+            return Ok(());
+        }
+
+        if !removed_sections.is_empty() {
+            let in_use = self
+                .repository
+                .find_sections_in_use(current.venue_id())
+                .await
+                .map_err(|e| ImportSeatingChartUseCaseError::DatabaseError(e.to_string()))?;
+            if let Some(section) = removed_sections
+                .iter()
+                .find(|section| in_use.contains(&section.id()))
+            {
+                return Err(ImportSeatingChartUseCaseError::SectionInUse(
+                    section.name().to_string(),
+                ));
+            }
+        }
+
+        if !removed_seats.is_empty() {
+            let events = self
+                .event_repository
+                .find_by_venue(current.venue_id(), Utc::now() - RESERVATIONS_KEPT_FOR)
+                .await
+                .map_err(|e| ImportSeatingChartUseCaseError::DatabaseError(e.to_string()))?;
+            for event in events {
+                let taken = self
+                    .seat_inventory
+                    .seat_statuses(event.id())
+                    .await
+                    .map_err(|e| ImportSeatingChartUseCaseError::DatabaseError(e.to_string()))?;
+                if let Some((section, row, seat)) = removed_seats
+                    .iter()
+                    .find(|(_, _, seat)| taken.contains_key(&seat.id()))
+                {
+                    return Err(ImportSeatingChartUseCaseError::SeatReserved(
+                        section.to_string(),
+                        row.to_string(),
+                        seat.label().to_string(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UseCase<ImportSeatingChartParams, Result<SeatingChart, ImportSeatingChartUseCaseError>>
+    for ImportSeatingChartUseCase
+{
+    async fn execute(
+        &self,
+        data: ImportSeatingChartParams,
+    ) -> Result<SeatingChart, ImportSeatingChartUseCaseError> {
+        let venue = self
+            .repository
+            .find_by_id(data.venue_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => ImportSeatingChartUseCaseError::VenueNotFound,
+                DomainError::DatabaseError(msg) => {
+                    ImportSeatingChartUseCaseError::DatabaseError(msg)
+                }
+            })?;
+
+        if !can_manage_venue(self.users.as_ref(), &venue, data.requested_by)
+            .await
+            .map_err(|e| ImportSeatingChartUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(ImportSeatingChartUseCaseError::Forbidden);
+        }
+
+        let current = self
+            .repository
+            .find_seating_chart(venue.id())
+            .await
+            .map_err(|e| ImportSeatingChartUseCaseError::DatabaseError(e.to_string()))?;
+        self.check_removals(&current, &data).await?;
+
+        self.repository
+            .replace_seating_chart(data)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => ImportSeatingChartUseCaseError::VenueNotFound,
+                DomainError::DatabaseError(msg) => {
+                    ImportSeatingChartUseCaseError::DatabaseError(msg)
+                }
+            })
+    }
+}
@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_use_template;
+use super::event_copy::{EventCopier, EventCopyError, EventCopyOverrides};
+use crate::domain::{
+    entities::{ScheduleError, ScheduleTime},
+    CategoryRepository, DomainError, Event, EventRepository, EventTemplateRepository,
+    ObjectStorage, UsersClient, VenueRepository,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstantiateEventTemplateParams {
+    pub template_id: Uuid,
+    pub starts_at: ScheduleTime,
+    pub overrides: EventCopyOverrides,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum InstantiateEventTemplateUseCaseError {
+    #[error("Event template not found")]
+    NotFound,
+    #[error("Only the organization's owners and managers, or the organizer who saved it, can use this template")]
+    Forbidden,
+    #[error("{0}")]
+    InvalidSchedule(ScheduleError),
+    #[error("Event date must be in the future")]
+    EventDateInPast,
+    #[error("Venue not found")]
+    VenueNotFound,
+    #[error("Storage error: {0}")]
+    StorageError(String),
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+impl From<EventCopyError> for InstantiateEventTemplateUseCaseError {
+    fn from(e: EventCopyError) -> Self {
+        match e {
+            EventCopyError::InvalidSchedule(e) => Self::InvalidSchedule(e),
+            EventCopyError::EventDateInPast => Self::EventDateInPast,
+            EventCopyError::VenueNotFound => Self::VenueNotFound,
+            EventCopyError::StorageError(msg) => Self::StorageError(msg),
+            EventCopyError::DatabaseError(msg) => Self::DatabaseError(msg),
+        }
+    }
+}
+
+/// Creates an unpublished event from a template, owned by the caller and
+/// the template's organization.
+pub struct InstantiateEventTemplateUseCase {
+    template_repository: Arc<dyn EventTemplateRepository>,
+    repository: Arc<dyn EventRepository>,
+    venue_repository: Arc<dyn VenueRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    storage: Arc<dyn ObjectStorage>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl InstantiateEventTemplateUseCase {
+    pub fn new(
+        template_repository: Arc<dyn EventTemplateRepository>,
+        repository: Arc<dyn EventRepository>,
+        venue_repository: Arc<dyn VenueRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+        storage: Arc<dyn ObjectStorage>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            template_repository,
+            repository,
+            venue_repository,
+            category_repository,
+            storage,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<InstantiateEventTemplateParams, Result<Event, InstantiateEventTemplateUseCaseError>>
+    for InstantiateEventTemplateUseCase
+{
+    async fn execute(
+        &self,
+        data: InstantiateEventTemplateParams,
+    ) -> Result<Event, InstantiateEventTemplateUseCaseError> {
+        let template = self
+            .template_repository
+            .find_by_id(data.template_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => InstantiateEventTemplateUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => {
+                    InstantiateEventTemplateUseCaseError::DatabaseError(msg)
+                }
+            })?;
+
+        if !can_use_template(self.users.as_ref(), &template, data.requested_by)
+            .await
+            .map_err(|e| InstantiateEventTemplateUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(InstantiateEventTemplateUseCaseError::Forbidden);
+        }
+
+        let copier = EventCopier {
+            events: self.repository.as_ref(),
+            venues: self.venue_repository.as_ref(),
+            categories: self.category_repository.as_ref(),
+            storage: self.storage.as_ref(),
+        };
+        Ok(copier
+            .create_draft(
+                template.blueprint(),
+                data.starts_at,
+                data.overrides,
+                data.requested_by,
+                template.organization_id(),
+            )
+            .await?)
+    }
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use shared::UseCase;
+use std::sync::Arc;
+
+use crate::domain::{entities::Taxonomy, CategoryRepository};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ListCategoriesUseCaseError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct ListCategoriesUseCase {
+    repository: Arc<dyn CategoryRepository>,
+}
+
+impl ListCategoriesUseCase {
+    pub fn new(repository: Arc<dyn CategoryRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<(), Result<Taxonomy, ListCategoriesUseCaseError>> for ListCategoriesUseCase {
+    async fn execute(&self, _: ()) -> Result<Taxonomy, ListCategoriesUseCaseError> {
+        self.repository
+            .find_all()
+            .await
+            .map_err(|e| ListCategoriesUseCaseError::DatabaseError(e.to_string()))
+    }
+}
@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{entities::EventTemplate, EventTemplateRepository, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListEventTemplatesParams {
+    /// Organization whose templates to list; `None` lists the caller's own.
+    pub organization_id: Option<Uuid>,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ListEventTemplatesUseCaseError {
+    #[error("Only the organization's owners and managers can see its templates")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct ListEventTemplatesUseCase {
+    template_repository: Arc<dyn EventTemplateRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl ListEventTemplatesUseCase {
+    pub fn new(
+        template_repository: Arc<dyn EventTemplateRepository>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            template_repository,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<ListEventTemplatesParams, Result<Vec<EventTemplate>, ListEventTemplatesUseCaseError>>
+    for ListEventTemplatesUseCase
+{
+    async fn execute(
+        &self,
+        data: ListEventTemplatesParams,
+    ) -> Result<Vec<EventTemplate>, ListEventTemplatesUseCaseError> {
+        if let Some(organization_id) = data.organization_id {
+            let role = self
+                .users
+                .find_organization_role(organization_id, data.requested_by)
+                .await
+                .map_err(|e| ListEventTemplatesUseCaseError::DatabaseError(e.to_string()))?;
+            if !role.is_some_and(|role| role.can_manage_events()) {
+                return Err(ListEventTemplatesUseCaseError::Forbidden);
+            }
+        }
+
+        self.template_repository
+            .list(data.requested_by, data.organization_id)
+            .await
+            .map_err(|e| ListEventTemplatesUseCaseError::DatabaseError(e.to_string()))
+    }
+}
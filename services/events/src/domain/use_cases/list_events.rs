@@ -0,0 +1,182 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{EventListing, GeoRadius, ListingEntry},
+    CategoryRepository, EventRepository, EventSeriesRepository, VenueRepository,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListEventsParams {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub limit: usize,
+    /// Only events in this category or one of its subcategories.
+    pub category_id: Option<Uuid>,
+    /// Only events carrying every one of these tags.
+    pub tags: Vec<String>,
+    /// Only events at venues in this area, nearest first.
+    pub near: Option<GeoRadius>,
+    /// Languages the caller prefers, most preferred first; one-off events
+    /// come back in their best matching locale.
+    pub languages: Vec<String>,
+}
+
+/// Restricts which one-off events are listed; empty fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    /// Events filed under any of these categories.
+    pub category_ids: Vec<Uuid>,
+    /// Events carrying all of these tags.
+    pub tags: Vec<String>,
+    /// Events at any of these venues; listings follow the order of the
+    /// venues before start time.
+    pub venue_ids: Option<Vec<Uuid>>,
+}
+
+impl EventFilter {
+    /// Whether only the categories and tags are unrestricted; series carry
+    /// neither, so they can only be listed then.
+    pub fn is_unclassified(&self) -> bool {
+        self.category_ids.is_empty() && self.tags.is_empty()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ListEventsUseCaseError {
+    #[error("Category not found")]
+    CategoryNotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct ListEventsUseCase {
+    repository: Arc<dyn EventRepository>,
+    series_repository: Arc<dyn EventSeriesRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    venue_repository: Arc<dyn VenueRepository>,
+}
+
+impl ListEventsUseCase {
+    pub fn new(
+        repository: Arc<dyn EventRepository>,
+        series_repository: Arc<dyn EventSeriesRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+        venue_repository: Arc<dyn VenueRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            series_repository,
+            category_repository,
+            venue_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<ListEventsParams, Result<Vec<ListingEntry>, ListEventsUseCaseError>>
+    for ListEventsUseCase
+{
+    async fn execute(
+        &self,
+        data: ListEventsParams,
+    ) -> Result<Vec<ListingEntry>, ListEventsUseCaseError> {
+        let mut filter = EventFilter {
+            category_ids: Vec::new(),
+            tags: data.tags,
+            venue_ids: None,
+        };
+        if let Some(category_id) = data.category_id {
+            let taxonomy = self
+                .category_repository
+                .find_all()
+                .await
+                .map_err(|e| ListEventsUseCaseError::DatabaseError(e.to_string()))?;
+            if taxonomy.get(category_id).is_none() {
+                return Err(ListEventsUseCaseError::CategoryNotFound);
+            }
+            filter.category_ids = taxonomy.subtree(category_id);
+        }
+
+        // Distance of each venue in the searched area, nearest first
+        let mut distances: HashMap<Uuid, f64> = HashMap::new();
+        if let Some(near) = data.near {
+            let venues = self
+                .venue_repository
+                .find_within(&near)
+                .await
+                .map_err(|e| ListEventsUseCaseError::DatabaseError(e.to_string()))?;
+            let mut venue_ids = Vec::with_capacity(venues.len());
+            for venue in venues {
+                if let Some(location) = venue.location() {
+                    distances.insert(venue.id(), near.center.distance_km(&location));
+                    venue_ids.push(venue.id());
+                }
+            }
+            filter.venue_ids = Some(venue_ids);
+        }
+        let distance_of = |venue_id: Option<Uuid>| {
+            venue_id.and_then(|venue_id| distances.get(&venue_id).copied())
+        };
+
+        let events = self
+            .repository
+            .list_between(data.from, data.to, data.limit as u64, &filter)
+            .await
+            .map_err(|e| ListEventsUseCaseError::DatabaseError(e.to_string()))?;
+
+        // Series are not categorized, so they drop out of filtered listings
+        let series = if filter.is_unclassified() {
+            self.series_repository
+                .find_active_between(data.from, data.to)
+                .await
+                .map_err(|e| ListEventsUseCaseError::DatabaseError(e.to_string()))?
+        } else {
+            Vec::new()
+        };
+
+        let mut listings: Vec<ListingEntry> = events
+            .into_iter()
+            .map(|event| ListingEntry {
+                distance_km: distance_of(event.venue_id()),
+                listing: EventListing::Single(Box::new(event.localize(&data.languages))),
+            })
+            .collect();
+        for series in series {
+            match series.occurrences_between(data.from, data.to) {
+                Ok(occurrences) => listings.extend(
+                    occurrences
+                        .into_iter()
+                        .filter(|occurrence| {
+                            data.near.is_none() || distance_of(occurrence.venue_id()).is_some()
+                        })
+                        .take(data.limit)
+                        .map(|occurrence| ListingEntry {
+                            distance_km: distance_of(occurrence.venue_id()),
+                            listing: EventListing::Occurrence(occurrence),
+                        }),
+                ),
+                Err(e) => log::error!("Skipping series {} in listing: {}", series.id(), e),
+            }
+        }
+
+        if data.near.is_some() {
+            let distance = |entry: &ListingEntry| entry.distance_km.unwrap_or(f64::INFINITY);
+            listings.sort_by(|a, b| {
+                distance(a)
+                    .total_cmp(&distance(b))
+                    .then_with(|| a.listing.starts_at().cmp(b.listing.starts_at()))
+            });
+        } else {
+            listings.sort_by_key(|entry| *entry.listing.starts_at());
+        }
+        listings.truncate(data.limit);
+
+        Ok(listings)
+    }
+}
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{entities::Occurrence, DomainError, EventSeriesRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListOccurrencesParams {
+    pub series_id: Uuid,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub limit: usize,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ListOccurrencesUseCaseError {
+    #[error("Event series not found")]
+    NotFound,
+    #[error("{0}")]
+    InvalidRecurrence(String),
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct ListOccurrencesUseCase {
+    repository: Arc<dyn EventSeriesRepository>,
+}
+
+impl ListOccurrencesUseCase {
+    pub fn new(repository: Arc<dyn EventSeriesRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<ListOccurrencesParams, Result<Vec<Occurrence>, ListOccurrencesUseCaseError>>
+    for ListOccurrencesUseCase
+{
+    async fn execute(
+        &self,
+        data: ListOccurrencesParams,
+    ) -> Result<Vec<Occurrence>, ListOccurrencesUseCaseError> {
+        let series = self
+            .repository
+            .find_by_id(data.series_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => ListOccurrencesUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => ListOccurrencesUseCaseError::DatabaseError(msg),
+            })?;
+
+        let mut occurrences = series
+            .occurrences_between(data.from, data.to)
+            .map_err(|e| ListOccurrencesUseCaseError::InvalidRecurrence(e.to_string()))?;
+        occurrences.truncate(data.limit);
+
+        Ok(occurrences)
+    }
+}
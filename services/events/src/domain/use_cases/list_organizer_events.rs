@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{Event, EventRepository, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListOrganizerEventsParams {
+    pub organizer_id: Uuid,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub limit: usize,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ListOrganizerEventsUseCaseError {
+    #[error("Organizer not found")]
+    OrganizerNotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct ListOrganizerEventsUseCase {
+    repository: Arc<dyn EventRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl ListOrganizerEventsUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<ListOrganizerEventsParams, Result<Vec<Event>, ListOrganizerEventsUseCaseError>>
+    for ListOrganizerEventsUseCase
+{
+    async fn execute(
+        &self,
+        data: ListOrganizerEventsParams,
+    ) -> Result<Vec<Event>, ListOrganizerEventsUseCaseError> {
+        let exists = self
+            .users
+            .user_exists(data.organizer_id)
+            .await
+            .map_err(|e| ListOrganizerEventsUseCaseError::DatabaseError(e.to_string()))?;
+        if !exists {
+            return Err(ListOrganizerEventsUseCaseError::OrganizerNotFound);
+        }
+
+        self.repository
+            .list_by_organizer(data.organizer_id, data.from, data.to, data.limit as u64)
+            .await
+            .map_err(|e| ListOrganizerEventsUseCaseError::DatabaseError(e.to_string()))
+    }
+}
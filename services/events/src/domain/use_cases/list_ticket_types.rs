@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, EventRepository, TicketType};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ListTicketTypesUseCaseError {
+    #[error("Event not found")]
+    EventNotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct ListTicketTypesUseCase {
+    repository: Arc<dyn EventRepository>,
+}
+
+impl ListTicketTypesUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<Uuid, Result<Vec<TicketType>, ListTicketTypesUseCaseError>>
+    for ListTicketTypesUseCase
+{
+    async fn execute(
+        &self,
+        event_id: Uuid,
+    ) -> Result<Vec<TicketType>, ListTicketTypesUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => ListTicketTypesUseCaseError::EventNotFound,
+                DomainError::DatabaseError(msg) => ListTicketTypesUseCaseError::DatabaseError(msg),
+            })?;
+
+        Ok(event.ticket_types().to_vec())
+    }
+}
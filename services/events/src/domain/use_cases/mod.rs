@@ -1,5 +1,170 @@
+pub mod cancel_event;
+pub mod create_category;
 pub mod create_event;
+pub mod create_event_series;
+pub mod create_event_session;
+pub mod create_event_template;
+pub mod create_ticket_type;
+pub mod create_venue;
+pub mod delete_category;
+pub mod delete_event_media;
+pub mod delete_event_session;
+pub mod delete_event_template;
+pub mod delete_event_translation;
+pub mod delete_occurrence_override;
+pub mod delete_ticket_type;
+pub mod dispatch_domain_events;
+pub mod duplicate_event;
+mod event_access;
+pub mod event_copy;
 pub mod get_event;
+pub mod get_event_cancellation;
+pub mod get_event_facets;
+pub mod get_event_history;
+pub mod get_event_revision;
+pub mod get_event_series;
+pub mod get_event_template;
+pub mod get_import_job;
+pub mod get_organizer_calendar;
+pub mod get_seat_map;
+pub mod get_seating_chart;
+pub mod get_ticket_type;
+pub mod get_venue;
+pub mod get_venue_calendar;
+pub mod import_events;
+pub mod import_seating_chart;
+pub mod instantiate_event_template;
+pub mod list_categories;
+pub mod list_event_templates;
+pub mod list_events;
+pub mod list_occurrences;
+pub mod list_organizer_events;
+pub mod list_ticket_types;
+pub mod override_occurrence;
+pub mod publish_event;
+pub mod run_event_cancellations;
+pub mod run_import_jobs;
+pub mod run_publishing_schedule;
+pub mod save_event_translation;
+pub mod update_category;
+pub mod update_event;
+pub mod update_event_session;
+pub mod update_ticket_type;
+pub mod upload_event_media;
 
+pub use cancel_event::{CancelEventParams, CancelEventUseCase, CancelEventUseCaseError};
+pub use create_category::{
+    CreateCategoryParams, CreateCategoryUseCase, CreateCategoryUseCaseError,
+};
 pub use create_event::{CreateEventParams, CreateEventUseCase, CreateEventUseCaseError};
-pub use get_event::{GetEventUseCase, GetEventUseCaseError};
+pub use create_event_series::{
+    CreateEventSeriesParams, CreateEventSeriesUseCase, CreateEventSeriesUseCaseError,
+};
+pub use create_event_session::{
+    CreateEventSessionParams, CreateEventSessionUseCase, CreateEventSessionUseCaseError,
+    EventSessionParams,
+};
+pub use create_event_template::{
+    CreateEventTemplateParams, CreateEventTemplateUseCase, CreateEventTemplateUseCaseError,
+};
+pub use create_ticket_type::{
+    CreateTicketTypeParams, CreateTicketTypeUseCase, CreateTicketTypeUseCaseError, TicketTypeParams,
+};
+pub use create_venue::{CreateVenueParams, CreateVenueUseCase, CreateVenueUseCaseError};
+pub use delete_category::{
+    DeleteCategoryParams, DeleteCategoryUseCase, DeleteCategoryUseCaseError,
+};
+pub use delete_event_media::{
+    DeleteEventMediaParams, DeleteEventMediaUseCase, DeleteEventMediaUseCaseError,
+};
+pub use delete_event_session::{
+    DeleteEventSessionParams, DeleteEventSessionUseCase, DeleteEventSessionUseCaseError,
+};
+pub use delete_event_template::{
+    DeleteEventTemplateParams, DeleteEventTemplateUseCase, DeleteEventTemplateUseCaseError,
+};
+pub use delete_event_translation::{
+    DeleteEventTranslationParams, DeleteEventTranslationUseCase, DeleteEventTranslationUseCaseError,
+};
+pub use delete_occurrence_override::{
+    DeleteOccurrenceOverrideParams, DeleteOccurrenceOverrideUseCase,
+    DeleteOccurrenceOverrideUseCaseError,
+};
+pub use delete_ticket_type::{
+    DeleteTicketTypeParams, DeleteTicketTypeUseCase, DeleteTicketTypeUseCaseError,
+};
+pub use dispatch_domain_events::DispatchDomainEventsUseCase;
+pub use duplicate_event::{
+    DuplicateEventParams, DuplicateEventUseCase, DuplicateEventUseCaseError,
+};
+pub use event_copy::EventCopyOverrides;
+pub use get_event::{GetEventParams, GetEventUseCase, GetEventUseCaseError};
+pub use get_event_cancellation::{
+    GetEventCancellationParams, GetEventCancellationUseCase, GetEventCancellationUseCaseError,
+};
+pub use get_event_facets::{
+    GetEventFacetsParams, GetEventFacetsUseCase, GetEventFacetsUseCaseError,
+};
+pub use get_event_history::{
+    GetEventHistoryParams, GetEventHistoryUseCase, GetEventHistoryUseCaseError,
+};
+pub use get_event_revision::{
+    GetEventRevisionParams, GetEventRevisionUseCase, GetEventRevisionUseCaseError,
+};
+pub use get_event_series::{GetEventSeriesUseCase, GetEventSeriesUseCaseError};
+pub use get_event_template::{
+    GetEventTemplateParams, GetEventTemplateUseCase, GetEventTemplateUseCaseError,
+};
+pub use get_import_job::{GetImportJobParams, GetImportJobUseCase, GetImportJobUseCaseError};
+pub use get_organizer_calendar::{GetOrganizerCalendarUseCase, GetOrganizerCalendarUseCaseError};
+pub use get_seat_map::{GetSeatMapUseCase, GetSeatMapUseCaseError};
+pub use get_seating_chart::{GetSeatingChartUseCase, GetSeatingChartUseCaseError};
+pub use get_ticket_type::{GetTicketTypeUseCase, GetTicketTypeUseCaseError};
+pub use get_venue::{GetVenueUseCase, GetVenueUseCaseError};
+pub use get_venue_calendar::{GetVenueCalendarUseCase, GetVenueCalendarUseCaseError};
+pub use import_events::{
+    ImportEntry, ImportEventsParams, ImportEventsUseCase, ImportEventsUseCaseError,
+};
+pub use import_seating_chart::{
+    ImportSeatingChartParams, ImportSeatingChartUseCase, ImportSeatingChartUseCaseError, RowLayout,
+    SeatLayout, SectionLayout,
+};
+pub use instantiate_event_template::{
+    InstantiateEventTemplateParams, InstantiateEventTemplateUseCase,
+    InstantiateEventTemplateUseCaseError,
+};
+pub use list_categories::{ListCategoriesUseCase, ListCategoriesUseCaseError};
+pub use list_event_templates::{
+    ListEventTemplatesParams, ListEventTemplatesUseCase, ListEventTemplatesUseCaseError,
+};
+pub use list_events::{EventFilter, ListEventsParams, ListEventsUseCase, ListEventsUseCaseError};
+pub use list_occurrences::{
+    ListOccurrencesParams, ListOccurrencesUseCase, ListOccurrencesUseCaseError,
+};
+pub use list_organizer_events::{
+    ListOrganizerEventsParams, ListOrganizerEventsUseCase, ListOrganizerEventsUseCaseError,
+};
+pub use list_ticket_types::{ListTicketTypesUseCase, ListTicketTypesUseCaseError};
+pub use override_occurrence::{
+    OverrideOccurrenceParams, OverrideOccurrenceUseCase, OverrideOccurrenceUseCaseError,
+};
+pub use publish_event::{PublishEventParams, PublishEventUseCase, PublishEventUseCaseError};
+pub use run_event_cancellations::RunEventCancellationsUseCase;
+pub use run_import_jobs::RunImportJobsUseCase;
+pub use run_publishing_schedule::RunPublishingScheduleUseCase;
+pub use save_event_translation::{
+    SaveEventTranslationParams, SaveEventTranslationUseCase, SaveEventTranslationUseCaseError,
+};
+pub use update_category::{
+    UpdateCategoryParams, UpdateCategoryUseCase, UpdateCategoryUseCaseError,
+};
+pub use update_event::{UpdateEventParams, UpdateEventUseCase, UpdateEventUseCaseError};
+pub use update_event_session::{
+    UpdateEventSessionParams, UpdateEventSessionUseCase, UpdateEventSessionUseCaseError,
+};
+pub use update_ticket_type::{
+    UpdateTicketTypeParams, UpdateTicketTypeUseCase, UpdateTicketTypeUseCaseError,
+};
+pub use upload_event_media::{
+    UploadEventMediaParams, UploadEventMediaUseCase, UploadEventMediaUseCaseError,
+};
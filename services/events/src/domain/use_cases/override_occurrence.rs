@@ -0,0 +1,116 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage_series;
+use crate::domain::{
+    entities::{OccurrenceOverride, RichText},
+    DomainError, EventSeriesRepository, UsersClient, VenueRepository,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverrideOccurrenceParams {
+    pub series_id: Uuid,
+    pub occurrence_start: DateTime<Utc>,
+    pub title: Option<String>,
+    pub description: Option<RichText>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub venue_name: Option<String>,
+    pub venue_id: Option<Uuid>,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum OverrideOccurrenceUseCaseError {
+    #[error("Event series not found")]
+    SeriesNotFound,
+    #[error("The series has no occurrence at the given start")]
+    OccurrenceNotFound,
+    #[error("Venue not found")]
+    VenueNotFound,
+    #[error("Only the series' organizer or its organization's owners and managers can change its occurrences")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct OverrideOccurrenceUseCase {
+    repository: Arc<dyn EventSeriesRepository>,
+    venue_repository: Arc<dyn VenueRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl OverrideOccurrenceUseCase {
+    pub fn new(
+        repository: Arc<dyn EventSeriesRepository>,
+        venue_repository: Arc<dyn VenueRepository>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            repository,
+            venue_repository,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<OverrideOccurrenceParams, Result<OccurrenceOverride, OverrideOccurrenceUseCaseError>>
+    for OverrideOccurrenceUseCase
+{
+    async fn execute(
+        &self,
+        data: OverrideOccurrenceParams,
+    ) -> Result<OccurrenceOverride, OverrideOccurrenceUseCaseError> {
+        let series = self
+            .repository
+            .find_by_id(data.series_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => OverrideOccurrenceUseCaseError::SeriesNotFound,
+                DomainError::DatabaseError(msg) => {
+                    OverrideOccurrenceUseCaseError::DatabaseError(msg)
+                }
+            })?;
+
+        if !can_manage_series(self.users.as_ref(), &series, data.requested_by)
+            .await
+            .map_err(|e| OverrideOccurrenceUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(OverrideOccurrenceUseCaseError::Forbidden);
+        }
+
+        let is_occurrence = series
+            .recurrence()
+            .map(|recurrence| recurrence.contains(data.occurrence_start))
+            .unwrap_or(false);
+        if !is_occurrence {
+            return Err(OverrideOccurrenceUseCaseError::OccurrenceNotFound);
+        }
+
+        if let Some(venue_id) = data.venue_id {
+            self.venue_repository
+                .find_by_id(venue_id)
+                .await
+                .map_err(|e| match e {
+                    DomainError::NotFound(_) => OverrideOccurrenceUseCaseError::VenueNotFound,
+                    DomainError::DatabaseError(msg) => {
+                        OverrideOccurrenceUseCaseError::DatabaseError(msg)
+                    }
+                })?;
+        }
+
+        self.repository
+            .upsert_override(data)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => OverrideOccurrenceUseCaseError::SeriesNotFound,
+                DomainError::DatabaseError(msg) => {
+                    OverrideOccurrenceUseCaseError::DatabaseError(msg)
+                }
+            })
+    }
+}
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use crate::domain::{DomainError, Event, EventRepository, UsersClient};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublishEventParams {
+    pub event_id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PublishEventUseCaseError {
+    #[error("Event not found")]
+    NotFound,
+    #[error("Only the organizer or the organization's owners and managers can change this event")]
+    Forbidden,
+    #[error("Cancelled events cannot be changed")]
+    EventCancelled,
+    #[error("Event is already published")]
+    AlreadyPublished,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Publishes a draft or scheduled event right away.
+pub struct PublishEventUseCase {
+    repository: Arc<dyn EventRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl PublishEventUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<PublishEventParams, Result<Event, PublishEventUseCaseError>> for PublishEventUseCase {
+    async fn execute(&self, data: PublishEventParams) -> Result<Event, PublishEventUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => PublishEventUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => PublishEventUseCaseError::DatabaseError(msg),
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| PublishEventUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(PublishEventUseCaseError::Forbidden);
+        }
+        if event.is_cancelled() {
+            return Err(PublishEventUseCaseError::EventCancelled);
+        }
+        if event.publication().is_published() {
+            return Err(PublishEventUseCaseError::AlreadyPublished);
+        }
+
+        self.repository
+            .publish(data.event_id, Utc::now())
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => PublishEventUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => PublishEventUseCaseError::DatabaseError(msg),
+            })?
+            .ok_or(PublishEventUseCaseError::AlreadyPublished)
+    }
+}
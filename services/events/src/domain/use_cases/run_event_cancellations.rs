@@ -0,0 +1,159 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use shared::UseCase;
+use std::sync::Arc;
+
+use crate::domain::{
+    entities::{CancellationStep, EventCancellation},
+    CancellationRepository, DomainResult, EventRepository, HolderNotifier, Ticketing,
+};
+
+/// Cancellations worked on per run.
+const BATCH_SIZE: u64 = 20;
+
+/// How long a claimed cancellation is left alone before another runner may
+/// take it over; long enough to notify a sold-out stadium.
+const CLAIM_LEASE_SECONDS: i64 = 15 * 60;
+
+/// Holders notified between saves of the notification step's progress,
+/// each of which also renews the claim. A runner that dies mid-step
+/// leaves at most this many to be notified twice.
+const NOTIFY_SAVE_INTERVAL: i32 = 50;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RunEventCancellationsUseCaseError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Whether the runner still holds the cancellation it is working on.
+enum Claim {
+    Held,
+    /// Another runner took it over after the lease lapsed.
+    Lost,
+}
+
+/// Drives cancelled events through stopping sales, refunding orders and
+/// notifying ticket holders. Each due cancellation is taken as far as it
+/// goes; a failing step is recorded and retried by a later run. Returns how
+/// many cancellations were completed.
+pub struct RunEventCancellationsUseCase {
+    cancellations: Arc<dyn CancellationRepository>,
+    events: Arc<dyn EventRepository>,
+    ticketing: Arc<dyn Ticketing>,
+    notifier: Arc<dyn HolderNotifier>,
+}
+
+impl RunEventCancellationsUseCase {
+    pub fn new(
+        cancellations: Arc<dyn CancellationRepository>,
+        events: Arc<dyn EventRepository>,
+        ticketing: Arc<dyn Ticketing>,
+        notifier: Arc<dyn HolderNotifier>,
+    ) -> Self {
+        Self {
+            cancellations,
+            events,
+            ticketing,
+            notifier,
+        }
+    }
+
+    async fn run_step(
+        &self,
+        cancellation: &mut EventCancellation,
+        step: CancellationStep,
+    ) -> DomainResult<Claim> {
+        let event_id = cancellation.event_id();
+        match step {
+            CancellationStep::StopSales => self.ticketing.stop_sales(event_id).await?,
+            CancellationStep::RefundOrders => {
+                let refunded = self.ticketing.refund_orders(event_id).await?;
+                cancellation.record_refunds(refunded);
+            }
+            CancellationStep::NotifyHolders => {
+                let holders = self.ticketing.ticket_holders(event_id).await?;
+                if holders.is_empty() {
+                    return Ok(Claim::Held);
+                }
+                let event = self.events.find_by_id(event_id).await?;
+                let already = usize::try_from(cancellation.holders_notified()).unwrap_or(0);
+                for holder_id in holders.into_iter().skip(already) {
+                    self.notifier
+                        .notify_cancelled(holder_id, &event, cancellation.reason())
+                        .await?;
+                    cancellation.record_notified();
+                    if cancellation.holders_notified() % NOTIFY_SAVE_INTERVAL == 0 {
+                        let held_until = Utc::now() + Duration::seconds(CLAIM_LEASE_SECONDS);
+                        if !self
+                            .cancellations
+                            .save_progress(cancellation, held_until)
+                            .await?
+                        {
+                            return Ok(Claim::Lost);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(Claim::Held)
+    }
+}
+
+#[async_trait]
+impl UseCase<DateTime<Utc>, Result<usize, RunEventCancellationsUseCaseError>>
+    for RunEventCancellationsUseCase
+{
+    async fn execute(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<usize, RunEventCancellationsUseCaseError> {
+        let due = self
+            .cancellations
+            .claim_due(now, BATCH_SIZE, Duration::seconds(CLAIM_LEASE_SECONDS))
+            .await
+            .map_err(|e| RunEventCancellationsUseCaseError::DatabaseError(e.to_string()))?;
+
+        let mut completed = 0;
+        'cancellations: for mut cancellation in due {
+            while let Some(step) = cancellation.step() {
+                match self.run_step(&mut cancellation, step).await {
+                    Ok(Claim::Held) => cancellation.advance(Utc::now()),
+                    Ok(Claim::Lost) => {
+                        log::warn!(
+                            "Lost the claim on the cancellation of event {} at {}",
+                            cancellation.event_id(),
+                            step.as_str()
+                        );
+                        continue 'cancellations;
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            "Cancellation of event {} failed at {}: {}",
+                            cancellation.event_id(),
+                            step.as_str(),
+                            e
+                        );
+                        cancellation.fail(e.to_string(), Utc::now());
+                        break;
+                    }
+                }
+            }
+            let saved = self
+                .cancellations
+                .save(&cancellation)
+                .await
+                .map_err(|e| RunEventCancellationsUseCaseError::DatabaseError(e.to_string()))?;
+            if !saved {
+                log::warn!(
+                    "Lost the claim on the cancellation of event {}; progress left to its new runner",
+                    cancellation.event_id()
+                );
+            } else if cancellation.is_completed() {
+                completed += 1;
+            }
+        }
+
+        Ok(completed)
+    }
+}
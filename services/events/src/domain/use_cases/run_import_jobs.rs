@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared::UseCase;
+use std::sync::Arc;
+
+use super::import_events::ImportRunner;
+use super::CreateEventUseCase;
+use crate::domain::{EventRepository, ImportJobRepository};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RunImportJobsUseCaseError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Works through queued import jobs one at a time, including jobs whose
+/// runner died and let its claim lapse. Returns how many jobs were finished.
+pub struct RunImportJobsUseCase {
+    runner: ImportRunner,
+    job_repository: Arc<dyn ImportJobRepository>,
+}
+
+impl RunImportJobsUseCase {
+    pub fn new(
+        create_event: Arc<CreateEventUseCase>,
+        repository: Arc<dyn EventRepository>,
+        job_repository: Arc<dyn ImportJobRepository>,
+    ) -> Self {
+        Self {
+            runner: ImportRunner::new(create_event, repository, job_repository.clone()),
+            job_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<DateTime<Utc>, Result<usize, RunImportJobsUseCaseError>> for RunImportJobsUseCase {
+    async fn execute(&self, now: DateTime<Utc>) -> Result<usize, RunImportJobsUseCaseError> {
+        let mut finished = 0;
+        while let Some((job, entries)) = self
+            .job_repository
+            .claim_next(now, ImportRunner::lease())
+            .await
+            .map_err(|e| RunImportJobsUseCaseError::DatabaseError(e.to_string()))?
+        {
+            let job = self.runner.run(job, entries).await;
+            if job.is_finished() {
+                log::info!(
+                    "Import job {} finished: {} created, {} duplicate, {} invalid, {} failed",
+                    job.id(),
+                    job.created_count(),
+                    job.duplicate_count(),
+                    job.invalid_count(),
+                    job.failed_count()
+                );
+                finished += 1;
+            }
+        }
+
+        Ok(finished)
+    }
+}
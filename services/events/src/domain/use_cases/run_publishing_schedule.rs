@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared::UseCase;
+use std::sync::Arc;
+
+use crate::domain::{entities::DomainEvent, EventRepository};
+
+/// Events transitioned per query; the use case keeps going until a batch
+/// comes back short, so a backlog after downtime is worked off in one run.
+const BATCH_SIZE: u64 = 100;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RunPublishingScheduleUseCaseError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Publishes events and opens ticket sales whose scheduled time is at or
+/// before the given instant.
+pub struct RunPublishingScheduleUseCase {
+    repository: Arc<dyn EventRepository>,
+}
+
+impl RunPublishingScheduleUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<DateTime<Utc>, Result<Vec<DomainEvent>, RunPublishingScheduleUseCaseError>>
+    for RunPublishingScheduleUseCase
+{
+    async fn execute(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<DomainEvent>, RunPublishingScheduleUseCaseError> {
+        let mut emitted = Vec::new();
+
+        // Publish first, so sales due at the same time open in this run
+        loop {
+            let batch = self
+                .repository
+                .publish_due(now, BATCH_SIZE)
+                .await
+                .map_err(|e| RunPublishingScheduleUseCaseError::DatabaseError(e.to_string()))?;
+            let done = batch.len() < BATCH_SIZE as usize;
+            emitted.extend(batch);
+            if done {
+                break;
+            }
+        }
+        loop {
+            let batch = self
+                .repository
+                .open_sales_due(now, BATCH_SIZE)
+                .await
+                .map_err(|e| RunPublishingScheduleUseCaseError::DatabaseError(e.to_string()))?;
+            let done = batch.len() < BATCH_SIZE as usize;
+            emitted.extend(batch);
+            if done {
+                break;
+            }
+        }
+
+        Ok(emitted)
+    }
+}
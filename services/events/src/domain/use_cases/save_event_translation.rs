@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use crate::domain::{
+    entities::{EventTranslation, Locale, RichText},
+    DomainError, EventRepository, UsersClient,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveEventTranslationParams {
+    pub event_id: Uuid,
+    pub locale: Locale,
+    pub title: String,
+    pub description: RichText,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SaveEventTranslationUseCaseError {
+    #[error("Event not found")]
+    NotFound,
+    #[error("Only the organizer or the organization's owners and managers can change this event")]
+    Forbidden,
+    #[error("{0} is the event's default locale; change the event itself instead")]
+    DefaultLocale(Locale),
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct SaveEventTranslationUseCase {
+    repository: Arc<dyn EventRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl SaveEventTranslationUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<SaveEventTranslationParams, Result<EventTranslation, SaveEventTranslationUseCaseError>>
+    for SaveEventTranslationUseCase
+{
+    async fn execute(
+        &self,
+        data: SaveEventTranslationParams,
+    ) -> Result<EventTranslation, SaveEventTranslationUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => SaveEventTranslationUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => {
+                    SaveEventTranslationUseCaseError::DatabaseError(msg)
+                }
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| SaveEventTranslationUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(SaveEventTranslationUseCaseError::Forbidden);
+        }
+        if &data.locale == event.default_locale() {
+            return Err(SaveEventTranslationUseCaseError::DefaultLocale(data.locale));
+        }
+
+        let now = Utc::now();
+        let created_at = event
+            .translation(&data.locale)
+            .map_or(now, |translation| *translation.created_at());
+        let translation = EventTranslation::new(
+            data.event_id,
+            data.locale,
+            data.title,
+            data.description,
+            created_at,
+            now,
+        );
+
+        self.repository
+            .save_translation(&translation)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => SaveEventTranslationUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => {
+                    SaveEventTranslationUseCaseError::DatabaseError(msg)
+                }
+            })
+    }
+}
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{Administrators, Category},
+    CategoryRepository, DomainError,
+};
+
+/// Partial update of a category; `None` leaves the field unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCategoryParams {
+    pub id: Uuid,
+    pub name: Option<String>,
+    pub slug: Option<String>,
+    /// `Some(None)` moves the category to the top level.
+    pub parent_id: Option<Option<Uuid>>,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateCategoryUseCaseError {
+    #[error("Only administrators can change categories")]
+    Forbidden,
+    #[error("Category not found")]
+    NotFound,
+    #[error("Parent category not found")]
+    ParentNotFound,
+    #[error("A category cannot be moved under itself or one of its subcategories")]
+    Cycle,
+    #[error("Slug `{0}` is already in use")]
+    SlugTaken(String),
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct UpdateCategoryUseCase {
+    repository: Arc<dyn CategoryRepository>,
+    admins: Administrators,
+}
+
+impl UpdateCategoryUseCase {
+    pub fn new(repository: Arc<dyn CategoryRepository>, admins: Administrators) -> Self {
+        Self { repository, admins }
+    }
+}
+
+#[async_trait]
+impl UseCase<UpdateCategoryParams, Result<Category, UpdateCategoryUseCaseError>>
+    for UpdateCategoryUseCase
+{
+    async fn execute(
+        &self,
+        data: UpdateCategoryParams,
+    ) -> Result<Category, UpdateCategoryUseCaseError> {
+        if !self.admins.contains(data.requested_by) {
+            return Err(UpdateCategoryUseCaseError::Forbidden);
+        }
+
+        let taxonomy = self
+            .repository
+            .find_all()
+            .await
+            .map_err(|e| UpdateCategoryUseCaseError::DatabaseError(e.to_string()))?;
+
+        if taxonomy.get(data.id).is_none() {
+            return Err(UpdateCategoryUseCaseError::NotFound);
+        }
+        if let Some(Some(parent_id)) = data.parent_id {
+            if taxonomy.get(parent_id).is_none() {
+                return Err(UpdateCategoryUseCaseError::ParentNotFound);
+            }
+            if taxonomy.is_within(parent_id, data.id) {
+                return Err(UpdateCategoryUseCaseError::Cycle);
+            }
+        }
+        if let Some(slug) = &data.slug {
+            if taxonomy
+                .categories()
+                .iter()
+                .any(|category| category.slug() == slug && category.id() != data.id)
+            {
+                return Err(UpdateCategoryUseCaseError::SlugTaken(slug.clone()));
+            }
+        }
+
+        self.repository.update(data).await.map_err(|e| match e {
+            DomainError::NotFound(_) => UpdateCategoryUseCaseError::NotFound,
+            DomainError::DatabaseError(msg) => UpdateCategoryUseCaseError::DatabaseError(msg),
+        })
+    }
+}
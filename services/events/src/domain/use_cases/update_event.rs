@@ -0,0 +1,198 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use crate::domain::{
+    entities::{Locale, PublicationError, RichText, ScheduleEnd, ScheduleError, ScheduleTime},
+    CategoryRepository, DomainError, Event, EventRepository, UsersClient, VenueRepository,
+};
+
+/// Partial update of an event; `None` leaves the field unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateEventParams {
+    pub id: Uuid,
+    pub title: Option<String>,
+    pub description: Option<RichText>,
+    /// Locale the title and description are written in from now on.
+    pub default_locale: Option<Locale>,
+    pub starts_at: Option<ScheduleTime>,
+    /// New timezone; when unset, moving to another venue adopts the venue's.
+    pub timezone: Option<Tz>,
+    pub end: Option<ScheduleEnd>,
+    pub doors_open_at: Option<ScheduleTime>,
+    pub venue_name: Option<String>,
+    pub venue_id: Option<Uuid>,
+    /// Replaces the event's categories.
+    pub category_ids: Option<Vec<Uuid>>,
+    /// Replaces the event's tags; normalized like on creation.
+    pub tags: Option<Vec<String>>,
+    /// Moves a pending publication.
+    pub publish_at: Option<DateTime<Utc>>,
+    /// Moves a pending opening of ticket sales.
+    pub on_sale_at: Option<DateTime<Utc>>,
+    /// Version the change was made against; `None` applies it to whatever
+    /// the current version is.
+    pub expected_version: Option<i32>,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateEventUseCaseError {
+    #[error("Event not found")]
+    NotFound,
+    #[error("Only the organizer or the organization's owners and managers can change this event")]
+    Forbidden,
+    #[error("Venue not found")]
+    VenueNotFound,
+    #[error("Category {0} not found")]
+    CategoryNotFound(Uuid),
+    #[error("{0}")]
+    InvalidSchedule(#[from] ScheduleError),
+    #[error("Event date must be in the future")]
+    EventDateInPast,
+    #[error("{0}")]
+    InvalidPublication(#[from] PublicationError),
+    #[error("Sessions would fall outside the event; move or delete them first")]
+    SessionsOutsideEvent,
+    #[error("The event is already translated into {0}; delete that translation first")]
+    DefaultLocaleTranslated(Locale),
+    #[error("Cancelled events cannot be changed")]
+    EventCancelled,
+    #[error("Event has been modified since the given version")]
+    VersionMismatch,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct UpdateEventUseCase {
+    repository: Arc<dyn EventRepository>,
+    venue_repository: Arc<dyn VenueRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl UpdateEventUseCase {
+    pub fn new(
+        repository: Arc<dyn EventRepository>,
+        venue_repository: Arc<dyn VenueRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            repository,
+            venue_repository,
+            category_repository,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<UpdateEventParams, Result<Event, UpdateEventUseCaseError>> for UpdateEventUseCase {
+    async fn execute(&self, data: UpdateEventParams) -> Result<Event, UpdateEventUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => UpdateEventUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => UpdateEventUseCaseError::DatabaseError(msg),
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| UpdateEventUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(UpdateEventUseCaseError::Forbidden);
+        }
+
+        if event.is_cancelled() {
+            return Err(UpdateEventUseCaseError::EventCancelled);
+        }
+
+        if data
+            .expected_version
+            .is_some_and(|version| version != event.version())
+        {
+            return Err(UpdateEventUseCaseError::VersionMismatch);
+        }
+
+        let venue = match data.venue_id {
+            Some(venue_id) => Some(self.venue_repository.find_by_id(venue_id).await.map_err(
+                |e| match e {
+                    DomainError::NotFound(_) => UpdateEventUseCaseError::VenueNotFound,
+                    DomainError::DatabaseError(msg) => UpdateEventUseCaseError::DatabaseError(msg),
+                },
+            )?),
+            None => None,
+        };
+
+        let timezone = data
+            .timezone
+            .or(venue.as_ref().map(|venue| venue.timezone()));
+        let schedule =
+            event
+                .schedule()
+                .reschedule(timezone, data.starts_at, data.end, data.doors_open_at)?;
+        let now = Utc::now();
+        if data.starts_at.is_some() && *schedule.starts_at() < now {
+            return Err(UpdateEventUseCaseError::EventDateInPast);
+        }
+        // Sessions move along with the start
+        let shift = *schedule.starts_at() - *event.event_date();
+        if !event.sessions().iter().all(|session| {
+            schedule.covers(
+                &(*session.starts_at() + shift),
+                &(*session.ends_at() + shift),
+            )
+        }) {
+            return Err(UpdateEventUseCaseError::SessionsOutsideEvent);
+        }
+        if let Some(locale) = data
+            .default_locale
+            .as_ref()
+            .filter(|locale| event.translation(locale).is_some())
+        {
+            return Err(UpdateEventUseCaseError::DefaultLocaleTranslated(
+                locale.clone(),
+            ));
+        }
+        let publication = event.publication().reschedule(
+            data.publish_at,
+            data.on_sale_at,
+            *schedule.starts_at(),
+            now,
+        )?;
+
+        if !data.category_ids.as_ref().is_none_or(Vec::is_empty) {
+            let taxonomy = self
+                .category_repository
+                .find_all()
+                .await
+                .map_err(|e| UpdateEventUseCaseError::DatabaseError(e.to_string()))?;
+            if let Some(unknown) = data
+                .category_ids
+                .iter()
+                .flatten()
+                .find(|id| taxonomy.get(**id).is_none())
+            {
+                return Err(UpdateEventUseCaseError::CategoryNotFound(*unknown));
+            }
+        }
+
+        self.repository
+            .update(data, schedule, publication)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => UpdateEventUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => UpdateEventUseCaseError::DatabaseError(msg),
+            })?
+            // Another change landed between the check above and the update
+            .ok_or(UpdateEventUseCaseError::VersionMismatch)
+    }
+}
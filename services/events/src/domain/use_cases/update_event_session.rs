@@ -0,0 +1,209 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::create_event_session::{resolve_session_times, SessionTimesError};
+use super::{event_access::can_manage, EventSessionParams};
+use crate::domain::{
+    entities::{EventSession, ScheduleError},
+    DomainError, Event, EventRepository, UsersClient,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateEventSessionParams {
+    pub event_id: Uuid,
+    pub session_id: Uuid,
+    pub session: EventSessionParams,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateEventSessionUseCaseError {
+    #[error("Event not found")]
+    EventNotFound,
+    #[error("Session not found")]
+    SessionNotFound,
+    #[error("Only the organizer or the organization's owners and managers can change this event")]
+    Forbidden,
+    #[error("{0}")]
+    InvalidSchedule(ScheduleError),
+    #[error("Session must end after it starts")]
+    EndsBeforeStart,
+    #[error("Session must take place within the event")]
+    OutsideEvent,
+    #[error("{0} tickets already admit to the session, more than the new capacity")]
+    CapacityBelowTickets(i64),
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+impl From<SessionTimesError> for UpdateEventSessionUseCaseError {
+    fn from(e: SessionTimesError) -> Self {
+        match e {
+            SessionTimesError::InvalidSchedule(e) => Self::InvalidSchedule(e),
+            SessionTimesError::EndsBeforeStart => Self::EndsBeforeStart,
+            SessionTimesError::OutsideEvent => Self::OutsideEvent,
+        }
+    }
+}
+
+/// Replaces a session's details.
+pub struct UpdateEventSessionUseCase {
+    repository: Arc<dyn EventRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl UpdateEventSessionUseCase {
+    pub fn new(repository: Arc<dyn EventRepository>, users: Arc<dyn UsersClient>) -> Self {
+        Self { repository, users }
+    }
+}
+
+#[async_trait]
+impl UseCase<UpdateEventSessionParams, Result<EventSession, UpdateEventSessionUseCaseError>>
+    for UpdateEventSessionUseCase
+{
+    async fn execute(
+        &self,
+        data: UpdateEventSessionParams,
+    ) -> Result<EventSession, UpdateEventSessionUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => UpdateEventSessionUseCaseError::EventNotFound,
+                DomainError::DatabaseError(msg) => {
+                    UpdateEventSessionUseCaseError::DatabaseError(msg)
+                }
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| UpdateEventSessionUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(UpdateEventSessionUseCaseError::Forbidden);
+        }
+
+        let current = event
+            .session(data.session_id)
+            .ok_or(UpdateEventSessionUseCaseError::SessionNotFound)?;
+        let (starts_at, ends_at) = resolve_session_times(&event, &data.session)?;
+        check_capacity(&event, data.session_id, &starts_at, data.session.capacity)?;
+
+        let session = EventSession::new(
+            data.session_id,
+            data.event_id,
+            data.session.title,
+            data.session.stage,
+            starts_at,
+            ends_at,
+            data.session.capacity,
+            *current.created_at(),
+            Utc::now(),
+        );
+
+        self.repository
+            .update_session(&session)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => UpdateEventSessionUseCaseError::SessionNotFound,
+                DomainError::DatabaseError(msg) => {
+                    UpdateEventSessionUseCaseError::DatabaseError(msg)
+                }
+            })
+    }
+}
+
+/// Checks that the session `session_id`, starting at `starts_at`, still
+/// seats everyone holding a ticket that admits to it.
+fn check_capacity(
+    event: &Event,
+    session_id: Uuid,
+    starts_at: &DateTime<Utc>,
+    capacity: Option<i32>,
+) -> Result<(), UpdateEventSessionUseCaseError> {
+    let Some(capacity) = capacity else {
+        return Ok(());
+    };
+    let tickets = event.tickets_admitted_to_session(session_id, starts_at, None);
+    if tickets > i64::from(capacity) {
+        return Err(UpdateEventSessionUseCaseError::CapacityBelowTickets(
+            tickets,
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Admission, EventSchedule, TicketType, TicketTypeVisibility};
+    use crate::domain::fixtures::EventFixture;
+    use chrono::{Duration, TimeZone};
+    use chrono_tz::Tz;
+
+    fn ticket_type(event_id: Uuid, admission: Admission, quantity: i32) -> TicketType {
+        let at = Utc.with_ymd_and_hms(2026, 7, 1, 0, 0, 0).unwrap();
+        TicketType::new(
+            Uuid::new_v4(),
+            event_id,
+            "Ticket".to_string(),
+            2500,
+            "EUR".to_string(),
+            quantity,
+            1,
+            4,
+            None,
+            None,
+            TicketTypeVisibility::Public,
+            admission,
+            at,
+            at,
+        )
+    }
+
+    #[test]
+    fn test_capacity_cannot_drop_below_tickets_admitting_to_the_session() {
+        let id = Uuid::new_v4();
+        let starts_at = Utc.with_ymd_and_hms(2026, 7, 17, 10, 0, 0).unwrap();
+        let session = EventSession::new(
+            Uuid::new_v4(),
+            id,
+            "Workshop".to_string(),
+            None,
+            starts_at,
+            starts_at + Duration::hours(1),
+            Some(200),
+            starts_at,
+            starts_at,
+        );
+        let event = EventFixture {
+            id,
+            schedule: EventSchedule::new(
+                Tz::UTC,
+                starts_at,
+                Some(starts_at + Duration::hours(8)),
+                None,
+            ),
+            ..EventFixture::at(starts_at)
+        }
+        .build()
+        .with_sessions(vec![session.clone()])
+        .with_ticket_types(vec![
+            ticket_type(id, Admission::Session(session.id()), 120),
+            ticket_type(id, Admission::Event, 60),
+        ]);
+
+        assert!(check_capacity(&event, session.id(), &starts_at, Some(180)).is_ok());
+        assert!(check_capacity(&event, session.id(), &starts_at, None).is_ok());
+        // Whole-event tickets count as well as the session's own
+        assert!(matches!(
+            check_capacity(&event, session.id(), &starts_at, Some(179)),
+            Err(UpdateEventSessionUseCaseError::CapacityBelowTickets(180))
+        ));
+    }
+}
@@ -0,0 +1,119 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{
+    create_ticket_type::{check_section, sales_end_in_time, SectionMismatch},
+    event_access::can_manage,
+    TicketTypeParams,
+};
+use crate::domain::{
+    entities::AdmissionError, DomainError, EventRepository, TicketType, UsersClient,
+    VenueRepository,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTicketTypeParams {
+    pub event_id: Uuid,
+    pub ticket_type_id: Uuid,
+    pub ticket_type: TicketTypeParams,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateTicketTypeUseCaseError {
+    #[error("Event not found")]
+    EventNotFound,
+    #[error("Only the organizer or the organization's owners and managers can change this event")]
+    Forbidden,
+    #[error("Ticket type not found")]
+    TicketTypeNotFound,
+    #[error("Ticket sales must end before the event, day or session they admit to starts")]
+    SalesEndAfterEvent,
+    #[error("{0}")]
+    InvalidAdmission(#[from] AdmissionError),
+    #[error("Section is not part of the event venue's seating chart")]
+    UnknownSection,
+    #[error("The event's venue has a seating chart, so the ticket type must name a section")]
+    SectionRequired,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct UpdateTicketTypeUseCase {
+    repository: Arc<dyn EventRepository>,
+    venues: Arc<dyn VenueRepository>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl UpdateTicketTypeUseCase {
+    pub fn new(
+        repository: Arc<dyn EventRepository>,
+        venues: Arc<dyn VenueRepository>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            repository,
+            venues,
+            users,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<UpdateTicketTypeParams, Result<TicketType, UpdateTicketTypeUseCaseError>>
+    for UpdateTicketTypeUseCase
+{
+    async fn execute(
+        &self,
+        data: UpdateTicketTypeParams,
+    ) -> Result<TicketType, UpdateTicketTypeUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => UpdateTicketTypeUseCaseError::EventNotFound,
+                DomainError::DatabaseError(msg) => UpdateTicketTypeUseCaseError::DatabaseError(msg),
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| UpdateTicketTypeUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(UpdateTicketTypeUseCaseError::Forbidden);
+        }
+
+        let ticket_type = &data.ticket_type;
+        event.check_admission(
+            ticket_type.admission,
+            ticket_type.quantity,
+            Some(data.ticket_type_id),
+        )?;
+        if !sales_end_in_time(&event, ticket_type) {
+            return Err(UpdateTicketTypeUseCaseError::SalesEndAfterEvent);
+        }
+        match check_section(self.venues.as_ref(), &event, ticket_type.section_id)
+            .await
+            .map_err(|e| UpdateTicketTypeUseCaseError::DatabaseError(e.to_string()))?
+        {
+            None => {}
+            Some(SectionMismatch::Unknown) => {
+                return Err(UpdateTicketTypeUseCaseError::UnknownSection)
+            }
+            Some(SectionMismatch::Required) => {
+                return Err(UpdateTicketTypeUseCaseError::SectionRequired)
+            }
+        }
+
+        self.repository
+            .update_ticket_type(data.event_id, data.ticket_type_id, data.ticket_type)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => UpdateTicketTypeUseCaseError::TicketTypeNotFound,
+                DomainError::DatabaseError(msg) => UpdateTicketTypeUseCaseError::DatabaseError(msg),
+            })
+    }
+}
@@ -0,0 +1,201 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::event_access::can_manage;
+use crate::domain::{
+    entities::{MediaAsset, MediaFormat, MediaKind},
+    DomainError, EventRepository, ImageProcessor, ObjectStorage, UsersClient,
+};
+
+/// Most gallery items an event can have.
+pub const MAX_GALLERY_ITEMS: usize = 20;
+
+#[derive(Debug, Clone)]
+pub struct UploadEventMediaParams {
+    pub event_id: Uuid,
+    pub requested_by: Uuid,
+    pub kind: MediaKind,
+    /// Declared `Content-Type` of the upload.
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UploadEventMediaUseCaseError {
+    #[error("Event not found")]
+    NotFound,
+    #[error("Only the organizer or the organization's owners and managers can change this event")]
+    Forbidden,
+    #[error("Cancelled events cannot be changed")]
+    EventCancelled,
+    #[error("Unsupported media type `{0}`; posters accept image/jpeg, image/png and image/webp, galleries also video/mp4")]
+    UnsupportedType(String),
+    #[error("File is empty")]
+    Empty,
+    #[error("File exceeds the {0} byte limit for its type")]
+    TooLarge(usize),
+    #[error("File content does not match its declared type `{0}`")]
+    ContentMismatch(String),
+    #[error("Image could not be decoded")]
+    Unreadable,
+    #[error("Gallery is full; an event can have at most {MAX_GALLERY_ITEMS} gallery items")]
+    GalleryFull,
+    #[error("Storage error: {0}")]
+    StorageError(String),
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct UploadEventMediaUseCase {
+    repository: Arc<dyn EventRepository>,
+    storage: Arc<dyn ObjectStorage>,
+    images: Arc<dyn ImageProcessor>,
+    users: Arc<dyn UsersClient>,
+}
+
+impl UploadEventMediaUseCase {
+    pub fn new(
+        repository: Arc<dyn EventRepository>,
+        storage: Arc<dyn ObjectStorage>,
+        images: Arc<dyn ImageProcessor>,
+        users: Arc<dyn UsersClient>,
+    ) -> Self {
+        Self {
+            repository,
+            storage,
+            images,
+            users,
+        }
+    }
+
+    /// Best-effort removal of stored objects that are no longer referenced.
+    async fn discard(&self, keys: impl Iterator<Item = &str>) {
+        for key in keys {
+            if let Err(e) = self.storage.delete(key).await {
+                log::warn!("Failed to delete media object `{}`: {}", key, e);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<UploadEventMediaParams, Result<MediaAsset, UploadEventMediaUseCaseError>>
+    for UploadEventMediaUseCase
+{
+    async fn execute(
+        &self,
+        data: UploadEventMediaParams,
+    ) -> Result<MediaAsset, UploadEventMediaUseCaseError> {
+        let event = self
+            .repository
+            .find_by_id(data.event_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => UploadEventMediaUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => UploadEventMediaUseCaseError::DatabaseError(msg),
+            })?;
+
+        if !can_manage(self.users.as_ref(), &event, data.requested_by)
+            .await
+            .map_err(|e| UploadEventMediaUseCaseError::DatabaseError(e.to_string()))?
+        {
+            return Err(UploadEventMediaUseCaseError::Forbidden);
+        }
+        if event.is_cancelled() {
+            return Err(UploadEventMediaUseCaseError::EventCancelled);
+        }
+
+        let format = MediaFormat::from_content_type(&data.content_type)
+            .filter(|format| format.allowed_for(data.kind))
+            .ok_or(UploadEventMediaUseCaseError::UnsupportedType(
+                data.content_type,
+            ))?;
+        if data.bytes.is_empty() {
+            return Err(UploadEventMediaUseCaseError::Empty);
+        }
+        if data.bytes.len() > format.max_bytes() {
+            return Err(UploadEventMediaUseCaseError::TooLarge(format.max_bytes()));
+        }
+        if MediaFormat::sniff(&data.bytes) != Some(format) {
+            return Err(UploadEventMediaUseCaseError::ContentMismatch(
+                format.content_type().to_string(),
+            ));
+        }
+        if data.kind == MediaKind::Gallery && event.gallery().count() >= MAX_GALLERY_ITEMS {
+            return Err(UploadEventMediaUseCaseError::GalleryFull);
+        }
+
+        let processed = if format.is_image() {
+            Some(
+                self.images
+                    .process(data.bytes.clone(), format)
+                    .await
+                    .map_err(|e| UploadEventMediaUseCaseError::StorageError(e.to_string()))?
+                    .ok_or(UploadEventMediaUseCaseError::Unreadable)?,
+            )
+        } else {
+            None
+        };
+
+        let id = Uuid::new_v4();
+        let storage_key = format!("events/{}/{}.{}", event.id(), id, format.extension());
+        let size_bytes = data.bytes.len() as i64;
+        self.storage
+            .put(&storage_key, data.bytes, format.content_type())
+            .await
+            .map_err(|e| UploadEventMediaUseCaseError::StorageError(e.to_string()))?;
+
+        let (width, height, thumbnail_key) = match processed {
+            Some(image) => {
+                let key = format!("events/{}/{}_thumb.jpg", event.id(), id);
+                if let Err(e) = self
+                    .storage
+                    .put(&key, image.thumbnail, MediaFormat::Jpeg.content_type())
+                    .await
+                {
+                    self.discard(std::iter::once(storage_key.as_str())).await;
+                    return Err(UploadEventMediaUseCaseError::StorageError(e.to_string()));
+                }
+                (
+                    Some(image.width as i32),
+                    Some(image.height as i32),
+                    Some(key),
+                )
+            }
+            None => (None, None, None),
+        };
+
+        let url = self.storage.url(&storage_key);
+        let thumbnail_url = thumbnail_key.as_deref().map(|key| self.storage.url(key));
+        let asset = MediaAsset::new(
+            id,
+            event.id(),
+            data.kind,
+            format,
+            size_bytes,
+            width,
+            height,
+            storage_key,
+            thumbnail_key,
+            url,
+            thumbnail_url,
+            Utc::now(),
+        );
+
+        match self.repository.add_media(asset.clone()).await {
+            Ok(replaced) => {
+                if let Some(replaced) = replaced {
+                    self.discard(replaced.storage_keys()).await;
+                }
+                Ok(asset)
+            }
+            Err(e) => {
+                self.discard(asset.storage_keys()).await;
+                Err(UploadEventMediaUseCaseError::DatabaseError(e.to_string()))
+            }
+        }
+    }
+}
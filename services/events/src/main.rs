@@ -3,23 +3,129 @@ mod domain;
 
 use actix_web::{middleware::Logger, web, App, HttpServer};
 use adapters::inbound::http::configure_routes;
-use adapters::outbound::persistence::EventRepositoryImpl;
-use domain::{CreateEventUseCase, GetEventUseCase};
+use adapters::inbound::scheduler::Scheduler;
+use adapters::outbound::clients::{
+    HttpSeatInventory, HttpTicketing, HttpUsersClient, LocalSeatInventory, LocalTicketing,
+    LocalUsersClient,
+};
+use adapters::outbound::media::{ImageThumbnailer, LocalObjectStorage, S3Config, S3ObjectStorage};
+use adapters::outbound::messaging::{LogEventPublisher, LogHolderNotifier, WebhookEventPublisher};
+use adapters::outbound::persistence::{
+    CancellationRepositoryImpl, CategoryRepositoryImpl, EventRepositoryImpl,
+    EventSeriesRepositoryImpl, EventTemplateRepositoryImpl, ImportJobRepositoryImpl,
+    OutboxRepositoryImpl, VenueRepositoryImpl,
+};
+use domain::{
+    entities::Administrators, CancelEventUseCase, CreateCategoryUseCase, CreateEventSeriesUseCase,
+    CreateEventSessionUseCase, CreateEventTemplateUseCase, CreateEventUseCase,
+    CreateTicketTypeUseCase, CreateVenueUseCase, DeleteCategoryUseCase, DeleteEventMediaUseCase,
+    DeleteEventSessionUseCase, DeleteEventTemplateUseCase, DeleteEventTranslationUseCase,
+    DeleteOccurrenceOverrideUseCase, DeleteTicketTypeUseCase, DispatchDomainEventsUseCase,
+    DomainEventPublisher, DuplicateEventUseCase, GetEventCancellationUseCase,
+    GetEventFacetsUseCase, GetEventHistoryUseCase, GetEventRevisionUseCase, GetEventSeriesUseCase,
+    GetEventTemplateUseCase, GetEventUseCase, GetImportJobUseCase, GetOrganizerCalendarUseCase,
+    GetSeatMapUseCase, GetSeatingChartUseCase, GetTicketTypeUseCase, GetVenueCalendarUseCase,
+    GetVenueUseCase, ImportEventsUseCase, ImportSeatingChartUseCase,
+    InstantiateEventTemplateUseCase, ListCategoriesUseCase, ListEventTemplatesUseCase,
+    ListEventsUseCase, ListOccurrencesUseCase, ListOrganizerEventsUseCase, ListTicketTypesUseCase,
+    ObjectStorage, OverrideOccurrenceUseCase, PublishEventUseCase, RunEventCancellationsUseCase,
+    RunImportJobsUseCase, RunPublishingScheduleUseCase, SaveEventTranslationUseCase, SeatInventory,
+    Ticketing, UpdateCategoryUseCase, UpdateEventSessionUseCase, UpdateEventUseCase,
+    UpdateTicketTypeUseCase, UploadEventMediaUseCase, UsersClient,
+};
 use sea_orm::{Database, DatabaseConnection};
 use sea_orm_migration::MigratorTrait;
+use shared::{purge_expired_keys, Idempotency, PostgresIdempotencyStore, ServiceToken};
 use std::sync::Arc;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 use adapters::inbound::http::dtos::{
-    CreateEventError, CreateEventRequest, CreateEventResponse, GetEventError, GetEventResponse,
+    AdmissionKind, CancelEventError, CancelEventRequest, CancellationStepKind,
+    CancellationStepResponse, CancellationStepState, CategoryFacetResponse, CategoryResponse,
+    CategoryTreeResponse, CreateCategoryError, CreateCategoryRequest, CreateEventError,
+    CreateEventRequest, CreateEventResponse, CreateEventSeriesError, CreateEventSeriesRequest,
+    CreateEventSessionError, CreateEventTemplateError, CreateEventTemplateRequest,
+    CreateTicketTypeError, CreateVenueError, CreateVenueRequest, CreateVenueResponse,
+    DeleteCategoryError, DeleteEventMediaError, DeleteEventSessionError, DeleteEventTemplateError,
+    DeleteEventTranslationError, DeleteOccurrenceOverrideError, DeleteTicketTypeError,
+    DuplicateEventError, DuplicateEventRequest, EventCancellationResponse, EventListingResponse,
+    EventRevisionDetailResponse, EventRevisionResponse, EventScheduleResponse, EventSeriesResponse,
+    EventSessionRequest, EventSessionResponse, EventSnapshotResponse, EventState,
+    EventTemplateResponse, EventTranslationRequest, EventTranslationResponse, FieldChangeResponse,
+    GetEventCancellationError, GetEventError, GetEventFacetsError, GetEventHistoryError,
+    GetEventResponse, GetEventRevisionError, GetEventScheduleError, GetEventSeriesError,
+    GetEventTemplateError, GetImportJobError, GetOrganizerCalendarError, GetSeatMapError,
+    GetSeatingChartError, GetTicketTypeError, GetVenueCalendarError, GetVenueError,
+    GetVenueResponse, ImportEventsError, ImportJobResponse, ImportJobState, ImportOutcome,
+    ImportRowResponse, ImportSeatingChartError, ImportSeatingChartRequest,
+    InstantiateEventTemplateError, InstantiateEventTemplateRequest, ListCategoriesError,
+    ListEventTemplatesError, ListEventsError, ListOccurrencesError, ListOrganizerEventsError,
+    ListTicketTypesError, ListingKind, MediaPlacement, MediaResponse, OccurrenceOverrideResponse,
+    OccurrenceResponse, OverrideOccurrenceError, OverrideOccurrenceRequest, PublishEventError,
+    RevisionKind, RowLayoutRequest, SaveEventTranslationError, ScheduleDayResponse,
+    ScheduleStageResponse, SeatAvailability, SeatLayoutRequest, SeatMapResponse,
+    SeatMapRowResponse, SeatMapSeatResponse, SeatMapSectionResponse, SeatResponse,
+    SeatingChartResponse, SeatingRowResponse, SeatingSectionResponse, SectionLayoutRequest,
+    TemplateMediaResponse, TemplateSessionResponse, TemplateTicketTypeResponse, TicketTypeRequest,
+    TicketTypeResponse, TicketVisibility, UpdateCategoryError, UpdateCategoryRequest,
+    UpdateEventError, UpdateEventRequest, UpdateEventSessionError, UpdateTicketTypeError,
+    UploadEventMediaError,
 };
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         adapters::inbound::http::handlers::get_event::get_event,
+        adapters::inbound::http::handlers::get_event::get_event_ics,
+        adapters::inbound::http::handlers::update_event::update_event,
+        adapters::inbound::http::handlers::cancel_event::cancel_event,
+        adapters::inbound::http::handlers::get_event_cancellation::get_event_cancellation,
+        adapters::inbound::http::handlers::duplicate_event::duplicate_event,
+        adapters::inbound::http::handlers::publish_event::publish_event,
+        adapters::inbound::http::handlers::get_event_history::get_event_history,
+        adapters::inbound::http::handlers::get_event_history::get_event_revision,
+        adapters::inbound::http::handlers::upload_event_media::upload_event_media,
+        adapters::inbound::http::handlers::delete_event_media::delete_event_media,
+        adapters::inbound::http::handlers::get_venue_calendar::get_venue_calendar,
+        adapters::inbound::http::handlers::get_organizer_calendar::get_organizer_calendar,
         adapters::inbound::http::handlers::create_event::create_event,
+        adapters::inbound::http::handlers::list_events::list_events,
+        adapters::inbound::http::handlers::get_event_facets::get_event_facets,
+        adapters::inbound::http::handlers::list_organizer_events::list_organizer_events,
+        adapters::inbound::http::handlers::get_seat_map::get_seat_map,
+        adapters::inbound::http::handlers::get_seat_map::get_seat_map_svg,
+        adapters::inbound::http::handlers::create_ticket_type::create_ticket_type,
+        adapters::inbound::http::handlers::list_ticket_types::list_ticket_types,
+        adapters::inbound::http::handlers::get_ticket_type::get_ticket_type,
+        adapters::inbound::http::handlers::update_ticket_type::update_ticket_type,
+        adapters::inbound::http::handlers::delete_ticket_type::delete_ticket_type,
+        adapters::inbound::http::handlers::create_event_session::create_event_session,
+        adapters::inbound::http::handlers::update_event_session::update_event_session,
+        adapters::inbound::http::handlers::delete_event_session::delete_event_session,
+        adapters::inbound::http::handlers::get_event_schedule::get_event_schedule,
+        adapters::inbound::http::handlers::save_event_translation::save_event_translation,
+        adapters::inbound::http::handlers::delete_event_translation::delete_event_translation,
+        adapters::inbound::http::handlers::create_event_template::create_event_template,
+        adapters::inbound::http::handlers::list_event_templates::list_event_templates,
+        adapters::inbound::http::handlers::get_event_template::get_event_template,
+        adapters::inbound::http::handlers::delete_event_template::delete_event_template,
+        adapters::inbound::http::handlers::instantiate_event_template::instantiate_event_template,
+        adapters::inbound::http::handlers::create_event_series::create_event_series,
+        adapters::inbound::http::handlers::get_event_series::get_event_series,
+        adapters::inbound::http::handlers::list_occurrences::list_occurrences,
+        adapters::inbound::http::handlers::override_occurrence::override_occurrence,
+        adapters::inbound::http::handlers::delete_occurrence_override::delete_occurrence_override,
+        adapters::inbound::http::handlers::import_events::import_events,
+        adapters::inbound::http::handlers::get_import_job::get_import_job,
+        adapters::inbound::http::handlers::create_venue::create_venue,
+        adapters::inbound::http::handlers::get_venue::get_venue,
+        adapters::inbound::http::handlers::get_seating_chart::get_seating_chart,
+        adapters::inbound::http::handlers::import_seating_chart::import_seating_chart,
+        adapters::inbound::http::handlers::create_category::create_category,
+        adapters::inbound::http::handlers::list_categories::list_categories,
+        adapters::inbound::http::handlers::update_category::update_category,
+        adapters::inbound::http::handlers::delete_category::delete_category,
     ),
     components(
         schemas(
@@ -27,11 +133,130 @@ use adapters::inbound::http::dtos::{
             CreateEventResponse,
             CreateEventError,
             GetEventResponse,
-            GetEventError
+            GetEventError,
+            EventState,
+            UpdateEventRequest,
+            UpdateEventError,
+            CancelEventRequest,
+            CancelEventError,
+            EventCancellationResponse,
+            CancellationStepResponse,
+            CancellationStepKind,
+            CancellationStepState,
+            GetEventCancellationError,
+            DuplicateEventRequest,
+            DuplicateEventError,
+            PublishEventError,
+            EventRevisionResponse,
+            EventRevisionDetailResponse,
+            EventSnapshotResponse,
+            FieldChangeResponse,
+            RevisionKind,
+            GetEventHistoryError,
+            GetEventRevisionError,
+            MediaPlacement,
+            MediaResponse,
+            UploadEventMediaError,
+            DeleteEventMediaError,
+            GetVenueCalendarError,
+            GetOrganizerCalendarError,
+            EventListingResponse,
+            ListingKind,
+            ListEventsError,
+            CategoryFacetResponse,
+            GetEventFacetsError,
+            ListOrganizerEventsError,
+            SeatMapResponse,
+            SeatMapSectionResponse,
+            SeatMapRowResponse,
+            SeatMapSeatResponse,
+            SeatAvailability,
+            GetSeatMapError,
+            TicketTypeRequest,
+            TicketTypeResponse,
+            TicketVisibility,
+            AdmissionKind,
+            CreateTicketTypeError,
+            ListTicketTypesError,
+            GetTicketTypeError,
+            UpdateTicketTypeError,
+            DeleteTicketTypeError,
+            EventSessionRequest,
+            EventSessionResponse,
+            EventScheduleResponse,
+            ScheduleDayResponse,
+            ScheduleStageResponse,
+            CreateEventSessionError,
+            UpdateEventSessionError,
+            DeleteEventSessionError,
+            GetEventScheduleError,
+            EventTranslationRequest,
+            EventTranslationResponse,
+            SaveEventTranslationError,
+            DeleteEventTranslationError,
+            CreateEventTemplateRequest,
+            EventTemplateResponse,
+            TemplateTicketTypeResponse,
+            TemplateSessionResponse,
+            TemplateMediaResponse,
+            CreateEventTemplateError,
+            ListEventTemplatesError,
+            GetEventTemplateError,
+            DeleteEventTemplateError,
+            InstantiateEventTemplateRequest,
+            InstantiateEventTemplateError,
+            CreateEventSeriesRequest,
+            EventSeriesResponse,
+            OccurrenceOverrideResponse,
+            CreateEventSeriesError,
+            GetEventSeriesError,
+            OccurrenceResponse,
+            ListOccurrencesError,
+            OverrideOccurrenceRequest,
+            OverrideOccurrenceError,
+            DeleteOccurrenceOverrideError,
+            ImportJobResponse,
+            ImportJobState,
+            ImportRowResponse,
+            ImportOutcome,
+            ImportEventsError,
+            GetImportJobError,
+            CreateVenueRequest,
+            CreateVenueResponse,
+            CreateVenueError,
+            GetVenueResponse,
+            GetVenueError,
+            ImportSeatingChartRequest,
+            SectionLayoutRequest,
+            RowLayoutRequest,
+            SeatLayoutRequest,
+            ImportSeatingChartError,
+            SeatingChartResponse,
+            SeatingSectionResponse,
+            SeatingRowResponse,
+            SeatResponse,
+            GetSeatingChartError,
+            CreateCategoryRequest,
+            UpdateCategoryRequest,
+            CategoryResponse,
+            CategoryTreeResponse,
+            CreateCategoryError,
+            ListCategoriesError,
+            UpdateCategoryError,
+            DeleteCategoryError
         )
     ),
     tags(
-        (name = "events", description = "Event management endpoints")
+        (name = "events", description = "Event management endpoints"),
+        (name = "ticket-types", description = "Ticket type and pricing tier endpoints"),
+        (name = "sessions", description = "Sessions of multi-session events and their schedule"),
+        (name = "translations", description = "Translations of event titles and descriptions"),
+        (name = "calendars", description = "iCalendar export and subscribable feeds"),
+        (name = "imports", description = "iCalendar and CSV event import endpoints"),
+        (name = "templates", description = "Reusable event template endpoints"),
+        (name = "series", description = "Recurring event series endpoints"),
+        (name = "venues", description = "Venue and seating chart endpoints"),
+        (name = "categories", description = "Category taxonomy endpoints")
     ),
     info(
         title = "Events Service API",
@@ -41,6 +266,19 @@ use adapters::inbound::http::dtos::{
 )]
 struct ApiDoc;
 
+/// Reads the S3-compatible storage settings from `S3_*` variables.
+fn s3_config_from_env() -> Result<S3Config, String> {
+    let var = |name: &str| std::env::var(name).map_err(|_| format!("{} is not set", name));
+    Ok(S3Config {
+        endpoint: var("S3_ENDPOINT")?,
+        bucket: var("S3_BUCKET")?,
+        region: std::env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+        access_key_id: var("S3_ACCESS_KEY_ID")?,
+        secret_access_key: var("S3_SECRET_ACCESS_KEY")?,
+        public_url: std::env::var("S3_PUBLIC_URL").ok(),
+    })
+}
+
 async fn create_database_connection() -> Result<DatabaseConnection, Box<dyn std::error::Error>> {
     dotenvy::dotenv().ok();
     let database_url = std::env::var("DATABASE_URL")?;
@@ -71,10 +309,321 @@ async fn main() -> std::io::Result<()> {
     }
     println!("✅ Database migrations completed");
 
-    let event_repository = Arc::new(EventRepositoryImpl::new(db));
+    let event_repository = Arc::new(EventRepositoryImpl::new(db.clone()));
+    let series_repository = Arc::new(EventSeriesRepositoryImpl::new(db.clone()));
+    let import_job_repository = Arc::new(ImportJobRepositoryImpl::new(db.clone()));
+    let category_repository = Arc::new(CategoryRepositoryImpl::new(db.clone()));
+    let outbox_repository = Arc::new(OutboxRepositoryImpl::new(db.clone()));
+    let cancellation_repository = Arc::new(CancellationRepositoryImpl::new(db.clone()));
+    let template_repository = Arc::new(EventTemplateRepositoryImpl::new(db.clone()));
+    let idempotency_store = Arc::new(PostgresIdempotencyStore::new(db.clone()));
+    tokio::spawn(purge_expired_keys(idempotency_store.clone()));
+    let venue_repository = Arc::new(VenueRepositoryImpl::new(db));
+    let seat_inventory: Arc<dyn SeatInventory> = match std::env::var("TICKETS_SERVICE_URL") {
+        Ok(url) => Arc::new(HttpSeatInventory::new(&url)),
+        Err(_) => {
+            println!("⚠️  TICKETS_SERVICE_URL not set; every seat is shown as available");
+            Arc::new(LocalSeatInventory)
+        }
+    };
+    let ticketing: Arc<dyn Ticketing> = match std::env::var("TICKETS_SERVICE_URL") {
+        Ok(url) => {
+            match std::env::var("SERVICE_TOKEN") {
+                Ok(token) if !token.trim().is_empty() => {
+                    Arc::new(HttpTicketing::new(&url, ServiceToken::new(token.trim())))
+                }
+                _ => {
+                    eprintln!("❌ SERVICE_TOKEN must be set to the secret shared with the tickets service");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Err(_) => {
+            println!("⚠️  TICKETS_SERVICE_URL not set; cancellations refund and notify nobody");
+            Arc::new(LocalTicketing)
+        }
+    };
+    let users_client: Arc<dyn UsersClient> = match std::env::var("USERS_SERVICE_URL") {
+        Ok(url) => Arc::new(HttpUsersClient::new(&url)),
+        Err(_) => {
+            println!("⚠️  USERS_SERVICE_URL not set; using the local users stub");
+            Arc::new(LocalUsersClient)
+        }
+    };
+    let admins = match Administrators::parse(&std::env::var("ADMIN_USER_IDS").unwrap_or_default()) {
+        Ok(admins) => admins,
+        Err(e) => {
+            eprintln!("❌ Invalid ADMIN_USER_IDS: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if admins.is_empty() {
+        println!("⚠️  ADMIN_USER_IDS not set; nobody can change categories");
+    }
+
+    let media_root = std::env::var("MEDIA_ROOT").unwrap_or_else(|_| "./media".to_string());
+    let serve_local_media = std::env::var("MEDIA_STORAGE").as_deref() != Ok("s3");
+    let object_storage: Arc<dyn ObjectStorage> = if serve_local_media {
+        if let Err(e) = std::fs::create_dir_all(&media_root) {
+            eprintln!("❌ Failed to create media directory {}: {}", media_root, e);
+            std::process::exit(1);
+        }
+        let base_url =
+            std::env::var("MEDIA_BASE_URL").unwrap_or_else(|_| "/events/media".to_string());
+        Arc::new(LocalObjectStorage::new(&media_root, &base_url))
+    } else {
+        match s3_config_from_env() {
+            Ok(config) => Arc::new(S3ObjectStorage::new(config)),
+            Err(e) => {
+                eprintln!("❌ Invalid S3 media storage configuration: {}", e);
+                std::process::exit(1);
+            }
+        }
+    };
+    let image_processor = Arc::new(ImageThumbnailer);
+
+    let get_event_use_case = web::Data::new(GetEventUseCase::new(
+        event_repository.clone(),
+        users_client.clone(),
+    ));
+    let create_event_use_case = web::Data::new(CreateEventUseCase::new(
+        event_repository.clone(),
+        venue_repository.clone(),
+        category_repository.clone(),
+        users_client.clone(),
+    ));
+    let import_events_use_case = web::Data::new(ImportEventsUseCase::new(
+        create_event_use_case.clone().into_inner(),
+        event_repository.clone(),
+        import_job_repository.clone(),
+    ));
+    let get_import_job_use_case =
+        web::Data::new(GetImportJobUseCase::new(import_job_repository.clone()));
+
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("import") {
+        let code = adapters::inbound::cli::import::run(&import_events_use_case, &args[2..]).await;
+        std::process::exit(code);
+    }
+    let update_event_use_case = web::Data::new(UpdateEventUseCase::new(
+        event_repository.clone(),
+        venue_repository.clone(),
+        category_repository.clone(),
+        users_client.clone(),
+    ));
+    let cancel_event_use_case = web::Data::new(CancelEventUseCase::new(
+        event_repository.clone(),
+        users_client.clone(),
+    ));
+    let get_event_cancellation_use_case = web::Data::new(GetEventCancellationUseCase::new(
+        event_repository.clone(),
+        cancellation_repository.clone(),
+        users_client.clone(),
+    ));
+    let duplicate_event_use_case = web::Data::new(DuplicateEventUseCase::new(
+        event_repository.clone(),
+        venue_repository.clone(),
+        category_repository.clone(),
+        object_storage.clone(),
+        users_client.clone(),
+    ));
+    let publish_event_use_case = web::Data::new(PublishEventUseCase::new(
+        event_repository.clone(),
+        users_client.clone(),
+    ));
+    let get_event_history_use_case = web::Data::new(GetEventHistoryUseCase::new(
+        event_repository.clone(),
+        users_client.clone(),
+    ));
+    let get_event_revision_use_case = web::Data::new(GetEventRevisionUseCase::new(
+        event_repository.clone(),
+        users_client.clone(),
+    ));
+    let upload_event_media_use_case = web::Data::new(UploadEventMediaUseCase::new(
+        event_repository.clone(),
+        object_storage.clone(),
+        image_processor.clone(),
+        users_client.clone(),
+    ));
+    let delete_event_media_use_case = web::Data::new(DeleteEventMediaUseCase::new(
+        event_repository.clone(),
+        object_storage.clone(),
+        users_client.clone(),
+    ));
+    let get_venue_calendar_use_case = web::Data::new(GetVenueCalendarUseCase::new(
+        event_repository.clone(),
+        venue_repository.clone(),
+    ));
+    let get_organizer_calendar_use_case =
+        web::Data::new(GetOrganizerCalendarUseCase::new(event_repository.clone()));
+    let list_organizer_events_use_case = web::Data::new(ListOrganizerEventsUseCase::new(
+        event_repository.clone(),
+        users_client.clone(),
+    ));
+    let list_events_use_case = web::Data::new(ListEventsUseCase::new(
+        event_repository.clone(),
+        series_repository.clone(),
+        category_repository.clone(),
+        venue_repository.clone(),
+    ));
+    let get_event_facets_use_case = web::Data::new(GetEventFacetsUseCase::new(
+        event_repository.clone(),
+        category_repository.clone(),
+        venue_repository.clone(),
+    ));
+    let get_seat_map_use_case = web::Data::new(GetSeatMapUseCase::new(
+        event_repository.clone(),
+        venue_repository.clone(),
+        seat_inventory.clone(),
+    ));
+    let create_ticket_type_use_case = web::Data::new(CreateTicketTypeUseCase::new(
+        event_repository.clone(),
+        venue_repository.clone(),
+        users_client.clone(),
+    ));
+    let list_ticket_types_use_case =
+        web::Data::new(ListTicketTypesUseCase::new(event_repository.clone()));
+    let get_ticket_type_use_case =
+        web::Data::new(GetTicketTypeUseCase::new(event_repository.clone()));
+    let update_ticket_type_use_case = web::Data::new(UpdateTicketTypeUseCase::new(
+        event_repository.clone(),
+        venue_repository.clone(),
+        users_client.clone(),
+    ));
+    let delete_ticket_type_use_case = web::Data::new(DeleteTicketTypeUseCase::new(
+        event_repository.clone(),
+        users_client.clone(),
+    ));
+    let create_event_session_use_case = web::Data::new(CreateEventSessionUseCase::new(
+        event_repository.clone(),
+        users_client.clone(),
+    ));
+    let update_event_session_use_case = web::Data::new(UpdateEventSessionUseCase::new(
+        event_repository.clone(),
+        users_client.clone(),
+    ));
+    let delete_event_session_use_case = web::Data::new(DeleteEventSessionUseCase::new(
+        event_repository.clone(),
+        users_client.clone(),
+    ));
+    let save_event_translation_use_case = web::Data::new(SaveEventTranslationUseCase::new(
+        event_repository.clone(),
+        users_client.clone(),
+    ));
+    let delete_event_translation_use_case = web::Data::new(DeleteEventTranslationUseCase::new(
+        event_repository.clone(),
+        users_client.clone(),
+    ));
+    let create_event_template_use_case = web::Data::new(CreateEventTemplateUseCase::new(
+        event_repository.clone(),
+        template_repository.clone(),
+        object_storage.clone(),
+        users_client.clone(),
+    ));
+    let list_event_templates_use_case = web::Data::new(ListEventTemplatesUseCase::new(
+        template_repository.clone(),
+        users_client.clone(),
+    ));
+    let get_event_template_use_case = web::Data::new(GetEventTemplateUseCase::new(
+        template_repository.clone(),
+        users_client.clone(),
+    ));
+    let delete_event_template_use_case = web::Data::new(DeleteEventTemplateUseCase::new(
+        template_repository.clone(),
+        object_storage.clone(),
+        users_client.clone(),
+    ));
+    let instantiate_event_template_use_case = web::Data::new(InstantiateEventTemplateUseCase::new(
+        template_repository,
+        event_repository.clone(),
+        venue_repository.clone(),
+        category_repository.clone(),
+        object_storage.clone(),
+        users_client.clone(),
+    ));
+    let create_event_series_use_case = web::Data::new(CreateEventSeriesUseCase::new(
+        series_repository.clone(),
+        venue_repository.clone(),
+        users_client.clone(),
+    ));
+    let get_event_series_use_case =
+        web::Data::new(GetEventSeriesUseCase::new(series_repository.clone()));
+    let list_occurrences_use_case =
+        web::Data::new(ListOccurrencesUseCase::new(series_repository.clone()));
+    let override_occurrence_use_case = web::Data::new(OverrideOccurrenceUseCase::new(
+        series_repository.clone(),
+        venue_repository.clone(),
+        users_client.clone(),
+    ));
+    let delete_occurrence_override_use_case = web::Data::new(DeleteOccurrenceOverrideUseCase::new(
+        series_repository.clone(),
+        users_client.clone(),
+    ));
+    let create_venue_use_case = web::Data::new(CreateVenueUseCase::new(
+        venue_repository.clone(),
+        users_client.clone(),
+    ));
+    let get_venue_use_case = web::Data::new(GetVenueUseCase::new(venue_repository.clone()));
+    let get_seating_chart_use_case =
+        web::Data::new(GetSeatingChartUseCase::new(venue_repository.clone()));
+    let import_seating_chart_use_case = web::Data::new(ImportSeatingChartUseCase::new(
+        venue_repository.clone(),
+        event_repository.clone(),
+        seat_inventory.clone(),
+        users_client.clone(),
+    ));
+    let create_category_use_case = web::Data::new(CreateCategoryUseCase::new(
+        category_repository.clone(),
+        admins.clone(),
+    ));
+    let list_categories_use_case =
+        web::Data::new(ListCategoriesUseCase::new(category_repository.clone()));
+    let update_category_use_case = web::Data::new(UpdateCategoryUseCase::new(
+        category_repository.clone(),
+        admins.clone(),
+    ));
+    let delete_category_use_case = web::Data::new(DeleteCategoryUseCase::new(
+        category_repository.clone(),
+        admins.clone(),
+    ));
 
-    let get_event_use_case = web::Data::new(GetEventUseCase::new(event_repository.clone()));
-    let create_event_use_case = web::Data::new(CreateEventUseCase::new(event_repository.clone()));
+    let event_publisher: Arc<dyn DomainEventPublisher> =
+        match std::env::var("DOMAIN_EVENTS_WEBHOOK_URL") {
+            Ok(url) => Arc::new(WebhookEventPublisher::new(&url)),
+            Err(_) => {
+                println!("⚠️  DOMAIN_EVENTS_WEBHOOK_URL not set; domain events are only logged");
+                Arc::new(LogEventPublisher)
+            }
+        };
+    let scheduler_interval = std::env::var("SCHEDULER_INTERVAL_SECS")
+        .ok()
+        .and_then(|secs| secs.parse().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(15);
+    let scheduler = Scheduler::new(
+        Arc::new(RunPublishingScheduleUseCase::new(event_repository.clone())),
+        Arc::new(RunEventCancellationsUseCase::new(
+            cancellation_repository,
+            event_repository.clone(),
+            ticketing,
+            Arc::new(LogHolderNotifier),
+        )),
+        Arc::new(DispatchDomainEventsUseCase::new(
+            outbox_repository,
+            event_publisher,
+        )),
+        Arc::new(RunImportJobsUseCase::new(
+            create_event_use_case.clone().into_inner(),
+            event_repository.clone(),
+            import_job_repository.clone(),
+        )),
+        std::time::Duration::from_secs(scheduler_interval),
+    );
+    tokio::spawn(scheduler.run());
+    println!(
+        "⏰ Publishing scheduler running every {}s",
+        scheduler_interval
+    );
 
     let host = std::env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_string());
     let port = std::env::var("PORT").unwrap_or_else(|_| "8081".to_string());
@@ -90,12 +639,63 @@ async fn main() -> std::io::Result<()> {
 
     HttpServer::new(move || {
         App::new()
+            .wrap(Idempotency::new(idempotency_store.clone()))
             .wrap(Logger::default())
             .app_data(get_event_use_case.clone())
             .app_data(create_event_use_case.clone())
+            .app_data(import_events_use_case.clone())
+            .app_data(get_import_job_use_case.clone())
+            .app_data(update_event_use_case.clone())
+            .app_data(cancel_event_use_case.clone())
+            .app_data(get_event_cancellation_use_case.clone())
+            .app_data(duplicate_event_use_case.clone())
+            .app_data(publish_event_use_case.clone())
+            .app_data(get_event_history_use_case.clone())
+            .app_data(get_event_revision_use_case.clone())
+            .app_data(upload_event_media_use_case.clone())
+            .app_data(delete_event_media_use_case.clone())
+            .app_data(get_venue_calendar_use_case.clone())
+            .app_data(get_organizer_calendar_use_case.clone())
+            .app_data(list_organizer_events_use_case.clone())
+            .app_data(list_events_use_case.clone())
+            .app_data(get_event_facets_use_case.clone())
+            .app_data(get_seat_map_use_case.clone())
+            .app_data(create_ticket_type_use_case.clone())
+            .app_data(list_ticket_types_use_case.clone())
+            .app_data(get_ticket_type_use_case.clone())
+            .app_data(update_ticket_type_use_case.clone())
+            .app_data(delete_ticket_type_use_case.clone())
+            .app_data(create_event_session_use_case.clone())
+            .app_data(update_event_session_use_case.clone())
+            .app_data(delete_event_session_use_case.clone())
+            .app_data(save_event_translation_use_case.clone())
+            .app_data(delete_event_translation_use_case.clone())
+            .app_data(create_event_template_use_case.clone())
+            .app_data(list_event_templates_use_case.clone())
+            .app_data(get_event_template_use_case.clone())
+            .app_data(delete_event_template_use_case.clone())
+            .app_data(instantiate_event_template_use_case.clone())
+            .app_data(create_event_series_use_case.clone())
+            .app_data(get_event_series_use_case.clone())
+            .app_data(list_occurrences_use_case.clone())
+            .app_data(override_occurrence_use_case.clone())
+            .app_data(delete_occurrence_override_use_case.clone())
+            .app_data(create_venue_use_case.clone())
+            .app_data(get_venue_use_case.clone())
+            .app_data(get_seating_chart_use_case.clone())
+            .app_data(import_seating_chart_use_case.clone())
+            .app_data(create_category_use_case.clone())
+            .app_data(list_categories_use_case.clone())
+            .app_data(update_category_use_case.clone())
+            .app_data(delete_category_use_case.clone())
             .service(
                 web::scope("/events")
                     .configure(configure_routes)
+                    .configure(|cfg| {
+                        if serve_local_media {
+                            cfg.service(actix_files::Files::new("/media", media_root.clone()));
+                        }
+                    })
                     .route(
                         "/api-docs/openapi.json",
                         web::get().to({
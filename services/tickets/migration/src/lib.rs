@@ -0,0 +1,22 @@
+pub use sea_orm_migration::prelude::*;
+
+mod m20261020_030000_create_inventory_tables;
+mod m20261020_040000_add_reservation_holds;
+mod m20261020_050000_create_reserved_seats_table;
+mod m20261020_060000_create_idempotency_keys_table;
+mod m20261020_080000_create_tickets_table;
+
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20261020_030000_create_inventory_tables::Migration),
+            Box::new(m20261020_040000_add_reservation_holds::Migration),
+            Box::new(m20261020_050000_create_reserved_seats_table::Migration),
+            Box::new(m20261020_060000_create_idempotency_keys_table::Migration),
+            Box::new(m20261020_080000_create_tickets_table::Migration),
+        ]
+    }
+}
@@ -0,0 +1,182 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create ticket_inventories table; one row per ticket type, seeded from
+        // the events service the first time the ticket type is looked at
+        manager
+            .create_table(
+                Table::create()
+                    .table(TicketInventories::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TicketInventories::TicketTypeId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TicketInventories::EventId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TicketInventories::Capacity)
+                            .integer()
+                            .not_null()
+                            .check(Expr::col(TicketInventories::Capacity).gte(0)),
+                    )
+                    .col(
+                        ColumnDef::new(TicketInventories::Reserved)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .check(Expr::col(TicketInventories::Reserved).gte(0)),
+                    )
+                    .col(
+                        ColumnDef::new(TicketInventories::Sold)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .check(Expr::col(TicketInventories::Sold).gte(0)),
+                    )
+                    .col(
+                        ColumnDef::new(TicketInventories::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(TicketInventories::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create index on event_id for looking up an event's inventory
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_ticket_inventories_event_id")
+                    .table(TicketInventories::Table)
+                    .col(TicketInventories::EventId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create reservations table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Reservations::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Reservations::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Reservations::EventId).uuid().not_null())
+                    .col(ColumnDef::new(Reservations::TicketTypeId).uuid().not_null())
+                    .col(ColumnDef::new(Reservations::HolderId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(Reservations::Quantity)
+                            .integer()
+                            .not_null()
+                            .check(Expr::col(Reservations::Quantity).gte(1)),
+                    )
+                    .col(
+                        ColumnDef::new(Reservations::Status)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Reservations::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(Reservations::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reservations_ticket_type_id")
+                            .from(Reservations::Table, Reservations::TicketTypeId)
+                            .to(TicketInventories::Table, TicketInventories::TicketTypeId)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create index on holder_id for listing a holder's reservations
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_reservations_holder_id")
+                    .table(Reservations::Table)
+                    .col(Reservations::HolderId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create index on ticket_type_id for the foreign key
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_reservations_ticket_type_id")
+                    .table(Reservations::Table)
+                    .col(Reservations::TicketTypeId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Reservations::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(TicketInventories::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum TicketInventories {
+    Table,
+    TicketTypeId,
+    EventId,
+    Capacity,
+    Reserved,
+    Sold,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Reservations {
+    Table,
+    Id,
+    EventId,
+    TicketTypeId,
+    HolderId,
+    Quantity,
+    Status,
+    CreatedAt,
+    UpdatedAt,
+}
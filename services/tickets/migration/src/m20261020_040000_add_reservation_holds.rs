@@ -0,0 +1,74 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Holds placed before holds had a deadline expire on the first sweep
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Reservations::Table)
+                    .add_column(
+                        ColumnDef::new(Reservations::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .add_column(
+                        ColumnDef::new(Reservations::ExtendedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create partial index on expires_at for the sweeper's lookups
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_reservations_held_expires_at")
+                    .table(Reservations::Table)
+                    .col(Reservations::ExpiresAt)
+                    .and_where(Expr::col(Reservations::Status).eq("held"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_reservations_held_expires_at")
+                    .table(Reservations::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Reservations::Table)
+                    .drop_column(Reservations::ExpiresAt)
+                    .drop_column(Reservations::ExtendedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Reservations {
+    Table,
+    Status,
+    ExpiresAt,
+    ExtendedAt,
+}
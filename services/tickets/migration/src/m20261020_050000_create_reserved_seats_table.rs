@@ -0,0 +1,92 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Backstop for the conditional updates: no ticket type ever has more
+        // tickets out than it has
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE ticket_inventories ADD CONSTRAINT ticket_inventories_not_oversold \
+                 CHECK (reserved + sold <= capacity)",
+            )
+            .await?;
+
+        // Create reserved_seats table; the primary key lets a seat be taken by
+        // only one reservation at a time
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReservedSeats::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ReservedSeats::EventId).uuid().not_null())
+                    .col(ColumnDef::new(ReservedSeats::SeatId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(ReservedSeats::ReservationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(ReservedSeats::EventId)
+                            .col(ReservedSeats::SeatId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reserved_seats_reservation_id")
+                            .from(ReservedSeats::Table, ReservedSeats::ReservationId)
+                            .to(Reservations::Table, Reservations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create index on reservation_id for loading and freeing a
+        // reservation's seats
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_reserved_seats_reservation_id")
+                    .table(ReservedSeats::Table)
+                    .col(ReservedSeats::ReservationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ReservedSeats::Table).to_owned())
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE ticket_inventories DROP CONSTRAINT ticket_inventories_not_oversold",
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Reservations {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum ReservedSeats {
+    Table,
+    EventId,
+    SeatId,
+    ReservationId,
+}
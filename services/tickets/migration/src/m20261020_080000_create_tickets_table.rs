@@ -0,0 +1,94 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create tickets table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tickets::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Tickets::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(Tickets::ReservationId).uuid().not_null())
+                    .col(ColumnDef::new(Tickets::EventId).uuid().not_null())
+                    .col(ColumnDef::new(Tickets::TicketTypeId).uuid().not_null())
+                    .col(ColumnDef::new(Tickets::HolderId).uuid().not_null())
+                    .col(ColumnDef::new(Tickets::SeatId).uuid())
+                    .col(
+                        ColumnDef::new(Tickets::IssuedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tickets_reservation_id")
+                            .from(Tickets::Table, Tickets::ReservationId)
+                            .to(Reservations::Table, Reservations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create index on reservation_id for listing a reservation's tickets
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_tickets_reservation_id")
+                    .table(Tickets::Table)
+                    .col(Tickets::ReservationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Issue the tickets of reservations confirmed before tickets were
+        // stored, on their seats in seat order
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "INSERT INTO tickets \
+                 (id, reservation_id, event_id, ticket_type_id, holder_id, seat_id, issued_at) \
+                 SELECT gen_random_uuid(), r.id, r.event_id, r.ticket_type_id, r.holder_id, \
+                 s.seat_id, r.updated_at \
+                 FROM reservations r \
+                 CROSS JOIN LATERAL generate_series(1, r.quantity) AS n \
+                 LEFT JOIN LATERAL (SELECT seat_id FROM reserved_seats \
+                 WHERE reservation_id = r.id ORDER BY seat_id OFFSET n - 1 LIMIT 1) s ON true \
+                 WHERE r.status = 'confirmed'",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Tickets::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Reservations {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Tickets {
+    Table,
+    Id,
+    ReservationId,
+    EventId,
+    TicketTypeId,
+    HolderId,
+    SeatId,
+    IssuedAt,
+}
@@ -0,0 +1,5 @@
+pub mod requests;
+pub mod responses;
+
+pub use requests::*;
+pub use responses::*;
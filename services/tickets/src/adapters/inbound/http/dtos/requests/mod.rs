@@ -0,0 +1,5 @@
+pub mod release_event_reservations;
+pub mod reserve_tickets;
+
+pub use release_event_reservations::{ReleasableState, ReleaseEventReservationsRequest};
+pub use reserve_tickets::{BestAvailableRequest, ReserveTicketsRequest};
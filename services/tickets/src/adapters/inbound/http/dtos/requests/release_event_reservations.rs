@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{entities::ReservationStatus, use_cases::ReleaseEventReservationsParams};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleasableState {
+    /// Holds that aren't bought yet.
+    Held,
+    /// Bought reservations; their tickets stop being valid.
+    Confirmed,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReleaseEventReservationsRequest {
+    pub status: ReleasableState,
+}
+
+impl From<(Uuid, ReleaseEventReservationsRequest)> for ReleaseEventReservationsParams {
+    fn from((event_id, req): (Uuid, ReleaseEventReservationsRequest)) -> Self {
+        Self {
+            event_id,
+            status: match req.status {
+                ReleasableState::Held => ReservationStatus::Held,
+                ReleasableState::Confirmed => ReservationStatus::Confirmed,
+            },
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use shared::AuthenticatedUser;
+use std::collections::HashSet;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::use_cases::{ReserveTicketsParams, SeatSelection};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReserveTicketsRequest {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub event_id: Uuid,
+    #[schema(example = "9b2f6c1e-8d4a-4e37-b5a1-0c2d3e4f5a6b")]
+    pub ticket_type_id: Uuid,
+    #[schema(example = 2)]
+    pub quantity: i32,
+    /// Assigned seats to hold, one per ticket; leave out for general
+    /// admission.
+    #[serde(default)]
+    pub seat_ids: Option<Vec<Uuid>>,
+    /// Lets the service pick the best seats left next to each other instead
+    /// of naming them. Ticket types sold by section get the best available
+    /// seats when neither this nor `seat_ids` is given.
+    #[serde(default)]
+    pub best_available: Option<BestAvailableRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BestAvailableRequest {
+    /// How many of the seats must be wheelchair accessible.
+    #[serde(default)]
+    #[schema(example = 1)]
+    pub accessible_seats: i32,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReserveTicketsRequestError {
+    #[error("Quantity must be at least 1")]
+    QuantityNotPositive,
+    #[error("Each seat can only be picked once")]
+    DuplicateSeats,
+    #[error("Quantity must match the number of seats")]
+    SeatCountMismatch,
+    #[error("Seats can either be picked or best available, not both")]
+    ConflictingSeatSelection,
+    #[error("Accessible seats must be between 0 and the quantity")]
+    InvalidAccessibleSeats,
+}
+
+impl TryFrom<(AuthenticatedUser, ReserveTicketsRequest)> for ReserveTicketsParams {
+    type Error = ReserveTicketsRequestError;
+
+    fn try_from(
+        (user, req): (AuthenticatedUser, ReserveTicketsRequest),
+    ) -> Result<Self, Self::Error> {
+        if req.quantity < 1 {
+            return Err(ReserveTicketsRequestError::QuantityNotPositive);
+        }
+
+        let seats = match (req.seat_ids, req.best_available) {
+            (Some(_), Some(_)) => return Err(ReserveTicketsRequestError::ConflictingSeatSelection),
+            (Some(seat_ids), None) if !seat_ids.is_empty() => {
+                if seat_ids.iter().collect::<HashSet<_>>().len() != seat_ids.len() {
+                    return Err(ReserveTicketsRequestError::DuplicateSeats);
+                }
+                if seat_ids.len() != req.quantity as usize {
+                    return Err(ReserveTicketsRequestError::SeatCountMismatch);
+                }
+                SeatSelection::Picked(seat_ids)
+            }
+            (_, Some(best_available)) => {
+                if !(0..=req.quantity).contains(&best_available.accessible_seats) {
+                    return Err(ReserveTicketsRequestError::InvalidAccessibleSeats);
+                }
+                SeatSelection::BestAvailable {
+                    accessible: best_available.accessible_seats as usize,
+                }
+            }
+            _ => SeatSelection::Unspecified,
+        };
+
+        Ok(Self {
+            event_id: req.event_id,
+            ticket_type_id: req.ticket_type_id,
+            quantity: req.quantity,
+            seats,
+            requested_by: user.id(),
+        })
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventHoldersResponse {
+    pub event_id: Uuid,
+    /// Users who were issued tickets for the event, in user ID order.
+    pub holders: Vec<Uuid>,
+}
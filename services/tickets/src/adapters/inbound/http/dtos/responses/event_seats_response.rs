@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::ReservationStatus;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TakenSeatState {
+    /// In a reservation that isn't confirmed yet.
+    Held,
+    Sold,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TakenSeatResponse {
+    pub seat_id: Uuid,
+    pub status: TakenSeatState,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventSeatsResponse {
+    pub event_id: Uuid,
+    /// Seats held or sold, in seat ID order; every other seat is free.
+    pub seats: Vec<TakenSeatResponse>,
+}
+
+impl EventSeatsResponse {
+    pub fn new(event_id: Uuid, taken: HashMap<Uuid, ReservationStatus>) -> Self {
+        let mut seats: Vec<TakenSeatResponse> = taken
+            .into_iter()
+            .map(|(seat_id, status)| TakenSeatResponse {
+                seat_id,
+                status: match status {
+                    ReservationStatus::Confirmed => TakenSeatState::Sold,
+                    ReservationStatus::Held
+                    | ReservationStatus::Released
+                    | ReservationStatus::Expired => TakenSeatState::Held,
+                },
+            })
+            .collect();
+        seats.sort_by_key(|seat| seat.seat_id);
+        Self { event_id, seats }
+    }
+}
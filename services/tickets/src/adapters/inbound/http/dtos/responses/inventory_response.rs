@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::Inventory;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct InventoryResponse {
+    #[schema(example = "9b2f6c1e-8d4a-4e37-b5a1-0c2d3e4f5a6b")]
+    pub ticket_type_id: Uuid,
+    pub event_id: Uuid,
+    /// Tickets of the type in total.
+    #[schema(example = 200)]
+    pub capacity: i32,
+    /// Tickets held in reservations that aren't confirmed yet.
+    #[schema(example = 12)]
+    pub reserved: i32,
+    #[schema(example = 150)]
+    pub sold: i32,
+    /// Tickets that can still be reserved.
+    #[schema(example = 38)]
+    pub available: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Inventory> for InventoryResponse {
+    fn from(inventory: Inventory) -> Self {
+        Self {
+            ticket_type_id: inventory.ticket_type_id(),
+            event_id: inventory.event_id(),
+            capacity: inventory.capacity(),
+            reserved: inventory.reserved(),
+            sold: inventory.sold(),
+            available: inventory.available(),
+            updated_at: *inventory.updated_at(),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+pub mod confirm_reservation_error;
+pub mod event_holders_response;
+pub mod event_seats_response;
+pub mod extend_reservation_error;
+pub mod get_event_holders_error;
+pub mod get_event_seats_error;
+pub mod get_inventory_error;
+pub mod get_reservation_error;
+pub mod get_ticket_error;
+pub mod get_tickets_error;
+pub mod inventory_response;
+pub mod release_event_reservations_error;
+pub mod release_event_reservations_response;
+pub mod release_reservation_error;
+pub mod reservation_response;
+pub mod reserve_tickets_error;
+pub mod ticket_keys_response;
+pub mod ticket_response;
+
+pub use confirm_reservation_error::ConfirmReservationError;
+pub use event_holders_response::EventHoldersResponse;
+pub use event_seats_response::{EventSeatsResponse, TakenSeatResponse, TakenSeatState};
+pub use extend_reservation_error::ExtendReservationError;
+pub use get_event_holders_error::GetEventHoldersError;
+pub use get_event_seats_error::GetEventSeatsError;
+pub use get_inventory_error::GetInventoryError;
+pub use get_reservation_error::GetReservationError;
+pub use get_ticket_error::GetTicketError;
+pub use get_tickets_error::GetTicketsError;
+pub use inventory_response::InventoryResponse;
+pub use release_event_reservations_error::ReleaseEventReservationsError;
+pub use release_event_reservations_response::ReleaseEventReservationsResponse;
+pub use release_reservation_error::ReleaseReservationError;
+pub use reservation_response::{ReservationResponse, ReservationState};
+pub use reserve_tickets_error::ReserveTicketsError;
+pub use ticket_keys_response::{TicketKeyResponse, TicketKeysResponse};
+pub use ticket_response::TicketResponse;
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReleaseEventReservationsResponse {
+    pub event_id: Uuid,
+    /// Reservations released by this call; ones released before aren't
+    /// counted again.
+    pub released: u32,
+}
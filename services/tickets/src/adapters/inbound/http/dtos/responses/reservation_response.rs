@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{entities::ReservationStatus, Reservation};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReservationState {
+    Held,
+    Confirmed,
+    Released,
+    Expired,
+}
+
+impl From<ReservationStatus> for ReservationState {
+    fn from(status: ReservationStatus) -> Self {
+        match status {
+            ReservationStatus::Held => ReservationState::Held,
+            ReservationStatus::Confirmed => ReservationState::Confirmed,
+            ReservationStatus::Released => ReservationState::Released,
+            ReservationStatus::Expired => ReservationState::Expired,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReservationResponse {
+    #[schema(example = "3f6c2a9e-1b7d-4c5e-9a8f-2d4b6c8e0a1f")]
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub ticket_type_id: Uuid,
+    pub holder_id: Uuid,
+    #[schema(example = 2)]
+    pub quantity: i32,
+    /// The held or bought seats; empty for general admission and once the
+    /// tickets go back on sale.
+    pub seat_ids: Vec<Uuid>,
+    pub status: ReservationState,
+    /// When the hold runs out and the tickets go back on sale; only set
+    /// while the reservation is held.
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Whether the hold was extended; it can be extended once.
+    pub extended: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Reservation> for ReservationResponse {
+    fn from(reservation: Reservation) -> Self {
+        Self {
+            id: reservation.id(),
+            event_id: reservation.event_id(),
+            ticket_type_id: reservation.ticket_type_id(),
+            holder_id: reservation.holder_id(),
+            quantity: reservation.quantity(),
+            seat_ids: reservation.seat_ids().to_vec(),
+            status: reservation.status().into(),
+            expires_at: (reservation.status() == ReservationStatus::Held)
+                .then(|| *reservation.expires_at()),
+            extended: reservation.extended_at().is_some(),
+            created_at: *reservation.created_at(),
+            updated_at: *reservation.updated_at(),
+        }
+    }
+}
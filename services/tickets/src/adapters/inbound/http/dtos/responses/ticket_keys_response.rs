@@ -0,0 +1,40 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::TicketKey;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TicketKeyResponse {
+    #[schema(example = "2026-10")]
+    pub key_id: String,
+    #[schema(example = "Ed25519")]
+    pub algorithm: String,
+    /// Raw 32-byte public key, base64url-encoded.
+    pub public_key: String,
+    /// Whether new passes are signed with the key; the others only verify
+    /// passes handed out before a rotation.
+    pub active: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TicketKeysResponse {
+    /// Active key first.
+    pub keys: Vec<TicketKeyResponse>,
+}
+
+impl From<Vec<TicketKey>> for TicketKeysResponse {
+    fn from(keys: Vec<TicketKey>) -> Self {
+        Self {
+            keys: keys
+                .into_iter()
+                .map(|key| TicketKeyResponse {
+                    key_id: key.id,
+                    algorithm: "Ed25519".to_string(),
+                    public_key: URL_SAFE_NO_PAD.encode(key.public_key),
+                    active: key.active,
+                })
+                .collect(),
+        }
+    }
+}
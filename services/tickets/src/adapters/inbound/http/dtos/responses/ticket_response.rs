@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::IssuedTicket;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TicketResponse {
+    #[schema(example = "7d1e5f2a-4c3b-4a9e-8f6d-1b2c3d4e5f60")]
+    pub id: Uuid,
+    pub reservation_id: Uuid,
+    pub event_id: Uuid,
+    pub ticket_type_id: Uuid,
+    pub holder_id: Uuid,
+    /// Assigned seat; `null` for general admission.
+    pub seat_id: Option<Uuid>,
+    pub issued_at: DateTime<Utc>,
+    /// When scanners start accepting the ticket.
+    pub valid_from: DateTime<Utc>,
+    /// When scanners stop accepting the ticket.
+    pub valid_until: DateTime<Utc>,
+    /// Signing key of `payload`, as listed at `/tickets/api/ticket-keys`.
+    #[schema(example = "2026-10")]
+    pub key_id: String,
+    /// The signed pass the ticket's QR code holds, base64url-encoded.
+    pub payload: String,
+}
+
+impl From<IssuedTicket> for TicketResponse {
+    fn from(IssuedTicket { ticket, pass }: IssuedTicket) -> Self {
+        Self {
+            id: ticket.id(),
+            reservation_id: ticket.reservation_id(),
+            event_id: ticket.event_id(),
+            ticket_type_id: ticket.ticket_type_id(),
+            holder_id: ticket.holder_id(),
+            seat_id: ticket.seat_id(),
+            issued_at: *ticket.issued_at(),
+            valid_from: pass.claims.valid_from,
+            valid_until: pass.claims.valid_until,
+            key_id: pass.key_id,
+            payload: pass.payload,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::ConfirmReservationParams, ConfirmReservationUseCase, ConfirmReservationUseCaseError,
+};
+
+use super::super::dtos::{ConfirmReservationError, ReservationResponse};
+
+#[utoipa::path(
+    post,
+    path = "/tickets/api/reservations/{id}/confirm",
+    params(
+        ("id" = Uuid, Path, description = "Reservation ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "Reservation confirmed; its tickets count as sold", body = ReservationResponse),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller doesn't hold the reservation", body = ConfirmReservationError),
+        (status = 404, description = "Reservation not found", body = ConfirmReservationError),
+        (status = 409, description = "Reservation is already confirmed or released, or its hold expired", body = ConfirmReservationError),
+        (status = 500, description = "Internal server error", body = ConfirmReservationError)
+    ),
+    tag = "reservations"
+)]
+pub async fn confirm_reservation(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<ConfirmReservationUseCase>,
+) -> impl Responder {
+    let data = ConfirmReservationParams {
+        id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(reservation) => {
+            let response: ReservationResponse = reservation.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(e @ ConfirmReservationUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(ConfirmReservationError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ ConfirmReservationUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(ConfirmReservationError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (ConfirmReservationUseCaseError::NotHeld | ConfirmReservationUseCaseError::Expired),
+        ) => HttpResponse::Conflict().json(ConfirmReservationError {
+            error: e.to_string(),
+        }),
+        Err(ConfirmReservationUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ConfirmReservationError { error: msg })
+        }
+    }
+}
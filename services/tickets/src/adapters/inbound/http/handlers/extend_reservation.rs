@@ -0,0 +1,64 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::ExtendReservationParams, ExtendReservationUseCase, ExtendReservationUseCaseError,
+};
+
+use super::super::dtos::{ExtendReservationError, ReservationResponse};
+
+#[utoipa::path(
+    post,
+    path = "/tickets/api/reservations/{id}/extend",
+    params(
+        ("id" = Uuid, Path, description = "Reservation ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "Hold extended by another hold period", body = ReservationResponse),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller doesn't hold the reservation", body = ExtendReservationError),
+        (status = 404, description = "Reservation not found", body = ExtendReservationError),
+        (status = 409, description = "Reservation isn't held, its hold expired, or it was already extended", body = ExtendReservationError),
+        (status = 500, description = "Internal server error", body = ExtendReservationError)
+    ),
+    tag = "reservations"
+)]
+pub async fn extend_reservation(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<ExtendReservationUseCase>,
+) -> impl Responder {
+    let data = ExtendReservationParams {
+        id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(reservation) => {
+            let response: ReservationResponse = reservation.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(e @ ExtendReservationUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(ExtendReservationError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ ExtendReservationUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(ExtendReservationError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (ExtendReservationUseCaseError::NotHeld
+            | ExtendReservationUseCaseError::Expired
+            | ExtendReservationUseCaseError::AlreadyExtended),
+        ) => HttpResponse::Conflict().json(ExtendReservationError {
+            error: e.to_string(),
+        }),
+        Err(ExtendReservationUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ExtendReservationError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{ServiceCaller, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::GetEventHoldersParams, GetEventHoldersUseCase, GetEventHoldersUseCaseError,
+};
+
+use super::super::dtos::{EventHoldersResponse, GetEventHoldersError};
+
+#[utoipa::path(
+    get,
+    path = "/tickets/api/events/{event_id}/holders",
+    params(
+        ("event_id" = Uuid, Path, description = "Event ID"),
+        ("X-Service-Token" = String, Header, description = "Secret shared by the services; this endpoint is internal")
+    ),
+    responses(
+        (status = 200, description = "Users who were issued tickets for the event", body = EventHoldersResponse),
+        (status = 401, description = "Caller is not one of the services"),
+        (status = 409, description = "The events service still shows the event as scheduled", body = GetEventHoldersError),
+        (status = 500, description = "Internal server error", body = GetEventHoldersError)
+    ),
+    tag = "tickets"
+)]
+pub async fn get_event_holders(
+    path: web::Path<Uuid>,
+    _caller: ServiceCaller,
+    use_case: web::Data<GetEventHoldersUseCase>,
+) -> impl Responder {
+    let event_id = path.into_inner();
+    let data = GetEventHoldersParams { event_id };
+
+    match use_case.execute(data).await {
+        Ok(holders) => HttpResponse::Ok().json(EventHoldersResponse { event_id, holders }),
+        Err(e @ GetEventHoldersUseCaseError::EventScheduled) => {
+            HttpResponse::Conflict().json(GetEventHoldersError {
+                error: e.to_string(),
+            })
+        }
+        Err(GetEventHoldersUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetEventHoldersError { error: msg })
+        }
+    }
+}
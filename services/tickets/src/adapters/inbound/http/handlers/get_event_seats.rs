@@ -0,0 +1,36 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::GetEventSeatsParams, GetEventSeatsUseCase, GetEventSeatsUseCaseError,
+};
+
+use super::super::dtos::{EventSeatsResponse, GetEventSeatsError};
+
+#[utoipa::path(
+    get,
+    path = "/tickets/api/events/{event_id}/seats",
+    params(
+        ("event_id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Seats of the event that are held or sold", body = EventSeatsResponse),
+        (status = 500, description = "Internal server error", body = GetEventSeatsError)
+    ),
+    tag = "inventory"
+)]
+pub async fn get_event_seats(
+    path: web::Path<Uuid>,
+    use_case: web::Data<GetEventSeatsUseCase>,
+) -> impl Responder {
+    let event_id = path.into_inner();
+    let data = GetEventSeatsParams { event_id };
+
+    match use_case.execute(data).await {
+        Ok(taken) => HttpResponse::Ok().json(EventSeatsResponse::new(event_id, taken)),
+        Err(GetEventSeatsUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetEventSeatsError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{use_cases::GetInventoryParams, GetInventoryUseCase, GetInventoryUseCaseError};
+
+use super::super::dtos::{GetInventoryError, InventoryResponse};
+
+#[utoipa::path(
+    get,
+    path = "/tickets/api/events/{event_id}/ticket-types/{ticket_type_id}/inventory",
+    params(
+        ("event_id" = Uuid, Path, description = "Event ID"),
+        ("ticket_type_id" = Uuid, Path, description = "Ticket type ID")
+    ),
+    responses(
+        (status = 200, description = "How many tickets of the type are available, reserved and sold", body = InventoryResponse),
+        (status = 404, description = "Event or ticket type not found", body = GetInventoryError),
+        (status = 500, description = "Internal server error", body = GetInventoryError)
+    ),
+    tag = "inventory"
+)]
+pub async fn get_inventory(
+    path: web::Path<(Uuid, Uuid)>,
+    use_case: web::Data<GetInventoryUseCase>,
+) -> impl Responder {
+    let (event_id, ticket_type_id) = path.into_inner();
+    let data = GetInventoryParams {
+        event_id,
+        ticket_type_id,
+    };
+
+    match use_case.execute(data).await {
+        Ok(inventory) => {
+            let response: InventoryResponse = inventory.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(e @ GetInventoryUseCaseError::TicketTypeNotFound) => {
+            HttpResponse::NotFound().json(GetInventoryError {
+                error: e.to_string(),
+            })
+        }
+        Err(GetInventoryUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetInventoryError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::GetReservationParams, GetReservationUseCase, GetReservationUseCaseError,
+};
+
+use super::super::dtos::{GetReservationError, ReservationResponse};
+
+#[utoipa::path(
+    get,
+    path = "/tickets/api/reservations/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Reservation ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "Reservation found", body = ReservationResponse),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller doesn't hold the reservation", body = GetReservationError),
+        (status = 404, description = "Reservation not found", body = GetReservationError),
+        (status = 500, description = "Internal server error", body = GetReservationError)
+    ),
+    tag = "reservations"
+)]
+pub async fn get_reservation(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<GetReservationUseCase>,
+) -> impl Responder {
+    let data = GetReservationParams {
+        id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(reservation) => {
+            let response: ReservationResponse = reservation.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(e @ GetReservationUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(GetReservationError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ GetReservationUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(GetReservationError {
+                error: e.to_string(),
+            })
+        }
+        Err(GetReservationUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetReservationError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::IssuedTicket, use_cases::GetTicketParams, GetTicketUseCase, GetTicketUseCaseError,
+};
+
+use super::super::dtos::{GetTicketError, TicketResponse};
+use super::super::renderers::{render_ticket_qr_png, render_ticket_qr_svg};
+
+fn error_response(error: GetTicketUseCaseError) -> HttpResponse {
+    match error {
+        e @ (GetTicketUseCaseError::NotFound | GetTicketUseCaseError::TicketTypeNotFound) => {
+            HttpResponse::NotFound().json(GetTicketError {
+                error: e.to_string(),
+            })
+        }
+        e @ GetTicketUseCaseError::Forbidden => HttpResponse::Forbidden().json(GetTicketError {
+            error: e.to_string(),
+        }),
+        e @ GetTicketUseCaseError::Voided => HttpResponse::Gone().json(GetTicketError {
+            error: e.to_string(),
+        }),
+        GetTicketUseCaseError::DatabaseError(msg) => {
+            HttpResponse::InternalServerError().json(GetTicketError { error: msg })
+        }
+    }
+}
+
+async fn issued_ticket(
+    id: Uuid,
+    user: AuthenticatedUser,
+    use_case: &GetTicketUseCase,
+) -> Result<IssuedTicket, HttpResponse> {
+    let data = GetTicketParams {
+        id,
+        requested_by: user.id(),
+    };
+    use_case.execute(data).await.map_err(error_response)
+}
+
+#[utoipa::path(
+    get,
+    path = "/tickets/api/tickets/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Ticket ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "The ticket with its signed pass", body = TicketResponse),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller doesn't hold the ticket", body = GetTicketError),
+        (status = 404, description = "Ticket, event or ticket type not found", body = GetTicketError),
+        (status = 410, description = "The ticket's reservation was released", body = GetTicketError),
+        (status = 500, description = "Internal server error", body = GetTicketError)
+    ),
+    tag = "tickets"
+)]
+pub async fn get_ticket(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<GetTicketUseCase>,
+) -> impl Responder {
+    match issued_ticket(path.into_inner(), user, &use_case).await {
+        Ok(issued) => {
+            let response: TicketResponse = issued.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(response) => response,
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/tickets/api/tickets/{id}/qr.png",
+    params(
+        ("id" = Uuid, Path, description = "Ticket ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "The ticket's signed pass as a QR code", body = Vec<u8>, content_type = "image/png"),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller doesn't hold the ticket", body = GetTicketError),
+        (status = 404, description = "Ticket, event or ticket type not found", body = GetTicketError),
+        (status = 410, description = "The ticket's reservation was released", body = GetTicketError),
+        (status = 500, description = "Internal server error", body = GetTicketError)
+    ),
+    tag = "tickets"
+)]
+pub async fn get_ticket_qr_png(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<GetTicketUseCase>,
+) -> impl Responder {
+    match issued_ticket(path.into_inner(), user, &use_case).await {
+        Ok(issued) => HttpResponse::Ok()
+            .content_type("image/png")
+            .body(render_ticket_qr_png(&issued.pass)),
+        Err(response) => response,
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/tickets/api/tickets/{id}/qr.svg",
+    params(
+        ("id" = Uuid, Path, description = "Ticket ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "The ticket's signed pass as a QR code", body = String, content_type = "image/svg+xml"),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller doesn't hold the ticket", body = GetTicketError),
+        (status = 404, description = "Ticket, event or ticket type not found", body = GetTicketError),
+        (status = 410, description = "The ticket's reservation was released", body = GetTicketError),
+        (status = 500, description = "Internal server error", body = GetTicketError)
+    ),
+    tag = "tickets"
+)]
+pub async fn get_ticket_qr_svg(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<GetTicketUseCase>,
+) -> impl Responder {
+    match issued_ticket(path.into_inner(), user, &use_case).await {
+        Ok(issued) => HttpResponse::Ok()
+            .content_type("image/svg+xml")
+            .body(render_ticket_qr_svg(&issued.pass)),
+        Err(response) => response,
+    }
+}
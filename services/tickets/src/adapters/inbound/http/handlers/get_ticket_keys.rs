@@ -0,0 +1,28 @@
+use actix_web::{http::header, web, HttpResponse, Responder};
+use shared::UseCase;
+
+use crate::domain::GetTicketKeysUseCase;
+
+use super::super::dtos::TicketKeysResponse;
+
+/// How long scanners may cache the key list. Rotations publish the new key
+/// well before it signs, so a stale list only misses keys not in use yet.
+const KEYS_MAX_AGE_SECS: u32 = 3600;
+
+#[utoipa::path(
+    get,
+    path = "/tickets/api/ticket-keys",
+    responses(
+        (status = 200, description = "Public keys ticket passes are signed with, for verifying them offline", body = TicketKeysResponse)
+    ),
+    tag = "tickets"
+)]
+pub async fn get_ticket_keys(use_case: web::Data<GetTicketKeysUseCase>) -> impl Responder {
+    let response: TicketKeysResponse = use_case.execute(()).await.into();
+    HttpResponse::Ok()
+        .insert_header(header::CacheControl(vec![
+            header::CacheDirective::Public,
+            header::CacheDirective::MaxAge(KEYS_MAX_AGE_SECS),
+        ]))
+        .json(response)
+}
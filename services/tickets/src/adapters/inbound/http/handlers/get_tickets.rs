@@ -0,0 +1,60 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{use_cases::GetTicketsParams, GetTicketsUseCase, GetTicketsUseCaseError};
+
+use super::super::dtos::{GetTicketsError, TicketResponse};
+
+#[utoipa::path(
+    get,
+    path = "/tickets/api/reservations/{id}/tickets",
+    params(
+        ("id" = Uuid, Path, description = "Reservation ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "The reservation's tickets with their signed passes", body = Vec<TicketResponse>),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller doesn't hold the reservation", body = GetTicketsError),
+        (status = 404, description = "Reservation, event or ticket type not found", body = GetTicketsError),
+        (status = 409, description = "Reservation isn't confirmed", body = GetTicketsError),
+        (status = 500, description = "Internal server error", body = GetTicketsError)
+    ),
+    tag = "tickets"
+)]
+pub async fn get_tickets(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<GetTicketsUseCase>,
+) -> impl Responder {
+    let data = GetTicketsParams {
+        reservation_id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(tickets) => {
+            let response: Vec<TicketResponse> = tickets.into_iter().map(Into::into).collect();
+            HttpResponse::Ok().json(response)
+        }
+        Err(
+            e @ (GetTicketsUseCaseError::NotFound | GetTicketsUseCaseError::TicketTypeNotFound),
+        ) => HttpResponse::NotFound().json(GetTicketsError {
+            error: e.to_string(),
+        }),
+        Err(e @ GetTicketsUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(GetTicketsError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ GetTicketsUseCaseError::NotConfirmed) => {
+            HttpResponse::Conflict().json(GetTicketsError {
+                error: e.to_string(),
+            })
+        }
+        Err(GetTicketsUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetTicketsError { error: msg })
+        }
+    }
+}
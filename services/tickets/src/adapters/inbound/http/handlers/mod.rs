@@ -0,0 +1,25 @@
+pub mod confirm_reservation;
+pub mod extend_reservation;
+pub mod get_event_holders;
+pub mod get_event_seats;
+pub mod get_inventory;
+pub mod get_reservation;
+pub mod get_ticket;
+pub mod get_ticket_keys;
+pub mod get_tickets;
+pub mod release_event_reservations;
+pub mod release_reservation;
+pub mod reserve_tickets;
+
+pub use confirm_reservation::confirm_reservation;
+pub use extend_reservation::extend_reservation;
+pub use get_event_holders::get_event_holders;
+pub use get_event_seats::get_event_seats;
+pub use get_inventory::get_inventory;
+pub use get_reservation::get_reservation;
+pub use get_ticket::{get_ticket, get_ticket_qr_png, get_ticket_qr_svg};
+pub use get_ticket_keys::get_ticket_keys;
+pub use get_tickets::get_tickets;
+pub use release_event_reservations::release_event_reservations;
+pub use release_reservation::release_reservation;
+pub use reserve_tickets::reserve_tickets;
@@ -0,0 +1,54 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{ServiceCaller, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{ReleaseEventReservationsUseCase, ReleaseEventReservationsUseCaseError};
+
+use super::super::dtos::{
+    ReleaseEventReservationsError, ReleaseEventReservationsRequest,
+    ReleaseEventReservationsResponse,
+};
+
+#[utoipa::path(
+    post,
+    path = "/tickets/api/events/{event_id}/reservations/release",
+    params(
+        ("event_id" = Uuid, Path, description = "Event ID"),
+        ("X-Service-Token" = String, Header, description = "Secret shared by the services; this endpoint is internal")
+    ),
+    request_body = ReleaseEventReservationsRequest,
+    responses(
+        (status = 200, description = "Every held or confirmed reservation of the event released", body = ReleaseEventReservationsResponse),
+        (status = 400, description = "Invalid input", body = ReleaseEventReservationsError),
+        (status = 401, description = "Caller is not one of the services"),
+        (status = 409, description = "The events service still shows the event as scheduled", body = ReleaseEventReservationsError),
+        (status = 500, description = "Internal server error", body = ReleaseEventReservationsError)
+    ),
+    tag = "reservations"
+)]
+pub async fn release_event_reservations(
+    path: web::Path<Uuid>,
+    _caller: ServiceCaller,
+    req: web::Json<ReleaseEventReservationsRequest>,
+    use_case: web::Data<ReleaseEventReservationsUseCase>,
+) -> impl Responder {
+    let event_id = path.into_inner();
+    let data = (event_id, req.into_inner()).into();
+
+    match use_case.execute(data).await {
+        Ok(released) => {
+            HttpResponse::Ok().json(ReleaseEventReservationsResponse { event_id, released })
+        }
+        Err(e @ ReleaseEventReservationsUseCaseError::InvalidStatus) => HttpResponse::BadRequest()
+            .json(ReleaseEventReservationsError {
+                error: e.to_string(),
+            }),
+        Err(e @ ReleaseEventReservationsUseCaseError::EventScheduled) => HttpResponse::Conflict()
+            .json(ReleaseEventReservationsError {
+                error: e.to_string(),
+            }),
+        Err(ReleaseEventReservationsUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ReleaseEventReservationsError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::ReleaseReservationParams, ReleaseReservationUseCase, ReleaseReservationUseCaseError,
+};
+
+use super::super::dtos::{ReleaseReservationError, ReservationResponse};
+
+#[utoipa::path(
+    post,
+    path = "/tickets/api/reservations/{id}/release",
+    params(
+        ("id" = Uuid, Path, description = "Reservation ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "Hold released; its tickets go back on sale", body = ReservationResponse),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller doesn't hold the reservation", body = ReleaseReservationError),
+        (status = 404, description = "Reservation not found", body = ReleaseReservationError),
+        (status = 409, description = "Reservation is already released or expired, or is confirmed", body = ReleaseReservationError),
+        (status = 500, description = "Internal server error", body = ReleaseReservationError)
+    ),
+    tag = "reservations"
+)]
+pub async fn release_reservation(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<ReleaseReservationUseCase>,
+) -> impl Responder {
+    let data = ReleaseReservationParams {
+        id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(reservation) => {
+            let response: ReservationResponse = reservation.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(e @ ReleaseReservationUseCaseError::NotFound) => {
+            HttpResponse::NotFound().json(ReleaseReservationError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ ReleaseReservationUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(ReleaseReservationError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (ReleaseReservationUseCaseError::AlreadyReleased
+            | ReleaseReservationUseCaseError::Confirmed),
+        ) => HttpResponse::Conflict().json(ReleaseReservationError {
+            error: e.to_string(),
+        }),
+        Err(ReleaseReservationUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ReleaseReservationError { error: msg })
+        }
+    }
+}
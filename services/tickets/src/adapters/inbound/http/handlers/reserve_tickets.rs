@@ -0,0 +1,72 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+
+use crate::domain::{
+    use_cases::ReserveTicketsParams, ReserveTicketsUseCase, ReserveTicketsUseCaseError,
+};
+
+use super::super::dtos::{ReservationResponse, ReserveTicketsError, ReserveTicketsRequest};
+
+#[utoipa::path(
+    post,
+    path = "/tickets/api/reservations",
+    params(
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = ReserveTicketsRequest,
+    responses(
+        (status = 201, description = "Tickets held for the caller until the reservation is confirmed, released or its hold expires", body = ReservationResponse),
+        (status = 400, description = "Quantity outside the ticket type's per-order limits, seats not at the event's venue or the ticket type's section, or best available seats asked for an event without assigned seating, or seats chosen for a ticket type not sold by section", body = ReserveTicketsError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 404, description = "Event or ticket type not found", body = ReserveTicketsError),
+        (status = 409, description = "Ticket type not on sale, not enough tickets left, seats already taken, or not enough seats left together", body = ReserveTicketsError),
+        (status = 500, description = "Internal server error", body = ReserveTicketsError)
+    ),
+    tag = "reservations"
+)]
+pub async fn reserve_tickets(
+    user: AuthenticatedUser,
+    body: web::Json<ReserveTicketsRequest>,
+    use_case: web::Data<ReserveTicketsUseCase>,
+) -> impl Responder {
+    let data: ReserveTicketsParams = match (user, body.into_inner()).try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(ReserveTicketsError {
+                error: e.to_string(),
+            });
+        }
+    };
+
+    match use_case.execute(data).await {
+        Ok(reservation) => {
+            let response: ReservationResponse = reservation.into();
+            HttpResponse::Created().json(response)
+        }
+        Err(
+            e @ (ReserveTicketsUseCaseError::BelowMinimum(_)
+            | ReserveTicketsUseCaseError::AboveMaximum(_)
+            | ReserveTicketsUseCaseError::UnknownSeats
+            | ReserveTicketsUseCaseError::NoSeating
+            | ReserveTicketsUseCaseError::NotSoldBySection),
+        ) => HttpResponse::BadRequest().json(ReserveTicketsError {
+            error: e.to_string(),
+        }),
+        Err(e @ ReserveTicketsUseCaseError::TicketTypeNotFound) => {
+            HttpResponse::NotFound().json(ReserveTicketsError {
+                error: e.to_string(),
+            })
+        }
+        Err(
+            e @ (ReserveTicketsUseCaseError::NotOnSale
+            | ReserveTicketsUseCaseError::SoldOut
+            | ReserveTicketsUseCaseError::SeatsTaken
+            | ReserveTicketsUseCaseError::NotEnoughSeatsTogether),
+        ) => HttpResponse::Conflict().json(ReserveTicketsError {
+            error: e.to_string(),
+        }),
+        Err(ReserveTicketsUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(ReserveTicketsError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod dtos;
+pub mod handlers;
+pub mod renderers;
+pub mod routes;
+
+pub use routes::configure_routes;
@@ -0,0 +1,3 @@
+pub mod ticket_qr;
+
+pub use ticket_qr::{render_ticket_qr_png, render_ticket_qr_svg};
@@ -0,0 +1,36 @@
+use image::{ImageFormat, Luma};
+use qrcode::{render::svg, EcLevel, QrCode};
+use std::io::Cursor;
+
+use crate::domain::entities::TicketPass;
+
+/// Smallest width and height of a rendered code, in pixels.
+const MIN_SIZE: u32 = 256;
+
+/// Medium error correction keeps the code readable off a scratched phone
+/// screen or a creased print-out without making it much denser.
+fn qr_code(pass: &TicketPass) -> QrCode {
+    QrCode::with_error_correction_level(pass.payload.as_bytes(), EcLevel::M)
+        .expect("ticket passes fit in a QR code")
+}
+
+/// Renders a ticket's pass as a standalone SVG document.
+pub fn render_ticket_qr_svg(pass: &TicketPass) -> String {
+    qr_code(pass)
+        .render::<svg::Color>()
+        .min_dimensions(MIN_SIZE, MIN_SIZE)
+        .build()
+}
+
+/// Renders a ticket's pass as a greyscale PNG image.
+pub fn render_ticket_qr_png(pass: &TicketPass) -> Vec<u8> {
+    let image = qr_code(pass)
+        .render::<Luma<u8>>()
+        .min_dimensions(MIN_SIZE, MIN_SIZE)
+        .build();
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .expect("PNG encoding into memory can't fail");
+    png
+}
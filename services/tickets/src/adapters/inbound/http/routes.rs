@@ -0,0 +1,39 @@
+use actix_web::web;
+
+use super::handlers::{
+    confirm_reservation, extend_reservation, get_event_holders, get_event_seats, get_inventory,
+    get_reservation, get_ticket, get_ticket_keys, get_ticket_qr_png, get_ticket_qr_svg,
+    get_tickets, release_event_reservations, release_reservation, reserve_tickets,
+};
+
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/reservations")
+            .route("", web::post().to(reserve_tickets))
+            .route("/{id}", web::get().to(get_reservation))
+            .route("/{id}/confirm", web::post().to(confirm_reservation))
+            .route("/{id}/release", web::post().to(release_reservation))
+            .route("/{id}/extend", web::post().to(extend_reservation))
+            .route("/{id}/tickets", web::get().to(get_tickets)),
+    )
+    .service(
+        web::scope("/api/tickets")
+            .route("/{id}", web::get().to(get_ticket))
+            .route("/{id}/qr.png", web::get().to(get_ticket_qr_png))
+            .route("/{id}/qr.svg", web::get().to(get_ticket_qr_svg)),
+    )
+    .route("/api/ticket-keys", web::get().to(get_ticket_keys))
+    .service(
+        web::scope("/api/events")
+            .route("/{event_id}/seats", web::get().to(get_event_seats))
+            .route("/{event_id}/holders", web::get().to(get_event_holders))
+            .route(
+                "/{event_id}/reservations/release",
+                web::post().to(release_event_reservations),
+            )
+            .route(
+                "/{event_id}/ticket-types/{ticket_type_id}/inventory",
+                web::get().to(get_inventory),
+            ),
+    );
+}
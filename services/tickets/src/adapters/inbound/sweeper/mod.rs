@@ -0,0 +1,41 @@
+use chrono::Utc;
+use shared::UseCase;
+use std::{sync::Arc, time::Duration};
+use tokio::time::MissedTickBehavior;
+
+use crate::domain::ExpireHoldsUseCase;
+
+/// Background loop that puts the tickets of expired holds back on sale.
+///
+/// Every replica runs its own sweeper. Lapsed holds are claimed with
+/// `SKIP LOCKED`, so replicas never expire the same hold twice, and a hold
+/// being confirmed at the same moment is left to the confirmation.
+pub struct HoldSweeper {
+    expire_holds: Arc<ExpireHoldsUseCase>,
+    interval: Duration,
+}
+
+impl HoldSweeper {
+    pub fn new(expire_holds: Arc<ExpireHoldsUseCase>, interval: Duration) -> Self {
+        Self {
+            expire_holds,
+            interval,
+        }
+    }
+
+    pub async fn run(self) {
+        let mut ticker = tokio::time::interval(self.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match self.expire_holds.execute(Utc::now()).await {
+                Ok(expired) if expired > 0 => {
+                    log::info!("Sweeper expired {} hold(s)", expired)
+                }
+                Ok(_) => {}
+                Err(e) => log::error!("Expiring holds failed: {}", e),
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{AdmissionWindow, PlanRow, PlanSeat, PlanSection, SeatingPlan},
+    DomainError, DomainResult, EventCatalog, TicketOffer,
+};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum EventState {
+    Scheduled,
+    Cancelled,
+}
+
+#[derive(Deserialize)]
+struct TicketTypeResponse {
+    id: Uuid,
+    quantity: i32,
+    min_per_order: i32,
+    max_per_order: i32,
+    sales_start: Option<DateTime<Utc>>,
+    sales_end: Option<DateTime<Utc>>,
+    section_id: Option<Uuid>,
+}
+
+#[derive(Deserialize)]
+struct EventResponse {
+    status: EventState,
+    event_date: DateTime<Utc>,
+    doors_open_at: Option<DateTime<Utc>>,
+    ends_at: Option<DateTime<Utc>>,
+    sales_opened_at: Option<DateTime<Utc>>,
+    ticket_types: Vec<TicketTypeResponse>,
+}
+
+#[derive(Deserialize)]
+struct SeatResponse {
+    id: Uuid,
+    x: f64,
+    accessible: bool,
+    obstructed: bool,
+}
+
+#[derive(Deserialize)]
+struct SeatRowResponse {
+    seats: Vec<SeatResponse>,
+}
+
+#[derive(Deserialize)]
+struct SeatSectionResponse {
+    id: Uuid,
+    rows: Vec<SeatRowResponse>,
+}
+
+#[derive(Deserialize)]
+struct SeatMapResponse {
+    sections: Vec<SeatSectionResponse>,
+}
+
+/// Reads ticket types from the events service's public HTTP API, which only
+/// shows published events and their public ticket types.
+pub struct HttpEventCatalog {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl HttpEventCatalog {
+    /// `base_url` is the service root, e.g. `http://events-service:8081`.
+    pub fn new(base_url: &str) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("HTTP client configuration is valid");
+        Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// The event as the public sees it, or `None` when it isn't shown.
+    async fn find_event(&self, event_id: Uuid) -> DomainResult<Option<EventResponse>> {
+        let response = self
+            .client
+            .get(format!("{}/events/api/events/{}", self.base_url, event_id))
+            .send()
+            .await
+            .map_err(|e| DomainError::DatabaseError(format!("Events service: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(None),
+            status if status.is_success() => response
+                .json()
+                .await
+                .map(Some)
+                .map_err(|e| DomainError::DatabaseError(format!("Events service: {}", e))),
+            status => Err(DomainError::DatabaseError(format!(
+                "Events service responded with {}",
+                status
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl EventCatalog for HttpEventCatalog {
+    async fn find_offer(
+        &self,
+        event_id: Uuid,
+        ticket_type_id: Uuid,
+    ) -> DomainResult<Option<TicketOffer>> {
+        let Some(event) = self.find_event(event_id).await? else {
+            return Ok(None);
+        };
+
+        let sales_open = event.status == EventState::Scheduled && event.sales_opened_at.is_some();
+        let admission =
+            AdmissionWindow::for_event(event.event_date, event.doors_open_at, event.ends_at);
+        Ok(event
+            .ticket_types
+            .into_iter()
+            .find(|ticket_type| ticket_type.id == ticket_type_id)
+            .map(|ticket_type| {
+                TicketOffer::new(
+                    event_id,
+                    ticket_type.id,
+                    ticket_type.quantity,
+                    ticket_type.min_per_order,
+                    ticket_type.max_per_order,
+                    sales_open,
+                    ticket_type.sales_start,
+                    ticket_type.sales_end,
+                    admission,
+                )
+                .with_section(ticket_type.section_id)
+            }))
+    }
+
+    async fn find_seating_plan(&self, event_id: Uuid) -> DomainResult<Option<SeatingPlan>> {
+        let response = self
+            .client
+            .get(format!(
+                "{}/events/api/events/{}/seat-map",
+                self.base_url, event_id
+            ))
+            .send()
+            .await
+            .map_err(|e| DomainError::DatabaseError(format!("Events service: {}", e)))?;
+
+        // Not found covers both an unknown event and one without assigned
+        // seating.
+        let seat_map: SeatMapResponse = match response.status() {
+            StatusCode::NOT_FOUND => return Ok(None),
+            status if status.is_success() => response
+                .json()
+                .await
+                .map_err(|e| DomainError::DatabaseError(format!("Events service: {}", e)))?,
+            status => {
+                return Err(DomainError::DatabaseError(format!(
+                    "Events service responded with {}",
+                    status
+                )))
+            }
+        };
+
+        // Sections and rows come in chart order, which puts the better
+        // ones first; seats within a row are ordered by position.
+        let sections = seat_map
+            .sections
+            .into_iter()
+            .map(|section| {
+                let rows = section
+                    .rows
+                    .into_iter()
+                    .map(|row| {
+                        let mut seats = row.seats;
+                        seats.sort_by(|a, b| a.x.total_cmp(&b.x));
+                        PlanRow::new(
+                            seats
+                                .into_iter()
+                                .map(|seat| {
+                                    PlanSeat::new(seat.id, seat.accessible, seat.obstructed)
+                                })
+                                .collect(),
+                        )
+                    })
+                    .collect();
+                PlanSection::new(section.id, rows)
+            })
+            .collect();
+        Ok(Some(SeatingPlan::new(sections)))
+    }
+
+    async fn is_scheduled(&self, event_id: Uuid) -> DomainResult<bool> {
+        Ok(self
+            .find_event(event_id)
+            .await?
+            .is_some_and(|event| event.status == EventState::Scheduled))
+    }
+}
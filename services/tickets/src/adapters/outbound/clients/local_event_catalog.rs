@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{AdmissionWindow, SeatingPlan},
+    DomainResult, EventCatalog, TicketOffer,
+};
+
+const LOCAL_QUANTITY: i32 = 100;
+const LOCAL_MAX_PER_ORDER: i32 = 10;
+
+/// Stand-in for the events service for local development: every ID is a
+/// ticket type on sale, with 100 tickets and up to 10 per order, for an event
+/// a week from now without assigned seating.
+pub struct LocalEventCatalog;
+
+#[async_trait]
+impl EventCatalog for LocalEventCatalog {
+    async fn find_offer(
+        &self,
+        event_id: Uuid,
+        ticket_type_id: Uuid,
+    ) -> DomainResult<Option<TicketOffer>> {
+        Ok(Some(TicketOffer::new(
+            event_id,
+            ticket_type_id,
+            LOCAL_QUANTITY,
+            1,
+            LOCAL_MAX_PER_ORDER,
+            true,
+            None,
+            None,
+            AdmissionWindow::for_event(Utc::now() + Duration::weeks(1), None, None),
+        )))
+    }
+
+    async fn find_seating_plan(&self, _event_id: Uuid) -> DomainResult<Option<SeatingPlan>> {
+        Ok(None)
+    }
+
+    async fn is_scheduled(&self, _event_id: Uuid) -> DomainResult<bool> {
+        Ok(true)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod http_event_catalog;
+pub mod local_event_catalog;
+
+pub use http_event_catalog::HttpEventCatalog;
+pub use local_event_catalog::LocalEventCatalog;
@@ -0,0 +1,4 @@
+pub mod models;
+pub mod repositories;
+
+pub use repositories::{InventoryRepositoryImpl, ReservationRepositoryImpl, TicketRepositoryImpl};
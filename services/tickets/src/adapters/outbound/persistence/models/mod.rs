@@ -0,0 +1,4 @@
+pub mod reservation;
+pub mod reserved_seat;
+pub mod ticket;
+pub mod ticket_inventory;
@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{entities::ReservationStatus, Reservation};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "reservations")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub ticket_type_id: Uuid,
+    pub holder_id: Uuid,
+    pub quantity: i32,
+    /// `ReservationStatus::as_str` of the reservation's status.
+    pub status: String,
+    pub expires_at: DateTime<Utc>,
+    pub extended_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    pub fn status(&self) -> ReservationStatus {
+        ReservationStatus::parse(&self.status).unwrap_or_else(|| {
+            log::warn!(
+                "Unknown status `{}` for reservation {}, treating it as released",
+                self.status,
+                self.id
+            );
+            ReservationStatus::Released
+        })
+    }
+}
+
+impl From<Model> for Reservation {
+    fn from(model: Model) -> Self {
+        let status = model.status();
+        Reservation::new(
+            model.id,
+            model.event_id,
+            model.ticket_type_id,
+            model.holder_id,
+            model.quantity,
+            status,
+            model.expires_at,
+            model.extended_at,
+            model.created_at,
+            model.updated_at,
+        )
+    }
+}
+
+impl From<&Reservation> for ActiveModel {
+    fn from(reservation: &Reservation) -> Self {
+        ActiveModel {
+            id: sea_orm::Set(reservation.id()),
+            event_id: sea_orm::Set(reservation.event_id()),
+            ticket_type_id: sea_orm::Set(reservation.ticket_type_id()),
+            holder_id: sea_orm::Set(reservation.holder_id()),
+            quantity: sea_orm::Set(reservation.quantity()),
+            status: sea_orm::Set(reservation.status().as_str().to_string()),
+            expires_at: sea_orm::Set(*reservation.expires_at()),
+            extended_at: sea_orm::Set(reservation.extended_at().copied()),
+            created_at: sea_orm::Set(*reservation.created_at()),
+            updated_at: sea_orm::Set(*reservation.updated_at()),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::Ticket;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "tickets")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub reservation_id: Uuid,
+    pub event_id: Uuid,
+    pub ticket_type_id: Uuid,
+    pub holder_id: Uuid,
+    pub seat_id: Option<Uuid>,
+    pub issued_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for Ticket {
+    fn from(model: Model) -> Self {
+        Ticket::new(
+            model.id,
+            model.reservation_id,
+            model.event_id,
+            model.ticket_type_id,
+            model.holder_id,
+            model.seat_id,
+            model.issued_at,
+        )
+    }
+}
+
+impl From<&Ticket> for ActiveModel {
+    fn from(ticket: &Ticket) -> Self {
+        ActiveModel {
+            id: sea_orm::Set(ticket.id()),
+            reservation_id: sea_orm::Set(ticket.reservation_id()),
+            event_id: sea_orm::Set(ticket.event_id()),
+            ticket_type_id: sea_orm::Set(ticket.ticket_type_id()),
+            holder_id: sea_orm::Set(ticket.holder_id()),
+            seat_id: sea_orm::Set(ticket.seat_id()),
+            issued_at: sea_orm::Set(*ticket.issued_at()),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::Inventory;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "ticket_inventories")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub ticket_type_id: Uuid,
+    pub event_id: Uuid,
+    pub capacity: i32,
+    pub reserved: i32,
+    pub sold: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for Inventory {
+    fn from(model: Model) -> Self {
+        Inventory::new(
+            model.ticket_type_id,
+            model.event_id,
+            model.capacity,
+            model.reserved,
+            model.sold,
+            model.updated_at,
+        )
+    }
+}
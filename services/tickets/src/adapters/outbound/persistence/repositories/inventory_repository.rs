@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{sea_query::OnConflict, DatabaseConnection, EntityTrait};
+
+use crate::domain::{
+    entities::TicketOffer, DomainError, DomainResult, Inventory, InventoryRepository,
+};
+
+use super::super::models::ticket_inventory::{self, Entity as TicketInventoryEntity};
+
+/// Capacity for an upserted inventory: what the offer says, but never less
+/// than what is already taken.
+const SYNCED_CAPACITY: &str =
+    "GREATEST(EXCLUDED.capacity, ticket_inventories.reserved + ticket_inventories.sold)";
+
+pub struct InventoryRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl InventoryRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl InventoryRepository for InventoryRepositoryImpl {
+    async fn sync(&self, offer: &TicketOffer) -> DomainResult<Inventory> {
+        // Most lookups find the capacity unchanged; skipping the write then
+        // keeps reads from queueing behind reservations for the row lock.
+        let existing = TicketInventoryEntity::find_by_id(offer.ticket_type_id())
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        if let Some(model) = existing {
+            if model.capacity == offer.quantity().max(model.reserved + model.sold) {
+                return Ok(model.into());
+            }
+        }
+
+        let now = Utc::now();
+        let model = ticket_inventory::ActiveModel {
+            ticket_type_id: sea_orm::Set(offer.ticket_type_id()),
+            event_id: sea_orm::Set(offer.event_id()),
+            capacity: sea_orm::Set(offer.quantity()),
+            reserved: sea_orm::Set(0),
+            sold: sea_orm::Set(0),
+            created_at: sea_orm::Set(now),
+            updated_at: sea_orm::Set(now),
+        };
+
+        let synced = TicketInventoryEntity::insert(model)
+            .on_conflict(
+                OnConflict::column(ticket_inventory::Column::TicketTypeId)
+                    .value(
+                        ticket_inventory::Column::Capacity,
+                        sea_orm::sea_query::Expr::cust(SYNCED_CAPACITY),
+                    )
+                    .update_column(ticket_inventory::Column::UpdatedAt)
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(synced.into())
+    }
+}
@@ -0,0 +1,596 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    sea_query::{Expr, LockBehavior, LockType, OnConflict},
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{HoldConflict, ReservationStatus, Ticket},
+    DomainError, DomainResult, Reservation, ReservationRepository,
+};
+
+use super::super::models::{
+    reservation::{self, Entity as ReservationEntity},
+    reserved_seat::{self, Entity as ReservedSeatEntity},
+    ticket::{self, Entity as TicketEntity},
+    ticket_inventory::{self, Entity as TicketInventoryEntity},
+};
+
+/// The seats a reservation holds, in seat ID order.
+async fn find_seat_ids<C: ConnectionTrait>(
+    conn: &C,
+    reservation_id: Uuid,
+) -> DomainResult<Vec<Uuid>> {
+    let seats = ReservedSeatEntity::find()
+        .filter(reserved_seat::Column::ReservationId.eq(reservation_id))
+        .order_by_asc(reserved_seat::Column::SeatId)
+        .all(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    Ok(seats.into_iter().map(|seat| seat.seat_id).collect())
+}
+
+/// Frees the seats of the given reservations.
+async fn free_seats<C: ConnectionTrait>(conn: &C, reservation_ids: &[Uuid]) -> DomainResult<()> {
+    ReservedSeatEntity::delete_many()
+        .filter(reserved_seat::Column::ReservationId.is_in(reservation_ids.iter().copied()))
+        .exec(conn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    Ok(())
+}
+
+pub struct ReservationRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl ReservationRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    async fn begin(&self) -> DomainResult<DatabaseTransaction> {
+        self.db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))
+    }
+
+    /// Locks the reservation row for the rest of the transaction, so
+    /// concurrent transitions of the same reservation run one at a time.
+    async fn lock(txn: &DatabaseTransaction, id: Uuid) -> DomainResult<reservation::Model> {
+        ReservationEntity::find_by_id(id)
+            .lock_exclusive()
+            .one(txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| DomainError::NotFound(format!("Reservation with id {} not found", id)))
+    }
+
+    /// Moves the reservation to `status` and shifts its tickets between the
+    /// inventory counters: `reserved_delta` and `sold_delta` are applied to
+    /// its ticket type's reserved and sold counts. Releasing frees its seats.
+    async fn transition(
+        txn: &DatabaseTransaction,
+        model: reservation::Model,
+        status: ReservationStatus,
+        reserved_delta: i32,
+        sold_delta: i32,
+        now: DateTime<Utc>,
+    ) -> DomainResult<Reservation> {
+        TicketInventoryEntity::update_many()
+            .col_expr(
+                ticket_inventory::Column::Reserved,
+                Expr::col(ticket_inventory::Column::Reserved).add(reserved_delta),
+            )
+            .col_expr(
+                ticket_inventory::Column::Sold,
+                Expr::col(ticket_inventory::Column::Sold).add(sold_delta),
+            )
+            .col_expr(ticket_inventory::Column::UpdatedAt, Expr::value(now))
+            .filter(ticket_inventory::Column::TicketTypeId.eq(model.ticket_type_id))
+            .exec(txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let seat_ids = if status == ReservationStatus::Released {
+            free_seats(txn, &[model.id]).await?;
+            Vec::new()
+        } else {
+            find_seat_ids(txn, model.id).await?
+        };
+
+        let mut active: reservation::ActiveModel = model.into();
+        active.status = sea_orm::Set(status.as_str().to_string());
+        active.updated_at = sea_orm::Set(now);
+        let updated = active
+            .update(txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(Reservation::from(updated).with_seats(seat_ids))
+    }
+}
+
+#[async_trait]
+impl ReservationRepository for ReservationRepositoryImpl {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Reservation> {
+        let model = ReservationEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("Reservation with id {} not found", id))
+            })?;
+        let seat_ids = find_seat_ids(&self.db, id).await?;
+
+        Ok(Reservation::from(model).with_seats(seat_ids))
+    }
+
+    async fn create(&self, reservation: &Reservation) -> DomainResult<Result<(), HoldConflict>> {
+        let quantity = reservation.quantity();
+        let txn = self.begin().await?;
+
+        // Checking and taking the tickets in one statement means two buyers
+        // can never both take the last ones: the second update waits for the
+        // first one's row lock and then re-checks against its result.
+        let taken = TicketInventoryEntity::update_many()
+            .col_expr(
+                ticket_inventory::Column::Reserved,
+                Expr::col(ticket_inventory::Column::Reserved).add(quantity),
+            )
+            .col_expr(
+                ticket_inventory::Column::UpdatedAt,
+                Expr::value(*reservation.created_at()),
+            )
+            .filter(ticket_inventory::Column::TicketTypeId.eq(reservation.ticket_type_id()))
+            .filter(
+                Expr::expr(
+                    Expr::col(ticket_inventory::Column::Capacity)
+                        .sub(Expr::col(ticket_inventory::Column::Reserved))
+                        .sub(Expr::col(ticket_inventory::Column::Sold)),
+                )
+                .gte(quantity),
+            )
+            .exec(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        if taken.rows_affected == 0 {
+            return Ok(Err(HoldConflict::SoldOut));
+        }
+
+        reservation::ActiveModel::from(reservation)
+            .insert(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        if !reservation.seat_ids().is_empty() {
+            // The primary key allows one reservation per seat; a seat another
+            // transaction is taking blocks until that one commits or rolls
+            // back. Seats go in sorted so two reservations wanting the same
+            // seats queue up instead of deadlocking.
+            let mut seat_ids = reservation.seat_ids().to_vec();
+            seat_ids.sort_unstable();
+            let seats = seat_ids.iter().map(|seat_id| reserved_seat::ActiveModel {
+                event_id: sea_orm::Set(reservation.event_id()),
+                seat_id: sea_orm::Set(*seat_id),
+                reservation_id: sea_orm::Set(reservation.id()),
+            });
+            let inserted = ReservedSeatEntity::insert_many(seats)
+                .on_conflict(
+                    OnConflict::columns([
+                        reserved_seat::Column::EventId,
+                        reserved_seat::Column::SeatId,
+                    ])
+                    .do_nothing()
+                    .to_owned(),
+                )
+                .exec_without_returning(&txn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+            if inserted < seat_ids.len() as u64 {
+                return Ok(Err(HoldConflict::SeatsTaken));
+            }
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(Ok(()))
+    }
+
+    async fn confirm(&self, id: Uuid, now: DateTime<Utc>) -> DomainResult<Option<Reservation>> {
+        let txn = self.begin().await?;
+        let model = Self::lock(&txn, id).await?;
+        if model.status() != ReservationStatus::Held || model.expires_at <= now {
+            return Ok(None);
+        }
+
+        let quantity = model.quantity;
+        let confirmed = Self::transition(
+            &txn,
+            model,
+            ReservationStatus::Confirmed,
+            -quantity,
+            quantity,
+            now,
+        )
+        .await?;
+
+        // The tickets are issued with the sale, so a confirmed reservation
+        // always has them.
+        let tickets = Ticket::issue_all(&confirmed, now);
+        TicketEntity::insert_many(tickets.iter().map(ticket::ActiveModel::from))
+            .exec_without_returning(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(Some(confirmed))
+    }
+
+    async fn release(
+        &self,
+        id: Uuid,
+        status: ReservationStatus,
+        now: DateTime<Utc>,
+    ) -> DomainResult<Option<Reservation>> {
+        let txn = self.begin().await?;
+        let model = Self::lock(&txn, id).await?;
+        if model.status() != status {
+            return Ok(None);
+        }
+        let quantity = model.quantity;
+        let (reserved_delta, sold_delta) = match status {
+            ReservationStatus::Held => (-quantity, 0),
+            ReservationStatus::Confirmed => (0, -quantity),
+            ReservationStatus::Released | ReservationStatus::Expired => return Ok(None),
+        };
+
+        let released = Self::transition(
+            &txn,
+            model,
+            ReservationStatus::Released,
+            reserved_delta,
+            sold_delta,
+            now,
+        )
+        .await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(Some(released))
+    }
+
+    async fn extend(&self, reservation: &Reservation) -> DomainResult<bool> {
+        let Some(extended_at) = reservation.extended_at().copied() else {
+            return Ok(false);
+        };
+
+        let result = ReservationEntity::update_many()
+            .col_expr(
+                reservation::Column::ExpiresAt,
+                Expr::value(*reservation.expires_at()),
+            )
+            .col_expr(reservation::Column::ExtendedAt, Expr::value(extended_at))
+            .col_expr(
+                reservation::Column::UpdatedAt,
+                Expr::value(*reservation.updated_at()),
+            )
+            .filter(reservation::Column::Id.eq(reservation.id()))
+            .filter(reservation::Column::Status.eq(ReservationStatus::Held.as_str()))
+            .filter(reservation::Column::ExtendedAt.is_null())
+            .filter(reservation::Column::ExpiresAt.gt(extended_at))
+            .exec(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn expire_lapsed(
+        &self,
+        now: DateTime<Utc>,
+        limit: u64,
+    ) -> DomainResult<Vec<Reservation>> {
+        let txn = self.begin().await?;
+
+        // Holds being confirmed, released or swept by another replica right
+        // now are locked; skipping them leaves them to whoever holds the lock.
+        let lapsed = ReservationEntity::find()
+            .filter(reservation::Column::Status.eq(ReservationStatus::Held.as_str()))
+            .filter(reservation::Column::ExpiresAt.lte(now))
+            .order_by_asc(reservation::Column::ExpiresAt)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        if lapsed.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let expired = ReservationEntity::update_many()
+            .col_expr(
+                reservation::Column::Status,
+                Expr::value(ReservationStatus::Expired.as_str()),
+            )
+            .col_expr(reservation::Column::UpdatedAt, Expr::value(now))
+            .filter(reservation::Column::Id.is_in(lapsed.iter().map(|model| model.id)))
+            .exec_with_returning(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        let expired_ids: Vec<Uuid> = expired.iter().map(|model| model.id).collect();
+        free_seats(&txn, &expired_ids).await?;
+
+        // Inventory rows are updated in a fixed order so that concurrent
+        // sweeps can't deadlock on each other.
+        let mut returned: BTreeMap<Uuid, i32> = BTreeMap::new();
+        for model in &expired {
+            *returned.entry(model.ticket_type_id).or_default() += model.quantity;
+        }
+        for (ticket_type_id, quantity) in returned {
+            TicketInventoryEntity::update_many()
+                .col_expr(
+                    ticket_inventory::Column::Reserved,
+                    Expr::col(ticket_inventory::Column::Reserved).sub(quantity),
+                )
+                .col_expr(ticket_inventory::Column::UpdatedAt, Expr::value(now))
+                .filter(ticket_inventory::Column::TicketTypeId.eq(ticket_type_id))
+                .exec(&txn)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(expired.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_ids_by_event(
+        &self,
+        event_id: Uuid,
+        status: ReservationStatus,
+    ) -> DomainResult<Vec<Uuid>> {
+        ReservationEntity::find()
+            .select_only()
+            .column(reservation::Column::Id)
+            .filter(reservation::Column::EventId.eq(event_id))
+            .filter(reservation::Column::Status.eq(status.as_str()))
+            .order_by_asc(reservation::Column::CreatedAt)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))
+    }
+
+    async fn find_taken_seats(
+        &self,
+        event_id: Uuid,
+    ) -> DomainResult<HashMap<Uuid, ReservationStatus>> {
+        let seats = ReservedSeatEntity::find()
+            .filter(reserved_seat::Column::EventId.eq(event_id))
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        if seats.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let statuses: HashMap<Uuid, ReservationStatus> = ReservationEntity::find()
+            .filter(reservation::Column::EventId.eq(event_id))
+            .filter(reservation::Column::Id.is_in(seats.iter().map(|seat| seat.reservation_id)))
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(|model| (model.id, model.status()))
+            .collect();
+
+        // A seat whose reservation was released between the two reads is
+        // free again.
+        Ok(seats
+            .into_iter()
+            .filter_map(|seat| {
+                statuses
+                    .get(&seat.reservation_id)
+                    .map(|status| (seat.seat_id, *status))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::persistence::InventoryRepositoryImpl;
+    use crate::domain::{entities::AdmissionWindow, InventoryRepository, TicketOffer};
+    use chrono::Duration;
+    use migration::MigratorTrait;
+    use sea_orm::{ConnectOptions, Database};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tokio::task::JoinSet;
+
+    const BUYERS: usize = 2000;
+
+    /// The scratch database in `TICKETS_TEST_DATABASE_URL`. Without one the
+    /// tests are skipped locally, but fail in CI, which always provides it.
+    async fn connect() -> Option<DatabaseConnection> {
+        let Ok(url) = std::env::var("TICKETS_TEST_DATABASE_URL") else {
+            assert!(
+                std::env::var_os("CI").is_none(),
+                "TICKETS_TEST_DATABASE_URL must be set in CI"
+            );
+            eprintln!("TICKETS_TEST_DATABASE_URL not set; skipping");
+            return None;
+        };
+        let mut options = ConnectOptions::new(url);
+        options.max_connections(50);
+        let db = Database::connect(options)
+            .await
+            .expect("test database is reachable");
+        migration::Migrator::up(&db, None)
+            .await
+            .expect("migrations run");
+        Some(db)
+    }
+
+    /// A new ticket type with `capacity` tickets, on sale.
+    async fn ticket_type(db: &DatabaseConnection, capacity: i32) -> TicketOffer {
+        let offer = TicketOffer::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            capacity,
+            1,
+            10,
+            true,
+            None,
+            None,
+            AdmissionWindow::for_event(Utc::now() + chrono::Duration::days(30), None, None),
+        );
+        InventoryRepositoryImpl::new(db.clone())
+            .sync(&offer)
+            .await
+            .expect("inventory is created");
+        offer
+    }
+
+    async fn reserved_count(db: &DatabaseConnection, ticket_type_id: Uuid) -> i32 {
+        TicketInventoryEntity::find_by_id(ticket_type_id)
+            .one(db)
+            .await
+            .expect("inventory loads")
+            .expect("inventory exists")
+            .reserved
+    }
+
+    /// Fires all `holds` at once and returns the ones that got their tickets.
+    async fn hold_all(
+        repository: Arc<ReservationRepositoryImpl>,
+        holds: Vec<Reservation>,
+    ) -> Vec<Reservation> {
+        let mut buyers = JoinSet::new();
+        for reservation in holds {
+            let repository = repository.clone();
+            buyers.spawn(async move {
+                let outcome = repository
+                    .create(&reservation)
+                    .await
+                    .expect("holding tickets doesn't fail");
+                outcome.ok().map(|()| reservation)
+            });
+        }
+
+        let mut held = Vec::new();
+        while let Some(outcome) = buyers.join_next().await {
+            held.extend(outcome.expect("buyer finishes"));
+        }
+        held
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+    async fn test_concurrent_holds_never_oversell_general_admission() {
+        let Some(db) = connect().await else {
+            return;
+        };
+        let capacity = 500;
+        let offer = ticket_type(&db, capacity).await;
+
+        let holds = (0..BUYERS)
+            .map(|buyer| {
+                Reservation::hold(
+                    offer.event_id(),
+                    offer.ticket_type_id(),
+                    Uuid::new_v4(),
+                    (buyer % 3) as i32 + 1,
+                    Duration::minutes(10),
+                    Utc::now(),
+                )
+            })
+            .collect();
+        let held = hold_all(Arc::new(ReservationRepositoryImpl::new(db.clone())), holds).await;
+
+        let held_tickets: i32 = held.iter().map(Reservation::quantity).sum();
+        assert!(
+            held_tickets <= capacity,
+            "{} tickets held out of {}",
+            held_tickets,
+            capacity
+        );
+        assert!(held.len() < BUYERS, "every buyer got tickets");
+        assert_eq!(
+            reserved_count(&db, offer.ticket_type_id()).await,
+            held_tickets
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+    async fn test_concurrent_holds_never_double_book_a_seat() {
+        let Some(db) = connect().await else {
+            return;
+        };
+        let offer = ticket_type(&db, BUYERS as i32 * 4).await;
+        let seats: Vec<Uuid> = (0..60).map(|_| Uuid::new_v4()).collect();
+
+        // Overlapping runs of one to four seats, half of them picked back to
+        // front, so buyers keep colliding on the same seats.
+        let holds = (0..BUYERS)
+            .map(|buyer| {
+                let count = buyer % 4 + 1;
+                let mut picked: Vec<Uuid> = (0..count)
+                    .map(|i| seats[(buyer * 7 + i) % seats.len()])
+                    .collect();
+                if buyer % 2 == 1 {
+                    picked.reverse();
+                }
+                Reservation::hold(
+                    offer.event_id(),
+                    offer.ticket_type_id(),
+                    Uuid::new_v4(),
+                    count as i32,
+                    Duration::minutes(10),
+                    Utc::now(),
+                )
+                .with_seats(picked)
+            })
+            .collect();
+        let held = hold_all(Arc::new(ReservationRepositoryImpl::new(db.clone())), holds).await;
+
+        let mut seat_holders = HashMap::new();
+        for reservation in &held {
+            for seat_id in reservation.seat_ids() {
+                let previous = seat_holders.insert(*seat_id, reservation.id());
+                assert!(previous.is_none(), "seat {} was sold twice", seat_id);
+            }
+        }
+
+        let booked: HashMap<Uuid, Uuid> = ReservedSeatEntity::find()
+            .filter(reserved_seat::Column::EventId.eq(offer.event_id()))
+            .all(&db)
+            .await
+            .expect("seats load")
+            .into_iter()
+            .map(|seat| (seat.seat_id, seat.reservation_id))
+            .collect();
+        assert_eq!(booked, seat_holders);
+
+        let held_tickets: i32 = held.iter().map(Reservation::quantity).sum();
+        assert_eq!(
+            reserved_count(&db, offer.ticket_type_id()).await,
+            held_tickets
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use uuid::Uuid;
+
+use crate::domain::{entities::Ticket, DomainError, DomainResult, TicketRepository};
+
+use super::super::models::ticket::{self, Entity as TicketEntity};
+
+pub struct TicketRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl TicketRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TicketRepository for TicketRepositoryImpl {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Ticket> {
+        TicketEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .map(Into::into)
+            .ok_or_else(|| DomainError::NotFound(format!("Ticket with id {} not found", id)))
+    }
+
+    async fn find_by_reservation(&self, reservation_id: Uuid) -> DomainResult<Vec<Ticket>> {
+        let tickets = TicketEntity::find()
+            .filter(ticket::Column::ReservationId.eq(reservation_id))
+            .order_by_asc(ticket::Column::SeatId)
+            .order_by_asc(ticket::Column::Id)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        Ok(tickets.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_holders_by_event(&self, event_id: Uuid) -> DomainResult<Vec<Uuid>> {
+        TicketEntity::find()
+            .select_only()
+            .column(ticket::Column::HolderId)
+            .distinct()
+            .filter(ticket::Column::EventId.eq(event_id))
+            .order_by_asc(ticket::Column::HolderId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))
+    }
+}
@@ -0,0 +1,206 @@
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use std::collections::HashSet;
+
+use crate::domain::{
+    entities::{TicketClaims, TicketKey, TicketPass, MAX_KEY_ID_LEN},
+    TicketSigner,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SigningKeyConfigError {
+    #[error("No ticket signing keys given")]
+    Empty,
+    #[error("Ticket signing key `{0}` isn't `<key id>:<base64 32-byte seed>`")]
+    Malformed(String),
+    #[error("Key ID `{0}` must be 1 to 32 ASCII letters, digits, `-`, `_` or `.`")]
+    InvalidKeyId(String),
+    #[error("Key ID `{0}` is given twice")]
+    DuplicateKeyId(String),
+}
+
+/// Signs ticket passes with Ed25519. The first key signs; the others only
+/// stay published so that passes they signed before a rotation keep
+/// verifying.
+pub struct Ed25519TicketSigner {
+    keys: Vec<(String, SigningKey)>,
+}
+
+impl Ed25519TicketSigner {
+    /// Reads keys from comma-separated `<key id>:<seed>` pairs, where the seed
+    /// is a base64-encoded 32-byte Ed25519 secret key, as generated by
+    /// `openssl rand -base64 32`.
+    pub fn from_config(config: &str) -> Result<Self, SigningKeyConfigError> {
+        let mut keys = Vec::new();
+        let mut ids = HashSet::new();
+        for entry in config
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let (id, seed) = entry
+                .split_once(':')
+                .ok_or_else(|| SigningKeyConfigError::Malformed(entry.to_string()))?;
+            let valid_id = !id.is_empty()
+                && id.len() <= MAX_KEY_ID_LEN
+                && id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+            if !valid_id {
+                return Err(SigningKeyConfigError::InvalidKeyId(id.to_string()));
+            }
+            if !ids.insert(id) {
+                return Err(SigningKeyConfigError::DuplicateKeyId(id.to_string()));
+            }
+            let seed: [u8; 32] = STANDARD
+                .decode(seed)
+                .ok()
+                .and_then(|seed| seed.try_into().ok())
+                .ok_or_else(|| SigningKeyConfigError::Malformed(id.to_string()))?;
+            keys.push((id.to_string(), SigningKey::from_bytes(&seed)));
+        }
+
+        if keys.is_empty() {
+            return Err(SigningKeyConfigError::Empty);
+        }
+        Ok(Self { keys })
+    }
+}
+
+impl TicketSigner for Ed25519TicketSigner {
+    fn sign(&self, claims: TicketClaims) -> TicketPass {
+        let (key_id, key) = &self.keys[0];
+        let mut bytes = claims.signed_bytes(key_id);
+        let signature = key.sign(&bytes);
+        bytes.extend_from_slice(&signature.to_bytes());
+
+        TicketPass {
+            claims,
+            key_id: key_id.clone(),
+            payload: URL_SAFE_NO_PAD.encode(bytes),
+        }
+    }
+
+    fn keys(&self) -> Vec<TicketKey> {
+        self.keys
+            .iter()
+            .enumerate()
+            .map(|(i, (id, key))| TicketKey {
+                id: id.clone(),
+                public_key: key.verifying_key().to_bytes(),
+                active: i == 0,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::ticket_pass::PASS_FORMAT_VERSION;
+    use chrono::{DateTime, TimeZone, Utc};
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+    use uuid::Uuid;
+
+    /// What a scanner does with a pass: find the key it names among the
+    /// published ones and check the signature.
+    fn verify(payload: &str, keys: &[TicketKey]) -> Option<TicketClaims> {
+        let bytes = URL_SAFE_NO_PAD.decode(payload).ok()?;
+        let (signed, signature) = bytes.split_at_checked(bytes.len().checked_sub(64)?)?;
+        let uuid = |at: usize| Uuid::from_slice(&signed[at..at + 16]).ok();
+        let time = |at: usize| {
+            let secs = i64::from_be_bytes(signed[at..at + 8].try_into().ok()?);
+            DateTime::<Utc>::from_timestamp(secs, 0)
+        };
+        if signed.len() < 66
+            || signed[0] != PASS_FORMAT_VERSION
+            || signed.len() != 66 + signed[65] as usize
+        {
+            return None;
+        }
+
+        let key_id = std::str::from_utf8(&signed[66..]).ok()?;
+        let key = keys.iter().find(|key| key.id == key_id)?;
+        VerifyingKey::from_bytes(&key.public_key)
+            .ok()?
+            .verify(signed, &Signature::from_slice(signature).ok()?)
+            .ok()?;
+
+        Some(TicketClaims {
+            ticket_id: uuid(1)?,
+            event_id: uuid(17)?,
+            holder_id: uuid(33)?,
+            valid_from: time(49)?,
+            valid_until: time(57)?,
+        })
+    }
+
+    fn claims() -> TicketClaims {
+        TicketClaims {
+            ticket_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            holder_id: Uuid::new_v4(),
+            valid_from: Utc.with_ymd_and_hms(2026, 12, 1, 18, 0, 0).unwrap(),
+            valid_until: Utc.with_ymd_and_hms(2026, 12, 1, 23, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_passes_verify_offline_across_key_rotations() {
+        let old_seed = STANDARD.encode([1u8; 32]);
+        let new_seed = STANDARD.encode([2u8; 32]);
+        let before = Ed25519TicketSigner::from_config(&format!("2026-04:{}", old_seed)).unwrap();
+        let after = Ed25519TicketSigner::from_config(&format!(
+            "2026-10:{}, 2026-04:{}",
+            new_seed, old_seed
+        ))
+        .unwrap();
+
+        let claims = claims();
+        let old_pass = before.sign(claims);
+        let new_pass = after.sign(claims);
+        assert_eq!(old_pass.key_id, "2026-04");
+        assert_eq!(new_pass.key_id, "2026-10");
+
+        // Passes handed out before the rotation still verify against the
+        // keys published after it.
+        let published = after.keys();
+        assert!(published[0].active && !published[1].active);
+        assert_eq!(verify(&old_pass.payload, &published), Some(claims));
+        assert_eq!(verify(&new_pass.payload, &published), Some(claims));
+        assert_eq!(verify(&new_pass.payload, &before.keys()), None);
+
+        // Changing any byte breaks the signature.
+        let mut bytes = URL_SAFE_NO_PAD.decode(&new_pass.payload).unwrap();
+        bytes[20] ^= 1;
+        assert_eq!(verify(&URL_SAFE_NO_PAD.encode(bytes), &published), None);
+    }
+
+    #[test]
+    fn test_from_config_rejects_bad_keys() {
+        let seed = STANDARD.encode([1u8; 32]);
+        assert!(matches!(
+            Ed25519TicketSigner::from_config(" "),
+            Err(SigningKeyConfigError::Empty)
+        ));
+        assert!(matches!(
+            Ed25519TicketSigner::from_config(&seed),
+            Err(SigningKeyConfigError::Malformed(_))
+        ));
+        assert!(matches!(
+            Ed25519TicketSigner::from_config("a:c2hvcnQ="),
+            Err(SigningKeyConfigError::Malformed(_))
+        ));
+        assert!(matches!(
+            Ed25519TicketSigner::from_config(&format!("bad id:{}", seed)),
+            Err(SigningKeyConfigError::InvalidKeyId(_))
+        ));
+        assert!(matches!(
+            Ed25519TicketSigner::from_config(&format!("a:{},a:{}", seed, seed)),
+            Err(SigningKeyConfigError::DuplicateKeyId(_))
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod ed25519_ticket_signer;
+
+pub use ed25519_ticket_signer::Ed25519TicketSigner;
@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How many tickets of a ticket type exist and where they are. Tickets move
+/// from available to reserved when someone reserves them, and from reserved
+/// to sold when the reservation is confirmed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Inventory {
+    ticket_type_id: Uuid,
+    event_id: Uuid,
+    capacity: i32,
+    reserved: i32,
+    sold: i32,
+    updated_at: DateTime<Utc>,
+}
+
+impl Inventory {
+    pub fn new(
+        ticket_type_id: Uuid,
+        event_id: Uuid,
+        capacity: i32,
+        reserved: i32,
+        sold: i32,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            ticket_type_id,
+            event_id,
+            capacity,
+            reserved,
+            sold,
+            updated_at,
+        }
+    }
+
+    pub fn ticket_type_id(&self) -> Uuid {
+        self.ticket_type_id
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn capacity(&self) -> i32 {
+        self.capacity
+    }
+
+    pub fn reserved(&self) -> i32 {
+        self.reserved
+    }
+
+    pub fn sold(&self) -> i32 {
+        self.sold
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    /// Tickets nobody has reserved or bought yet. Never negative, even when
+    /// the organizer has cut the capacity below what is already taken.
+    pub fn available(&self) -> i32 {
+        (self.capacity - self.reserved - self.sold).max(0)
+    }
+}
@@ -0,0 +1,13 @@
+pub mod inventory;
+pub mod reservation;
+pub mod seating_plan;
+pub mod ticket;
+pub mod ticket_offer;
+pub mod ticket_pass;
+
+pub use inventory::Inventory;
+pub use reservation::{ExtendRejection, HoldConflict, Reservation, ReservationStatus};
+pub use seating_plan::{PlanRow, PlanSeat, PlanSection, SeatRequest, SeatingPlan};
+pub use ticket::Ticket;
+pub use ticket_offer::{AdmissionWindow, OfferRejection, TicketOffer};
+pub use ticket_pass::{IssuedTicket, TicketClaims, TicketKey, TicketPass, MAX_KEY_ID_LEN};
@@ -0,0 +1,233 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReservationStatus {
+    /// The tickets are set aside for the holder but not bought yet.
+    Held,
+    /// The holder bought the tickets.
+    Confirmed,
+    /// The tickets went back into the inventory.
+    Released,
+    /// The hold lapsed before it was confirmed and the tickets went back
+    /// into the inventory.
+    Expired,
+}
+
+impl ReservationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReservationStatus::Held => "held",
+            ReservationStatus::Confirmed => "confirmed",
+            ReservationStatus::Released => "released",
+            ReservationStatus::Expired => "expired",
+        }
+    }
+
+    pub fn parse(status: &str) -> Option<Self> {
+        match status {
+            "held" => Some(ReservationStatus::Held),
+            "confirmed" => Some(ReservationStatus::Confirmed),
+            "released" => Some(ReservationStatus::Released),
+            "expired" => Some(ReservationStatus::Expired),
+            _ => None,
+        }
+    }
+}
+
+/// Why tickets couldn't be taken for a new reservation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HoldConflict {
+    /// Fewer tickets of the type are left than the reservation asks for.
+    SoldOut,
+    /// Another reservation has one of the seats.
+    SeatsTaken,
+}
+
+/// Why a hold can't be extended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtendRejection {
+    NotHeld,
+    Expired,
+    AlreadyExtended,
+}
+
+/// Tickets of one ticket type taken out of the inventory for a holder.
+///
+/// A held reservation only lasts until `expires_at`; after that its tickets
+/// are swept back into the inventory unless it was confirmed in time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reservation {
+    id: Uuid,
+    event_id: Uuid,
+    ticket_type_id: Uuid,
+    holder_id: Uuid,
+    quantity: i32,
+    /// Assigned seats, one per ticket; empty for general admission.
+    seat_ids: Vec<Uuid>,
+    status: ReservationStatus,
+    expires_at: DateTime<Utc>,
+    /// When the hold was extended; a hold can only be extended once.
+    extended_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Reservation {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        event_id: Uuid,
+        ticket_type_id: Uuid,
+        holder_id: Uuid,
+        quantity: i32,
+        status: ReservationStatus,
+        expires_at: DateTime<Utc>,
+        extended_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            event_id,
+            ticket_type_id,
+            holder_id,
+            quantity,
+            seat_ids: Vec::new(),
+            status,
+            expires_at,
+            extended_at,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn with_seats(mut self, seat_ids: Vec<Uuid>) -> Self {
+        self.seat_ids = seat_ids;
+        self
+    }
+
+    /// A new reservation holding `quantity` tickets for `holder_id` for
+    /// `ttl`.
+    pub fn hold(
+        event_id: Uuid,
+        ticket_type_id: Uuid,
+        holder_id: Uuid,
+        quantity: i32,
+        ttl: Duration,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self::new(
+            Uuid::new_v4(),
+            event_id,
+            ticket_type_id,
+            holder_id,
+            quantity,
+            ReservationStatus::Held,
+            now + ttl,
+            None,
+            now,
+            now,
+        )
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn ticket_type_id(&self) -> Uuid {
+        self.ticket_type_id
+    }
+
+    pub fn holder_id(&self) -> Uuid {
+        self.holder_id
+    }
+
+    pub fn quantity(&self) -> i32 {
+        self.quantity
+    }
+
+    pub fn seat_ids(&self) -> &[Uuid] {
+        &self.seat_ids
+    }
+
+    pub fn status(&self) -> ReservationStatus {
+        self.status
+    }
+
+    pub fn expires_at(&self) -> &DateTime<Utc> {
+        &self.expires_at
+    }
+
+    pub fn extended_at(&self) -> Option<&DateTime<Utc>> {
+        self.extended_at.as_ref()
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    /// Whether the hold ran out, whether or not it has been swept yet.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        match self.status {
+            ReservationStatus::Expired => true,
+            ReservationStatus::Held => self.expires_at <= now,
+            ReservationStatus::Confirmed | ReservationStatus::Released => false,
+        }
+    }
+
+    /// Pushes the hold's expiry back by `ttl`, once.
+    pub fn extend(&mut self, ttl: Duration, now: DateTime<Utc>) -> Result<(), ExtendRejection> {
+        if self.is_expired(now) {
+            return Err(ExtendRejection::Expired);
+        }
+        if self.status != ReservationStatus::Held {
+            return Err(ExtendRejection::NotHeld);
+        }
+        if self.extended_at.is_some() {
+            return Err(ExtendRejection::AlreadyExtended);
+        }
+
+        self.expires_at += ttl;
+        self.extended_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_hold_can_be_extended_once_before_it_expires() {
+        let now = Utc.with_ymd_and_hms(2026, 11, 1, 12, 0, 0).unwrap();
+        let ttl = Duration::minutes(10);
+        let mut reservation = Reservation::hold(Uuid::nil(), Uuid::nil(), Uuid::nil(), 2, ttl, now);
+        assert_eq!(*reservation.expires_at(), now + ttl);
+
+        let later = now + Duration::minutes(9);
+        assert_eq!(reservation.extend(ttl, later), Ok(()));
+        assert_eq!(*reservation.expires_at(), now + ttl * 2);
+        assert_eq!(reservation.extended_at(), Some(&later));
+        assert_eq!(
+            reservation.extend(ttl, later),
+            Err(ExtendRejection::AlreadyExtended)
+        );
+
+        let mut lapsed = Reservation::hold(Uuid::nil(), Uuid::nil(), Uuid::nil(), 2, ttl, now);
+        assert!(lapsed.is_expired(now + ttl));
+        assert_eq!(lapsed.extend(ttl, now + ttl), Err(ExtendRejection::Expired));
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::HashSet;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct PlanSeat {
+    id: Uuid,
+    accessible: bool,
+    obstructed: bool,
+}
+
+impl PlanSeat {
+    pub fn new(id: Uuid, accessible: bool, obstructed: bool) -> Self {
+        Self {
+            id,
+            accessible,
+            obstructed,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+/// Seats next to each other, left to right.
+#[derive(Debug, Clone)]
+pub struct PlanRow {
+    seats: Vec<PlanSeat>,
+}
+
+impl PlanRow {
+    pub fn new(seats: Vec<PlanSeat>) -> Self {
+        Self { seats }
+    }
+}
+
+/// A priced area of the venue; its rows are listed best first.
+#[derive(Debug, Clone)]
+pub struct PlanSection {
+    id: Uuid,
+    rows: Vec<PlanRow>,
+}
+
+impl PlanSection {
+    pub fn new(id: Uuid, rows: Vec<PlanRow>) -> Self {
+        Self { id, rows }
+    }
+}
+
+/// What a buyer asks of the best available seats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeatRequest {
+    pub quantity: usize,
+    /// How many of the seats must be accessible.
+    pub accessible: usize,
+    /// Section to pick from; any section when `None`.
+    pub section_id: Option<Uuid>,
+}
+
+/// How good a group of seats is, lower first: stranded single seats, spare
+/// accessible seats, obstructed seats, row rank and distance from the centre.
+type Score = (usize, usize, usize, usize, usize);
+
+/// The seating chart of an event's venue, as far as picking seats goes.
+#[derive(Debug, Clone)]
+pub struct SeatingPlan {
+    sections: Vec<PlanSection>,
+}
+
+impl SeatingPlan {
+    pub fn new(sections: Vec<PlanSection>) -> Self {
+        Self { sections }
+    }
+
+    /// Rows of `section_id`, or of every section, best first.
+    fn rows(&self, section_id: Option<Uuid>) -> impl Iterator<Item = &PlanRow> {
+        self.sections
+            .iter()
+            .filter(move |section| section_id.is_none_or(|id| section.id == id))
+            .flat_map(|section| &section.rows)
+    }
+
+    /// Whether every seat is in `section_id`, or anywhere in the plan when
+    /// `None`.
+    pub fn contains(&self, section_id: Option<Uuid>, seat_ids: &[Uuid]) -> bool {
+        let seats: HashSet<Uuid> = self
+            .rows(section_id)
+            .flat_map(|row| &row.seats)
+            .map(PlanSeat::id)
+            .collect();
+        seat_ids.iter().all(|seat_id| seats.contains(seat_id))
+    }
+
+    /// The best seats left for `request`, all next to each other in one row,
+    /// or `None` when no row has enough free seats together.
+    ///
+    /// Groups that would leave a single seat stranded between them and a
+    /// taken seat or the end of the row come last, and accessible seats are
+    /// kept for buyers who need them. Otherwise the unobstructed, better
+    /// ranked and more central seats win.
+    pub fn best_available(&self, request: SeatRequest, taken: &HashSet<Uuid>) -> Option<Vec<Uuid>> {
+        if request.quantity == 0 || request.accessible > request.quantity {
+            return None;
+        }
+
+        let mut best: Option<(Score, &[PlanSeat])> = None;
+        for (rank, row) in self.rows(request.section_id).enumerate() {
+            let seats = &row.seats;
+            let mut run_start = 0;
+            while run_start < seats.len() {
+                if taken.contains(&seats[run_start].id) {
+                    run_start += 1;
+                    continue;
+                }
+                let run_end = (run_start..seats.len())
+                    .find(|&i| taken.contains(&seats[i].id))
+                    .unwrap_or(seats.len());
+
+                if run_end - run_start < request.quantity {
+                    run_start = run_end;
+                    continue;
+                }
+
+                for start in run_start..=run_end - request.quantity {
+                    let end = start + request.quantity;
+                    let group = &seats[start..end];
+                    let accessible = group.iter().filter(|seat| seat.accessible).count();
+                    if accessible < request.accessible {
+                        continue;
+                    }
+
+                    let orphans = [start - run_start, run_end - end]
+                        .iter()
+                        .filter(|&&gap| gap == 1)
+                        .count();
+                    let obstructed = group.iter().filter(|seat| seat.obstructed).count();
+                    let off_center = (2 * start + request.quantity).abs_diff(seats.len());
+                    let score = (
+                        orphans,
+                        accessible - request.accessible,
+                        obstructed,
+                        rank,
+                        off_center,
+                    );
+                    if best
+                        .as_ref()
+                        .is_none_or(|(best_score, _)| score < *best_score)
+                    {
+                        best = Some((score, group));
+                    }
+                }
+                run_start = run_end;
+            }
+        }
+
+        best.map(|(_, group)| group.iter().map(PlanSeat::id).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A row of seats with generated IDs; `a` marks an accessible seat, `o`
+    /// an obstructed one and `.` a plain one.
+    fn row(layout: &str) -> (PlanRow, Vec<Uuid>) {
+        let seats: Vec<PlanSeat> = layout
+            .chars()
+            .map(|kind| PlanSeat::new(Uuid::new_v4(), kind == 'a', kind == 'o'))
+            .collect();
+        let ids = seats.iter().map(PlanSeat::id).collect();
+        (PlanRow::new(seats), ids)
+    }
+
+    fn request(quantity: usize, accessible: usize) -> SeatRequest {
+        SeatRequest {
+            quantity,
+            accessible,
+            section_id: None,
+        }
+    }
+
+    #[test]
+    fn test_best_available_keeps_groups_together_without_orphans() {
+        let (front, front_ids) = row("......");
+        let (back, back_ids) = row("......");
+        let plan = SeatingPlan::new(vec![PlanSection::new(Uuid::new_v4(), vec![front, back])]);
+
+        // The middle of the front row
+        assert_eq!(
+            plan.best_available(request(2, 0), &HashSet::new()),
+            Some(front_ids[2..4].to_vec())
+        );
+
+        // Three together in the front row would strand a seat on either side
+        // of them, so the back row is used instead.
+        let taken: HashSet<Uuid> = [front_ids[0], front_ids[5]].into();
+        assert_eq!(
+            plan.best_available(request(3, 0), &taken),
+            Some(back_ids[0..3].to_vec())
+        );
+        // Four fill the gap exactly.
+        assert_eq!(
+            plan.best_available(request(4, 0), &taken),
+            Some(front_ids[1..5].to_vec())
+        );
+
+        let mut split: HashSet<Uuid> = [front_ids[3], back_ids[3]].into();
+        assert_eq!(plan.best_available(request(4, 0), &split), None);
+        split.remove(&back_ids[3]);
+        assert_eq!(
+            plan.best_available(request(4, 0), &split),
+            Some(back_ids[0..4].to_vec())
+        );
+    }
+
+    #[test]
+    fn test_best_available_respects_accessibility_and_sections() {
+        let (front, front_ids) = row("aa....");
+        let (back, _) = row("oo..");
+        let balcony_id = Uuid::new_v4();
+        let (balcony, balcony_ids) = row("....");
+        let plan = SeatingPlan::new(vec![
+            PlanSection::new(Uuid::new_v4(), vec![front, back]),
+            PlanSection::new(balcony_id, vec![balcony]),
+        ]);
+
+        // Accessible seats are left for those who need them.
+        assert_eq!(
+            plan.best_available(request(4, 0), &HashSet::new()),
+            Some(front_ids[2..6].to_vec())
+        );
+        assert_eq!(
+            plan.best_available(request(2, 1), &HashSet::new()),
+            Some(front_ids[0..2].to_vec())
+        );
+        assert_eq!(plan.best_available(request(2, 3), &HashSet::new()), None);
+
+        // A clear view beats a better row; neither row strands a seat.
+        let front_taken: HashSet<Uuid> = front_ids.iter().copied().collect();
+        assert_eq!(
+            plan.best_available(request(4, 0), &front_taken),
+            Some(balcony_ids.clone())
+        );
+
+        let balcony_only = SeatRequest {
+            section_id: Some(balcony_id),
+            ..request(2, 0)
+        };
+        assert_eq!(
+            plan.best_available(balcony_only, &HashSet::new()),
+            Some(balcony_ids[0..2].to_vec())
+        );
+        assert!(plan.contains(Some(balcony_id), &balcony_ids));
+        assert!(!plan.contains(Some(balcony_id), &front_ids[..1]));
+        assert!(plan.contains(None, &front_ids[..1]));
+    }
+}
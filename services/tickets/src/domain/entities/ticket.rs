@@ -0,0 +1,85 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::Reservation;
+
+/// One admission, issued when the reservation it was bought in is
+/// confirmed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ticket {
+    id: Uuid,
+    reservation_id: Uuid,
+    event_id: Uuid,
+    ticket_type_id: Uuid,
+    holder_id: Uuid,
+    seat_id: Option<Uuid>,
+    issued_at: DateTime<Utc>,
+}
+
+impl Ticket {
+    pub fn new(
+        id: Uuid,
+        reservation_id: Uuid,
+        event_id: Uuid,
+        ticket_type_id: Uuid,
+        holder_id: Uuid,
+        seat_id: Option<Uuid>,
+        issued_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            reservation_id,
+            event_id,
+            ticket_type_id,
+            holder_id,
+            seat_id,
+            issued_at,
+        }
+    }
+
+    /// A ticket for every ticket in `reservation`, on its seats in order.
+    pub fn issue_all(reservation: &Reservation, now: DateTime<Utc>) -> Vec<Self> {
+        (0..reservation.quantity() as usize)
+            .map(|i| {
+                Self::new(
+                    Uuid::new_v4(),
+                    reservation.id(),
+                    reservation.event_id(),
+                    reservation.ticket_type_id(),
+                    reservation.holder_id(),
+                    reservation.seat_ids().get(i).copied(),
+                    now,
+                )
+            })
+            .collect()
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn reservation_id(&self) -> Uuid {
+        self.reservation_id
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn ticket_type_id(&self) -> Uuid {
+        self.ticket_type_id
+    }
+
+    pub fn holder_id(&self) -> Uuid {
+        self.holder_id
+    }
+
+    pub fn seat_id(&self) -> Option<Uuid> {
+        self.seat_id
+    }
+
+    pub fn issued_at(&self) -> &DateTime<Utc> {
+        &self.issued_at
+    }
+}
@@ -0,0 +1,179 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// Why an order can't be placed against a ticket offer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OfferRejection {
+    /// The event is cancelled, sales haven't opened, or the ticket type's
+    /// sales window isn't open.
+    NotOnSale,
+    BelowMinimum(i32),
+    AboveMaximum(i32),
+}
+
+/// How long an event without an end time is taken to last.
+const DEFAULT_EVENT_LENGTH: Duration = Duration::days(1);
+
+/// When ticket holders are let into the event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdmissionWindow {
+    pub opens_at: DateTime<Utc>,
+    pub closes_at: DateTime<Utc>,
+}
+
+impl AdmissionWindow {
+    /// From doors opening, or the start when no doors time is set, until the
+    /// end, or a day after the start when no end is set.
+    pub fn for_event(
+        starts_at: DateTime<Utc>,
+        doors_open_at: Option<DateTime<Utc>>,
+        ends_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            opens_at: doors_open_at.unwrap_or(starts_at).min(starts_at),
+            closes_at: ends_at.unwrap_or(starts_at + DEFAULT_EVENT_LENGTH),
+        }
+    }
+}
+
+/// A ticket type as the events service currently sells it. The events
+/// service owns these terms; the tickets service only keeps count.
+#[derive(Debug, Clone)]
+pub struct TicketOffer {
+    event_id: Uuid,
+    ticket_type_id: Uuid,
+    /// How many tickets of the type exist in total.
+    quantity: i32,
+    min_per_order: i32,
+    max_per_order: i32,
+    /// Whether the event is going ahead and has opened its sales.
+    sales_open: bool,
+    sales_start: Option<DateTime<Utc>>,
+    sales_end: Option<DateTime<Utc>>,
+    admission: AdmissionWindow,
+    /// Seating-chart section the ticket type sells seats in.
+    section_id: Option<Uuid>,
+}
+
+impl TicketOffer {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        event_id: Uuid,
+        ticket_type_id: Uuid,
+        quantity: i32,
+        min_per_order: i32,
+        max_per_order: i32,
+        sales_open: bool,
+        sales_start: Option<DateTime<Utc>>,
+        sales_end: Option<DateTime<Utc>>,
+        admission: AdmissionWindow,
+    ) -> Self {
+        Self {
+            event_id,
+            ticket_type_id,
+            quantity,
+            min_per_order,
+            max_per_order,
+            sales_open,
+            sales_start,
+            sales_end,
+            admission,
+            section_id: None,
+        }
+    }
+
+    pub fn with_section(mut self, section_id: Option<Uuid>) -> Self {
+        self.section_id = section_id;
+        self
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn ticket_type_id(&self) -> Uuid {
+        self.ticket_type_id
+    }
+
+    pub fn quantity(&self) -> i32 {
+        self.quantity
+    }
+
+    pub fn section_id(&self) -> Option<Uuid> {
+        self.section_id
+    }
+
+    pub fn admission(&self) -> AdmissionWindow {
+        self.admission
+    }
+
+    /// Checks that `quantity` tickets may be ordered at `now`.
+    pub fn check_order(&self, quantity: i32, now: DateTime<Utc>) -> Result<(), OfferRejection> {
+        let window_open = self.sales_start.is_none_or(|start| start <= now)
+            && self.sales_end.is_none_or(|end| now < end);
+        if !self.sales_open || !window_open {
+            return Err(OfferRejection::NotOnSale);
+        }
+        if quantity < self.min_per_order {
+            return Err(OfferRejection::BelowMinimum(self.min_per_order));
+        }
+        if quantity > self.max_per_order {
+            return Err(OfferRejection::AboveMaximum(self.max_per_order));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_check_order_enforces_sales_window_and_order_limits() {
+        let now = Utc.with_ymd_and_hms(2026, 11, 1, 12, 0, 0).unwrap();
+        let admission = AdmissionWindow::for_event(now + Duration::days(30), None, None);
+        let offer = |sales_open, start, end| {
+            TicketOffer::new(
+                Uuid::nil(),
+                Uuid::nil(),
+                100,
+                2,
+                6,
+                sales_open,
+                start,
+                end,
+                admission,
+            )
+        };
+
+        let open = offer(
+            true,
+            Some(now - Duration::days(1)),
+            Some(now + Duration::days(1)),
+        );
+        assert_eq!(open.check_order(2, now), Ok(()));
+        assert_eq!(open.check_order(6, now), Ok(()));
+        assert_eq!(
+            open.check_order(1, now),
+            Err(OfferRejection::BelowMinimum(2))
+        );
+        assert_eq!(
+            open.check_order(7, now),
+            Err(OfferRejection::AboveMaximum(6))
+        );
+
+        assert_eq!(
+            offer(false, None, None).check_order(2, now),
+            Err(OfferRejection::NotOnSale)
+        );
+        assert_eq!(
+            offer(true, Some(now + Duration::hours(1)), None).check_order(2, now),
+            Err(OfferRejection::NotOnSale)
+        );
+        assert_eq!(
+            offer(true, None, Some(now)).check_order(2, now),
+            Err(OfferRejection::NotOnSale)
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::Ticket;
+
+/// Version of the signed payload layout described on [`TicketClaims`].
+pub const PASS_FORMAT_VERSION: u8 = 1;
+
+/// Longest key ID that fits in a signed payload.
+pub const MAX_KEY_ID_LEN: usize = 32;
+
+/// What a ticket's QR code vouches for. Scanners verify it offline against
+/// the published signing keys. The payload in the code is these bytes,
+/// encoded as unpadded base64url:
+///
+/// | bytes | field                                              |
+/// |-------|----------------------------------------------------|
+/// | 1     | format version, `1`                                |
+/// | 16    | ticket ID                                          |
+/// | 16    | event ID                                           |
+/// | 16    | holder ID                                          |
+/// | 8     | valid from, Unix seconds, big-endian               |
+/// | 8     | valid until, Unix seconds, big-endian              |
+/// | 1     | length of the key ID                               |
+/// | n     | key ID, ASCII                                      |
+/// | 64    | Ed25519 signature over all of the bytes before it  |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TicketClaims {
+    pub ticket_id: Uuid,
+    pub event_id: Uuid,
+    pub holder_id: Uuid,
+    pub valid_from: DateTime<Utc>,
+    pub valid_until: DateTime<Utc>,
+}
+
+impl TicketClaims {
+    /// The bytes the key `key_id` signs, up to the signature.
+    pub fn signed_bytes(&self, key_id: &str) -> Vec<u8> {
+        debug_assert!(key_id.len() <= MAX_KEY_ID_LEN && key_id.is_ascii());
+        let mut bytes = Vec::with_capacity(1 + 16 * 3 + 8 * 2 + 1 + key_id.len());
+        bytes.push(PASS_FORMAT_VERSION);
+        bytes.extend_from_slice(self.ticket_id.as_bytes());
+        bytes.extend_from_slice(self.event_id.as_bytes());
+        bytes.extend_from_slice(self.holder_id.as_bytes());
+        bytes.extend_from_slice(&self.valid_from.timestamp().to_be_bytes());
+        bytes.extend_from_slice(&self.valid_until.timestamp().to_be_bytes());
+        bytes.push(key_id.len() as u8);
+        bytes.extend_from_slice(key_id.as_bytes());
+        bytes
+    }
+}
+
+/// A ticket's signed payload, ready to be put into a QR code.
+#[derive(Debug, Clone)]
+pub struct TicketPass {
+    pub claims: TicketClaims,
+    /// Key the payload is signed with.
+    pub key_id: String,
+    pub payload: String,
+}
+
+/// A key ticket passes may be signed with, as scanners need it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TicketKey {
+    pub id: String,
+    /// Ed25519 public key.
+    pub public_key: [u8; 32],
+    /// Whether new passes are signed with the key; the others only verify
+    /// passes handed out before a rotation.
+    pub active: bool,
+}
+
+/// A ticket together with its pass.
+#[derive(Debug, Clone)]
+pub struct IssuedTicket {
+    pub ticket: Ticket,
+    pub pass: TicketPass,
+}
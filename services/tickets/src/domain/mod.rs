@@ -0,0 +1,20 @@
+pub mod entities;
+pub mod errors;
+pub mod ports;
+pub mod repositories;
+pub mod use_cases;
+
+pub use entities::{Inventory, Reservation, TicketOffer};
+pub use errors::{DomainError, DomainResult};
+pub use ports::{EventCatalog, TicketSigner};
+pub use repositories::{InventoryRepository, ReservationRepository, TicketRepository};
+pub use use_cases::{
+    ConfirmReservationUseCase, ConfirmReservationUseCaseError, ExpireHoldsUseCase,
+    ExtendReservationUseCase, ExtendReservationUseCaseError, GetEventHoldersUseCase,
+    GetEventHoldersUseCaseError, GetEventSeatsUseCase, GetEventSeatsUseCaseError,
+    GetInventoryUseCase, GetInventoryUseCaseError, GetReservationUseCase,
+    GetReservationUseCaseError, GetTicketKeysUseCase, GetTicketUseCase, GetTicketUseCaseError,
+    GetTicketsUseCase, GetTicketsUseCaseError, ReleaseEventReservationsUseCase,
+    ReleaseEventReservationsUseCaseError, ReleaseReservationUseCase,
+    ReleaseReservationUseCaseError, ReserveTicketsUseCase, ReserveTicketsUseCaseError,
+};
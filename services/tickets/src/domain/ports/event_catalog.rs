@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{SeatingPlan, TicketOffer},
+    errors::DomainResult,
+};
+
+/// Read access to the ticket types the events service sells.
+#[async_trait]
+pub trait EventCatalog: Send + Sync {
+    /// The ticket type as it is currently offered, or `None` when the event
+    /// or ticket type doesn't exist or isn't public.
+    async fn find_offer(
+        &self,
+        event_id: Uuid,
+        ticket_type_id: Uuid,
+    ) -> DomainResult<Option<TicketOffer>>;
+    /// The seating chart of the event's venue, or `None` when the event
+    /// has no assigned seating.
+    async fn find_seating_plan(&self, event_id: Uuid) -> DomainResult<Option<SeatingPlan>>;
+    /// Whether the event is shown and not cancelled. Events are never
+    /// unpublished, so one that isn't shown was never on sale or is gone.
+    async fn is_scheduled(&self, event_id: Uuid) -> DomainResult<bool>;
+}
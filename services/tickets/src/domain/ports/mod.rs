@@ -0,0 +1,5 @@
+pub mod event_catalog;
+pub mod ticket_signer;
+
+pub use event_catalog::EventCatalog;
+pub use ticket_signer::TicketSigner;
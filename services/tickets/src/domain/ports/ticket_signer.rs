@@ -0,0 +1,10 @@
+use crate::domain::entities::{TicketClaims, TicketKey, TicketPass};
+
+/// Signs ticket passes so that scanners can check them without asking the
+/// tickets service.
+pub trait TicketSigner: Send + Sync {
+    /// Signs `claims` with the active key.
+    fn sign(&self, claims: TicketClaims) -> TicketPass;
+    /// Every key passes may be signed with, the active one first.
+    fn keys(&self) -> Vec<TicketKey>;
+}
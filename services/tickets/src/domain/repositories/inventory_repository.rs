@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{Inventory, TicketOffer},
+    errors::DomainResult,
+};
+
+#[async_trait]
+pub trait InventoryRepository: Send + Sync {
+    /// Creates the ticket type's inventory on first sight and brings its
+    /// capacity in line with the offer. The capacity never drops below the
+    /// tickets already reserved or sold, so a cut never takes tickets away
+    /// from anyone.
+    async fn sync(&self, offer: &TicketOffer) -> DomainResult<Inventory>;
+}
@@ -0,0 +1,7 @@
+pub mod inventory_repository;
+pub mod reservation_repository;
+pub mod ticket_repository;
+
+pub use inventory_repository::InventoryRepository;
+pub use reservation_repository::ReservationRepository;
+pub use ticket_repository::TicketRepository;
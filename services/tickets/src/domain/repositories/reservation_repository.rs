@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{HoldConflict, Reservation, ReservationStatus},
+    errors::DomainResult,
+};
+
+/// Reservations and the inventory counts they move. Every change to a
+/// reservation adjusts its ticket type's inventory in the same transaction.
+#[async_trait]
+pub trait ReservationRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Reservation>;
+    /// Stores a held reservation, moving its tickets from available to
+    /// reserved and taking its seats. Changes nothing and returns the
+    /// conflict when fewer tickets are available than it holds or one of its
+    /// seats is taken; concurrent calls can never take more tickets than
+    /// exist or the same seat twice.
+    async fn create(&self, reservation: &Reservation) -> DomainResult<Result<(), HoldConflict>>;
+    /// Moves a held reservation's tickets from reserved to sold and issues
+    /// them. Returns `None`, changing nothing, when the reservation isn't
+    /// held or its hold ran out by `now`.
+    async fn confirm(&self, id: Uuid, now: DateTime<Utc>) -> DomainResult<Option<Reservation>>;
+    /// Puts the tickets of a reservation that is still `status`, held or
+    /// confirmed, back into the inventory and frees its seats. Returns
+    /// `None`, changing nothing, when it is in any other status.
+    async fn release(
+        &self,
+        id: Uuid,
+        status: ReservationStatus,
+        now: DateTime<Utc>,
+    ) -> DomainResult<Option<Reservation>>;
+    /// Stores an extended hold. Returns `false`, changing nothing, when the
+    /// stored reservation is no longer a running hold that was never
+    /// extended.
+    async fn extend(&self, reservation: &Reservation) -> DomainResult<bool>;
+    /// Expires up to `limit` holds that ran out by `now`, putting their
+    /// tickets back into the inventory and freeing their seats, and returns
+    /// them. Holds another
+    /// caller is working on are skipped.
+    async fn expire_lapsed(&self, now: DateTime<Utc>, limit: u64)
+        -> DomainResult<Vec<Reservation>>;
+    /// IDs of the event's reservations that are in `status`.
+    async fn find_ids_by_event(
+        &self,
+        event_id: Uuid,
+        status: ReservationStatus,
+    ) -> DomainResult<Vec<Uuid>>;
+    /// The event's taken seats, with the status of the reservation holding
+    /// each.
+    async fn find_taken_seats(
+        &self,
+        event_id: Uuid,
+    ) -> DomainResult<HashMap<Uuid, ReservationStatus>>;
+}
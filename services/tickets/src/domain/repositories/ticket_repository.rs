@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{entities::Ticket, errors::DomainResult};
+
+/// Tickets issued for confirmed reservations. They are stored together with
+/// the confirmation, so only reading lives here.
+#[async_trait]
+pub trait TicketRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Ticket>;
+    /// The reservation's tickets, in seat order.
+    async fn find_by_reservation(&self, reservation_id: Uuid) -> DomainResult<Vec<Ticket>>;
+    /// Everyone who was issued a ticket for the event, released or not,
+    /// ordered by user ID.
+    async fn find_holders_by_event(&self, event_id: Uuid) -> DomainResult<Vec<Uuid>>;
+}
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, Reservation, ReservationRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfirmReservationParams {
+    pub id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfirmReservationUseCaseError {
+    #[error("Reservation not found")]
+    NotFound,
+    #[error("Only the holder can confirm a reservation")]
+    Forbidden,
+    #[error("Only held reservations can be confirmed")]
+    NotHeld,
+    #[error("The hold has expired and its tickets went back on sale")]
+    Expired,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct ConfirmReservationUseCase {
+    reservations: Arc<dyn ReservationRepository>,
+}
+
+impl ConfirmReservationUseCase {
+    pub fn new(reservations: Arc<dyn ReservationRepository>) -> Self {
+        Self { reservations }
+    }
+}
+
+#[async_trait]
+impl UseCase<ConfirmReservationParams, Result<Reservation, ConfirmReservationUseCaseError>>
+    for ConfirmReservationUseCase
+{
+    async fn execute(
+        &self,
+        data: ConfirmReservationParams,
+    ) -> Result<Reservation, ConfirmReservationUseCaseError> {
+        let map_err = |e| match e {
+            DomainError::NotFound(_) => ConfirmReservationUseCaseError::NotFound,
+            DomainError::DatabaseError(msg) => ConfirmReservationUseCaseError::DatabaseError(msg),
+        };
+
+        let reservation = self
+            .reservations
+            .find_by_id(data.id)
+            .await
+            .map_err(map_err)?;
+        if reservation.holder_id() != data.requested_by {
+            return Err(ConfirmReservationUseCaseError::Forbidden);
+        }
+        let now = Utc::now();
+        if reservation.is_expired(now) {
+            return Err(ConfirmReservationUseCaseError::Expired);
+        }
+
+        self.reservations
+            .confirm(data.id, now)
+            .await
+            .map_err(map_err)?
+            .ok_or(ConfirmReservationUseCaseError::NotHeld)
+    }
+}
@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared::UseCase;
+use std::sync::Arc;
+
+use crate::domain::ReservationRepository;
+
+/// Holds expired per batch.
+const BATCH_SIZE: u64 = 100;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExpireHoldsUseCaseError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Puts the tickets of every hold that ran out back into the inventory and
+/// returns how many holds expired.
+pub struct ExpireHoldsUseCase {
+    reservations: Arc<dyn ReservationRepository>,
+}
+
+impl ExpireHoldsUseCase {
+    pub fn new(reservations: Arc<dyn ReservationRepository>) -> Self {
+        Self { reservations }
+    }
+}
+
+#[async_trait]
+impl UseCase<DateTime<Utc>, Result<usize, ExpireHoldsUseCaseError>> for ExpireHoldsUseCase {
+    async fn execute(&self, now: DateTime<Utc>) -> Result<usize, ExpireHoldsUseCaseError> {
+        let mut total = 0;
+        loop {
+            let expired = self
+                .reservations
+                .expire_lapsed(now, BATCH_SIZE)
+                .await
+                .map_err(|e| ExpireHoldsUseCaseError::DatabaseError(e.to_string()))?;
+            total += expired.len();
+            if (expired.len() as u64) < BATCH_SIZE {
+                return Ok(total);
+            }
+        }
+    }
+}
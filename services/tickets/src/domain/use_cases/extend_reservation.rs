@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{entities::ExtendRejection, DomainError, Reservation, ReservationRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtendReservationParams {
+    pub id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExtendReservationUseCaseError {
+    #[error("Reservation not found")]
+    NotFound,
+    #[error("Only the holder can extend a reservation")]
+    Forbidden,
+    #[error("Only held reservations can be extended")]
+    NotHeld,
+    #[error("The hold has expired and its tickets went back on sale")]
+    Expired,
+    #[error("A hold can only be extended once")]
+    AlreadyExtended,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+impl From<ExtendRejection> for ExtendReservationUseCaseError {
+    fn from(rejection: ExtendRejection) -> Self {
+        match rejection {
+            ExtendRejection::NotHeld => ExtendReservationUseCaseError::NotHeld,
+            ExtendRejection::Expired => ExtendReservationUseCaseError::Expired,
+            ExtendRejection::AlreadyExtended => ExtendReservationUseCaseError::AlreadyExtended,
+        }
+    }
+}
+
+/// Gives the holder another hold period to finish checking out.
+pub struct ExtendReservationUseCase {
+    reservations: Arc<dyn ReservationRepository>,
+    hold_ttl: Duration,
+}
+
+impl ExtendReservationUseCase {
+    pub fn new(reservations: Arc<dyn ReservationRepository>, hold_ttl: Duration) -> Self {
+        Self {
+            reservations,
+            hold_ttl,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<ExtendReservationParams, Result<Reservation, ExtendReservationUseCaseError>>
+    for ExtendReservationUseCase
+{
+    async fn execute(
+        &self,
+        data: ExtendReservationParams,
+    ) -> Result<Reservation, ExtendReservationUseCaseError> {
+        let map_err = |e| match e {
+            DomainError::NotFound(_) => ExtendReservationUseCaseError::NotFound,
+            DomainError::DatabaseError(msg) => ExtendReservationUseCaseError::DatabaseError(msg),
+        };
+
+        let mut reservation = self
+            .reservations
+            .find_by_id(data.id)
+            .await
+            .map_err(map_err)?;
+        if reservation.holder_id() != data.requested_by {
+            return Err(ExtendReservationUseCaseError::Forbidden);
+        }
+
+        let now = Utc::now();
+        reservation.extend(self.hold_ttl, now)?;
+        if self
+            .reservations
+            .extend(&reservation)
+            .await
+            .map_err(map_err)?
+        {
+            return Ok(reservation);
+        }
+
+        // Someone else changed the reservation since it was read; report
+        // what it is now.
+        let mut current = self
+            .reservations
+            .find_by_id(data.id)
+            .await
+            .map_err(map_err)?;
+        current.extend(self.hold_ttl, now)?;
+        Err(ExtendReservationUseCaseError::AlreadyExtended)
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, EventCatalog, TicketRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEventHoldersParams {
+    pub event_id: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetEventHoldersUseCaseError {
+    #[error("The event is still scheduled")]
+    EventScheduled,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Everyone who was issued a ticket for an event that was called off, so
+/// they can be told.
+pub struct GetEventHoldersUseCase {
+    event_catalog: Arc<dyn EventCatalog>,
+    tickets: Arc<dyn TicketRepository>,
+}
+
+impl GetEventHoldersUseCase {
+    pub fn new(event_catalog: Arc<dyn EventCatalog>, tickets: Arc<dyn TicketRepository>) -> Self {
+        Self {
+            event_catalog,
+            tickets,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<GetEventHoldersParams, Result<Vec<Uuid>, GetEventHoldersUseCaseError>>
+    for GetEventHoldersUseCase
+{
+    async fn execute(
+        &self,
+        data: GetEventHoldersParams,
+    ) -> Result<Vec<Uuid>, GetEventHoldersUseCaseError> {
+        let db_err = |e: DomainError| GetEventHoldersUseCaseError::DatabaseError(e.to_string());
+
+        // Who bought tickets isn't public while the event is on.
+        if self
+            .event_catalog
+            .is_scheduled(data.event_id)
+            .await
+            .map_err(db_err)?
+        {
+            return Err(GetEventHoldersUseCaseError::EventScheduled);
+        }
+
+        self.tickets
+            .find_holders_by_event(data.event_id)
+            .await
+            .map_err(db_err)
+    }
+}
@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{entities::ReservationStatus, ReservationRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEventSeatsParams {
+    pub event_id: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetEventSeatsUseCaseError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// The seats of an event taken by reservations, with the status of the
+/// reservation holding each. Seats not listed are free.
+pub struct GetEventSeatsUseCase {
+    reservations: Arc<dyn ReservationRepository>,
+}
+
+impl GetEventSeatsUseCase {
+    pub fn new(reservations: Arc<dyn ReservationRepository>) -> Self {
+        Self { reservations }
+    }
+}
+
+#[async_trait]
+impl
+    UseCase<
+        GetEventSeatsParams,
+        Result<HashMap<Uuid, ReservationStatus>, GetEventSeatsUseCaseError>,
+    > for GetEventSeatsUseCase
+{
+    async fn execute(
+        &self,
+        data: GetEventSeatsParams,
+    ) -> Result<HashMap<Uuid, ReservationStatus>, GetEventSeatsUseCaseError> {
+        self.reservations
+            .find_taken_seats(data.event_id)
+            .await
+            .map_err(|e| GetEventSeatsUseCaseError::DatabaseError(e.to_string()))
+    }
+}
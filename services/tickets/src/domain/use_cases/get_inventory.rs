@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, EventCatalog, Inventory, InventoryRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetInventoryParams {
+    pub event_id: Uuid,
+    pub ticket_type_id: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetInventoryUseCaseError {
+    #[error("Ticket type not found")]
+    TicketTypeNotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetInventoryUseCase {
+    catalog: Arc<dyn EventCatalog>,
+    inventories: Arc<dyn InventoryRepository>,
+}
+
+impl GetInventoryUseCase {
+    pub fn new(catalog: Arc<dyn EventCatalog>, inventories: Arc<dyn InventoryRepository>) -> Self {
+        Self {
+            catalog,
+            inventories,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<GetInventoryParams, Result<Inventory, GetInventoryUseCaseError>>
+    for GetInventoryUseCase
+{
+    async fn execute(
+        &self,
+        data: GetInventoryParams,
+    ) -> Result<Inventory, GetInventoryUseCaseError> {
+        let offer = self
+            .catalog
+            .find_offer(data.event_id, data.ticket_type_id)
+            .await
+            .map_err(|e| GetInventoryUseCaseError::DatabaseError(e.to_string()))?
+            .ok_or(GetInventoryUseCaseError::TicketTypeNotFound)?;
+
+        // Syncing on read keeps the capacity current with edits made on the
+        // events side, even for ticket types nobody has reserved yet.
+        self.inventories.sync(&offer).await.map_err(|e| match e {
+            DomainError::NotFound(_) => GetInventoryUseCaseError::TicketTypeNotFound,
+            DomainError::DatabaseError(msg) => GetInventoryUseCaseError::DatabaseError(msg),
+        })
+    }
+}
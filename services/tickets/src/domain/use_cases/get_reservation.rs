@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, Reservation, ReservationRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetReservationParams {
+    pub id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetReservationUseCaseError {
+    #[error("Reservation not found")]
+    NotFound,
+    #[error("Only the holder can see a reservation")]
+    Forbidden,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetReservationUseCase {
+    reservations: Arc<dyn ReservationRepository>,
+}
+
+impl GetReservationUseCase {
+    pub fn new(reservations: Arc<dyn ReservationRepository>) -> Self {
+        Self { reservations }
+    }
+}
+
+#[async_trait]
+impl UseCase<GetReservationParams, Result<Reservation, GetReservationUseCaseError>>
+    for GetReservationUseCase
+{
+    async fn execute(
+        &self,
+        data: GetReservationParams,
+    ) -> Result<Reservation, GetReservationUseCaseError> {
+        let reservation = self
+            .reservations
+            .find_by_id(data.id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetReservationUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => GetReservationUseCaseError::DatabaseError(msg),
+            })?;
+
+        if reservation.holder_id() != data.requested_by {
+            return Err(GetReservationUseCaseError::Forbidden);
+        }
+
+        Ok(reservation)
+    }
+}
@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{IssuedTicket, ReservationStatus},
+    DomainError, EventCatalog, ReservationRepository, TicketRepository, TicketSigner,
+};
+
+use super::get_tickets::issue_passes;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTicketParams {
+    pub id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetTicketUseCaseError {
+    #[error("Ticket not found")]
+    NotFound,
+    #[error("Only the holder can see a ticket")]
+    Forbidden,
+    #[error("The ticket's reservation was released")]
+    Voided,
+    #[error("Event or ticket type not found")]
+    TicketTypeNotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// A single ticket with its signed pass.
+pub struct GetTicketUseCase {
+    catalog: Arc<dyn EventCatalog>,
+    reservations: Arc<dyn ReservationRepository>,
+    tickets: Arc<dyn TicketRepository>,
+    signer: Arc<dyn TicketSigner>,
+}
+
+impl GetTicketUseCase {
+    pub fn new(
+        catalog: Arc<dyn EventCatalog>,
+        reservations: Arc<dyn ReservationRepository>,
+        tickets: Arc<dyn TicketRepository>,
+        signer: Arc<dyn TicketSigner>,
+    ) -> Self {
+        Self {
+            catalog,
+            reservations,
+            tickets,
+            signer,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<GetTicketParams, Result<IssuedTicket, GetTicketUseCaseError>> for GetTicketUseCase {
+    async fn execute(&self, data: GetTicketParams) -> Result<IssuedTicket, GetTicketUseCaseError> {
+        let ticket = self
+            .tickets
+            .find_by_id(data.id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetTicketUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => GetTicketUseCaseError::DatabaseError(msg),
+            })?;
+
+        if ticket.holder_id() != data.requested_by {
+            return Err(GetTicketUseCaseError::Forbidden);
+        }
+
+        // Releasing a confirmed reservation refunds its tickets.
+        let reservation = self
+            .reservations
+            .find_by_id(ticket.reservation_id())
+            .await
+            .map_err(|e| GetTicketUseCaseError::DatabaseError(e.to_string()))?;
+        if reservation.status() != ReservationStatus::Confirmed {
+            return Err(GetTicketUseCaseError::Voided);
+        }
+
+        issue_passes(
+            self.catalog.as_ref(),
+            self.signer.as_ref(),
+            ticket.event_id(),
+            ticket.ticket_type_id(),
+            vec![ticket],
+        )
+        .await
+        .map_err(|e| GetTicketUseCaseError::DatabaseError(e.to_string()))?
+        .and_then(|issued| issued.into_iter().next())
+        .ok_or(GetTicketUseCaseError::TicketTypeNotFound)
+    }
+}
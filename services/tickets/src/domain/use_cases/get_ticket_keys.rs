@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use shared::UseCase;
+use std::sync::Arc;
+
+use crate::domain::{entities::TicketKey, TicketSigner};
+
+/// The public keys scanners verify ticket passes with.
+pub struct GetTicketKeysUseCase {
+    signer: Arc<dyn TicketSigner>,
+}
+
+impl GetTicketKeysUseCase {
+    pub fn new(signer: Arc<dyn TicketSigner>) -> Self {
+        Self { signer }
+    }
+}
+
+#[async_trait]
+impl UseCase<(), Vec<TicketKey>> for GetTicketKeysUseCase {
+    async fn execute(&self, _: ()) -> Vec<TicketKey> {
+        self.signer.keys()
+    }
+}
@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{IssuedTicket, ReservationStatus, Ticket, TicketClaims},
+    DomainError, EventCatalog, ReservationRepository, TicketRepository, TicketSigner,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTicketsParams {
+    pub reservation_id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetTicketsUseCaseError {
+    #[error("Reservation not found")]
+    NotFound,
+    #[error("Only the holder can see the tickets")]
+    Forbidden,
+    #[error("Tickets are only issued for confirmed reservations")]
+    NotConfirmed,
+    #[error("Event or ticket type not found")]
+    TicketTypeNotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// The tickets of a confirmed reservation with their signed passes.
+pub struct GetTicketsUseCase {
+    catalog: Arc<dyn EventCatalog>,
+    reservations: Arc<dyn ReservationRepository>,
+    tickets: Arc<dyn TicketRepository>,
+    signer: Arc<dyn TicketSigner>,
+}
+
+impl GetTicketsUseCase {
+    pub fn new(
+        catalog: Arc<dyn EventCatalog>,
+        reservations: Arc<dyn ReservationRepository>,
+        tickets: Arc<dyn TicketRepository>,
+        signer: Arc<dyn TicketSigner>,
+    ) -> Self {
+        Self {
+            catalog,
+            reservations,
+            tickets,
+            signer,
+        }
+    }
+}
+
+/// Signs passes for `tickets`, all of one ticket type, valid while its event
+/// admits holders. Passes are signed afresh every time, so they follow a
+/// rescheduled event and a key rotation.
+pub(super) async fn issue_passes(
+    catalog: &dyn EventCatalog,
+    signer: &dyn TicketSigner,
+    event_id: Uuid,
+    ticket_type_id: Uuid,
+    tickets: Vec<Ticket>,
+) -> Result<Option<Vec<IssuedTicket>>, DomainError> {
+    let Some(offer) = catalog.find_offer(event_id, ticket_type_id).await? else {
+        return Ok(None);
+    };
+    let admission = offer.admission();
+
+    Ok(Some(
+        tickets
+            .into_iter()
+            .map(|ticket| {
+                let pass = signer.sign(TicketClaims {
+                    ticket_id: ticket.id(),
+                    event_id: ticket.event_id(),
+                    holder_id: ticket.holder_id(),
+                    valid_from: admission.opens_at,
+                    valid_until: admission.closes_at,
+                });
+                IssuedTicket { ticket, pass }
+            })
+            .collect(),
+    ))
+}
+
+#[async_trait]
+impl UseCase<GetTicketsParams, Result<Vec<IssuedTicket>, GetTicketsUseCaseError>>
+    for GetTicketsUseCase
+{
+    async fn execute(
+        &self,
+        data: GetTicketsParams,
+    ) -> Result<Vec<IssuedTicket>, GetTicketsUseCaseError> {
+        let reservation = self
+            .reservations
+            .find_by_id(data.reservation_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => GetTicketsUseCaseError::NotFound,
+                DomainError::DatabaseError(msg) => GetTicketsUseCaseError::DatabaseError(msg),
+            })?;
+
+        if reservation.holder_id() != data.requested_by {
+            return Err(GetTicketsUseCaseError::Forbidden);
+        }
+        if reservation.status() != ReservationStatus::Confirmed {
+            return Err(GetTicketsUseCaseError::NotConfirmed);
+        }
+
+        let tickets = self
+            .tickets
+            .find_by_reservation(reservation.id())
+            .await
+            .map_err(|e| GetTicketsUseCaseError::DatabaseError(e.to_string()))?;
+
+        issue_passes(
+            self.catalog.as_ref(),
+            self.signer.as_ref(),
+            reservation.event_id(),
+            reservation.ticket_type_id(),
+            tickets,
+        )
+        .await
+        .map_err(|e| GetTicketsUseCaseError::DatabaseError(e.to_string()))?
+        .ok_or(GetTicketsUseCaseError::TicketTypeNotFound)
+    }
+}
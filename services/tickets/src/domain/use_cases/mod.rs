@@ -0,0 +1,42 @@
+pub mod confirm_reservation;
+pub mod expire_holds;
+pub mod extend_reservation;
+pub mod get_event_holders;
+pub mod get_event_seats;
+pub mod get_inventory;
+pub mod get_reservation;
+pub mod get_ticket;
+pub mod get_ticket_keys;
+pub mod get_tickets;
+pub mod release_event_reservations;
+pub mod release_reservation;
+pub mod reserve_tickets;
+
+pub use confirm_reservation::{
+    ConfirmReservationParams, ConfirmReservationUseCase, ConfirmReservationUseCaseError,
+};
+pub use expire_holds::ExpireHoldsUseCase;
+pub use extend_reservation::{
+    ExtendReservationParams, ExtendReservationUseCase, ExtendReservationUseCaseError,
+};
+pub use get_event_holders::{
+    GetEventHoldersParams, GetEventHoldersUseCase, GetEventHoldersUseCaseError,
+};
+pub use get_event_seats::{GetEventSeatsParams, GetEventSeatsUseCase, GetEventSeatsUseCaseError};
+pub use get_inventory::{GetInventoryParams, GetInventoryUseCase, GetInventoryUseCaseError};
+pub use get_reservation::{
+    GetReservationParams, GetReservationUseCase, GetReservationUseCaseError,
+};
+pub use get_ticket::{GetTicketParams, GetTicketUseCase, GetTicketUseCaseError};
+pub use get_ticket_keys::GetTicketKeysUseCase;
+pub use get_tickets::{GetTicketsParams, GetTicketsUseCase, GetTicketsUseCaseError};
+pub use release_event_reservations::{
+    ReleaseEventReservationsParams, ReleaseEventReservationsUseCase,
+    ReleaseEventReservationsUseCaseError,
+};
+pub use release_reservation::{
+    ReleaseReservationParams, ReleaseReservationUseCase, ReleaseReservationUseCaseError,
+};
+pub use reserve_tickets::{
+    ReserveTicketsParams, ReserveTicketsUseCase, ReserveTicketsUseCaseError, SeatSelection,
+};
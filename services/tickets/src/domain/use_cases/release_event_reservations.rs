@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ReservationStatus, DomainError, EventCatalog, ReservationRepository,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseEventReservationsParams {
+    pub event_id: Uuid,
+    /// Which reservations to release: held ones, so nothing more can be
+    /// bought, or confirmed ones, which voids their tickets.
+    pub status: ReservationStatus,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReleaseEventReservationsUseCaseError {
+    #[error("Only held or confirmed reservations can be released")]
+    InvalidStatus,
+    #[error("The event is still scheduled")]
+    EventScheduled,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Releases every reservation of an event that was called off, putting its
+/// tickets back and freeing its seats, and returns how many it released.
+/// Reservations released by an earlier call are skipped, so it can be
+/// repeated.
+pub struct ReleaseEventReservationsUseCase {
+    event_catalog: Arc<dyn EventCatalog>,
+    reservations: Arc<dyn ReservationRepository>,
+}
+
+impl ReleaseEventReservationsUseCase {
+    pub fn new(
+        event_catalog: Arc<dyn EventCatalog>,
+        reservations: Arc<dyn ReservationRepository>,
+    ) -> Self {
+        Self {
+            event_catalog,
+            reservations,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<ReleaseEventReservationsParams, Result<u32, ReleaseEventReservationsUseCaseError>>
+    for ReleaseEventReservationsUseCase
+{
+    async fn execute(
+        &self,
+        data: ReleaseEventReservationsParams,
+    ) -> Result<u32, ReleaseEventReservationsUseCaseError> {
+        let db_err =
+            |e: DomainError| ReleaseEventReservationsUseCaseError::DatabaseError(e.to_string());
+
+        if !matches!(
+            data.status,
+            ReservationStatus::Held | ReservationStatus::Confirmed
+        ) {
+            return Err(ReleaseEventReservationsUseCaseError::InvalidStatus);
+        }
+        // Anyone can call this, so the events service has to agree that the
+        // event is off first.
+        if self
+            .event_catalog
+            .is_scheduled(data.event_id)
+            .await
+            .map_err(db_err)?
+        {
+            return Err(ReleaseEventReservationsUseCaseError::EventScheduled);
+        }
+
+        let ids = self
+            .reservations
+            .find_ids_by_event(data.event_id, data.status)
+            .await
+            .map_err(db_err)?;
+        let now = Utc::now();
+        let mut released = 0;
+        for id in ids {
+            // `None` means it was confirmed, released or expired in the
+            // meantime; a confirmed one is picked up by the next call.
+            if self
+                .reservations
+                .release(id, data.status, now)
+                .await
+                .map_err(db_err)?
+                .is_some()
+            {
+                released += 1;
+            }
+        }
+        Ok(released)
+    }
+}
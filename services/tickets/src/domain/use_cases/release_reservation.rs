@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{entities::ReservationStatus, DomainError, Reservation, ReservationRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseReservationParams {
+    pub id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReleaseReservationUseCaseError {
+    #[error("Reservation not found")]
+    NotFound,
+    #[error("Only the holder can release a reservation")]
+    Forbidden,
+    #[error("The reservation is already released or expired")]
+    AlreadyReleased,
+    #[error("Confirmed reservations can only be voided by the organizer")]
+    Confirmed,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct ReleaseReservationUseCase {
+    reservations: Arc<dyn ReservationRepository>,
+}
+
+impl ReleaseReservationUseCase {
+    pub fn new(reservations: Arc<dyn ReservationRepository>) -> Self {
+        Self { reservations }
+    }
+}
+
+#[async_trait]
+impl UseCase<ReleaseReservationParams, Result<Reservation, ReleaseReservationUseCaseError>>
+    for ReleaseReservationUseCase
+{
+    async fn execute(
+        &self,
+        data: ReleaseReservationParams,
+    ) -> Result<Reservation, ReleaseReservationUseCaseError> {
+        let map_err = |e| match e {
+            DomainError::NotFound(_) => ReleaseReservationUseCaseError::NotFound,
+            DomainError::DatabaseError(msg) => ReleaseReservationUseCaseError::DatabaseError(msg),
+        };
+
+        let reservation = self
+            .reservations
+            .find_by_id(data.id)
+            .await
+            .map_err(map_err)?;
+        if reservation.holder_id() != data.requested_by {
+            return Err(ReleaseReservationUseCaseError::Forbidden);
+        }
+        // Sold tickets are only voided when the event is called off, through
+        // the events service.
+        if reservation.status() == ReservationStatus::Confirmed {
+            return Err(ReleaseReservationUseCaseError::Confirmed);
+        }
+
+        // `None` means it was released, expired or confirmed in the meantime.
+        self.reservations
+            .release(data.id, ReservationStatus::Held, Utc::now())
+            .await
+            .map_err(map_err)?
+            .ok_or(ReleaseReservationUseCaseError::AlreadyReleased)
+    }
+}
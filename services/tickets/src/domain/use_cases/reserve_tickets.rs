@@ -0,0 +1,269 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{HoldConflict, OfferRejection, SeatRequest, SeatingPlan},
+    DomainError, EventCatalog, InventoryRepository, Reservation, ReservationRepository,
+};
+
+/// How many times the best available seats are picked again when another
+/// buyer takes one of them first.
+const BEST_AVAILABLE_ATTEMPTS: usize = 3;
+
+/// How the seats of a reservation are chosen.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SeatSelection {
+    /// No seats for general admission; the best available ones for ticket
+    /// types sold by section.
+    Unspecified,
+    /// Seats the buyer picked, one per ticket.
+    Picked(Vec<Uuid>),
+    /// The best seats left next to each other, `accessible` of them
+    /// accessible.
+    BestAvailable { accessible: usize },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReserveTicketsParams {
+    pub event_id: Uuid,
+    pub ticket_type_id: Uuid,
+    pub quantity: i32,
+    pub seats: SeatSelection,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReserveTicketsUseCaseError {
+    #[error("Ticket type not found")]
+    TicketTypeNotFound,
+    #[error("Tickets of this type are not on sale")]
+    NotOnSale,
+    #[error("At least {0} tickets must be reserved at once")]
+    BelowMinimum(i32),
+    #[error("At most {0} tickets can be reserved at once")]
+    AboveMaximum(i32),
+    #[error("Not enough tickets left")]
+    SoldOut,
+    #[error("Some of the seats aren't at this event's venue or in the ticket type's section")]
+    UnknownSeats,
+    #[error("Some of the seats are already taken")]
+    SeatsTaken,
+    #[error("This event has no assigned seating")]
+    NoSeating,
+    #[error("Seats can only be chosen for ticket types sold by seating-chart section")]
+    NotSoldBySection,
+    #[error("Not enough seats left together")]
+    NotEnoughSeatsTogether,
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct ReserveTicketsUseCase {
+    catalog: Arc<dyn EventCatalog>,
+    inventories: Arc<dyn InventoryRepository>,
+    reservations: Arc<dyn ReservationRepository>,
+    /// How long the tickets stay held before they go back on sale.
+    hold_ttl: Duration,
+}
+
+impl ReserveTicketsUseCase {
+    pub fn new(
+        catalog: Arc<dyn EventCatalog>,
+        inventories: Arc<dyn InventoryRepository>,
+        reservations: Arc<dyn ReservationRepository>,
+        hold_ttl: Duration,
+    ) -> Self {
+        Self {
+            catalog,
+            inventories,
+            reservations,
+            hold_ttl,
+        }
+    }
+
+    async fn find_seating_plan(
+        &self,
+        event_id: Uuid,
+    ) -> Result<Option<SeatingPlan>, ReserveTicketsUseCaseError> {
+        self.catalog
+            .find_seating_plan(event_id)
+            .await
+            .map_err(|e| ReserveTicketsUseCaseError::DatabaseError(e.to_string()))
+    }
+
+    /// Holds the tickets on `seat_ids`. The inventory count checked before
+    /// may be stale by now; the repository only takes the tickets and seats
+    /// if they are still there.
+    async fn hold(
+        &self,
+        data: &ReserveTicketsParams,
+        seat_ids: Vec<Uuid>,
+    ) -> Result<Result<Reservation, HoldConflict>, ReserveTicketsUseCaseError> {
+        let reservation = Reservation::hold(
+            data.event_id,
+            data.ticket_type_id,
+            data.requested_by,
+            data.quantity,
+            self.hold_ttl,
+            Utc::now(),
+        )
+        .with_seats(seat_ids);
+        let created = self
+            .reservations
+            .create(&reservation)
+            .await
+            .map_err(|e| ReserveTicketsUseCaseError::DatabaseError(e.to_string()))?;
+        Ok(created.map(|()| reservation))
+    }
+}
+
+/// Checks the seat selection against the event's seating plan and tells how
+/// many accessible seats to pick among the best available ones, or `None`
+/// when no seats are to be picked. A ticket type without a section at a
+/// seated venue is general admission; choosing seats for it would let it
+/// take seats in any section.
+fn check_seats(
+    section_id: Option<Uuid>,
+    seats: &SeatSelection,
+    plan: Option<&SeatingPlan>,
+) -> Result<Option<usize>, ReserveTicketsUseCaseError> {
+    if section_id.is_none() && plan.is_some() && *seats != SeatSelection::Unspecified {
+        return Err(ReserveTicketsUseCaseError::NotSoldBySection);
+    }
+    match seats {
+        SeatSelection::Picked(seat_ids) => {
+            if plan.is_some_and(|plan| plan.contains(section_id, seat_ids)) {
+                Ok(None)
+            } else {
+                Err(ReserveTicketsUseCaseError::UnknownSeats)
+            }
+        }
+        SeatSelection::BestAvailable { accessible } => Ok(Some(*accessible)),
+        SeatSelection::Unspecified if section_id.is_some() => Ok(Some(0)),
+        SeatSelection::Unspecified => Ok(None),
+    }
+}
+
+fn conflict_error(conflict: HoldConflict) -> ReserveTicketsUseCaseError {
+    match conflict {
+        HoldConflict::SoldOut => ReserveTicketsUseCaseError::SoldOut,
+        HoldConflict::SeatsTaken => ReserveTicketsUseCaseError::SeatsTaken,
+    }
+}
+
+#[async_trait]
+impl UseCase<ReserveTicketsParams, Result<Reservation, ReserveTicketsUseCaseError>>
+    for ReserveTicketsUseCase
+{
+    async fn execute(
+        &self,
+        data: ReserveTicketsParams,
+    ) -> Result<Reservation, ReserveTicketsUseCaseError> {
+        let now = Utc::now();
+        let offer = self
+            .catalog
+            .find_offer(data.event_id, data.ticket_type_id)
+            .await
+            .map_err(|e| ReserveTicketsUseCaseError::DatabaseError(e.to_string()))?
+            .ok_or(ReserveTicketsUseCaseError::TicketTypeNotFound)?;
+
+        offer
+            .check_order(data.quantity, now)
+            .map_err(|rejection| match rejection {
+                OfferRejection::NotOnSale => ReserveTicketsUseCaseError::NotOnSale,
+                OfferRejection::BelowMinimum(min) => ReserveTicketsUseCaseError::BelowMinimum(min),
+                OfferRejection::AboveMaximum(max) => ReserveTicketsUseCaseError::AboveMaximum(max),
+            })?;
+
+        let plan = match (&data.seats, offer.section_id()) {
+            (SeatSelection::Unspecified, None) => None,
+            _ => self.find_seating_plan(data.event_id).await?,
+        };
+        let accessible = check_seats(offer.section_id(), &data.seats, plan.as_ref())?;
+
+        let inventory = self.inventories.sync(&offer).await.map_err(|e| match e {
+            DomainError::NotFound(_) => ReserveTicketsUseCaseError::TicketTypeNotFound,
+            DomainError::DatabaseError(msg) => ReserveTicketsUseCaseError::DatabaseError(msg),
+        })?;
+        if inventory.available() < data.quantity {
+            return Err(ReserveTicketsUseCaseError::SoldOut);
+        }
+
+        let Some(accessible) = accessible else {
+            let seat_ids = match &data.seats {
+                SeatSelection::Picked(seat_ids) => seat_ids.clone(),
+                _ => Vec::new(),
+            };
+            return self.hold(&data, seat_ids).await?.map_err(conflict_error);
+        };
+
+        let plan = plan.ok_or(ReserveTicketsUseCaseError::NoSeating)?;
+        let request = SeatRequest {
+            quantity: data.quantity as usize,
+            accessible,
+            section_id: offer.section_id(),
+        };
+        // Another buyer may take one of the picked seats before they are
+        // held; the next best ones are picked then.
+        for _ in 0..BEST_AVAILABLE_ATTEMPTS {
+            let taken: HashSet<Uuid> = self
+                .reservations
+                .find_taken_seats(data.event_id)
+                .await
+                .map_err(|e| ReserveTicketsUseCaseError::DatabaseError(e.to_string()))?
+                .into_keys()
+                .collect();
+            let seat_ids = plan
+                .best_available(request, &taken)
+                .ok_or(ReserveTicketsUseCaseError::NotEnoughSeatsTogether)?;
+            match self.hold(&data, seat_ids).await? {
+                Ok(reservation) => return Ok(reservation),
+                Err(HoldConflict::SeatsTaken) => continue,
+                Err(conflict) => return Err(conflict_error(conflict)),
+            }
+        }
+        Err(ReserveTicketsUseCaseError::SeatsTaken)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{PlanRow, PlanSeat, PlanSection};
+
+    #[test]
+    fn test_general_admission_cannot_pick_seats_at_a_seated_venue() {
+        let premium_seat = Uuid::new_v4();
+        let plan = SeatingPlan::new(vec![PlanSection::new(
+            Uuid::new_v4(),
+            vec![PlanRow::new(vec![PlanSeat::new(
+                premium_seat,
+                false,
+                false,
+            )])],
+        )]);
+
+        let picked = SeatSelection::Picked(vec![premium_seat]);
+        assert!(matches!(
+            check_seats(None, &picked, Some(&plan)),
+            Err(ReserveTicketsUseCaseError::NotSoldBySection)
+        ));
+        assert!(matches!(
+            check_seats(
+                None,
+                &SeatSelection::BestAvailable { accessible: 0 },
+                Some(&plan)
+            ),
+            Err(ReserveTicketsUseCaseError::NotSoldBySection)
+        ));
+        assert!(matches!(
+            check_seats(None, &SeatSelection::Unspecified, Some(&plan)),
+            Ok(None)
+        ));
+    }
+}
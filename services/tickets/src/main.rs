@@ -0,0 +1,291 @@
+mod adapters;
+mod domain;
+
+use actix_web::{middleware::Logger, web, App, HttpServer};
+use adapters::inbound::http::configure_routes;
+use adapters::inbound::sweeper::HoldSweeper;
+use adapters::outbound::clients::{HttpEventCatalog, LocalEventCatalog};
+use adapters::outbound::persistence::{
+    InventoryRepositoryImpl, ReservationRepositoryImpl, TicketRepositoryImpl,
+};
+use adapters::outbound::signing::Ed25519TicketSigner;
+use domain::{
+    ConfirmReservationUseCase, EventCatalog, ExpireHoldsUseCase, ExtendReservationUseCase,
+    GetEventHoldersUseCase, GetEventSeatsUseCase, GetInventoryUseCase, GetReservationUseCase,
+    GetTicketKeysUseCase, GetTicketUseCase, GetTicketsUseCase, ReleaseEventReservationsUseCase,
+    ReleaseReservationUseCase, ReserveTicketsUseCase, TicketSigner,
+};
+use sea_orm::{Database, DatabaseConnection};
+use sea_orm_migration::prelude::*;
+use shared::{purge_expired_keys, Idempotency, PostgresIdempotencyStore, ServiceToken};
+use std::sync::Arc;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use adapters::inbound::http::dtos::{
+    BestAvailableRequest, ConfirmReservationError, EventHoldersResponse, EventSeatsResponse,
+    ExtendReservationError, GetEventHoldersError, GetEventSeatsError, GetInventoryError,
+    GetReservationError, GetTicketError, GetTicketsError, InventoryResponse, ReleasableState,
+    ReleaseEventReservationsError, ReleaseEventReservationsRequest,
+    ReleaseEventReservationsResponse, ReleaseReservationError, ReservationResponse,
+    ReservationState, ReserveTicketsError, ReserveTicketsRequest, TakenSeatResponse,
+    TakenSeatState, TicketKeyResponse, TicketKeysResponse, TicketResponse,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        adapters::inbound::http::handlers::reserve_tickets::reserve_tickets,
+        adapters::inbound::http::handlers::get_reservation::get_reservation,
+        adapters::inbound::http::handlers::confirm_reservation::confirm_reservation,
+        adapters::inbound::http::handlers::release_reservation::release_reservation,
+        adapters::inbound::http::handlers::extend_reservation::extend_reservation,
+        adapters::inbound::http::handlers::release_event_reservations::release_event_reservations,
+        adapters::inbound::http::handlers::get_inventory::get_inventory,
+        adapters::inbound::http::handlers::get_event_seats::get_event_seats,
+        adapters::inbound::http::handlers::get_tickets::get_tickets,
+        adapters::inbound::http::handlers::get_ticket::get_ticket,
+        adapters::inbound::http::handlers::get_ticket::get_ticket_qr_png,
+        adapters::inbound::http::handlers::get_ticket::get_ticket_qr_svg,
+        adapters::inbound::http::handlers::get_ticket_keys::get_ticket_keys,
+        adapters::inbound::http::handlers::get_event_holders::get_event_holders,
+    ),
+    components(
+        schemas(
+            ReserveTicketsRequest,
+            BestAvailableRequest,
+            ReserveTicketsError,
+            ReservationResponse,
+            ReservationState,
+            GetReservationError,
+            ConfirmReservationError,
+            ReleaseReservationError,
+            ExtendReservationError,
+            ReleaseEventReservationsRequest,
+            ReleasableState,
+            ReleaseEventReservationsResponse,
+            ReleaseEventReservationsError,
+            InventoryResponse,
+            GetInventoryError,
+            EventSeatsResponse,
+            TakenSeatResponse,
+            TakenSeatState,
+            GetEventSeatsError,
+            TicketResponse,
+            GetTicketsError,
+            GetTicketError,
+            TicketKeysResponse,
+            TicketKeyResponse,
+            EventHoldersResponse,
+            GetEventHoldersError
+        )
+    ),
+    tags(
+        (name = "reservations", description = "Holding, confirming and releasing tickets"),
+        (name = "inventory", description = "Ticket availability per ticket type and taken seats"),
+        (name = "tickets", description = "Issued tickets and their signed QR code passes")
+    ),
+    info(
+        title = "Tickets Service API",
+        version = "0.1.0",
+        description = "RESTful API for ticket inventory and reservations using hexagonal architecture"
+    )
+)]
+struct ApiDoc;
+
+async fn create_database_connection() -> Result<DatabaseConnection, Box<dyn std::error::Error>> {
+    dotenvy::dotenv().ok();
+    let database_url = std::env::var("DATABASE_URL")?;
+    let db = Database::connect(&database_url).await?;
+    Ok(db)
+}
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    env_logger::init();
+
+    println!("🔌 Connecting to database...");
+    let db = match create_database_connection().await {
+        Ok(db) => {
+            println!("✅ Database connection established");
+            db
+        }
+        Err(e) => {
+            eprintln!("❌ Failed to connect to database: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    println!("🔄 Running database migrations...");
+    if let Err(e) = migration::Migrator::up(&db, None).await {
+        eprintln!("❌ Failed to run migrations: {}", e);
+        std::process::exit(1);
+    }
+    println!("✅ Database migrations completed");
+
+    let inventory_repository = Arc::new(InventoryRepositoryImpl::new(db.clone()));
+    let idempotency_store = Arc::new(PostgresIdempotencyStore::new(db.clone()));
+    tokio::spawn(purge_expired_keys(idempotency_store.clone()));
+    let ticket_repository = Arc::new(TicketRepositoryImpl::new(db.clone()));
+    let reservation_repository = Arc::new(ReservationRepositoryImpl::new(db));
+    // The stub puts every ID on sale, so it has to be asked for by name
+    let event_catalog: Arc<dyn EventCatalog> = match std::env::var("EVENTS_SERVICE_URL") {
+        Ok(url) => Arc::new(HttpEventCatalog::new(&url)),
+        Err(_) if std::env::var("EVENT_CATALOG").as_deref() == Ok("local") => {
+            println!("⚠️  EVENT_CATALOG=local; using the local events stub, for development only");
+            Arc::new(LocalEventCatalog)
+        }
+        Err(_) => {
+            eprintln!("❌ EVENTS_SERVICE_URL must be set, or EVENT_CATALOG=local for development");
+            std::process::exit(1);
+        }
+    };
+
+    let ticket_signer: Arc<dyn TicketSigner> = match std::env::var("TICKET_SIGNING_KEYS") {
+        Ok(keys) => match Ed25519TicketSigner::from_config(&keys) {
+            Ok(signer) => Arc::new(signer),
+            Err(e) => {
+                eprintln!("❌ Invalid TICKET_SIGNING_KEYS: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Err(_) => {
+            eprintln!("❌ TICKET_SIGNING_KEYS must be set to the keys that sign ticket passes");
+            std::process::exit(1);
+        }
+    };
+
+    // Lets the events service void reservations and list holders of
+    // cancelled events
+    let service_token = match std::env::var("SERVICE_TOKEN") {
+        Ok(token) if !token.trim().is_empty() => ServiceToken::new(token.trim()),
+        _ => {
+            eprintln!("❌ SERVICE_TOKEN must be set to the secret shared with the events service");
+            std::process::exit(1);
+        }
+    };
+
+    let hold_ttl_secs = std::env::var("HOLD_TTL_SECS")
+        .ok()
+        .and_then(|secs| secs.parse().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(600);
+    let hold_ttl = chrono::Duration::seconds(hold_ttl_secs);
+
+    let reserve_tickets_use_case = web::Data::new(ReserveTicketsUseCase::new(
+        event_catalog.clone(),
+        inventory_repository.clone(),
+        reservation_repository.clone(),
+        hold_ttl,
+    ));
+    let get_reservation_use_case =
+        web::Data::new(GetReservationUseCase::new(reservation_repository.clone()));
+    let confirm_reservation_use_case = web::Data::new(ConfirmReservationUseCase::new(
+        reservation_repository.clone(),
+    ));
+    let release_reservation_use_case = web::Data::new(ReleaseReservationUseCase::new(
+        reservation_repository.clone(),
+    ));
+    let extend_reservation_use_case = web::Data::new(ExtendReservationUseCase::new(
+        reservation_repository.clone(),
+        hold_ttl,
+    ));
+    let get_inventory_use_case = web::Data::new(GetInventoryUseCase::new(
+        event_catalog.clone(),
+        inventory_repository.clone(),
+    ));
+    let get_event_seats_use_case =
+        web::Data::new(GetEventSeatsUseCase::new(reservation_repository.clone()));
+    let get_tickets_use_case = web::Data::new(GetTicketsUseCase::new(
+        event_catalog.clone(),
+        reservation_repository.clone(),
+        ticket_repository.clone(),
+        ticket_signer.clone(),
+    ));
+    let get_ticket_use_case = web::Data::new(GetTicketUseCase::new(
+        event_catalog.clone(),
+        reservation_repository.clone(),
+        ticket_repository.clone(),
+        ticket_signer.clone(),
+    ));
+    let get_ticket_keys_use_case = web::Data::new(GetTicketKeysUseCase::new(ticket_signer));
+    let release_event_reservations_use_case = web::Data::new(ReleaseEventReservationsUseCase::new(
+        event_catalog.clone(),
+        reservation_repository.clone(),
+    ));
+    let get_event_holders_use_case = web::Data::new(GetEventHoldersUseCase::new(
+        event_catalog.clone(),
+        ticket_repository.clone(),
+    ));
+
+    let sweep_interval = std::env::var("HOLD_SWEEP_INTERVAL_SECS")
+        .ok()
+        .and_then(|secs| secs.parse().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(15);
+    let sweeper = HoldSweeper::new(
+        Arc::new(ExpireHoldsUseCase::new(reservation_repository.clone())),
+        std::time::Duration::from_secs(sweep_interval),
+    );
+    tokio::spawn(sweeper.run());
+    println!(
+        "⏰ Holds last {}s; expired holds are swept every {}s",
+        hold_ttl_secs, sweep_interval
+    );
+
+    let host = std::env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_string());
+    let port = std::env::var("PORT").unwrap_or_else(|_| "8082".to_string());
+    let bind_address = format!("{}:{}", host, port);
+
+    println!("🚀 Server starting on http://{}", bind_address);
+    println!(
+        "📚 Swagger UI available at http://{}/swagger-ui/",
+        bind_address
+    );
+
+    let openapi = ApiDoc::openapi();
+
+    HttpServer::new(move || {
+        App::new()
+            .wrap(Idempotency::new(idempotency_store.clone()))
+            .wrap(Logger::default())
+            .app_data(service_token.clone())
+            .app_data(reserve_tickets_use_case.clone())
+            .app_data(get_reservation_use_case.clone())
+            .app_data(confirm_reservation_use_case.clone())
+            .app_data(release_reservation_use_case.clone())
+            .app_data(extend_reservation_use_case.clone())
+            .app_data(get_inventory_use_case.clone())
+            .app_data(get_event_seats_use_case.clone())
+            .app_data(get_tickets_use_case.clone())
+            .app_data(get_ticket_use_case.clone())
+            .app_data(get_ticket_keys_use_case.clone())
+            .app_data(release_event_reservations_use_case.clone())
+            .app_data(get_event_holders_use_case.clone())
+            .service(
+                web::scope("/tickets")
+                    .configure(configure_routes)
+                    .route(
+                        "/api-docs/openapi.json",
+                        web::get().to({
+                            let openapi = openapi.clone();
+                            move || {
+                                let openapi = openapi.clone();
+                                async move {
+                                    actix_web::HttpResponse::Ok()
+                                        .content_type("application/json")
+                                        .json(openapi)
+                                }
+                            }
+                        }),
+                    )
+                    .service(
+                        SwaggerUi::new("/swagger-ui/{_:.*}")
+                            .url("/tickets/api-docs/openapi.json", openapi.clone()),
+                    ),
+            )
+    })
+    .bind(&bind_address)?
+    .run()
+    .await
+}
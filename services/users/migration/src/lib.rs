@@ -1,12 +1,20 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20251004_041126_create_users_table;
+mod m20261019_140000_create_organizations_tables;
+mod m20261019_210000_add_user_version;
+mod m20261020_060000_create_idempotency_keys_table;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20251004_041126_create_users_table::Migration)]
+        vec![
+            Box::new(m20251004_041126_create_users_table::Migration),
+            Box::new(m20261019_140000_create_organizations_tables::Migration),
+            Box::new(m20261019_210000_add_user_version::Migration),
+            Box::new(m20261020_060000_create_idempotency_keys_table::Migration),
+        ]
     }
 }
@@ -1,5 +1,12 @@
 pub mod requests;
 pub mod responses;
 
-pub use requests::CreateUserRequest;
-pub use responses::{CreateUserError, CreateUserResponse, GetUserError, GetUserResponse};
+pub use requests::{
+    CreateOrganizationRequest, CreateUserRequest, SetOrganizationMemberRequest, UpdateUserRequest,
+};
+pub use responses::{
+    CreateOrganizationError, CreateUserError, CreateUserResponse, GetOrganizationError,
+    GetOrganizationMemberError, GetUserError, GetUserResponse, MemberRole,
+    OrganizationMemberResponse, OrganizationResponse, RemoveOrganizationMemberError,
+    SetOrganizationMemberError, UpdateUserError,
+};
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use shared::AuthenticatedUser;
+use utoipa::ToSchema;
+
+use crate::domain::use_cases::CreateOrganizationParams;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateOrganizationRequest {
+    #[schema(example = "Acme Promotions")]
+    pub name: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateOrganizationRequestError {
+    #[error("Name cannot be empty")]
+    NameEmpty,
+    #[error("Name is too long (maximum 255 characters)")]
+    NameTooLong,
+}
+
+fn validate_name(name: &str) -> Result<String, CreateOrganizationRequestError> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        return Err(CreateOrganizationRequestError::NameEmpty);
+    }
+    if trimmed.len() > 255 {
+        return Err(CreateOrganizationRequestError::NameTooLong);
+    }
+    Ok(trimmed.to_string())
+}
+
+impl TryFrom<(AuthenticatedUser, CreateOrganizationRequest)> for CreateOrganizationParams {
+    type Error = CreateOrganizationRequestError;
+
+    fn try_from(
+        (user, req): (AuthenticatedUser, CreateOrganizationRequest),
+    ) -> Result<Self, Self::Error> {
+        let name = validate_name(&req.name)?;
+
+        Ok(Self {
+            name,
+            owner_id: user.id(),
+        })
+    }
+}
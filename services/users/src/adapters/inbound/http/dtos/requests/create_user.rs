@@ -25,7 +25,7 @@ pub enum CreateUserRequestError {
     NameTooLong,
 }
 
-fn validate_email(email: &str) -> Result<String, CreateUserRequestError> {
+pub(super) fn validate_email(email: &str) -> Result<String, CreateUserRequestError> {
     if !email.contains('@') {
         return Err(CreateUserRequestError::EmailMissingAtSymbol);
     }
@@ -38,7 +38,7 @@ fn validate_email(email: &str) -> Result<String, CreateUserRequestError> {
     Ok(email.to_string())
 }
 
-fn validate_name(name: &str) -> Result<String, CreateUserRequestError> {
+pub(super) fn validate_name(name: &str) -> Result<String, CreateUserRequestError> {
     let trimmed = name.trim();
     if trimmed.is_empty() {
         return Err(CreateUserRequestError::NameEmpty);
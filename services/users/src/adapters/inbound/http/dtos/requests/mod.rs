@@ -1,3 +1,9 @@
+pub mod create_organization;
 pub mod create_user;
+pub mod set_organization_member;
+pub mod update_user;
 
+pub use create_organization::CreateOrganizationRequest;
 pub use create_user::CreateUserRequest;
+pub use set_organization_member::SetOrganizationMemberRequest;
+pub use update_user::UpdateUserRequest;
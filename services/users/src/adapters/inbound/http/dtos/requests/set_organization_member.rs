@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::super::responses::MemberRole;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SetOrganizationMemberRequest {
+    pub role: MemberRole,
+}
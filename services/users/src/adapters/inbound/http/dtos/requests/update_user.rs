@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use shared::{AuthenticatedUser, IfMatch};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::use_cases::UpdateUserParams;
+
+use super::create_user::{validate_email, validate_name, CreateUserRequestError};
+
+/// Fields to change; omitted fields keep their current value.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateUserRequest {
+    #[schema(example = "user@example.com")]
+    pub email: Option<String>,
+    #[schema(example = "John Doe")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateUserRequestError {
+    #[error("At least one field must be provided")]
+    NoChanges,
+    #[error(transparent)]
+    Invalid(#[from] CreateUserRequestError),
+}
+
+impl TryFrom<(Uuid, AuthenticatedUser, IfMatch, UpdateUserRequest)> for UpdateUserParams {
+    type Error = UpdateUserRequestError;
+
+    fn try_from(
+        (id, user, if_match, req): (Uuid, AuthenticatedUser, IfMatch, UpdateUserRequest),
+    ) -> Result<Self, Self::Error> {
+        if req.email.is_none() && req.name.is_none() {
+            return Err(UpdateUserRequestError::NoChanges);
+        }
+
+        let email = req.email.as_deref().map(validate_email).transpose()?;
+        let name = req.name.as_deref().map(validate_name).transpose()?;
+
+        Ok(Self {
+            id,
+            email,
+            name,
+            expected_version: if_match.version(),
+            requested_by: user.id(),
+        })
+    }
+}
@@ -0,0 +1,8 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreateOrganizationError {
+    #[schema(example = "Name cannot be empty")]
+    pub error: String,
+}
@@ -0,0 +1,8 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GetOrganizationError {
+    #[schema(example = "Organization not found")]
+    pub error: String,
+}
@@ -0,0 +1,8 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GetOrganizationMemberError {
+    #[schema(example = "Member not found")]
+    pub error: String,
+}
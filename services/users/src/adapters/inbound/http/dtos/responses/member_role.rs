@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::OrganizationRole;
+
+/// Role of a member within an organization.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum MemberRole {
+    Owner,
+    Manager,
+    BoxOffice,
+    Viewer,
+}
+
+impl From<OrganizationRole> for MemberRole {
+    fn from(role: OrganizationRole) -> Self {
+        match role {
+            OrganizationRole::Owner => MemberRole::Owner,
+            OrganizationRole::Manager => MemberRole::Manager,
+            OrganizationRole::BoxOffice => MemberRole::BoxOffice,
+            OrganizationRole::Viewer => MemberRole::Viewer,
+        }
+    }
+}
+
+impl From<MemberRole> for OrganizationRole {
+    fn from(role: MemberRole) -> Self {
+        match role {
+            MemberRole::Owner => OrganizationRole::Owner,
+            MemberRole::Manager => OrganizationRole::Manager,
+            MemberRole::BoxOffice => OrganizationRole::BoxOffice,
+            MemberRole::Viewer => OrganizationRole::Viewer,
+        }
+    }
+}
@@ -1,9 +1,25 @@
+pub mod create_organization_error;
 pub mod create_user_error;
 pub mod create_user_response;
+pub mod get_organization_error;
+pub mod get_organization_member_error;
 pub mod get_user_error;
 pub mod get_user_response;
+pub mod member_role;
+pub mod organization_response;
+pub mod remove_organization_member_error;
+pub mod set_organization_member_error;
+pub mod update_user_error;
 
+pub use create_organization_error::CreateOrganizationError;
 pub use create_user_error::CreateUserError;
 pub use create_user_response::CreateUserResponse;
+pub use get_organization_error::GetOrganizationError;
+pub use get_organization_member_error::GetOrganizationMemberError;
 pub use get_user_error::GetUserError;
 pub use get_user_response::GetUserResponse;
+pub use member_role::MemberRole;
+pub use organization_response::{OrganizationMemberResponse, OrganizationResponse};
+pub use remove_organization_member_error::RemoveOrganizationMemberError;
+pub use set_organization_member_error::SetOrganizationMemberError;
+pub use update_user_error::UpdateUserError;
@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{Organization, OrganizationMember};
+
+use super::MemberRole;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationMemberResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub user_id: Uuid,
+    pub role: MemberRole,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&OrganizationMember> for OrganizationMemberResponse {
+    fn from(member: &OrganizationMember) -> Self {
+        Self {
+            user_id: member.user_id(),
+            role: member.role().into(),
+            created_at: *member.created_at(),
+            updated_at: *member.updated_at(),
+        }
+    }
+}
+
+impl From<OrganizationMember> for OrganizationMemberResponse {
+    fn from(member: OrganizationMember) -> Self {
+        (&member).into()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationResponse {
+    #[schema(example = "7d444840-9dc0-11d1-b245-5ffdce74fad2")]
+    pub id: Uuid,
+    #[schema(example = "Acme Promotions")]
+    pub name: String,
+    pub members: Vec<OrganizationMemberResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Organization> for OrganizationResponse {
+    fn from(organization: Organization) -> Self {
+        Self {
+            id: organization.id(),
+            name: organization.name().to_string(),
+            members: organization.members().iter().map(Into::into).collect(),
+            created_at: *organization.created_at(),
+            updated_at: *organization.updated_at(),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RemoveOrganizationMemberError {
+    #[schema(example = "The only owner cannot leave; promote another owner first")]
+    pub error: String,
+}
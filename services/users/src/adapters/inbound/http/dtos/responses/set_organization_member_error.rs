@@ -0,0 +1,8 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SetOrganizationMemberError {
+    #[schema(example = "Only owners can manage members")]
+    pub error: String,
+}
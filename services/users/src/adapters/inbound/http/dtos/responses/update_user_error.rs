@@ -0,0 +1,8 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UpdateUserError {
+    #[schema(example = "User has been modified since the given version")]
+    pub error: String,
+}
@@ -0,0 +1,53 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+
+use crate::domain::{CreateOrganizationUseCase, CreateOrganizationUseCaseError};
+
+use super::super::dtos::{
+    CreateOrganizationError, CreateOrganizationRequest, OrganizationResponse,
+};
+
+#[utoipa::path(
+    post,
+    path = "/users/api/organizations",
+    params(
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = CreateOrganizationRequest,
+    responses(
+        (status = 201, description = "Organization created; the caller is its owner", body = OrganizationResponse),
+        (status = 400, description = "Invalid request", body = CreateOrganizationError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 500, description = "Internal server error", body = CreateOrganizationError)
+    ),
+    tag = "organizations"
+)]
+pub async fn create_organization(
+    user: AuthenticatedUser,
+    body: web::Json<CreateOrganizationRequest>,
+    use_case: web::Data<CreateOrganizationUseCase>,
+) -> impl Responder {
+    use crate::domain::use_cases::CreateOrganizationParams;
+
+    let data: CreateOrganizationParams = match (user, body.into_inner()).try_into() {
+        Ok(data) => data,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(CreateOrganizationError {
+                error: e.to_string(),
+            });
+        }
+    };
+
+    match use_case.execute(data).await {
+        Ok(organization) => {
+            let response: OrganizationResponse = organization.into();
+            HttpResponse::Created().json(response)
+        }
+        Err(CreateOrganizationUseCaseError::OwnerNotFound(msg)) => {
+            HttpResponse::BadRequest().json(CreateOrganizationError { error: msg })
+        }
+        Err(CreateOrganizationUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(CreateOrganizationError { error: msg })
+        }
+    }
+}
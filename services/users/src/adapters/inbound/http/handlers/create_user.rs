@@ -12,6 +12,7 @@ use super::super::dtos::{CreateUserError, CreateUserRequest, CreateUserResponse}
     responses(
         (status = 201, description = "User created successfully", body = CreateUserResponse),
         (status = 400, description = "Invalid request", body = CreateUserError),
+        (status = 409, description = "A user with this email already exists", body = CreateUserError),
         (status = 500, description = "Internal server error", body = CreateUserError)
     ),
     tag = "users"
@@ -36,6 +37,11 @@ pub async fn create_user(
             let response: CreateUserResponse = user.into();
             HttpResponse::Created().json(response)
         }
+        Err(e @ CreateUserUseCaseError::EmailTaken) => {
+            HttpResponse::Conflict().json(CreateUserError {
+                error: e.to_string(),
+            })
+        }
         Err(CreateUserUseCaseError::DatabaseError(msg)) => {
             HttpResponse::InternalServerError().json(CreateUserError { error: msg })
         }
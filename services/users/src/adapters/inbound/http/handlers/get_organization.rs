@@ -0,0 +1,54 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::GetOrganizationParams, GetOrganizationUseCase, GetOrganizationUseCaseError,
+};
+
+use super::super::dtos::{GetOrganizationError, OrganizationResponse};
+
+#[utoipa::path(
+    get,
+    path = "/users/api/organizations/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Organization ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 200, description = "Organization with its members", body = OrganizationResponse),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is not a member", body = GetOrganizationError),
+        (status = 404, description = "Organization not found", body = GetOrganizationError),
+        (status = 500, description = "Internal server error", body = GetOrganizationError)
+    ),
+    tag = "organizations"
+)]
+pub async fn get_organization(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    use_case: web::Data<GetOrganizationUseCase>,
+) -> impl Responder {
+    let data = GetOrganizationParams {
+        id: path.into_inner(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(organization) => {
+            let response: OrganizationResponse = organization.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(GetOrganizationUseCaseError::NotFound(msg)) => {
+            HttpResponse::NotFound().json(GetOrganizationError { error: msg })
+        }
+        Err(e @ GetOrganizationUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(GetOrganizationError {
+                error: e.to_string(),
+            })
+        }
+        Err(GetOrganizationUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetOrganizationError { error: msg })
+        }
+    }
+}
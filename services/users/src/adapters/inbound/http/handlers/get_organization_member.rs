@@ -0,0 +1,39 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::UseCase;
+use uuid::Uuid;
+
+use crate::domain::{GetOrganizationMemberUseCase, GetOrganizationMemberUseCaseError};
+
+use super::super::dtos::{GetOrganizationMemberError, OrganizationMemberResponse};
+
+#[utoipa::path(
+    get,
+    path = "/users/api/organizations/{id}/members/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Organization ID"),
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "The user's role in the organization", body = OrganizationMemberResponse),
+        (status = 404, description = "User is not a member", body = GetOrganizationMemberError),
+        (status = 500, description = "Internal server error", body = GetOrganizationMemberError)
+    ),
+    tag = "organizations"
+)]
+pub async fn get_organization_member(
+    path: web::Path<(Uuid, Uuid)>,
+    use_case: web::Data<GetOrganizationMemberUseCase>,
+) -> impl Responder {
+    match use_case.execute(path.into_inner()).await {
+        Ok(member) => {
+            let response: OrganizationMemberResponse = member.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(GetOrganizationMemberUseCaseError::NotFound(msg)) => {
+            HttpResponse::NotFound().json(GetOrganizationMemberError { error: msg })
+        }
+        Err(GetOrganizationMemberUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(GetOrganizationMemberError { error: msg })
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use actix_web::{web, HttpResponse, Responder};
-use shared::UseCase;
+use actix_web::{http::header::ETag, web, HttpRequest, HttpResponse, Responder};
+use shared::{entity_tag, is_not_modified, UseCase};
 use uuid::Uuid;
 
 use crate::domain::{GetUserUseCase, GetUserUseCaseError};
@@ -10,25 +10,32 @@ use super::super::dtos::{GetUserError, GetUserResponse};
     get,
     path = "/users/api/users/{id}",
     params(
-        ("id" = Uuid, Path, description = "User ID")
+        ("id" = Uuid, Path, description = "User ID"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a copy the client already has")
     ),
     responses(
-        (status = 200, description = "User found successfully", body = GetUserResponse),
+        (status = 200, description = "User found successfully; its version is in the ETag header", body = GetUserResponse),
+        (status = 304, description = "The client's copy is current"),
         (status = 404, description = "User not found", body = GetUserError),
         (status = 500, description = "Internal server error", body = GetUserError)
     ),
     tag = "users"
 )]
 pub async fn get_user(
+    req: HttpRequest,
     path: web::Path<Uuid>,
     use_case: web::Data<GetUserUseCase>,
 ) -> impl Responder {
     let user_id = path.into_inner();
 
     match use_case.execute(user_id).await {
+        Ok(user) if is_not_modified(&req, user.version()) => HttpResponse::NotModified()
+            .insert_header(ETag(entity_tag(user.version())))
+            .finish(),
         Ok(user) => {
+            let etag = ETag(entity_tag(user.version()));
             let response: GetUserResponse = user.into();
-            HttpResponse::Ok().json(response)
+            HttpResponse::Ok().insert_header(etag).json(response)
         }
         Err(GetUserUseCaseError::NotFound(msg)) => {
             HttpResponse::NotFound().json(GetUserError { error: msg })
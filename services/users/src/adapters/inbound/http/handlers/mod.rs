@@ -1,5 +1,17 @@
+pub mod create_organization;
 pub mod create_user;
+pub mod get_organization;
+pub mod get_organization_member;
 pub mod get_user;
+pub mod remove_organization_member;
+pub mod set_organization_member;
+pub mod update_user;
 
+pub use create_organization::create_organization;
 pub use create_user::create_user;
+pub use get_organization::get_organization;
+pub use get_organization_member::get_organization_member;
 pub use get_user::get_user;
+pub use remove_organization_member::remove_organization_member;
+pub use set_organization_member::set_organization_member;
+pub use update_user::update_user;
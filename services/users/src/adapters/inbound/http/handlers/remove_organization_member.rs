@@ -0,0 +1,65 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::RemoveOrganizationMemberParams, RemoveOrganizationMemberUseCase,
+    RemoveOrganizationMemberUseCaseError,
+};
+
+use super::super::dtos::RemoveOrganizationMemberError;
+
+#[utoipa::path(
+    delete,
+    path = "/users/api/organizations/{id}/members/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Organization ID"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    responses(
+        (status = 204, description = "Member removed"),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is neither an owner nor the member", body = RemoveOrganizationMemberError),
+        (status = 404, description = "Organization or member not found", body = RemoveOrganizationMemberError),
+        (status = 409, description = "Would leave the organization without an owner", body = RemoveOrganizationMemberError),
+        (status = 500, description = "Internal server error", body = RemoveOrganizationMemberError)
+    ),
+    tag = "organizations"
+)]
+pub async fn remove_organization_member(
+    path: web::Path<(Uuid, Uuid)>,
+    user: AuthenticatedUser,
+    use_case: web::Data<RemoveOrganizationMemberUseCase>,
+) -> impl Responder {
+    let (organization_id, user_id) = path.into_inner();
+    let data = RemoveOrganizationMemberParams {
+        organization_id,
+        user_id,
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(RemoveOrganizationMemberUseCaseError::OrganizationNotFound(msg)) => {
+            HttpResponse::NotFound().json(RemoveOrganizationMemberError { error: msg })
+        }
+        Err(e @ RemoveOrganizationMemberUseCaseError::MemberNotFound) => HttpResponse::NotFound()
+            .json(RemoveOrganizationMemberError {
+                error: e.to_string(),
+            }),
+        Err(e @ RemoveOrganizationMemberUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(RemoveOrganizationMemberError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ RemoveOrganizationMemberUseCaseError::SoleOwner) => {
+            HttpResponse::Conflict().json(RemoveOrganizationMemberError {
+                error: e.to_string(),
+            })
+        }
+        Err(RemoveOrganizationMemberUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(RemoveOrganizationMemberError { error: msg })
+        }
+    }
+}
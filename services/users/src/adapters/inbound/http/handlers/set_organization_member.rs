@@ -0,0 +1,70 @@
+use actix_web::{web, HttpResponse, Responder};
+use shared::{AuthenticatedUser, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::SetOrganizationMemberParams, SetOrganizationMemberUseCase,
+    SetOrganizationMemberUseCaseError,
+};
+
+use super::super::dtos::{
+    OrganizationMemberResponse, SetOrganizationMemberError, SetOrganizationMemberRequest,
+};
+
+#[utoipa::path(
+    put,
+    path = "/users/api/organizations/{id}/members/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Organization ID"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway")
+    ),
+    request_body = SetOrganizationMemberRequest,
+    responses(
+        (status = 200, description = "Member added or role changed", body = OrganizationMemberResponse),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is not an owner", body = SetOrganizationMemberError),
+        (status = 404, description = "Organization or user not found", body = SetOrganizationMemberError),
+        (status = 409, description = "Would leave the organization without an owner", body = SetOrganizationMemberError),
+        (status = 500, description = "Internal server error", body = SetOrganizationMemberError)
+    ),
+    tag = "organizations"
+)]
+pub async fn set_organization_member(
+    path: web::Path<(Uuid, Uuid)>,
+    user: AuthenticatedUser,
+    body: web::Json<SetOrganizationMemberRequest>,
+    use_case: web::Data<SetOrganizationMemberUseCase>,
+) -> impl Responder {
+    let (organization_id, user_id) = path.into_inner();
+    let data = SetOrganizationMemberParams {
+        organization_id,
+        user_id,
+        role: body.into_inner().role.into(),
+        requested_by: user.id(),
+    };
+
+    match use_case.execute(data).await {
+        Ok(member) => {
+            let response: OrganizationMemberResponse = member.into();
+            HttpResponse::Ok().json(response)
+        }
+        Err(
+            SetOrganizationMemberUseCaseError::OrganizationNotFound(msg)
+            | SetOrganizationMemberUseCaseError::UserNotFound(msg),
+        ) => HttpResponse::NotFound().json(SetOrganizationMemberError { error: msg }),
+        Err(e @ SetOrganizationMemberUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(SetOrganizationMemberError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ SetOrganizationMemberUseCaseError::SoleOwner) => {
+            HttpResponse::Conflict().json(SetOrganizationMemberError {
+                error: e.to_string(),
+            })
+        }
+        Err(SetOrganizationMemberUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(SetOrganizationMemberError { error: msg })
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use actix_web::{http::header::ETag, web, HttpResponse, Responder};
+use shared::{entity_tag, AuthenticatedUser, IfMatch, UseCase};
+use uuid::Uuid;
+
+use crate::domain::{use_cases::UpdateUserParams, UpdateUserUseCase, UpdateUserUseCaseError};
+
+use super::super::dtos::{GetUserResponse, UpdateUserError, UpdateUserRequest};
+
+#[utoipa::path(
+    patch,
+    path = "/users/api/users/{id}",
+    params(
+        ("id" = Uuid, Path, description = "User ID"),
+        ("X-User-Id" = Uuid, Header, description = "Caller, set by the API gateway"),
+        ("If-Match" = String, Header, description = "ETag of the version being changed, or * to change any version")
+    ),
+    request_body = UpdateUserRequest,
+    responses(
+        (status = 200, description = "User updated; its new version is in the ETag header", body = GetUserResponse),
+        (status = 400, description = "Invalid request", body = UpdateUserError),
+        (status = 401, description = "Missing or invalid caller identity"),
+        (status = 403, description = "Caller is not the user", body = UpdateUserError),
+        (status = 404, description = "User not found", body = UpdateUserError),
+        (status = 409, description = "A user with this email already exists", body = UpdateUserError),
+        (status = 412, description = "User has been modified since the If-Match version", body = UpdateUserError),
+        (status = 428, description = "If-Match header is missing", body = UpdateUserError),
+        (status = 500, description = "Internal server error", body = UpdateUserError)
+    ),
+    tag = "users"
+)]
+pub async fn update_user(
+    path: web::Path<Uuid>,
+    user: AuthenticatedUser,
+    if_match: IfMatch,
+    body: web::Json<UpdateUserRequest>,
+    use_case: web::Data<UpdateUserUseCase>,
+) -> impl Responder {
+    let data: UpdateUserParams =
+        match (path.into_inner(), user, if_match, body.into_inner()).try_into() {
+            Ok(data) => data,
+            Err(e) => {
+                return HttpResponse::BadRequest().json(UpdateUserError {
+                    error: e.to_string(),
+                });
+            }
+        };
+
+    match use_case.execute(data).await {
+        Ok(user) => {
+            let etag = ETag(entity_tag(user.version()));
+            let response: GetUserResponse = user.into();
+            HttpResponse::Ok().insert_header(etag).json(response)
+        }
+        Err(UpdateUserUseCaseError::NotFound(msg)) => {
+            HttpResponse::NotFound().json(UpdateUserError { error: msg })
+        }
+        Err(e @ UpdateUserUseCaseError::Forbidden) => {
+            HttpResponse::Forbidden().json(UpdateUserError {
+                error: e.to_string(),
+            })
+        }
+        Err(e @ UpdateUserUseCaseError::VersionMismatch) => HttpResponse::PreconditionFailed()
+            .json(UpdateUserError {
+                error: e.to_string(),
+            }),
+        Err(e @ UpdateUserUseCaseError::EmailTaken) => {
+            HttpResponse::Conflict().json(UpdateUserError {
+                error: e.to_string(),
+            })
+        }
+        Err(UpdateUserUseCaseError::DatabaseError(msg)) => {
+            HttpResponse::InternalServerError().json(UpdateUserError { error: msg })
+        }
+    }
+}
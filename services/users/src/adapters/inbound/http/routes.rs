@@ -1,11 +1,32 @@
 use actix_web::web;
 
-use super::handlers::{create_user, get_user};
+use super::handlers::{
+    create_organization, create_user, get_organization, get_organization_member, get_user,
+    remove_organization_member, set_organization_member, update_user,
+};
 
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/api/users")
             .route("", web::post().to(create_user))
-            .route("/{id}", web::get().to(get_user)),
+            .route("/{id}", web::get().to(get_user))
+            .route("/{id}", web::patch().to(update_user)),
+    )
+    .service(
+        web::scope("/api/organizations")
+            .route("", web::post().to(create_organization))
+            .route("/{id}", web::get().to(get_organization))
+            .route(
+                "/{id}/members/{user_id}",
+                web::get().to(get_organization_member),
+            )
+            .route(
+                "/{id}/members/{user_id}",
+                web::put().to(set_organization_member),
+            )
+            .route(
+                "/{id}/members/{user_id}",
+                web::delete().to(remove_organization_member),
+            ),
     );
 }
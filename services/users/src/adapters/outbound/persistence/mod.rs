@@ -1,4 +1,4 @@
 pub mod models;
 pub mod repositories;
 
-pub use repositories::UserRepositoryImpl;
+pub use repositories::{OrganizationRepositoryImpl, UserRepositoryImpl};
@@ -1 +1,3 @@
+pub mod organization;
+pub mod organization_member;
 pub mod user;
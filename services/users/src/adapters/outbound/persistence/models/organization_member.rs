@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{OrganizationMember, OrganizationRole};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum Role {
+    #[sea_orm(string_value = "owner")]
+    Owner,
+    #[sea_orm(string_value = "manager")]
+    Manager,
+    #[sea_orm(string_value = "box_office")]
+    BoxOffice,
+    #[sea_orm(string_value = "viewer")]
+    Viewer,
+}
+
+impl From<Role> for OrganizationRole {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::Owner => OrganizationRole::Owner,
+            Role::Manager => OrganizationRole::Manager,
+            Role::BoxOffice => OrganizationRole::BoxOffice,
+            Role::Viewer => OrganizationRole::Viewer,
+        }
+    }
+}
+
+impl From<OrganizationRole> for Role {
+    fn from(role: OrganizationRole) -> Self {
+        match role {
+            OrganizationRole::Owner => Role::Owner,
+            OrganizationRole::Manager => Role::Manager,
+            OrganizationRole::BoxOffice => Role::BoxOffice,
+            OrganizationRole::Viewer => Role::Viewer,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "organization_members")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub organization_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user_id: Uuid,
+    pub role: Role,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for OrganizationMember {
+    fn from(model: Model) -> Self {
+        OrganizationMember::new(
+            model.user_id,
+            model.role.into(),
+            model.created_at,
+            model.updated_at,
+        )
+    }
+}
@@ -12,6 +12,7 @@ pub struct Model {
     pub id: Uuid,
     pub email: String,
     pub name: String,
+    pub version: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -28,6 +29,7 @@ impl From<Model> for User {
             model.id,
             model.email,
             model.name,
+            model.version,
             model.created_at,
             model.updated_at,
         )
@@ -49,6 +51,7 @@ mod tests {
             id,
             email: "test@example.com".to_string(),
             name: "Test User".to_string(),
+            version: 1,
             created_at: now,
             updated_at: now,
         };
@@ -58,6 +61,7 @@ mod tests {
         assert_eq!(user.id(), id);
         assert_eq!(user.email(), "test@example.com");
         assert_eq!(user.name(), "Test User");
+        assert_eq!(user.version(), 1);
         assert_eq!(*user.created_at(), now);
         assert_eq!(*user.updated_at(), now);
     }
@@ -72,6 +76,7 @@ mod tests {
             id,
             email: "user@domain.com".to_string(),
             name: "John Doe".to_string(),
+            version: 1,
             created_at: created,
             updated_at: updated,
         };
@@ -102,6 +107,7 @@ mod tests {
             id,
             email: "test+filter@example.co.uk".to_string(),
             name: "François O'Brien-Smith".to_string(),
+            version: 1,
             created_at: now,
             updated_at: now,
         };
@@ -1,3 +1,5 @@
+pub mod organization_repository;
 pub mod user_repository;
 
+pub use organization_repository::OrganizationRepositoryImpl;
 pub use user_repository::UserRepositoryImpl;
@@ -0,0 +1,227 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::OnConflict, ActiveModelTrait, ColumnTrait, DatabaseConnection, DatabaseTransaction,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use uuid::Uuid;
+
+use crate::domain::{
+    use_cases::CreateOrganizationParams, DomainError, DomainResult, Organization,
+    OrganizationMember, OrganizationRepository, OrganizationRole,
+};
+
+use super::super::models::{
+    organization::{self, Entity as OrganizationEntity},
+    organization_member::{self, Entity as MemberEntity, Role},
+};
+
+pub struct OrganizationRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl OrganizationRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+/// Locks the organization's row until `txn` ends, so changes to its members
+/// are made one at a time, and fails with `Conflict` when the user is its
+/// only owner.
+async fn lock_unless_sole_owner(
+    txn: &DatabaseTransaction,
+    organization_id: Uuid,
+    user_id: Uuid,
+) -> DomainResult<()> {
+    OrganizationEntity::find_by_id(organization_id)
+        .lock_exclusive()
+        .one(txn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+        .ok_or_else(|| {
+            DomainError::NotFound(format!(
+                "Organization with id {} not found",
+                organization_id
+            ))
+        })?;
+
+    let owners = MemberEntity::find()
+        .filter(organization_member::Column::OrganizationId.eq(organization_id))
+        .filter(organization_member::Column::Role.eq(Role::Owner))
+        .limit(2)
+        .all(txn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    if owners.len() == 1 && owners[0].user_id == user_id {
+        return Err(DomainError::Conflict(format!(
+            "User {} is the only owner of organization {}",
+            user_id, organization_id
+        )));
+    }
+
+    Ok(())
+}
+
+fn into_organization(model: organization::Model, members: Vec<OrganizationMember>) -> Organization {
+    Organization::new(
+        model.id,
+        model.name,
+        members,
+        model.created_at,
+        model.updated_at,
+    )
+}
+
+#[async_trait]
+impl OrganizationRepository for OrganizationRepositoryImpl {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Organization> {
+        let organization = OrganizationEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("Organization with id {} not found", id))
+            })?;
+
+        let members = MemberEntity::find()
+            .filter(organization_member::Column::OrganizationId.eq(id))
+            .order_by_asc(organization_member::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(into_organization(
+            organization,
+            members.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    async fn create(&self, data: CreateOrganizationParams) -> DomainResult<Organization> {
+        let now = Utc::now();
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let organization = organization::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(data.name),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(&txn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let owner = organization_member::ActiveModel {
+            organization_id: Set(organization.id),
+            user_id: Set(data.owner_id),
+            role: Set(Role::Owner),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(&txn)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(into_organization(organization, vec![owner.into()]))
+    }
+
+    async fn find_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<OrganizationMember> {
+        let member = MemberEntity::find_by_id((organization_id, user_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!(
+                    "User {} is not a member of organization {}",
+                    user_id, organization_id
+                ))
+            })?;
+
+        Ok(member.into())
+    }
+
+    async fn save_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        role: OrganizationRole,
+    ) -> DomainResult<OrganizationMember> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        if role != OrganizationRole::Owner {
+            lock_unless_sole_owner(&txn, organization_id, user_id).await?;
+        }
+
+        let now = Utc::now();
+        let member = organization_member::ActiveModel {
+            organization_id: Set(organization_id),
+            user_id: Set(user_id),
+            role: Set(role.into()),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        let saved = MemberEntity::insert(member)
+            .on_conflict(
+                OnConflict::columns([
+                    organization_member::Column::OrganizationId,
+                    organization_member::Column::UserId,
+                ])
+                .update_columns([
+                    organization_member::Column::Role,
+                    organization_member::Column::UpdatedAt,
+                ])
+                .to_owned(),
+            )
+            .exec_with_returning(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(saved.into())
+    }
+
+    async fn remove_member(&self, organization_id: Uuid, user_id: Uuid) -> DomainResult<()> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        lock_unless_sole_owner(&txn, organization_id, user_id).await?;
+
+        let result = MemberEntity::delete_by_id((organization_id, user_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::NotFound(format!(
+                "User {} is not a member of organization {}",
+                user_id, organization_id
+            )));
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+}
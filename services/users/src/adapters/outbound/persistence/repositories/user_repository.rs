@@ -1,11 +1,28 @@
 use async_trait::async_trait;
 use chrono::Utc;
-use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, Set};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, Set, SqlErr,
+};
 use uuid::Uuid;
 
-use crate::domain::{use_cases::CreateUserParams, DomainError, DomainResult, User, UserRepository};
+use crate::domain::{
+    use_cases::{CreateUserParams, UpdateUserParams},
+    DomainError, DomainResult, User, UserRepository,
+};
 
-use super::super::models::user::{ActiveModel, Entity as UserEntity};
+use super::super::models::user::{self, ActiveModel, Entity as UserEntity};
+
+/// Reports a taken email, the only unique column besides the id, as a
+/// conflict rather than a database failure.
+fn map_write_error(e: DbErr) -> DomainError {
+    match e.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => {
+            DomainError::Conflict("Email is already in use".to_string())
+        }
+        _ => DomainError::DatabaseError(e.to_string()),
+    }
+}
 
 pub struct UserRepositoryImpl {
     db: DatabaseConnection,
@@ -37,15 +54,45 @@ impl UserRepository for UserRepositoryImpl {
             id: Set(user_id),
             email: Set(data.email),
             name: Set(data.name),
+            version: Set(1),
             created_at: Set(now),
             updated_at: Set(now),
         };
 
-        let inserted = user
-            .insert(&self.db)
-            .await
-            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        let inserted = user.insert(&self.db).await.map_err(map_write_error)?;
 
         Ok(inserted.into())
     }
+
+    async fn update(&self, data: UpdateUserParams) -> DomainResult<Option<User>> {
+        let mut update = UserEntity::update_many()
+            .col_expr(
+                user::Column::Version,
+                Expr::col(user::Column::Version).add(1),
+            )
+            .col_expr(user::Column::UpdatedAt, Expr::value(Utc::now()))
+            .filter(user::Column::Id.eq(data.id));
+        if let Some(email) = data.email {
+            update = update.col_expr(user::Column::Email, Expr::value(email));
+        }
+        if let Some(name) = data.name {
+            update = update.col_expr(user::Column::Name, Expr::value(name));
+        }
+        if let Some(version) = data.expected_version {
+            update = update.filter(user::Column::Version.eq(version));
+        }
+
+        let updated = update
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(map_write_error)?
+            .into_iter()
+            .next();
+
+        match updated {
+            Some(model) => Ok(Some(model.into())),
+            // Either the user doesn't exist or it moved past the expected version
+            None => self.find_by_id(data.id).await.map(|_| None),
+        }
+    }
 }
@@ -1,3 +1,5 @@
+pub mod organization;
 pub mod user;
 
+pub use organization::{Organization, OrganizationMember, OrganizationRole};
 pub use user::User;
@@ -0,0 +1,176 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// What a member may do on behalf of an organization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrganizationRole {
+    /// Manages members and everything below.
+    Owner,
+    /// Creates and changes the organization's events.
+    Manager,
+    /// Sells and checks tickets at the door.
+    BoxOffice,
+    /// Read-only access.
+    Viewer,
+}
+
+impl OrganizationRole {
+    pub fn can_manage_members(&self) -> bool {
+        matches!(self, Self::Owner)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationMember {
+    user_id: Uuid,
+    role: OrganizationRole,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl OrganizationMember {
+    pub fn new(
+        user_id: Uuid,
+        role: OrganizationRole,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            user_id,
+            role,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn role(&self) -> OrganizationRole {
+        self.role
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+/// A company or promoter whose staff manage events together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Organization {
+    id: Uuid,
+    name: String,
+    members: Vec<OrganizationMember>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Organization {
+    pub fn new(
+        id: Uuid,
+        name: String,
+        members: Vec<OrganizationMember>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            members,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn members(&self) -> &[OrganizationMember] {
+        &self.members
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    pub fn member(&self, user_id: Uuid) -> Option<&OrganizationMember> {
+        self.members.iter().find(|member| member.user_id == user_id)
+    }
+
+    pub fn role_of(&self, user_id: Uuid) -> Option<OrganizationRole> {
+        self.member(user_id).map(OrganizationMember::role)
+    }
+
+    /// Whether `user_id` is the only owner, so they cannot leave or be
+    /// demoted without orphaning the organization.
+    pub fn is_sole_owner(&self, user_id: Uuid) -> bool {
+        let mut owners = self
+            .members
+            .iter()
+            .filter(|member| member.role == OrganizationRole::Owner);
+        matches!(
+            (owners.next(), owners.next()),
+            (Some(owner), None) if owner.user_id == user_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn organization(members: &[(Uuid, OrganizationRole)]) -> Organization {
+        let now = Utc::now();
+        Organization::new(
+            Uuid::new_v4(),
+            "Acme Promotions".to_string(),
+            members
+                .iter()
+                .map(|(user_id, role)| OrganizationMember::new(*user_id, *role, now, now))
+                .collect(),
+            now,
+            now,
+        )
+    }
+
+    #[test]
+    fn test_sole_owner_is_detected() {
+        let owner = Uuid::new_v4();
+        let manager = Uuid::new_v4();
+        let org = organization(&[
+            (owner, OrganizationRole::Owner),
+            (manager, OrganizationRole::Manager),
+        ]);
+
+        assert!(org.is_sole_owner(owner));
+        assert!(!org.is_sole_owner(manager));
+    }
+
+    #[test]
+    fn test_co_owners_are_not_sole_owners() {
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let org = organization(&[
+            (first, OrganizationRole::Owner),
+            (second, OrganizationRole::Owner),
+        ]);
+
+        assert!(!org.is_sole_owner(first));
+        assert!(!org.is_sole_owner(second));
+    }
+}
@@ -7,6 +7,8 @@ pub struct User {
     id: Uuid,
     email: String,
     name: String,
+    /// Incremented on every change; the user's ETag.
+    version: i32,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -16,6 +18,7 @@ impl User {
         id: Uuid,
         email: String,
         name: String,
+        version: i32,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Self {
@@ -23,6 +26,7 @@ impl User {
             id,
             email,
             name,
+            version,
             created_at,
             updated_at,
         }
@@ -40,6 +44,10 @@ impl User {
         &self.name
     }
 
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
     pub fn created_at(&self) -> &DateTime<Utc> {
         &self.created_at
     }
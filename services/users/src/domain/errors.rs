@@ -8,6 +8,9 @@ pub enum DomainError {
     #[error("Database error: {0}")]
     DatabaseError(String),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     #[allow(dead_code)]
     #[error("Validation error: {0}")]
     ValidationError(String),
@@ -3,9 +3,14 @@ pub mod errors;
 pub mod repositories;
 pub mod use_cases;
 
-pub use entities::User;
+pub use entities::{Organization, OrganizationMember, OrganizationRole, User};
 pub use errors::{DomainError, DomainResult};
-pub use repositories::UserRepository;
+pub use repositories::{OrganizationRepository, UserRepository};
 pub use use_cases::{
-    CreateUserUseCase, CreateUserUseCaseError, GetUserUseCase, GetUserUseCaseError,
+    CreateOrganizationUseCase, CreateOrganizationUseCaseError, CreateUserUseCase,
+    CreateUserUseCaseError, GetOrganizationMemberUseCase, GetOrganizationMemberUseCaseError,
+    GetOrganizationUseCase, GetOrganizationUseCaseError, GetUserUseCase, GetUserUseCaseError,
+    RemoveOrganizationMemberUseCase, RemoveOrganizationMemberUseCaseError,
+    SetOrganizationMemberUseCase, SetOrganizationMemberUseCaseError, UpdateUserUseCase,
+    UpdateUserUseCaseError,
 };
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{Organization, OrganizationMember, OrganizationRole},
+    errors::DomainResult,
+    use_cases::CreateOrganizationParams,
+};
+
+#[async_trait]
+pub trait OrganizationRepository: Send + Sync {
+    /// Loads the organization with all of its members.
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Organization>;
+    /// Creates the organization with its creator as the first owner.
+    async fn create(&self, data: CreateOrganizationParams) -> DomainResult<Organization>;
+    async fn find_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<OrganizationMember>;
+    /// Adds the user or changes their role. Fails with `Conflict`, changing
+    /// nothing, when that would demote the organization's only owner.
+    async fn save_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        role: OrganizationRole,
+    ) -> DomainResult<OrganizationMember>;
+    /// Fails with `Conflict`, changing nothing, when the user is the
+    /// organization's only owner.
+    async fn remove_member(&self, organization_id: Uuid, user_id: Uuid) -> DomainResult<()>;
+}
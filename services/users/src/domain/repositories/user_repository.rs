@@ -1,10 +1,20 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::domain::{entities::User, errors::DomainResult, use_cases::CreateUserParams};
+use crate::domain::{
+    entities::User,
+    errors::DomainResult,
+    use_cases::{CreateUserParams, UpdateUserParams},
+};
 
 #[async_trait]
 pub trait UserRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> DomainResult<User>;
+    /// Fails with `DomainError::Conflict` when the email is taken.
     async fn create(&self, data: CreateUserParams) -> DomainResult<User>;
+    /// Applies the changes and bumps the user's version. Returns `None`,
+    /// changing nothing, when the user is no longer at
+    /// `data.expected_version`; fails with `DomainError::Conflict` when the
+    /// new email is taken.
+    async fn update(&self, data: UpdateUserParams) -> DomainResult<Option<User>>;
 }
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, Organization, OrganizationRepository, UserRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateOrganizationParams {
+    pub name: String,
+    /// The creating user, who becomes the first owner.
+    pub owner_id: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateOrganizationUseCaseError {
+    #[error("User not found: {0}")]
+    OwnerNotFound(String),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct CreateOrganizationUseCase {
+    repository: Arc<dyn OrganizationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl CreateOrganizationUseCase {
+    pub fn new(
+        repository: Arc<dyn OrganizationRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            user_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl UseCase<CreateOrganizationParams, Result<Organization, CreateOrganizationUseCaseError>>
+    for CreateOrganizationUseCase
+{
+    async fn execute(
+        &self,
+        data: CreateOrganizationParams,
+    ) -> Result<Organization, CreateOrganizationUseCaseError> {
+        self.user_repository
+            .find_by_id(data.owner_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(msg) => CreateOrganizationUseCaseError::OwnerNotFound(msg),
+                e => CreateOrganizationUseCaseError::DatabaseError(e.to_string()),
+            })?;
+
+        self.repository
+            .create(data)
+            .await
+            .map_err(|e| CreateOrganizationUseCaseError::DatabaseError(e.to_string()))
+    }
+}
@@ -13,6 +13,8 @@ pub struct CreateUserParams {
 
 #[derive(Debug, thiserror::Error)]
 pub enum CreateUserUseCaseError {
+    #[error("A user with this email already exists")]
+    EmailTaken,
     #[error("Database error: {0}")]
     DatabaseError(String),
 }
@@ -32,6 +34,7 @@ impl UseCase<CreateUserParams, Result<User, CreateUserUseCaseError>> for CreateU
     async fn execute(&self, data: CreateUserParams) -> Result<User, CreateUserUseCaseError> {
         self.repository.create(data).await.map_err(|e| match e {
             DomainError::DatabaseError(msg) => CreateUserUseCaseError::DatabaseError(msg),
+            DomainError::Conflict(_) => CreateUserUseCaseError::EmailTaken,
             _ => {
                 log::error!("Unexpected error in CreateUserUseCase: {:?}", e);
                 CreateUserUseCaseError::DatabaseError("Unexpected error".to_string())
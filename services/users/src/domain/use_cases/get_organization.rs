@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, Organization, OrganizationRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetOrganizationParams {
+    pub id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetOrganizationUseCaseError {
+    #[error("Organization not found: {0}")]
+    NotFound(String),
+
+    #[error("Only members can view this organization")]
+    Forbidden,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct GetOrganizationUseCase {
+    repository: Arc<dyn OrganizationRepository>,
+}
+
+impl GetOrganizationUseCase {
+    pub fn new(repository: Arc<dyn OrganizationRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<GetOrganizationParams, Result<Organization, GetOrganizationUseCaseError>>
+    for GetOrganizationUseCase
+{
+    async fn execute(
+        &self,
+        data: GetOrganizationParams,
+    ) -> Result<Organization, GetOrganizationUseCaseError> {
+        let organization = self
+            .repository
+            .find_by_id(data.id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(msg) => GetOrganizationUseCaseError::NotFound(msg),
+                e => GetOrganizationUseCaseError::DatabaseError(e.to_string()),
+            })?;
+
+        if organization.member(data.requested_by).is_none() {
+            return Err(GetOrganizationUseCaseError::Forbidden);
+        }
+
+        Ok(organization)
+    }
+}
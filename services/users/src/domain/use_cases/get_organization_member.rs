@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, OrganizationMember, OrganizationRepository};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetOrganizationMemberUseCaseError {
+    #[error("Member not found: {0}")]
+    NotFound(String),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Looks up a single membership; other services use it to authorize actions
+/// taken on behalf of an organization.
+pub struct GetOrganizationMemberUseCase {
+    repository: Arc<dyn OrganizationRepository>,
+}
+
+impl GetOrganizationMemberUseCase {
+    pub fn new(repository: Arc<dyn OrganizationRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<(Uuid, Uuid), Result<OrganizationMember, GetOrganizationMemberUseCaseError>>
+    for GetOrganizationMemberUseCase
+{
+    async fn execute(
+        &self,
+        (organization_id, user_id): (Uuid, Uuid),
+    ) -> Result<OrganizationMember, GetOrganizationMemberUseCaseError> {
+        self.repository
+            .find_member(organization_id, user_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(msg) => GetOrganizationMemberUseCaseError::NotFound(msg),
+                e => GetOrganizationMemberUseCaseError::DatabaseError(e.to_string()),
+            })
+    }
+}
@@ -1,5 +1,28 @@
+pub mod create_organization;
 pub mod create_user;
+pub mod get_organization;
+pub mod get_organization_member;
 pub mod get_user;
+pub mod remove_organization_member;
+pub mod set_organization_member;
+pub mod update_user;
 
+pub use create_organization::{
+    CreateOrganizationParams, CreateOrganizationUseCase, CreateOrganizationUseCaseError,
+};
 pub use create_user::{CreateUserParams, CreateUserUseCase, CreateUserUseCaseError};
+pub use get_organization::{
+    GetOrganizationParams, GetOrganizationUseCase, GetOrganizationUseCaseError,
+};
+pub use get_organization_member::{
+    GetOrganizationMemberUseCase, GetOrganizationMemberUseCaseError,
+};
 pub use get_user::{GetUserUseCase, GetUserUseCaseError};
+pub use remove_organization_member::{
+    RemoveOrganizationMemberParams, RemoveOrganizationMemberUseCase,
+    RemoveOrganizationMemberUseCaseError,
+};
+pub use set_organization_member::{
+    SetOrganizationMemberParams, SetOrganizationMemberUseCase, SetOrganizationMemberUseCaseError,
+};
+pub use update_user::{UpdateUserParams, UpdateUserUseCase, UpdateUserUseCaseError};
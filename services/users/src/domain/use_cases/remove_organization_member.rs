@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, OrganizationRepository};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveOrganizationMemberParams {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RemoveOrganizationMemberUseCaseError {
+    #[error("Organization not found: {0}")]
+    OrganizationNotFound(String),
+
+    #[error("Member not found")]
+    MemberNotFound,
+
+    #[error("Only owners can remove other members")]
+    Forbidden,
+
+    #[error("The only owner cannot leave; promote another owner first")]
+    SoleOwner,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Owners can remove anyone; every member can remove themselves.
+pub struct RemoveOrganizationMemberUseCase {
+    repository: Arc<dyn OrganizationRepository>,
+}
+
+impl RemoveOrganizationMemberUseCase {
+    pub fn new(repository: Arc<dyn OrganizationRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<RemoveOrganizationMemberParams, Result<(), RemoveOrganizationMemberUseCaseError>>
+    for RemoveOrganizationMemberUseCase
+{
+    async fn execute(
+        &self,
+        data: RemoveOrganizationMemberParams,
+    ) -> Result<(), RemoveOrganizationMemberUseCaseError> {
+        let organization = self
+            .repository
+            .find_by_id(data.organization_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(msg) => {
+                    RemoveOrganizationMemberUseCaseError::OrganizationNotFound(msg)
+                }
+                e => RemoveOrganizationMemberUseCaseError::DatabaseError(e.to_string()),
+            })?;
+
+        let leaving = data.requested_by == data.user_id;
+        let is_owner = organization
+            .role_of(data.requested_by)
+            .is_some_and(|role| role.can_manage_members());
+        if !leaving && !is_owner {
+            return Err(RemoveOrganizationMemberUseCaseError::Forbidden);
+        }
+
+        if organization.member(data.user_id).is_none() {
+            return Err(RemoveOrganizationMemberUseCaseError::MemberNotFound);
+        }
+        // Checked again by the repository while it holds the organization
+        if organization.is_sole_owner(data.user_id) {
+            return Err(RemoveOrganizationMemberUseCaseError::SoleOwner);
+        }
+
+        self.repository
+            .remove_member(data.organization_id, data.user_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => RemoveOrganizationMemberUseCaseError::MemberNotFound,
+                DomainError::Conflict(_) => RemoveOrganizationMemberUseCaseError::SoleOwner,
+                e => RemoveOrganizationMemberUseCaseError::DatabaseError(e.to_string()),
+            })
+    }
+}
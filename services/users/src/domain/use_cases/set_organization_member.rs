@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    DomainError, OrganizationMember, OrganizationRepository, OrganizationRole, UserRepository,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetOrganizationMemberParams {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub role: OrganizationRole,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SetOrganizationMemberUseCaseError {
+    #[error("Organization not found: {0}")]
+    OrganizationNotFound(String),
+
+    #[error("User not found: {0}")]
+    UserNotFound(String),
+
+    #[error("Only owners can manage members")]
+    Forbidden,
+
+    #[error("The only owner cannot be demoted; promote another owner first")]
+    SoleOwner,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct SetOrganizationMemberUseCase {
+    repository: Arc<dyn OrganizationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl SetOrganizationMemberUseCase {
+    pub fn new(
+        repository: Arc<dyn OrganizationRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            user_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl
+    UseCase<
+        SetOrganizationMemberParams,
+        Result<OrganizationMember, SetOrganizationMemberUseCaseError>,
+    > for SetOrganizationMemberUseCase
+{
+    async fn execute(
+        &self,
+        data: SetOrganizationMemberParams,
+    ) -> Result<OrganizationMember, SetOrganizationMemberUseCaseError> {
+        let organization = self
+            .repository
+            .find_by_id(data.organization_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(msg) => {
+                    SetOrganizationMemberUseCaseError::OrganizationNotFound(msg)
+                }
+                e => SetOrganizationMemberUseCaseError::DatabaseError(e.to_string()),
+            })?;
+
+        if !organization
+            .role_of(data.requested_by)
+            .is_some_and(|role| role.can_manage_members())
+        {
+            return Err(SetOrganizationMemberUseCaseError::Forbidden);
+        }
+
+        // Checked again by the repository while it holds the organization,
+        // in case another owner leaves meanwhile
+        if data.role != OrganizationRole::Owner && organization.is_sole_owner(data.user_id) {
+            return Err(SetOrganizationMemberUseCaseError::SoleOwner);
+        }
+
+        self.user_repository
+            .find_by_id(data.user_id)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(msg) => SetOrganizationMemberUseCaseError::UserNotFound(msg),
+                e => SetOrganizationMemberUseCaseError::DatabaseError(e.to_string()),
+            })?;
+
+        self.repository
+            .save_member(data.organization_id, data.user_id, data.role)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(msg) => {
+                    SetOrganizationMemberUseCaseError::OrganizationNotFound(msg)
+                }
+                DomainError::Conflict(_) => SetOrganizationMemberUseCaseError::SoleOwner,
+                e => SetOrganizationMemberUseCaseError::DatabaseError(e.to_string()),
+            })
+    }
+}
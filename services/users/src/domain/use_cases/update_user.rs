@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::UseCase;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{DomainError, User, UserRepository};
+
+/// Partial update of a user; `None` leaves the field unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateUserParams {
+    pub id: Uuid,
+    pub email: Option<String>,
+    pub name: Option<String>,
+    /// Version the change was made against; `None` applies it to whatever
+    /// the current version is.
+    pub expected_version: Option<i32>,
+    pub requested_by: Uuid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateUserUseCaseError {
+    #[error("User not found: {0}")]
+    NotFound(String),
+
+    #[error("Users can only change their own profile")]
+    Forbidden,
+
+    #[error("User has been modified since the given version")]
+    VersionMismatch,
+
+    #[error("A user with this email already exists")]
+    EmailTaken,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+pub struct UpdateUserUseCase {
+    repository: Arc<dyn UserRepository>,
+}
+
+impl UpdateUserUseCase {
+    pub fn new(repository: Arc<dyn UserRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl UseCase<UpdateUserParams, Result<User, UpdateUserUseCaseError>> for UpdateUserUseCase {
+    async fn execute(&self, data: UpdateUserParams) -> Result<User, UpdateUserUseCaseError> {
+        if data.requested_by != data.id {
+            return Err(UpdateUserUseCaseError::Forbidden);
+        }
+
+        self.repository
+            .update(data)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(msg) => UpdateUserUseCaseError::NotFound(msg),
+                DomainError::DatabaseError(msg) => UpdateUserUseCaseError::DatabaseError(msg),
+                DomainError::Conflict(_) => UpdateUserUseCaseError::EmailTaken,
+                _ => {
+                    log::error!("Unexpected error in UpdateUserUseCase: {:?}", e);
+                    UpdateUserUseCaseError::DatabaseError("Unexpected error".to_string())
+                }
+            })?
+            .ok_or(UpdateUserUseCaseError::VersionMismatch)
+    }
+}
@@ -3,16 +3,25 @@ mod domain;
 
 use actix_web::{middleware::Logger, web, App, HttpServer};
 use adapters::inbound::http::configure_routes;
-use adapters::outbound::persistence::UserRepositoryImpl;
-use domain::{CreateUserUseCase, GetUserUseCase};
+use adapters::outbound::persistence::{OrganizationRepositoryImpl, UserRepositoryImpl};
+use domain::{
+    CreateOrganizationUseCase, CreateUserUseCase, GetOrganizationMemberUseCase,
+    GetOrganizationUseCase, GetUserUseCase, RemoveOrganizationMemberUseCase,
+    SetOrganizationMemberUseCase, UpdateUserUseCase,
+};
 use sea_orm::{Database, DatabaseConnection};
 use sea_orm_migration::prelude::*;
+use shared::{purge_expired_keys, Idempotency, PostgresIdempotencyStore};
 use std::sync::Arc;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 use adapters::inbound::http::dtos::{
-    CreateUserError, CreateUserRequest, CreateUserResponse, GetUserError, GetUserResponse,
+    CreateOrganizationError, CreateOrganizationRequest, CreateUserError, CreateUserRequest,
+    CreateUserResponse, GetOrganizationError, GetOrganizationMemberError, GetUserError,
+    GetUserResponse, MemberRole, OrganizationMemberResponse, OrganizationResponse,
+    RemoveOrganizationMemberError, SetOrganizationMemberError, SetOrganizationMemberRequest,
+    UpdateUserError, UpdateUserRequest,
 };
 
 #[derive(OpenApi)]
@@ -20,6 +29,12 @@ use adapters::inbound::http::dtos::{
     paths(
         adapters::inbound::http::handlers::get_user::get_user,
         adapters::inbound::http::handlers::create_user::create_user,
+        adapters::inbound::http::handlers::update_user::update_user,
+        adapters::inbound::http::handlers::create_organization::create_organization,
+        adapters::inbound::http::handlers::get_organization::get_organization,
+        adapters::inbound::http::handlers::get_organization_member::get_organization_member,
+        adapters::inbound::http::handlers::set_organization_member::set_organization_member,
+        adapters::inbound::http::handlers::remove_organization_member::remove_organization_member,
     ),
     components(
         schemas(
@@ -27,11 +42,24 @@ use adapters::inbound::http::dtos::{
             CreateUserResponse,
             CreateUserError,
             GetUserResponse,
-            GetUserError
+            GetUserError,
+            UpdateUserRequest,
+            UpdateUserError,
+            CreateOrganizationRequest,
+            CreateOrganizationError,
+            OrganizationResponse,
+            OrganizationMemberResponse,
+            MemberRole,
+            GetOrganizationError,
+            GetOrganizationMemberError,
+            SetOrganizationMemberRequest,
+            SetOrganizationMemberError,
+            RemoveOrganizationMemberError
         )
     ),
     tags(
-        (name = "users", description = "User management endpoints")
+        (name = "users", description = "User management endpoints"),
+        (name = "organizations", description = "Organizations, members and roles")
     ),
     info(
         title = "Users Service API",
@@ -71,10 +99,30 @@ async fn main() -> std::io::Result<()> {
     }
     println!("✅ Database migrations completed");
 
-    let user_repository = Arc::new(UserRepositoryImpl::new(db));
+    let user_repository = Arc::new(UserRepositoryImpl::new(db.clone()));
+    let idempotency_store = Arc::new(PostgresIdempotencyStore::new(db.clone()));
+    tokio::spawn(purge_expired_keys(idempotency_store.clone()));
+    let organization_repository = Arc::new(OrganizationRepositoryImpl::new(db));
 
     let get_user_use_case = web::Data::new(GetUserUseCase::new(user_repository.clone()));
     let create_user_use_case = web::Data::new(CreateUserUseCase::new(user_repository.clone()));
+    let update_user_use_case = web::Data::new(UpdateUserUseCase::new(user_repository.clone()));
+    let create_organization_use_case = web::Data::new(CreateOrganizationUseCase::new(
+        organization_repository.clone(),
+        user_repository.clone(),
+    ));
+    let get_organization_use_case =
+        web::Data::new(GetOrganizationUseCase::new(organization_repository.clone()));
+    let get_organization_member_use_case = web::Data::new(GetOrganizationMemberUseCase::new(
+        organization_repository.clone(),
+    ));
+    let set_organization_member_use_case = web::Data::new(SetOrganizationMemberUseCase::new(
+        organization_repository.clone(),
+        user_repository.clone(),
+    ));
+    let remove_organization_member_use_case = web::Data::new(RemoveOrganizationMemberUseCase::new(
+        organization_repository.clone(),
+    ));
 
     let host = std::env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_string());
     let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
@@ -90,9 +138,16 @@ async fn main() -> std::io::Result<()> {
 
     HttpServer::new(move || {
         App::new()
+            .wrap(Idempotency::new(idempotency_store.clone()))
             .wrap(Logger::default())
             .app_data(get_user_use_case.clone())
             .app_data(create_user_use_case.clone())
+            .app_data(update_user_use_case.clone())
+            .app_data(create_organization_use_case.clone())
+            .app_data(get_organization_use_case.clone())
+            .app_data(get_organization_member_use_case.clone())
+            .app_data(set_organization_member_use_case.clone())
+            .app_data(remove_organization_member_use_case.clone())
             .service(
                 web::scope("/users")
                     .configure(configure_routes)
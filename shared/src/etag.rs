@@ -0,0 +1,166 @@
+use actix_web::{
+    dev::Payload,
+    http::{
+        header::{self, EntityTag, Header},
+        StatusCode,
+    },
+    FromRequest, HttpRequest, HttpResponse,
+};
+use serde::Serialize;
+use std::future::{ready, Ready};
+
+/// Strong entity tag of a resource at the given version.
+///
+/// Return it in the `ETag` header of reads and writes so clients can make
+/// conditional requests against that version.
+pub fn entity_tag(version: i32) -> EntityTag {
+    EntityTag::new_strong(version.to_string())
+}
+
+/// Whether the request's `If-None-Match` already names the resource at
+/// `version`, in which case a read can be answered with 304 Not Modified.
+pub fn is_not_modified(req: &HttpRequest, version: i32) -> bool {
+    let current = entity_tag(version);
+    match header::IfNoneMatch::parse(req) {
+        Ok(header::IfNoneMatch::Any) => true,
+        Ok(header::IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(&current)),
+        Err(_) => false,
+    }
+}
+
+/// The version an update was made against, from its `If-Match` header.
+///
+/// Use it as a handler argument to require a precondition on writes, so two
+/// clients editing the same resource cannot silently overwrite each other.
+/// Requests without the header are rejected with 428, and requests whose tag
+/// can never match one of ours (weak or foreign tags) with 412.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IfMatch {
+    version: Option<i32>,
+}
+
+impl IfMatch {
+    /// The expected version, or `None` for `If-Match: *`, which matches any
+    /// current version.
+    pub fn version(&self) -> Option<i32> {
+        self.version
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PreconditionError {
+    #[error("If-Match header is required; send the ETag of the version being changed")]
+    Missing,
+    #[error("If-Match must carry a single ETag or *")]
+    Invalid,
+    #[error("The resource has been modified since the given ETag")]
+    Failed,
+}
+
+#[derive(Serialize)]
+struct PreconditionErrorBody {
+    error: String,
+}
+
+impl actix_web::ResponseError for PreconditionError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            PreconditionError::Missing => StatusCode::PRECONDITION_REQUIRED,
+            PreconditionError::Invalid => StatusCode::BAD_REQUEST,
+            PreconditionError::Failed => StatusCode::PRECONDITION_FAILED,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(PreconditionErrorBody {
+            error: self.to_string(),
+        })
+    }
+}
+
+impl FromRequest for IfMatch {
+    type Error = PreconditionError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(parse_if_match(req))
+    }
+}
+
+fn parse_if_match(req: &HttpRequest) -> Result<IfMatch, PreconditionError> {
+    if !req.headers().contains_key(header::IF_MATCH) {
+        return Err(PreconditionError::Missing);
+    }
+
+    match header::IfMatch::parse(req).map_err(|_| PreconditionError::Invalid)? {
+        header::IfMatch::Any => Ok(IfMatch { version: None }),
+        header::IfMatch::Items(tags) => match tags.as_slice() {
+            [tag] => {
+                // If-Match uses strong comparison, so a weak tag never matches.
+                if tag.weak {
+                    return Err(PreconditionError::Failed);
+                }
+                tag.tag()
+                    .parse()
+                    .map(|version| IfMatch {
+                        version: Some(version),
+                    })
+                    .map_err(|_| PreconditionError::Failed)
+            }
+            _ => Err(PreconditionError::Invalid),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    #[test]
+    fn if_match_requires_a_single_strong_version_tag() {
+        let parse = |value: Option<&str>| {
+            let mut req = TestRequest::default();
+            if let Some(value) = value {
+                req = req.insert_header((header::IF_MATCH, value));
+            }
+            parse_if_match(&req.to_http_request())
+        };
+
+        assert_eq!(parse(Some("\"3\"")).unwrap().version(), Some(3));
+        assert_eq!(parse(Some("*")).unwrap().version(), None);
+        assert!(matches!(parse(None), Err(PreconditionError::Missing)));
+        assert!(matches!(
+            parse(Some("W/\"3\"")),
+            Err(PreconditionError::Failed)
+        ));
+        assert!(matches!(
+            parse(Some("\"abc\"")),
+            Err(PreconditionError::Failed)
+        ));
+        assert!(matches!(
+            parse(Some("\"3\", \"4\"")),
+            Err(PreconditionError::Invalid)
+        ));
+    }
+
+    #[test]
+    fn if_none_match_uses_weak_comparison() {
+        let check = |value: &str| {
+            let req = TestRequest::default()
+                .insert_header((header::IF_NONE_MATCH, value))
+                .to_http_request();
+            is_not_modified(&req, 3)
+        };
+
+        assert!(check("\"3\""));
+        assert!(check("W/\"3\""));
+        assert!(check("\"2\", \"3\""));
+        assert!(check("*"));
+        assert!(!check("\"2\""));
+        assert!(!is_not_modified(
+            &TestRequest::default().to_http_request(),
+            3
+        ));
+    }
+}
@@ -0,0 +1,830 @@
+use actix_web::{
+    body::{self, BoxBody, MessageBody},
+    dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform},
+    http::{
+        header::{self, HeaderName, HeaderValue},
+        Method, StatusCode,
+    },
+    web::{Bytes, BytesMut},
+    HttpMessage, HttpResponse,
+};
+use async_trait::async_trait;
+use futures_util::{
+    future::{self, Either, LocalBoxFuture},
+    pin_mut, StreamExt,
+};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::{
+    convert::Infallible,
+    future::{ready, Ready},
+    rc::Rc,
+    sync::Arc,
+    time::Duration,
+};
+use uuid::Uuid;
+
+use crate::auth::USER_ID_HEADER;
+
+/// Header carrying the client's key for a mutating request. Retries of the
+/// request must send the same key.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Header set on responses replayed from an earlier request with the same key.
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "Idempotent-Replayed";
+
+const MAX_KEY_LENGTH: usize = 255;
+
+/// Largest request body that is buffered to fingerprint it; above the
+/// largest upload any endpoint accepts.
+const MAX_BODY_BYTES: usize = 64 * 1024 * 1024;
+
+/// How long a key is remembered; after that it may be used for a new request.
+const KEY_RETENTION_SECS: f64 = 24.0 * 60.0 * 60.0;
+
+/// How long a claim is kept without a heartbeat before its request is
+/// considered crashed and a retry may take the key over.
+const IN_FLIGHT_TIMEOUT_SECS: f64 = 60.0;
+
+/// How often a running request renews its claim; well within the in-flight
+/// timeout, so a slow request never loses its key.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How often expired keys are deleted.
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Scope prefix for keys sent without a caller; see `Idempotency`.
+const ANONYMOUS_SCOPE: &str = "anonymous";
+
+/// A response as it was first sent, to be replayed on retries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// What a request found when it tried to claim its key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyClaim {
+    /// The key is new; the request runs and its response is stored. Only
+    /// the holder of `token` may store the response or give the key up.
+    Acquired { token: Uuid },
+    /// A request with the same key and body is still running.
+    InFlight,
+    /// A request with the same key and body already ran.
+    Completed(StoredResponse),
+    /// The key was used for a different request.
+    Mismatch,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Idempotency store: {0}")]
+pub struct IdempotencyStoreError(pub String);
+
+/// Where idempotency keys and their responses are kept. Keys are scoped to
+/// the caller, or for anonymous requests to the request itself, so two
+/// callers can't see each other's responses.
+#[async_trait]
+pub trait IdempotencyStore: Send + Sync {
+    /// Atomically claims `key` for a request with `fingerprint`, unless it
+    /// was already claimed.
+    async fn claim(
+        &self,
+        scope: &str,
+        key: &str,
+        fingerprint: &str,
+    ) -> Result<IdempotencyClaim, IdempotencyStoreError>;
+
+    /// Keeps the claim `token` on `key` alive. Returns `false` if the claim
+    /// was lost.
+    async fn refresh(
+        &self,
+        scope: &str,
+        key: &str,
+        token: Uuid,
+    ) -> Result<bool, IdempotencyStoreError>;
+
+    /// Stores the response of the request holding the claim `token` on `key`.
+    /// Returns `false`, storing nothing, if the claim was lost.
+    async fn complete(
+        &self,
+        scope: &str,
+        key: &str,
+        token: Uuid,
+        response: &StoredResponse,
+    ) -> Result<bool, IdempotencyStoreError>;
+
+    /// Gives up the claim `token` on `key` without a response, so a retry
+    /// runs the request again.
+    async fn release(
+        &self,
+        scope: &str,
+        key: &str,
+        token: Uuid,
+    ) -> Result<(), IdempotencyStoreError>;
+
+    /// Deletes keys past their retention whose requests are no longer
+    /// running, returning how many were deleted.
+    async fn purge_expired(&self) -> Result<u64, IdempotencyStoreError>;
+}
+
+/// Keeps idempotency keys in the service's own database, in the
+/// `idempotency_keys` table.
+pub struct PostgresIdempotencyStore {
+    db: DatabaseConnection,
+}
+
+impl PostgresIdempotencyStore {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn statement(sql: &str, values: Vec<sea_orm::Value>) -> Statement {
+        Statement::from_sql_and_values(DbBackend::Postgres, sql, values)
+    }
+}
+
+fn store_error(e: impl std::fmt::Display) -> IdempotencyStoreError {
+    IdempotencyStoreError(e.to_string())
+}
+
+#[async_trait]
+impl IdempotencyStore for PostgresIdempotencyStore {
+    async fn claim(
+        &self,
+        scope: &str,
+        key: &str,
+        fingerprint: &str,
+    ) -> Result<IdempotencyClaim, IdempotencyStoreError> {
+        // The primary key lets one request insert the key; a concurrent
+        // duplicate waits for that insert and then finds the key taken. An
+        // expired key, or one whose request stopped sending heartbeats, is
+        // taken over under a new token.
+        let token = Uuid::new_v4();
+        let claimed = self
+            .db
+            .query_one(Self::statement(
+                r#"INSERT INTO idempotency_keys
+                    (scope, key, fingerprint, claim_token, created_at, refreshed_at)
+                VALUES ($1, $2, $3, $4, NOW(), NOW())
+                ON CONFLICT (scope, key) DO UPDATE
+                SET fingerprint = EXCLUDED.fingerprint,
+                    claim_token = EXCLUDED.claim_token,
+                    created_at = EXCLUDED.created_at,
+                    refreshed_at = EXCLUDED.refreshed_at,
+                    status_code = NULL,
+                    response_headers = NULL,
+                    response_body = NULL,
+                    completed_at = NULL
+                WHERE idempotency_keys.created_at < NOW() - make_interval(secs => $5)
+                   OR (idempotency_keys.completed_at IS NULL
+                       AND idempotency_keys.refreshed_at < NOW() - make_interval(secs => $6))
+                RETURNING key"#,
+                vec![
+                    scope.into(),
+                    key.into(),
+                    fingerprint.into(),
+                    token.into(),
+                    KEY_RETENTION_SECS.into(),
+                    IN_FLIGHT_TIMEOUT_SECS.into(),
+                ],
+            ))
+            .await
+            .map_err(store_error)?;
+        if claimed.is_some() {
+            return Ok(IdempotencyClaim::Acquired { token });
+        }
+
+        let existing = self
+            .db
+            .query_one(Self::statement(
+                r#"SELECT fingerprint, status_code, response_headers, response_body
+                FROM idempotency_keys WHERE scope = $1 AND key = $2"#,
+                vec![scope.into(), key.into()],
+            ))
+            .await
+            .map_err(store_error)?;
+        // Released between the two statements; the retry will claim it.
+        let Some(existing) = existing else {
+            return Ok(IdempotencyClaim::InFlight);
+        };
+
+        let stored_fingerprint: String =
+            existing.try_get("", "fingerprint").map_err(store_error)?;
+        if stored_fingerprint != fingerprint {
+            return Ok(IdempotencyClaim::Mismatch);
+        }
+        let status: Option<i16> = existing.try_get("", "status_code").map_err(store_error)?;
+        let Some(status) = status else {
+            return Ok(IdempotencyClaim::InFlight);
+        };
+        let headers: Option<String> = existing
+            .try_get("", "response_headers")
+            .map_err(store_error)?;
+        let body: Option<Vec<u8>> = existing.try_get("", "response_body").map_err(store_error)?;
+
+        Ok(IdempotencyClaim::Completed(StoredResponse {
+            status: status as u16,
+            headers: serde_json::from_str(headers.as_deref().unwrap_or("[]"))
+                .map_err(store_error)?,
+            body: body.unwrap_or_default(),
+        }))
+    }
+
+    async fn refresh(
+        &self,
+        scope: &str,
+        key: &str,
+        token: Uuid,
+    ) -> Result<bool, IdempotencyStoreError> {
+        let result = self
+            .db
+            .execute(Self::statement(
+                r#"UPDATE idempotency_keys SET refreshed_at = NOW()
+                WHERE scope = $1 AND key = $2 AND claim_token = $3 AND completed_at IS NULL"#,
+                vec![scope.into(), key.into(), token.into()],
+            ))
+            .await
+            .map_err(store_error)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn complete(
+        &self,
+        scope: &str,
+        key: &str,
+        token: Uuid,
+        response: &StoredResponse,
+    ) -> Result<bool, IdempotencyStoreError> {
+        let headers = serde_json::to_string(&response.headers).map_err(store_error)?;
+        let result = self
+            .db
+            .execute(Self::statement(
+                r#"UPDATE idempotency_keys
+                SET status_code = $4, response_headers = $5, response_body = $6,
+                    completed_at = NOW()
+                WHERE scope = $1 AND key = $2 AND claim_token = $3 AND completed_at IS NULL"#,
+                vec![
+                    scope.into(),
+                    key.into(),
+                    token.into(),
+                    (response.status as i16).into(),
+                    headers.into(),
+                    response.body.clone().into(),
+                ],
+            ))
+            .await
+            .map_err(store_error)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn release(
+        &self,
+        scope: &str,
+        key: &str,
+        token: Uuid,
+    ) -> Result<(), IdempotencyStoreError> {
+        self.db
+            .execute(Self::statement(
+                r#"DELETE FROM idempotency_keys
+                WHERE scope = $1 AND key = $2 AND claim_token = $3 AND completed_at IS NULL"#,
+                vec![scope.into(), key.into(), token.into()],
+            ))
+            .await
+            .map_err(store_error)?;
+        Ok(())
+    }
+
+    async fn purge_expired(&self) -> Result<u64, IdempotencyStoreError> {
+        let result = self
+            .db
+            .execute(Self::statement(
+                r#"DELETE FROM idempotency_keys
+                WHERE created_at < NOW() - make_interval(secs => $1)
+                  AND (completed_at IS NOT NULL
+                       OR refreshed_at < NOW() - make_interval(secs => $2))"#,
+                vec![KEY_RETENTION_SECS.into(), IN_FLIGHT_TIMEOUT_SECS.into()],
+            ))
+            .await
+            .map_err(store_error)?;
+        Ok(result.rows_affected())
+    }
+}
+
+/// Deletes expired keys from `store` every hour, for as long as it runs.
+/// Each replica can run one; the deletes don't conflict.
+pub async fn purge_expired_keys(store: Arc<dyn IdempotencyStore>) {
+    loop {
+        match store.purge_expired().await {
+            Ok(purged) if purged > 0 => log::info!("Purged {} expired idempotency key(s)", purged),
+            Ok(_) => {}
+            Err(e) => log::error!("Purging idempotency keys failed: {}", e),
+        }
+        actix_web::rt::time::sleep(PURGE_INTERVAL).await;
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum IdempotencyError {
+    #[error("{IDEMPOTENCY_KEY_HEADER} must be 1 to 255 visible ASCII characters")]
+    InvalidKey,
+    #[error("Request body is too large")]
+    BodyTooLarge,
+    #[error("Request body could not be read")]
+    UnreadableBody,
+    #[error("{IDEMPOTENCY_KEY_HEADER} was already used for a different request")]
+    KeyReused,
+    #[error("A request with this {IDEMPOTENCY_KEY_HEADER} is still being processed")]
+    InFlight,
+    #[error("{0}")]
+    Store(#[from] IdempotencyStoreError),
+}
+
+#[derive(Serialize)]
+struct IdempotencyErrorBody {
+    error: String,
+}
+
+impl actix_web::ResponseError for IdempotencyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            IdempotencyError::InvalidKey | IdempotencyError::UnreadableBody => {
+                StatusCode::BAD_REQUEST
+            }
+            IdempotencyError::BodyTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            IdempotencyError::KeyReused => StatusCode::UNPROCESSABLE_ENTITY,
+            IdempotencyError::InFlight => StatusCode::CONFLICT,
+            IdempotencyError::Store(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response = HttpResponse::build(self.status_code());
+        if let IdempotencyError::InFlight = self {
+            response.insert_header((header::RETRY_AFTER, 1));
+        }
+        response.json(IdempotencyErrorBody {
+            error: self.to_string(),
+        })
+    }
+}
+
+/// The request's idempotency key, if it is a mutating request that has one.
+fn idempotency_key(req: &ServiceRequest) -> Option<Result<String, IdempotencyError>> {
+    if !matches!(
+        *req.method(),
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    ) {
+        return None;
+    }
+
+    let value = req.headers().get(IDEMPOTENCY_KEY_HEADER)?;
+    Some(
+        value
+            .to_str()
+            .ok()
+            .map(str::trim)
+            .filter(|key| {
+                !key.is_empty()
+                    && key.len() <= MAX_KEY_LENGTH
+                    && key.bytes().all(|b| b.is_ascii_graphic())
+            })
+            .map(str::to_string)
+            .ok_or(IdempotencyError::InvalidKey),
+    )
+}
+
+/// Identifies a request by its method, target and body, so a key reused for
+/// a different request can be told apart from a retry.
+fn fingerprint(method: &Method, target: &str, body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_str());
+    hasher.update([0]);
+    hasher.update(target);
+    hasher.update([0]);
+    hasher.update(body);
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+async fn read_body(payload: &mut Payload) -> Result<Bytes, IdempotencyError> {
+    let mut body = BytesMut::new();
+    while let Some(chunk) = payload.next().await {
+        let chunk = chunk.map_err(|_| IdempotencyError::UnreadableBody)?;
+        if body.len() + chunk.len() > MAX_BODY_BYTES {
+            return Err(IdempotencyError::BodyTooLarge);
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body.freeze())
+}
+
+/// Renews the claim `token` on `key` until dropped, so that a request which
+/// runs longer than the in-flight timeout keeps its key.
+async fn keep_claimed(
+    store: &dyn IdempotencyStore,
+    scope: &str,
+    key: &str,
+    token: Uuid,
+) -> Infallible {
+    loop {
+        actix_web::rt::time::sleep(HEARTBEAT_INTERVAL).await;
+        match store.refresh(scope, key, token).await {
+            Ok(true) => {}
+            Ok(false) => {
+                log::warn!("Lost the claim on idempotency key {}", key);
+                return future::pending().await;
+            }
+            Err(e) => log::error!("Failed to refresh idempotency key: {}", e),
+        }
+    }
+}
+
+/// Headers that describe the connection rather than the response.
+fn is_replayable(name: &HeaderName) -> bool {
+    !matches!(
+        *name,
+        header::DATE | header::CONTENT_LENGTH | header::TRANSFER_ENCODING | header::CONNECTION
+    )
+}
+
+fn replay(stored: StoredResponse) -> HttpResponse {
+    let status = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+    let mut response = HttpResponse::build(status);
+    for (name, value) in &stored.headers {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::try_from(name.as_str()),
+            HeaderValue::from_str(value),
+        ) {
+            response.append_header((name, value));
+        }
+    }
+    response.insert_header((IDEMPOTENT_REPLAYED_HEADER, "true"));
+    response.body(stored.body)
+}
+
+/// Makes mutating requests safe to retry.
+///
+/// A POST, PUT, PATCH or DELETE sent with an `Idempotency-Key` header runs
+/// once; retries with the same key and body get the first response again,
+/// marked with `Idempotent-Replayed: true`. Reusing a key for a different
+/// request is rejected with 422, and a retry that arrives while the first
+/// request is still running with 409. Server errors aren't kept, so a retry
+/// after one runs the request again. Keys belong to the caller named by
+/// `X-User-Id`. Without one, as for signing up, a key is scoped to the
+/// request's fingerprint too, so only the same request replays its response
+/// and a different one under the same key simply runs. Requests without the
+/// header are passed through.
+pub struct Idempotency {
+    store: Arc<dyn IdempotencyStore>,
+}
+
+impl Idempotency {
+    pub fn new(store: Arc<dyn IdempotencyStore>) -> Self {
+        Self { store }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for Idempotency
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = actix_web::Error;
+    type Transform = IdempotencyMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(IdempotencyMiddleware {
+            service: Rc::new(service),
+            store: self.store.clone(),
+        }))
+    }
+}
+
+pub struct IdempotencyMiddleware<S> {
+    service: Rc<S>,
+    store: Arc<dyn IdempotencyStore>,
+}
+
+impl<S, B> Service<ServiceRequest> for IdempotencyMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let store = self.store.clone();
+
+        Box::pin(async move {
+            let key = match idempotency_key(&req) {
+                None => return service.call(req).await.map(|res| res.map_into_boxed_body()),
+                Some(Ok(key)) => key,
+                Some(Err(e)) => return Ok(req.error_response(e)),
+            };
+            let body = match read_body(&mut req.take_payload()).await {
+                Ok(body) => body,
+                Err(e) => return Ok(req.error_response(e)),
+            };
+            let target = req
+                .uri()
+                .path_and_query()
+                .map(|target| target.as_str())
+                .unwrap_or_else(|| req.path());
+            let fingerprint = fingerprint(req.method(), target, &body);
+            req.set_payload(Payload::from(body));
+            // Anonymous callers sharing one scope could replay each other's
+            // responses just by guessing a key.
+            let scope = match req
+                .headers()
+                .get(USER_ID_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| Uuid::parse_str(value.trim()).ok())
+            {
+                Some(user_id) => user_id.to_string(),
+                None => format!("{}:{}", ANONYMOUS_SCOPE, fingerprint),
+            };
+
+            let token = match store.claim(&scope, &key, &fingerprint).await {
+                Ok(IdempotencyClaim::Acquired { token }) => token,
+                Ok(IdempotencyClaim::Completed(stored)) => {
+                    return Ok(req.into_response(replay(stored)));
+                }
+                Ok(IdempotencyClaim::InFlight) => {
+                    return Ok(req.error_response(IdempotencyError::InFlight));
+                }
+                Ok(IdempotencyClaim::Mismatch) => {
+                    return Ok(req.error_response(IdempotencyError::KeyReused));
+                }
+                Err(e) => return Ok(req.error_response(IdempotencyError::Store(e))),
+            };
+
+            let outcome = {
+                let call = service.call(req);
+                let heartbeat = keep_claimed(&*store, &scope, &key, token);
+                pin_mut!(call, heartbeat);
+                match future::select(call, heartbeat).await {
+                    Either::Left((outcome, _)) => outcome,
+                    Either::Right((never, _)) => match never {},
+                }
+            };
+            let res = match outcome {
+                Ok(res) if !res.status().is_server_error() => res,
+                outcome => {
+                    if let Err(e) = store.release(&scope, &key, token).await {
+                        log::error!("Failed to release idempotency key: {}", e);
+                    }
+                    return outcome.map(|res| res.map_into_boxed_body());
+                }
+            };
+
+            let (req, res) = res.into_parts();
+            let (res, res_body) = res.into_parts();
+            let res_body = match body::to_bytes(res_body).await {
+                Ok(res_body) => res_body,
+                Err(e) => {
+                    if let Err(e) = store.release(&scope, &key, token).await {
+                        log::error!("Failed to release idempotency key: {}", e);
+                    }
+                    return Err(actix_web::error::ErrorInternalServerError(
+                        e.into().to_string(),
+                    ));
+                }
+            };
+
+            let stored = StoredResponse {
+                status: res.status().as_u16(),
+                headers: res
+                    .headers()
+                    .iter()
+                    .filter(|(name, _)| is_replayable(name))
+                    .filter_map(|(name, value)| {
+                        Some((name.to_string(), value.to_str().ok()?.to_string()))
+                    })
+                    .collect(),
+                body: res_body.to_vec(),
+            };
+            // The request already took effect, so its response goes out even
+            // if it can't be kept; retries then wait out the in-flight timeout.
+            match store.complete(&scope, &key, token, &stored).await {
+                Ok(true) => {}
+                Ok(false) => log::warn!(
+                    "Lost the claim on idempotency key {} before storing its response",
+                    key
+                ),
+                Err(e) => log::error!("Failed to store idempotent response: {}", e),
+            }
+
+            Ok(ServiceResponse::new(
+                req,
+                res.set_body(res_body).map_into_boxed_body(),
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{test, web, App};
+    use std::{
+        collections::HashMap,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Mutex,
+        },
+    };
+
+    /// Fingerprint, claim token and response.
+    type StoredKey = (String, Uuid, Option<StoredResponse>);
+
+    #[derive(Default)]
+    struct MemoryStore {
+        /// Keys by scope and key.
+        keys: Mutex<HashMap<String, StoredKey>>,
+    }
+
+    #[async_trait]
+    impl IdempotencyStore for MemoryStore {
+        async fn claim(
+            &self,
+            scope: &str,
+            key: &str,
+            fingerprint: &str,
+        ) -> Result<IdempotencyClaim, IdempotencyStoreError> {
+            let mut keys = self.keys.lock().unwrap();
+            let id = format!("{}/{}", scope, key);
+            Ok(match keys.get(&id) {
+                None => {
+                    let token = Uuid::new_v4();
+                    keys.insert(id, (fingerprint.to_string(), token, None));
+                    IdempotencyClaim::Acquired { token }
+                }
+                Some((stored, _, _)) if stored != fingerprint => IdempotencyClaim::Mismatch,
+                Some((_, _, None)) => IdempotencyClaim::InFlight,
+                Some((_, _, Some(response))) => IdempotencyClaim::Completed(response.clone()),
+            })
+        }
+
+        async fn refresh(
+            &self,
+            scope: &str,
+            key: &str,
+            token: Uuid,
+        ) -> Result<bool, IdempotencyStoreError> {
+            let keys = self.keys.lock().unwrap();
+            Ok(matches!(
+                keys.get(&format!("{}/{}", scope, key)),
+                Some((_, held, None)) if *held == token
+            ))
+        }
+
+        async fn complete(
+            &self,
+            scope: &str,
+            key: &str,
+            token: Uuid,
+            response: &StoredResponse,
+        ) -> Result<bool, IdempotencyStoreError> {
+            let mut keys = self.keys.lock().unwrap();
+            match keys.get_mut(&format!("{}/{}", scope, key)) {
+                Some((_, held, stored @ None)) if *held == token => {
+                    *stored = Some(response.clone());
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn release(
+            &self,
+            scope: &str,
+            key: &str,
+            token: Uuid,
+        ) -> Result<(), IdempotencyStoreError> {
+            let mut keys = self.keys.lock().unwrap();
+            let id = format!("{}/{}", scope, key);
+            if matches!(keys.get(&id), Some((_, held, None)) if *held == token) {
+                keys.remove(&id);
+            }
+            Ok(())
+        }
+
+        async fn purge_expired(&self) -> Result<u64, IdempotencyStoreError> {
+            Ok(0)
+        }
+    }
+
+    #[actix_web::test]
+    async fn retries_replay_the_first_response_and_reused_keys_are_rejected() {
+        let created = Arc::new(AtomicUsize::new(0));
+        let app = test::init_service(
+            App::new()
+                .wrap(Idempotency::new(Arc::new(MemoryStore::default())))
+                .app_data(web::Data::new(created.clone()))
+                .route(
+                    "/things",
+                    web::post().to(
+                        |body: Bytes, created: web::Data<Arc<AtomicUsize>>| async move {
+                            let n = created.fetch_add(1, Ordering::SeqCst) + 1;
+                            HttpResponse::Created().body(format!("{} {:?}", n, body))
+                        },
+                    ),
+                ),
+        )
+        .await;
+        let caller = Uuid::new_v4().to_string();
+        let post = |key: Option<&str>, body: &'static str| {
+            let mut req = test::TestRequest::post()
+                .uri("/things")
+                .insert_header((USER_ID_HEADER, caller.as_str()))
+                .set_payload(body);
+            if let Some(key) = key {
+                req = req.insert_header((IDEMPOTENCY_KEY_HEADER, key));
+            }
+            req.to_request()
+        };
+
+        let first = test::call_service(&app, post(Some("k1"), "a")).await;
+        assert_eq!(first.status(), StatusCode::CREATED);
+        assert!(!first.headers().contains_key(IDEMPOTENT_REPLAYED_HEADER));
+        let first = test::read_body(first).await;
+
+        let retry = test::call_service(&app, post(Some("k1"), "a")).await;
+        assert_eq!(retry.status(), StatusCode::CREATED);
+        assert_eq!(
+            retry.headers().get(IDEMPOTENT_REPLAYED_HEADER).unwrap(),
+            "true"
+        );
+        assert_eq!(test::read_body(retry).await, first);
+        assert_eq!(created.load(Ordering::SeqCst), 1);
+
+        let reused = test::call_service(&app, post(Some("k1"), "b")).await;
+        assert_eq!(reused.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let invalid = test::call_service(&app, post(Some("bad key"), "a")).await;
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+
+        test::call_service(&app, post(None, "a")).await;
+        test::call_service(&app, post(None, "a")).await;
+        assert_eq!(created.load(Ordering::SeqCst), 3);
+    }
+
+    #[actix_web::test]
+    async fn anonymous_keys_replay_only_the_same_request() {
+        let created = Arc::new(AtomicUsize::new(0));
+        let app = test::init_service(
+            App::new()
+                .wrap(Idempotency::new(Arc::new(MemoryStore::default())))
+                .app_data(web::Data::new(created.clone()))
+                .route(
+                    "/signup",
+                    web::post().to(
+                        |body: Bytes, created: web::Data<Arc<AtomicUsize>>| async move {
+                            let n = created.fetch_add(1, Ordering::SeqCst) + 1;
+                            HttpResponse::Created().body(format!("{} {:?}", n, body))
+                        },
+                    ),
+                ),
+        )
+        .await;
+        let signup = |body: &'static str| {
+            test::TestRequest::post()
+                .uri("/signup")
+                .insert_header((IDEMPOTENCY_KEY_HEADER, "k1"))
+                .set_payload(body)
+                .to_request()
+        };
+
+        let first = test::call_service(&app, signup("alice")).await;
+        assert_eq!(first.status(), StatusCode::CREATED);
+        let first = test::read_body(first).await;
+
+        let retry = test::call_service(&app, signup("alice")).await;
+        assert_eq!(
+            retry.headers().get(IDEMPOTENT_REPLAYED_HEADER).unwrap(),
+            "true"
+        );
+        assert_eq!(test::read_body(retry).await, first);
+
+        // Someone else signing up with the same key gets their own account
+        let other = test::call_service(&app, signup("bob")).await;
+        assert_eq!(other.status(), StatusCode::CREATED);
+        assert!(!other.headers().contains_key(IDEMPOTENT_REPLAYED_HEADER));
+        assert_ne!(test::read_body(other).await, first);
+        assert_eq!(created.load(Ordering::SeqCst), 2);
+    }
+}
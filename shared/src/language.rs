@@ -0,0 +1,84 @@
+use actix_web::{
+    dev::Payload,
+    http::header::{self, Header, Preference, Quality},
+    FromRequest, HttpRequest,
+};
+use std::{
+    cmp::Reverse,
+    convert::Infallible,
+    future::{ready, Ready},
+};
+
+/// Languages the client prefers, from its `Accept-Language` header.
+///
+/// Use it as a handler argument to pick which translation to respond with.
+/// A missing or malformed header means no preference, so the extractor never
+/// fails.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AcceptLanguage {
+    ranges: Vec<String>,
+}
+
+impl AcceptLanguage {
+    /// Language ranges, most preferred first. `*` and languages the client
+    /// refuses (`q=0`) are left out.
+    pub fn ranges(&self) -> &[String] {
+        &self.ranges
+    }
+}
+
+impl FromRequest for AcceptLanguage {
+    type Error = Infallible;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(Ok(parse_accept_language(req)))
+    }
+}
+
+fn parse_accept_language(req: &HttpRequest) -> AcceptLanguage {
+    let Ok(header) = header::AcceptLanguage::parse(req) else {
+        return AcceptLanguage::default();
+    };
+
+    let mut items = header.0;
+    // Stable, so ranges of equal quality keep the client's order.
+    items.sort_by_key(|item| Reverse(item.quality));
+    AcceptLanguage {
+        ranges: items
+            .into_iter()
+            .filter(|item| item.quality > Quality::ZERO)
+            .filter_map(|item| match item.item {
+                Preference::Specific(tag) => Some(tag.to_string()),
+                Preference::Any => None,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    #[test]
+    fn accept_language_ranks_by_quality() {
+        let parse = |value: Option<&str>| {
+            let mut req = TestRequest::default();
+            if let Some(value) = value {
+                req = req.insert_header((header::ACCEPT_LANGUAGE, value));
+            }
+            parse_accept_language(&req.to_http_request())
+                .ranges()
+                .to_vec()
+        };
+
+        assert_eq!(
+            parse(Some("fr;q=0.5, de-AT, en;q=0.8, *;q=0.1")),
+            vec!["de-AT", "en", "fr"]
+        );
+        assert_eq!(parse(Some("es, it;q=0")), vec!["es"]);
+        assert!(parse(None).is_empty());
+        assert!(parse(Some(";;q=x")).is_empty());
+    }
+}
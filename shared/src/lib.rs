@@ -1,3 +1,18 @@
+pub mod auth;
+pub mod etag;
+pub mod idempotency;
+pub mod language;
+
+pub use auth::{
+    AuthenticatedUser, AuthenticationError, ServiceCaller, ServiceToken, SERVICE_TOKEN_HEADER,
+    USER_ID_HEADER,
+};
+pub use etag::{entity_tag, is_not_modified, IfMatch, PreconditionError};
+pub use idempotency::{
+    purge_expired_keys, Idempotency, PostgresIdempotencyStore, IDEMPOTENCY_KEY_HEADER,
+};
+pub use language::AcceptLanguage;
+
 use async_trait::async_trait;
 
 /// Generic use case trait that all use cases should implement.